@@ -21,8 +21,9 @@ use std::fmt::Write as _;
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 use destructive_command_guard::packs::{REGISTRY, pack_aware_quick_reject};
 use destructive_command_guard::{
-    Config, ExtractionLimits, ScriptLanguage, check_triggers, evaluate_command_with_pack_order,
-    extract_content, extract_shell_commands, matched_triggers,
+    Config, CustomTriggers, ExtractionLimits, ScriptLanguage, check_triggers,
+    check_triggers_with_custom, evaluate_command_with_pack_order, extract_content,
+    extract_shell_commands, matched_triggers,
 };
 
 // =============================================================================
@@ -35,6 +36,9 @@ const SIMPLE_COMMAND: &str = "git status --short";
 /// Command with inline Python script.
 const INLINE_PYTHON: &str = r#"python3 -c "import os; os.system('rm -rf /')" "#;
 
+/// Command using an in-house interpreter covered only by a custom trigger.
+const CUSTOM_INLINE: &str = r#"mylang -e "import os; os.system('rm -rf /')" "#;
+
 /// Command with heredoc marker.
 const HEREDOC_BASH: &str = r#"bash << 'EOF'
 rm -rf /
@@ -129,6 +133,21 @@ fn bench_tier1_triggers(c: &mut Criterion) {
         },
     );
 
+    // Custom trigger phrases ([heredoc] custom_triggers)
+    let custom = CustomTriggers::new(&["mylang -e"]).expect("custom trigger phrase");
+    for (name, cmd) in [
+        ("simple_cmd", SIMPLE_COMMAND),
+        ("custom_inline", CUSTOM_INLINE),
+    ] {
+        group.bench_with_input(
+            BenchmarkId::new("check_triggers_with_custom", name),
+            cmd,
+            |b: &mut criterion::Bencher<'_>, cmd: &str| {
+                b.iter(|| check_triggers_with_custom(black_box(cmd), Some(&custom)));
+            },
+        );
+    }
+
     // Detailed trigger matching
     for (name, cmd) in cases {
         group.bench_with_input(
@@ -3,17 +3,20 @@
 //! Embeds build metadata (timestamp, git commit, rustc version) into the binary
 //! for display in --version output and debugging.
 
-use vergen_gix::{Build, Cargo, Emitter, Rustc};
+use vergen_gix::{Build, Cargo, Emitter, Gix, Rustc};
 
 fn main() {
     // Emit build metadata as environment variables at compile time
     let build = Build::builder().build_timestamp(true).build();
     let cargo = Cargo::builder().target_triple(true).build();
     let rustc = Rustc::builder().semver(true).build();
+    // Full commit SHA. Source tarballs (crates.io, distro packages) have no
+    // repository, in which case nothing is emitted and the SHA is unknown.
+    let gix = Gix::builder().sha(false).build();
 
     let mut emitter = Emitter::default();
 
-    // Add build, cargo, and rustc instructions if available
+    // Add build, cargo, rustc, and git instructions if available
     if let Err(e) = emitter.add_instructions(&build) {
         eprintln!("cargo:warning=vergen build instructions failed: {e}");
     }
@@ -26,6 +29,10 @@ fn main() {
         eprintln!("cargo:warning=vergen rustc instructions failed: {e}");
     }
 
+    if let Err(e) = emitter.add_instructions(&gix) {
+        eprintln!("cargo:warning=vergen git instructions failed: {e}");
+    }
+
     // Emit all collected instructions
     if let Err(e) = emitter.emit() {
         eprintln!("cargo:warning=vergen emit failed: {e}");
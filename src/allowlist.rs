@@ -385,6 +385,53 @@ impl LayeredAllowlist {
         }
         None
     }
+
+    /// Find expired entries that would otherwise match a rule or command.
+    ///
+    /// `dcg explain` uses this to report entries that were skipped because they
+    /// expired. Path restrictions are honored; regex selectors are not checked.
+    #[must_use]
+    pub fn expired_matches_at_path(
+        &self,
+        pack_id: Option<&str>,
+        pattern_name: Option<&str>,
+        command: &str,
+        cwd: Option<&Path>,
+    ) -> Vec<AllowlistHit<'_>> {
+        let mut hits = Vec::new();
+        for layer in &self.layers {
+            for entry in &layer.file.entries {
+                if !is_expired(entry) {
+                    continue;
+                }
+                if let Some(cwd) = cwd {
+                    if !entry_path_matches(entry, &cwd.to_string_lossy()) {
+                        continue;
+                    }
+                }
+
+                let matches = match &entry.selector {
+                    AllowSelector::Rule(rule_id) => {
+                        rule_id.pack_id != "*"
+                            && pack_id == Some(rule_id.pack_id.as_str())
+                            && pattern_name.is_some_and(|name| {
+                                rule_id.pattern_name == name || rule_id.pattern_name == "*"
+                            })
+                    }
+                    AllowSelector::ExactCommand(cmd) => cmd == command,
+                    AllowSelector::CommandPrefix(prefix) => command.starts_with(prefix.as_str()),
+                    AllowSelector::RegexPattern(_) => false,
+                };
+                if matches {
+                    hits.push(AllowlistHit {
+                        layer: layer.layer,
+                        entry,
+                    });
+                }
+            }
+        }
+        hits
+    }
 }
 
 /// A successful allowlist match (borrowed view).
@@ -428,31 +475,62 @@ pub fn is_expired(entry: &AllowEntry) -> bool {
     false
 }
 
-/// Check if an absolute timestamp has expired.
-fn is_timestamp_expired(expires_at: &str) -> bool {
+/// Compute when an allowlist entry expires.
+///
+/// Uses `expires_at` when set, otherwise `ttl` relative to `added_at`.
+/// Returns `None` for entries without an expiration (or session-scoped ones)
+/// and for expirations that cannot be parsed.
+#[must_use]
+pub fn expiration_time(entry: &AllowEntry) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Some(ref expires_at) = entry.expires_at {
+        return parse_expiration_timestamp(expires_at);
+    }
+
+    let ttl = entry.ttl.as_deref()?;
+    let added_time = parse_timestamp(entry.added_at.as_deref()?)?;
+    let duration = parse_duration(ttl).ok()?;
+    added_time.checked_add_signed(duration)
+}
+
+/// Check if an entry is still valid but expires within `window` from now.
+///
+/// Used by `dcg doctor` to flag temporary entries that are about to lapse.
+#[must_use]
+pub fn expires_within(entry: &AllowEntry, window: chrono::TimeDelta) -> bool {
+    let now = chrono::Utc::now();
+    expiration_time(entry).is_some_and(|expires| expires >= now && expires - now <= window)
+}
+
+/// Parse an absolute expiration timestamp.
+///
+/// Accepts RFC 3339, ISO 8601 without timezone (UTC), and date-only values.
+fn parse_expiration_timestamp(expires_at: &str) -> Option<chrono::DateTime<chrono::Utc>> {
     // Try RFC 3339 first (e.g., "2030-01-01T00:00:00Z" or "2030-01-01T00:00:00+00:00")
     if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(expires_at) {
-        return dt < chrono::Utc::now();
+        return Some(dt.with_timezone(&chrono::Utc));
     }
 
     // Try ISO 8601 without timezone (treat as UTC)
     if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(expires_at, "%Y-%m-%dT%H:%M:%S") {
-        let utc = dt.and_utc();
-        return utc < chrono::Utc::now();
+        return Some(dt.and_utc());
     }
 
     // Try date only (YYYY-MM-DD) - treat as end of day UTC (23:59:59)
     // This matches intuitive semantics: "expires 2026-01-08" means valid through that day
     if let Ok(date) = chrono::NaiveDate::parse_from_str(expires_at, "%Y-%m-%d") {
-        if let Some(end_of_day) = date.and_hms_opt(23, 59, 59) {
-            return end_of_day.and_utc() < chrono::Utc::now();
-        }
-        return true;
+        return date
+            .and_hms_opt(23, 59, 59)
+            .map(|end_of_day| end_of_day.and_utc());
     }
 
+    None
+}
+
+/// Check if an absolute timestamp has expired.
+fn is_timestamp_expired(expires_at: &str) -> bool {
     // Invalid timestamp format - treat as expired (fail closed) for safety.
     // This prevents typos like "2025/01/01" from accidentally creating permanent allowlists.
-    true
+    parse_expiration_timestamp(expires_at).is_none_or(|dt| dt < chrono::Utc::now())
 }
 
 /// Check if a TTL-based entry has expired.
@@ -1415,6 +1493,65 @@ mod tests {
         assert!(is_expired(&entry));
     }
 
+    #[test]
+    fn expires_within_flags_only_entries_about_to_lapse() {
+        let window = chrono::TimeDelta::days(7);
+        let mut entry = make_test_entry();
+        assert!(!expires_within(&entry, window), "no expiration");
+
+        entry.expires_at = Some((chrono::Utc::now() + chrono::TimeDelta::days(2)).to_rfc3339());
+        assert!(expires_within(&entry, window));
+
+        entry.expires_at = Some((chrono::Utc::now() + chrono::TimeDelta::days(30)).to_rfc3339());
+        assert!(!expires_within(&entry, window));
+
+        entry.expires_at = Some("2020-01-01T00:00:00Z".to_string());
+        assert!(!expires_within(&entry, window), "already expired");
+
+        entry.expires_at = None;
+        entry.added_at = Some(chrono::Utc::now().to_rfc3339());
+        entry.ttl = Some("3d".to_string());
+        assert!(expires_within(&entry, window), "ttl relative to added_at");
+    }
+
+    #[test]
+    fn expired_matches_reports_lapsed_entries_only() {
+        let mut expired_rule = make_test_entry();
+        expired_rule.expires_at = Some("2020-01-01T00:00:00Z".to_string());
+        let mut expired_command = make_test_entry();
+        expired_command.selector = AllowSelector::ExactCommand("git reset --hard".to_string());
+        expired_command.expires_at = Some("2020-01-01".to_string());
+        let live_rule = make_test_entry();
+
+        let allowlists = LayeredAllowlist {
+            layers: vec![LoadedAllowlistLayer {
+                layer: AllowlistLayer::User,
+                path: PathBuf::from("allowlist.toml"),
+                file: AllowlistFile {
+                    entries: vec![expired_rule, expired_command, live_rule],
+                    errors: Vec::new(),
+                },
+            }],
+        };
+
+        let hits = allowlists.expired_matches_at_path(
+            Some("core.git"),
+            Some("reset-hard"),
+            "git reset --hard",
+            None,
+        );
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|hit| hit.layer == AllowlistLayer::User));
+
+        let hits = allowlists.expired_matches_at_path(
+            Some("core.git"),
+            Some("clean-force"),
+            "git clean -fd",
+            None,
+        );
+        assert!(hits.is_empty());
+    }
+
     #[test]
     fn entry_with_future_iso8601_no_tz_is_not_expired() {
         let mut entry = make_test_entry();
@@ -12,7 +12,8 @@ use crate::agent::{DetectionMethod, detect_agent_with_details};
 use crate::config::Config;
 use crate::evaluator::{
     DEFAULT_WINDOW_WIDTH, EvaluationDecision, EvaluationResult, MatchSource,
-    evaluate_command_with_pack_order, evaluate_command_with_pack_order_deadline_at_path,
+    evaluate_command_with_pack_order, evaluate_command_with_pack_order_at_path,
+    evaluate_command_with_pack_order_deadline_at_path,
 };
 use crate::exit_codes::EXIT_DENIED;
 use crate::highlight::{HighlightSpan, format_highlighted_command, should_use_color};
@@ -29,6 +30,7 @@ use crate::pending_exceptions::{
     AllowOnceEntry, AllowOnceScopeKind, AllowOnceStore, PendingExceptionRecord,
     PendingExceptionStore,
 };
+use crate::review_queue::{ReviewQueueEntry, ReviewQueueStore, ReviewStatus};
 use crate::suggest::{
     AllowlistSuggestion, CommandEntryInfo, ConfidenceTier, RiskLevel, filter_by_confidence,
     filter_by_risk, generate_enhanced_suggestions,
@@ -126,14 +128,34 @@ pub struct Cli {
     #[arg(long, global = true, env = "DCG_LEGACY_OUTPUT")]
     pub legacy_output: bool,
 
-    /// Disable colored output globally
+    /// Disable colored output globally (same as `--color never`)
     #[arg(long, global = true, env = "DCG_NO_COLOR")]
     pub no_color: bool,
 
+    /// When to color output: auto (TTY/`NO_COLOR` detection), always (even
+    /// when piped), or never
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "WHEN",
+        default_value = "auto",
+        env = "DCG_COLOR"
+    )]
+    pub color: crate::output::ColorChoice,
+
     /// Disable suggestion output in warnings/denials
     #[arg(long, global = true, env = "DCG_NO_SUGGESTIONS")]
     pub no_suggestions: bool,
 
+    /// Print JSON output on a single line instead of indented
+    ///
+    /// Applies to every `--format json` / `--json` output. Compact output keeps
+    /// logs small in CI; the default pretty output is easier to read. Both
+    /// contain the same fields in the same order.
+    #[arg(long, global = true, env = "DCG_COMPACT_JSON")]
+    pub compact_json: bool,
+
     /// Enable robot/machine mode for AI agent integration
     ///
     /// When enabled:
@@ -241,6 +263,22 @@ pub enum Command {
     #[command(name = "allow-once")]
     AllowOnce(AllowOnceCommand),
 
+    /// Pre-approve one run of an exact command with a single-use token
+    ///
+    /// The next time the exact command arrives in hook mode (in this
+    /// directory, or anywhere in this repository) it is allowed and the token
+    /// is consumed. Unused tokens expire. Manage them with `dcg allow-once
+    /// list|revoke`.
+    #[command(name = "approve")]
+    Approve(ApproveCommand),
+
+    /// Approve or reject denied commands queued for human review
+    ///
+    /// With `[review_queue] enabled = true`, hook denials are queued here.
+    /// Run without a subcommand to step through pending entries interactively.
+    #[command(name = "review")]
+    Review(ReviewCommand),
+
     /// Install the hook into Claude Code settings
     #[command(name = "install")]
     Install {
@@ -276,6 +314,10 @@ pub enum Command {
         #[arg(long)]
         enabled: bool,
 
+        /// Show only packs carrying this tag (e.g., "aws", "data-loss")
+        #[arg(long, value_name = "TAG")]
+        tag: Option<String>,
+
         // NOTE: Removed `verbose: bool` - use global `-v`/`--verbose` instead.
         // The global flag (u8 count) conflicts with local bool flags.
         /// Output format (json for structured output, pretty for human-readable)
@@ -300,7 +342,17 @@ pub enum Command {
     #[command(name = "test")]
     TestCommand {
         /// Command to test
-        command: String,
+        #[arg(required_unless_present = "stdin")]
+        command: Option<String>,
+
+        /// Read commands from stdin, one per line, and print ALLOW or
+        /// DENY <rule_id> for each (blank lines and `#` comments are skipped)
+        #[arg(long, conflicts_with_all = ["command", "explain", "min_severity"])]
+        stdin: bool,
+
+        /// With --stdin, exit non-zero if any line is denied
+        #[arg(long)]
+        exit_code: bool,
 
         /// Use a specific config file (overrides default config discovery)
         #[arg(long, short = 'c', value_name = "PATH")]
@@ -310,10 +362,30 @@ pub enum Command {
         #[arg(long, value_delimiter = ',')]
         with_packs: Option<Vec<String>>,
 
+        /// Evaluate with exactly these built-in packs (comma-separated),
+        /// ignoring configured packs, overrides, external packs, and allowlists
+        #[arg(
+            long = "only-pack",
+            value_delimiter = ',',
+            value_name = "PACKS",
+            conflicts_with_all = ["with_packs", "explain"]
+        )]
+        only_pack: Option<Vec<String>>,
+
+        /// Skip allowlist overrides (project/user/system)
+        #[arg(long = "no-allowlist")]
+        no_allowlist: bool,
+
         /// Show detailed decision trace (same as `dcg explain`)
         #[arg(long)]
         explain: bool,
 
+        /// Also report the decision if only matches at or above this
+        /// severity were enforced (preview only; the real decision and exit
+        /// code are unchanged)
+        #[arg(long, value_enum, value_name = "LEVEL")]
+        min_severity: Option<MinSeverity>,
+
         /// Output format (json for structured output, pretty for human-readable)
         #[arg(
             long,
@@ -365,13 +437,20 @@ pub enum Command {
     #[command(name = "config")]
     ShowConfig,
 
+    /// Review the effective security policy
+    #[command(name = "policy")]
+    Policy {
+        #[command(subcommand)]
+        action: PolicyAction,
+    },
+
     /// Scan files for destructive commands (CI/pre-commit integration)
     ///
     /// Extracts executable command contexts from files and evaluates them
     /// using the same pipeline as hook mode. Use `--fail-on` to control
     /// exit codes for CI integration.
     #[command(name = "scan")]
-    Scan(ScanCommand),
+    Scan(Box<ScanCommand>),
 
     /// Simulate policy evaluation on command logs (replay/dry-run)
     ///
@@ -395,7 +474,8 @@ pub enum Command {
     #[command(name = "explain")]
     Explain {
         /// Command to explain
-        command: String,
+        #[arg(required_unless_present_any = ["print_schema", "compare"])]
+        command: Option<String>,
 
         /// Output format
         #[arg(
@@ -410,6 +490,43 @@ pub enum Command {
         /// Additional packs to enable for this evaluation
         #[arg(long, value_delimiter = ',')]
         with_packs: Option<Vec<String>>,
+
+        /// Evaluate as if run from this directory (config, project policy, git branch)
+        #[arg(long, value_name = "DIR")]
+        context: Option<std::path::PathBuf>,
+
+        /// Evaluate as if on this git branch (overrides branch detection)
+        #[arg(long, value_name = "NAME")]
+        branch: Option<String>,
+
+        /// Also report the decision if only matches at or above this
+        /// severity were enforced (preview only; the real decision is unchanged)
+        #[arg(long, value_enum, value_name = "LEVEL")]
+        min_severity: Option<MinSeverity>,
+
+        /// List every safe and destructive pattern that matches, across all
+        /// packs, and mark the one that decided the outcome
+        #[arg(long)]
+        all_matches: bool,
+
+        /// Write the result to this file instead of stdout (uncolored; created
+        /// atomically, parent directories included)
+        #[arg(long, short = 'o', value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+
+        /// Print the JSON Schema for `--format json` output and exit
+        #[arg(long = "print-schema", conflicts_with = "output")]
+        print_schema: bool,
+
+        /// Explain two commands and show where their traces differ
+        /// (pretty or JSON format)
+        #[arg(
+            long,
+            num_args = 2,
+            value_names = ["CMD_A", "CMD_B"],
+            conflicts_with_all = ["command", "print_schema", "all_matches"]
+        )]
+        compare: Option<Vec<String>>,
     },
 
     /// Run regression corpus tests and output detailed JSON logs
@@ -427,7 +544,7 @@ pub enum Command {
     Stats(StatsCommand),
 
     /// Query command history database
-    #[command(name = "history")]
+    #[command(name = "history", alias = "telemetry")]
     History {
         #[command(subcommand)]
         action: HistoryAction,
@@ -468,6 +585,43 @@ pub enum Command {
     /// ```
     #[command(name = "mcp-server")]
     McpServer,
+
+    /// Serve command evaluation over HTTP for non-shell agents
+    ///
+    /// Endpoints (JSON body `{"command": "..."}`):
+    /// - `POST /evaluate` returns `{"decision", "rule_id", "reason"}`
+    /// - `POST /explain` returns the `dcg explain --format json` trace
+    ///
+    /// Requires the `http-server` cargo feature.
+    #[cfg(feature = "http-server")]
+    #[command(name = "serve")]
+    Serve(ServeCommand),
+
+    /// Show version, build, and catalog information
+    ///
+    /// With `--json`, prints a stable object for support tickets and fleet
+    /// audits. Plain `dcg --version` is unaffected.
+    #[command(name = "version")]
+    Version {
+        /// Output machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// `dcg serve` command arguments.
+#[cfg(feature = "http-server")]
+#[derive(Args, Debug)]
+pub struct ServeCommand {
+    /// Address to listen on (loopback by default)
+    #[arg(long, value_name = "ADDR", default_value = crate::serve::DEFAULT_HTTP_ADDR)]
+    pub http: String,
+
+    /// Shared secret required in the `X-DCG-Token` header of every request
+    ///
+    /// Mandatory when `--http` is not a loopback address.
+    #[arg(long, env = "DCG_SERVE_TOKEN", hide_env_values = true)]
+    pub token: Option<String>,
 }
 
 /// `dcg hook` command arguments.
@@ -601,9 +755,47 @@ pub struct StatsCommand {
     #[arg(long, short = 'r')]
     pub rules: bool,
 
+    /// Summarize recent activity from the history database
+    ///
+    /// Shows total commands, the outcome breakdown, the top 10 blocked rules,
+    /// and commands per agent type.
+    #[arg(long, conflicts_with = "rules")]
+    pub history: bool,
+
+    /// Time window for `--history` (e.g. `24h`, `7d`, `2w`; defaults to `--days`)
+    #[arg(long, value_name = "DURATION")]
+    pub since: Option<String>,
+
     /// Limit number of rules to display (default: 20)
     #[arg(long, short = 'n', default_value = "20")]
     pub limit: usize,
+
+    /// Push the stats to this Prometheus Pushgateway instead of printing them
+    /// (e.g. `http://pushgw:9091`), for CI jobs that cannot be scraped
+    ///
+    /// Requires the `http-server` cargo feature.
+    #[cfg(feature = "http-server")]
+    #[arg(
+        long = "push-gateway",
+        value_name = "URL",
+        conflicts_with_all = ["rules", "history"]
+    )]
+    pub push_gateway: Option<String>,
+
+    /// `job` label for `--push-gateway`
+    #[cfg(feature = "http-server")]
+    #[arg(
+        long,
+        value_name = "NAME",
+        default_value = "dcg",
+        requires = "push_gateway"
+    )]
+    pub job: String,
+
+    /// `instance` label for `--push-gateway` (omitted when unset)
+    #[cfg(feature = "http-server")]
+    #[arg(long, value_name = "NAME", requires = "push_gateway")]
+    pub instance: Option<String>,
 }
 
 /// Output format for stats command.
@@ -630,6 +822,28 @@ pub enum TestFormat {
     Json,
 }
 
+/// Severity threshold for `--min-severity` previews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MinSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl MinSeverity {
+    /// The pack severity this threshold corresponds to.
+    #[must_use]
+    pub const fn severity(self) -> PackSeverity {
+        match self {
+            Self::Low => PackSeverity::Low,
+            Self::Medium => PackSeverity::Medium,
+            Self::High => PackSeverity::High,
+            Self::Critical => PackSeverity::Critical,
+        }
+    }
+}
+
 /// Output format for packs list command.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
 pub enum PacksFormat {
@@ -642,6 +856,77 @@ pub enum PacksFormat {
     Json,
 }
 
+/// JSON output structure for `dcg version --json`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VersionOutput {
+    /// DCG version (e.g., "0.3.0")
+    pub version: String,
+    /// Git commit the binary was built from, if built from a checkout
+    pub git_sha: Option<String>,
+    /// Build timestamp (RFC 3339), if recorded at build time
+    pub build_date: Option<String>,
+    /// Schema versions of the machine-readable outputs
+    pub schema_versions: VersionSchemaVersions,
+    /// Number of built-in packs
+    pub pack_count: usize,
+    /// Number of destructive rules across all built-in packs
+    pub rule_count: usize,
+}
+
+/// Schema versions reported by `dcg version --json`
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VersionSchemaVersions {
+    /// History/telemetry database schema
+    pub telemetry: u32,
+    /// `dcg scan` report schema
+    pub scan: u32,
+    /// `dcg explain --format json` schema
+    pub explain: u32,
+}
+
+impl VersionOutput {
+    /// Collect version info for this binary.
+    #[must_use]
+    pub fn collect() -> Self {
+        let rule_count = REGISTRY
+            .all_pack_ids()
+            .into_iter()
+            .filter_map(|id| REGISTRY.get(id))
+            .map(|pack| pack.destructive_patterns.len())
+            .sum();
+
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            git_sha: option_env!("VERGEN_GIT_SHA").map(str::to_string),
+            build_date: option_env!("VERGEN_BUILD_TIMESTAMP").map(str::to_string),
+            schema_versions: VersionSchemaVersions {
+                telemetry: crate::history::CURRENT_SCHEMA_VERSION,
+                scan: crate::scan::SCAN_SCHEMA_VERSION,
+                explain: crate::trace::EXPLAIN_JSON_SCHEMA_VERSION,
+            },
+            pack_count: REGISTRY.pack_count(),
+            rule_count,
+        }
+    }
+}
+
+fn print_version_info(json: bool) {
+    let info = VersionOutput::collect();
+    if json {
+        println!("{}", crate::output::to_json_string(&info).unwrap());
+        return;
+    }
+
+    println!("dcg {}", info.version);
+    if let Some(sha) = &info.git_sha {
+        println!("Commit: {sha}");
+    }
+    if let Some(date) = &info.build_date {
+        println!("Built: {date}");
+    }
+    println!("Packs: {} ({} rules)", info.pack_count, info.rule_count);
+}
+
 /// Schema version for TestOutput JSON format
 const TEST_OUTPUT_SCHEMA_VERSION: u32 = 1;
 
@@ -688,6 +973,13 @@ pub struct TestOutput {
     /// Detected agent information
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agent: Option<AgentInfo>,
+    /// Threshold from `--min-severity` (preview only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<String>,
+    /// Decision if only matches at or above `min_severity` were enforced.
+    /// `decision` remains the real, enforced decision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_decision: Option<String>,
 }
 
 /// Allowlist override information in test output
@@ -732,6 +1024,11 @@ pub struct PackInfo {
     pub category: String,
     /// Description
     pub description: String,
+    /// Tags for grouping and filtering (e.g., "aws", "data-loss")
+    pub tags: Vec<String>,
+    /// Documentation link (if available)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docs_url: Option<String>,
     /// Whether the pack is enabled
     pub enabled: bool,
     /// Number of safe patterns
@@ -853,12 +1150,25 @@ pub enum HistoryAction {
         json: bool,
     },
 
-    /// Prune history entries older than the specified age
+    /// Prune history entries by age and/or total size
     #[command(name = "prune")]
     Prune {
         /// Prune entries older than this many days
-        #[arg(long, value_name = "DAYS")]
-        older_than_days: u64,
+        #[arg(
+            long,
+            value_name = "DAYS",
+            conflicts_with = "older_than",
+            required_unless_present_any = ["older_than", "max_rows"]
+        )]
+        older_than_days: Option<u64>,
+
+        /// Prune entries older than this age (e.g. 30d, 12h, 2w)
+        #[arg(long, value_name = "AGE")]
+        older_than: Option<String>,
+
+        /// After pruning by age, keep at most this many of the newest entries
+        #[arg(long, value_name = "ROWS")]
+        max_rows: Option<usize>,
 
         /// Show what would be pruned without deleting
         #[arg(long)]
@@ -869,6 +1179,22 @@ pub enum HistoryAction {
         yes: bool,
     },
 
+    /// Full-text search over logged commands, newest first
+    #[command(name = "search")]
+    Search {
+        /// Text to search for (matched as a phrase, e.g. "rm -rf")
+        #[arg(value_name = "QUERY")]
+        query: String,
+
+        /// Maximum number of entries to show
+        #[arg(long, short = 'n', default_value = "20")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Export command history to a file
     #[command(name = "export")]
     Export {
@@ -880,15 +1206,15 @@ pub enum HistoryAction {
         #[arg(long, short = 'f', value_enum, default_value = "json")]
         format: ExportFormat,
 
-        /// Filter by outcome (allow, deny, warn, bypass)
+        /// Filter by outcome (allow, deny, warn, bypass, allowlisted, timeout)
         #[arg(long, value_name = "OUTCOME")]
         outcome: Option<String>,
 
-        /// Include only commands since this date/time (ISO 8601)
+        /// Include only commands since this date/time (ISO 8601, e.g. 2024-01-01)
         #[arg(long, value_name = "DATETIME")]
         since: Option<String>,
 
-        /// Include only commands until this date/time (ISO 8601)
+        /// Include only commands until this date/time (ISO 8601, e.g. 2024-01-01)
         #[arg(long, value_name = "DATETIME")]
         until: Option<String>,
 
@@ -1152,18 +1478,23 @@ impl CompletionShell {
 pub struct ScanCommand {
     // === File selection modes (mutually exclusive) ===
     /// Scan files staged for commit (git index)
-    #[arg(long, conflicts_with_all = ["paths", "git_diff"])]
+    #[arg(long, conflicts_with_all = ["paths", "paths_from", "git_diff"])]
     staged: bool,
 
-    /// Scan explicit file paths (directories are expanded recursively)
+    /// Scan explicit file paths (directories are expanded recursively; repeatable)
     #[arg(long, conflicts_with_all = ["staged", "git_diff"], num_args = 1..)]
     paths: Option<Vec<std::path::PathBuf>>,
 
+    /// Read paths to scan from a file, one per line ("-" for stdin).
+    /// Blank lines and `#` comments are skipped; combines with --paths
+    #[arg(long = "paths-from", value_name = "FILE", conflicts_with_all = ["staged", "git_diff"])]
+    paths_from: Option<std::path::PathBuf>,
+
     /// Scan files changed in a git diff range (e.g., "HEAD~3..HEAD", "main..feature")
     #[arg(
         long = "git-diff",
         value_name = "REV_RANGE",
-        conflicts_with_all = ["staged", "paths"]
+        conflicts_with_all = ["staged", "paths", "paths_from"]
     )]
     git_diff: Option<String>,
 
@@ -1176,6 +1507,14 @@ pub struct ScanCommand {
     #[arg(long, value_enum)]
     fail_on: Option<crate::scan::ScanFailOn>,
 
+    /// Only let findings from packs with these tags trigger `--fail-on` (comma-separated)
+    #[arg(long = "fail-only-tags", value_name = "TAGS", value_delimiter = ',')]
+    fail_only_tags: Vec<String>,
+
+    /// Exit non-zero when the aggregate risk score (severity × confidence) exceeds N
+    #[arg(long = "fail-on-score", value_name = "N")]
+    fail_on_score: Option<f64>,
+
     // === Safety / performance knobs ===
     /// Maximum file size to scan (bytes); larger files are skipped
     #[arg(
@@ -1197,6 +1536,62 @@ pub struct ScanCommand {
     #[arg(long, value_name = "GLOB")]
     include: Vec<String>,
 
+    /// Don't apply the default excludes (`.git`, `target`, `node_modules`, `.venv`)
+    #[arg(long = "no-default-excludes")]
+    no_default_excludes: bool,
+
+    /// Scan files ignored by `.gitignore` too
+    #[arg(long = "no-gitignore")]
+    no_gitignore: bool,
+
+    /// Files to evaluate in parallel (0 = one per CPU; needs the `rayon`
+    /// feature, otherwise files are scanned one at a time)
+    #[arg(long, short = 'j', value_name = "N", default_value_t = 0)]
+    jobs: usize,
+
+    /// Suppress findings by rule and path, e.g. 'core.filesystem:* in tests/fixtures/**'
+    /// (repeatable; scan-only, never affects the runtime allowlist)
+    #[arg(
+        long = "scan-allow",
+        value_name = "RULE_GLOB in PATH_GLOB",
+        value_parser = crate::scan::ScanAllowEntry::parse
+    )]
+    scan_allow: Vec<crate::scan::ScanAllowEntry>,
+
+    /// Baseline of known findings (a `--format json` report): matching
+    /// findings are counted but not listed and never fail the scan
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<std::path::PathBuf>,
+
+    /// When the run passes `--fail-on`, rewrite the `--baseline` file without
+    /// the findings that no longer occur (new findings are never added)
+    #[arg(
+        long = "update-baseline-on-pass",
+        alias = "baseline-update-on-pass",
+        requires = "baseline"
+    )]
+    update_baseline_on_pass: bool,
+
+    /// Write every current finding to PATH as a baseline for later
+    /// `--baseline` runs (the scan still reports and fails as usual)
+    #[arg(
+        long = "write-baseline",
+        value_name = "PATH",
+        conflicts_with = "update_baseline_on_pass"
+    )]
+    write_baseline: Option<std::path::PathBuf>,
+
+    /// Rewrite offending lines in place for rules that register an auto-fix
+    /// (e.g. `git reset --hard` -> `--soft`) and print a diff. Files not clean
+    /// in git are backed up to `<file>.dcg.bak` first
+    #[arg(long, conflicts_with_all = ["print_extracted", "print_schema", "list_extractors"])]
+    fix: bool,
+
+    /// Attach the owning team/user from this CODEOWNERS file to each finding
+    /// (`owner` in JSON and markdown; last matching pattern wins)
+    #[arg(long, value_name = "PATH")]
+    codeowners: Option<std::path::PathBuf>,
+
     // === Redaction / truncation ===
     /// Redact sensitive content in output
     #[arg(long, value_enum)]
@@ -1213,6 +1608,40 @@ pub struct ScanCommand {
     #[arg(long, value_name = "N", default_value = "10")]
     top: usize,
 
+    /// Print files-processed/total to stderr while scanning (TTY only; off with --quiet)
+    #[arg(long)]
+    progress: bool,
+
+    /// Print every command the extractors produced (file, line, extractor) and exit
+    /// without evaluating; honours `--format json`
+    #[arg(long = "print-extracted")]
+    print_extracted: bool,
+
+    /// Print the JSON Schema for `--format json` reports and exit
+    #[arg(long = "print-schema", conflicts_with_all = ["print_extracted", "output"])]
+    print_schema: bool,
+
+    /// Run only these extractors (comma-separated ids, e.g.
+    /// `shell.script,docker.dockerfile`); default runs all of them
+    #[arg(long, value_name = "IDS", value_delimiter = ',')]
+    extractors: Vec<String>,
+
+    /// List the available extractors and the files they claim, then exit;
+    /// honours `--format json`
+    #[arg(long = "list-extractors", conflicts_with_all = ["print_extracted", "print_schema", "output"])]
+    list_extractors: bool,
+
+    /// Write the formatted report to this file instead of stdout (created
+    /// atomically, parent directories included); the summary line still goes
+    /// to stderr. Requires a non-pretty `--format`
+    #[arg(
+        long,
+        short = 'o',
+        value_name = "PATH",
+        conflicts_with = "print_extracted"
+    )]
+    output: Option<std::path::PathBuf>,
+
     /// Optional action subcommand (pre-commit integration helpers)
     #[command(subcommand)]
     action: Option<ScanAction>,
@@ -1305,6 +1734,9 @@ pub enum ExplainFormat {
     /// Structured JSON output
     #[value(alias = "sarif")]
     Json,
+    /// Graphviz DOT decision graph (pipe into `dot -Tpng`)
+    #[value(alias = "graphviz")]
+    Dot,
 }
 
 /// Allowlist subcommand actions
@@ -1408,6 +1840,48 @@ pub enum AllowlistAction {
     },
 }
 
+/// Arguments for `dcg review`.
+#[derive(Args, Debug)]
+pub struct ReviewCommand {
+    /// Optional non-interactive subcommand.
+    #[command(subcommand)]
+    pub action: Option<ReviewAction>,
+
+    /// Add approvals to the user allowlist instead of the project allowlist
+    #[arg(long, global = true)]
+    pub user: bool,
+
+    /// Output JSON (list only)
+    #[arg(long, global = true)]
+    pub json: bool,
+}
+
+/// Subcommands for the review queue.
+#[derive(Subcommand, Debug, Clone)]
+pub enum ReviewAction {
+    /// List commands awaiting review
+    #[command(name = "list")]
+    List,
+
+    /// Approve a queued command (adds it to the allowlist)
+    #[command(name = "approve")]
+    Approve {
+        /// Review entry id
+        id: String,
+    },
+
+    /// Reject a queued command (it stays blocked)
+    #[command(name = "reject")]
+    Reject {
+        /// Review entry id
+        id: String,
+    },
+
+    /// Remove approved and rejected entries from the queue file
+    #[command(name = "prune")]
+    Prune,
+}
+
 /// Subcommands for managing allow-once entries.
 #[derive(Subcommand, Debug, Clone)]
 pub enum AllowOnceAction {
@@ -1445,6 +1919,25 @@ pub struct AllowOnceRevokeArgs {
     pub target: String,
 }
 
+/// `dcg approve` command arguments.
+#[derive(Args, Debug)]
+pub struct ApproveCommand {
+    /// Exact command to approve (must match the hook input verbatim)
+    pub command: String,
+
+    /// How long the token stays valid if unused (e.g. "15m", "1h", "1d")
+    #[arg(long, value_name = "DURATION", default_value = "1h")]
+    pub expires: String,
+
+    /// Why the command is approved (recorded with the token)
+    #[arg(long)]
+    pub reason: Option<String>,
+
+    /// Output JSON for automation
+    #[arg(long)]
+    pub json: bool,
+}
+
 /// Allow-once command arguments.
 ///
 /// - `dcg allow-once <CODE>` (legacy shorthand for applying an allow-once code)
@@ -1494,6 +1987,42 @@ pub struct AllowOnceCommand {
     pub hash: Option<String>,
 }
 
+/// `dcg policy` subcommands.
+#[derive(Subcommand, Debug)]
+pub enum PolicyAction {
+    /// Export the fully-resolved effective policy for review
+    ///
+    /// Lists enabled packs, every rule's effective decision mode after
+    /// `[policy]` overrides, config overrides, allowlist entries across
+    /// layers, project-scoped packs, and the active agent profile. Output is
+    /// deterministic, so committing it makes policy changes show up in diffs.
+    #[command(name = "export")]
+    Export {
+        /// Output format
+        #[arg(long, short = 'f', value_enum, default_value = "toml")]
+        format: PolicyExportFormat,
+
+        /// Resolve the profile for this agent key (e.g. `claude-code`)
+        /// instead of the detected agent
+        #[arg(long, value_name = "AGENT")]
+        agent: Option<String>,
+
+        /// Write the export to this file instead of stdout (created
+        /// atomically, parent directories included)
+        #[arg(long, short = 'o', value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
+/// Output format for `dcg policy export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PolicyExportFormat {
+    /// TOML document
+    Toml,
+    /// JSON document
+    Json,
+}
+
 /// Output format for allowlist list command
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum AllowlistOutputFormat {
@@ -1654,6 +2183,10 @@ fn maybe_show_update_notice(cli: &Cli, config: &Config, verbosity: Verbosity) {
         // Skip update notices for update/hook/completion/server flows.
         return;
     }
+    #[cfg(feature = "http-server")]
+    if matches!(cli.command, Some(Command::Serve(_))) {
+        return;
+    }
 
     let stderr_is_tty = std::io::stderr().is_terminal();
     if stderr_is_tty {
@@ -1701,7 +2234,11 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
         Some(Command::Completions { shell }) => {
             write_completions(shell)?;
         }
-        Some(Command::ListPacks { enabled, format }) => {
+        Some(Command::ListPacks {
+            enabled,
+            tag,
+            format,
+        }) => {
             // Robot mode forces JSON output
             let robot_mode = cli.robot || std::env::var("DCG_ROBOT").is_ok();
             let effective_format = if robot_mode {
@@ -1713,6 +2250,7 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
             list_packs(
                 &config,
                 enabled,
+                tag.as_deref(),
                 verbosity.is_verbose(),
                 effective_format,
                 verbosity.quiet,
@@ -1723,9 +2261,14 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
         }
         Some(Command::TestCommand {
             command,
+            stdin,
+            exit_code,
             config: config_path,
             with_packs,
+            only_pack,
+            no_allowlist,
             explain,
+            min_severity,
             format,
             no_color,
             heredoc_scan,
@@ -1747,6 +2290,48 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
                 config.clone()
             };
 
+            if let Some(packs) = &only_pack {
+                let unknown: Vec<&str> = packs
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|id| !is_valid_pack_id(id))
+                    .collect();
+                if !unknown.is_empty() {
+                    return Err(format!(
+                        "--only-pack: unknown pack ID(s): {} (see `dcg packs`)",
+                        unknown.join(", ")
+                    )
+                    .into());
+                }
+            }
+
+            if exit_code && !stdin {
+                return Err("--exit-code requires --stdin (a single `dcg test` already \
+                            exits non-zero when the command is denied)"
+                    .into());
+            }
+            if stdin {
+                let any_denied = test_commands_from_stdin(
+                    &effective_config,
+                    with_packs,
+                    PackIsolation {
+                        only_packs: only_pack,
+                        no_allowlist,
+                    },
+                    effective_format,
+                    heredoc_scan,
+                    no_heredoc_scan,
+                    heredoc_timeout_ms,
+                    heredoc_languages,
+                )?;
+                if exit_code && any_denied {
+                    std::process::exit(EXIT_DENIED);
+                }
+                return Ok(());
+            }
+            // clap requires the positional unless --stdin is given.
+            let command = command.unwrap_or_default();
+
             if explain {
                 // Delegate to explain handler for detailed trace output
                 // Convert TestFormat to ExplainFormat for explain mode
@@ -1754,12 +2339,27 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
                     TestFormat::Pretty => ExplainFormat::Pretty,
                     TestFormat::Json => ExplainFormat::Json,
                 };
-                handle_explain(&effective_config, &command, explain_format, with_packs);
+                let explain_context = ExplainContext {
+                    min_severity: min_severity.map(MinSeverity::severity),
+                    ..ExplainContext::default()
+                };
+                handle_explain_in_context(
+                    &effective_config,
+                    &command,
+                    explain_format,
+                    with_packs,
+                    &explain_context,
+                );
             } else {
                 let was_blocked = test_command(
                     &effective_config,
                     &command,
                     with_packs,
+                    PackIsolation {
+                        only_packs: only_pack,
+                        no_allowlist,
+                    },
+                    min_severity.map(MinSeverity::severity),
                     effective_format,
                     verbosity,
                     no_color || robot_mode, // Robot mode also implies no color
@@ -1783,6 +2383,9 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
                 show_config(&config);
             }
         }
+        Some(Command::Policy { action }) => {
+            handle_policy_command(&config, action)?;
+        }
         Some(Command::Allowlist { action }) => {
             handle_allowlist_command(action)?;
         }
@@ -1841,8 +2444,14 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
         Some(Command::AllowOnce(cmd)) => {
             handle_allow_once_command(&config, &cmd)?;
         }
+        Some(Command::Approve(cmd)) => {
+            handle_approve_command(&config, &cmd)?;
+        }
+        Some(Command::Review(cmd)) => {
+            handle_review_command(&config, &cmd)?;
+        }
         Some(Command::Scan(scan)) => {
-            handle_scan_command(&config, scan, verbosity)?;
+            handle_scan_command(&config, *scan, verbosity)?;
         }
         Some(Command::Simulate(sim)) => {
             handle_simulate_command(sim, &config, verbosity)?;
@@ -1851,7 +2460,22 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
             command,
             format,
             with_packs,
+            context,
+            branch,
+            min_severity,
+            all_matches,
+            output,
+            print_schema,
+            compare,
         }) => {
+            if print_schema {
+                println!(
+                    "{}",
+                    crate::output::to_json_string(&crate::trace::explain_json_schema())?
+                );
+                return Ok(());
+            }
+            let command = command.unwrap_or_default();
             // Robot mode forces JSON output
             let robot_mode = cli.robot || std::env::var("DCG_ROBOT").is_ok();
             let effective_format = if robot_mode {
@@ -1860,8 +2484,46 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
                 format
             };
 
-            if !verbosity.quiet {
-                handle_explain(&config, &command, effective_format, with_packs);
+            // --context reloads config so the target repo's .dcg.toml applies.
+            let effective_config = match context.as_deref() {
+                Some(dir) if !dir.is_dir() => {
+                    return Err(format!("--context: '{}' is not a directory", dir.display()).into());
+                }
+                Some(dir) => Config::load_for_dir(Some(dir)),
+                None => config,
+            };
+            let explain_context = ExplainContext {
+                dir: context,
+                branch,
+                min_severity: min_severity.map(MinSeverity::severity),
+                all_matches,
+            };
+
+            if let Some(commands) = compare {
+                let rendered = explain_compare(
+                    &effective_config,
+                    [&commands[0], &commands[1]],
+                    effective_format,
+                    with_packs,
+                    &explain_context,
+                )?;
+                if let Some(path) = output {
+                    write_output_file(&path, &rendered)?;
+                } else if !verbosity.quiet {
+                    print!("{rendered}");
+                }
+            } else if let Some(path) = output {
+                let trace =
+                    explain_trace(&effective_config, &command, with_packs, &explain_context);
+                write_output_file(&path, &render_explain_trace(&trace, effective_format))?;
+            } else if !verbosity.quiet {
+                handle_explain_in_context(
+                    &effective_config,
+                    &command,
+                    effective_format,
+                    with_packs,
+                    &explain_context,
+                );
             }
         }
         Some(Command::Corpus(corpus)) => {
@@ -1882,6 +2544,15 @@ pub fn run_command(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
         Some(Command::McpServer) => {
             crate::mcp::run_mcp_server()?;
         }
+        #[cfg(feature = "http-server")]
+        Some(Command::Serve(cmd)) => {
+            let server = crate::serve::HttpServer::bind(&cmd.http, config, cmd.token)?;
+            eprintln!("dcg serve: listening on http://{}", server.local_addr()?);
+            server.run();
+        }
+        Some(Command::Version { json }) => {
+            print_version_info(json);
+        }
         None => {
             // No subcommand - run in hook mode (default behavior)
             // This is handled by main.rs
@@ -1934,7 +2605,8 @@ fn run_hook_command(config: &Config, cmd: &HookCommand) -> Result<(), Box<dyn st
     let heredoc_settings = config.heredoc_settings();
     let enabled_packs = config.enabled_pack_ids();
     let enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &config.packs.pack_order);
     let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
 
     // TODO: External pack loading is not yet implemented.
@@ -2195,6 +2867,7 @@ fn evaluate_batch_line(
 fn list_packs(
     config: &Config,
     enabled_only: bool,
+    tag: Option<&str>,
     verbose: bool,
     format: PacksFormat,
     quiet: bool,
@@ -2204,7 +2877,10 @@ fn list_packs(
     }
 
     let enabled_packs = config.enabled_pack_ids();
-    let infos = REGISTRY.list_packs(&enabled_packs);
+    let mut infos = REGISTRY.list_packs(&enabled_packs);
+    if let Some(tag) = tag {
+        infos.retain(|info| info.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)));
+    }
 
     // Build pack list (filtered if enabled_only)
     let pack_list: Vec<PackInfo> = infos
@@ -2217,6 +2893,8 @@ fn list_packs(
                 name: info.name.to_string(),
                 category,
                 description: info.description.to_string(),
+                tags: info.tags.iter().map(ToString::to_string).collect(),
+                docs_url: info.docs_url.map(ToString::to_string),
                 enabled: info.enabled,
                 safe_pattern_count: info.safe_pattern_count,
                 destructive_pattern_count: info.destructive_pattern_count,
@@ -2232,14 +2910,14 @@ fn list_packs(
             enabled_count,
             total_count: infos.len(),
         };
-        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        println!("{}", crate::output::to_json_string(&output).unwrap());
         return;
     }
 
     // Rich output when feature enabled
     #[cfg(feature = "rich-output")]
     {
-        list_packs_rich(config, enabled_only, verbose);
+        list_packs_rich(&infos, enabled_only, verbose);
     }
 
     // Pretty output (default, non-rich fallback)
@@ -2273,6 +2951,9 @@ fn list_packs(
                         info.safe_pattern_count,
                         info.destructive_pattern_count
                     );
+                    if !info.tags.is_empty() {
+                        println!("        tags: {}", info.tags.join(", "));
+                    }
                 } else {
                     println!("    {} {} - {}", status, info.id, info.name);
                 }
@@ -2288,12 +2969,10 @@ fn list_packs(
 
 /// Rich terminal packs output using DcgConsole and markup.
 #[cfg(feature = "rich-output")]
-fn list_packs_rich(config: &Config, enabled_only: bool, verbose: bool) {
+fn list_packs_rich(infos: &[crate::packs::PackInfo], enabled_only: bool, verbose: bool) {
     use crate::output::console::console;
 
     let con = console();
-    let enabled_packs = config.enabled_pack_ids();
-    let infos = REGISTRY.list_packs(&enabled_packs);
 
     // Header
     con.rule(Some("[bold cyan] Available Packs [/]"));
@@ -2302,7 +2981,7 @@ fn list_packs_rich(config: &Config, enabled_only: bool, verbose: bool) {
     // Group by category
     let mut by_category: std::collections::BTreeMap<&str, Vec<_>> =
         std::collections::BTreeMap::new();
-    for info in &infos {
+    for info in infos {
         let category = info.id.split('.').next().unwrap_or(&info.id);
         by_category.entry(category).or_default().push(info);
     }
@@ -2328,6 +3007,9 @@ fn list_packs_rich(config: &Config, enabled_only: bool, verbose: bool) {
                     safe = info.safe_pattern_count,
                     destr = info.destructive_pattern_count
                 ));
+                if !info.tags.is_empty() {
+                    con.print(&format!("      [dim]tags: {}[/]", info.tags.join(", ")));
+                }
             } else {
                 con.print(&format!(
                     "  [{color}]{status}[/] [bold]{id}[/] - {name}",
@@ -2353,6 +3035,12 @@ fn pack_info(pack_id: &str, show_patterns: bool) -> Result<(), Box<dyn std::erro
     println!("ID: {}", pack.id);
     println!("Description: {}", pack.description);
     println!("Keywords: {}", pack.keywords.join(", "));
+    if !pack.tags.is_empty() {
+        println!("Tags: {}", pack.tags.join(", "));
+    }
+    if let Some(url) = pack.docs_url {
+        println!("Docs: {url}");
+    }
     println!();
     println!("Patterns:");
     println!("  Safe patterns: {}", pack.safe_patterns.len());
@@ -2731,7 +3419,7 @@ fn output_pack_validation(
 
     match format {
         PackValidateFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(result)?);
+            println!("{}", crate::output::to_json_string(result)?);
         }
         PackValidateFormat::Pretty => {
             println!("{}", "Pack Validation Report".bold().cyan());
@@ -3000,9 +3688,15 @@ fn resolve_mode_for_cli(
     let pack = info.pack_id.as_deref();
     let pattern = info.pattern_name.as_deref();
 
+    let branch_policy = crate::evaluator::resolve_branch_policy(config, None);
     let mut mode = match info.source {
-        MatchSource::Pack | MatchSource::HeredocAst => {
-            config.policy().resolve_mode(pack, pattern, info.severity)
+        MatchSource::Pack | MatchSource::HeredocAst | MatchSource::Heuristic => {
+            config.policy().resolve_mode_for_branch(
+                pack,
+                pattern,
+                info.severity,
+                branch_policy.as_ref().map(|policy| policy.mode),
+            )
         }
         MatchSource::ConfigOverride | MatchSource::LegacyPattern => DecisionMode::Deny,
     };
@@ -3035,40 +3729,32 @@ fn resolve_mode_for_cli(
     Some(mode)
 }
 
-/// Test a command against the configured packs using the shared evaluator.
-///
-/// This ensures parity with hook mode by using the same evaluation logic:
-/// 1. Config allow overrides
-/// 2. Config block overrides
-/// 3. Quick rejection (keyword filtering)
-/// 4. Command normalization
-/// 5. Pack pattern matching
-#[allow(clippy::needless_pass_by_value)] // Value is consumed from CLI args
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
-fn test_command(
-    config: &Config,
-    command: &str,
+/// `dcg test` flags that evaluate packs in isolation from ambient config.
+#[derive(Debug, Default)]
+struct PackIsolation {
+    /// `--only-pack`: evaluate exactly these packs, with no config overrides,
+    /// external packs, or allowlists.
+    only_packs: Option<Vec<String>>,
+    /// `--no-allowlist`: skip allowlist overrides.
+    no_allowlist: bool,
+}
+
+impl PackIsolation {
+    /// Whether allowlists (and the config-driven explain trace) are bypassed.
+    const fn is_active(&self) -> bool {
+        self.only_packs.is_some() || self.no_allowlist
+    }
+}
+
+/// Apply `dcg test` CLI overrides (extra packs, heredoc flags) to the config.
+fn test_effective_config(
+    config: &Config,
     extra_packs: Option<Vec<String>>,
-    format: TestFormat,
-    verbosity: Verbosity,
-    no_color: bool,
-    robot_mode: bool,
     heredoc_scan: bool,
     no_heredoc_scan: bool,
     heredoc_timeout_ms: Option<u64>,
     heredoc_languages: Option<Vec<String>>,
-) -> bool {
-    use std::time::Instant;
-
-    if verbosity.quiet {
-        return false; // Not blocked in quiet mode
-    }
-
-    if verbosity.is_trace() && format == TestFormat::Pretty {
-        handle_explain(config, command, ExplainFormat::Pretty, extra_packs);
-        return false; // Explain mode doesn't track blocked status
-    }
-
+) -> Config {
     // Build effective config with extra packs if specified
     let mut effective_config = extra_packs.map_or_else(
         || config.clone(),
@@ -3092,30 +3778,281 @@ fn test_command(
     if let Some(langs) = heredoc_languages {
         effective_config.heredoc.languages = Some(langs);
     }
+    effective_config
+}
 
-    // Get enabled packs and collect keywords for quick rejection
-    let mut enabled_packs = effective_config.enabled_pack_ids();
-    let mut enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
-    let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
-    let heredoc_settings = effective_config.heredoc_settings();
+/// Evaluator state for `dcg test`, built once and reused across commands
+/// (`--stdin` evaluates many lines against the same context).
+struct TestEvalContext {
+    enabled_packs: std::collections::HashSet<String>,
+    enabled_keywords: Vec<&'static str>,
+    ordered_packs: Vec<String>,
+    keyword_index: Option<crate::packs::EnabledKeywordIndex>,
+    compiled_overrides: crate::config::CompiledOverrides,
+    allowlists: crate::LayeredAllowlist,
+    heredoc_settings: crate::config::HeredocSettings,
+    external_store: &'static crate::packs::ExternalPackStore,
+}
 
-    // Compile overrides once (not per-command)
-    let compiled_overrides = effective_config.overrides.compile();
+impl TestEvalContext {
+    fn new(effective_config: &Config, isolation: &PackIsolation) -> Self {
+        // Get enabled packs and collect keywords for quick rejection
+        crate::packs::heuristic::destructive_verbs::configure_verbs(
+            effective_config.heuristics.destructive_verbs.as_deref(),
+        );
+        let mut enabled_packs = isolation.only_packs.as_ref().map_or_else(
+            || effective_config.enabled_pack_ids(),
+            |packs| packs.iter().cloned().collect(),
+        );
+        let mut enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
+        let ordered_packs = REGISTRY
+            .expand_enabled_ordered_with(&enabled_packs, &effective_config.packs.pack_order);
+        let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
+        let heredoc_settings = effective_config.heredoc_settings();
+
+        // Compile overrides once (not per-command)
+        let compiled_overrides = if isolation.only_packs.is_some() {
+            crate::config::CompiledOverrides::default()
+        } else {
+            effective_config.overrides.compile()
+        };
+
+        // Load allowlists (project/user/system) for parity with hook mode.
+        // This is a small file read and only affects decisions when a rule matches.
+        let allowlists = if isolation.is_active() {
+            crate::LayeredAllowlist::default()
+        } else {
+            load_default_allowlists()
+        };
+
+        // Load external packs from custom_paths (glob + tilde expansion).
+        let external_paths = if isolation.only_packs.is_some() {
+            Vec::new()
+        } else {
+            effective_config.packs.expand_custom_paths()
+        };
+        let external_store = load_external_packs(&external_paths);
+
+        // Auto-enable external packs and merge their keywords.
+        for id in external_store.pack_ids() {
+            enabled_packs.insert(id.clone());
+        }
+        enabled_keywords.extend(external_store.keywords().iter().copied());
+
+        Self {
+            enabled_packs,
+            enabled_keywords,
+            ordered_packs,
+            keyword_index,
+            compiled_overrides,
+            allowlists,
+            heredoc_settings,
+            external_store,
+        }
+    }
+
+    fn evaluate(&self, command: &str) -> EvaluationResult {
+        let mut result = evaluate_command_with_pack_order_deadline_at_path(
+            command,
+            &self.enabled_keywords,
+            &self.ordered_packs,
+            self.keyword_index.as_ref(),
+            &self.compiled_overrides,
+            &self.allowlists,
+            &self.heredoc_settings,
+            None, // allow_once_audit
+            None, // project_path
+            None, // deadline
+        );
+
+        // Check external packs if built-in evaluation allowed the command.
+        if result.decision != EvaluationDecision::Deny && !self.external_store.is_empty() {
+            let normalized = crate::normalize::normalize_command(command);
+            let cmd_for_match = crate::sanitize_for_pattern_matching(&normalized);
+
+            if let Some(external_result) = self
+                .external_store
+                .check_command_with_details(&cmd_for_match, &self.enabled_packs)
+            {
+                if external_result.blocked {
+                    result.decision = EvaluationDecision::Deny;
+                    result.pattern_info = Some(crate::evaluator::PatternMatch {
+                        pack_id: external_result.pack_id,
+                        pattern_name: external_result.pattern_name,
+                        severity: external_result.severity,
+                        reason: external_result.reason.unwrap_or_default(),
+                        source: MatchSource::Pack,
+                        matched_span: None,
+                        matched_text_preview: None,
+                        explanation: external_result.explanation,
+                        suggestions: &[],
+                    });
+                    result.effective_mode = external_result.decision_mode;
+                }
+            }
+        }
+        result
+    }
+
+    /// Decision if only matches at or above `min_severity` were enforced.
+    fn decision_at_min_severity(
+        &self,
+        command: &str,
+        result: &EvaluationResult,
+        min_severity: PackSeverity,
+    ) -> EvaluationDecision {
+        let hits = if result.is_denied() && result.is_below_severity(min_severity) {
+            crate::evaluator::collect_all_pattern_hits(
+                command,
+                &self.enabled_keywords,
+                &self.ordered_packs,
+            )
+        } else {
+            Vec::new()
+        };
+        result.decision_at_min_severity(min_severity, &hits)
+    }
+}
+
+/// One line of `dcg test --stdin --format json` output.
+#[derive(Debug, Clone, serde::Serialize)]
+struct TestStdinOutput<'a> {
+    /// 1-based line number in the input
+    line: usize,
+    command: &'a str,
+    /// "allow" or "deny"
+    decision: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+}
+
+/// `dcg test --stdin`: evaluate one command per stdin line against a single
+/// warm evaluator context, printing `ALLOW` or `DENY <rule_id>` per line.
+///
+/// Returns whether any line was denied.
+#[allow(clippy::too_many_arguments)]
+fn test_commands_from_stdin(
+    config: &Config,
+    extra_packs: Option<Vec<String>>,
+    isolation: PackIsolation,
+    format: TestFormat,
+    heredoc_scan: bool,
+    no_heredoc_scan: bool,
+    heredoc_timeout_ms: Option<u64>,
+    heredoc_languages: Option<Vec<String>>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    use std::io::{BufRead, Write};
+
+    let effective_config = test_effective_config(
+        config,
+        extra_packs,
+        heredoc_scan,
+        no_heredoc_scan,
+        heredoc_timeout_ms,
+        heredoc_languages,
+    );
+    let context = TestEvalContext::new(&effective_config, &isolation);
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    let mut any_denied = false;
+    for (index, line) in std::io::stdin().lock().lines().enumerate() {
+        let line = line?;
+        let command = line.trim();
+        if command.is_empty() || command.starts_with('#') {
+            continue;
+        }
+
+        let result = context.evaluate(command);
+        let denied = result.decision == EvaluationDecision::Deny;
+        any_denied |= denied;
+        let rule_id = result
+            .pattern_info
+            .as_ref()
+            .filter(|_| denied)
+            .and_then(|info| {
+                let pack = info.pack_id.as_deref()?;
+                let name = info.pattern_name.as_deref()?;
+                Some(format!("{pack}:{name}"))
+            });
+
+        match format {
+            TestFormat::Json => {
+                let output = TestStdinOutput {
+                    line: index + 1,
+                    command,
+                    decision: if denied { "deny" } else { "allow" },
+                    rule_id,
+                };
+                writeln!(out, "{}", serde_json::to_string(&output)?)?;
+            }
+            TestFormat::Pretty => match (denied, rule_id) {
+                (false, _) => writeln!(out, "ALLOW")?,
+                (true, Some(rule_id)) => writeln!(out, "DENY {rule_id}")?,
+                (true, None) => writeln!(out, "DENY")?,
+            },
+        }
+    }
+    out.flush()?;
+    Ok(any_denied)
+}
 
-    // Load allowlists (project/user/system) for parity with hook mode.
-    // This is a small file read and only affects decisions when a rule matches.
-    let allowlists = load_default_allowlists();
+/// Test a command against the configured packs using the shared evaluator.
+///
+/// This ensures parity with hook mode by using the same evaluation logic:
+/// 1. Config allow overrides
+/// 2. Config block overrides
+/// 3. Quick rejection (keyword filtering)
+/// 4. Command normalization
+/// 5. Pack pattern matching
+#[allow(clippy::needless_pass_by_value)] // Value is consumed from CLI args
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn test_command(
+    config: &Config,
+    command: &str,
+    extra_packs: Option<Vec<String>>,
+    isolation: PackIsolation,
+    min_severity: Option<PackSeverity>,
+    format: TestFormat,
+    verbosity: Verbosity,
+    no_color: bool,
+    robot_mode: bool,
+    heredoc_scan: bool,
+    no_heredoc_scan: bool,
+    heredoc_timeout_ms: Option<u64>,
+    heredoc_languages: Option<Vec<String>>,
+) -> bool {
+    use std::time::Instant;
 
-    // Load external packs from custom_paths (glob + tilde expansion).
-    let external_paths = effective_config.packs.expand_custom_paths();
-    let external_store = load_external_packs(&external_paths);
+    if verbosity.quiet {
+        return false; // Not blocked in quiet mode
+    }
 
-    // Auto-enable external packs and merge their keywords.
-    for id in external_store.pack_ids() {
-        enabled_packs.insert(id.clone());
+    // The explain trace uses the configured packs, so skip it when isolating.
+    if verbosity.is_trace() && format == TestFormat::Pretty && !isolation.is_active() {
+        let explain_context = ExplainContext {
+            min_severity,
+            ..ExplainContext::default()
+        };
+        handle_explain_in_context(
+            config,
+            command,
+            ExplainFormat::Pretty,
+            extra_packs,
+            &explain_context,
+        );
+        return false; // Explain mode doesn't track blocked status
     }
-    enabled_keywords.extend(external_store.keywords().iter().copied());
+
+    let effective_config = test_effective_config(
+        config,
+        extra_packs,
+        heredoc_scan,
+        no_heredoc_scan,
+        heredoc_timeout_ms,
+        heredoc_languages,
+    );
+    let context = TestEvalContext::new(&effective_config, &isolation);
 
     // Detect the current AI coding agent for agent-specific profiles
     let detection = detect_agent_with_details();
@@ -3133,50 +4070,13 @@ fn test_command(
 
     // Use shared evaluator for consistent behavior with hook mode
     let start = Instant::now();
-    let mut result = evaluate_command_with_pack_order_deadline_at_path(
-        command,
-        &enabled_keywords,
-        &ordered_packs,
-        keyword_index.as_ref(),
-        &compiled_overrides,
-        &allowlists,
-        &heredoc_settings,
-        None, // allow_once_audit
-        None, // project_path
-        None, // deadline
-    );
-
-    // Check external packs if built-in evaluation allowed the command.
-    if result.decision != EvaluationDecision::Deny && !external_store.is_empty() {
-        let normalized = crate::normalize::normalize_command(command);
-        let cmd_for_match = crate::sanitize_for_pattern_matching(&normalized);
-
-        if let Some(external_result) =
-            external_store.check_command_with_details(&cmd_for_match, &enabled_packs)
-        {
-            if external_result.blocked {
-                result.decision = EvaluationDecision::Deny;
-                result.pattern_info = Some(crate::evaluator::PatternMatch {
-                    pack_id: external_result.pack_id,
-                    pattern_name: external_result.pattern_name,
-                    severity: external_result.severity,
-                    reason: external_result.reason.unwrap_or_default(),
-                    source: MatchSource::Pack,
-                    matched_span: None,
-                    matched_text_preview: None,
-                    explanation: external_result.explanation,
-                    suggestions: &[],
-                });
-                result.effective_mode = external_result.decision_mode;
-            }
-        }
-    }
+    let result = context.evaluate(command);
 
     let elapsed = start.elapsed();
 
     // Handle JSON output
     if format == TestFormat::Json {
-        let output = match result.decision {
+        let mut output = match result.decision {
             EvaluationDecision::Allow => {
                 let allowlist =
                     result
@@ -3202,6 +4102,8 @@ fn test_command(
                     severity: None,
                     allowlist,
                     agent: Some(agent_info.clone()),
+                    min_severity: None,
+                    threshold_decision: None,
                 }
             }
             EvaluationDecision::Deny => {
@@ -3222,6 +4124,7 @@ fn test_command(
                             MatchSource::LegacyPattern => "legacy_pattern",
                             MatchSource::Pack => "pack",
                             MatchSource::HeredocAst => "heredoc_ast",
+                            MatchSource::Heuristic => "heuristic",
                         };
                         let rule_id = info
                             .pack_id
@@ -3261,10 +4164,22 @@ fn test_command(
                     severity,
                     allowlist: None,
                     agent: Some(agent_info.clone()),
+                    min_severity: None,
+                    threshold_decision: None,
                 }
             }
         };
-        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        if let Some(min) = min_severity {
+            output.min_severity = Some(min.label().to_string());
+            output.threshold_decision = Some(
+                match context.decision_at_min_severity(command, &result, min) {
+                    EvaluationDecision::Allow => "allow",
+                    EvaluationDecision::Deny => "deny",
+                }
+                .to_string(),
+            );
+        }
+        println!("{}", crate::output::to_json_string(&output).unwrap());
         return result.decision == EvaluationDecision::Deny;
     }
 
@@ -3340,6 +4255,7 @@ fn test_command(
                     MatchSource::LegacyPattern => "legacy pattern",
                     MatchSource::Pack => "pack",
                     MatchSource::HeredocAst => "heredoc/inline script (AST)",
+                    MatchSource::Heuristic => "heuristic (unknown CLI)",
                 };
                 println!("Source: {source}");
 
@@ -3472,6 +4388,28 @@ fn test_command(
         }
     }
 
+    if let Some(min) = min_severity {
+        let severity = result.pattern_info.as_ref().and_then(|p| p.severity);
+        let threshold_decision = context.decision_at_min_severity(command, &result, min);
+        if result.is_denied() && threshold_decision == EvaluationDecision::Allow {
+            println!(
+                "At --min-severity {}: ALLOWED (match is {}; actual result above is unchanged)",
+                min.label(),
+                severity.map_or("unknown", |s| s.label())
+            );
+        } else if result.is_below_severity(min) && result.is_denied() {
+            println!(
+                "At --min-severity {}: unchanged (a later match meets threshold)",
+                min.label()
+            );
+        } else if result.is_denied() {
+            println!(
+                "At --min-severity {}: unchanged (match meets threshold)",
+                min.label()
+            );
+        }
+    }
+
     if verbosity.is_verbose() {
         println!("Elapsed: {:.2}ms", elapsed.as_secs_f64() * 1000.0);
         println!("Agent: {}", detection.agent);
@@ -3555,19 +4493,9 @@ fn show_config(config: &Config) {
     println!("Current configuration:");
     println!();
     println!("Config sources (lowest → highest priority):");
-    let user_cfg = config_path();
-    let system_cfg = std::path::PathBuf::from("/etc/dcg").join("config.toml");
-    if system_cfg.exists() {
-        println!("  - system: {}", system_cfg.display());
-    }
-    if user_cfg.exists() {
-        println!("  - user: {}", user_cfg.display());
-    }
-    if let Some(repo_root) = find_repo_root_from_cwd() {
-        let project_cfg = repo_root.join(".dcg.toml");
-        if project_cfg.exists() {
-            println!("  - project: {}", project_cfg.display());
-        }
+    for (label, path) in config_source_paths() {
+        print_config_includes(&path);
+        println!("  - {label}: {}", path.display());
     }
     if let Ok(value) = std::env::var(crate::config::ENV_CONFIG_PATH) {
         if let Some(path) = crate::config::resolve_config_path_value(
@@ -3575,6 +4503,7 @@ fn show_config(config: &Config) {
             std::env::current_dir().ok().as_deref(),
         ) {
             if path.exists() {
+                print_config_includes(&path);
                 println!("  - DCG_CONFIG: {}", path.display());
             } else {
                 println!("  - DCG_CONFIG: {} (missing)", path.display());
@@ -3613,22 +4542,11 @@ fn show_config(config: &Config) {
     );
     println!("  Fail-open on timeout: {}", heredoc.fallback_on_timeout);
 
-    let lang_label = |lang: crate::heredoc::ScriptLanguage| -> &'static str {
-        match lang {
-            crate::heredoc::ScriptLanguage::Bash => "bash",
-            crate::heredoc::ScriptLanguage::Go => "go",
-            crate::heredoc::ScriptLanguage::Php => "php",
-            crate::heredoc::ScriptLanguage::Python => "python",
-            crate::heredoc::ScriptLanguage::Ruby => "ruby",
-            crate::heredoc::ScriptLanguage::Perl => "perl",
-            crate::heredoc::ScriptLanguage::JavaScript => "javascript",
-            crate::heredoc::ScriptLanguage::TypeScript => "typescript",
-            crate::heredoc::ScriptLanguage::Unknown => "unknown",
-        }
-    };
-
     if let Some(langs) = &heredoc.allowed_languages {
-        let langs = langs.iter().copied().map(lang_label).collect::<Vec<_>>();
+        let langs = langs
+            .iter()
+            .map(crate::heredoc::ScriptLanguage::label)
+            .collect::<Vec<_>>();
         println!("  Languages: {}", langs.join(","));
     } else {
         println!("  Languages: all");
@@ -3849,24 +4767,33 @@ If you want to keep it, you can still add dcg scanning by adding this line:\n\
 struct ResolvedScanSettings {
     format: crate::scan::ScanFormat,
     fail_on: crate::scan::ScanFailOn,
+    fail_only_tags: Vec<String>,
+    fail_on_score: Option<f64>,
     max_file_size: u64,
     max_findings: usize,
     redact: crate::scan::ScanRedactMode,
     truncate: usize,
     include: Vec<String>,
     exclude: Vec<String>,
+    respect_gitignore: bool,
+    scan_allow: Vec<crate::scan::ScanAllowEntry>,
 }
 
 #[derive(Debug, Clone)]
 struct ScanSettingsOverrides {
     format: Option<crate::scan::ScanFormat>,
     fail_on: Option<crate::scan::ScanFailOn>,
+    fail_only_tags: Vec<String>,
+    fail_on_score: Option<f64>,
     max_file_size: Option<u64>,
     max_findings: Option<usize>,
     redact: Option<crate::scan::ScanRedactMode>,
     truncate: Option<usize>,
     include: Vec<String>,
     exclude: Vec<String>,
+    no_default_excludes: bool,
+    no_gitignore: bool,
+    scan_allow: Vec<crate::scan::ScanAllowEntry>,
 }
 
 impl ScanSettingsOverrides {
@@ -3874,13 +4801,18 @@ impl ScanSettingsOverrides {
         let mut resolved = ResolvedScanSettings {
             format: crate::scan::ScanFormat::Pretty,
             fail_on: crate::scan::ScanFailOn::Error,
+            fail_only_tags: Vec::new(),
+            fail_on_score: None,
             max_file_size: 1_048_576,
             max_findings: 100,
             redact: crate::scan::ScanRedactMode::None,
             truncate: 200,
             include: Vec::new(),
             exclude: Vec::new(),
+            respect_gitignore: true,
+            scan_allow: Vec::new(),
         };
+        let mut default_excludes = true;
 
         if let Some(hooks) = hooks {
             if let Some(format) = hooks.scan.format {
@@ -3889,6 +4821,12 @@ impl ScanSettingsOverrides {
             if let Some(fail_on) = hooks.scan.fail_on {
                 resolved.fail_on = fail_on;
             }
+            resolved
+                .fail_only_tags
+                .clone_from(&hooks.scan.fail_only_tags);
+            if let Some(fail_on_score) = hooks.scan.fail_on_score {
+                resolved.fail_on_score = Some(fail_on_score);
+            }
             if let Some(max_file_size) = hooks.scan.max_file_size {
                 resolved.max_file_size = max_file_size;
             }
@@ -3903,6 +4841,13 @@ impl ScanSettingsOverrides {
             }
             resolved.include.clone_from(&hooks.scan.paths.include);
             resolved.exclude.clone_from(&hooks.scan.paths.exclude);
+            if let Some(enabled) = hooks.scan.paths.default_excludes {
+                default_excludes = enabled;
+            }
+            if let Some(enabled) = hooks.scan.paths.respect_gitignore {
+                resolved.respect_gitignore = enabled;
+            }
+            resolved.scan_allow.clone_from(&hooks.scan.allow);
         }
 
         if let Some(format) = self.format {
@@ -3911,6 +4856,12 @@ impl ScanSettingsOverrides {
         if let Some(fail_on) = self.fail_on {
             resolved.fail_on = fail_on;
         }
+        if !self.fail_only_tags.is_empty() {
+            resolved.fail_only_tags = self.fail_only_tags;
+        }
+        if let Some(fail_on_score) = self.fail_on_score {
+            resolved.fail_on_score = Some(fail_on_score);
+        }
         if let Some(max_file_size) = self.max_file_size {
             resolved.max_file_size = max_file_size;
         }
@@ -3929,6 +4880,20 @@ impl ScanSettingsOverrides {
         if !self.exclude.is_empty() {
             resolved.exclude = self.exclude;
         }
+        if !self.scan_allow.is_empty() {
+            resolved.scan_allow = self.scan_allow;
+        }
+        if self.no_gitignore {
+            resolved.respect_gitignore = false;
+        }
+        if default_excludes && !self.no_default_excludes {
+            resolved.exclude.splice(
+                0..0,
+                crate::scan::DEFAULT_SCAN_EXCLUDES
+                    .iter()
+                    .map(ToString::to_string),
+            );
+        }
 
         resolved
     }
@@ -4018,6 +4983,25 @@ fn handle_simulate_command(
     Ok(())
 }
 
+/// Combine `--paths` with the entries of a `--paths-from` list.
+fn merge_scan_path_sources(
+    paths: Option<Vec<std::path::PathBuf>>,
+    paths_from: Option<&std::path::Path>,
+) -> Result<Option<Vec<std::path::PathBuf>>, Box<dyn std::error::Error>> {
+    let Some(list_path) = paths_from else {
+        return Ok(paths);
+    };
+    let content = if list_path == std::path::Path::new("-") {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(list_path)
+            .map_err(|e| format!("--paths-from {}: {e}", list_path.display()))?
+    };
+    let mut merged = paths.unwrap_or_default();
+    merged.extend(crate::scan::parse_path_list(&content));
+    Ok(Some(merged))
+}
+
 fn handle_scan_command(
     config: &Config,
     scan: ScanCommand,
@@ -4026,16 +5010,34 @@ fn handle_scan_command(
     let ScanCommand {
         staged,
         paths,
+        paths_from,
         git_diff,
         format,
         fail_on,
+        fail_only_tags,
+        fail_on_score,
         max_file_size,
         max_findings,
         exclude,
         include,
+        no_default_excludes,
+        no_gitignore,
+        jobs,
+        scan_allow,
+        baseline,
+        update_baseline_on_pass,
+        write_baseline,
+        fix,
+        codeowners,
         redact,
         truncate,
         top,
+        progress,
+        print_extracted,
+        print_schema,
+        extractors,
+        list_extractors,
+        output,
         action,
     } = scan;
     let effective_verbose = verbosity.is_verbose();
@@ -4050,7 +5052,22 @@ fn handle_scan_command(
         Some(ScanAction::UninstallPreCommit) => {
             uninstall_scan_pre_commit_hook()?;
         }
+        None if print_schema => {
+            println!(
+                "{}",
+                crate::output::to_json_string(&crate::scan::scan_report_json_schema())?
+            );
+        }
+        None if list_extractors => {
+            print_scan_extractors(format == Some(crate::scan::ScanFormat::Json))?;
+        }
         None => {
+            let extractors = if extractors.is_empty() {
+                None
+            } else {
+                Some(crate::scan::parse_extractor_selection(&extractors)?)
+            };
+
             let cwd = std::env::current_dir()?;
             let hooks = maybe_load_repo_hooks_toml(&cwd)?;
             if let Some(hooks) = &hooks {
@@ -4059,15 +5076,22 @@ fn handle_scan_command(
                 }
             }
 
+            let paths = merge_scan_path_sources(paths, paths_from.as_deref())?;
+
             let settings = ScanSettingsOverrides {
                 format,
                 fail_on,
+                fail_only_tags,
+                fail_on_score,
                 max_file_size,
                 max_findings,
                 redact,
                 truncate,
                 include,
                 exclude,
+                no_default_excludes,
+                no_gitignore,
+                scan_allow,
             }
             .resolve(hooks.as_ref().map(|h| &h.cfg));
 
@@ -4078,10 +5102,15 @@ fn handle_scan_command(
                 git_diff,
                 settings.format,
                 settings.fail_on,
+                &settings.fail_only_tags,
+                settings.fail_on_score,
                 settings.max_file_size,
                 settings.max_findings,
                 &settings.exclude,
                 &settings.include,
+                settings.respect_gitignore,
+                jobs,
+                settings.scan_allow,
                 settings.redact,
                 settings.truncate,
                 effective_verbose,
@@ -4089,6 +5118,15 @@ fn handle_scan_command(
                 debug,
                 trace,
                 top,
+                progress,
+                print_extracted,
+                extractors,
+                output.as_deref(),
+                baseline.as_deref(),
+                update_baseline_on_pass,
+                write_baseline.as_deref(),
+                fix,
+                codeowners.as_deref(),
             )?;
         }
     }
@@ -4106,10 +5144,15 @@ fn handle_scan(
     git_diff: Option<String>,
     format: crate::scan::ScanFormat,
     fail_on: crate::scan::ScanFailOn,
+    fail_only_tags: &[String],
+    fail_on_score: Option<f64>,
     max_file_size: u64,
     max_findings: usize,
     exclude: &[String],
     include: &[String],
+    respect_gitignore: bool,
+    jobs: usize,
+    scan_allow: Vec<crate::scan::ScanAllowEntry>,
     redact: crate::scan::ScanRedactMode,
     truncate: usize,
     verbose: bool,
@@ -4117,9 +5160,21 @@ fn handle_scan(
     debug: bool,
     trace: bool,
     top: usize,
+    show_progress: bool,
+    print_extracted: bool,
+    extractors: Option<Vec<&'static str>>,
+    output: Option<&std::path::Path>,
+    baseline_path: Option<&std::path::Path>,
+    update_baseline_on_pass: bool,
+    write_baseline_path: Option<&std::path::Path>,
+    fix: bool,
+    codeowners_path: Option<&std::path::Path>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use crate::output::progress::MaybeProgress;
-    use crate::scan::{ScanEvalContext, ScanOptions, scan_paths_with_progress, should_fail};
+    use crate::output::progress::{MaybeProgress, ThrottledProgress};
+    use crate::scan::{
+        ScanEvalContext, ScanOptions, scan_paths_with_progress, should_fail_on_score,
+        should_fail_with_tags, summary_line,
+    };
 
     // Validate file selection mode - at least one must be specified
     let file_sources = [staged, paths.is_some(), git_diff.is_some()]
@@ -4133,10 +5188,17 @@ fn handle_scan(
         eprintln!("Use one of:");
         eprintln!("  --staged         Scan files staged for commit");
         eprintln!("  --paths <paths>  Scan explicit file paths");
+        eprintln!("  --paths-from <f> Scan paths listed in a file (\"-\" for stdin)");
         eprintln!("  --git-diff <rev> Scan files changed in a git diff range");
         std::process::exit(1);
     }
 
+    if output.is_some() && format == crate::scan::ScanFormat::Pretty {
+        return Err(
+            "--output needs a file format: use --format json, sarif, markdown, csv, github-summary, or github-annotations".into(),
+        );
+    }
+
     // Build scan options
     let options = ScanOptions {
         format,
@@ -4145,8 +5207,20 @@ fn handle_scan(
         max_findings,
         redact,
         truncate,
+        scan_allow,
+        extractors,
+        include: include.to_vec(),
+        exclude: exclude.to_vec(),
+        respect_gitignore,
+        jobs,
     };
 
+    // Load the baseline up front so a bad path fails before scanning.
+    let baseline = baseline_path.map(crate::scan::load_baseline).transpose()?;
+    let codeowners = codeowners_path
+        .map(crate::scan::CodeOwners::load)
+        .transpose()?;
+
     // Build evaluation context from config
     let ctx = ScanEvalContext::from_config(config);
 
@@ -4167,43 +5241,59 @@ fn handle_scan(
         }
         if debug {
             eprintln!(
-                "Scan settings: format={format:?}, fail_on={fail_on:?}, max_file_size={max_file_size}, max_findings={max_findings}"
+                "Scan settings: format={format:?}, fail_on={fail_on:?}, fail_only_tags={fail_only_tags:?}, fail_on_score={fail_on_score:?}, max_file_size={max_file_size}, max_findings={max_findings}"
             );
         }
         if trace {
             eprintln!(
-                "Scan filters: include={include:?}, exclude={exclude:?}, truncate={truncate}, redact={redact:?}"
+                "Scan filters: include={include:?}, exclude={exclude:?}, respect_gitignore={respect_gitignore}, truncate={truncate}, redact={redact:?}"
             );
         }
     }
 
-    // Run scan with progress reporting
     let repo_root = find_repo_root_from_cwd();
 
+    if print_extracted {
+        let extraction =
+            crate::scan::extract_paths(&scan_paths_list, &options, &ctx, repo_root.as_deref())?;
+        if format == crate::scan::ScanFormat::Json {
+            println!("{}", crate::output::to_json_string(&extraction)?);
+        } else {
+            print_extracted_commands(&extraction);
+        }
+        return Ok(());
+    }
+
+    // Run scan with progress reporting
+
     // Create progress tracker lazily when we know total file count
     // Use RefCell to allow mutation inside the closure
     use std::cell::RefCell;
     let progress: RefCell<Option<MaybeProgress>> = RefCell::new(None);
+    // `--progress` replaces the bar with a throttled stderr counter.
+    let counter: RefCell<Option<ThrottledProgress>> = RefCell::new(None);
 
     let mut progress_callback = |current: usize, total: usize, file: &str| {
         if current == 0 {
             // First call signals total file count - initialize progress
-            if !quiet {
+            if show_progress {
+                *counter.borrow_mut() = ThrottledProgress::new_if_enabled(total, quiet);
+            } else if !quiet {
                 *progress.borrow_mut() = Some(MaybeProgress::new(total as u64));
             }
+        } else if let Some(ref c) = *counter.borrow() {
+            c.tick();
         } else if let Some(ref p) = *progress.borrow() {
             // Subsequent calls tick the progress bar
             p.tick(file);
         }
     };
 
-    let report = scan_paths_with_progress(
+    let mut report = scan_paths_with_progress(
         &scan_paths_list,
         &options,
         config,
         &ctx,
-        include,
-        exclude,
         repo_root.as_deref(),
         if quiet {
             None
@@ -4216,39 +5306,209 @@ fn handle_scan(
     if let Some(ref p) = *progress.borrow() {
         p.finish_and_clear();
     }
+    if let Some(ref c) = *counter.borrow() {
+        c.finish();
+    }
+
+    // Captured before `--baseline` filtering so known findings stay recorded.
+    let fresh_baseline = write_baseline_path.map(|_| crate::scan::baseline_from_report(&report));
+    let known = baseline
+        .as_ref()
+        .map(|baseline| crate::scan::apply_baseline(&mut report, baseline));
+    if let Some(codeowners) = &codeowners {
+        codeowners.annotate(&mut report);
+    }
+
+    // Exit with appropriate code based on fail-on policy
+    let failed = should_fail_with_tags(&report, fail_on, fail_only_tags)
+        || fail_on_score.is_some_and(|threshold| should_fail_on_score(&report, threshold));
 
     // Output results
+    let rendered = render_scan_report(&report, format, top, truncate)?;
+    if let Some(path) = output {
+        // Pretty output was rejected up front, so there is always text here.
+        write_output_file(path, rendered.as_deref().unwrap_or_default())?;
+    } else if !quiet {
+        match rendered {
+            Some(text) => print!("{text}"),
+            None => print_scan_pretty(&report, verbose, top, failed),
+        }
+    }
+
+    // Grep-able outcome on stderr so stdout stays clean for JSON/SARIF.
     if !quiet {
-        match format {
-            crate::scan::ScanFormat::Pretty => {
-                print_scan_pretty(&report, verbose, top);
+        eprintln!("{}", summary_line(&report.summary, i32::from(failed)));
+    }
+
+    if let (Some(path), Some(fresh)) = (write_baseline_path, &fresh_baseline) {
+        write_output_file(
+            path,
+            &format!("{}\n", crate::output::to_json_string(fresh)?),
+        )?;
+        if !quiet {
+            eprintln!(
+                "Baseline: wrote {} finding(s) to {}",
+                fresh.findings.len(),
+                path.display()
+            );
+        }
+    }
+
+    // Only a passing run may rewrite the baseline; otherwise new findings
+    // could be masked.
+    if let (Some(path), Some(baseline), Some(known)) = (baseline_path, &baseline, known) {
+        let resolved = baseline.findings.len().saturating_sub(known.len());
+        if update_baseline_on_pass && !failed && resolved > 0 {
+            let pruned = crate::scan::pruned_baseline(known, &report);
+            write_output_file(
+                path,
+                &format!("{}\n", crate::output::to_json_string(&pruned)?),
+            )?;
+            if !quiet {
+                eprintln!(
+                    "Baseline: removed {resolved} resolved finding(s) from {}",
+                    path.display()
+                );
             }
-            crate::scan::ScanFormat::Json => {
-                let json = serde_json::to_string_pretty(&report)?;
-                println!("{json}");
+        }
+    }
+
+    // The report and exit code above describe the files as scanned; fixes
+    // only touch lines whose rewrite was verified to clear the rule.
+    if fix {
+        let fixes = crate::scan::plan_fixes(&report.findings, &options, config, &ctx);
+        let written = crate::scan::apply_fixes(&fixes)?;
+        if !quiet {
+            let diff = crate::scan::format_fix_diff(&fixes);
+            if output.is_none() && format == crate::scan::ScanFormat::Pretty {
+                print!("{diff}");
+            } else {
+                eprint!("{diff}");
             }
-            crate::scan::ScanFormat::Markdown => {
-                print_scan_markdown(&report, top, truncate);
+            for file in &written {
+                if let Some(backup) = &file.backup {
+                    eprintln!("Backed up {} to {}", file.path.display(), backup.display());
+                }
             }
-            crate::scan::ScanFormat::Sarif => {
-                let sarif = crate::sarif::SarifReport::from_scan_report(&report);
-                let json = serde_json::to_string_pretty(&sarif)?;
-                println!("{json}");
+            if fixes.is_empty() {
+                eprintln!("Fix: no findings have an auto-fix");
+            } else {
+                eprintln!(
+                    "Fix: applied {} fix(es) in {} file(s); re-run dcg scan to confirm",
+                    fixes.len(),
+                    written.len()
+                );
             }
         }
     }
 
-    // Exit with appropriate code based on fail-on policy
-    if should_fail(&report, fail_on) {
+    if failed {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
-/// Get list of files staged for commit (git index).
-fn get_staged_files() -> Result<Vec<std::path::PathBuf>, Box<dyn std::error::Error>> {
-    let cwd = std::env::current_dir()?;
+/// Render a scan report as text for the machine-readable formats.
+///
+/// Returns `None` for `pretty`, which is printed straight to the terminal.
+fn render_scan_report(
+    report: &crate::scan::ScanReport,
+    format: crate::scan::ScanFormat,
+    top: usize,
+    truncate: usize,
+) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let rendered = match format {
+        crate::scan::ScanFormat::Pretty => return Ok(None),
+        crate::scan::ScanFormat::Json => {
+            format!("{}\n", crate::output::to_json_string(report)?)
+        }
+        crate::scan::ScanFormat::Markdown => format_scan_markdown(report, top, truncate),
+        crate::scan::ScanFormat::Sarif => {
+            let sarif = crate::sarif::SarifReport::from_scan_report(report);
+            format!("{}\n", crate::output::to_json_string(&sarif)?)
+        }
+        crate::scan::ScanFormat::Csv => crate::scan::format_scan_csv(report),
+        crate::scan::ScanFormat::GithubSummary => {
+            crate::scan::format_scan_github_summary(report, top)
+        }
+        crate::scan::ScanFormat::GithubAnnotations => {
+            crate::scan::format_scan_github_annotations(report)
+        }
+    };
+    Ok(Some(rendered))
+}
+
+/// Handle `dcg policy` subcommands.
+fn handle_policy_command(
+    config: &Config,
+    action: PolicyAction,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        PolicyAction::Export {
+            format,
+            agent,
+            output,
+        } => {
+            let agent_key =
+                agent.unwrap_or_else(|| crate::agent::detect_agent().config_key().to_string());
+            let allowlists = crate::allowlist::load_default_allowlists();
+            let export = crate::policy_export::build_policy_export(config, &allowlists, &agent_key);
+            let rendered = match format {
+                PolicyExportFormat::Toml => export.to_toml()?,
+                PolicyExportFormat::Json => {
+                    format!("{}\n", crate::output::to_json_string(&export)?)
+                }
+            };
+            match output {
+                Some(path) => write_output_file(&path, &rendered)?,
+                None => print!("{rendered}"),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write `--output` content atomically, creating parent directories.
+///
+/// Content goes to a temp file in the target directory which is then renamed
+/// over `path`, so CI never picks up a half-written report.
+fn write_output_file(
+    path: &std::path::Path,
+    content: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::Write;
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => std::path::Path::new("."),
+    };
+    std::fs::create_dir_all(parent)?;
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("--output: '{}' is not a file path", path.display()))?;
+    let temp_path = parent.join(format!(
+        ".{}.dcg-{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let written = std::fs::File::create(&temp_path).and_then(|mut file| {
+        file.write_all(content.as_bytes())?;
+        file.sync_all()
+    });
+    if let Err(err) = written.and_then(|()| std::fs::rename(&temp_path, path)) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(format!("failed to write {}: {err}", path.display()).into());
+    }
+
+    Ok(())
+}
+
+/// Get list of files staged for commit (git index).
+fn get_staged_files() -> Result<Vec<std::path::PathBuf>, Box<dyn std::error::Error>> {
+    let cwd = std::env::current_dir()?;
     get_staged_files_at(&cwd)
 }
 
@@ -4365,10 +5625,13 @@ fn parse_git_name_status_z(stdout: &[u8]) -> Vec<std::path::PathBuf> {
 
 /// Print scan report in pretty format.
 #[cfg(not(feature = "rich-output"))]
-fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize) {
-    use crate::output::{ScanResultRow, ScanResultsTable, TableStyle, auto_theme};
+fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize, failed: bool) {
+    use crate::output::{
+        ScanResultRow, ScanResultsTable, TableStyle, auto_theme, severity_badge_ansi,
+    };
     use colored::Colorize;
 
+    let theme = auto_theme();
     if report.findings.is_empty() {
         println!("{}", "No findings.".green());
     } else {
@@ -4385,7 +5648,6 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
             .map(ScanResultRow::from_scan_finding)
             .collect();
 
-        let theme = auto_theme();
         let table = ScanResultsTable::new(rows)
             .with_theme(&theme)
             .with_style(TableStyle::Ascii)
@@ -4436,9 +5698,36 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
     println!("---");
     let considered = report.summary.files_scanned + report.summary.files_skipped;
     println!(
-        "Files: {considered} considered, {} scanned, {} skipped",
-        report.summary.files_scanned, report.summary.files_skipped
+        "Files: {considered} considered, {} scanned, {} skipped{}",
+        report.summary.files_scanned,
+        report.summary.files_skipped,
+        format_scan_skip_breakdown(&report.summary.skipped)
     );
+    if report.summary.files_dcgignored > 0 {
+        println!(
+            "Excluded by {}: {} file(s)",
+            crate::scan::DCGIGNORE_FILE,
+            report.summary.files_dcgignored
+        );
+    }
+    if report.summary.findings_suppressed > 0 {
+        println!(
+            "Suppressed by dcg:ignore: {} finding(s)",
+            report.summary.findings_suppressed
+        );
+    }
+    if report.summary.findings_scan_allowed > 0 {
+        println!(
+            "Suppressed by --scan-allow: {} finding(s)",
+            report.summary.findings_scan_allowed
+        );
+    }
+    if report.summary.findings_baselined > 0 {
+        println!(
+            "Known from --baseline: {} finding(s)",
+            report.summary.findings_baselined
+        );
+    }
     println!("Commands extracted: {}", report.summary.commands_extracted);
     println!(
         "Findings: {} (allow={}, warn={}, deny={})",
@@ -4447,16 +5736,7 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
         report.summary.decisions.warn,
         report.summary.decisions.deny
     );
-    println!(
-        "Severities: error={}, warning={}, info={}",
-        report.summary.severities.error,
-        report.summary.severities.warning,
-        report.summary.severities.info
-    );
-
-    if let Some(elapsed_ms) = report.summary.elapsed_ms {
-        println!("Elapsed: {elapsed_ms} ms");
-    }
+    println!("Risk score: {:.2}", report.summary.risk_score);
 
     if report.summary.max_findings_reached {
         println!(
@@ -4465,6 +5745,23 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
         );
     }
 
+    println!();
+    println!(
+        "{}",
+        scan_severity_badges(&report.summary.severities, |severity| {
+            severity_badge_ansi(&theme, severity)
+        })
+    );
+    let decision = if failed {
+        "FAIL".red().bold()
+    } else {
+        "PASS".green().bold()
+    };
+    println!(
+        "Result: {decision} {}",
+        scan_outcome_details(&report.summary, failed)
+    );
+
     if verbose {
         // Additional verbose info could go here
     }
@@ -4472,11 +5769,12 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
 
 /// Print scan report in pretty format with rich output.
 #[cfg(feature = "rich-output")]
-fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize) {
+fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize, failed: bool) {
     use crate::output::console::console;
-    use crate::output::{ScanResultRow, ScanResultsTable, auto_theme};
+    use crate::output::{ScanResultRow, ScanResultsTable, auto_theme, severity_badge_markup};
 
     let con = console();
+    let theme = auto_theme();
 
     if report.findings.is_empty() {
         con.print("[green]No findings.[/]");
@@ -4496,7 +5794,6 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
             .map(ScanResultRow::from_scan_finding)
             .collect();
 
-        let theme = auto_theme();
         let table = ScanResultsTable::new(rows)
             .with_theme(&theme)
             .with_command_preview();
@@ -4543,9 +5840,36 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
     con.print("[dim]───[/]");
     let considered = report.summary.files_scanned + report.summary.files_skipped;
     con.print(&format!(
-        "[cyan]Files:[/] {considered} considered, {} scanned, {} skipped",
-        report.summary.files_scanned, report.summary.files_skipped
+        "[cyan]Files:[/] {considered} considered, {} scanned, {} skipped{}",
+        report.summary.files_scanned,
+        report.summary.files_skipped,
+        format_scan_skip_breakdown(&report.summary.skipped)
     ));
+    if report.summary.files_dcgignored > 0 {
+        con.print(&format!(
+            "[cyan]Excluded by {}:[/] {} file(s)",
+            crate::scan::DCGIGNORE_FILE,
+            report.summary.files_dcgignored
+        ));
+    }
+    if report.summary.findings_suppressed > 0 {
+        con.print(&format!(
+            "[cyan]Suppressed by dcg:ignore:[/] {} finding(s)",
+            report.summary.findings_suppressed
+        ));
+    }
+    if report.summary.findings_scan_allowed > 0 {
+        con.print(&format!(
+            "[cyan]Suppressed by --scan-allow:[/] {} finding(s)",
+            report.summary.findings_scan_allowed
+        ));
+    }
+    if report.summary.findings_baselined > 0 {
+        con.print(&format!(
+            "[cyan]Known from --baseline:[/] {} finding(s)",
+            report.summary.findings_baselined
+        ));
+    }
     con.print(&format!(
         "[cyan]Commands extracted:[/] {}",
         report.summary.commands_extracted
@@ -4558,42 +5882,122 @@ fn print_scan_pretty(report: &crate::scan::ScanReport, verbose: bool, top: usize
         report.summary.decisions.deny
     ));
     con.print(&format!(
-        "[cyan]Severities:[/] [red]error={}[/], [yellow]warning={}[/], [blue]info={}[/]",
-        report.summary.severities.error,
-        report.summary.severities.warning,
-        report.summary.severities.info
+        "[cyan]Risk score:[/] {:.2}",
+        report.summary.risk_score
     ));
 
-    if let Some(elapsed_ms) = report.summary.elapsed_ms {
-        con.print(&format!("[cyan]Elapsed:[/] {elapsed_ms} ms"));
-    }
-
     if report.summary.max_findings_reached {
         con.print("[yellow]Note: max findings limit reached, scan stopped early[/]");
     }
 
+    con.print("");
+    con.print(&scan_severity_badges(
+        &report.summary.severities,
+        |severity| severity_badge_markup(&theme, severity),
+    ));
+    let decision = if failed {
+        "[bold red]FAIL[/]"
+    } else {
+        "[bold green]PASS[/]"
+    };
+    con.print(&format!(
+        "[cyan]Result:[/] {decision} {}",
+        scan_outcome_details(&report.summary, failed)
+    ));
+
     if verbose {
         // Additional verbose info could go here
     }
 }
 
-/// Print scan report as GitHub-flavored Markdown (for PR comments).
+/// Closing severity line of the pretty scan output: each count is led by the
+/// badge for the severity it maps to in the findings table.
+fn scan_severity_badges(
+    severities: &crate::scan::ScanSeverityCounts,
+    badge: impl Fn(crate::output::Severity) -> String,
+) -> String {
+    use crate::output::Severity;
+
+    format!(
+        "Severities: {} {} error  {} {} warning  {} {} info",
+        badge(Severity::High),
+        severities.error,
+        badge(Severity::Medium),
+        severities.warning,
+        badge(Severity::Low),
+        severities.info
+    )
+}
+
+/// Exit code, finding total, and elapsed time after the PASS/FAIL decision.
+fn scan_outcome_details(summary: &crate::scan::ScanSummary, failed: bool) -> String {
+    let elapsed = summary
+        .elapsed_ms
+        .map_or_else(String::new, |ms| format!(" in {ms} ms"));
+    format!(
+        "(exit {}), {} finding(s){elapsed}",
+        i32::from(failed),
+        summary.findings_total
+    )
+}
+
+/// Print `dcg scan --list-extractors` output: id and claimed files per line.
+fn print_scan_extractors(json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let extractors = crate::scan::SCAN_EXTRACTORS;
+    if json {
+        let list: Vec<serde_json::Value> = extractors
+            .iter()
+            .map(|e| serde_json::json!({ "id": e.id, "file_patterns": e.file_patterns }))
+            .collect();
+        println!("{}", crate::output::to_json_string(&list)?);
+        return Ok(());
+    }
+    let width = extractors.iter().map(|e| e.id.len()).max().unwrap_or(0);
+    for extractor in extractors {
+        println!("{:<width$}  {}", extractor.id, extractor.file_patterns);
+    }
+    Ok(())
+}
+
+/// Print `dcg scan --print-extracted` output: one extracted command per line.
+fn print_extracted_commands(extraction: &crate::scan::ExtractionReport) {
+    for cmd in &extraction.commands {
+        println!(
+            "{}:{}\t[{}]\t{}",
+            cmd.file, cmd.line, cmd.extractor_id, cmd.command
+        );
+    }
+    eprintln!(
+        "{} command(s) extracted from {} file(s) ({} skipped)",
+        extraction.commands.len(),
+        extraction.files_scanned,
+        extraction.files_skipped
+    );
+}
+
+/// Format scan report as GitHub-flavored Markdown (for PR comments).
 ///
 /// Output structure:
 /// - Summary header with findings counts
 /// - Findings grouped by file, each in a `<details>` block
 /// - Severity badges (error/warning/info)
 /// - Truncated command preview for readability
-fn print_scan_markdown(report: &crate::scan::ScanReport, top: usize, truncate: usize) {
+fn format_scan_markdown(report: &crate::scan::ScanReport, top: usize, truncate: usize) -> String {
     use std::collections::BTreeMap;
+    use std::fmt::Write;
+
+    let mut out = String::new();
 
     // Header
-    println!("## DCG Scan Results\n");
+    let _ = writeln!(out, "## DCG Scan Results\n");
 
     if report.findings.is_empty() {
-        println!(":white_check_mark: **No findings** - all commands passed safety checks.\n");
-        print_scan_markdown_summary(report);
-        return;
+        let _ = writeln!(
+            out,
+            ":white_check_mark: **No findings** - all commands passed safety checks.\n"
+        );
+        write_scan_markdown_summary(&mut out, report);
+        return out;
     }
 
     // Summary badges
@@ -4602,15 +6006,15 @@ fn print_scan_markdown(report: &crate::scan::ScanReport, top: usize, truncate: u
     let info_count = report.summary.severities.info;
 
     if error_count > 0 {
-        print!(":x: **{error_count} error(s)** ");
+        let _ = write!(out, ":x: **{error_count} error(s)** ");
     }
     if warning_count > 0 {
-        print!(":warning: **{warning_count} warning(s)** ");
+        let _ = write!(out, ":warning: **{warning_count} warning(s)** ");
     }
     if info_count > 0 {
-        print!(":information_source: **{info_count} info** ");
+        let _ = write!(out, ":information_source: **{info_count} info** ");
     }
-    println!("\n");
+    let _ = writeln!(out, "\n");
 
     // Group findings by file
     let mut by_file: BTreeMap<&str, Vec<&crate::scan::ScanFinding>> = BTreeMap::new();
@@ -4651,8 +6055,11 @@ fn print_scan_markdown(report: &crate::scan::ScanReport, top: usize, truncate: u
             format!(" - {}", summary_parts.join(", "))
         };
 
-        println!("<details>");
-        println!("<summary><code>{file}</code>{summary_suffix}</summary>\n");
+        let _ = writeln!(out, "<details>");
+        let _ = writeln!(
+            out,
+            "<summary><code>{file}</code>{summary_suffix}</summary>\n"
+        );
 
         for finding in findings {
             if shown >= limit {
@@ -4672,62 +6079,149 @@ fn print_scan_markdown(report: &crate::scan::ScanReport, top: usize, truncate: u
             };
 
             let location = finding.col.map_or_else(
-                || finding.line.to_string(),
-                |col| format!("{}:{col}", finding.line),
+                || format!("{file}:{}", finding.line),
+                |col| format!("{file}:{}:{col}", finding.line),
             );
 
             // Truncate command for readability
             let cmd_preview = truncate_for_markdown(&finding.extracted_command, truncate);
 
-            println!("{severity_badge} **{decision_str}** at line {location}");
-            println!("```");
-            println!("{cmd_preview}");
-            println!("```");
+            let _ = writeln!(out, "{severity_badge} **{decision_str}** at `{location}`");
+            let _ = writeln!(out, "```");
+            let _ = writeln!(out, "{cmd_preview}");
+            let _ = writeln!(out, "```");
 
             if let Some(ref rule_id) = finding.rule_id {
-                println!("- **Rule:** `{rule_id}`");
+                let _ = writeln!(out, "- **Rule:** `{rule_id}`");
+            }
+            if let Some(ref owner) = finding.owner {
+                let owner = owner.as_deref().unwrap_or("_unowned_");
+                let _ = writeln!(out, "- **Owner:** {owner}");
             }
             if let Some(ref reason) = finding.reason {
-                println!("- **Reason:** {reason}");
+                let _ = writeln!(out, "- **Reason:** {reason}");
             }
             if let Some(ref suggestion) = finding.suggestion {
-                println!("- :bulb: **Suggestion:** {suggestion}");
+                let _ = writeln!(out, "\n:bulb: **Suggestion:**");
+                let _ = writeln!(out, "```");
+                let _ = writeln!(out, "{suggestion}");
+                let _ = writeln!(out, "```");
             }
-            println!();
+            let _ = writeln!(out);
 
             shown += 1;
         }
 
-        println!("</details>\n");
+        let _ = writeln!(out, "</details>\n");
     }
 
     if shown < total_findings {
-        println!("*Showing {shown} of {total_findings} findings. Use `--top 0` to show all.*\n");
+        let _ = writeln!(
+            out,
+            "*Showing {shown} of {total_findings} findings. Use `--top 0` to show all.*\n"
+        );
     }
 
-    print_scan_markdown_summary(report);
+    write_scan_markdown_summary(&mut out, report);
+    out
 }
 
-/// Print markdown summary section.
-fn print_scan_markdown_summary(report: &crate::scan::ScanReport) {
-    println!("---\n");
-    println!("### Summary\n");
-    println!("| Metric | Value |");
-    println!("|--------|-------|");
-    println!("| Files scanned | {} |", report.summary.files_scanned);
-    println!("| Files skipped | {} |", report.summary.files_skipped);
-    println!(
+/// Format the non-zero skip reasons as ` (1 binary, 3 excluded)`.
+fn format_scan_skip_breakdown(skipped: &crate::scan::ScanSkipCounts) -> String {
+    let parts: Vec<String> = [
+        (skipped.too_large, "too large"),
+        (skipped.binary, "binary"),
+        (skipped.unreadable, "unreadable"),
+        (skipped.excluded, "excluded"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{count} {label}"))
+    .collect();
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", parts.join(", "))
+    }
+}
+
+/// Append the markdown summary section.
+fn write_scan_markdown_summary(out: &mut String, report: &crate::scan::ScanReport) {
+    use std::fmt::Write;
+
+    let _ = writeln!(out, "---\n");
+    let _ = writeln!(out, "### Summary\n");
+    let _ = writeln!(out, "| Metric | Value |");
+    let _ = writeln!(out, "|--------|-------|");
+    let _ = writeln!(out, "| Files scanned | {} |", report.summary.files_scanned);
+    let _ = writeln!(
+        out,
+        "| Files skipped | {}{} |",
+        report.summary.files_skipped,
+        format_scan_skip_breakdown(&report.summary.skipped)
+    );
+    if report.summary.files_dcgignored > 0 {
+        let _ = writeln!(
+            out,
+            "| Files excluded by `.dcgignore` | {} |",
+            report.summary.files_dcgignored
+        );
+    }
+    if report.summary.findings_suppressed > 0 {
+        let _ = writeln!(
+            out,
+            "| Findings suppressed by `dcg:ignore` | {} |",
+            report.summary.findings_suppressed
+        );
+    }
+    if report.summary.findings_scan_allowed > 0 {
+        let _ = writeln!(
+            out,
+            "| Findings suppressed by `--scan-allow` | {} |",
+            report.summary.findings_scan_allowed
+        );
+    }
+    if report.summary.findings_baselined > 0 {
+        let _ = writeln!(
+            out,
+            "| Findings known from `--baseline` | {} |",
+            report.summary.findings_baselined
+        );
+    }
+    let _ = writeln!(
+        out,
         "| Commands extracted | {} |",
         report.summary.commands_extracted
     );
-    println!("| Total findings | {} |", report.summary.findings_total);
+    let _ = writeln!(
+        out,
+        "| Total findings | {} |",
+        report.summary.findings_total
+    );
+    if report.summary.findings_total > 0 {
+        let decisions = &report.summary.decisions;
+        let severities = &report.summary.severities;
+        let _ = writeln!(
+            out,
+            "| By decision | {} deny, {} warn, {} allow |",
+            decisions.deny, decisions.warn, decisions.allow
+        );
+        let _ = writeln!(
+            out,
+            "| By severity | {} error, {} warning, {} info |",
+            severities.error, severities.warning, severities.info
+        );
+    }
 
     if let Some(elapsed_ms) = report.summary.elapsed_ms {
-        println!("| Elapsed | {elapsed_ms} ms |");
+        let _ = writeln!(out, "| Elapsed | {elapsed_ms} ms |");
     }
 
     if report.summary.max_findings_reached {
-        println!("\n:warning: *Max findings limit reached, scan stopped early.*");
+        let _ = writeln!(
+            out,
+            "\n:warning: *Max findings limit reached, scan stopped early.*"
+        );
     }
 }
 
@@ -4750,6 +6244,20 @@ fn truncate_for_markdown(s: &str, max_len: usize) -> String {
     format!("{}...", &s[..end])
 }
 
+/// Where `dcg explain` should pretend the command runs (`--context`/`--branch`),
+/// which `--min-severity` preview to report, and whether to list all matches.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ExplainContext {
+    /// Directory to evaluate in (defaults to the current directory).
+    dir: Option<std::path::PathBuf>,
+    /// Branch name overriding git detection.
+    branch: Option<String>,
+    /// Severity threshold for the what-if preview.
+    min_severity: Option<PackSeverity>,
+    /// Report every pattern hit, not just the deciding one (`--all-matches`).
+    all_matches: bool,
+}
+
 /// Handle the `dcg explain` subcommand.
 ///
 /// Shows a detailed decision trace for why a command would be allowed or denied.
@@ -4761,6 +6269,90 @@ fn handle_explain(
     format: ExplainFormat,
     extra_packs: Option<Vec<String>>,
 ) {
+    handle_explain_in_context(
+        config,
+        command,
+        format,
+        extra_packs,
+        &ExplainContext::default(),
+    );
+}
+
+fn handle_explain_in_context(
+    config: &Config,
+    command: &str,
+    format: ExplainFormat,
+    extra_packs: Option<Vec<String>>,
+    context: &ExplainContext,
+) {
+    let trace = explain_trace(config, command, extra_packs, context);
+
+    // Format and print based on selected format
+    match format {
+        ExplainFormat::Pretty => {
+            #[cfg(feature = "rich-output")]
+            {
+                explain_rich(&trace);
+            }
+            #[cfg(not(feature = "rich-output"))]
+            {
+                let output =
+                    trace.format_pretty(colored::control::SHOULD_COLORIZE.should_colorize());
+                println!("{output}");
+            }
+        }
+        ExplainFormat::Compact | ExplainFormat::Json | ExplainFormat::Dot => {
+            print!("{}", render_explain_trace(&trace, format));
+        }
+    }
+}
+
+/// Render an explain trace as uncolored text, newline-terminated.
+///
+/// Used for `--output`, and for the compact/JSON/DOT formats on stdout.
+fn render_explain_trace(trace: &crate::trace::ExplainTrace, format: ExplainFormat) -> String {
+    let rendered = match format {
+        ExplainFormat::Pretty => trace.format_pretty(false),
+        ExplainFormat::Compact => trace.format_compact(None),
+        ExplainFormat::Json => {
+            let json_output = trace.to_json_output();
+            crate::output::to_json_string(&json_output)
+                .unwrap_or_else(|e| format!("{{\"error\": \"JSON serialization failed: {e}\"}}"))
+        }
+        ExplainFormat::Dot => return trace.format_dot(),
+    };
+    format!("{rendered}\n")
+}
+
+/// Trace two commands and render their differences for `dcg explain --compare`.
+fn explain_compare(
+    config: &Config,
+    commands: [&str; 2],
+    format: ExplainFormat,
+    extra_packs: Option<Vec<String>>,
+    context: &ExplainContext,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let left = explain_trace(config, commands[0], extra_packs.clone(), context);
+    let right = explain_trace(config, commands[1], extra_packs, context);
+    let diff = left.diff(&right);
+    match format {
+        ExplainFormat::Pretty => Ok(format!("{}\n", diff.format_pretty())),
+        ExplainFormat::Json => Ok(format!("{}\n", crate::output::to_json_string(&diff)?)),
+        ExplainFormat::Compact | ExplainFormat::Dot => {
+            Err("--compare supports only the pretty and json formats".into())
+        }
+    }
+}
+
+/// Evaluate `command` with full tracing for `dcg explain`.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn explain_trace(
+    config: &Config,
+    command: &str,
+    extra_packs: Option<Vec<String>>,
+    context: &ExplainContext,
+) -> crate::trace::ExplainTrace {
+    use crate::git::BranchInfo;
     use crate::trace::{MatchInfo, TraceCollector, TraceDetails};
 
     // Build effective config with extra packs if specified
@@ -4773,10 +6365,16 @@ fn handle_explain(
         },
     );
 
-    // Get enabled packs and collect keywords
-    let enabled_packs = effective_config.enabled_pack_ids();
+    let working_dir = context.dir.clone().or_else(|| std::env::current_dir().ok());
+
+    // Get enabled packs (path-scoped to the working dir) and collect keywords
+    let enabled_packs = working_dir.as_deref().map_or_else(
+        || effective_config.enabled_pack_ids(),
+        |dir| effective_config.enabled_pack_ids_for_dir(dir),
+    );
     let enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &effective_config.packs.pack_order);
     let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
     let heredoc_settings = effective_config.heredoc_settings();
     let compiled_overrides = effective_config.overrides.compile();
@@ -4785,9 +6383,26 @@ fn handle_explain(
     // Start tracing
     let mut collector = TraceCollector::new(command);
 
+    let branch_info = match (&context.branch, working_dir.as_deref()) {
+        (Some(name), _) => BranchInfo::Branch(name.clone()),
+        (None, Some(dir)) => crate::git::get_branch_info_at_path(dir),
+        (None, None) => crate::git::get_branch_info(),
+    };
+
+    collector.begin_step();
+    if let Some(resolved) = crate::normalize::resolve_indirection(command) {
+        collector.end_step(
+            "resolved eval/var",
+            TraceDetails::IndirectionResolution {
+                kind: resolved.label(),
+                resolved_command: resolved.command,
+            },
+        );
+    }
+
     // Evaluate with timing
     collector.begin_step();
-    let result = evaluate_command_with_pack_order(
+    let result = evaluate_command_with_pack_order_at_path(
         command,
         &enabled_keywords,
         &ordered_packs,
@@ -4795,6 +6410,7 @@ fn handle_explain(
         &compiled_overrides,
         &allowlists,
         &heredoc_settings,
+        working_dir.as_deref(),
     );
     collector.end_step(
         "full_evaluation",
@@ -4807,6 +6423,117 @@ fn handle_explain(
     );
     collector.set_budget_skip(result.skipped_due_to_budget);
 
+    collector.begin_step();
+    let result = crate::evaluator::apply_branch_strictness_with_info(
+        result,
+        &effective_config,
+        &branch_info,
+    );
+    let branch_context = result.branch_context.as_ref();
+    collector.end_step(
+        "evaluation_context",
+        TraceDetails::EvaluationContext {
+            working_dir: working_dir
+                .as_deref()
+                .map_or_else(String::new, |dir| dir.display().to_string()),
+            branch: branch_info.branch_name().map(String::from),
+            branch_overridden: context.branch.is_some(),
+            strictness: branch_context.map(|ctx| ctx.strictness.to_string()),
+            affected_decision: branch_context.is_some_and(|ctx| ctx.affected_decision),
+        },
+    );
+
+    if !effective_config.branch_policy.is_empty() {
+        collector.begin_step();
+        let branch_policy =
+            crate::evaluator::resolve_branch_policy_with_info(&effective_config, &branch_info);
+        let effective_mode = result.pattern_info.as_ref().and_then(|info| {
+            matches!(
+                info.source,
+                MatchSource::Pack | MatchSource::HeredocAst | MatchSource::Heuristic
+            )
+            .then(|| {
+                effective_config.policy().resolve_mode_for_branch(
+                    info.pack_id.as_deref(),
+                    info.pattern_name.as_deref(),
+                    info.severity,
+                    branch_policy.as_ref().map(|policy| policy.mode),
+                )
+            })
+        });
+        collector.end_step(
+            "branch_policy",
+            TraceDetails::BranchPolicy {
+                branch: branch_info.branch_name().map(String::from),
+                matched_pattern: branch_policy.as_ref().map(|policy| policy.pattern.clone()),
+                policy_mode: branch_policy.map(|policy| policy.mode.to_decision_mode()),
+                effective_mode,
+            },
+        );
+    }
+
+    // A deny despite an allowlist entry usually means the entry lapsed; say so.
+    if result.decision == EvaluationDecision::Deny {
+        let pack_id = result
+            .pattern_info
+            .as_ref()
+            .and_then(|p| p.pack_id.as_deref());
+        let pattern_name = result
+            .pattern_info
+            .as_ref()
+            .and_then(|p| p.pattern_name.as_deref());
+        let loaded_allowlists = load_default_allowlists();
+        for hit in loaded_allowlists.expired_matches_at_path(
+            pack_id,
+            pattern_name,
+            command,
+            working_dir.as_deref(),
+        ) {
+            collector.begin_step();
+            collector.end_step(
+                "allowlist_expired",
+                TraceDetails::AllowlistExpired {
+                    layer: hit.layer,
+                    selector: allowlist_selector_label(&hit.entry.selector),
+                    expired_at: hit.entry.expires_at.clone().unwrap_or_else(|| {
+                        crate::allowlist::expiration_time(hit.entry)
+                            .map_or_else(|| "unknown".to_string(), |dt| dt.to_rfc3339())
+                    }),
+                },
+            );
+        }
+    }
+
+    if let Some(min_severity) = context.min_severity {
+        let below_threshold = result.is_denied() && result.is_below_severity(min_severity);
+        let hits = if below_threshold {
+            crate::evaluator::collect_all_pattern_hits(command, &enabled_keywords, &ordered_packs)
+        } else {
+            Vec::new()
+        };
+        collector.begin_step();
+        collector.end_step(
+            "severity_threshold",
+            TraceDetails::SeverityThreshold {
+                min_severity,
+                match_severity: result.pattern_info.as_ref().and_then(|p| p.severity),
+                below_threshold,
+                threshold_decision: result.decision_at_min_severity(min_severity, &hits),
+            },
+        );
+    }
+
+    if context.all_matches {
+        collector.set_all_matches(crate::evaluator::collect_all_pattern_hits(
+            command,
+            &enabled_keywords,
+            &ordered_packs,
+        ));
+        if !effective_config.packs.pack_order.is_empty() {
+            collector.set_pack_order(effective_config.packs.pack_order.clone());
+        }
+    }
+
     // Add match info if present
     if let Some(ref pattern) = result.pattern_info {
         let rule_id = pattern
@@ -4828,32 +6555,18 @@ fn handle_explain(
         });
     }
 
-    // Finish and get trace
-    let trace = collector.finish(result.decision);
+    collector.finish(result.decision)
+}
 
-    // Format and print based on selected format
-    match format {
-        ExplainFormat::Pretty => {
-            #[cfg(feature = "rich-output")]
-            {
-                explain_rich(&trace);
-            }
-            #[cfg(not(feature = "rich-output"))]
-            {
-                let output =
-                    trace.format_pretty(colored::control::SHOULD_COLORIZE.should_colorize());
-                println!("{output}");
-            }
-        }
-        ExplainFormat::Compact => {
-            println!("{}", trace.format_compact(None));
-        }
-        ExplainFormat::Json => {
-            let json_output = trace.to_json_output();
-            let json = serde_json::to_string_pretty(&json_output)
-                .unwrap_or_else(|e| format!("{{\"error\": \"JSON serialization failed: {e}\"}}"));
-            println!("{json}");
-        }
+/// Short description of what an allowlist entry targets.
+fn allowlist_selector_label(selector: &crate::allowlist::AllowSelector) -> String {
+    use crate::allowlist::AllowSelector;
+
+    match selector {
+        AllowSelector::Rule(rule_id) => format!("rule {rule_id}"),
+        AllowSelector::ExactCommand(cmd) => format!("command '{cmd}'"),
+        AllowSelector::CommandPrefix(prefix) => format!("prefix '{prefix}'"),
+        AllowSelector::RegexPattern(pattern) => format!("pattern '{pattern}'"),
     }
 }
 
@@ -4878,6 +6591,12 @@ fn explain_rich(trace: &crate::trace::ExplainTrace) {
     con.print(&format!(
         "[bold]Decision:[/] [{decision_color} bold]{decision_icon} {decision_text}[/]"
     ));
+    if let Some(note) = trace.severity_threshold_note() {
+        con.print(&format!("[yellow]{note}[/]"));
+    }
+    for note in trace.expired_allowlist_notes() {
+        con.print(&format!("[yellow]{note}[/]"));
+    }
     con.print(&format!(
         "[bold]Latency:[/]  [dim]{:.2}ms[/]",
         trace.total_duration_us as f64 / 1000.0
@@ -5004,6 +6723,19 @@ fn explain_rich(trace: &crate::trace::ExplainTrace) {
         con.print("");
     }
 
+    // All matches (--all-matches)
+    if trace.all_matches.is_some() {
+        con.print("[bold yellow]All Matches[/]");
+        for line in trace.all_matches_lines() {
+            if line.ends_with(crate::trace::ALL_MATCHES_DECIDED_MARKER) {
+                con.print(&format!("[red]{line}[/]"));
+            } else {
+                con.print(&line);
+            }
+        }
+        con.print("");
+    }
+
     // Pipeline trace tree
     if !trace.steps.is_empty() {
         con.print("[bold blue]Pipeline Trace[/]");
@@ -5038,6 +6770,9 @@ fn explain_rich(trace: &crate::trace::ExplainTrace) {
                     "unchanged"
                 }
                 .to_string(),
+                TraceDetails::IndirectionResolution { kind, .. } => {
+                    format!("resolved {kind}")
+                }
                 TraceDetails::Sanitization {
                     was_modified,
                     spans_masked,
@@ -5100,28 +6835,27 @@ fn explain_rich(trace: &crate::trace::ExplainTrace) {
     // Suggestions
     if let Some(ref info) = trace.match_info {
         if let Some(rule_id) = info.rule_id.as_deref() {
-            if let Some(suggestions) = crate::suggestions::get_suggestions(rule_id) {
-                if !suggestions.is_empty() && crate::output::suggestions_enabled() {
-                    con.print("[bold yellow]Suggestions[/]");
-                    let suggestion_count = suggestions.len();
-
-                    for (i, s) in suggestions.iter().enumerate() {
-                        let branch = if i == suggestion_count - 1 {
-                            "└─"
-                        } else {
-                            "├─"
-                        };
-                        con.print(&format!(
-                            "{branch} [yellow]{}[/]: {}",
-                            s.kind.label(),
-                            s.text
-                        ));
-                        if let Some(ref cmd) = s.command {
-                            con.print(&format!("   [dim]$[/] [green]{cmd}[/]"));
-                        }
-                        if let Some(ref url) = s.url {
-                            con.print(&format!("   [dim]→ {url}[/]"));
-                        }
+            let suggestions = crate::suggestions::suggestions_for_command(rule_id, &trace.command);
+            if !suggestions.is_empty() && crate::output::suggestions_enabled() {
+                con.print("[bold yellow]Suggestions[/]");
+                let suggestion_count = suggestions.len();
+
+                for (i, s) in suggestions.iter().enumerate() {
+                    let branch = if i == suggestion_count - 1 {
+                        "└─"
+                    } else {
+                        "├─"
+                    };
+                    con.print(&format!(
+                        "{branch} [yellow]{}[/]: {}",
+                        s.kind.label(),
+                        s.text
+                    ));
+                    if let Some(ref cmd) = s.command {
+                        con.print(&format!("   [dim]$[/] [green]{cmd}[/]"));
+                    }
+                    if let Some(ref url) = s.url {
+                        con.print(&format!("   [dim]→ {url}[/]"));
                     }
                 }
             }
@@ -5421,7 +7155,8 @@ fn run_single_corpus_test(
 
     let enabled_packs = effective_config.enabled_pack_ids();
     let enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &effective_config.packs.pack_order);
     let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
     let compiled_overrides = effective_config.overrides.compile();
     let allowlists = crate::LayeredAllowlist::default();
@@ -5431,13 +7166,18 @@ fn run_single_corpus_test(
     let mut heredoc_triggers = Vec::new();
     let mut heredoc_triggers_sanitized = Vec::new();
     let mut heredoc_suppression_reason = None;
-    if crate::heredoc::check_triggers(&case.command) == crate::heredoc::TriggerResult::Triggered {
-        heredoc_triggers = crate::heredoc::matched_triggers(&case.command);
+    let custom_triggers = heredoc_settings.custom_triggers.as_ref();
+    if crate::heredoc::check_triggers_with_custom(&case.command, custom_triggers)
+        == crate::heredoc::TriggerResult::Triggered
+    {
+        heredoc_triggers =
+            crate::heredoc::matched_triggers_with_custom(&case.command, custom_triggers);
 
         let sanitized = crate::context::sanitize_for_pattern_matching(&case.command);
         if matches!(sanitized, std::borrow::Cow::Owned(_)) {
             let sanitized_str = sanitized.as_ref();
-            heredoc_triggers_sanitized = crate::heredoc::matched_triggers(sanitized_str);
+            heredoc_triggers_sanitized =
+                crate::heredoc::matched_triggers_with_custom(sanitized_str, custom_triggers);
             if heredoc_triggers_sanitized.is_empty() {
                 heredoc_suppression_reason =
                     Some("sanitized_removed_all_tier1_triggers".to_string());
@@ -5551,7 +7291,7 @@ fn handle_corpus_command(
 
     // Format output
     let output_str = match cmd.format {
-        CorpusFormat::Json => serde_json::to_string_pretty(&output)?,
+        CorpusFormat::Json => crate::output::to_json_string(&output)?,
         CorpusFormat::Pretty => format_corpus_pretty(&output),
     };
 
@@ -5573,26 +7313,11 @@ fn handle_corpus_command(
     Ok(())
 }
 
-/// Handle the `dcg stats` command.
+/// Log file for `dcg stats`: `--file`, then `general.log_file`, then the
+/// default location.
 #[allow(clippy::option_if_let_else)]
-fn handle_stats_command(
-    config: &Config,
-    cmd: &StatsCommand,
-    quiet: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
-    use crate::stats;
-
-    if quiet {
-        return Ok(());
-    }
-
-    // Handle --rules mode (query history database for rule-level metrics)
-    if cmd.rules {
-        return handle_stats_rules(config, cmd);
-    }
-
-    // Determine log file path
-    let log_path = if let Some(ref path) = cmd.file {
+fn stats_log_path(config: &Config, cmd: &StatsCommand) -> std::path::PathBuf {
+    if let Some(ref path) = cmd.file {
         path.clone()
     } else if let Some(ref log_file) = config.general.log_file {
         // Expand ~ in path
@@ -5610,7 +7335,57 @@ fn handle_stats_command(
             .unwrap_or_else(|| std::path::PathBuf::from("~/.local/share"))
             .join("dcg")
             .join("blocked.log")
-    };
+    }
+}
+
+/// Handle the `dcg stats` command.
+fn handle_stats_command(
+    config: &Config,
+    cmd: &StatsCommand,
+    quiet: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::stats;
+
+    if cmd.since.is_some() && !cmd.history {
+        return Err("--since requires --history".into());
+    }
+
+    let log_path = stats_log_path(config, cmd);
+
+    #[cfg(feature = "http-server")]
+    if let Some(gateway) = &cmd.push_gateway {
+        let aggregated = if log_path.exists() {
+            stats::parse_log_file(&log_path, cmd.days * 24 * 60 * 60)?
+        } else {
+            stats::AggregatedStats::default()
+        };
+        crate::pushgateway::push(
+            gateway,
+            &cmd.job,
+            cmd.instance.as_deref(),
+            &stats::format_stats_prometheus(&aggregated),
+        )?;
+        if !quiet {
+            eprintln!(
+                "dcg stats: pushed {} log entries to {gateway} (job={})",
+                aggregated.total_entries, cmd.job
+            );
+        }
+        return Ok(());
+    }
+
+    if quiet {
+        return Ok(());
+    }
+
+    // Handle --rules mode (query history database for rule-level metrics)
+    if cmd.rules {
+        return handle_stats_rules(config, cmd);
+    }
+
+    if cmd.history {
+        return handle_stats_history(config, cmd);
+    }
 
     // Check if log file exists
     if !log_path.exists() {
@@ -5681,7 +7456,7 @@ fn handle_stats_rules(
         Err(err) => {
             if matches!(cmd.format, StatsFormat::Json) {
                 // Output empty metrics for JSON format
-                print!("{}", format_rule_metrics_json(&[], cmd.days)?);
+                print!("{}", format_rule_metrics_json(&[], &[], cmd.days)?);
                 return Ok(());
             }
             if matches!(err, crate::history::HistoryError::Disabled) {
@@ -5693,48 +7468,187 @@ fn handle_stats_rules(
                 println!("  enabled = true");
                 return Ok(());
             }
-            println!("Error opening history database: {err}");
+            println!("Error opening history database: {err}");
+            return Ok(());
+        }
+    };
+
+    // Calculate the start time based on --days
+    let since = Some(Utc::now() - Duration::days(i64::try_from(cmd.days).unwrap_or(30)));
+
+    // Query rule metrics
+    let metrics = db.get_rule_metrics(since, cmd.limit)?;
+
+    // All-time first/last trigger per rule, independent of --days
+    let mut timeline = db.rule_timeline()?;
+    timeline.truncate(cmd.limit);
+
+    if metrics.is_empty() {
+        if matches!(cmd.format, StatsFormat::Json) {
+            // Output empty metrics for JSON format
+            print!("{}", format_rule_metrics_json(&[], &timeline, cmd.days)?);
+            return Ok(());
+        }
+        println!("No rule metrics found in the last {} days.", cmd.days);
+        println!();
+        println!("Rule metrics are collected when commands are blocked or bypassed.");
+        println!("Run some commands through dcg to generate metrics.");
+        if !timeline.is_empty() {
+            println!();
+            print!("{}", format_rule_timeline_pretty(&timeline));
+        }
+        return Ok(());
+    }
+
+    // Format and print output
+    match cmd.format {
+        StatsFormat::Pretty => {
+            #[cfg(feature = "rich-output")]
+            {
+                format_rule_metrics_rich(&metrics, cmd.days);
+                format_rule_timeline_rich(&timeline);
+            }
+            #[cfg(not(feature = "rich-output"))]
+            {
+                print!("{}", format_rule_metrics_pretty(&metrics, cmd.days));
+                if !timeline.is_empty() {
+                    println!();
+                    print!("{}", format_rule_timeline_pretty(&timeline));
+                }
+            }
+        }
+        StatsFormat::Json => {
+            print!(
+                "{}",
+                format_rule_metrics_json(&metrics, &timeline, cmd.days)?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle the `dcg stats --history` command.
+fn handle_stats_history(
+    config: &Config,
+    cmd: &StatsCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use crate::history::{HistoryDb, HistoryError};
+    use chrono::{Duration, Utc};
+
+    /// Number of blocked rules listed in the summary.
+    const TOP_BLOCKED_RULES: usize = 10;
+
+    let (window, label) = match &cmd.since {
+        Some(since) => (parse_duration_string(since)?, since.trim().to_string()),
+        None => (
+            Duration::days(i64::try_from(cmd.days).unwrap_or(30)),
+            format!("{}d", cmd.days),
+        ),
+    };
+
+    let db = match HistoryDb::open(config.history.expanded_database_path()) {
+        Ok(db) => db,
+        Err(HistoryError::Disabled) => {
+            if matches!(cmd.format, StatsFormat::Json) {
+                println!(
+                    "{}",
+                    crate::output::to_json_string(
+                        &serde_json::json!({ "history_enabled": false })
+                    )?
+                );
+            } else {
+                println!(
+                    "History is disabled ({} is set), so there is no activity to summarize.",
+                    crate::history::ENV_HISTORY_DISABLED
+                );
+            }
             return Ok(());
         }
+        Err(err) => return Err(format!("Error opening history database: {err}").into()),
     };
 
-    // Calculate the start time based on --days
-    let since = Some(Utc::now() - Duration::days(i64::try_from(cmd.days).unwrap_or(30)));
+    let summary = db.activity_summary(Utc::now() - window, TOP_BLOCKED_RULES)?;
 
-    // Query rule metrics
-    let metrics = db.get_rule_metrics(since, cmd.limit)?;
+    match cmd.format {
+        StatsFormat::Pretty => print!("{}", format_history_activity_pretty(&summary, &label)),
+        StatsFormat::Json => println!("{}", crate::output::to_json_string(&summary)?),
+    }
 
-    if metrics.is_empty() {
-        if matches!(cmd.format, StatsFormat::Json) {
-            // Output empty metrics for JSON format
-            print!("{}", format_rule_metrics_json(&[], cmd.days)?);
-            return Ok(());
-        }
-        println!("No rule metrics found in the last {} days.", cmd.days);
-        println!();
-        println!("Rule metrics are collected when commands are blocked or bypassed.");
-        println!("Run some commands through dcg to generate metrics.");
-        return Ok(());
+    Ok(())
+}
+
+/// Format a `dcg stats --history` summary, rendering the blocked-rule table
+/// with the active theme.
+fn format_history_activity_pretty(
+    summary: &crate::history::ActivitySummary,
+    window_label: &str,
+) -> String {
+    use crate::output::auto_theme;
+    use crate::output::tables::{StatsRow, StatsTable};
+    use std::fmt::Write;
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "History activity (last {window_label}, since {})",
+        summary.since.format("%Y-%m-%d %H:%M UTC")
+    );
+    let _ = writeln!(output, "Total commands: {}", summary.total_commands);
+    let outcomes = &summary.outcomes;
+    let _ = writeln!(
+        output,
+        "Outcomes: allow {} | deny {} | warn {} | bypass {} | allowlisted {} | timeout {}",
+        outcomes.allowed,
+        outcomes.denied,
+        outcomes.warned,
+        outcomes.bypassed,
+        outcomes.allowlisted,
+        outcomes.timed_out
+    );
+    let _ = writeln!(output);
+
+    if summary.top_blocked_rules.is_empty() {
+        let _ = writeln!(output, "No blocked commands in this window.");
+    } else {
+        let rows = summary
+            .top_blocked_rules
+            .iter()
+            .map(|rule| StatsRow {
+                name: rule.rule_id.clone(),
+                hits: rule.hits,
+                allowed: rule.hits.saturating_sub(rule.denied),
+                denied: rule.denied,
+                noise_pct: None,
+            })
+            .collect();
+        let table = StatsTable::new(rows)
+            .with_theme(&auto_theme())
+            .with_title("Top blocked rules:");
+        let _ = writeln!(output, "{}", table.render());
     }
 
-    // Format and print output
-    match cmd.format {
-        StatsFormat::Pretty => {
-            #[cfg(feature = "rich-output")]
-            {
-                format_rule_metrics_rich(&metrics, cmd.days);
-            }
-            #[cfg(not(feature = "rich-output"))]
-            {
-                print!("{}", format_rule_metrics_pretty(&metrics, cmd.days));
-            }
-        }
-        StatsFormat::Json => {
-            print!("{}", format_rule_metrics_json(&metrics, cmd.days)?);
+    if !summary.agents.is_empty() {
+        let _ = writeln!(output);
+        let _ = writeln!(output, "Agents:");
+        let width = summary
+            .agents
+            .iter()
+            .map(|agent| agent.name.len())
+            .max()
+            .unwrap_or(0);
+        for agent in &summary.agents {
+            #[allow(clippy::cast_precision_loss)]
+            let share = agent.count as f64 * 100.0 / summary.total_commands.max(1) as f64;
+            let _ = writeln!(
+                output,
+                "  {:<width$}  {:>6}  ({share:.1}%)",
+                agent.name, agent.count
+            );
         }
     }
 
-    Ok(())
+    output
 }
 
 /// Format rule metrics as a pretty table.
@@ -5856,6 +7770,96 @@ fn format_rule_metrics_pretty(metrics: &[crate::history::RuleMetrics], period_da
     output
 }
 
+/// Format the all-time rule timeline as a plain table.
+fn format_rule_timeline_pretty(timeline: &[crate::history::RuleTimeline]) -> String {
+    use std::fmt::Write;
+
+    let mut output = String::new();
+    let _ = writeln!(output, "Rule Timeline (all time):");
+    let _ = writeln!(output);
+
+    let max_rule_len = timeline
+        .iter()
+        .map(|t| t.rule_id.len())
+        .max()
+        .unwrap_or(10)
+        .clamp(10, 40);
+
+    let _ = writeln!(
+        output,
+        "  {:<width$}  {:<16}  {:<16}  {:>6}",
+        "Rule ID",
+        "First Seen",
+        "Last Seen",
+        "Hits",
+        width = max_rule_len
+    );
+    let _ = writeln!(
+        output,
+        "  {:-<width$}  {:-<16}  {:-<16}  {:->6}",
+        "",
+        "",
+        "",
+        "",
+        width = max_rule_len
+    );
+
+    for t in timeline {
+        let rule_id_display = if t.rule_id.len() > max_rule_len {
+            format!("{}...", &t.rule_id[..max_rule_len - 3])
+        } else {
+            t.rule_id.clone()
+        };
+        let _ = writeln!(
+            output,
+            "  {:<width$}  {:<16}  {:<16}  {:>6}",
+            rule_id_display,
+            t.first_seen.format("%Y-%m-%d %H:%M"),
+            t.last_seen.format("%Y-%m-%d %H:%M"),
+            t.total_hits,
+            width = max_rule_len
+        );
+    }
+
+    output
+}
+
+/// Rich output for the all-time rule timeline.
+#[cfg(feature = "rich-output")]
+fn format_rule_timeline_rich(timeline: &[crate::history::RuleTimeline]) {
+    use crate::output::console::console;
+
+    if timeline.is_empty() {
+        return;
+    }
+
+    let con = console();
+
+    con.print("");
+    con.rule(Some("[bold] Rule Timeline (all time) [/]"));
+    con.print("");
+
+    con.print("[bold cyan]Rule ID                           First Seen        Last Seen           Hits[/]");
+    con.print(
+        "[dim]─────────────────────────────────────────────────────────────────────────────[/]",
+    );
+
+    for t in timeline {
+        let rule_display = if t.rule_id.len() > 32 {
+            format!("{}...", &t.rule_id[..29])
+        } else {
+            t.rule_id.clone()
+        };
+        con.print(&format!(
+            "{:<32}  [dim]{:<16}[/]  {:<16}  {:>6}",
+            rule_display,
+            t.first_seen.format("%Y-%m-%d %H:%M"),
+            t.last_seen.format("%Y-%m-%d %H:%M"),
+            t.total_hits
+        ));
+    }
+}
+
 /// Rich output for pack statistics.
 #[cfg(feature = "rich-output")]
 fn format_stats_pack_rich(stats: &crate::stats::AggregatedStats, period_days: u64) {
@@ -5992,6 +7996,17 @@ struct RuleMetricsOutput {
     period_days: u64,
     rules: Vec<RuleMetricEntry>,
     totals: RuleMetricsTotals,
+    /// All-time first/last trigger per rule (ignores `period_days`).
+    timeline: Vec<RuleTimelineEntry>,
+}
+
+/// Single all-time timeline entry in JSON output.
+#[derive(serde::Serialize)]
+struct RuleTimelineEntry {
+    rule_id: String,
+    first_seen: String,
+    last_seen: String,
+    total_hits: u64,
 }
 
 /// Single rule entry in JSON output.
@@ -6028,6 +8043,7 @@ struct RuleMetricsTotals {
 /// Format rule metrics as JSON.
 fn format_rule_metrics_json(
     metrics: &[crate::history::RuleMetrics],
+    timeline: &[crate::history::RuleTimeline],
     period_days: u64,
 ) -> Result<String, Box<dyn std::error::Error>> {
     let rules: Vec<RuleMetricEntry> = metrics
@@ -6079,9 +8095,18 @@ fn format_rule_metrics_json(
             avg_override_rate: avg_rate,
             rule_count: metrics.len(),
         },
+        timeline: timeline
+            .iter()
+            .map(|t| RuleTimelineEntry {
+                rule_id: t.rule_id.clone(),
+                first_seen: t.first_seen.to_rfc3339(),
+                last_seen: t.last_seen.to_rfc3339(),
+                total_hits: t.total_hits,
+            })
+            .collect(),
     };
 
-    Ok(serde_json::to_string_pretty(&output)?)
+    Ok(crate::output::to_json_string(&output)?)
 }
 
 /// Handle the `dcg suggest-allowlist` command.
@@ -6304,7 +8329,7 @@ fn output_suggestions_json(
         })
         .collect();
 
-    let json = serde_json::to_string_pretty(&output)?;
+    let json = crate::output::to_json_string(&output)?;
     println!("{json}");
     Ok(())
 }
@@ -6613,10 +8638,22 @@ fn handle_history_command(
         }
         HistoryAction::Prune {
             older_than_days,
+            older_than,
+            max_rows,
             dry_run,
             yes,
         } => {
-            history_prune(&db, older_than_days, dry_run, yes)?;
+            history_prune(
+                &db,
+                older_than_days,
+                older_than.as_deref(),
+                max_rows,
+                dry_run,
+                yes,
+            )?;
+        }
+        HistoryAction::Search { query, limit, json } => {
+            history_search(&db, &query, limit, json)?;
         }
         HistoryAction::Export {
             output,
@@ -6662,7 +8699,7 @@ fn history_stats(
     };
 
     if json {
-        let output = serde_json::to_string_pretty(&stats)?;
+        let output = crate::output::to_json_string(&stats)?;
         println!("{output}");
     } else {
         let output = format_history_stats_pretty(&stats);
@@ -6674,29 +8711,100 @@ fn history_stats(
 
 fn history_prune(
     db: &HistoryDb,
-    older_than_days: u64,
+    older_than_days: Option<u64>,
+    older_than: Option<&str>,
+    max_rows: Option<usize>,
     dry_run: bool,
     yes: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    if older_than_days == 0 {
+    if older_than_days == Some(0) {
         return Err("older-than-days must be at least 1".into());
     }
 
+    let (age, age_label) = match (older_than_days, older_than) {
+        (Some(days), _) => (
+            Some(chrono::Duration::days(
+                i64::try_from(days).unwrap_or(i64::MAX),
+            )),
+            format!("{days} days"),
+        ),
+        (None, Some(spec)) => {
+            let age = parse_duration_string(spec)?;
+            if age <= chrono::Duration::zero() {
+                return Err("--older-than must be a positive duration".into());
+            }
+            (Some(age), spec.trim().to_string())
+        }
+        (None, None) => (None, String::new()),
+    };
+
     if !dry_run && !yes {
         println!("Refusing to prune without --yes or --dry-run.");
         return Ok(());
     }
 
-    let pruned = db.prune_older_than_days(older_than_days, dry_run)?;
-    if dry_run {
-        println!("Would prune {pruned} entries older than {older_than_days} days");
+    // With only --max-rows, nothing is old enough to prune by age.
+    let age_cutoff = age.unwrap_or(chrono::Duration::MAX);
+    let pruned = if dry_run {
+        db.preview_prune(age_cutoff, max_rows)?
     } else {
-        println!("Pruned {pruned} entries older than {older_than_days} days");
+        db.prune(age_cutoff, max_rows)?
+    };
+
+    let mut scope = Vec::new();
+    if age.is_some() {
+        scope.push(format!("older than {age_label}"));
+    }
+    if let Some(max_rows) = max_rows {
+        scope.push(format!("beyond the newest {max_rows}"));
+    }
+    let verb = if dry_run { "Would prune" } else { "Pruned" };
+    println!("{verb} {pruned} entries {}", scope.join(" or "));
+
+    Ok(())
+}
+
+fn history_search(
+    db: &HistoryDb,
+    query: &str,
+    limit: usize,
+    json: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let entries = db.search(query, limit)?;
+
+    if json {
+        let output = crate::output::to_json_string(&entries)?;
+        println!("{output}");
+        return Ok(());
     }
 
+    if entries.is_empty() {
+        println!("No history entries match \"{query}\"");
+        return Ok(());
+    }
+
+    print!("{}", format_history_search_pretty(&entries));
     Ok(())
 }
 
+/// One line per entry: timestamp, outcome, working directory, command.
+fn format_history_search_pretty(entries: &[crate::history::CommandEntry]) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::new();
+    for entry in entries {
+        let _ = writeln!(
+            out,
+            "{}  {:<11}  {}  {}",
+            entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            entry.outcome.as_str(),
+            entry.working_dir,
+            entry.command
+        );
+    }
+    out
+}
+
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::needless_pass_by_value)]
 fn history_export(
@@ -6709,7 +8817,6 @@ fn history_export(
     limit: Option<usize>,
     compress: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use chrono::DateTime;
     use flate2::Compression;
     use flate2::write::GzEncoder;
     use std::fs::File;
@@ -6724,20 +8831,12 @@ fn history_export(
     // Parse date/time filters
     let since_dt = since
         .as_deref()
-        .map(|s| {
-            DateTime::parse_from_rfc3339(s)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .map_err(|_| format!("Invalid since datetime: {s} (use ISO 8601 format)"))
-        })
+        .map(|s| parse_export_datetime(s, "since"))
         .transpose()?;
 
     let until_dt = until
         .as_deref()
-        .map(|s| {
-            DateTime::parse_from_rfc3339(s)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .map_err(|_| format!("Invalid until datetime: {s} (use ISO 8601 format)"))
-        })
+        .map(|s| parse_export_datetime(s, "until"))
         .transpose()?;
 
     let options = ExportOptions {
@@ -6773,6 +8872,29 @@ fn history_export(
     Ok(())
 }
 
+/// Parse an export `--since`/`--until` bound.
+///
+/// Accepts RFC 3339 (`2024-01-01T12:00:00Z`), a naive date-time taken as UTC
+/// (`2024-01-01T12:00:00` or `2024-01-01 12:00:00`), or a bare date meaning
+/// midnight UTC (`2024-01-01`).
+fn parse_export_datetime(value: &str, flag: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    let value = value.trim();
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&chrono::Utc));
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(value, format) {
+            return Ok(naive.and_utc());
+        }
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+    Err(format!(
+        "Invalid {flag} datetime: {value} (use ISO 8601, e.g. 2024-01-01 or 2024-01-01T12:00:00Z)"
+    ))
+}
+
 fn export_to_writer<W: std::io::Write>(
     db: &HistoryDb,
     writer: &mut W,
@@ -6806,7 +8928,7 @@ fn history_analyze(
     let analysis = db.analyze_pack_effectiveness(days, &enabled_packs)?;
 
     if json {
-        let output = serde_json::to_string_pretty(&analysis)?;
+        let output = crate::output::to_json_string(&analysis)?;
         println!("{output}");
         return Ok(());
     }
@@ -6943,7 +9065,7 @@ fn history_check(
     let result = db.check_health()?;
 
     if json {
-        let output = serde_json::to_string_pretty(&result)?;
+        let output = crate::output::to_json_string(&result)?;
         println!("{output}");
     } else {
         println!(
@@ -7087,11 +9209,13 @@ fn format_history_stats_pretty(stats: &HistoryStats) -> String {
     let _ = writeln!(output, "Total commands: {}", stats.total_commands);
     let _ = writeln!(
         output,
-        "Outcomes: allow {} | deny {} | warn {} | bypass {}",
+        "Outcomes: allow {} | deny {} | warn {} | bypass {} | allowlisted {} | timeout {}",
         stats.outcomes.allowed,
         stats.outcomes.denied,
         stats.outcomes.warned,
-        stats.outcomes.bypassed
+        stats.outcomes.bypassed,
+        stats.outcomes.allowlisted,
+        stats.outcomes.timed_out
     );
     let _ = writeln!(output, "Block rate: {:.2}%", stats.block_rate * 100.0);
     let _ = writeln!(
@@ -7635,8 +9759,11 @@ const DOCTOR_SCHEMA_VERSION: u32 = 1;
 
 fn doctor_json(fix: bool) {
     let report = collect_doctor_report(fix);
-    let json = serde_json::to_string_pretty(&report).expect("serialize doctor report");
+    let json = crate::output::to_json_string(&report).expect("serialize doctor report");
     println!("{json}");
+    if !report.ok {
+        std::process::exit(1);
+    }
 }
 
 /// Rich terminal doctor output using DcgConsole and markup.
@@ -8017,70 +10144,345 @@ fn collect_doctor_report(fix: bool) -> DoctorReport {
                 )
             }
         } else {
-            (
-                DoctorCheckStatus::Ok,
-                "Observe mode disabled".to_string(),
-                None,
-            )
+            (
+                DoctorCheckStatus::Ok,
+                "Observe mode disabled".to_string(),
+                None,
+            )
+        };
+    checks.push(DoctorCheck {
+        id: "observe_mode",
+        name: "Observe mode",
+        status,
+        message,
+        remediation,
+        fixed: false,
+    });
+
+    // Check 8: Allowlist discovery + validation
+    let allowlist_diag = diagnose_allowlists();
+    let (status, message, remediation) = if allowlist_diag.total_errors > 0 {
+        issues += allowlist_diag.total_errors;
+        (
+            DoctorCheckStatus::Error,
+            format!(
+                "Allowlist errors: {}",
+                allowlist_diag.error_messages.join("; ")
+            ),
+            Some("Run 'dcg allowlist validate' for details".to_string()),
+        )
+    } else if allowlist_diag.total_warnings > 0 {
+        (
+            DoctorCheckStatus::Warning,
+            format!(
+                "Allowlist warnings: {}",
+                allowlist_diag.warning_messages.join("; ")
+            ),
+            Some("Run 'dcg allowlist validate' for details".to_string()),
+        )
+    } else if allowlist_diag.layers_found == 0 {
+        (
+            DoctorCheckStatus::Warning,
+            "No allowlist files found (project or user)".to_string(),
+            Some("Use 'dcg allow <rule-id> -r \"reason\"' to create one".to_string()),
+        )
+    } else {
+        (
+            DoctorCheckStatus::Ok,
+            format!("Allowlist layers found: {}", allowlist_diag.layers_found),
+            None,
+        )
+    };
+    checks.push(DoctorCheck {
+        id: "allowlists",
+        name: "Allowlists",
+        status,
+        message,
+        remediation,
+        fixed: false,
+    });
+
+    // Checks 9-13: config sources, DCG_CONFIG, history DB, git, pack catalog
+    for check in [
+        doctor_config_sources_check(),
+        doctor_dcg_config_check(),
+        doctor_history_db_check(&config),
+        doctor_git_check(),
+        doctor_pack_catalog_check(&config),
+    ] {
+        if check.status == DoctorCheckStatus::Error {
+            issues += 1;
+        }
+        checks.push(check);
+    }
+
+    DoctorReport {
+        schema_version: DOCTOR_SCHEMA_VERSION,
+        checks,
+        issues,
+        fixed,
+        ok: issues == 0 || (fix && fixed == issues),
+    }
+}
+
+/// Print the files a config source includes, in merge order (before the source).
+fn print_config_includes(path: &std::path::Path) {
+    match crate::config::resolve_config_includes(path) {
+        Ok(includes) => {
+            for include in includes {
+                println!("  - include: {}", include.display());
+            }
+        }
+        Err(e) => println!("  - include error: {e}"),
+    }
+}
+
+/// List the config files that contribute to the effective configuration.
+fn config_source_paths() -> Vec<(&'static str, std::path::PathBuf)> {
+    let mut sources = Vec::new();
+    let system_cfg = std::path::PathBuf::from("/etc/dcg").join("config.toml");
+    if system_cfg.exists() {
+        sources.push(("system", system_cfg));
+    }
+    let user_cfg = config_path();
+    if user_cfg.exists() {
+        sources.push(("user", user_cfg));
+    }
+    if let Some(repo_root) = find_repo_root_from_cwd() {
+        let project_cfg = repo_root.join(".dcg.toml");
+        if project_cfg.exists() {
+            sources.push(("project", project_cfg));
+        }
+    }
+    sources
+}
+
+fn doctor_config_sources_check() -> DoctorCheck {
+    let mut sources = Vec::new();
+    let mut include_errors = Vec::new();
+    for (label, path) in config_source_paths() {
+        match crate::config::resolve_config_includes(&path) {
+            Ok(includes) => sources.extend(
+                includes
+                    .iter()
+                    .map(|include| format!("include: {}", include.display())),
+            ),
+            Err(e) => include_errors.push(e),
+        }
+        sources.push(format!("{label}: {}", path.display()));
+    }
+    if std::env::var_os(crate::config::ENV_CONFIG_PATH).is_some() {
+        sources.push("DCG_CONFIG".to_string());
+    }
+    let message = if sources.is_empty() {
+        "No config sources found; using built-in defaults".to_string()
+    } else {
+        format!("Config sources: {}", sources.join(", "))
+    };
+    if !include_errors.is_empty() {
+        // A cycle makes dcg ignore the whole config file; a missing include
+        // only drops that include.
+        let status = if include_errors
+            .iter()
+            .any(|e| matches!(e, crate::config::ConfigIncludeError::Cycle(_)))
+        {
+            DoctorCheckStatus::Error
+        } else {
+            DoctorCheckStatus::Warning
         };
-    checks.push(DoctorCheck {
-        id: "observe_mode",
-        name: "Observe mode",
+        let errors: Vec<String> = include_errors.iter().map(ToString::to_string).collect();
+        return DoctorCheck {
+            id: "config_sources",
+            name: "Config sources",
+            status,
+            message: format!("{message}; {}", errors.join("; ")),
+            remediation: Some(
+                "Fix the `include` list so every file exists and no file includes itself"
+                    .to_string(),
+            ),
+            fixed: false,
+        };
+    }
+    DoctorCheck {
+        id: "config_sources",
+        name: "Config sources",
+        status: DoctorCheckStatus::Ok,
+        message,
+        remediation: None,
+        fixed: false,
+    }
+}
+
+fn doctor_dcg_config_check() -> DoctorCheck {
+    let remediation = Some("Point DCG_CONFIG at a readable, valid TOML config file".to_string());
+    let (status, message, remediation) = match std::env::var(crate::config::ENV_CONFIG_PATH) {
+        Err(_) => (
+            DoctorCheckStatus::Skipped,
+            "DCG_CONFIG not set".to_string(),
+            None,
+        ),
+        Ok(value) => {
+            let cwd = std::env::current_dir().ok();
+            match crate::config::resolve_config_path_value(&value, cwd.as_deref()) {
+                None => (
+                    DoctorCheckStatus::Error,
+                    "DCG_CONFIG is set but empty".to_string(),
+                    remediation,
+                ),
+                Some(path) => match std::fs::read_to_string(&path) {
+                    Err(e) => (
+                        DoctorCheckStatus::Error,
+                        format!("DCG_CONFIG is unreadable ({}): {e}", path.display()),
+                        remediation,
+                    ),
+                    Ok(content) => match toml::from_str::<Config>(&content) {
+                        Ok(_) => (
+                            DoctorCheckStatus::Ok,
+                            format!("DCG_CONFIG valid at {}", path.display()),
+                            None,
+                        ),
+                        Err(e) => (
+                            DoctorCheckStatus::Error,
+                            format!("DCG_CONFIG has invalid TOML ({}): {e}", path.display()),
+                            remediation,
+                        ),
+                    },
+                },
+            }
+        }
+    };
+    DoctorCheck {
+        id: "dcg_config",
+        name: "DCG_CONFIG override",
         status,
         message,
         remediation,
         fixed: false,
-    });
+    }
+}
 
-    // Check 8: Allowlist discovery + validation
-    let allowlist_diag = diagnose_allowlists();
-    let (status, message, remediation) = if allowlist_diag.total_errors > 0 {
-        issues += allowlist_diag.total_errors;
-        (
-            DoctorCheckStatus::Error,
-            format!(
-                "Allowlist errors: {}",
-                allowlist_diag.error_messages.join("; ")
+fn doctor_history_db_check(config: &Config) -> DoctorCheck {
+    use crate::history::{HistoryDb, HistoryError};
+
+    let (status, message, remediation) = if config.history.enabled {
+        match HistoryDb::open(config.history.expanded_database_path()) {
+            Ok(db) => (
+                DoctorCheckStatus::Ok,
+                format!(
+                    "History database accessible at {}",
+                    db.path()
+                        .map_or_else(String::new, |p| p.display().to_string())
+                ),
+                None,
             ),
-            Some("Run 'dcg allowlist validate' for details".to_string()),
-        )
-    } else if allowlist_diag.total_warnings > 0 {
+            Err(HistoryError::Disabled) => (
+                DoctorCheckStatus::Skipped,
+                "History disabled via environment".to_string(),
+                None,
+            ),
+            Err(e) => (
+                DoctorCheckStatus::Error,
+                format!("History database not accessible: {e}"),
+                Some("Check [history] database_path and directory permissions".to_string()),
+            ),
+        }
+    } else {
         (
+            DoctorCheckStatus::Skipped,
+            "History disabled in config".to_string(),
+            None,
+        )
+    };
+    DoctorCheck {
+        id: "history_db",
+        name: "History database",
+        status,
+        message,
+        remediation,
+        fixed: false,
+    }
+}
+
+fn doctor_git_check() -> DoctorCheck {
+    let version = std::process::Command::new("git")
+        .arg("--version")
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+    let (status, message, remediation) = match version {
+        Some(version) => (DoctorCheckStatus::Ok, version, None),
+        None => (
             DoctorCheckStatus::Warning,
+            "git not found in PATH; branch-aware policies are unavailable".to_string(),
+            Some("Install git or add it to PATH".to_string()),
+        ),
+    };
+    DoctorCheck {
+        id: "git",
+        name: "Git availability",
+        status,
+        message,
+        remediation,
+        fixed: false,
+    }
+}
+
+/// Instantiate every built-in pack and compile its patterns.
+fn doctor_pack_catalog_check(config: &Config) -> DoctorCheck {
+    let mut problems = Vec::new();
+    let mut pattern_count = 0usize;
+    let pack_ids = REGISTRY.all_pack_ids();
+    for id in &pack_ids {
+        let Some(pack) = REGISTRY.get(id) else {
+            problems.push(format!("{id}: not instantiable"));
+            continue;
+        };
+        if pack.id != *id {
+            problems.push(format!("{id}: pack reports id {}", pack.id));
+        }
+        let regexes = pack
+            .safe_patterns
+            .iter()
+            .map(|p| &p.regex)
+            .chain(pack.destructive_patterns.iter().map(|p| &p.regex));
+        for regex in regexes {
+            pattern_count += 1;
+            let _ = regex.is_match("");
+            if !regex.is_compiled() {
+                problems.push(format!("{id}: invalid regex {}", regex.as_str()));
+            }
+        }
+    }
+
+    let external_store = load_external_packs(&config.packs.expand_custom_paths());
+    problems.extend(external_store.warnings().iter().cloned());
+
+    let (status, message, remediation) = if problems.is_empty() {
+        (
+            DoctorCheckStatus::Ok,
             format!(
-                "Allowlist warnings: {}",
-                allowlist_diag.warning_messages.join("; ")
+                "{} built-in packs, {pattern_count} patterns compiled",
+                pack_ids.len()
             ),
-            Some("Run 'dcg allowlist validate' for details".to_string()),
-        )
-    } else if allowlist_diag.layers_found == 0 {
-        (
-            DoctorCheckStatus::Warning,
-            "No allowlist files found (project or user)".to_string(),
-            Some("Use 'dcg allow <rule-id> -r \"reason\"' to create one".to_string()),
+            None,
         )
     } else {
         (
-            DoctorCheckStatus::Ok,
-            format!("Allowlist layers found: {}", allowlist_diag.layers_found),
-            None,
+            DoctorCheckStatus::Error,
+            format!("Pack catalog problems: {}", problems.join("; ")),
+            Some("Run 'dcg pack validate <file>' for external packs".to_string()),
         )
     };
-    checks.push(DoctorCheck {
-        id: "allowlists",
-        name: "Allowlists",
+    DoctorCheck {
+        id: "pack_catalog",
+        name: "Pack catalog",
         status,
         message,
         remediation,
         fixed: false,
-    });
-
-    DoctorReport {
-        schema_version: DOCTOR_SCHEMA_VERSION,
-        checks,
-        issues,
-        fixed,
-        ok: issues == 0 || (fix && fixed == issues),
     }
 }
 
@@ -8832,7 +11234,7 @@ fn validate_config_diagnostics() -> ConfigDiagnostics {
             diag.unknown_packs.push(pack_id.clone());
         }
     }
-    for pack_id in &config.packs.disabled {
+    for pack_id in config.packs.disabled.iter().chain(&config.packs.pack_order) {
         if !is_valid_pack_id(pack_id) {
             diag.unknown_packs.push(pack_id.clone());
         }
@@ -8889,7 +11291,8 @@ fn run_smoke_test() -> bool {
     let config = Config::load();
     let enabled_packs = config.enabled_pack_ids();
     let enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &config.packs.pack_order);
     let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
     let compiled_overrides = config.overrides.compile();
     let allowlists = crate::LayeredAllowlist::default();
@@ -8943,6 +11346,9 @@ struct AllowlistDiagnostics {
     warning_messages: Vec<String>,
 }
 
+/// `dcg doctor` warns about allowlist entries expiring within this many days.
+const ALLOWLIST_EXPIRY_WARNING_DAYS: i64 = 7;
+
 /// Diagnose allowlist health across project and user layers.
 fn diagnose_allowlists() -> AllowlistDiagnostics {
     use crate::allowlist::{AllowSelector, AllowlistLayer};
@@ -9000,6 +11406,20 @@ fn diagnose_allowlists() -> AllowlistDiagnostics {
                 }
             }
 
+            // Warn ahead of time about temporary entries that are about to lapse
+            if crate::allowlist::expires_within(
+                entry,
+                chrono::TimeDelta::days(ALLOWLIST_EXPIRY_WARNING_DAYS),
+            ) {
+                if let Some(expires) = crate::allowlist::expiration_time(entry) {
+                    diag.total_warnings += 1;
+                    diag.warning_messages.push(format!(
+                        "{layer_label}: entry {entry_num} expires soon ({})",
+                        expires.format("%Y-%m-%d %H:%M UTC")
+                    ));
+                }
+            }
+
             // Check for risky regex patterns without acknowledgement
             if matches!(entry.selector, AllowSelector::RegexPattern(_)) && !entry.risk_acknowledged
             {
@@ -9200,7 +11620,7 @@ fn handle_allow_once_command(
             "cwd": selected.cwd.clone(),
             "expires_at": entry.expires_at,
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json_string(&output)?);
         if cmd.dry_run {
             return Ok(());
         }
@@ -9267,6 +11687,74 @@ fn handle_allow_once_command(
     Ok(())
 }
 
+fn handle_approve_command(
+    config: &Config,
+    cmd: &ApproveCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let ttl = parse_duration_string(&cmd.expires)?;
+    if ttl <= chrono::Duration::zero() {
+        return Err("--expires must be a positive duration".into());
+    }
+
+    let now = Utc::now();
+    let cwd = std::env::current_dir()?;
+    let repo_root = crate::config::find_repo_root(&cwd, crate::config::REPO_ROOT_SEARCH_MAX_HOPS);
+    let (scope_kind, scope_path) = repo_root.map_or_else(
+        || (AllowOnceScopeKind::Cwd, cwd.clone()),
+        |root| (AllowOnceScopeKind::Project, root),
+    );
+    let scope_path_str = scope_path.to_string_lossy().to_string();
+
+    let entry = AllowOnceEntry::approval(
+        &cmd.command,
+        now,
+        ttl,
+        scope_kind,
+        &scope_path_str,
+        cmd.reason.as_deref().unwrap_or("approved via dcg approve"),
+        &config.logging.redaction,
+    );
+
+    AllowOnceStore::new(AllowOnceStore::default_path(Some(&cwd))).add_entry(&entry, now)?;
+
+    if let Some(log_file) = config.general.log_file.as_deref() {
+        let _ = crate::pending_exceptions::log_allow_once_action(
+            log_file,
+            "approve",
+            &format!(
+                "token={}, full_hash={}, expires_at={}",
+                entry.source_short_code, entry.source_full_hash, entry.expires_at
+            ),
+        );
+    }
+
+    if cmd.json {
+        let output = serde_json::json!({
+            "status": "ok",
+            "token": entry.source_short_code,
+            "full_hash": entry.source_full_hash,
+            "single_use": true,
+            "scope_kind": format!("{scope_kind:?}").to_lowercase(),
+            "scope_path": scope_path_str,
+            "command": entry.command_redacted,
+            "expires_at": entry.expires_at,
+        });
+        println!("{}", crate::output::to_json_string(&output)?);
+    } else {
+        println!("✓ Single-use approval created");
+        println!("  Token: {}", entry.source_short_code);
+        println!("  Command: {}", entry.command_redacted);
+        println!("  Scope: {scope_kind:?} ({scope_path_str})");
+        println!("  Expires: {}", entry.expires_at);
+        println!(
+            "  Revoke: dcg allow-once revoke {}",
+            entry.source_short_code
+        );
+    }
+
+    Ok(())
+}
+
 fn handle_allow_once_list(
     _config: &Config,
     cmd: &AllowOnceCommand,
@@ -9288,7 +11776,7 @@ fn handle_allow_once_list(
             allow_once_maintenance,
             cmd.show_raw,
         );
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json_string(&output)?);
         return Ok(());
     }
 
@@ -9491,7 +11979,7 @@ fn handle_allow_once_clear(
                 "maintenance": allow_once_maintenance,
             },
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json_string(&output)?);
         return Ok(());
     }
 
@@ -9561,7 +12049,7 @@ fn handle_allow_once_revoke(
             "pending": { "removed": pending_removed, "maintenance": pending_maintenance },
             "allow_once": { "removed": allow_once_removed, "maintenance": allow_once_maintenance },
         });
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", crate::output::to_json_string(&output)?);
         return Ok(());
     }
 
@@ -9631,42 +12119,228 @@ fn select_pending_entry<'a>(
         return Ok(&matches[0]);
     }
 
-    if let Some(hash) = cmd.hash.as_deref() {
-        let record = matches
-            .iter()
-            .find(|record| record.full_hash == hash)
-            .ok_or_else(|| format!("No pending entry with hash '{hash}'"))?;
-        return Ok(record);
+    if let Some(hash) = cmd.hash.as_deref() {
+        let record = matches
+            .iter()
+            .find(|record| record.full_hash == hash)
+            .ok_or_else(|| format!("No pending entry with hash '{hash}'"))?;
+        return Ok(record);
+    }
+
+    if let Some(pick) = cmd.pick {
+        if pick == 0 || pick > matches.len() {
+            return Err(format!("Pick must be between 1 and {}", matches.len()).into());
+        }
+        return Ok(&matches[pick - 1]);
+    }
+
+    print_pending_choices(matches, cmd.show_raw);
+    Err("Multiple pending entries share this code; use --pick or --hash.".into())
+}
+
+fn print_pending_choices(matches: &[PendingExceptionRecord], show_raw: bool) {
+    println!("Multiple pending entries match this code:");
+    for (idx, record) in matches.iter().enumerate() {
+        let display_command = if show_raw {
+            record.command_raw.as_str()
+        } else {
+            record.command_redacted.as_str()
+        };
+        println!(
+            "  {}. [{}] {} (cwd: {}, created: {})",
+            idx + 1,
+            &record.full_hash[..8.min(record.full_hash.len())],
+            display_command,
+            record.cwd,
+            record.created_at
+        );
+    }
+}
+
+// ============================================================================
+// Review queue CLI implementation
+// ============================================================================
+
+fn handle_review_command(
+    config: &Config,
+    cmd: &ReviewCommand,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let store = ReviewQueueStore::new(ReviewQueueStore::default_path(
+        &config.review_queue,
+        Some(&cwd),
+    ));
+
+    match &cmd.action {
+        Some(ReviewAction::List) => review_list(&store, cmd.json),
+        Some(ReviewAction::Approve { id }) => {
+            let entry = find_pending_review(&store, id)?;
+            review_approve(&store, &entry, cmd.user)
+        }
+        Some(ReviewAction::Reject { id }) => {
+            let entry = find_pending_review(&store, id)?;
+            store.resolve(&entry.id, ReviewStatus::Rejected, Utc::now())?;
+            println!("Rejected {} (command stays blocked)", entry.id);
+            Ok(())
+        }
+        Some(ReviewAction::Prune) => {
+            let removed = store.prune_resolved()?;
+            println!(
+                "Removed {removed} resolved review entr{}",
+                plural_y(removed)
+            );
+            Ok(())
+        }
+        None => review_interactive(&store, cmd.user),
+    }
+}
+
+const fn plural_y(count: usize) -> &'static str {
+    if count == 1 { "y" } else { "ies" }
+}
+
+fn find_pending_review(
+    store: &ReviewQueueStore,
+    id: &str,
+) -> Result<ReviewQueueEntry, Box<dyn std::error::Error>> {
+    store
+        .pending()?
+        .into_iter()
+        .find(|entry| entry.id == id)
+        .ok_or_else(|| format!("No pending review entry with id '{id}'").into())
+}
+
+fn review_list(store: &ReviewQueueStore, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let pending = store.pending()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&pending)?);
+        return Ok(());
+    }
+    if pending.is_empty() {
+        println!("No commands awaiting review.");
+        return Ok(());
+    }
+    println!("Commands awaiting review ({}):", pending.len());
+    for entry in &pending {
+        print_review_entry(entry);
+    }
+    Ok(())
+}
+
+fn print_review_entry(entry: &ReviewQueueEntry) {
+    use colored::Colorize;
+
+    println!();
+    println!("  [{}] {}", entry.id.cyan(), entry.command.bold());
+    println!("    CWD: {}", entry.cwd);
+    if let Some(rule_id) = &entry.rule_id {
+        println!("    Rule: {rule_id}");
+    }
+    println!("    Reason: {}", entry.reason);
+    println!("    Queued: {}", entry.created_at);
+    println!("    Approve adds: {}", entry.allowlist_suggestion.dimmed());
+}
+
+/// Step through pending entries, prompting approve/reject/skip for each.
+fn review_interactive(
+    store: &ReviewQueueStore,
+    force_user: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{self, Write};
+
+    if !io::stdin().is_terminal() {
+        return Err(
+            "dcg review needs an interactive terminal; use `dcg review approve|reject <ID>`".into(),
+        );
+    }
+
+    let pending = store.pending()?;
+    if pending.is_empty() {
+        println!("No commands awaiting review.");
+        return Ok(());
     }
 
-    if let Some(pick) = cmd.pick {
-        if pick == 0 || pick > matches.len() {
-            return Err(format!("Pick must be between 1 and {}", matches.len()).into());
+    for entry in &pending {
+        print_review_entry(entry);
+        loop {
+            print!("  [a]pprove / [r]eject / [s]kip / [q]uit: ");
+            io::stdout().flush()?;
+            let mut response = String::new();
+            if io::stdin().read_line(&mut response)? == 0 {
+                return Ok(());
+            }
+            match response.trim().to_lowercase().as_str() {
+                "a" | "approve" => {
+                    review_approve(store, entry, force_user)?;
+                    break;
+                }
+                "r" | "reject" => {
+                    store.resolve(&entry.id, ReviewStatus::Rejected, Utc::now())?;
+                    println!("  Rejected (command stays blocked)");
+                    break;
+                }
+                "s" | "skip" | "" => break,
+                "q" | "quit" => return Ok(()),
+                _ => println!("  Please answer a, r, s, or q."),
+            }
         }
-        return Ok(&matches[pick - 1]);
     }
+    Ok(())
+}
 
-    print_pending_choices(matches, cmd.show_raw);
-    Err("Multiple pending entries share this code; use --pick or --hash.".into())
+fn review_approve(
+    store: &ReviewQueueStore,
+    entry: &ReviewQueueEntry,
+    force_user: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use colored::Colorize;
+
+    let (layer, path) = review_allowlist_target(&entry.cwd, force_user);
+    approve_review_entry(store, entry, &path)?;
+    println!(
+        "{} Approved {}; added exact command to {} allowlist",
+        "✓".green(),
+        entry.id.cyan(),
+        layer.label()
+    );
+    println!("  File: {}", path.display());
+    Ok(())
 }
 
-fn print_pending_choices(matches: &[PendingExceptionRecord], show_raw: bool) {
-    println!("Multiple pending entries match this code:");
-    for (idx, record) in matches.iter().enumerate() {
-        let display_command = if show_raw {
-            record.command_raw.as_str()
-        } else {
-            record.command_redacted.as_str()
-        };
-        println!(
-            "  {}. [{}] {} (cwd: {}, created: {})",
-            idx + 1,
-            &record.full_hash[..8.min(record.full_hash.len())],
-            display_command,
-            record.cwd,
-            record.created_at
+/// Allowlist that receives an approval: the project allowlist of the repo the
+/// command was denied in, or the user allowlist outside a repo (or with `--user`).
+fn review_allowlist_target(cwd: &str, force_user: bool) -> (AllowlistLayer, std::path::PathBuf) {
+    if !force_user {
+        if let Some(root) = crate::config::find_repo_root(
+            std::path::Path::new(cwd),
+            crate::config::REPO_ROOT_SEARCH_MAX_HOPS,
+        ) {
+            return (
+                AllowlistLayer::Project,
+                root.join(".dcg").join("allowlist.toml"),
+            );
+        }
+    }
+    (AllowlistLayer::User, config_dir().join("allowlist.toml"))
+}
+
+/// Add the queued command to the allowlist at `path` and mark it approved.
+fn approve_review_entry(
+    store: &ReviewQueueStore,
+    entry: &ReviewQueueEntry,
+    path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut doc = load_or_create_allowlist_doc(path)?;
+    if !has_command_entry(&doc, &entry.command) {
+        let reason = entry.rule_id.as_deref().map_or_else(
+            || format!("approved in review {}", entry.id),
+            |rule_id| format!("approved in review {} ({rule_id})", entry.id),
         );
+        append_entry(&mut doc, build_command_entry(&entry.command, &reason, None));
+        write_allowlist(path, &doc)?;
     }
+    store.resolve(&entry.id, ReviewStatus::Approved, Utc::now())?;
+    Ok(())
 }
 
 /// Add a rule to the allowlist.
@@ -9882,7 +12556,7 @@ fn allowlist_list(
                 })
                 .collect();
 
-            println!("{}", serde_json::to_string_pretty(&json_entries)?);
+            println!("{}", crate::output::to_json_string(&json_entries)?);
         }
     }
 
@@ -10876,7 +13550,8 @@ fn dev_debug(config: &Config, command: &str, all_packs: bool) {
 
     // Check each pack
     println!("{}", "Pack Evaluation:".bold());
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &config.packs.pack_order);
 
     for pack_id in &ordered_packs {
         if let Some(pack) = REGISTRY.get(pack_id) {
@@ -11294,6 +13969,21 @@ mod tests {
         assert!(cli.command.is_none());
     }
 
+    #[test]
+    fn test_cli_parse_version_json() {
+        let cli = Cli::parse_from(["dcg", "version", "--json"]);
+        assert!(matches!(cli.command, Some(Command::Version { json: true })));
+    }
+
+    #[test]
+    fn version_output_reports_catalog_and_schemas() {
+        let info = VersionOutput::collect();
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.pack_count, REGISTRY.pack_count());
+        assert!(info.rule_count > info.pack_count);
+        assert_eq!(info.schema_versions.scan, crate::scan::SCAN_SCHEMA_VERSION);
+    }
+
     #[test]
     fn test_cli_parse_packs() {
         let cli = Cli::parse_from(["dcg", "packs"]);
@@ -11325,7 +14015,7 @@ mod tests {
     fn test_cli_parse_test() {
         let cli = Cli::parse_from(["dcg", "test", "git reset --hard"]);
         if let Some(Command::TestCommand { command, .. }) = cli.command {
-            assert_eq!(command, "git reset --hard");
+            assert_eq!(command.as_deref(), Some("git reset --hard"));
         } else {
             unreachable!("Expected TestCommand command");
         }
@@ -11673,6 +14363,39 @@ mod tests {
         assert_eq!(allow_array.map_or(0, toml_edit::ArrayOfTables::len), 1);
     }
 
+    #[test]
+    fn review_approval_adds_exact_command_to_allowlist() {
+        use tempfile::TempDir;
+        let temp = TempDir::new().unwrap();
+        let store = ReviewQueueStore::new(temp.path().join("review_queue.jsonl"));
+        let path = temp.path().join("allowlist.toml");
+
+        let entry = store
+            .enqueue(
+                "git reset --hard HEAD~1",
+                "/repo",
+                "destroys uncommitted changes",
+                Some("core.git:reset-hard"),
+            )
+            .unwrap();
+        approve_review_entry(&store, &entry, &path).unwrap();
+
+        let doc = load_or_create_allowlist_doc(&path).unwrap();
+        assert!(has_command_entry(&doc, "git reset --hard HEAD~1"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains(&entry.id));
+        assert!(store.pending().unwrap().is_empty());
+
+        // Re-approving the same command (queued again) does not duplicate it.
+        let again = store
+            .enqueue("git reset --hard HEAD~1", "/repo", "r", None)
+            .unwrap();
+        approve_review_entry(&store, &again, &path).unwrap();
+        let doc = load_or_create_allowlist_doc(&path).unwrap();
+        let allow_array = doc.get("allow").and_then(|v| v.as_array_of_tables());
+        assert_eq!(allow_array.map_or(0, toml_edit::ArrayOfTables::len), 1);
+    }
+
     #[test]
     fn allowlist_remove_deletes_matching_entry() {
         use tempfile::TempDir;
@@ -12082,6 +14805,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_scan_repeated_paths_and_paths_from() {
+        let cli = Cli::try_parse_from([
+            "dcg",
+            "scan",
+            "--paths",
+            "a.sh",
+            "--paths",
+            "b.sh",
+            "--paths-from",
+            "changed.txt",
+        ])
+        .expect("parse");
+        if let Some(Command::Scan(scan)) = cli.command {
+            assert_eq!(
+                scan.paths,
+                Some(vec![
+                    std::path::PathBuf::from("a.sh"),
+                    std::path::PathBuf::from("b.sh"),
+                ])
+            );
+            assert_eq!(
+                scan.paths_from,
+                Some(std::path::PathBuf::from("changed.txt"))
+            );
+        } else {
+            unreachable!("Expected Scan command");
+        }
+
+        assert!(
+            Cli::try_parse_from(["dcg", "scan", "--staged", "--paths-from", "changed.txt"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_cli_parse_scan_git_diff() {
         let cli = Cli::try_parse_from(["dcg", "scan", "--git-diff", "main..HEAD"]).expect("parse");
@@ -12117,6 +14875,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_scan_fail_only_tags() {
+        let cli = Cli::try_parse_from([
+            "dcg",
+            "scan",
+            "--staged",
+            "--fail-only-tags",
+            "data-loss,database",
+        ])
+        .expect("parse");
+        if let Some(Command::Scan(scan)) = cli.command {
+            assert_eq!(scan.fail_only_tags, vec!["data-loss", "database"]);
+        } else {
+            unreachable!("Expected Scan command");
+        }
+    }
+
     #[test]
     fn test_cli_parse_scan_max_file_size() {
         let cli = Cli::try_parse_from(["dcg", "scan", "--staged", "--max-file-size", "2048"])
@@ -12150,6 +14925,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_scan_progress() {
+        let cli =
+            Cli::try_parse_from(["dcg", "scan", "--paths", ".", "--progress"]).expect("parse");
+        if let Some(Command::Scan(scan)) = cli.command {
+            assert!(scan.progress);
+        } else {
+            unreachable!("Expected Scan command");
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_global_color() {
+        let cli = Cli::try_parse_from(["dcg", "explain", "ls"]).expect("parse");
+        assert_eq!(cli.color, crate::output::ColorChoice::Auto);
+        let cli =
+            Cli::try_parse_from(["dcg", "explain", "ls", "--color", "always"]).expect("parse");
+        assert_eq!(cli.color, crate::output::ColorChoice::Always);
+        assert!(Cli::try_parse_from(["dcg", "--color", "sometimes", "explain", "ls"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_scan_print_extracted() {
+        let cli = Cli::try_parse_from(["dcg", "scan", "--paths", ".", "--print-extracted"])
+            .expect("parse");
+        if let Some(Command::Scan(scan)) = cli.command {
+            assert!(scan.print_extracted);
+        } else {
+            unreachable!("Expected Scan command");
+        }
+    }
+
     #[test]
     fn test_cli_parse_scan_conflicts() {
         // --staged and --paths should conflict
@@ -12212,14 +15019,18 @@ mod tests {
 [scan]
 format = "json"
 fail_on = "warning"
+fail_only_tags = ["data-loss"]
+fail_on_score = 50
 max_file_size = 123
 max_findings = 5
 redact = "quoted"
 truncate = 9
+allow = ["core.filesystem:* in tests/fixtures/**"]
 
 [scan.paths]
 include = ["src/**"]
 exclude = ["target/**"]
+default_excludes = false
 "#,
         )
         .expect("parse");
@@ -12227,23 +15038,37 @@ exclude = ["target/**"]
         let settings = ScanSettingsOverrides {
             format: None,
             fail_on: None,
+            fail_only_tags: Vec::new(),
+            fail_on_score: None,
             max_file_size: None,
             max_findings: None,
             redact: None,
             truncate: None,
             include: Vec::new(),
             exclude: Vec::new(),
+            no_default_excludes: false,
+            no_gitignore: false,
+            scan_allow: Vec::new(),
         }
         .resolve(Some(&hooks));
 
         assert_eq!(settings.format, crate::scan::ScanFormat::Json);
         assert_eq!(settings.fail_on, crate::scan::ScanFailOn::Warning);
+        assert_eq!(settings.fail_only_tags, vec!["data-loss"]);
+        assert_eq!(settings.fail_on_score, Some(50.0));
         assert_eq!(settings.max_file_size, 123);
         assert_eq!(settings.max_findings, 5);
         assert_eq!(settings.redact, crate::scan::ScanRedactMode::Quoted);
         assert_eq!(settings.truncate, 9);
         assert_eq!(settings.include, vec!["src/**"]);
         assert_eq!(settings.exclude, vec!["target/**"]);
+        assert_eq!(
+            settings.scan_allow,
+            vec![
+                crate::scan::ScanAllowEntry::parse("core.filesystem:* in tests/fixtures/**")
+                    .unwrap()
+            ]
+        );
     }
 
     #[test]
@@ -12254,23 +15079,31 @@ exclude = ["target/**"]
         let settings = ScanSettingsOverrides {
             format: Some(crate::scan::ScanFormat::Pretty),
             fail_on: Some(crate::scan::ScanFailOn::Error),
+            fail_only_tags: vec!["database".to_string()],
+            fail_on_score: Some(12.5),
             max_file_size: Some(777),
             max_findings: Some(42),
             redact: Some(crate::scan::ScanRedactMode::Aggressive),
             truncate: Some(0),
             include: vec!["cli/**".to_string()],
             exclude: vec!["cli/tmp/**".to_string()],
+            no_default_excludes: true,
+            no_gitignore: true,
+            scan_allow: Vec::new(),
         }
         .resolve(Some(&hooks));
 
         assert_eq!(settings.format, crate::scan::ScanFormat::Pretty);
         assert_eq!(settings.fail_on, crate::scan::ScanFailOn::Error);
+        assert_eq!(settings.fail_only_tags, vec!["database"]);
+        assert_eq!(settings.fail_on_score, Some(12.5));
         assert_eq!(settings.max_file_size, 777);
         assert_eq!(settings.max_findings, 42);
         assert_eq!(settings.redact, crate::scan::ScanRedactMode::Aggressive);
         assert_eq!(settings.truncate, 0);
         assert_eq!(settings.include, vec!["cli/**"]);
         assert_eq!(settings.exclude, vec!["cli/tmp/**"]);
+        assert!(!settings.respect_gitignore);
     }
 
     #[test]
@@ -12278,12 +15111,17 @@ exclude = ["target/**"]
         let settings = ScanSettingsOverrides {
             format: None,
             fail_on: None,
+            fail_only_tags: Vec::new(),
+            fail_on_score: None,
             max_file_size: None,
             max_findings: None,
             redact: None,
             truncate: None,
             include: Vec::new(),
             exclude: Vec::new(),
+            no_default_excludes: false,
+            no_gitignore: false,
+            scan_allow: Vec::new(),
         }
         .resolve(None);
 
@@ -12294,7 +15132,8 @@ exclude = ["target/**"]
         assert_eq!(settings.redact, crate::scan::ScanRedactMode::None);
         assert_eq!(settings.truncate, 200);
         assert!(settings.include.is_empty());
-        assert!(settings.exclude.is_empty());
+        assert_eq!(settings.exclude, crate::scan::DEFAULT_SCAN_EXCLUDES);
+        assert!(settings.respect_gitignore);
     }
 
     // ========================================================================
@@ -12396,6 +15235,119 @@ exclude = ["target/**"]
         }
     }
 
+    #[test]
+    fn test_cli_parse_telemetry_prune_alias() {
+        let cli = Cli::try_parse_from([
+            "dcg",
+            "telemetry",
+            "prune",
+            "--older-than",
+            "30d",
+            "--max-rows",
+            "5000",
+            "--yes",
+        ])
+        .expect("parse");
+        let Some(Command::History {
+            action:
+                HistoryAction::Prune {
+                    older_than_days,
+                    older_than,
+                    max_rows,
+                    yes,
+                    ..
+                },
+        }) = cli.command
+        else {
+            unreachable!("Expected History prune action");
+        };
+        assert_eq!(older_than_days, None);
+        assert_eq!(older_than.as_deref(), Some("30d"));
+        assert_eq!(max_rows, Some(5000));
+        assert!(yes);
+
+        assert!(Cli::try_parse_from(["dcg", "history", "prune", "--yes"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "dcg",
+                "history",
+                "prune",
+                "--older-than",
+                "30d",
+                "--older-than-days",
+                "30",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_format_history_search_pretty_shows_timestamp_outcome_and_dir() {
+        use chrono::TimeZone;
+
+        let entry = crate::history::CommandEntry {
+            timestamp: chrono::Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap(),
+            working_dir: "/work/app".to_string(),
+            command: "rm -rf ./build".to_string(),
+            outcome: crate::history::Outcome::Deny,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            format_history_search_pretty(&[entry]),
+            "2026-03-04 05:06:07  deny         /work/app  rm -rf ./build\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_export_datetime_accepts_dates_and_timestamps() {
+        use chrono::TimeZone;
+
+        let midnight = chrono::Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(parse_export_datetime("2024-01-01", "since"), Ok(midnight));
+        assert_eq!(
+            parse_export_datetime("2024-01-01T00:00:00Z", "since"),
+            Ok(midnight)
+        );
+        assert_eq!(
+            parse_export_datetime("2024-01-01T02:00:00+02:00", "since"),
+            Ok(midnight)
+        );
+        assert_eq!(
+            parse_export_datetime("2024-01-01 00:00:00", "since"),
+            Ok(midnight)
+        );
+        let err = parse_export_datetime("last tuesday", "until").unwrap_err();
+        assert!(err.contains("Invalid until datetime"));
+    }
+
+    #[test]
+    fn test_rule_metrics_json_includes_all_time_timeline() {
+        use chrono::TimeZone;
+
+        let first_seen = chrono::Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let last_seen = chrono::Utc.with_ymd_and_hms(2026, 6, 7, 8, 9, 10).unwrap();
+        let timeline = vec![crate::history::RuleTimeline {
+            rule_id: "core.git:reset-hard".to_string(),
+            first_seen,
+            last_seen,
+            total_hits: 42,
+        }];
+
+        let json = format_rule_metrics_json(&[], &timeline, 30).expect("json");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(value["period_days"], 30);
+        assert_eq!(value["timeline"][0]["rule_id"], "core.git:reset-hard");
+        assert_eq!(value["timeline"][0]["first_seen"], first_seen.to_rfc3339());
+        assert_eq!(value["timeline"][0]["last_seen"], last_seen.to_rfc3339());
+        assert_eq!(value["timeline"][0]["total_hits"], 42);
+
+        let pretty = format_rule_timeline_pretty(&timeline);
+        assert!(pretty.contains("Rule Timeline (all time)"));
+        assert!(pretty.contains("2025-01-02 03:04"));
+        assert!(pretty.contains("2026-06-07 08:09"));
+    }
+
     #[test]
     fn test_cli_parse_explain() {
         let cli = Cli::try_parse_from(["dcg", "explain", "git reset --hard"]).expect("parse");
@@ -12403,14 +15355,114 @@ exclude = ["target/**"]
             command,
             format,
             with_packs,
+            context,
+            branch,
+            min_severity,
+            all_matches,
+            output,
+            print_schema,
+            compare,
         }) = cli.command
         {
-            assert_eq!(command, "git reset --hard");
+            assert_eq!(command.as_deref(), Some("git reset --hard"));
             assert_eq!(format, ExplainFormat::Pretty);
             assert!(with_packs.is_none());
+            assert!(context.is_none());
+            assert!(branch.is_none());
+            assert!(min_severity.is_none());
+            assert!(!all_matches);
+            assert!(output.is_none());
+            assert!(!print_schema);
+            assert!(compare.is_none());
+        } else {
+            unreachable!("Expected Explain command");
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_explain_print_schema_needs_no_command() {
+        let cli = Cli::try_parse_from(["dcg", "explain", "--print-schema"]).expect("parse");
+        if let Some(Command::Explain {
+            command,
+            print_schema,
+            ..
+        }) = cli.command
+        {
+            assert!(command.is_none());
+            assert!(print_schema);
+        } else {
+            unreachable!("Expected Explain command");
+        }
+
+        assert!(Cli::try_parse_from(["dcg", "explain"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_explain_all_matches() {
+        let cli = Cli::try_parse_from(["dcg", "explain", "--all-matches", "docker compose down"])
+            .expect("parse");
+        if let Some(Command::Explain { all_matches, .. }) = cli.command {
+            assert!(all_matches);
+        } else {
+            unreachable!("Expected Explain command");
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_explain_compare() {
+        let cli = Cli::try_parse_from([
+            "dcg",
+            "explain",
+            "--compare",
+            "git reset --hard",
+            "git reset --soft",
+        ])
+        .expect("parse");
+        if let Some(Command::Explain {
+            command, compare, ..
+        }) = cli.command
+        {
+            assert_eq!(command, None);
+            assert_eq!(
+                compare,
+                Some(vec![
+                    "git reset --hard".to_string(),
+                    "git reset --soft".to_string()
+                ])
+            );
+        } else {
+            unreachable!("Expected Explain command");
+        }
+
+        assert!(Cli::try_parse_from(["dcg", "explain", "--compare", "git status"]).is_err());
+        assert!(
+            Cli::try_parse_from(["dcg", "explain", "--compare", "a", "b", "--all-matches"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_parse_min_severity() {
+        let cli = Cli::try_parse_from(["dcg", "test", "--min-severity", "high", "git checkout ."])
+            .expect("parse");
+        if let Some(Command::TestCommand { min_severity, .. }) = cli.command {
+            assert_eq!(min_severity, Some(MinSeverity::High));
+        } else {
+            unreachable!("Expected TestCommand command");
+        }
+
+        let cli = Cli::try_parse_from(["dcg", "explain", "--min-severity", "critical", "ls"])
+            .expect("parse");
+        if let Some(Command::Explain { min_severity, .. }) = cli.command {
+            assert_eq!(
+                min_severity.map(MinSeverity::severity),
+                Some(PackSeverity::Critical)
+            );
         } else {
             unreachable!("Expected Explain command");
         }
+
+        assert!(Cli::try_parse_from(["dcg", "test", "--min-severity", "severe", "ls"]).is_err());
     }
 
     #[test]
@@ -12422,13 +15474,36 @@ exclude = ["target/**"]
             command, format, ..
         }) = cli.command
         {
-            assert_eq!(command, "docker system prune");
+            assert_eq!(command.as_deref(), Some("docker system prune"));
             assert_eq!(format, ExplainFormat::Json);
         } else {
             unreachable!("Expected Explain command");
         }
     }
 
+    #[test]
+    fn test_cli_parse_explain_with_context_and_branch() {
+        let cli = Cli::try_parse_from([
+            "dcg",
+            "explain",
+            "--context",
+            "/srv/repo",
+            "--branch",
+            "release/1.0",
+            "git push --force",
+        ])
+        .expect("parse");
+        if let Some(Command::Explain {
+            context, branch, ..
+        }) = cli.command
+        {
+            assert_eq!(context, Some(std::path::PathBuf::from("/srv/repo")));
+            assert_eq!(branch.as_deref(), Some("release/1.0"));
+        } else {
+            unreachable!("Expected Explain command");
+        }
+    }
+
     #[test]
     fn test_cli_parse_test_with_explain_flag() {
         let cli =
@@ -12440,7 +15515,7 @@ exclude = ["target/**"]
             ..
         }) = cli.command
         {
-            assert_eq!(command, "git reset --hard");
+            assert_eq!(command.as_deref(), Some("git reset --hard"));
             assert!(explain);
             assert_eq!(format, TestFormat::Pretty); // default format
         } else {
@@ -12456,7 +15531,7 @@ exclude = ["target/**"]
             command, format, ..
         }) = cli.command
         {
-            assert_eq!(command, "rm -rf /tmp");
+            assert_eq!(command.as_deref(), Some("rm -rf /tmp"));
             assert_eq!(format, TestFormat::Json);
         } else {
             unreachable!("Expected TestCommand");
@@ -12473,7 +15548,7 @@ exclude = ["target/**"]
             ..
         }) = cli.command
         {
-            assert_eq!(command, "git status");
+            assert_eq!(command.as_deref(), Some("git status"));
             assert!(!explain);
             assert_eq!(format, TestFormat::Pretty); // default
         } else {
@@ -12743,6 +15818,45 @@ exclude = ["target/**"]
         );
     }
 
+    #[test]
+    fn scan_markdown_renders_summary_locations_and_suggestions() {
+        use crate::scan::{ScanDecision, ScanFinding, ScanSeverity, build_report};
+
+        let finding = ScanFinding {
+            file: "scripts/deploy.sh".to_string(),
+            line: 12,
+            col: None,
+            extractor_id: "shell.script".to_string(),
+            extracted_command: "git reset --hard HEAD~1".to_string(),
+            normalized_command: None,
+            metadata: None,
+            decision: ScanDecision::Deny,
+            severity: ScanSeverity::Error,
+            rule_id: Some("core.git:reset-hard".to_string()),
+            reason: Some("destroys uncommitted changes".to_string()),
+            reason_code: crate::packs::ReasonCode::Unspecified,
+            suggestion: Some("git stash && git reset --hard HEAD~1".to_string()),
+            confidence: None,
+            suppression: None,
+            owner: None,
+        };
+        let report = build_report(vec![finding], 3, 0, 5, false, None);
+        let out = format_scan_markdown(&report, 0, 0);
+
+        assert!(out.contains(":x: **DENY** at `scripts/deploy.sh:12`"));
+        assert!(out.contains("- **Rule:** `core.git:reset-hard`"));
+        assert!(
+            out.contains(":bulb: **Suggestion:**\n```\ngit stash && git reset --hard HEAD~1\n```")
+        );
+        assert!(out.contains("| Files scanned | 3 |"));
+        assert!(out.contains("| By decision | 1 deny, 0 warn, 0 allow |"));
+        assert!(out.contains("| By severity | 1 error, 0 warning, 0 info |"));
+
+        let clean = format_scan_markdown(&build_report(Vec::new(), 3, 0, 5, false, None), 0, 0);
+        assert!(clean.contains("**No findings**"));
+        assert!(!clean.contains("By decision"));
+    }
+
     #[test]
     fn cli_parse_scan_format_markdown() {
         let cli = Cli::try_parse_from(["dcg", "scan", "--staged", "--format", "markdown"])
@@ -12754,6 +15868,17 @@ exclude = ["target/**"]
         }
     }
 
+    #[test]
+    fn cli_parse_scan_format_csv() {
+        let cli =
+            Cli::try_parse_from(["dcg", "scan", "--staged", "--format", "csv"]).expect("parse");
+        if let Some(Command::Scan(scan)) = cli.command {
+            assert_eq!(scan.format, Some(crate::scan::ScanFormat::Csv));
+        } else {
+            unreachable!("Expected Scan command");
+        }
+    }
+
     // ==========================================================================
     // Doctor diagnostics tests (git_safety_guard-1gt.7.1)
     // ==========================================================================
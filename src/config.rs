@@ -61,6 +61,9 @@ pub struct Config {
     /// Heredoc/inline-script scanning configuration.
     pub heredoc: HeredocConfig,
 
+    /// Heuristic pack tuning (e.g. `heuristic.destructive_verbs`).
+    pub heuristics: HeuristicsConfig,
+
     /// Confidence scoring configuration for ambiguous matches.
     pub confidence: ConfidenceConfig,
 
@@ -76,6 +79,12 @@ pub struct Config {
     /// Git branch-aware strictness configuration.
     pub git_awareness: GitAwarenessConfig,
 
+    /// Per-branch decision mode overrides (branch glob => mode).
+    pub branch_policy: BranchPolicyConfig,
+
+    /// Review queue for denied commands.
+    pub review_queue: crate::review_queue::ReviewQueueConfig,
+
     /// Agent-specific profiles configuration.
     #[serde(default)]
     pub agents: AgentsConfig,
@@ -103,6 +112,8 @@ pub struct Config {
 
 #[derive(Debug, Clone, Default, Deserialize)]
 struct ConfigLayer {
+    /// Other config files to merge first, relative to this file.
+    include: Option<Vec<String>>,
     general: Option<GeneralConfigLayer>,
     output: Option<OutputConfigLayer>,
     theme: Option<ThemeConfigLayer>,
@@ -110,11 +121,14 @@ struct ConfigLayer {
     policy: Option<PolicyConfig>,
     overrides: Option<OverridesConfig>,
     heredoc: Option<HeredocConfig>,
+    heuristics: Option<HeuristicsConfig>,
     confidence: Option<ConfidenceConfigLayer>,
     logging: Option<LoggingConfigLayer>,
     history: Option<HistoryConfigLayer>,
     interactive: Option<InteractiveConfigLayer>,
     git_awareness: Option<GitAwarenessConfigLayer>,
+    branch_policy: Option<BranchPolicyConfig>,
+    review_queue: Option<ReviewQueueConfigLayer>,
     agents: Option<AgentsConfig>,
     projects: Option<std::collections::HashMap<String, ProjectConfig>>,
 }
@@ -125,6 +139,7 @@ struct GeneralConfigLayer {
     log_file: Option<String>,
     verbose: Option<bool>,
     check_updates: Option<bool>,
+    #[serde(alias = "eval_timeout_ms")]
     hook_timeout_ms: Option<u64>,
     max_hook_input_bytes: Option<usize>,
     max_command_bytes: Option<usize>,
@@ -163,6 +178,12 @@ struct HistoryConfigLayer {
     database_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ReviewQueueConfigLayer {
+    enabled: Option<bool>,
+    path: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 struct InteractiveConfigLayer {
     enabled: Option<bool>,
@@ -206,6 +227,7 @@ struct GitAwarenessConfigLayer {
     relaxed_strictness: Option<StrictnessLevel>,
     default_strictness: Option<StrictnessLevel>,
     warn_if_not_git: Option<bool>,
+    probe_worktree: Option<bool>,
 }
 
 fn expand_tilde_path(value: &str) -> (PathBuf, bool) {
@@ -247,6 +269,155 @@ pub(crate) fn resolve_config_path_value(value: &str, cwd: Option<&Path>) -> Opti
     Some(path)
 }
 
+/// Maximum `include` nesting depth for config files.
+const MAX_CONFIG_INCLUDE_DEPTH: usize = 16;
+
+/// Problem found while following a config file's `include` list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigIncludeError {
+    /// The include chain returns to a file already being loaded.
+    Cycle(Vec<PathBuf>),
+    /// An included file does not exist.
+    Missing {
+        path: PathBuf,
+        included_from: PathBuf,
+    },
+    /// Includes are nested deeper than [`MAX_CONFIG_INCLUDE_DEPTH`].
+    TooDeep(PathBuf),
+}
+
+impl std::fmt::Display for ConfigIncludeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cycle(chain) => {
+                let chain = chain
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                write!(f, "config include cycle: {chain}")
+            }
+            Self::Missing {
+                path,
+                included_from,
+            } => write!(
+                f,
+                "included config file '{}' not found (included from '{}')",
+                path.display(),
+                included_from.display()
+            ),
+            Self::TooDeep(path) => write!(
+                f,
+                "config includes nested deeper than {MAX_CONFIG_INCLUDE_DEPTH} levels at '{}'",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigIncludeError {}
+
+/// A config file and the files it includes, in merge order.
+struct ConfigLayerChain {
+    /// Included files first, the root file last (so it wins).
+    layers: Vec<(PathBuf, ConfigLayer)>,
+    errors: Vec<ConfigIncludeError>,
+}
+
+impl ConfigLayerChain {
+    /// Load `root` and follow its `include` lists depth-first.
+    ///
+    /// Returns None when `root` itself is missing or invalid. Includes that
+    /// are missing or nest too deeply are skipped and reported in `errors`; a
+    /// file included twice is merged once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigIncludeError::Cycle`] when the includes form a cycle.
+    fn load(root: &Path) -> Result<Option<Self>, ConfigIncludeError> {
+        let mut chain = Self {
+            layers: Vec::new(),
+            errors: Vec::new(),
+        };
+        let mut stack = Vec::new();
+        let mut seen = HashSet::new();
+        if !chain.visit(root, &mut stack, &mut seen) {
+            return Ok(None);
+        }
+        if let Some(index) = chain
+            .errors
+            .iter()
+            .position(|error| matches!(error, ConfigIncludeError::Cycle(_)))
+        {
+            return Err(chain.errors.swap_remove(index));
+        }
+        Ok(Some(chain))
+    }
+
+    fn visit(
+        &mut self,
+        path: &Path,
+        stack: &mut Vec<PathBuf>,
+        seen: &mut HashSet<PathBuf>,
+    ) -> bool {
+        let Some(mut layer) = Config::load_layer_from_file(path) else {
+            return false;
+        };
+        let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        seen.insert(key.clone());
+        stack.push(key);
+
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        for value in layer.include.take().unwrap_or_default() {
+            let Some(target) = resolve_config_path_value(&value, Some(base_dir)) else {
+                continue;
+            };
+            let target_key = fs::canonicalize(&target).unwrap_or_else(|_| target.clone());
+            if let Some(start) = stack.iter().position(|entry| *entry == target_key) {
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(target_key);
+                self.errors.push(ConfigIncludeError::Cycle(cycle));
+            } else if seen.contains(&target_key) {
+                // Already merged through another include (diamond); merge once.
+            } else if stack.len() >= MAX_CONFIG_INCLUDE_DEPTH {
+                self.errors.push(ConfigIncludeError::TooDeep(target));
+            } else if !target.exists() {
+                self.errors.push(ConfigIncludeError::Missing {
+                    path: target,
+                    included_from: path.to_path_buf(),
+                });
+            } else {
+                self.visit(&target, stack, seen);
+            }
+        }
+
+        stack.pop();
+        self.layers.push((path.to_path_buf(), layer));
+        true
+    }
+}
+
+/// List the files `path` includes (directly or transitively), in merge order.
+///
+/// The root file itself is not part of the result. A missing or invalid root
+/// yields an empty list.
+///
+/// # Errors
+///
+/// Returns the first include problem found: a cycle, a missing included file,
+/// or nesting deeper than the supported limit.
+pub fn resolve_config_includes(path: &Path) -> Result<Vec<PathBuf>, ConfigIncludeError> {
+    let Some(chain) = ConfigLayerChain::load(path)? else {
+        return Ok(Vec::new());
+    };
+    if let Some(error) = chain.errors.into_iter().next() {
+        return Err(error);
+    }
+    let mut files: Vec<PathBuf> = chain.layers.into_iter().map(|(file, _)| file).collect();
+    files.pop();
+    Ok(files)
+}
+
 /// Find the git repo root by searching for a `.git` directory upwards from `start_dir`.
 ///
 /// This search is bounded by `max_hops` to avoid unbounded filesystem traversal in
@@ -309,6 +480,12 @@ pub struct HeredocConfig {
     /// Fail-open when extraction/parsing exceeds the timeout budget.
     pub fallback_on_timeout: Option<bool>,
 
+    /// Extra Tier 1 trigger phrases for in-house interpreters (e.g. `"mylang -e"`).
+    ///
+    /// These extend the built-in trigger set; a quoted argument following a
+    /// phrase is extracted as inline script content.
+    pub custom_triggers: Option<Vec<String>>,
+
     /// Content-based allowlist for heredocs (patterns, hashes, commands).
     pub allowlist: Option<HeredocAllowlistConfig>,
 }
@@ -323,6 +500,8 @@ pub struct HeredocSettings {
     pub fallback_on_timeout: bool,
     /// Content-based allowlist for heredocs (patterns, hashes, commands).
     pub content_allowlist: Option<HeredocAllowlistConfig>,
+    /// User-configured trigger phrases in addition to the built-in set.
+    pub custom_triggers: Option<crate::heredoc::CustomTriggers>,
 }
 
 impl Default for HeredocSettings {
@@ -334,6 +513,7 @@ impl Default for HeredocSettings {
             fallback_on_parse_error: true,
             fallback_on_timeout: true,
             content_allowlist: None,
+            custom_triggers: None,
         }
     }
 }
@@ -545,6 +725,10 @@ impl HeredocConfig {
             fallback_on_parse_error: self.fallback_on_parse_error.unwrap_or(true),
             fallback_on_timeout: self.fallback_on_timeout.unwrap_or(true),
             content_allowlist: self.allowlist.clone(),
+            custom_triggers: self
+                .custom_triggers
+                .as_deref()
+                .and_then(crate::heredoc::CustomTriggers::new),
         }
     }
 }
@@ -780,8 +964,9 @@ pub struct GeneralConfig {
     /// Whether to show verbose output.
     pub verbose: bool,
 
-    /// Hook evaluation budget override in milliseconds.
-    /// When set, overrides the default hook evaluation budget.
+    /// Hook evaluation budget override in milliseconds (alias: `eval_timeout_ms`).
+    /// When set, overrides the default hook evaluation budget; evaluations
+    /// that overrun it fail open and are recorded with a `timeout` outcome.
     pub hook_timeout_ms: Option<u64>,
 
     /// Maximum bytes to read from stdin in hook mode.
@@ -927,6 +1112,37 @@ pub struct PacksConfig {
     /// loading valid packs.
     #[serde(default)]
     pub custom_paths: Vec<String>,
+
+    /// Packs (or categories) to evaluate first, in this order.
+    ///
+    /// When several packs match the same command, the first in evaluation
+    /// order decides the reported rule, reason, and severity. Listed packs
+    /// run before all others; unlisted packs keep the default tier order.
+    #[serde(default)]
+    pub pack_order: Vec<String>,
+}
+
+/// Heuristic pack configuration.
+///
+/// Heuristic packs are opt-in (enable them under `[packs]`); this section only
+/// tunes what they look for.
+///
+/// ```toml
+/// [packs]
+/// enabled = ["heuristic.destructive_verbs"]
+///
+/// [heuristics]
+/// # Replaces the default verb list
+/// destructive_verbs = ["delete", "destroy", "purge", "wipe", "drop", "truncate", "nuke", "--force", "-rf"]
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeuristicsConfig {
+    /// Verbs checked by `heuristic.destructive_verbs` (replaces the defaults when set).
+    ///
+    /// Entries starting with `-` must match an argument exactly (`--force`);
+    /// other entries match a whole word inside an argument (`purge` in `purge-all`).
+    pub destructive_verbs: Option<Vec<String>>,
 }
 
 impl PacksConfig {
@@ -1088,6 +1304,32 @@ impl PolicyConfig {
         pack_id: Option<&str>,
         pattern_name: Option<&str>,
         severity: Option<crate::packs::Severity>,
+    ) -> crate::packs::DecisionMode {
+        self.resolve_mode_for_branch_at(now, pack_id, pattern_name, severity, None)
+    }
+
+    /// Resolve the effective decision mode, applying a `[branch_policy]` mode.
+    ///
+    /// The branch mode sits between rule-specific and pack-specific overrides:
+    /// an explicit `rules` entry still wins, and Critical rules stay `deny`.
+    #[must_use]
+    pub fn resolve_mode_for_branch(
+        &self,
+        pack_id: Option<&str>,
+        pattern_name: Option<&str>,
+        severity: Option<crate::packs::Severity>,
+        branch_mode: Option<PolicyMode>,
+    ) -> crate::packs::DecisionMode {
+        self.resolve_mode_for_branch_at(Utc::now(), pack_id, pattern_name, severity, branch_mode)
+    }
+
+    fn resolve_mode_for_branch_at(
+        &self,
+        now: DateTime<Utc>,
+        pack_id: Option<&str>,
+        pattern_name: Option<&str>,
+        severity: Option<crate::packs::Severity>,
+        branch_mode: Option<PolicyMode>,
     ) -> crate::packs::DecisionMode {
         // 1. Rule-specific override
         if let (Some(pack), Some(pattern)) = (pack_id, pattern_name) {
@@ -1103,6 +1345,11 @@ impl PolicyConfig {
             return crate::packs::DecisionMode::Deny;
         }
 
+        // Branch-specific override (from `[branch_policy]`)
+        if let Some(mode) = branch_mode {
+            return mode.to_decision_mode();
+        }
+
         // 2. Pack-specific override
         if let Some(pack) = pack_id {
             if let Some(mode) = self.packs.get(pack) {
@@ -1132,6 +1379,52 @@ impl PolicyConfig {
     }
 }
 
+/// Per-branch decision mode overrides.
+///
+/// Maps a branch pattern to the mode used for matched rules while that branch
+/// is checked out. Patterns use the same syntax as `git_awareness` branch lists
+/// (`main`, `release/*`, `*/hotfix`, `*`).
+///
+/// ```toml
+/// [branch_policy]
+/// "main" = "deny"        # strict: warn/log rules block too
+/// "release/*" = "deny"
+/// "feature/*" = "warn"
+/// ```
+///
+/// Detached HEAD and directories outside a git repository use the normal
+/// policy resolution.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BranchPolicyConfig {
+    /// Branch pattern => mode.
+    pub branches: std::collections::HashMap<String, PolicyMode>,
+}
+
+impl BranchPolicyConfig {
+    /// Returns `true` if no branch policies are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty()
+    }
+
+    /// Find the policy entry that applies to `branch`.
+    ///
+    /// An exact branch name wins over glob patterns; among globs the longest
+    /// (most specific) pattern wins. Returns the matching pattern and its mode.
+    #[must_use]
+    pub fn mode_for_branch(&self, branch: &str) -> Option<(&str, PolicyMode)> {
+        if let Some((pattern, mode)) = self.branches.get_key_value(branch) {
+            return Some((pattern.as_str(), *mode));
+        }
+        self.branches
+            .iter()
+            .filter(|(pattern, _)| GitAwarenessConfig::branch_matches_pattern(branch, pattern))
+            .max_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+            .map(|(pattern, mode)| (pattern.as_str(), *mode))
+    }
+}
+
 /// Custom pattern overrides.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
@@ -1741,6 +2034,9 @@ impl std::fmt::Display for StrictnessLevel {
 ///
 /// # Show branch context in output
 /// show_branch_in_output = true
+///
+/// # Check `git status` before blocking `git reset --hard` (shells out to git)
+/// probe_worktree = false
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -1785,6 +2081,14 @@ pub struct GitAwarenessConfig {
     /// evaluated using default strictness (graceful degradation).
     /// Default: `false`
     pub warn_if_not_git: bool,
+
+    /// Probe the worktree with `git status --porcelain` before blocking
+    /// `git reset --hard`.
+    /// A clean worktree downgrades the block to a warning; a dirty one keeps
+    /// the deny and reports how many files would be lost. Opt-in because it
+    /// runs `git` on every matching command. Independent of `enabled`.
+    /// Default: `false`
+    pub probe_worktree: bool,
 }
 
 impl Default for GitAwarenessConfig {
@@ -1808,6 +2112,7 @@ impl Default for GitAwarenessConfig {
             relaxed_disabled_packs: Vec::new(),
             show_branch_in_output: true,
             warn_if_not_git: false,
+            probe_worktree: false,
         }
     }
 }
@@ -2391,20 +2696,30 @@ impl Config {
     /// 6. Compiled defaults
     #[must_use]
     pub fn load() -> Self {
+        Self::load_for_dir(env::current_dir().ok().as_deref())
+    }
+
+    /// Load configuration as if dcg were running in `dir`.
+    ///
+    /// Identical to [`Config::load`], except the project config (`.dcg.toml`)
+    /// and relative `DCG_CONFIG` paths are resolved from `dir` instead of the
+    /// process working directory.
+    #[must_use]
+    pub fn load_for_dir(dir: Option<&Path>) -> Self {
         // Start with truly empty defaults - packs must be explicitly enabled.
         // generate_default() is for sample configs shown to users, not runtime defaults.
         let mut config = Self::default();
-        let cwd = env::current_dir().ok();
+        let cwd = dir;
 
         // Optional explicit config path override (highest-priority file config).
         let explicit_layer = env::var(ENV_CONFIG_PATH)
             .ok()
-            .and_then(|value| resolve_config_path_value(&value, cwd.as_deref()))
-            .and_then(|path| Self::load_layer_from_file(&path));
+            .and_then(|value| resolve_config_path_value(&value, cwd))
+            .and_then(|path| Self::load_layer_chain(&path));
 
         // Load system config (lowest priority of file configs)
         if let Some(system_config) = Self::load_system_config_layer() {
-            config.merge_layer(system_config);
+            config.merge_layer_chain(system_config);
         }
 
         // Load user config
@@ -2414,18 +2729,18 @@ impl Config {
         // reduce layering confusion.
         if explicit_layer.is_none() {
             if let Some(user_config) = Self::load_user_config_layer() {
-                config.merge_layer(user_config);
+                config.merge_layer_chain(user_config);
             }
         }
 
         // Load project config (if in a git repo)
-        if let Some(project_config) = Self::load_project_config_layer_from(cwd.as_deref()) {
-            config.merge_layer(project_config);
+        if let Some(project_config) = Self::load_project_config_layer_from(cwd) {
+            config.merge_layer_chain(project_config);
         }
 
         // Apply explicit config last among file configs (if present and valid).
         if let Some(explicit_layer) = explicit_layer {
-            config.merge_layer(explicit_layer);
+            config.merge_layer_chain(explicit_layer);
         }
 
         // Apply environment variable overrides (highest priority)
@@ -2466,6 +2781,20 @@ impl Config {
         }
     }
 
+    /// Load a config file together with the files it includes.
+    ///
+    /// A file whose includes form a cycle is rejected as a whole, like a file
+    /// that fails to parse, so a half-applied include graph never takes effect.
+    fn load_layer_chain(path: &Path) -> Option<ConfigLayerChain> {
+        match ConfigLayerChain::load(path) {
+            Ok(chain) => chain,
+            Err(e) => {
+                eprintln!("Error: {e}; ignoring config file '{}'", path.display());
+                None
+            }
+        }
+    }
+
     /// Load configuration from a specific file.
     #[must_use]
     pub fn load_from_file(path: &Path) -> Option<Self> {
@@ -2474,9 +2803,9 @@ impl Config {
     }
 
     /// Load system-wide configuration.
-    fn load_system_config_layer() -> Option<ConfigLayer> {
+    fn load_system_config_layer() -> Option<ConfigLayerChain> {
         let path = PathBuf::from("/etc/dcg").join(CONFIG_FILE_NAME);
-        Self::load_layer_from_file(&path)
+        Self::load_layer_chain(&path)
     }
 
     /// Load user configuration.
@@ -2484,14 +2813,14 @@ impl Config {
     /// Checks both XDG-style (`~/.config/dcg/`) and platform-native paths.
     /// This ensures users can use `~/.config/dcg/config.toml` on all platforms,
     /// including macOS where `dirs::config_dir()` returns `~/Library/Application Support`.
-    fn load_user_config_layer() -> Option<ConfigLayer> {
+    fn load_user_config_layer() -> Option<ConfigLayerChain> {
         // First try XDG-style path (~/.config/dcg/config.toml)
         // This is what users expect and works consistently across platforms
         if let Some(home) = dirs::home_dir() {
             let xdg_path = home.join(".config").join("dcg").join(CONFIG_FILE_NAME);
             if xdg_path.exists() {
-                if let Some(layer) = Self::load_layer_from_file(&xdg_path) {
-                    return Some(layer);
+                if let Some(chain) = Self::load_layer_chain(&xdg_path) {
+                    return Some(chain);
                 }
             }
         }
@@ -2499,18 +2828,31 @@ impl Config {
         // Fall back to platform-native path (e.g., ~/Library/Application Support/dcg/ on macOS)
         let config_dir = dirs::config_dir()?;
         let path = config_dir.join("dcg").join(CONFIG_FILE_NAME);
-        Self::load_layer_from_file(&path)
+        Self::load_layer_chain(&path)
     }
 
     /// Load project-level configuration (`.dcg.toml` in repo root).
-    fn load_project_config_layer_from(start_dir: Option<&Path>) -> Option<ConfigLayer> {
+    fn load_project_config_layer_from(start_dir: Option<&Path>) -> Option<ConfigLayerChain> {
         let start_dir = start_dir?;
         let repo_root = find_repo_root(start_dir, REPO_ROOT_SEARCH_MAX_HOPS)?;
         let config_path = repo_root.join(PROJECT_CONFIG_NAME);
         if !config_path.exists() {
             return None;
         }
-        Self::load_layer_from_file(&config_path)
+        Self::load_layer_chain(&config_path)
+    }
+
+    /// Merge a config file and its includes (included files first).
+    ///
+    /// Missing or too deeply nested includes are reported as warnings and
+    /// skipped, matching how unreadable config files are handled.
+    fn merge_layer_chain(&mut self, chain: ConfigLayerChain) {
+        for error in &chain.errors {
+            eprintln!("Warning: {error}; skipping include");
+        }
+        for (_, layer) in chain.layers {
+            self.merge_layer(layer);
+        }
     }
 
     /// Merge another config layer into this one (other takes priority when set).
@@ -2543,6 +2885,10 @@ impl Config {
             self.merge_heredoc_layer(heredoc);
         }
 
+        if let Some(heuristics) = other.heuristics {
+            self.merge_heuristics_layer(heuristics);
+        }
+
         if let Some(confidence) = other.confidence {
             self.merge_confidence_layer(confidence);
         }
@@ -2563,6 +2909,14 @@ impl Config {
             self.merge_git_awareness_layer(git_awareness);
         }
 
+        if let Some(branch_policy) = other.branch_policy {
+            self.branch_policy.branches.extend(branch_policy.branches);
+        }
+
+        if let Some(review_queue) = other.review_queue {
+            self.merge_review_queue_layer(review_queue);
+        }
+
         if let Some(agents) = other.agents {
             self.merge_agents_layer(agents);
         }
@@ -2628,6 +2982,10 @@ impl Config {
         self.packs.enabled.extend(packs.enabled);
         self.packs.disabled.extend(packs.disabled);
         self.packs.custom_paths.extend(packs.custom_paths);
+        // An ordering is a whole list; the most specific layer that sets one wins.
+        if !packs.pack_order.is_empty() {
+            self.packs.pack_order = packs.pack_order;
+        }
     }
 
     fn merge_policy_layer(&mut self, policy: PolicyConfig) {
@@ -2646,6 +3004,12 @@ impl Config {
         self.overrides.block.extend(overrides.block);
     }
 
+    fn merge_heuristics_layer(&mut self, heuristics: HeuristicsConfig) {
+        if heuristics.destructive_verbs.is_some() {
+            self.heuristics.destructive_verbs = heuristics.destructive_verbs;
+        }
+    }
+
     fn merge_heredoc_layer(&mut self, heredoc: HeredocConfig) {
         if heredoc.enabled.is_some() {
             self.heredoc.enabled = heredoc.enabled;
@@ -2672,6 +3036,16 @@ impl Config {
             self.heredoc.fallback_on_timeout = heredoc.fallback_on_timeout;
         }
 
+        // Merge custom triggers (additive).
+        if let Some(triggers) = heredoc.custom_triggers {
+            let existing = self.heredoc.custom_triggers.get_or_insert_with(Vec::new);
+            for trigger in triggers {
+                if !existing.contains(&trigger) {
+                    existing.push(trigger);
+                }
+            }
+        }
+
         // Merge heredoc allowlist (additive).
         if let Some(other_allowlist) = heredoc.allowlist {
             if let Some(existing) = self.heredoc.allowlist.as_mut() {
@@ -2747,6 +3121,15 @@ impl Config {
         }
     }
 
+    fn merge_review_queue_layer(&mut self, review_queue: ReviewQueueConfigLayer) {
+        if let Some(enabled) = review_queue.enabled {
+            self.review_queue.enabled = enabled;
+        }
+        if let Some(path) = review_queue.path {
+            self.review_queue.path = Some(path);
+        }
+    }
+
     fn merge_interactive_layer(&mut self, interactive: InteractiveConfigLayer) {
         if let Some(enabled) = interactive.enabled {
             self.interactive.enabled = enabled;
@@ -2796,6 +3179,9 @@ impl Config {
         if let Some(warn_if_not_git) = git_awareness.warn_if_not_git {
             self.git_awareness.warn_if_not_git = warn_if_not_git;
         }
+        if let Some(probe_worktree) = git_awareness.probe_worktree {
+            self.git_awareness.probe_worktree = probe_worktree;
+        }
     }
 
     fn merge_agents_layer(&mut self, agents: AgentsConfig) {
@@ -2829,6 +3215,15 @@ impl Config {
             self.packs.custom_paths = paths.split(',').map(|s| s.trim().to_string()).collect();
         }
 
+        // DCG_PACK_ORDER="cloud.aws_security,storage.s3"
+        if let Some(order) = get_env(&format!("{ENV_PREFIX}_PACK_ORDER")) {
+            self.packs.pack_order = order
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+
         // DCG_VERBOSE=0-3
         if let Some(verbose) = get_env(&format!("{ENV_PREFIX}_VERBOSE")) {
             if let Ok(level) = verbose.trim().parse::<u8>() {
@@ -2938,6 +3333,13 @@ impl Config {
             }
         }
 
+        // DCG_REVIEW_QUEUE=true|false|1|0
+        if let Some(enabled) = get_env(&format!("{ENV_PREFIX}_REVIEW_QUEUE")) {
+            if let Some(parsed) = parse_env_bool(&enabled) {
+                self.review_queue.enabled = parsed;
+            }
+        }
+
         // -----------------------------------------------------------------
         // Git awareness config (env overrides)
         // -----------------------------------------------------------------
@@ -3000,6 +3402,13 @@ impl Config {
                 self.git_awareness.warn_if_not_git = parsed;
             }
         }
+
+        // DCG_GIT_PROBE_WORKTREE=true|false|1|0
+        if let Some(probe) = get_env(&format!("{ENV_PREFIX}_GIT_PROBE_WORKTREE")) {
+            if let Some(parsed) = parse_env_bool(&probe) {
+                self.git_awareness.probe_worktree = parsed;
+            }
+        }
     }
 
     /// Get a reference to the policy config.
@@ -3040,12 +3449,18 @@ impl Config {
         }
 
         if let Ok(cwd) = std::env::current_dir() {
-            return self.effective_packs_for_project(&cwd).enabled_pack_ids();
+            return self.enabled_pack_ids_for_dir(&cwd);
         }
 
         self.packs.enabled_pack_ids()
     }
 
+    /// Get enabled pack IDs as they apply to commands run in `dir`.
+    #[must_use]
+    pub fn enabled_pack_ids_for_dir(&self, dir: &Path) -> HashSet<String> {
+        self.effective_packs_for_project(dir).enabled_pack_ids()
+    }
+
     /// Get enabled pack IDs adjusted for an agent's profile.
     ///
     /// This applies the agent's `disabled_packs` and `extra_packs` settings
@@ -3143,14 +3558,18 @@ impl Config {
                 ],
                 disabled: vec![],
                 custom_paths: vec![],
+                pack_order: vec![],
             },
             policy: PolicyConfig::default(),
             overrides: OverridesConfig::default(),
             heredoc: HeredocConfig::default(),
+            heuristics: HeuristicsConfig::default(),
             confidence: ConfidenceConfig::default(),
             logging: crate::logging::LoggingConfig::default(),
             history: HistoryConfig::default(),
             git_awareness: GitAwarenessConfig::default(),
+            branch_policy: BranchPolicyConfig::default(),
+            review_queue: crate::review_queue::ReviewQueueConfig::default(),
             agents: AgentsConfig::default(),
             projects: std::collections::HashMap::new(),
             interactive: crate::interactive::InteractiveConfig::default(),
@@ -3164,6 +3583,10 @@ impl Config {
         r#"# dcg configuration
 # https://github.com/Dicklesworthstone/destructive_command_guard
 
+# Merge shared config files first (paths are relative to this file).
+# Settings here override them; enabled packs and overrides are combined.
+# include = ["base.toml", "team.toml"]
+
 [general]
 # Color output: "auto" | "always" | "never"
 color = "auto"
@@ -3177,7 +3600,8 @@ verbose = false
 # Check for updates in the background (shows a notice if available)
 # check_updates = true
 
-# Hook evaluation budget override (milliseconds)
+# Hook evaluation budget (milliseconds). Evaluations that overrun it are
+# allowed (fail-open) and recorded in history with a "timeout" outcome.
 # hook_timeout_ms = 200
 
 #─────────────────────────────────────────────────────────────
@@ -3225,6 +3649,7 @@ verbose = false
 #   database.mongodb      - MongoDB destructive commands
 #   database.redis        - Redis FLUSH commands
 #   database.sqlite       - SQLite destructive commands
+#   database.vector       - Qdrant/Weaviate/Pinecone collection deletion
 #   containers.docker     - Docker destructive commands
 #   containers.compose    - Docker Compose destructive commands
 #   containers.podman     - Podman destructive commands
@@ -3240,6 +3665,7 @@ verbose = false
 #   system.disk           - Disk operations (dd, mkfs, fdisk)
 #   system.permissions    - Dangerous permission changes
 #   system.services       - Service management commands
+#   system.package_manager - apt/dnf/pacman/brew package removal
 #   strict_git            - Extra paranoid git protections
 #   package_managers      - npm unpublish, cargo yank, etc.
 
@@ -3264,6 +3690,14 @@ custom_paths = [
     # "/etc/dcg/packs/*.yaml",           # System-wide packs
 ]
 
+# When several packs match one command, the first in evaluation order decides
+# the reported rule, reason, and severity. List packs (or categories) to
+# evaluate first; the rest keep the default order. `dcg explain --all-matches`
+# shows the resulting order.
+pack_order = [
+    # "cloud.aws_security",
+]
+
 #─────────────────────────────────────────────────────────────
 # DECISION MODE POLICY
 #─────────────────────────────────────────────────────────────
@@ -3300,6 +3734,15 @@ custom_paths = [
 #
 # Safety: Critical rules are only loosened via explicit per-rule overrides.
 
+[branch_policy]
+# Override mode while a matching git branch is checked out (branch pattern => mode).
+# Applies after per-rule overrides and before pack/global modes.
+# Detached HEAD and non-repo directories use the normal policy.
+# Examples:
+# "main" = "deny"                    # strict: medium/low rules block too
+# "release/*" = "deny"
+# "feature/*" = "warn"
+
 #─────────────────────────────────────────────────────────────
 # CUSTOM OVERRIDES
 #─────────────────────────────────────────────────────────────
@@ -3350,6 +3793,9 @@ max_heredocs = 10
 fallback_on_parse_error = true
 fallback_on_timeout = true
 
+# Extra trigger phrases for in-house interpreters (extends the built-in set).
+# custom_triggers = ["mylang -e"]
+
 #─────────────────────────────────────────────────────────────
 # HISTORY
 #─────────────────────────────────────────────────────────────
@@ -3368,6 +3814,18 @@ max_size_mb = 500
 # Optional database path override.
 # database_path = "~/.config/dcg/history.db"
 
+#─────────────────────────────────────────────────────────────
+# REVIEW QUEUE
+#─────────────────────────────────────────────────────────────
+
+[review_queue]
+# Queue denied hook commands for human approval via `dcg review` (opt-in).
+# Commands stay blocked until approved; approval adds an allowlist entry.
+enabled = false
+
+# Optional queue file override (env: DCG_REVIEW_QUEUE_PATH).
+# path = "~/.config/dcg/review_queue.jsonl"
+
 #─────────────────────────────────────────────────────────────
 # PROJECT-SPECIFIC OVERRIDES
 #─────────────────────────────────────────────────────────────
@@ -3513,6 +3971,7 @@ mod tests {
                 enabled: vec!["kubernetes".to_string(), "kubernetes.helm".to_string()],
                 disabled: vec!["kubernetes.helm".to_string()],
                 custom_paths: vec![],
+                pack_order: vec![],
             },
             ..Default::default()
         };
@@ -3536,6 +3995,7 @@ mod tests {
                     enabled: vec!["database.postgresql".to_string()],
                     disabled: Vec::new(),
                     custom_paths: vec![],
+                    pack_order: vec![],
                 }),
                 overrides: None,
             },
@@ -3883,6 +4343,30 @@ explanations_enabled = false
         );
     }
 
+    #[test]
+    fn test_heuristics_layer_replaces_verbs_only_when_set() {
+        let mut base = Config::default();
+        assert!(base.heuristics.destructive_verbs.is_none());
+
+        let layer: ConfigLayer = toml::from_str(
+            r#"
+[heuristics]
+destructive_verbs = ["nuke", "--yes-really"]
+"#,
+        )
+        .expect("layer parses");
+        base.merge_layer(layer);
+        assert_eq!(
+            base.heuristics.destructive_verbs.as_deref(),
+            Some(&["nuke".to_string(), "--yes-really".to_string()][..])
+        );
+
+        // A layer without [heuristics] keeps the verbs from lower layers.
+        let layer: ConfigLayer = toml::from_str("[general]\nverbose = true\n").expect("parses");
+        base.merge_layer(layer);
+        assert!(base.heuristics.destructive_verbs.is_some());
+    }
+
     #[test]
     fn test_output_config_layer_merge_overwrites_when_set() {
         let mut base = Config::default();
@@ -4238,6 +4722,52 @@ color = "never"
         assert_eq!(config.general.color, "never");
     }
 
+    #[test]
+    fn test_config_merge_layer_accepts_eval_timeout_ms_alias() {
+        let mut config = Config::default();
+
+        let layer: ConfigLayer = toml::from_str(
+            r"
+[general]
+eval_timeout_ms = 75
+",
+        )
+        .expect("layer parses");
+        config.merge_layer(layer);
+
+        assert_eq!(config.general.hook_timeout_ms, Some(75));
+    }
+
+    #[test]
+    fn test_config_merge_layer_branch_policy_extends_by_pattern() {
+        let mut config = Config::default();
+
+        for layer in [
+            r#"
+[branch_policy]
+"main" = "deny"
+"feature/*" = "log"
+"#,
+            r#"
+[branch_policy]
+"feature/*" = "warn"
+"#,
+        ] {
+            let layer: ConfigLayer = toml::from_str(layer).expect("layer parses");
+            config.merge_layer(layer);
+        }
+
+        assert_eq!(
+            config.branch_policy.mode_for_branch("main"),
+            Some(("main", PolicyMode::Deny))
+        );
+        assert_eq!(
+            config.branch_policy.mode_for_branch("feature/login"),
+            Some(("feature/*", PolicyMode::Warn))
+        );
+        assert_eq!(config.branch_policy.mode_for_branch("develop"), None);
+    }
+
     #[test]
     fn test_config_merge_layer_logging_is_reversible() {
         let mut config = Config::default();
@@ -4389,6 +4919,123 @@ allow = false
         assert!(find_repo_root(&deep, 1).is_none());
     }
 
+    #[test]
+    fn test_config_includes_merge_before_including_file() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let shared = temp.path().join("shared");
+        std::fs::create_dir_all(&shared).expect("create shared dir");
+        std::fs::write(
+            shared.join("base.toml"),
+            r#"
+[general]
+verbose = true
+color = "never"
+
+[packs]
+enabled = ["database.postgresql"]
+
+[policy.rules]
+"core.git:reset-hard" = "deny"
+"core.git:clean-force" = "deny"
+"#,
+        )
+        .expect("write base");
+        std::fs::write(
+            shared.join("team.toml"),
+            r#"
+include = ["base.toml"]
+
+[packs]
+enabled = ["containers.docker"]
+
+[policy.rules]
+"core.git:reset-hard" = "warn"
+"#,
+        )
+        .expect("write team");
+        let root = temp.path().join("config.toml");
+        std::fs::write(
+            &root,
+            r#"
+include = ["shared/base.toml", "shared/team.toml"]
+
+[general]
+color = "always"
+"#,
+        )
+        .expect("write root");
+
+        let chain = ConfigLayerChain::load(&root)
+            .expect("no include cycle")
+            .expect("root loads");
+        assert!(chain.errors.is_empty(), "{:?}", chain.errors);
+        let mut config = Config::default();
+        config.merge_layer_chain(chain);
+
+        // Last wins: root over team over base.
+        assert_eq!(config.general.color, "always");
+        assert!(config.general.verbose);
+        assert_eq!(
+            config.policy.rules.get("core.git:reset-hard"),
+            Some(&PolicyMode::Warn)
+        );
+        assert_eq!(
+            config.policy.rules.get("core.git:clean-force"),
+            Some(&PolicyMode::Deny)
+        );
+        // Union: packs from every file are enabled.
+        let enabled = config.enabled_pack_ids();
+        assert!(enabled.contains("database.postgresql"));
+        assert!(enabled.contains("containers.docker"));
+
+        // base.toml is reached twice (directly and via team.toml) but listed once.
+        let includes = resolve_config_includes(&root).expect("no include errors");
+        let names: Vec<_> = includes
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["base.toml", "team.toml"]);
+    }
+
+    #[test]
+    fn test_config_include_cycle_is_an_error() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let a = temp.path().join("a.toml");
+        let b = temp.path().join("b.toml");
+        std::fs::write(&a, "include = [\"b.toml\"]\n\n[general]\nverbose = true\n")
+            .expect("write a");
+        std::fs::write(
+            &b,
+            "include = [\"a.toml\"]\n\n[general]\ncolor = \"never\"\n",
+        )
+        .expect("write b");
+
+        let err = resolve_config_includes(&a).expect_err("cycle should be detected");
+        let ConfigIncludeError::Cycle(chain) = &err else {
+            panic!("expected a cycle error, got {err:?}");
+        };
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain.first(), chain.last());
+        assert!(err.to_string().contains("config include cycle"));
+
+        // The cycle fails the load; neither file is applied.
+        assert!(matches!(
+            ConfigLayerChain::load(&a),
+            Err(ConfigIncludeError::Cycle(_))
+        ));
+        assert!(Config::load_layer_chain(&a).is_none());
+    }
+
+    #[test]
+    fn test_config_include_missing_file_is_reported() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let root = temp.path().join("config.toml");
+        std::fs::write(&root, "include = [\"nope.toml\"]\n").expect("write root");
+
+        let err = resolve_config_includes(&root).expect_err("missing include");
+        assert!(matches!(err, ConfigIncludeError::Missing { .. }));
+    }
+
     // ========================================================================
     // CompiledOverrides Tests (git_safety_guard-99e.4.1)
     // ========================================================================
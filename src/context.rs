@@ -988,6 +988,20 @@ pub fn is_argument_data(command: &str, preceding_flag: Option<&str>) -> bool {
     false
 }
 
+/// Length of a leading output-redirection operator (`>`, `>>`, `2>`), if any.
+fn output_redirect_prefix_len(token: &str) -> Option<usize> {
+    let bytes = token.as_bytes();
+    let mut i = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    if bytes.get(i) != Some(&b'>') {
+        return None;
+    }
+    i += 1;
+    if bytes.get(i) == Some(&b'>') {
+        i += 1;
+    }
+    Some(i)
+}
+
 /// Check if the current segment ends with a pipe (indicating potential code execution).
 fn is_piped_segment(command: &str, tokens: &[SanitizeToken], current_idx: usize) -> bool {
     for token in &tokens[current_idx..] {
@@ -1052,11 +1066,13 @@ pub fn sanitize_for_pattern_matching(command: &str) -> Cow<'_, str> {
     let mut git_subcommand: Option<&str> = None;
     let mut git_waiting_for_value = false;
     let mut git_options_ended = false;
+    let mut redirect_target_pending = false;
 
     for (i, token) in tokens.iter().enumerate() {
         if token.kind == SanitizeTokenKind::Separator {
             segment_cmd = None;
             segment_cmd_is_all_args_data = false;
+            redirect_target_pending = false;
             pending_safe_flag = None;
             options_ended = false;
             search_pattern_masked = false;
@@ -1178,6 +1194,15 @@ pub fn sanitize_for_pattern_matching(command: &str) -> Cow<'_, str> {
         }
 
         if segment_cmd_is_all_args_data {
+            // Output redirections are not arguments: `echo x > /etc/fstab` writes the
+            // file, so the operator and its target stay visible to pack patterns.
+            if std::mem::take(&mut redirect_target_pending) {
+                continue;
+            }
+            if let Some(op_len) = output_redirect_prefix_len(token_text) {
+                redirect_target_pending = op_len == token_text.len();
+                continue;
+            }
             // For commands like echo/printf, treat all args as data, but never strip inline code.
             if !token.has_inline_code {
                 mask_ranges.push(token.byte_range.clone());
@@ -3066,6 +3091,20 @@ mod tests {
         assert!(sanitized.as_ref().contains("command -pv"));
     }
 
+    #[test]
+    fn sanitize_keeps_echo_redirect_targets_visible() {
+        let sanitized = sanitize_for_pattern_matching(r#"echo "rm -rf /" > /etc/fstab"#);
+        assert!(!sanitized.as_ref().contains("rm -rf"));
+        assert!(sanitized.as_ref().contains("> /etc/fstab"));
+
+        let sanitized = sanitize_for_pattern_matching("printf x 2>/dev/sda");
+        assert!(sanitized.as_ref().contains("2>/dev/sda"));
+
+        let sanitized = sanitize_for_pattern_matching("echo x >> /etc/hosts extra");
+        assert!(sanitized.as_ref().contains(">> /etc/hosts"));
+        assert!(!sanitized.as_ref().contains("extra"));
+    }
+
     #[test]
     fn sanitize_does_not_mask_command_p_wrapper() {
         let cmd = r"command -p rm -rf /tmp";
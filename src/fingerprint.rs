@@ -0,0 +1,179 @@
+//! Canonical command fingerprints.
+//!
+//! A fingerprint is the SHA-256 (lowercase hex) of a command after canonical
+//! normalization, so trivially different spellings of the same command group
+//! together. History deduplication uses it, and it is public so tools built on
+//! dcg can compute matching fingerprints.
+//!
+//! Canonicalization:
+//!
+//! - Leading and trailing whitespace is trimmed.
+//! - Runs of unquoted whitespace collapse to a single space.
+//! - Whitespace inside quotes and backslash-escaped characters are kept as-is.
+//! - With [`FingerprintOptions::redact_quoted`], each quoted string becomes
+//!   `'?'`, so commands that differ only in a message or literal coalesce.
+//!
+//! A command that is already canonical hashes to the SHA-256 of its raw bytes.
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+
+/// Placeholder written in place of a quoted string when redacting.
+const REDACTED_QUOTED: &str = "'?'";
+
+/// Options for [`command_fingerprint_with`] and [`canonicalize_command`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FingerprintOptions {
+    /// Replace the contents of single- and double-quoted strings before hashing.
+    pub redact_quoted: bool,
+}
+
+/// Fingerprint a command with default options (whitespace normalization only).
+#[must_use]
+pub fn command_fingerprint(command: &str) -> String {
+    command_fingerprint_with(command, FingerprintOptions::default())
+}
+
+/// Fingerprint a command with explicit options.
+#[must_use]
+pub fn command_fingerprint_with(command: &str, options: FingerprintOptions) -> String {
+    let canonical = canonicalize_command(command, options);
+    let digest = Sha256::digest(canonical.as_bytes());
+    let mut hex = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+/// Return the canonical form of `command` that fingerprints are computed over.
+#[must_use]
+pub fn canonicalize_command(command: &str, options: FingerprintOptions) -> String {
+    let mut out = String::with_capacity(command.len());
+    let mut pending_space = false;
+    let mut chars = command.trim().chars();
+
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            out.push(' ');
+            pending_space = false;
+        }
+        match c {
+            '\\' => {
+                out.push(c);
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            '\'' | '"' => {
+                let mut quoted = String::new();
+                quoted.push(c);
+                let mut escaped = false;
+                for inner in chars.by_ref() {
+                    quoted.push(inner);
+                    if escaped {
+                        escaped = false;
+                    } else if c == '"' && inner == '\\' {
+                        escaped = true;
+                    } else if inner == c {
+                        break;
+                    }
+                }
+                if options.redact_quoted {
+                    out.push_str(REDACTED_QUOTED);
+                } else {
+                    out.push_str(&quoted);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REDACT: FingerprintOptions = FingerprintOptions {
+        redact_quoted: true,
+    };
+
+    #[test]
+    fn whitespace_variants_share_a_fingerprint() {
+        let a = command_fingerprint("git reset --hard HEAD");
+        assert_eq!(a, command_fingerprint("  git   reset\t--hard  HEAD \n"));
+        assert_eq!(a, command_fingerprint("git reset --hard\tHEAD"));
+        assert_eq!(a.len(), 64);
+    }
+
+    #[test]
+    fn different_commands_have_different_fingerprints() {
+        assert_ne!(
+            command_fingerprint("git reset --hard HEAD"),
+            command_fingerprint("git reset --soft HEAD")
+        );
+        assert_ne!(
+            command_fingerprint("rm -rf ./build"),
+            command_fingerprint("rm -rf /")
+        );
+    }
+
+    #[test]
+    fn canonical_commands_hash_their_raw_bytes() {
+        let command = "git status";
+        let expected =
+            Sha256::digest(command.as_bytes())
+                .iter()
+                .fold(String::new(), |mut hex, byte| {
+                    let _ = write!(hex, "{byte:02x}");
+                    hex
+                });
+        assert_eq!(command_fingerprint(command), expected);
+    }
+
+    #[test]
+    fn quoted_whitespace_is_preserved() {
+        let options = FingerprintOptions::default();
+        assert_eq!(
+            canonicalize_command(r#"echo  "a   b"   'c  d'"#, options),
+            r#"echo "a   b" 'c  d'"#
+        );
+        assert_ne!(
+            command_fingerprint(r#"rm "my  file""#),
+            command_fingerprint(r#"rm "my file""#)
+        );
+        assert_eq!(
+            canonicalize_command(r"rm my\ \ file", options),
+            r"rm my\ \ file"
+        );
+    }
+
+    #[test]
+    fn redaction_coalesces_quoted_literals() {
+        assert_eq!(
+            command_fingerprint_with(r#"git commit -m "fix typo""#, REDACT),
+            command_fingerprint_with("git commit -m 'update docs'", REDACT)
+        );
+        assert_eq!(
+            canonicalize_command(r#"git commit -m "say \"hi\"" --amend"#, REDACT),
+            "git commit -m '?' --amend"
+        );
+        assert_ne!(
+            command_fingerprint(r#"git commit -m "fix typo""#),
+            command_fingerprint("git commit -m 'update docs'")
+        );
+    }
+
+    #[test]
+    fn unterminated_quote_is_kept_verbatim() {
+        assert_eq!(
+            canonicalize_command("echo 'oops  ", FingerprintOptions::default()),
+            "echo 'oops"
+        );
+    }
+}
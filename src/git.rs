@@ -252,11 +252,10 @@ fn get_detached_head_hash(working_dir: Option<&std::path::Path>) -> Option<Strin
 /// Format: `ref: refs/heads/<branch-name>` for branches
 /// or a commit hash for detached HEAD.
 fn get_branch_from_head_file(working_dir: Option<&std::path::Path>) -> BranchInfo {
-    let git_dir = find_git_dir(working_dir);
-    let head_path = match git_dir {
-        Some(dir) => dir.join("HEAD"),
-        None => return BranchInfo::NotGitRepo,
+    let Some(git_dir) = find_git_dir(working_dir) else {
+        return BranchInfo::NotGitRepo;
     };
+    let head_path = git_dir.join("HEAD");
 
     let head_content = match std::fs::read_to_string(&head_path) {
         Ok(content) => content,
@@ -266,8 +265,10 @@ fn get_branch_from_head_file(working_dir: Option<&std::path::Path>) -> BranchInf
     let trimmed = head_content.trim();
 
     // Check if it's a symbolic reference: "ref: refs/heads/<branch>"
-    if let Some(ref_path) = trimmed.strip_prefix("ref: refs/heads/") {
-        return BranchInfo::Branch(ref_path.to_string());
+    if let Some(target) = trimmed.strip_prefix("ref:") {
+        let common_dir = find_common_dir(&git_dir);
+        return resolve_branch_ref(&git_dir, &common_dir, target.trim())
+            .map_or(BranchInfo::NotGitRepo, BranchInfo::Branch);
     }
 
     // It's a commit hash (detached HEAD)
@@ -286,6 +287,77 @@ fn get_branch_from_head_file(working_dir: Option<&std::path::Path>) -> BranchInf
     BranchInfo::NotGitRepo
 }
 
+/// Maximum number of symbolic refs followed before giving up.
+const MAX_SYMREF_DEPTH: usize = 5;
+
+/// Resolve the shared git directory for `git_dir`.
+///
+/// Linked worktrees keep a `commondir` file pointing at the main repository's
+/// git directory, where branch refs live. Regular repositories have no such
+/// file and are their own common directory.
+fn find_common_dir(git_dir: &std::path::Path) -> PathBuf {
+    let common_dir = std::fs::read_to_string(git_dir.join("commondir"))
+        .ok()
+        .map(|content| {
+            let path = PathBuf::from(content.trim());
+            if path.is_absolute() {
+                path
+            } else {
+                git_dir.join(path)
+            }
+        })
+        .unwrap_or_else(|| git_dir.to_path_buf());
+    std::fs::canonicalize(&common_dir).unwrap_or(common_dir)
+}
+
+/// Resolve a symbolic ref target to a branch name.
+///
+/// Accepts targets relative to the git directory (`refs/heads/main`) as well
+/// as absolute paths into the worktree or common directory, and follows
+/// loose refs that are themselves symbolic refs.
+fn resolve_branch_ref(
+    git_dir: &std::path::Path,
+    common_dir: &std::path::Path,
+    target: &str,
+) -> Option<String> {
+    let mut target = target.to_string();
+
+    for _ in 0..MAX_SYMREF_DEPTH {
+        let target_path = std::path::Path::new(&target);
+        if target_path.is_absolute() {
+            let target_path = std::fs::canonicalize(target_path).ok()?;
+            let relative = target_path
+                .strip_prefix(common_dir)
+                .or_else(|_| target_path.strip_prefix(git_dir))
+                .ok()?;
+            target = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+        }
+
+        let branch = target.strip_prefix("refs/heads/")?.to_string();
+
+        // Branch refs are shared, so they live in the common directory.
+        let next = std::fs::read_to_string(common_dir.join(&target))
+            .ok()
+            .and_then(|content| {
+                content
+                    .trim()
+                    .strip_prefix("ref:")
+                    .map(|next| next.trim().to_string())
+            });
+
+        match next {
+            Some(next) => target = next,
+            None => return Some(branch),
+        }
+    }
+
+    None
+}
+
 /// Find the .git directory for a repository.
 ///
 /// Handles both regular repositories (.git as directory) and worktrees
@@ -340,6 +412,40 @@ pub fn is_in_git_repo_at_path(path: &std::path::Path) -> bool {
     get_branch_info_at_path(path).is_in_git_repo()
 }
 
+/// Count tracked files with uncommitted changes (staged or unstaged).
+///
+/// Runs `git status --porcelain --untracked-files=no`, so untracked files are
+/// ignored: `git reset --hard` leaves them alone. Returns `None` when git is
+/// unavailable or the directory is not inside a repository.
+#[must_use]
+pub fn count_uncommitted_changes(working_dir: Option<&std::path::Path>) -> Option<usize> {
+    let mut cmd = Command::new("git");
+    cmd.args(["status", "--porcelain", "--untracked-files=no"]);
+
+    if let Some(dir) = working_dir {
+        cmd.current_dir(dir);
+    }
+
+    cmd.stderr(std::process::Stdio::null());
+
+    let output = cmd.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(count_porcelain_entries(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Count the entries in `git status --porcelain` output, skipping untracked files.
+fn count_porcelain_entries(porcelain: &str) -> usize {
+    porcelain
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with("??"))
+        .count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +510,59 @@ mod tests {
         assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
+    /// Build a main repository plus a linked worktree the way `git worktree add` lays them out.
+    fn fake_worktree(head: &str) -> (tempfile::TempDir, PathBuf) {
+        let temp = tempfile::tempdir().unwrap();
+        let main_git = temp.path().join("main/.git");
+        let wt_git = main_git.join("worktrees/feature");
+        std::fs::create_dir_all(main_git.join("refs/heads")).unwrap();
+        std::fs::create_dir_all(&wt_git).unwrap();
+        std::fs::write(main_git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        std::fs::write(
+            main_git.join("refs/heads/main"),
+            "0123456789abcdef0123456789abcdef01234567\n",
+        )
+        .unwrap();
+        std::fs::write(wt_git.join("commondir"), "../..\n").unwrap();
+        std::fs::write(wt_git.join("HEAD"), head).unwrap();
+
+        let worktree = temp.path().join("feature");
+        std::fs::create_dir_all(worktree.join("src")).unwrap();
+        std::fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", wt_git.display()),
+        )
+        .unwrap();
+        (temp, worktree)
+    }
+
+    #[test]
+    fn test_head_file_in_linked_worktree() {
+        let (_temp, worktree) = fake_worktree("ref: refs/heads/feature/login\n");
+        assert_eq!(
+            get_branch_from_head_file(Some(&worktree.join("src"))),
+            BranchInfo::Branch("feature/login".to_string())
+        );
+    }
+
+    #[test]
+    fn test_head_file_in_worktree_follows_commondir_symref() {
+        let (temp, worktree) = fake_worktree("ref: refs/heads/trunk\n");
+        let main_git = temp.path().join("main/.git");
+        std::fs::write(main_git.join("refs/heads/trunk"), "ref: refs/heads/main\n").unwrap();
+        assert_eq!(
+            get_branch_from_head_file(Some(&worktree)),
+            BranchInfo::Branch("main".to_string())
+        );
+
+        let absolute = format!("ref: {}\n", main_git.join("refs/heads/main").display());
+        std::fs::write(main_git.join("worktrees/feature/HEAD"), absolute).unwrap();
+        assert_eq!(
+            get_branch_from_head_file(Some(&worktree)),
+            BranchInfo::Branch("main".to_string())
+        );
+    }
+
     #[test]
     fn test_clear_cache() {
         // Just verify clear_cache doesn't panic
@@ -440,4 +599,13 @@ mod tests {
         // Just verify it doesn't panic
         drop(result);
     }
+
+    #[test]
+    fn test_count_porcelain_entries_ignores_untracked() {
+        assert_eq!(count_porcelain_entries(""), 0);
+        assert_eq!(
+            count_porcelain_entries(" M src/lib.rs\nM  Cargo.toml\n?? notes.txt\n D old.rs\n"),
+            3
+        );
+    }
 }
@@ -0,0 +1,116 @@
+//! Stable, high-level evaluation API for library consumers.
+//!
+//! [`Guard`] is the supported way to embed dcg in another tool. It resolves the
+//! enabled packs, keyword index, compiled overrides, allowlists, and heredoc
+//! settings once from a [`Config`], then evaluates any number of commands
+//! against that precomputed context.
+//!
+//! The lower-level `evaluate_command*` functions in [`crate::evaluator`] remain
+//! available, but their parameter lists follow the needs of hook mode and the
+//! CLI and may change between releases.
+//!
+//! # Example
+//!
+//! ```
+//! use destructive_command_guard::{Config, Guard};
+//!
+//! let guard = Guard::from_config(&Config::default());
+//!
+//! let result = guard.evaluate("git reset --hard HEAD~1");
+//! assert!(result.is_denied());
+//! assert_eq!(result.pack_id(), Some("core.git"));
+//!
+//! assert!(guard.evaluate("git status").is_allowed());
+//! ```
+
+use std::path::Path;
+
+use crate::config::Config;
+use crate::evaluator::{EvaluationResult, evaluate_command_with_pack_order_at_path};
+use crate::scan::ScanEvalContext;
+
+/// A reusable command evaluator built from a [`Config`].
+///
+/// Construction does the expensive setup (pack expansion, keyword indexing,
+/// override compilation, allowlist loading); [`Guard::evaluate`] only runs the
+/// per-command checks. Build one `Guard` and reuse it for every command.
+#[derive(Debug)]
+pub struct Guard {
+    context: ScanEvalContext,
+}
+
+impl Guard {
+    /// Build a guard from a loaded configuration.
+    ///
+    /// Allowlists are loaded from the default project/user/system locations,
+    /// the same way hook mode loads them.
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            context: ScanEvalContext::from_config(config),
+        }
+    }
+
+    /// Evaluate a command against the enabled packs, overrides, and allowlists.
+    #[must_use]
+    pub fn evaluate(&self, command: &str) -> EvaluationResult {
+        self.evaluate_at_path(command, None)
+    }
+
+    /// Evaluate a command as if it were run from `project_path`.
+    ///
+    /// The project path scopes project-specific heredoc content allowlists;
+    /// when `None`, the current working directory is used.
+    #[must_use]
+    pub fn evaluate_at_path(&self, command: &str, project_path: Option<&Path>) -> EvaluationResult {
+        let ctx = &self.context;
+        evaluate_command_with_pack_order_at_path(
+            command,
+            &ctx.enabled_keywords,
+            &ctx.ordered_packs,
+            ctx.keyword_index.as_ref(),
+            &ctx.compiled_overrides,
+            &ctx.allowlists,
+            &ctx.heredoc_settings,
+            project_path,
+        )
+    }
+
+    /// Pack IDs this guard evaluates, in evaluation order.
+    #[must_use]
+    pub fn enabled_packs(&self) -> &[String] {
+        &self.context.ordered_packs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guard_blocks_and_allows_with_default_config() {
+        let guard = Guard::from_config(&Config::default());
+
+        let denied = guard.evaluate("git reset --hard");
+        assert!(denied.is_denied());
+        assert_eq!(denied.pack_id(), Some("core.git"));
+
+        assert!(guard.evaluate("git status").is_allowed());
+        assert!(guard.evaluate("ls -la").is_allowed());
+    }
+
+    #[test]
+    fn guard_evaluates_configured_packs() {
+        let mut config = Config::default();
+        config.packs.enabled = vec!["database.postgresql".to_string()];
+        let guard = Guard::from_config(&config);
+
+        assert!(
+            guard
+                .enabled_packs()
+                .iter()
+                .any(|id| id == "database.postgresql")
+        );
+        assert!(guard.evaluate("psql -c 'DROP DATABASE prod'").is_denied());
+    }
+}
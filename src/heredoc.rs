@@ -100,6 +100,72 @@ static HEREDOC_TRIGGERS: LazyLock<RegexSet> = LazyLock::new(|| {
     RegexSet::new(HEREDOC_TRIGGER_PATTERNS).expect("heredoc trigger patterns should compile")
 });
 
+/// User-configured trigger phrases that extend the built-in Tier 1 set.
+///
+/// Each phrase names an interpreter invocation such as `mylang -e`. Words are
+/// matched literally (whitespace-tolerant) at a command boundary. When a phrase
+/// is followed by a quoted argument, Tier 2 extracts that argument as inline
+/// script content, the same way it does for `python -c '...'`.
+#[derive(Debug, Clone)]
+pub struct CustomTriggers {
+    phrases: Vec<String>,
+    set: RegexSet,
+    inline: Vec<Regex>,
+}
+
+impl CustomTriggers {
+    /// Build a trigger set from configured phrases.
+    ///
+    /// Blank phrases are ignored; returns `None` if none remain.
+    #[must_use]
+    pub fn new<S: AsRef<str>>(phrases: &[S]) -> Option<Self> {
+        let mut normalized: Vec<String> = Vec::new();
+        for phrase in phrases {
+            let phrase = phrase
+                .as_ref()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !phrase.is_empty() && !normalized.contains(&phrase) {
+                normalized.push(phrase);
+            }
+        }
+        if normalized.is_empty() {
+            return None;
+        }
+
+        let bodies: Vec<String> = normalized.iter().map(|p| custom_trigger_body(p)).collect();
+        // Phrases are regex-escaped, so these patterns always compile.
+        let set = RegexSet::new(bodies.iter().map(|b| format!(r#"{b}(?:\s|$|['"])"#)))
+            .expect("escaped custom trigger patterns should compile");
+        let inline = bodies
+            .iter()
+            .map(|b| {
+                Regex::new(&format!(r#"{b}\s*(?:'([^']*)'|"([^"]*)")"#))
+                    .expect("escaped custom trigger patterns should compile")
+            })
+            .collect();
+
+        Some(Self {
+            phrases: normalized,
+            set,
+            inline,
+        })
+    }
+
+    /// The normalized trigger phrases, in configuration order.
+    #[must_use]
+    pub fn phrases(&self) -> &[String] {
+        &self.phrases
+    }
+}
+
+/// Regex body for a custom trigger phrase. Group 1 captures the phrase itself.
+fn custom_trigger_body(phrase: &str) -> String {
+    let words: Vec<String> = phrase.split_whitespace().map(regex::escape).collect();
+    format!(r#"(?:^|[\s;&|()`'"/])({})"#, words.join(r"\s+"))
+}
+
 #[inline]
 #[must_use]
 fn contains_active_heredoc_operator(command: &str) -> bool {
@@ -430,6 +496,39 @@ pub fn matched_triggers(command: &str) -> Vec<usize> {
     matches
 }
 
+/// Like [`check_triggers`], but also fires on user-configured trigger phrases.
+#[inline]
+#[must_use]
+pub fn check_triggers_with_custom(command: &str, custom: Option<&CustomTriggers>) -> TriggerResult {
+    if check_triggers(command) == TriggerResult::Triggered {
+        return TriggerResult::Triggered;
+    }
+    if custom.is_some_and(|c| c.set.is_match(command)) {
+        debug!("tier1_trigger: custom trigger phrase detected");
+        return TriggerResult::Triggered;
+    }
+    TriggerResult::NoTrigger
+}
+
+/// Like [`matched_triggers`], but also reports user-configured trigger phrases.
+///
+/// Custom phrase `i` is reported as index `HEREDOC_TRIGGER_PATTERNS.len() + 1 + i`,
+/// after the built-in patterns and the heredoc-operator index.
+#[must_use]
+pub fn matched_triggers_with_custom(command: &str, custom: Option<&CustomTriggers>) -> Vec<usize> {
+    let mut matches = matched_triggers(command);
+    if let Some(custom) = custom {
+        matches.extend(
+            custom
+                .set
+                .matches(command)
+                .into_iter()
+                .map(|i| MANUAL_HEREDOC_TRIGGER_INDEX + 1 + i),
+        );
+    }
+    matches
+}
+
 // ============================================================================
 // Tier 2: Content Extraction
 // ============================================================================
@@ -475,6 +574,22 @@ pub enum ScriptLanguage {
 }
 
 impl ScriptLanguage {
+    /// Lowercase label for this language (e.g., "python", "javascript").
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Go => "go",
+            Self::Php => "php",
+            Self::Python => "python",
+            Self::Ruby => "ruby",
+            Self::Perl => "perl",
+            Self::JavaScript => "javascript",
+            Self::TypeScript => "typescript",
+            Self::Unknown => "unknown",
+        }
+    }
+
     /// Infer language from a command prefix (e.g., "python", "python3", "python3.11").
     ///
     /// Matches exact command names or names with version suffixes (e.g., "python3.11").
@@ -1083,8 +1198,19 @@ fn record_timeout_if_needed(
 /// }
 /// ```
 #[must_use]
-#[instrument(skip(command, limits), fields(cmd_len = command.len(), timeout_ms = limits.timeout_ms))]
 pub fn extract_content(command: &str, limits: &ExtractionLimits) -> ExtractionResult {
+    extract_content_with_custom(command, limits, None)
+}
+
+/// Like [`extract_content`], but also extracts quoted arguments that follow
+/// user-configured trigger phrases (see [`CustomTriggers`]).
+#[must_use]
+#[instrument(skip(command, limits, custom), fields(cmd_len = command.len(), timeout_ms = limits.timeout_ms))]
+pub fn extract_content_with_custom(
+    command: &str,
+    limits: &ExtractionLimits,
+    custom: Option<&CustomTriggers>,
+) -> ExtractionResult {
     let start_time = Instant::now();
     let timeout = Duration::from_millis(limits.timeout_ms);
     let mut skip_reasons: Vec<SkipReason> = Vec::new();
@@ -1126,6 +1252,17 @@ pub fn extract_content(command: &str, limits: &ExtractionLimits) -> ExtractionRe
         &mut extracted,
         &mut skip_reasons,
     );
+    if let Some(custom) = custom {
+        extract_custom_inline_scripts(
+            command,
+            custom,
+            limits,
+            start_time,
+            timeout,
+            &mut extracted,
+            &mut skip_reasons,
+        );
+    }
     if record_timeout_if_needed(start_time, timeout, limits.timeout_ms, &mut skip_reasons) {
         return if extracted.is_empty() {
             ExtractionResult::Skipped(skip_reasons)
@@ -1288,6 +1425,64 @@ fn extract_inline_scripts(
     }
 }
 
+/// Extract quoted arguments following custom trigger phrases.
+///
+/// Content already captured by the built-in inline extractors is skipped so a
+/// phrase like `python -c` does not produce duplicates.
+fn extract_custom_inline_scripts(
+    command: &str,
+    custom: &CustomTriggers,
+    limits: &ExtractionLimits,
+    start_time: Instant,
+    timeout: Duration,
+    extracted: &mut Vec<ExtractedContent>,
+    skip_reasons: &mut Vec<SkipReason>,
+) {
+    for (phrase, pattern) in custom.phrases.iter().zip(&custom.inline) {
+        for cap in pattern.captures_iter(command) {
+            if record_timeout_if_needed(start_time, timeout, limits.timeout_ms, skip_reasons) {
+                return;
+            }
+            if extracted.len() >= limits.max_heredocs {
+                skip_reasons.push(SkipReason::ExceededHeredocLimit {
+                    limit: limits.max_heredocs,
+                });
+                return;
+            }
+
+            let Some(content_match) = cap.get(2).or_else(|| cap.get(3)) else {
+                continue;
+            };
+            let content_range = content_match.start()..content_match.end();
+            if extracted
+                .iter()
+                .any(|e| e.content_range.as_ref() == Some(&content_range))
+            {
+                continue;
+            }
+            if content_match.len() > limits.max_body_bytes {
+                continue;
+            }
+
+            let content = content_match.as_str();
+            let full_match = cap.get(0).unwrap();
+            // Skip the boundary character consumed before the phrase.
+            let phrase_match = cap.get(1).unwrap_or(full_match);
+            let interpreter = phrase.split_whitespace().next().unwrap_or(phrase);
+            extracted.push(ExtractedContent {
+                content: content.to_string(),
+                language: ScriptLanguage::detect(phrase, content).0,
+                delimiter: None,
+                byte_range: phrase_match.start()..full_match.end(),
+                content_range: Some(content_range),
+                quoted: true,
+                heredoc_type: None,
+                target_command: Some(interpreter.to_string()),
+            });
+        }
+    }
+}
+
 /// Extract here-strings (<<<).
 fn extract_herestrings(
     command: &str,
@@ -2473,6 +2668,47 @@ mod tests {
             );
         }
 
+        #[test]
+        fn custom_trigger_enables_extraction() {
+            let cmd = r#"mylang -e 'import os; os.system("rm -rf /")'"#;
+            let limits = ExtractionLimits::default();
+
+            // Built-in triggers and extractors ignore the proprietary interpreter.
+            assert_eq!(check_triggers(cmd), TriggerResult::NoTrigger);
+            assert!(matches!(
+                extract_content(cmd, &limits),
+                ExtractionResult::NoContent
+            ));
+
+            let custom = CustomTriggers::new(&["mylang  -e", "  "]).expect("one phrase");
+            assert_eq!(custom.phrases(), ["mylang -e"]);
+            assert_eq!(
+                check_triggers_with_custom(cmd, Some(&custom)),
+                TriggerResult::Triggered
+            );
+            assert_eq!(
+                matched_triggers_with_custom(cmd, Some(&custom)),
+                vec![HEREDOC_TRIGGER_PATTERNS.len() + 1]
+            );
+
+            let ExtractionResult::Extracted(contents) =
+                extract_content_with_custom(cmd, &limits, Some(&custom))
+            else {
+                panic!("custom trigger should extract the inline script");
+            };
+            assert_eq!(contents.len(), 1);
+            assert!(contents[0].content.contains("os.system"));
+            assert_eq!(contents[0].language, ScriptLanguage::Python);
+            assert_eq!(contents[0].target_command.as_deref(), Some("mylang"));
+
+            // Word boundaries: a longer program name is not a match.
+            assert_eq!(
+                check_triggers_with_custom("notmylang -e 'x'", Some(&custom)),
+                TriggerResult::NoTrigger
+            );
+            assert!(CustomTriggers::new::<&str>(&[]).is_none());
+        }
+
         #[test]
         fn heredoc_syntax_inside_quoted_literals_does_not_trigger() {
             // Common false positives: heredoc syntax used as documentation or search patterns.
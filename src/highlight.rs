@@ -89,6 +89,12 @@ impl HighlightedCommand {
 /// Determines whether color should be used based on TTY and environment.
 #[must_use]
 pub fn should_use_color() -> bool {
+    match crate::output::color_choice() {
+        crate::output::ColorChoice::Always => return true,
+        crate::output::ColorChoice::Never => return false,
+        crate::output::ColorChoice::Auto => {}
+    }
+
     if std::env::var_os("NO_COLOR").is_some() || std::env::var_os("DCG_NO_COLOR").is_some() {
         return false;
     }
@@ -33,6 +33,9 @@
 //!     outcome: Outcome::Allow,
 //!     ..Default::default()
 //! })?;
+//!
+//! // Full-text search over command text via `commands_fts`, newest first.
+//! let hits = db.search("rm -rf", 20)?;
 //! ```
 
 mod schema;
@@ -45,12 +48,12 @@ use std::time::{Duration, Instant};
 use tracing::{debug, error, trace, warn};
 
 pub use schema::{
-    AgentStat, BackupResult, CURRENT_SCHEMA_VERSION, CheckResult, CommandEntry,
-    DEFAULT_DB_FILENAME, ExportFilters, ExportOptions, ExportedData, FrequentBlock,
+    ActivitySummary, AgentStat, BackupResult, BlockedRuleStat, CURRENT_SCHEMA_VERSION, CheckResult,
+    CommandEntry, DEFAULT_DB_FILENAME, ExportFilters, ExportOptions, ExportedData, FrequentBlock,
     HistoryAnalyzer, HistoryDb, HistoryError, HistoryStats, Outcome, OutcomeStats,
     PackEffectivenessAnalysis, PackRecommendation, PathCluster, PatternEffectiveness, PatternStat,
-    PerformanceStats, PotentialGap, ProjectStat, RecommendationType, RuleMetrics, RuleTrend,
-    StatsTrends, SuggestionAction, SuggestionAuditEntry, SuggestionCandidate,
+    PerformanceStats, PotentialGap, ProjectStat, RecommendationType, RuleMetrics, RuleTimeline,
+    RuleTrend, StatsTrends, SuggestionAction, SuggestionAuditEntry, SuggestionCandidate,
 };
 
 /// Environment variable to override the history database path.
@@ -59,6 +62,12 @@ pub const ENV_HISTORY_DB_PATH: &str = "DCG_HISTORY_DB";
 /// Environment variable to disable history collection entirely.
 pub const ENV_HISTORY_DISABLED: &str = "DCG_HISTORY_DISABLED";
 
+/// Environment variable an agent can set to group its commands into one session.
+pub const ENV_SESSION_ID: &str = "DCG_SESSION_ID";
+
+/// Agent-provided session variables used when `DCG_SESSION_ID` is unset.
+const AGENT_SESSION_ENV_VARS: &[&str] = &["CLAUDE_SESSION_ID", "CONTINUE_SESSION_ID"];
+
 enum HistoryMessage {
     Entry(Box<CommandEntry>),
     Flush(mpsc::Sender<()>),
@@ -135,8 +144,9 @@ impl HistoryWriter {
             return Self::disabled();
         }
 
-        // Generate a unique session ID for this writer instance
-        let session_id = generate_session_id();
+        // Prefer a session ID supplied by the agent so separate hook
+        // invocations share one session; otherwise generate one per process.
+        let session_id = session_id_from_env().unwrap_or_else(generate_session_id);
 
         let (sender, receiver) = mpsc::channel::<HistoryMessage>();
         let worker_config = WorkerConfig::from(config);
@@ -235,6 +245,18 @@ impl Drop for HistoryWriter {
     }
 }
 
+/// Read the session ID from `DCG_SESSION_ID` or a known agent variable.
+fn session_id_from_env() -> Option<String> {
+    std::iter::once(ENV_SESSION_ID)
+        .chain(AGENT_SESSION_ENV_VARS.iter().copied())
+        .find_map(|var| {
+            std::env::var(var)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        })
+}
+
 /// Generate a unique session ID for a writer instance.
 fn generate_session_id() -> String {
     use sha2::{Digest, Sha256};
@@ -11,27 +11,32 @@
 use chrono::{DateTime, Duration, Utc};
 use rusqlite::{Connection, params};
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::env;
-use std::fmt::Write as FmtWrite;
 use std::path::{Path, PathBuf};
 
 /// Current schema version for migrations.
-pub const CURRENT_SCHEMA_VERSION: u32 = 5;
+pub const CURRENT_SCHEMA_VERSION: u32 = 7;
 
 /// Default database filename.
 pub const DEFAULT_DB_FILENAME: &str = "history.db";
 
 /// History-specific error type.
+///
+/// Every fallible [`HistoryDb`] operation returns one of these variants.
 #[derive(Debug)]
 pub enum HistoryError {
     /// `SQLite` error.
     Sqlite(rusqlite::Error),
     /// I/O error.
     Io(std::io::Error),
-    /// Schema version mismatch (expected, found).
-    SchemaMismatch { expected: u32, found: u32 },
+    /// Schema version mismatch after running migrations.
+    SchemaMismatch {
+        /// Version this build expects ([`CURRENT_SCHEMA_VERSION`]).
+        expected: u32,
+        /// Version recorded in the database.
+        found: u32,
+    },
     /// Database is disabled.
     Disabled,
     /// Database integrity check failed.
@@ -85,10 +90,15 @@ pub enum Outcome {
     Allow,
     /// Command was blocked from execution.
     Deny,
-    /// Command triggered a warning but was allowed.
+    /// Command triggered a warning but was allowed (warn-mode rule).
     Warn,
     /// Command was allowed via bypass (allow-once).
     Bypass,
+    /// Command matched a deny rule but an allowlist entry permitted it.
+    #[serde(rename = "allowlisted")]
+    AllowlistedOverride,
+    /// Evaluation exceeded the hook time budget and failed open (allowed).
+    Timeout,
 }
 
 impl Outcome {
@@ -100,6 +110,8 @@ impl Outcome {
             Self::Deny => "deny",
             Self::Warn => "warn",
             Self::Bypass => "bypass",
+            Self::AllowlistedOverride => "allowlisted",
+            Self::Timeout => "timeout",
         }
     }
 
@@ -109,6 +121,8 @@ impl Outcome {
             "deny" => Some(Self::Deny),
             "warn" => Some(Self::Warn),
             "bypass" => Some(Self::Bypass),
+            "allowlisted" => Some(Self::AllowlistedOverride),
+            "timeout" => Some(Self::Timeout),
             _ => None,
         }
     }
@@ -232,6 +246,8 @@ pub struct OutcomeStats {
     pub denied: u64,
     pub warned: u64,
     pub bypassed: u64,
+    pub allowlisted: u64,
+    pub timed_out: u64,
 }
 
 /// Performance percentiles for history stats.
@@ -266,6 +282,24 @@ pub struct AgentStat {
     pub count: u64,
 }
 
+/// Deny count for one rule, alongside all of its hits in the same window.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockedRuleStat {
+    pub rule_id: String,
+    pub denied: u64,
+    pub hits: u64,
+}
+
+/// Recent activity summary for `dcg stats --history`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivitySummary {
+    pub since: DateTime<Utc>,
+    pub total_commands: u64,
+    pub outcomes: OutcomeStats,
+    pub top_blocked_rules: Vec<BlockedRuleStat>,
+    pub agents: Vec<AgentStat>,
+}
+
 /// Trend comparison for history stats.
 #[derive(Debug, Clone, Serialize)]
 pub struct StatsTrends {
@@ -516,10 +550,54 @@ struct StatsSnapshot {
     performance: PerformanceStats,
 }
 
+/// Column list matching [`command_entry_from_row`].
+const COMMAND_ENTRY_COLUMNS: &str = "timestamp, agent_type, working_dir, command, outcome,
+    pack_id, pattern_name, rule_id, eval_duration_us, session_id,
+    exit_code, parent_command_id, hostname, allowlist_layer, bypass_code";
+
+/// Build a `CommandEntry` from a row selected with [`COMMAND_ENTRY_COLUMNS`].
+fn command_entry_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<CommandEntry> {
+    let timestamp_str: String = row.get(0)?;
+    let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
+        .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
+
+    let outcome_str: String = row.get(4)?;
+    let outcome = Outcome::parse(&outcome_str).unwrap_or(Outcome::Allow);
+
+    let eval_duration_us: i64 = row.get(8)?;
+
+    Ok(CommandEntry {
+        timestamp,
+        agent_type: row.get(1)?,
+        working_dir: row.get(2)?,
+        command: row.get(3)?,
+        outcome,
+        pack_id: row.get(5)?,
+        pattern_name: row.get(6)?,
+        rule_id: row.get(7)?,
+        eval_duration_us: u64::try_from(eval_duration_us).unwrap_or(0),
+        session_id: row.get(9)?,
+        exit_code: row.get(10)?,
+        parent_command_id: row.get(11)?,
+        hostname: row.get(12)?,
+        allowlist_layer: row.get(13)?,
+        bypass_code: row.get(14)?,
+    })
+}
+
 fn format_timestamp(dt: DateTime<Utc>) -> String {
     dt.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
 }
 
+/// Quote user input as one FTS5 phrase whose last token is a prefix match.
+///
+/// Embedded double quotes are doubled, which is the only escaping FTS5 needs
+/// inside a string; everything else (operators, `-`, `:`, `*`) loses its
+/// special meaning once quoted.
+fn fts_phrase_query(query: &str) -> String {
+    format!("\"{}\" *", query.trim().replace('"', "\"\""))
+}
+
 fn percentile_from_sorted(values: &[u64], numerator: usize, denominator: usize) -> u64 {
     if values.is_empty() || denominator == 0 {
         return 0;
@@ -580,17 +658,12 @@ fn build_trends(current: &StatsSnapshot, previous: &StatsSnapshot) -> StatsTrend
 }
 
 impl CommandEntry {
-    /// Compute a SHA256 hash of the command for deduplication/grouping.
+    /// Compute the command's fingerprint for deduplication/grouping.
+    ///
+    /// See [`crate::fingerprint::command_fingerprint`].
     #[must_use]
     pub fn command_hash(&self) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(self.command.as_bytes());
-        let digest = hasher.finalize();
-        let mut hex = String::with_capacity(digest.len() * 2);
-        for byte in digest {
-            let _ = write!(hex, "{byte:02x}");
-        }
-        hex
+        crate::fingerprint::command_fingerprint(&self.command)
     }
 }
 
@@ -760,6 +833,74 @@ impl HistoryDb {
         Ok(u64::try_from(count).unwrap_or(0))
     }
 
+    /// Prune entries older than `older_than`, then, if more than `max_rows`
+    /// entries remain, delete the oldest until `max_rows` are left.
+    ///
+    /// Deleted rows are removed from `commands_fts` by the `commands_fts_delete`
+    /// trigger, so full-text search stays in sync. Both steps run in one
+    /// transaction. Returns the number of rows deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any query fails; nothing is deleted in that case.
+    pub fn prune(
+        &self,
+        older_than: Duration,
+        max_rows: Option<usize>,
+    ) -> Result<u64, HistoryError> {
+        self.prune_inner(older_than, max_rows, false)
+    }
+
+    /// Count the rows [`HistoryDb::prune`] would delete, without deleting them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any query fails.
+    pub fn preview_prune(
+        &self,
+        older_than: Duration,
+        max_rows: Option<usize>,
+    ) -> Result<u64, HistoryError> {
+        self.prune_inner(older_than, max_rows, true)
+    }
+
+    fn prune_inner(
+        &self,
+        older_than: Duration,
+        max_rows: Option<usize>,
+        dry_run: bool,
+    ) -> Result<u64, HistoryError> {
+        let cutoff = Utc::now()
+            .checked_sub_signed(older_than)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let cutoff_ts = format_timestamp(cutoff);
+
+        let tx = self.conn.unchecked_transaction()?;
+        let mut deleted = tx.execute("DELETE FROM commands WHERE timestamp < ?1", [cutoff_ts])?;
+
+        if let Some(max_rows) = max_rows {
+            let remaining: i64 =
+                tx.query_row("SELECT COUNT(*) FROM commands", [], |row| row.get(0))?;
+            let excess = remaining.saturating_sub(i64::try_from(max_rows).unwrap_or(i64::MAX));
+            if excess > 0 {
+                deleted += tx.execute(
+                    "DELETE FROM commands WHERE id IN (
+                        SELECT id FROM commands ORDER BY timestamp ASC, id ASC LIMIT ?1
+                    )",
+                    [excess],
+                )?;
+            }
+        }
+
+        if dry_run {
+            tx.rollback()?;
+        } else {
+            tx.commit()?;
+        }
+
+        Ok(u64::try_from(deleted).unwrap_or(u64::MAX))
+    }
+
     /// Compute history stats for the last `period_days` days.
     ///
     /// # Errors
@@ -848,6 +989,8 @@ impl HistoryDb {
                 Some(Outcome::Deny) => outcomes.denied = count,
                 Some(Outcome::Warn) => outcomes.warned = count,
                 Some(Outcome::Bypass) => outcomes.bypassed = count,
+                Some(Outcome::AllowlistedOverride) => outcomes.allowlisted = count,
+                Some(Outcome::Timeout) => outcomes.timed_out = count,
                 None => {}
             }
         }
@@ -1066,7 +1209,7 @@ impl HistoryDb {
                 working_dir TEXT NOT NULL,
                 command TEXT NOT NULL,
                 command_hash TEXT NOT NULL,
-                outcome TEXT NOT NULL CHECK (outcome IN ('allow', 'deny', 'warn', 'bypass')),
+                outcome TEXT NOT NULL CHECK (outcome IN ('allow', 'deny', 'warn', 'bypass', 'allowlisted', 'timeout')),
                 pack_id TEXT,
                 pattern_name TEXT,
                 rule_id TEXT,
@@ -1224,6 +1367,12 @@ impl HistoryDb {
         if from_version < 5 {
             self.migrate_v4_to_v5()?;
         }
+        if from_version < 6 {
+            self.migrate_v5_to_v6()?;
+        }
+        if from_version < 7 {
+            self.migrate_v6_to_v7()?;
+        }
 
         // Ensure we're at the expected version
         let current = self.get_schema_version()?;
@@ -1388,6 +1537,127 @@ impl HistoryDb {
         Ok(())
     }
 
+    /// Widen the `outcome` CHECK constraint to accept `allowlisted`.
+    fn migrate_v5_to_v6(&self) -> Result<(), HistoryError> {
+        self.rebuild_commands_with_outcomes(
+            "'allow', 'deny', 'warn', 'bypass', 'allowlisted'",
+            6,
+            "Add allowlisted outcome for allowlist overrides",
+        )
+    }
+
+    /// Widen the `outcome` CHECK constraint to accept `timeout`.
+    fn migrate_v6_to_v7(&self) -> Result<(), HistoryError> {
+        self.rebuild_commands_with_outcomes(
+            "'allow', 'deny', 'warn', 'bypass', 'allowlisted', 'timeout'",
+            7,
+            "Add timeout outcome for fail-open budget overruns",
+        )
+    }
+
+    /// Rebuild the commands table with a new `outcome` CHECK constraint.
+    ///
+    /// SQLite cannot alter a CHECK constraint in place, so the commands table
+    /// is rebuilt. Row ids are preserved so the external-content FTS index
+    /// stays valid; existing rows keep their outcomes.
+    #[allow(clippy::too_many_lines)]
+    fn rebuild_commands_with_outcomes(
+        &self,
+        outcomes: &str,
+        version: u32,
+        description: &str,
+    ) -> Result<(), HistoryError> {
+        const COLUMNS: &str = "id, timestamp, agent_type, working_dir, command, command_hash, \
+             outcome, pack_id, pattern_name, rule_id, eval_duration_us, session_id, exit_code, \
+             parent_command_id, hostname, allowlist_layer, bypass_code";
+
+        let tx = self.conn.unchecked_transaction()?;
+
+        tx.execute(
+            &format!(
+                r"CREATE TABLE commands_rebuilt (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp TEXT NOT NULL,
+                agent_type TEXT NOT NULL,
+                working_dir TEXT NOT NULL,
+                command TEXT NOT NULL,
+                command_hash TEXT NOT NULL,
+                outcome TEXT NOT NULL CHECK (outcome IN ({outcomes})),
+                pack_id TEXT,
+                pattern_name TEXT,
+                rule_id TEXT,
+                eval_duration_us INTEGER DEFAULT 0,
+                session_id TEXT,
+                exit_code INTEGER,
+                parent_command_id INTEGER REFERENCES commands(id),
+                hostname TEXT,
+                allowlist_layer TEXT,
+                bypass_code TEXT
+            )"
+            ),
+            [],
+        )?;
+
+        tx.execute(
+            &format!("INSERT INTO commands_rebuilt ({COLUMNS}) SELECT {COLUMNS} FROM commands"),
+            [],
+        )?;
+
+        // Drop the FTS triggers before the old table so the drop doesn't touch
+        // the FTS index; they are recreated against the new table below.
+        tx.execute_batch(
+            r"
+            DROP TRIGGER IF EXISTS commands_fts_insert;
+            DROP TRIGGER IF EXISTS commands_fts_delete;
+            DROP TRIGGER IF EXISTS commands_fts_update;
+            DROP TABLE commands;
+            ALTER TABLE commands_rebuilt RENAME TO commands;
+            ",
+        )?;
+
+        tx.execute_batch(
+            r"
+            CREATE INDEX IF NOT EXISTS idx_commands_timestamp ON commands(timestamp);
+            CREATE INDEX IF NOT EXISTS idx_commands_outcome ON commands(outcome);
+            CREATE INDEX IF NOT EXISTS idx_commands_working_dir ON commands(working_dir);
+            CREATE INDEX IF NOT EXISTS idx_commands_pack_id ON commands(pack_id);
+            CREATE INDEX IF NOT EXISTS idx_commands_rule_id ON commands(rule_id)
+                WHERE rule_id IS NOT NULL;
+            CREATE INDEX IF NOT EXISTS idx_commands_agent_type ON commands(agent_type);
+            CREATE INDEX IF NOT EXISTS idx_commands_session_id ON commands(session_id);
+            CREATE INDEX IF NOT EXISTS idx_commands_command_hash ON commands(command_hash);
+            CREATE INDEX IF NOT EXISTS idx_commands_outcome_timestamp
+                ON commands(outcome, timestamp);
+            CREATE INDEX IF NOT EXISTS idx_commands_pack_outcome
+                ON commands(pack_id, outcome);
+
+            CREATE TRIGGER IF NOT EXISTS commands_fts_insert AFTER INSERT ON commands BEGIN
+                INSERT INTO commands_fts(rowid, command) VALUES (new.id, new.command);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS commands_fts_delete AFTER DELETE ON commands BEGIN
+                INSERT INTO commands_fts(commands_fts, rowid, command)
+                    VALUES('delete', old.id, old.command);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS commands_fts_update AFTER UPDATE ON commands BEGIN
+                INSERT INTO commands_fts(commands_fts, rowid, command)
+                    VALUES('delete', old.id, old.command);
+                INSERT INTO commands_fts(rowid, command) VALUES (new.id, new.command);
+            END;
+            ",
+        )?;
+
+        // Record migration
+        tx.execute(
+            "INSERT INTO schema_version (version, description) VALUES (?1, ?2)",
+            params![version, description],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
     // ========================================================================
     // Batch Operations
     // ========================================================================
@@ -1859,20 +2129,43 @@ impl HistoryDb {
 
     /// Query commands for export with optional filtering.
     ///
+    /// This collects every matching row; use [`HistoryDb::for_each_export_entry`]
+    /// to visit large result sets without buffering them.
+    ///
     /// # Errors
     ///
     /// Returns an error if the query fails.
-    #[allow(clippy::redundant_closure_for_method_calls)]
     pub fn query_commands_for_export(
         &self,
         options: &ExportOptions,
     ) -> Result<Vec<CommandEntry>, HistoryError> {
-        let mut sql = String::from(
-            "SELECT timestamp, agent_type, working_dir, command, outcome,
-                    pack_id, pattern_name, rule_id, eval_duration_us, session_id,
-                    exit_code, parent_command_id, hostname, allowlist_layer, bypass_code
-             FROM commands WHERE 1=1",
-        );
+        let mut entries = Vec::new();
+        self.for_each_export_entry(options, |entry| {
+            entries.push(entry);
+            Ok(())
+        })?;
+        Ok(entries)
+    }
+
+    /// Visit each command matching `options`, newest first, one row at a time.
+    ///
+    /// Rows are read from SQLite as the callback consumes them, so memory use
+    /// stays flat regardless of table size. Returns the number of rows visited.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the callback returns an error;
+    /// iteration stops at the first error.
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn for_each_export_entry<F>(
+        &self,
+        options: &ExportOptions,
+        mut visit: F,
+    ) -> Result<usize, HistoryError>
+    where
+        F: FnMut(CommandEntry) -> Result<(), HistoryError>,
+    {
+        let mut sql = format!("SELECT {COMMAND_ENTRY_COLUMNS} FROM commands WHERE 1=1");
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
         if let Some(outcome) = &options.outcome_filter {
@@ -1899,34 +2192,67 @@ impl HistoryDb {
 
         let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
         let mut stmt = self.conn.prepare(&sql)?;
-        let rows = stmt.query_map(param_refs.as_slice(), |row| {
-            let timestamp_str: String = row.get(0)?;
-            let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
-                .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
+        let rows = stmt.query_map(param_refs.as_slice(), command_entry_from_row)?;
 
-            let outcome_str: String = row.get(4)?;
-            let outcome = Outcome::parse(&outcome_str).unwrap_or(Outcome::Allow);
+        let mut count = 0;
+        for row in rows {
+            visit(row?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Get every command recorded for a session, in the order it was run.
+    ///
+    /// Commands are ordered by timestamp (ties broken by insertion order), so
+    /// the result reconstructs the sequence of commands an agent issued.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn session(&self, id: &str) -> Result<Vec<CommandEntry>, HistoryError> {
+        let sql = format!(
+            "SELECT {COMMAND_ENTRY_COLUMNS} FROM commands
+             WHERE session_id = ?1
+             ORDER BY timestamp ASC, id ASC"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(params![id], command_entry_from_row)?;
 
-            let eval_duration_us: i64 = row.get(8)?;
+        let mut entries = Vec::new();
+        for row in rows {
+            entries.push(row?);
+        }
+        Ok(entries)
+    }
 
-            Ok(CommandEntry {
-                timestamp,
-                agent_type: row.get(1)?,
-                working_dir: row.get(2)?,
-                command: row.get(3)?,
-                outcome,
-                pack_id: row.get(5)?,
-                pattern_name: row.get(6)?,
-                rule_id: row.get(7)?,
-                eval_duration_us: u64::try_from(eval_duration_us).unwrap_or(0),
-                session_id: row.get(9)?,
-                exit_code: row.get(10)?,
-                parent_command_id: row.get(11)?,
-                hostname: row.get(12)?,
-                allowlist_layer: row.get(13)?,
-                bypass_code: row.get(14)?,
-            })
-        })?;
+    /// Full-text search over logged command text, newest first.
+    ///
+    /// The query is matched as a single phrase through `commands_fts`, with the
+    /// last word treated as a prefix, so `rm -rf` finds `rm -rf ./build` and
+    /// `git res` finds `git reset --hard`. FTS5 operators in the query
+    /// (`AND`, `*`, `"`, `:` ...) are taken literally rather than parsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<CommandEntry>, HistoryError> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let sql = format!(
+            "SELECT {COMMAND_ENTRY_COLUMNS} FROM commands
+             WHERE id IN (SELECT rowid FROM commands_fts WHERE commands_fts MATCH ?1)
+             ORDER BY timestamp DESC, id DESC
+             LIMIT ?2"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows = stmt.query_map(
+            params![fts_phrase_query(query), limit],
+            command_entry_from_row,
+        )?;
 
         let mut entries = Vec::new();
         for row in rows {
@@ -1969,26 +2295,23 @@ impl HistoryDb {
 
     /// Export commands to JSONL (JSON Lines) format for streaming.
     ///
-    /// Each line is a valid JSON object representing one command.
+    /// Each line is a valid JSON object representing one command. Rows are
+    /// written as they are read, so the table is never held in memory.
     ///
     /// # Errors
     ///
-    /// Returns an error if the query or serialization fails.
+    /// Returns an error if the query, serialization, or write fails.
     pub fn export_jsonl<W: std::io::Write>(
         &self,
         writer: &mut W,
         options: &ExportOptions,
     ) -> Result<usize, HistoryError> {
-        let entries = self.query_commands_for_export(options)?;
-        let count = entries.len();
-
-        for entry in &entries {
-            serde_json::to_writer(&mut *writer, entry)
+        self.for_each_export_entry(options, |entry| {
+            serde_json::to_writer(&mut *writer, &entry)
                 .map_err(|e| HistoryError::Io(std::io::Error::other(e)))?;
             writeln!(writer)?;
-        }
-
-        Ok(count)
+            Ok(())
+        })
     }
 
     /// Export commands to CSV format.
@@ -2003,17 +2326,14 @@ impl HistoryDb {
         writer: &mut W,
         options: &ExportOptions,
     ) -> Result<usize, HistoryError> {
-        let entries = self.query_commands_for_export(options)?;
-        let count = entries.len();
-
         // Write header
         writeln!(
             writer,
             "timestamp,agent_type,working_dir,command,outcome,pack_id,pattern_name,eval_duration_us"
         )?;
 
-        // Write data rows
-        for entry in &entries {
+        // Write data rows as they are read
+        self.for_each_export_entry(options, |entry| {
             writeln!(
                 writer,
                 "{},{},{},{},{},{},{},{}",
@@ -2026,9 +2346,8 @@ impl HistoryDb {
                 entry.pattern_name.as_deref().unwrap_or(""),
                 entry.eval_duration_us,
             )?;
-        }
-
-        Ok(count)
+            Ok(())
+        })
     }
 
     // ========================================================================
@@ -2431,7 +2750,7 @@ impl HistoryDb {
             r"SELECT
                 rule_id,
                 COUNT(*) as total_hits,
-                SUM(CASE WHEN outcome = 'bypass' THEN 1 ELSE 0 END) as overrides,
+                SUM(CASE WHEN outcome IN ('bypass', 'allowlisted') THEN 1 ELSE 0 END) as overrides,
                 MIN(timestamp) as first_seen,
                 MAX(timestamp) as last_seen,
                 COUNT(DISTINCT command_hash) as unique_commands
@@ -2499,6 +2818,55 @@ impl HistoryDb {
         Ok(metrics)
     }
 
+    /// Get the all-time first/last trigger timestamps for every rule.
+    ///
+    /// Unlike [`Self::get_rule_metrics`], this ignores any time window, so it
+    /// shows when agents started hitting a rule and whether it still fires.
+    /// Ordered by most recently triggered first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn rule_timeline(&self) -> Result<Vec<RuleTimeline>, HistoryError> {
+        let mut stmt = self.conn.prepare(
+            r"SELECT
+                rule_id,
+                MIN(timestamp) as first_seen,
+                MAX(timestamp) as last_seen,
+                COUNT(*) as total_hits
+             FROM commands
+             WHERE rule_id IS NOT NULL
+             GROUP BY rule_id
+             ORDER BY last_seen DESC, rule_id ASC",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, i64>(3)?,
+            ))
+        })?;
+
+        let mut timeline = Vec::new();
+        for row in rows {
+            let (rule_id, first_seen_str, last_seen_str, total_hits) = row?;
+            let first_seen = chrono::DateTime::parse_from_rfc3339(&first_seen_str)
+                .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
+            let last_seen = chrono::DateTime::parse_from_rfc3339(&last_seen_str)
+                .map_or_else(|_| Utc::now(), |dt| dt.with_timezone(&Utc));
+            timeline.push(RuleTimeline {
+                rule_id,
+                first_seen,
+                last_seen,
+                total_hits: u64::try_from(total_hits).unwrap_or(0),
+            });
+        }
+
+        Ok(timeline)
+    }
+
     /// Get metrics for a specific rule.
     ///
     /// # Errors
@@ -2511,7 +2879,7 @@ impl HistoryDb {
         let mut stmt = self.conn.prepare(
             r"SELECT
                 COUNT(*) as total_hits,
-                COALESCE(SUM(CASE WHEN outcome = 'bypass' THEN 1 ELSE 0 END), 0) as overrides,
+                COALESCE(SUM(CASE WHEN outcome IN ('bypass', 'allowlisted') THEN 1 ELSE 0 END), 0) as overrides,
                 MIN(timestamp) as first_seen,
                 MAX(timestamp) as last_seen,
                 COUNT(DISTINCT command_hash) as unique_commands
@@ -2592,7 +2960,7 @@ impl HistoryDb {
             r"SELECT
                 rule_id,
                 COUNT(*) as total_hits,
-                SUM(CASE WHEN outcome = 'bypass' THEN 1 ELSE 0 END) as overrides,
+                SUM(CASE WHEN outcome IN ('bypass', 'allowlisted') THEN 1 ELSE 0 END) as overrides,
                 MIN(timestamp) as first_seen,
                 MAX(timestamp) as last_seen,
                 COUNT(DISTINCT command_hash) as unique_commands
@@ -2661,6 +3029,99 @@ impl HistoryDb {
         Ok(metrics)
     }
 
+    /// Count denied commands per rule, most-blocked first.
+    ///
+    /// Ties are broken by rule id so the ranking is stable.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of rules to return
+    /// * `since` - Optional start time (defaults to all time)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn top_blocked_rules(
+        &self,
+        limit: usize,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<(String, u64)>, HistoryError> {
+        let since_ts = since.map_or_else(
+            || "1970-01-01T00:00:00Z".to_string(),
+            |dt| dt.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+        );
+
+        let mut stmt = self.conn.prepare(
+            r"SELECT rule_id, COUNT(*) as blocks
+             FROM commands
+             WHERE rule_id IS NOT NULL
+               AND outcome = ?1
+               AND timestamp >= ?2
+             GROUP BY rule_id
+             ORDER BY blocks DESC, rule_id ASC
+             LIMIT ?3",
+        )?;
+
+        let limit_i64 = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows = stmt.query_map(
+            params![Outcome::Deny.as_str(), &since_ts, limit_i64],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)),
+        )?;
+
+        let mut ranked = Vec::new();
+        for row in rows {
+            let (rule_id, blocks) = row?;
+            ranked.push((rule_id, u64::try_from(blocks).unwrap_or(0)));
+        }
+        Ok(ranked)
+    }
+
+    /// Summarize activity since `since`: totals, outcome breakdown, the `top`
+    /// most-blocked rules, and commands per agent type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any underlying query fails.
+    pub fn activity_summary(
+        &self,
+        since: DateTime<Utc>,
+        top: usize,
+    ) -> Result<ActivitySummary, HistoryError> {
+        let snapshot = self.compute_stats_range(since, Utc::now())?;
+        let blocked = self.top_blocked_rules(top, Some(since))?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT rule_id, COUNT(*) FROM commands
+             WHERE rule_id IS NOT NULL AND timestamp >= ?1
+             GROUP BY rule_id",
+        )?;
+        let rows = stmt.query_map(params![format_timestamp(since)], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?;
+        let mut hits_by_rule = HashMap::new();
+        for row in rows {
+            let (rule_id, hits) = row?;
+            hits_by_rule.insert(rule_id, u64::try_from(hits).unwrap_or(0));
+        }
+
+        let top_blocked_rules = blocked
+            .into_iter()
+            .map(|(rule_id, denied)| BlockedRuleStat {
+                hits: hits_by_rule.get(&rule_id).copied().unwrap_or(denied),
+                rule_id,
+                denied,
+            })
+            .collect();
+
+        Ok(ActivitySummary {
+            since,
+            total_commands: snapshot.total_commands,
+            outcomes: snapshot.outcomes,
+            top_blocked_rules,
+            agents: snapshot.agents,
+        })
+    }
+
     /// Calculate trend by comparing recent vs previous period.
     ///
     /// Returns (trend, `previous_period_hits`, `change_percentage`, `is_anomaly`).
@@ -3023,7 +3484,7 @@ pub struct RuleMetrics {
     pub rule_id: String,
     /// Total times this rule triggered (deny + bypass + warn).
     pub total_hits: u64,
-    /// Times the rule resulted in allowlist override (bypass).
+    /// Times the rule was overridden (allowlist entry or bypass).
     pub allowlist_overrides: u64,
     /// Override rate as a percentage (0.0-100.0).
     pub override_rate: f64,
@@ -3045,6 +3506,19 @@ pub struct RuleMetrics {
     pub is_anomaly: bool,
 }
 
+/// All-time first/last trigger timestamps for a rule.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleTimeline {
+    /// Stable rule identifier (`pack_id:pattern_name`).
+    pub rule_id: String,
+    /// When this rule was first triggered.
+    pub first_seen: DateTime<Utc>,
+    /// When this rule was last triggered.
+    pub last_seen: DateTime<Utc>,
+    /// Total times this rule triggered.
+    pub total_hits: u64,
+}
+
 impl RuleMetrics {
     /// Threshold for considering a rule noisy.
     pub const NOISY_THRESHOLD: f64 = 30.0;
@@ -3807,6 +4281,132 @@ mod tests {
         assert!(description_count > 0);
     }
 
+    #[test]
+    fn test_migration_v5_to_v6_keeps_rows_and_accepts_allowlisted() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let earlier = Utc::now() - Duration::hours(1);
+        db.log_command(&CommandEntry {
+            command: "git status".to_string(),
+            outcome: Outcome::Allow,
+            timestamp: earlier,
+            ..Default::default()
+        })
+        .unwrap();
+        db.log_command(&CommandEntry {
+            command: "git reset --hard".to_string(),
+            outcome: Outcome::Deny,
+            pack_id: Some("core.git".to_string()),
+            pattern_name: Some("reset-hard".to_string()),
+            timestamp: earlier,
+            ..Default::default()
+        })
+        .unwrap();
+
+        // Rebuild the commands table with the v5 CHECK constraint.
+        db.conn
+            .execute_batch(
+                r"
+                CREATE TABLE commands_v5 (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    timestamp TEXT NOT NULL,
+                    agent_type TEXT NOT NULL,
+                    working_dir TEXT NOT NULL,
+                    command TEXT NOT NULL,
+                    command_hash TEXT NOT NULL,
+                    outcome TEXT NOT NULL CHECK (outcome IN ('allow', 'deny', 'warn', 'bypass')),
+                    pack_id TEXT,
+                    pattern_name TEXT,
+                    rule_id TEXT,
+                    eval_duration_us INTEGER DEFAULT 0,
+                    session_id TEXT,
+                    exit_code INTEGER,
+                    parent_command_id INTEGER REFERENCES commands(id),
+                    hostname TEXT,
+                    allowlist_layer TEXT,
+                    bypass_code TEXT
+                );
+                INSERT INTO commands_v5 SELECT * FROM commands;
+                DROP TABLE commands;
+                ALTER TABLE commands_v5 RENAME TO commands;
+                DELETE FROM schema_version WHERE version >= 6;
+                ",
+            )
+            .unwrap();
+        assert!(
+            db.conn
+                .execute(
+                    "UPDATE commands SET outcome = 'allowlisted' WHERE command = 'git status'",
+                    [],
+                )
+                .is_err()
+        );
+
+        db.run_migrations(5).unwrap();
+        assert_eq!(db.get_schema_version().unwrap(), CURRENT_SCHEMA_VERSION);
+
+        // Old rows are carried over untouched.
+        let outcomes: Vec<(String, String, Option<String>)> = db
+            .conn
+            .prepare("SELECT command, outcome, rule_id FROM commands ORDER BY id")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("git status".to_string(), "allow".to_string(), None),
+                (
+                    "git reset --hard".to_string(),
+                    "deny".to_string(),
+                    Some("core.git:reset-hard".to_string())
+                ),
+            ]
+        );
+
+        // The new outcome is accepted and FTS stays in sync.
+        db.log_command(&CommandEntry {
+            command: "git clean -fd".to_string(),
+            outcome: Outcome::AllowlistedOverride,
+            pack_id: Some("core.git".to_string()),
+            pattern_name: Some("clean-force".to_string()),
+            timestamp: earlier,
+            ..Default::default()
+        })
+        .unwrap();
+        let git_matches: i64 = db
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM commands_fts WHERE commands_fts MATCH 'git'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(git_matches, 3);
+
+        let stats = db.compute_stats(30).unwrap();
+        assert_eq!(stats.outcomes.allowlisted, 1);
+        assert_eq!(stats.outcomes.allowed, 1);
+        assert_eq!(stats.outcomes.denied, 1);
+    }
+
+    #[test]
+    fn test_timeout_outcome_is_stored_and_counted() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        db.log_command(&CommandEntry {
+            command: "python3 <<'EOF' ... EOF".to_string(),
+            outcome: Outcome::Timeout,
+            timestamp: Utc::now() - Duration::hours(1),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let stats = db.compute_stats(30).unwrap();
+        assert_eq!(stats.outcomes.timed_out, 1);
+        assert_eq!(stats.outcomes.allowed, 0);
+    }
+
     #[test]
     fn test_command_hash_deterministic() {
         let entry1 = CommandEntry {
@@ -3820,6 +4420,12 @@ mod tests {
 
         assert_eq!(entry1.command_hash(), entry2.command_hash());
         assert_eq!(entry1.command_hash().len(), 64); // SHA256 = 64 hex chars
+
+        let spaced = CommandEntry {
+            command: "git   status ".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(entry1.command_hash(), spaced.command_hash());
     }
 
     #[test]
@@ -3829,6 +4435,8 @@ mod tests {
             Outcome::Deny,
             Outcome::Warn,
             Outcome::Bypass,
+            Outcome::AllowlistedOverride,
+            Outcome::Timeout,
         ] {
             let s = outcome.as_str();
             let parsed = Outcome::parse(s).unwrap();
@@ -3923,6 +4531,91 @@ mod tests {
         assert_eq!(db.count_commands().unwrap(), 1);
     }
 
+    #[test]
+    fn test_search_matches_phrases_newest_first() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let now = Utc::now();
+        let commands = [
+            (5, "rm -rf ./build", Outcome::Allow),
+            (4, "git reset --hard HEAD~1", Outcome::Deny),
+            (3, "echo \"rm -rf\" AND NOT done", Outcome::Allow),
+            (2, "rm -rf /", Outcome::Deny),
+            (1, "ls -la", Outcome::Allow),
+        ];
+        for (minutes_ago, command, outcome) in commands {
+            let mut entry = test_entry();
+            entry.timestamp = now - Duration::minutes(minutes_ago);
+            entry.command = command.to_string();
+            entry.outcome = outcome;
+            db.log_command(&entry).unwrap();
+        }
+
+        let found: Vec<String> = db
+            .search("rm -rf", 10)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.command)
+            .collect();
+        assert_eq!(
+            found,
+            vec!["rm -rf /", "echo \"rm -rf\" AND NOT done", "rm -rf ./build"]
+        );
+
+        let reset = db.search("git res", 10).unwrap();
+        assert_eq!(reset.len(), 1);
+        assert_eq!(reset[0].outcome, Outcome::Deny);
+
+        assert_eq!(db.search("rm -rf", 1).unwrap().len(), 1);
+        assert!(db.search("kubectl", 10).unwrap().is_empty());
+        assert!(db.search("   ", 10).unwrap().is_empty());
+
+        // FTS5 syntax in the query is treated as literal text, not parsed.
+        assert_eq!(db.search("\"rm -rf\" AND NOT", 10).unwrap().len(), 1);
+        assert!(db.search("NEAR(rm", 10).unwrap().is_empty());
+        assert!(db.search("--", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prune_by_age_then_max_rows_keeps_fts_in_sync() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let now = Utc::now();
+
+        for days_ago in [90, 60, 45, 10, 5, 3, 1] {
+            let mut entry = test_entry();
+            entry.timestamp = now - Duration::days(days_ago);
+            entry.command = format!("git status # {days_ago} days ago");
+            db.log_command(&entry).unwrap();
+        }
+
+        assert_eq!(db.preview_prune(Duration::days(30), Some(2)).unwrap(), 5);
+        assert_eq!(db.count_commands().unwrap(), 7);
+
+        let pruned = db.prune(Duration::days(30), Some(2)).unwrap();
+        assert_eq!(pruned, 5);
+        assert_eq!(db.count_commands().unwrap(), 2);
+
+        let fts_count: i64 = db
+            .conn
+            .query_row("SELECT COUNT(*) FROM commands_fts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(fts_count, 2);
+        let remaining: Vec<String> = db
+            .conn
+            .prepare("SELECT command FROM commands ORDER BY timestamp")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            remaining,
+            vec!["git status # 3 days ago", "git status # 1 days ago"]
+        );
+
+        // Nothing left to prune by age, and already under the row cap.
+        assert_eq!(db.prune(Duration::days(30), Some(10)).unwrap(), 0);
+    }
+
     #[test]
     fn test_file_size_in_memory() {
         let db = HistoryDb::open_in_memory().unwrap();
@@ -4118,6 +4811,58 @@ mod tests {
         assert_eq!(content.lines().count(), 50);
     }
 
+    #[test]
+    fn test_jsonl_export_roundtrips_filtered_entries() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let now = Utc::now();
+        let logged = [
+            (40, "git reset --hard", Outcome::Deny),
+            (3, "rm -rf ./dist", Outcome::Deny),
+            (2, "git status", Outcome::Allow),
+            (1, "git push --force", Outcome::Deny),
+        ];
+        for (days_ago, command, outcome) in logged {
+            let mut entry = test_entry();
+            entry.timestamp = now - Duration::days(days_ago);
+            entry.command = command.to_string();
+            entry.outcome = outcome;
+            entry.pack_id = Some("core.git".to_string());
+            entry.session_id = Some("session-1".to_string());
+            db.log_command(&entry).unwrap();
+        }
+
+        let mut buf = Vec::new();
+        let count = db
+            .export_jsonl(
+                &mut buf,
+                &ExportOptions {
+                    outcome_filter: Some(Outcome::Deny),
+                    since: Some(now - Duration::days(7)),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(count, 2);
+
+        let parsed: Vec<CommandEntry> = String::from_utf8(buf)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let commands: Vec<&str> = parsed.iter().map(|e| e.command.as_str()).collect();
+        assert_eq!(commands, vec!["git push --force", "rm -rf ./dist"]);
+        for entry in &parsed {
+            assert_eq!(entry.outcome, Outcome::Deny);
+            assert_eq!(entry.pack_id.as_deref(), Some("core.git"));
+            assert_eq!(entry.session_id.as_deref(), Some("session-1"));
+            assert_eq!(entry.working_dir, "/test/project");
+        }
+        assert_eq!(
+            parsed[0].timestamp.timestamp_millis(),
+            (now - Duration::days(1)).timestamp_millis()
+        );
+    }
+
     #[test]
     fn test_export_with_outcome_filter() {
         let db = create_test_db_with_mixed_outcomes(100);
@@ -4873,6 +5618,39 @@ mod tests {
         assert_eq!(stored.working_dir, Some("/dangerous/path".to_string()));
     }
 
+    #[test]
+    fn test_session_returns_commands_in_timestamp_order() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let start = Utc::now() - chrono::Duration::minutes(10);
+
+        for (offset, command, session) in [
+            (2, "rm -rf build", "ses-a"),
+            (0, "git status", "ses-a"),
+            (1, "ls -la", "ses-b"),
+            (1, "cargo test", "ses-a"),
+        ] {
+            let entry = CommandEntry {
+                timestamp: start + chrono::Duration::seconds(offset),
+                command: command.to_string(),
+                session_id: Some(session.to_string()),
+                ..test_entry()
+            };
+            db.log_command(&entry).unwrap();
+        }
+        db.log_command(&test_entry()).unwrap();
+
+        let commands: Vec<String> = db
+            .session("ses-a")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.command)
+            .collect();
+        assert_eq!(commands, vec!["git status", "cargo test", "rm -rf build"]);
+
+        assert_eq!(db.session("ses-b").unwrap().len(), 1);
+        assert!(db.session("ses-missing").unwrap().is_empty());
+    }
+
     #[test]
     fn test_suggestion_audit_with_null_optional_fields() {
         let db = HistoryDb::open_in_memory().unwrap();
@@ -4968,6 +5746,121 @@ mod tests {
         assert_eq!(metrics[1].total_hits, 3);
     }
 
+    #[test]
+    fn test_rule_timeline_spans_all_time() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let now = Utc::now();
+        let long_ago = now - Duration::days(400);
+        let last_month = now - Duration::days(30);
+
+        insert_rule_entry(&db, "pack:stale", Outcome::Deny, long_ago, "stale-1");
+        insert_rule_entry(&db, "pack:stale", Outcome::Deny, last_month, "stale-2");
+        insert_rule_entry(&db, "pack:active", Outcome::Deny, long_ago, "active-1");
+        insert_rule_entry(&db, "pack:active", Outcome::Bypass, now, "active-2");
+        insert_rule_entry(&db, "pack:active", Outcome::Deny, now, "active-3");
+
+        let timeline = db.rule_timeline().unwrap();
+        assert_eq!(timeline.len(), 2);
+
+        // Most recently triggered first.
+        assert_eq!(timeline[0].rule_id, "pack:active");
+        assert_eq!(timeline[0].total_hits, 3);
+        assert!((timeline[0].first_seen - long_ago).num_seconds().abs() <= 1);
+        assert!((timeline[0].last_seen - now).num_seconds().abs() <= 1);
+
+        assert_eq!(timeline[1].rule_id, "pack:stale");
+        assert_eq!(timeline[1].total_hits, 2);
+        assert!((timeline[1].first_seen - long_ago).num_seconds().abs() <= 1);
+        assert!((timeline[1].last_seen - last_month).num_seconds().abs() <= 1);
+    }
+
+    #[test]
+    fn test_top_blocked_rules_orders_by_deny_count() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        let now = Utc::now();
+        let last_month = now - Duration::days(30);
+
+        for i in 0..3 {
+            insert_rule_entry(
+                &db,
+                "core.git:reset-hard",
+                Outcome::Deny,
+                now,
+                &format!("a{i}"),
+            );
+        }
+        insert_rule_entry(&db, "core.git:clean-force", Outcome::Deny, now, "b0");
+        insert_rule_entry(&db, "core.git:clean-force", Outcome::Deny, last_month, "b1");
+        insert_rule_entry(&db, "core.git:clean-force", Outcome::Bypass, now, "b2");
+        insert_rule_entry(&db, "core.git:push-force", Outcome::Deny, now, "c0");
+        insert_rule_entry(&db, "core.git:push-force", Outcome::Deny, now, "c1");
+        insert_rule_entry(&db, "core.git:branch-delete", Outcome::Warn, now, "d0");
+
+        let top = db.top_blocked_rules(10, None).unwrap();
+        assert_eq!(
+            top,
+            vec![
+                ("core.git:reset-hard".to_string(), 3),
+                ("core.git:clean-force".to_string(), 2),
+                ("core.git:push-force".to_string(), 2),
+            ]
+        );
+
+        let recent = db
+            .top_blocked_rules(2, Some(now - Duration::days(7)))
+            .unwrap();
+        assert_eq!(
+            recent,
+            vec![
+                ("core.git:reset-hard".to_string(), 3),
+                ("core.git:push-force".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_activity_summary_window_and_agents() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        // Just before `now`: the summary window ends at the current instant.
+        let now = Utc::now() - Duration::seconds(1);
+        let long_ago = now - Duration::days(30);
+
+        insert_rule_entry(&db, "core.git:reset-hard", Outcome::Deny, now, "a0");
+        insert_rule_entry(&db, "core.git:reset-hard", Outcome::Deny, now, "a1");
+        insert_rule_entry(&db, "core.git:reset-hard", Outcome::Bypass, now, "a2");
+        insert_rule_entry(&db, "core.git:reset-hard", Outcome::Deny, long_ago, "a3");
+        db.log_command(&CommandEntry {
+            timestamp: now,
+            agent_type: "codex".to_string(),
+            working_dir: "/test".to_string(),
+            command: "ls".to_string(),
+            outcome: Outcome::Allow,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let summary = db.activity_summary(now - Duration::days(7), 10).unwrap();
+        assert_eq!(summary.total_commands, 4);
+        assert_eq!(summary.outcomes.denied, 2);
+        assert_eq!(summary.outcomes.allowed, 1);
+        assert_eq!(summary.top_blocked_rules.len(), 1);
+        assert_eq!(summary.top_blocked_rules[0].rule_id, "core.git:reset-hard");
+        assert_eq!(summary.top_blocked_rules[0].denied, 2);
+        assert_eq!(summary.top_blocked_rules[0].hits, 3);
+        let agents: Vec<_> = summary
+            .agents
+            .iter()
+            .map(|a| (a.name.as_str(), a.count))
+            .collect();
+        assert_eq!(agents, vec![("test_agent", 3), ("codex", 1)]);
+    }
+
+    #[test]
+    fn test_rule_timeline_empty_db() {
+        let db = HistoryDb::open_in_memory().unwrap();
+        assert!(db.rule_timeline().unwrap().is_empty());
+    }
+
     #[test]
     fn test_get_rule_metrics_with_limit() {
         let db = HistoryDb::open_in_memory().unwrap();
@@ -11,6 +11,7 @@ use crate::output::console::console;
 use crate::output::denial::DenialBox;
 use crate::output::theme::Severity as ThemeSeverity;
 use crate::packs::PatternSuggestion;
+use crate::suggestions::similar_safe_command;
 use colored::Colorize;
 #[cfg(feature = "rich-output")]
 #[allow(unused_imports)]
@@ -53,8 +54,11 @@ pub struct HookSpecificOutput<'a> {
     pub hook_event_name: &'static str,
 
     /// The permission decision: "allow" or "deny".
-    #[serde(rename = "permissionDecision")]
-    pub permission_decision: &'static str,
+    ///
+    /// Omitted for warn-mode matches (with `decision_mode` set) so the agent's
+    /// normal permission flow still applies.
+    #[serde(rename = "permissionDecision", skip_serializing_if = "Option::is_none")]
+    pub permission_decision: Option<&'static str>,
 
     /// Human-readable explanation of the decision.
     #[serde(rename = "permissionDecisionReason")]
@@ -90,6 +94,15 @@ pub struct HookSpecificOutput<'a> {
     /// Remediation suggestions for the blocked command.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remediation: Option<Remediation>,
+
+    /// Resolved policy mode when no permission decision is emitted
+    /// (`"warn"` for a matched rule that is only reported as an advisory).
+    #[serde(rename = "decisionMode", skip_serializing_if = "Option::is_none")]
+    pub decision_mode: Option<&'static str>,
+
+    /// Review queue entry id when the denial was queued for human approval.
+    #[serde(rename = "reviewQueueId", skip_serializing_if = "Option::is_none")]
+    pub review_queue_id: Option<String>,
 }
 
 /// Allow-once metadata for denial output.
@@ -197,6 +210,10 @@ pub fn extract_command(input: &HookInput) -> Option<String> {
 
 /// Configure colored output based on TTY detection.
 pub fn configure_colors() {
+    if crate::output::color_choice() != crate::output::ColorChoice::Auto {
+        return;
+    }
+
     if std::env::var_os("NO_COLOR").is_some() || std::env::var_os("DCG_NO_COLOR").is_some() {
         colored::control::set_override(false);
         return;
@@ -365,6 +382,13 @@ pub fn print_colorful_warning(
         }
     }
 
+    // Point at the closest safe command from the same pack, if any
+    if suggestions_enabled && alternatives.len() < MAX_SUGGESTIONS {
+        if let Some(similar) = pack.and_then(|id| similar_safe_command(id, command)) {
+            alternatives.push(similar.text);
+        }
+    }
+
     let mut denial = DenialBox::new(command, span, pattern_display, theme_severity)
         .with_alternatives(alternatives);
 
@@ -403,6 +427,15 @@ pub fn print_colorful_warning(
 
     if let Some(ref rule) = rule_id {
         eprintln!("  $ {cyan}dcg allowlist add {rule} --project{reset}");
+        // Clickable rule docs on terminals with OSC 8 support; plain output
+        // stays unchanged elsewhere.
+        if theme.colors_enabled
+            && crate::output::hyperlinks_supported()
+            && crate::output::rule_docs_url(rule).is_some()
+        {
+            let link = crate::output::rule_id_link(rule, true);
+            eprintln!("  {footer_style}Rule docs:{reset} {cyan}{link}{reset}");
+        }
     }
 
     eprintln!();
@@ -499,6 +532,7 @@ pub fn output_denial(
     severity: Option<crate::packs::Severity>,
     confidence: Option<f64>,
     pattern_suggestions: &[PatternSuggestion],
+    review_queue_id: Option<&str>,
 ) {
     // Print colorful warning to stderr (visible to user)
     let allow_once_code = allow_once.map(|info| info.code.as_str());
@@ -513,9 +547,16 @@ pub fn output_denial(
         pattern_suggestions,
         severity,
     );
+    if let Some(id) = review_queue_id {
+        eprintln!("Queued for review as {id}; approve or reject it with `dcg review`.");
+        eprintln!();
+    }
 
     // Build JSON response for hook protocol (stdout)
-    let message = format_denial_message(command, reason, explanation, pack, pattern);
+    let mut message = format_denial_message(command, reason, explanation, pack, pattern);
+    if let Some(id) = review_queue_id {
+        message.push_str(&format_review_queue_note(id));
+    }
 
     // Build rule_id from pack and pattern
     let rule_id = build_rule_id(pack, pattern);
@@ -533,7 +574,7 @@ pub fn output_denial(
     let output = HookOutput {
         hook_specific_output: HookSpecificOutput {
             hook_event_name: "PreToolUse",
-            permission_decision: "deny",
+            permission_decision: Some("deny"),
             permission_decision_reason: Cow::Owned(message),
             allow_once_code: allow_once.map(|info| info.code.clone()),
             allow_once_full_hash: allow_once.map(|info| info.full_hash.clone()),
@@ -542,6 +583,8 @@ pub fn output_denial(
             severity,
             confidence,
             remediation,
+            decision_mode: None,
+            review_queue_id: review_queue_id.map(String::from),
         },
     };
 
@@ -552,7 +595,42 @@ pub fn output_denial(
     let _ = writeln!(handle);
 }
 
-/// Output a warning to stderr (no JSON deny; command is allowed).
+/// Note appended to the denial reason when the command was queued for review.
+#[must_use]
+pub fn format_review_queue_note(id: &str) -> String {
+    format!(
+        "\n\nThis command was queued for human review (id: {id}). \
+         Do not retry it until the user approves it with `dcg review`."
+    )
+}
+
+/// Format the advisory returned to the agent when a warn-mode rule matches.
+#[must_use]
+pub fn format_warning_message(
+    command: &str,
+    reason: &str,
+    pack: Option<&str>,
+    pattern: Option<&str>,
+) -> String {
+    let rule_line = build_rule_id(pack, pattern).map_or_else(
+        || {
+            pack.map(|pack_name| format!("Pack: {pack_name}\n\n"))
+                .unwrap_or_default()
+        },
+        |rule| format!("Rule: {rule}\n\n"),
+    );
+
+    format!(
+        "dcg WARNING (allowed by policy)\n\n\
+         Reason: {reason}\n\n\
+         {rule_line}\
+         Command: {command}\n\n\
+         This rule is set to warn, so dcg did not block the command. Normal permission checks still apply; make sure it is intended."
+    )
+}
+
+/// Output a warning for a warn-mode match: an advisory to stderr and hook
+/// output carrying the rule details, without a permission decision, to stdout.
 #[cold]
 #[inline(never)]
 pub fn output_warning(
@@ -561,6 +639,7 @@ pub fn output_warning(
     pack: Option<&str>,
     pattern: Option<&str>,
     explanation: Option<&str>,
+    severity: Option<crate::packs::Severity>,
 ) {
     let stderr = io::stderr();
     let mut handle = stderr.lock();
@@ -595,8 +674,33 @@ pub fn output_warning(
     let _ = writeln!(
         handle,
         "  {}",
-        "No hook JSON deny was emitted; this warning is informational.".bright_black()
+        "dcg did not block the command; this warning is informational.".bright_black()
     );
+    drop(handle);
+
+    let output = HookOutput {
+        hook_specific_output: HookSpecificOutput {
+            hook_event_name: "PreToolUse",
+            permission_decision: None,
+            permission_decision_reason: Cow::Owned(format_warning_message(
+                command, reason, pack, pattern,
+            )),
+            allow_once_code: None,
+            allow_once_full_hash: None,
+            rule_id,
+            pack_id: pack.map(String::from),
+            severity,
+            confidence: None,
+            remediation: None,
+            decision_mode: Some("warn"),
+            review_queue_id: None,
+        },
+    };
+
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    let _ = serde_json::to_writer(&mut handle, &output);
+    let _ = writeln!(handle);
 }
 
 /// Log a blocked command to a file (if logging is enabled).
@@ -791,6 +895,22 @@ mod tests {
         assert!(message.contains("Tip: dcg explain"));
     }
 
+    #[test]
+    fn test_format_warning_message_names_rule_and_allows() {
+        let message = format_warning_message(
+            "git remote remove origin",
+            "drops the remote",
+            Some("core.git"),
+            Some("remote-remove"),
+        );
+
+        assert!(message.starts_with("dcg WARNING (allowed by policy)"));
+        assert!(message.contains("Reason: drops the remote"));
+        assert!(message.contains("Rule: core.git:remote-remove"));
+        assert!(message.contains("Command: git remote remove origin"));
+        assert!(!message.contains("BLOCKED"));
+    }
+
     #[test]
     fn test_env_var_guard_restores_value() {
         let _lock = ENV_LOCK.lock().unwrap();
@@ -37,28 +37,25 @@
 //!
 //! # Usage
 //!
-//! The main entry point for command evaluation is the [`evaluator`] module:
+//! Library consumers should evaluate commands through [`Guard`], which
+//! precomputes everything derived from the configuration once and is the
+//! supported, stable entry point:
 //!
-//! ```ignore
-//! use destructive_command_guard::config::Config;
-//! use destructive_command_guard::evaluator::{evaluate_command, EvaluationDecision};
+//! ```
+//! use destructive_command_guard::{Config, Guard};
 //!
-//! let config = Config::load();
-//! let compiled_overrides = config.overrides.compile();
-//! let enabled_keywords = vec!["git", "rm"];
-//! let allowlists = destructive_command_guard::load_default_allowlists();
-//! let result = evaluate_command(
-//!     "git status",
-//!     &config,
-//!     &enabled_keywords,
-//!     &compiled_overrides,
-//!     &allowlists,
-//! );
+//! let guard = Guard::from_config(&Config::default());
+//! let result = guard.evaluate("git reset --hard");
 //!
 //! if result.is_denied() {
 //!     println!("Blocked: {}", result.reason().unwrap_or("unknown"));
 //! }
+//! # assert!(result.is_denied());
 //! ```
+//!
+//! The lower-level functions in the [`evaluator`] module (such as
+//! [`evaluate_command`]) take the precomputed pieces as separate arguments.
+//! They back hook mode and the CLI, and their signatures may change.
 
 pub mod agent;
 pub mod allowlist;
@@ -70,7 +67,9 @@ pub mod context;
 pub mod error_codes;
 pub mod evaluator;
 pub mod exit_codes;
+pub mod fingerprint;
 pub mod git;
+pub mod guard;
 pub mod heredoc;
 pub mod highlight;
 pub mod history;
@@ -80,11 +79,18 @@ pub mod logging;
 pub mod mcp;
 pub mod normalize;
 pub mod output;
+pub mod pack_testing;
 pub mod packs;
 pub mod pending_exceptions;
 pub mod perf;
+pub mod policy_export;
+#[cfg(feature = "http-server")]
+pub mod pushgateway;
+pub mod review_queue;
 pub mod sarif;
 pub mod scan;
+#[cfg(feature = "http-server")]
+pub mod serve;
 pub mod simulate;
 pub mod stats;
 pub mod suggest;
@@ -111,6 +117,8 @@ pub use exit_codes::{
     EXIT_CONFIG_ERROR, EXIT_DENIED, EXIT_IO_ERROR, EXIT_PARSE_ERROR, EXIT_SUCCESS, EXIT_WARNING,
     ToExitCode, exit_with, to_exit_code,
 };
+pub use fingerprint::{FingerprintOptions, command_fingerprint, command_fingerprint_with};
+pub use guard::Guard;
 pub use hook::{HookInput, HookOutput, HookResult, HookSpecificOutput};
 pub use packs::external::{ExternalPack, parse_pack_file, parse_pack_string};
 pub use packs::{Pack, PackId, PackRegistry, PatternSuggestion, Platform};
@@ -118,6 +126,7 @@ pub use pending_exceptions::{
     AllowOnceEntry, AllowOnceScopeKind, AllowOnceStore, PendingExceptionRecord,
     PendingExceptionStore,
 };
+pub use review_queue::{ReviewQueueConfig, ReviewQueueEntry, ReviewQueueStore, ReviewStatus};
 
 // Re-export dual regex engine abstraction (from regex safety audit)
 pub use packs::regex_engine::{CompiledRegex, needs_backtracking_engine};
@@ -130,9 +139,10 @@ pub use context::{
 
 // Re-export heredoc detection types
 pub use heredoc::{
-    ExtractedContent, ExtractedShellCommand, ExtractionLimits, ExtractionResult, HeredocType,
-    ScriptLanguage, TriggerResult, check_triggers, extract_content, extract_shell_commands,
-    matched_triggers,
+    CustomTriggers, ExtractedContent, ExtractedShellCommand, ExtractionLimits, ExtractionResult,
+    HeredocType, ScriptLanguage, TriggerResult, check_triggers, check_triggers_with_custom,
+    extract_content, extract_content_with_custom, extract_shell_commands, matched_triggers,
+    matched_triggers_with_custom,
 };
 
 // Re-export AST matcher types
@@ -145,8 +155,8 @@ pub use ast_matcher::{
 pub use trace::{
     AllowlistInfo, EXPLAIN_JSON_SCHEMA_VERSION, ExplainJsonOutput, ExplainTrace, JsonAllowlistInfo,
     JsonMatchInfo, JsonPackSummary, JsonSpan, JsonSuggestion, JsonTraceDetails, JsonTraceStep,
-    MatchInfo, PackSummary, TraceCollector, TraceDetails, TraceStep, format_duration,
-    truncate_utf8,
+    MatchInfo, PackSummary, TraceCollector, TraceDetails, TraceStep, explain_json_schema,
+    format_duration, truncate_utf8,
 };
 
 // Re-export highlight types for terminal span highlighting
@@ -168,12 +178,12 @@ pub use suggestions::{Suggestion, SuggestionKind, get_suggestion_by_kind, get_su
 
 // Re-export scan types for `dcg scan`
 pub use scan::{
-    ExtractedCommand, ScanDecision, ScanEvalContext, ScanFailOn, ScanFinding, ScanFormat,
-    ScanOptions, ScanReport, ScanSeverity, ScanSummary, extract_docker_compose_from_str,
-    extract_dockerfile_from_str, extract_github_actions_workflow_from_str,
-    extract_gitlab_ci_from_str, extract_makefile_from_str, extract_package_json_from_str,
-    extract_shell_script_from_str, extract_terraform_from_str, scan_paths, should_fail,
-    sort_findings,
+    ExtractedCommand, ScanDecision, ScanError, ScanEvalContext, ScanFailOn, ScanFinding,
+    ScanFormat, ScanOptions, ScanReport, ScanSeverity, ScanSummary,
+    extract_docker_compose_from_str, extract_dockerfile_from_str,
+    extract_github_actions_workflow_from_str, extract_gitlab_ci_from_str,
+    extract_makefile_from_str, extract_package_json_from_str, extract_shell_script_from_str,
+    extract_terraform_from_str, scan_paths, scan_report_json_schema, should_fail, sort_findings,
 };
 
 // Re-export simulate types for `dcg simulate`
@@ -755,11 +755,16 @@ impl PackTestLogger {
         }
     }
 
-    /// Output the report as JSON.
+    /// Output the report as JSON, on a single line when `compact` is true.
     #[must_use]
-    pub fn report_json(&self) -> String {
+    pub fn report_json(&self, compact: bool) -> String {
         let report = self.generate_report();
-        serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".to_string())
+        let json = if compact {
+            serde_json::to_string(&report)
+        } else {
+            serde_json::to_string_pretty(&report)
+        };
+        json.unwrap_or_else(|_| "{}".to_string())
     }
 
     /// Get the number of pattern matches recorded.
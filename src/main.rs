@@ -33,12 +33,17 @@ use destructive_command_guard::history::{
 use destructive_command_guard::hook;
 use destructive_command_guard::load_default_allowlists;
 use destructive_command_guard::normalize::normalize_command;
+use destructive_command_guard::output::ColorChoice;
+use destructive_command_guard::packs::heuristic::destructive_verbs::configure_verbs as configure_heuristic_verbs;
 use destructive_command_guard::packs::load_external_packs;
 #[cfg(test)]
 use destructive_command_guard::packs::pack_aware_quick_reject;
 use destructive_command_guard::packs::{DecisionMode, REGISTRY};
-use destructive_command_guard::pending_exceptions::{PendingExceptionStore, log_maintenance};
+use destructive_command_guard::pending_exceptions::{
+    AllowOnceAuditConfig, AllowOnceLogFormat, PendingExceptionStore, log_maintenance,
+};
 use destructive_command_guard::perf::{Deadline, HOOK_EVALUATION_BUDGET};
+use destructive_command_guard::review_queue::ReviewQueueStore;
 use destructive_command_guard::sanitize_for_pattern_matching;
 // Import HookInput for parsing stdin JSON in hook mode
 #[cfg(test)]
@@ -224,18 +229,20 @@ fn main() {
     };
 
     // Initialize output system based on CLI flags.
-    // --legacy-output, --no-color, or --robot forces plain output mode.
+    // --legacy-output, --no-color/--color never, or --robot forces plain output mode.
     // Robot mode also suppresses all stderr output.
     let robot_mode = cli.robot || std::env::var("DCG_ROBOT").is_ok();
-    let force_plain_output = cli.legacy_output || cli.no_color || robot_mode;
+    let color = if cli.no_color || robot_mode {
+        ColorChoice::Never
+    } else {
+        cli.color
+    };
+    let force_plain_output = cli.legacy_output || color == ColorChoice::Never;
+    destructive_command_guard::output::init_color(color);
     destructive_command_guard::output::init(force_plain_output);
     destructive_command_guard::output::init_console(force_plain_output);
     destructive_command_guard::output::init_suggestions(!cli.no_suggestions && !robot_mode);
-
-    // In robot mode, also disable colors completely
-    if robot_mode {
-        colored::control::set_override(false);
-    }
+    destructive_command_guard::output::init_json_style(cli.compact_json);
 
     // If there's a subcommand, handle it and exit.
     if cli.command.is_some() {
@@ -264,11 +271,15 @@ fn main() {
     // Compute effective heredoc settings once (avoid per-command parsing/allocations).
     let heredoc_settings = config.heredoc_settings();
 
+    // Install configured heuristic verbs before keywords are collected from them.
+    configure_heuristic_verbs(config.heuristics.destructive_verbs.as_deref());
+
     // Get enabled pack IDs early for pack-aware quick reject.
     // This is done before stdin read to minimize latency on the critical path.
     let mut enabled_packs: HashSet<String> = config.enabled_pack_ids();
     let mut enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &config.packs.pack_order);
     let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
 
     // Load external packs from custom_paths (glob + tilde expansion).
@@ -307,12 +318,11 @@ fn main() {
     };
 
     // Start evaluation deadline after input size checks (includes evaluation).
-    let deadline = Deadline::new(
-        config
-            .general
-            .hook_timeout_ms
-            .map_or(HOOK_EVALUATION_BUDGET, Duration::from_millis),
-    );
+    let hook_budget = config
+        .general
+        .hook_timeout_ms
+        .map_or(HOOK_EVALUATION_BUDGET, Duration::from_millis);
+    let deadline = Deadline::new(hook_budget);
 
     // Only process Bash tool invocations
     if hook_input.tool_name.as_deref() != Some("Bash") {
@@ -366,18 +376,41 @@ fn main() {
     }
 
     if deadline.is_exceeded() {
+        if let Some(writer) = history_writer.as_ref() {
+            writer.log(build_history_entry(
+                &command,
+                &working_dir,
+                HistoryOutcome::Timeout,
+                deadline.elapsed(),
+                None,
+                None,
+                None,
+            ));
+        }
         if let Some(log_file) = config.general.log_file.as_deref() {
             let _ = hook::log_budget_skip(
                 log_file,
                 &command,
                 "pre_evaluation",
                 deadline.elapsed(),
-                HOOK_EVALUATION_BUDGET,
+                hook_budget,
             );
         }
         return;
     }
 
+    // Record allow-once grants and single-use consumption in the log file.
+    let allow_once_audit =
+        config
+            .general
+            .log_file
+            .as_deref()
+            .map(|log_file| AllowOnceAuditConfig {
+                log_file,
+                format: AllowOnceLogFormat::Text,
+                redaction: &config.logging.redaction,
+            });
+
     // Use the shared evaluator for hook mode parity with `dcg test`.
     let eval_start = Instant::now();
     let mut result = evaluate_command_with_pack_order_deadline_at_path(
@@ -388,7 +421,7 @@ fn main() {
         &compiled_overrides,
         &allowlists,
         &heredoc_settings,
-        None, // allow_once_audit
+        allow_once_audit.as_ref(),
         None, // project_path
         Some(&deadline),
     );
@@ -400,7 +433,10 @@ fn main() {
         let normalized = normalize_command(&command);
         let cmd_for_match = sanitize_for_pattern_matching(&normalized);
 
-        if let Some(external_result) =
+        if deadline.is_exceeded() {
+            // No time left for external packs: fail open rather than stall the agent.
+            result.skipped_due_to_budget = true;
+        } else if let Some(external_result) =
             external_store.check_command_with_details(&cmd_for_match, &enabled_packs)
         {
             if external_result.blocked {
@@ -429,7 +465,7 @@ fn main() {
             let entry = build_history_entry(
                 &command,
                 &working_dir,
-                HistoryOutcome::Allow,
+                HistoryOutcome::Timeout,
                 eval_duration,
                 None,
                 None,
@@ -443,7 +479,7 @@ fn main() {
                 &command,
                 "evaluation",
                 deadline.elapsed(),
-                HOOK_EVALUATION_BUDGET,
+                hook_budget,
             );
         }
         return;
@@ -451,25 +487,37 @@ fn main() {
 
     if result.decision != EvaluationDecision::Deny {
         if let Some(writer) = history_writer.as_ref() {
+            let mut outcome = HistoryOutcome::Allow;
             let mut pack_id = None;
             let mut pattern_name = None;
             let mut allowlist_layer = None;
 
+            // Would have been denied: record the overridden rule so stats can
+            // report how often the allowlist let a command through.
             if let Some(override_) = result.allowlist_override.as_ref() {
+                outcome = HistoryOutcome::AllowlistedOverride;
                 allowlist_layer = Some(override_.layer.label());
                 pack_id = override_.matched.pack_id.as_deref();
                 pattern_name = override_.matched.pattern_name.as_deref();
             }
 
-            let entry = build_history_entry(
+            // A consumed allow-once entry or `dcg approve` token is a bypass:
+            // keep its short code so the approval can be traced afterwards.
+            let bypass_code = result.allow_once.as_ref().map(|allow_once| {
+                outcome = HistoryOutcome::Bypass;
+                allow_once.source_short_code.clone()
+            });
+
+            let mut entry = build_history_entry(
                 &command,
                 &working_dir,
-                HistoryOutcome::Allow,
+                outcome,
                 eval_duration,
                 pack_id,
                 pattern_name,
                 allowlist_layer,
             );
+            entry.bypass_code = bypass_code;
             writer.log(entry);
         }
         return;
@@ -493,11 +541,16 @@ fn main() {
     };
 
     let pack = info.pack_id.as_deref();
+    let branch_policy =
+        destructive_command_guard::evaluator::resolve_branch_policy(&config, cwd_path.as_deref());
     let mut mode = match info.source {
-        MatchSource::Pack | MatchSource::HeredocAst => {
-            config
-                .policy()
-                .resolve_mode(pack, info.pattern_name.as_deref(), info.severity)
+        MatchSource::Pack | MatchSource::HeredocAst | MatchSource::Heuristic => {
+            config.policy().resolve_mode_for_branch(
+                pack,
+                info.pattern_name.as_deref(),
+                info.severity,
+                branch_policy.as_ref().map(|policy| policy.mode),
+            )
         }
         // Never downgrade explicit blocks.
         MatchSource::ConfigOverride | MatchSource::LegacyPattern => DecisionMode::Deny,
@@ -530,6 +583,21 @@ fn main() {
         mode = confidence_result.mode;
     }
 
+    // Opt-in: check the worktree before blocking `git reset --hard`.
+    let worktree_probe = destructive_command_guard::evaluator::probe_reset_hard_worktree(
+        &command,
+        info,
+        mode,
+        &config,
+        cwd_path.as_deref(),
+    );
+    let reason = worktree_probe
+        .as_ref()
+        .map_or(info.reason.as_str(), |probe| probe.reason.as_str());
+    if let Some(probe) = worktree_probe.as_ref() {
+        mode = probe.mode;
+    }
+
     let pattern = info.pattern_name.as_deref();
     let explanation = info.explanation.as_deref();
 
@@ -555,18 +623,18 @@ fn main() {
         DecisionMode::Deny => {
             let store_path = PendingExceptionStore::default_path(cwd_path.as_deref());
             let store = PendingExceptionStore::new(store_path);
-            let reason = match (pack, pattern) {
+            let block_reason = match (pack, pattern) {
                 (Some(pack_id), Some(pattern_name)) => {
-                    format!("{pack_id}:{pattern_name} - {}", info.reason)
+                    format!("{pack_id}:{pattern_name} - {reason}")
                 }
-                _ => info.reason.clone(),
+                _ => reason.to_string(),
             };
 
             let mut allow_once_info: Option<hook::AllowOnceInfo> = None;
             if let Ok((record, maintenance)) = store.record_block(
                 &command,
                 &working_dir,
-                &reason,
+                &block_reason,
                 &config.logging.redaction,
                 false,
                 Some(format!("{:?}", info.source)),
@@ -581,9 +649,23 @@ fn main() {
                 }
             }
 
+            // Opt-in: queue the denial for asynchronous human approval.
+            let review_entry = if config.review_queue.enabled {
+                let queue = ReviewQueueStore::new(ReviewQueueStore::default_path(
+                    &config.review_queue,
+                    cwd_path.as_deref(),
+                ));
+                let rule_id = pack.zip(pattern).map(|(p, n)| format!("{p}:{n}"));
+                queue
+                    .enqueue(&command, &working_dir, reason, rule_id.as_deref())
+                    .ok()
+            } else {
+                None
+            };
+
             hook::output_denial(
                 &command,
-                &info.reason,
+                reason,
                 pack,
                 pattern,
                 explanation,
@@ -592,20 +674,21 @@ fn main() {
                 info.severity,
                 None, // confidence not yet available in PatternMatch
                 info.suggestions,
+                review_entry.as_ref().map(|entry| entry.id.as_str()),
             );
 
             // Log if configured
             if let Some(log_file) = &config.general.log_file {
-                let _ = hook::log_blocked_command(log_file, &command, &info.reason, pack);
+                let _ = hook::log_blocked_command(log_file, &command, reason, pack);
             }
         }
         DecisionMode::Warn => {
-            hook::output_warning(&command, &info.reason, pack, pattern, explanation);
+            hook::output_warning(&command, reason, pack, pattern, explanation, info.severity);
         }
         DecisionMode::Log => {
             // Silent allow; optionally log to file for history.
             if let Some(log_file) = &config.general.log_file {
-                let _ = hook::log_blocked_command(log_file, &command, &info.reason, pack);
+                let _ = hook::log_blocked_command(log_file, &command, reason, pack);
             }
         }
     }
@@ -683,6 +766,10 @@ fn print_help() {
         "    {}=1       Suppress non-error output",
         "DCG_QUIET".green()
     );
+    eprintln!(
+        "    {}=auto|always|never  Color mode (same as --color)",
+        "DCG_COLOR".green()
+    );
     eprintln!(
         "    {}=1    Disable colored output (same as NO_COLOR)",
         "DCG_NO_COLOR".green()
@@ -826,7 +913,7 @@ mod tests {
             HookOutput {
                 hook_specific_output: HookSpecificOutput {
                     hook_event_name: "PreToolUse",
-                    permission_decision: "deny",
+                    permission_decision: Some("deny"),
                     permission_decision_reason: Cow::Owned(format!(
                         "BLOCKED by dcg\n\n\
                          Reason: {reason}\n\n\
@@ -841,6 +928,8 @@ mod tests {
                     severity: None,
                     confidence: None,
                     remediation: None,
+                    decision_mode: None,
+                    review_queue_id: None,
                 },
             }
         }
@@ -115,6 +115,12 @@ impl DcgMcpServer {
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 200,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         }
     }
 
@@ -344,18 +350,8 @@ impl ServerHandler for DcgMcpServer {
                 let path = Self::string_arg(params.arguments.as_ref(), "path")?;
                 let path_buf = PathBuf::from(path);
                 let options = Self::default_scan_options();
-                let include: Vec<String> = Vec::new();
-                let exclude: Vec<String> = Vec::new();
-                let report = scan_paths(
-                    &[path_buf],
-                    &options,
-                    &self.config,
-                    &self.scan_ctx,
-                    &include,
-                    &exclude,
-                    None,
-                )
-                .map_err(Self::call_tool_error)?;
+                let report = scan_paths(&[path_buf], &options, &self.config, &self.scan_ctx, None)
+                    .map_err(|err| Self::call_tool_error(err.to_string()))?;
                 Self::tool_result_json(&report)
             }
             "explain_pattern" => {
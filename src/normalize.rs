@@ -15,10 +15,14 @@
 //! - `env [-i] [-u name] [NAME=VALUE]... command` - environment modification
 //! - `\git`, `\rm` - bash alias bypass (leading backslash)
 //! - `command [-p] [--] cmd` - but NOT `command -v` or `command -V` (query mode)
+//!
+//! [`resolve_indirection`] separately rewrites `eval <string>` and
+//! `NAME=<literal>; $NAME` into the command they actually run.
 
 use fancy_regex::Regex;
 use smallvec::SmallVec;
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::ops::Range;
 use std::sync::LazyLock;
 
@@ -1580,10 +1584,356 @@ fn strip_leading_backslash(command: &str) -> Option<(String, StrippedWrapper)> {
     ))
 }
 
+// ============================================================================
+// Indirection resolution (`eval` strings and same-line variables)
+// ============================================================================
+
+/// Upper bound on resolution passes, so nested `eval` chains terminate.
+const MAX_INDIRECTION_PASSES: usize = 4;
+
+/// Resolved commands longer than this are not expanded any further.
+const MAX_RESOLVED_LEN: usize = 64 * 1024;
+
+/// A command with `eval` arguments and same-line variables resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedIndirection {
+    /// The command with indirection replaced by the literal text it runs.
+    pub command: String,
+    /// Whether an `eval` argument was resolved.
+    pub resolved_eval: bool,
+    /// Whether a `$NAME` expansion was resolved.
+    pub resolved_variable: bool,
+}
+
+impl ResolvedIndirection {
+    /// Short label for explain output (`eval`, `var`, or `eval+var`).
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match (self.resolved_eval, self.resolved_variable) {
+            (true, true) => "eval+var",
+            (true, false) => "eval",
+            _ => "var",
+        }
+    }
+}
+
+/// Resolve `eval <string>` and `NAME=<literal>; $NAME` indirection.
+///
+/// Only variables assigned a static literal earlier in the same command are
+/// expanded. Anything computed (`$(...)`, backticks, other variables) is left
+/// alone, and an `eval` whose arguments can't be fully resolved is kept as-is.
+/// Returns `None` when nothing was resolved.
+///
+/// ```ignore
+/// let resolved = resolve_indirection(r#"CMD="rm -rf /x"; $CMD"#).unwrap();
+/// assert_eq!(resolved.command, r#"CMD="rm -rf /x"; rm -rf /x"#);
+/// ```
+#[must_use]
+pub fn resolve_indirection(command: &str) -> Option<ResolvedIndirection> {
+    let may_resolve = command.contains("eval") || (command.contains('$') && command.contains('='));
+    if !may_resolve {
+        return None;
+    }
+
+    let mut resolved = ResolvedIndirection {
+        command: command.to_string(),
+        resolved_eval: false,
+        resolved_variable: false,
+    };
+    let mut changed = false;
+    for _ in 0..MAX_INDIRECTION_PASSES {
+        let Some((next, eval, variable)) = resolve_indirection_pass(&resolved.command) else {
+            break;
+        };
+        if next == resolved.command || next.len() > MAX_RESOLVED_LEN {
+            break;
+        }
+        resolved.command = next;
+        resolved.resolved_eval |= eval;
+        resolved.resolved_variable |= variable;
+        changed = true;
+    }
+
+    changed.then_some(resolved)
+}
+
+/// One left-to-right pass over the command's segments.
+fn resolve_indirection_pass(command: &str) -> Option<(String, bool, bool)> {
+    let tokens = tokenize_for_normalization(command);
+    let mut segments: Vec<SmallVec<[Range<usize>; 8]>> = vec![SmallVec::new()];
+    for token in &tokens {
+        match token.kind {
+            NormalizeTokenKind::Word => {
+                if let Some(segment) = segments.last_mut() {
+                    segment.push(token.byte_range.clone());
+                }
+            }
+            NormalizeTokenKind::Separator => segments.push(SmallVec::new()),
+        }
+    }
+
+    let mut vars: HashMap<&str, String> = HashMap::new();
+    let mut out = String::with_capacity(command.len());
+    let mut copied = 0;
+    let (mut any_eval, mut any_variable) = (false, false);
+
+    for words in segments.iter().filter(|words| !words.is_empty()) {
+        let Some((replacement, eval, variable)) = resolve_segment(command, words, &mut vars) else {
+            continue;
+        };
+        let start = words[0].start;
+        let end = words[words.len() - 1].end;
+        out.push_str(&command[copied..start]);
+        out.push_str(&replacement);
+        copied = end;
+        any_eval |= eval;
+        any_variable |= variable;
+    }
+
+    if copied == 0 {
+        return None;
+    }
+    out.push_str(&command[copied..]);
+    Some((out, any_eval, any_variable))
+}
+
+/// Resolve a single segment, recording literal assignments in `vars`.
+///
+/// Returns the replacement text plus whether it came from `eval` and/or a
+/// variable expansion.
+fn resolve_segment<'a>(
+    command: &'a str,
+    words: &[Range<usize>],
+    vars: &mut HashMap<&'a str, String>,
+) -> Option<(String, bool, bool)> {
+    let word = |range: &Range<usize>| &command[range.clone()];
+    let first = word(&words[0]);
+
+    // `NAME=value ...` (optionally behind `export`) with no command word
+    // defines shell variables for later segments.
+    let assignments = if first == "export" {
+        &words[1..]
+    } else {
+        words
+    };
+    if !assignments.is_empty() && assignments.iter().all(|r| is_env_assignment(word(r))) {
+        for range in assignments {
+            let (name, raw) = word(range).split_once('=')?;
+            let mut expanded = false;
+            match dequote_static_word(raw, None, &mut expanded) {
+                Some(value) => {
+                    vars.insert(name, value);
+                }
+                None => {
+                    vars.remove(name);
+                }
+            }
+        }
+        return None;
+    }
+
+    // Anything else that may change a variable makes it unknown from here on.
+    for range in words {
+        let text = word(range);
+        if let Some((name, _)) = text.split_once("+=") {
+            vars.remove(name);
+        }
+    }
+    if matches!(
+        first,
+        "unset" | "read" | "declare" | "local" | "readonly" | "typeset"
+    ) {
+        for range in &words[1..] {
+            let text = word(range);
+            vars.remove(text.split_once('=').map_or(text, |(name, _)| name));
+        }
+        return None;
+    }
+
+    if first == "eval" {
+        if words.len() < 2 {
+            return None;
+        }
+        let mut expanded = false;
+        let mut parts = Vec::with_capacity(words.len() - 1);
+        for range in &words[1..] {
+            parts.push(dequote_static_word(word(range), Some(vars), &mut expanded)?);
+        }
+        return Some((parts.join(" "), true, expanded));
+    }
+
+    if vars.is_empty() {
+        return None;
+    }
+    let text = &command[words[0].start..words[words.len() - 1].end];
+    expand_known_variables(text, vars).map(|expanded| (expanded, false, true))
+}
+
+/// Remove shell quoting from a word, expanding only variables in `vars`.
+///
+/// Returns `None` if the word contains anything that can't be resolved
+/// statically (command substitution, unknown variables, unbalanced quotes).
+fn dequote_static_word(
+    word: &str,
+    vars: Option<&HashMap<&str, String>>,
+    expanded: &mut bool,
+) -> Option<String> {
+    let bytes = word.as_bytes();
+    let mut out = String::with_capacity(word.len());
+    let mut in_double = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'`' => return None,
+            b'$' => {
+                let (value, next) = lookup_variable(word, i, vars?)?;
+                out.push_str(value);
+                *expanded = true;
+                i = next;
+            }
+            b'"' => {
+                in_double = !in_double;
+                i += 1;
+            }
+            b'\'' if !in_double => {
+                let close = word[i + 1..].find('\'')?;
+                out.push_str(&word[i + 1..i + 1 + close]);
+                i += close + 2;
+            }
+            b'\\' if i + 1 < bytes.len() => {
+                let next = word[i + 1..].chars().next()?;
+                if in_double && !matches!(next, '"' | '\\' | '$' | '`') {
+                    out.push('\\');
+                }
+                out.push(next);
+                i += 1 + next.len_utf8();
+            }
+            _ => {
+                let ch = word[i..].chars().next()?;
+                out.push(ch);
+                i += ch.len_utf8();
+            }
+        }
+    }
+
+    (!in_double).then_some(out)
+}
+
+/// Substitute known `$NAME` / `${NAME}` references outside single quotes.
+///
+/// Values containing quotes, backslashes, or further expansions are skipped
+/// so the substituted text never changes how the rest of the word parses.
+fn expand_known_variables(text: &str, vars: &HashMap<&str, String>) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let (mut in_single, mut in_double, mut changed) = (false, false, false);
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if !in_single => {
+                i += 2;
+                continue;
+            }
+            b'\'' if !in_double => in_single = !in_single,
+            b'"' if !in_single => in_double = !in_double,
+            b'$' if !in_single => {
+                if let Some((value, next)) = lookup_variable(text, i, vars) {
+                    if !value.contains(['\'', '"', '\\', '`', '$']) {
+                        out.push_str(&text[copied..i]);
+                        out.push_str(value);
+                        copied = next;
+                        i = next;
+                        changed = true;
+                        continue;
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if !changed {
+        return None;
+    }
+    out.push_str(&text[copied..]);
+    Some(out)
+}
+
+/// Look up the `$NAME` / `${NAME}` reference starting at `dollar`.
+///
+/// Returns the value and the byte offset just past the reference.
+fn lookup_variable<'v>(
+    text: &str,
+    dollar: usize,
+    vars: &'v HashMap<&str, String>,
+) -> Option<(&'v str, usize)> {
+    let rest = &text[dollar + 1..];
+    let (name, consumed) = if let Some(inner) = rest.strip_prefix('{') {
+        let close = inner.find('}')?;
+        (&inner[..close], close + 2)
+    } else {
+        let len = rest
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+            .count();
+        (&rest[..len], len)
+    };
+    if name.is_empty()
+        || name.as_bytes()[0].is_ascii_digit()
+        || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_')
+    {
+        return None;
+    }
+    let value = vars.get(name)?;
+    Some((value.as_str(), dollar + 1 + consumed))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn resolve_indirection_expands_eval_literal() {
+        let resolved = resolve_indirection(r#"eval "rm -rf /x""#).unwrap();
+        assert_eq!(resolved.command, "rm -rf /x");
+        assert_eq!(resolved.label(), "eval");
+
+        let resolved = resolve_indirection("eval 'git reset' --hard").unwrap();
+        assert_eq!(resolved.command, "git reset --hard");
+    }
+
+    #[test]
+    fn resolve_indirection_expands_same_line_literal_variables() {
+        let resolved = resolve_indirection(r#"CMD="rm -rf /x"; $CMD"#).unwrap();
+        assert_eq!(resolved.command, r#"CMD="rm -rf /x"; rm -rf /x"#);
+        assert_eq!(resolved.label(), "var");
+
+        let resolved = resolve_indirection("export T=/etc && rm -rf ${T}").unwrap();
+        assert_eq!(resolved.command, "export T=/etc && rm -rf /etc");
+
+        let resolved = resolve_indirection(r#"X='git reset --hard'; eval "$X""#).unwrap();
+        assert_eq!(resolved.command, "X='git reset --hard'; git reset --hard");
+        assert_eq!(resolved.label(), "eval+var");
+    }
+
+    #[test]
+    fn resolve_indirection_ignores_non_literal_values() {
+        assert_eq!(resolve_indirection(r#"eval "$DANGEROUS""#), None);
+        assert_eq!(resolve_indirection("X=$(cat cmd.txt); $X"), None);
+        assert_eq!(resolve_indirection(r#"X="$HOME/bin"; $X"#), None);
+        assert_eq!(resolve_indirection("eval `cat cmd.txt`"), None);
+        // Used before it is assigned, or only set for a single command.
+        assert_eq!(resolve_indirection("$X; X='rm -rf /'"), None);
+        assert_eq!(resolve_indirection("X='rm -rf /' true; $X"), None);
+        // Unset or appended-to variables are no longer known.
+        assert_eq!(resolve_indirection("X='rm -rf /'; unset X; $X"), None);
+        assert_eq!(resolve_indirection("echo $HOME"), None);
+    }
+
     #[test]
     fn test_sudo_simple() {
         let result = strip_wrapper_prefixes("sudo git reset --hard");
@@ -131,6 +131,8 @@ impl DcgConsole {
 
         if self.force_plain {
             builder = builder.no_color();
+        } else if crate::output::color_choice() == crate::output::ColorChoice::Always {
+            builder = builder.force_terminal(true);
         }
 
         builder.build()
@@ -0,0 +1,141 @@
+//! OSC 8 terminal hyperlinks.
+//!
+//! Rule IDs in explain and denial output are rendered as clickable links to
+//! the pack documentation when the terminal understands OSC 8. Everywhere
+//! else the rule ID is printed as plain text.
+//!
+//! Detection is environment-based (there is no reliable terminal query):
+//! `DCG_HYPERLINKS` / `FORCE_HYPERLINK` override it, and otherwise links are
+//! only emitted for terminals known to support them.
+
+use crate::packs::REGISTRY;
+
+/// Terminals (via `TERM_PROGRAM`) known to render OSC 8 links.
+const HYPERLINK_TERM_PROGRAMS: &[&str] = &[
+    "ghostty",
+    "Hyper",
+    "iTerm.app",
+    "terminology",
+    "vscode",
+    "WezTerm",
+];
+
+/// `TERM` values known to render OSC 8 links.
+const HYPERLINK_TERMS: &[&str] = &["alacritty", "foot", "xterm-ghostty", "xterm-kitty"];
+
+/// First VTE release with OSC 8 support (0.50).
+const MIN_VTE_VERSION: u32 = 5000;
+
+/// Wrap `text` in an OSC 8 hyperlink to `url`.
+#[must_use]
+pub fn osc8_link(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Returns `true` if the current terminal is expected to render OSC 8 links.
+///
+/// Callers should also require that colors are enabled, which already covers
+/// TTY detection, `NO_COLOR`, `--no-color`, and CI.
+#[must_use]
+pub fn hyperlinks_supported() -> bool {
+    hyperlinks_supported_with(|name| std::env::var(name).ok())
+}
+
+fn hyperlinks_supported_with(env: impl Fn(&str) -> Option<String>) -> bool {
+    for name in ["DCG_HYPERLINKS", "FORCE_HYPERLINK"] {
+        if let Some(value) = env(name) {
+            return !matches!(value.trim(), "" | "0" | "false" | "no" | "off");
+        }
+    }
+
+    if env("DOMTERM").is_some() || env("WT_SESSION").is_some() || env("KONSOLE_VERSION").is_some() {
+        return true;
+    }
+    if env("VTE_VERSION")
+        .and_then(|version| version.trim().parse::<u32>().ok())
+        .is_some_and(|version| version >= MIN_VTE_VERSION)
+    {
+        return true;
+    }
+    if env("TERM_PROGRAM")
+        .is_some_and(|program| HYPERLINK_TERM_PROGRAMS.contains(&program.as_str()))
+    {
+        return true;
+    }
+    env("TERM").is_some_and(|term| HYPERLINK_TERMS.contains(&term.as_str()))
+}
+
+/// Documentation URL for a rule ID (`pack:pattern`), if its pack has one.
+///
+/// Pack docs list every rule in the pack's section, so the link targets that
+/// section.
+#[must_use]
+pub fn rule_docs_url(rule_id: &str) -> Option<&'static str> {
+    let (pack_id, _) = rule_id.split_once(':')?;
+    REGISTRY.get(pack_id).and_then(|pack| pack.docs_url)
+}
+
+/// Render a rule ID, linked to its docs when `hyperlinks` is true and a docs
+/// URL is known; plain text otherwise.
+#[must_use]
+pub fn rule_id_link(rule_id: &str, hyperlinks: bool) -> String {
+    match rule_docs_url(rule_id) {
+        Some(url) if hyperlinks => osc8_link(rule_id, url),
+        _ => rule_id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn supported(vars: &[(&str, &str)]) -> bool {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        hyperlinks_supported_with(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn detects_known_terminals() {
+        assert!(supported(&[("TERM_PROGRAM", "iTerm.app")]));
+        assert!(supported(&[("TERM", "xterm-kitty")]));
+        assert!(supported(&[("VTE_VERSION", "6800")]));
+        assert!(supported(&[("WT_SESSION", "abc")]));
+        assert!(!supported(&[("VTE_VERSION", "4800")]));
+        assert!(!supported(&[("TERM", "xterm-256color")]));
+        assert!(!supported(&[]));
+    }
+
+    #[test]
+    fn explicit_override_wins() {
+        assert!(supported(&[("DCG_HYPERLINKS", "1")]));
+        assert!(!supported(&[
+            ("DCG_HYPERLINKS", "0"),
+            ("TERM_PROGRAM", "WezTerm")
+        ]));
+        assert!(!supported(&[
+            ("FORCE_HYPERLINK", "0"),
+            ("TERM", "xterm-kitty")
+        ]));
+    }
+
+    #[test]
+    fn rule_link_emits_osc8_only_when_enabled() {
+        let linked = rule_id_link("core.git:reset-hard", true);
+        let url = rule_docs_url("core.git:reset-hard").expect("core.git has docs");
+        assert_eq!(
+            linked,
+            format!("\x1b]8;;{url}\x1b\\core.git:reset-hard\x1b]8;;\x1b\\")
+        );
+
+        assert_eq!(
+            rule_id_link("core.git:reset-hard", false),
+            "core.git:reset-hard"
+        );
+        // Unknown pack: no URL, so never linked.
+        assert_eq!(rule_id_link("nope.pack:rule", true), "nope.pack:rule");
+    }
+}
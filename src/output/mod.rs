@@ -9,19 +9,21 @@
 //! - `test` - Test result box renderer
 //! - `progress` - Progress indicators using indicatif (with rich_rust support)
 //! - `console` - Console abstraction for stderr output
+//! - `hyperlink` - OSC 8 links from rule IDs to pack docs
 //! - `rich_theme` - rich_rust theme integration
 //! - `tree` - Tree visualization for hierarchical data
 //!
 //! # TTY Detection
 //!
 //! The module automatically detects whether rich output should be used based on:
-//! 1. Explicit flags (--json, --no-color)
+//! 1. Explicit flags (--json, --color, --no-color)
 //! 2. `NO_COLOR` environment variable
 //! 3. Whether stdout is a TTY
 //! 4. TERM environment variable (dumb terminals)
 
 pub mod console;
 pub mod denial;
+pub mod hyperlink;
 pub mod progress;
 pub mod rich_theme;
 pub mod tables;
@@ -31,12 +33,15 @@ pub mod tree;
 
 pub use console::{DcgConsole, console, init_console};
 pub use denial::DenialBox;
+pub use hyperlink::{hyperlinks_supported, osc8_link, rule_docs_url, rule_id_link};
 pub use progress::{
     MaybeProgress, NoopProgress, SCAN_PROGRESS_THRESHOLD, ScanProgress, ScanProgressStyle, spinner,
     spinner_if_tty,
 };
 #[cfg(feature = "rich-output")]
 pub use progress::{RichProgressStyle, render_progress_bar_rich};
+#[cfg(not(feature = "rich-output"))]
+pub use rich_theme::severity_badge_ansi;
 pub use rich_theme::{RichThemeExt, color_to_markup, severity_badge_markup, severity_panel_title};
 pub use tables::{ScanResultRow, ScanResultsTable, TableStyle};
 pub use test::{AllowedReason, TestOutcome, TestResultBox};
@@ -49,9 +54,27 @@ use std::sync::OnceLock;
 /// Global flag to force plain output (set by --no-color or similar).
 static FORCE_PLAIN: OnceLock<bool> = OnceLock::new();
 
+/// Global color choice (set by --color / --no-color).
+static COLOR_CHOICE: OnceLock<ColorChoice> = OnceLock::new();
+
+/// When to emit ANSI color (`--color`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Detect from `NO_COLOR`, `CI`, `TERM`, and whether output is a TTY
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
 /// Global flag for suggestions display (set by --no-suggestions).
 static SUGGESTIONS_ENABLED: OnceLock<bool> = OnceLock::new();
 
+/// Global flag for single-line JSON output (set by --compact-json).
+static COMPACT_JSON: OnceLock<bool> = OnceLock::new();
+
 /// Initialize the output system with explicit settings.
 ///
 /// Call this early in `main()` if you want to override TTY detection.
@@ -59,6 +82,25 @@ pub fn init(force_plain: bool) {
     let _ = FORCE_PLAIN.set(force_plain);
 }
 
+/// Initialize the global color choice.
+///
+/// Call this early in `main()`. `Always` and `Never` override environment
+/// and TTY detection everywhere, including the `colored` crate.
+pub fn init_color(choice: ColorChoice) {
+    let _ = COLOR_CHOICE.set(choice);
+    match choice {
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+        ColorChoice::Auto => {}
+    }
+}
+
+/// The color choice set by [`init_color`] (`Auto` if never initialized).
+#[must_use]
+pub fn color_choice() -> ColorChoice {
+    COLOR_CHOICE.get().copied().unwrap_or_default()
+}
+
 /// Initialize suggestions display setting.
 ///
 /// Call this early in `main()` to control whether suggestions are shown.
@@ -66,9 +108,44 @@ pub fn init_suggestions(enabled: bool) {
     let _ = SUGGESTIONS_ENABLED.set(enabled);
 }
 
+/// Initialize the JSON output style.
+///
+/// Call this early in `main()`; when `compact` is true, JSON-emitting commands
+/// print a single line instead of indented output.
+pub fn init_json_style(compact: bool) {
+    let _ = COMPACT_JSON.set(compact);
+}
+
+/// Returns `true` if JSON output should be compact (single-line).
+#[must_use]
+pub fn compact_json() -> bool {
+    COMPACT_JSON.get().copied().unwrap_or(false)
+}
+
+/// Serialize a value for JSON command output, honoring `--compact-json`.
+///
+/// Compact and pretty output differ only in whitespace: both keep the
+/// struct-definition field order and parse to the same value.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be serialized.
+pub fn to_json_string<T>(value: &T) -> serde_json::Result<String>
+where
+    T: serde::Serialize + ?Sized,
+{
+    if compact_json() {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
 /// Determines whether rich terminal output should be used.
 ///
-/// Returns `true` if all of the following are true:
+/// `--color always` forces `true` (unless plain output was forced) and
+/// `--color never` forces `false`. Otherwise returns `true` if all of the
+/// following are true:
 /// - `--no-color` flag was not passed (or `init(false)` was called)
 /// - `NO_COLOR` environment variable is not set
 /// - stdout is a TTY
@@ -91,6 +168,11 @@ pub fn should_use_rich_output() -> bool {
     if FORCE_PLAIN.get().copied().unwrap_or(false) {
         return false;
     }
+    match color_choice() {
+        ColorChoice::Always => return true,
+        ColorChoice::Never => return false,
+        ColorChoice::Auto => {}
+    }
 
     // 2. Check NO_COLOR environment variable (https://no-color.org/)
     if std::env::var("NO_COLOR").is_ok() || std::env::var("DCG_NO_COLOR").is_ok() {
@@ -157,7 +239,8 @@ pub fn auto_theme_with_config(config: &Config) -> Theme {
 
     let mut theme = Theme::from_palette(palette);
 
-    if let Some(use_color) = config.theme.use_color {
+    // An explicit `--color` beats the config file.
+    if let (Some(use_color), ColorChoice::Auto) = (config.theme.use_color, color_choice()) {
         if !use_color {
             theme = theme.without_colors();
         }
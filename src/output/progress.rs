@@ -43,7 +43,9 @@
 
 use indicatif::{ProgressBar, ProgressStyle};
 use std::borrow::Cow;
-use std::time::Duration;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 // Rich output imports
 #[cfg(feature = "rich-output")]
@@ -595,6 +597,104 @@ impl MaybeProgress {
     }
 }
 
+/// Minimum time between status lines written by [`ThrottledProgress`].
+pub const THROTTLED_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Plain-text `files processed/total` counter for `dcg scan --progress`.
+///
+/// Unlike [`ScanProgress`], this writes a single carriage-return-updated line
+/// to stderr at most once per interval, so it never touches stdout and stays
+/// cheap on very large scans. All state is atomic: `tick` may be called from
+/// multiple scanning threads through a shared reference.
+#[derive(Debug)]
+pub struct ThrottledProgress {
+    total: usize,
+    processed: AtomicUsize,
+    started: Instant,
+    interval_ms: u64,
+    last_emit_ms: AtomicU64,
+    emitted: AtomicBool,
+}
+
+impl ThrottledProgress {
+    /// Creates a counter for `total` files that emits at most once per `interval`.
+    #[must_use]
+    pub fn new(total: usize, interval: Duration) -> Self {
+        Self {
+            total,
+            processed: AtomicUsize::new(0),
+            started: Instant::now(),
+            interval_ms: u64::try_from(interval.as_millis()).unwrap_or(u64::MAX),
+            last_emit_ms: AtomicU64::new(0),
+            emitted: AtomicBool::new(false),
+        }
+    }
+
+    /// Creates a counter only when progress output is appropriate.
+    ///
+    /// Returns `None` if `quiet` is set or stderr is not a TTY.
+    #[must_use]
+    pub fn new_if_enabled(total: usize, quiet: bool) -> Option<Self> {
+        if quiet || !::console::Term::stderr().is_term() {
+            return None;
+        }
+        Some(Self::new(total, THROTTLED_PROGRESS_INTERVAL))
+    }
+
+    /// Records one processed file, writing a status line if the interval has elapsed.
+    pub fn tick(&self) {
+        let processed = self.processed.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.claim_emit(self.elapsed_ms()) {
+            write_progress_line(&format!("\r{}", self.status_line(processed)));
+        }
+    }
+
+    /// Returns the number of files processed so far.
+    #[must_use]
+    pub fn processed(&self) -> usize {
+        self.processed.load(Ordering::Relaxed)
+    }
+
+    /// Formats the status line for the given processed count.
+    #[must_use]
+    pub fn status_line(&self, processed: usize) -> String {
+        format!("Scanning: {processed}/{} files", self.total)
+    }
+
+    /// Writes the final count and ends the line, if any status line was shown.
+    pub fn finish(&self) {
+        if self.emitted.load(Ordering::Relaxed) {
+            write_progress_line(&format!("\r{}\n", self.status_line(self.processed())));
+        }
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+
+    /// Decides whether the caller at `now_ms` gets to emit; only one thread wins per interval.
+    fn claim_emit(&self, now_ms: u64) -> bool {
+        let last = self.last_emit_ms.load(Ordering::Relaxed);
+        if now_ms.saturating_sub(last) < self.interval_ms {
+            return false;
+        }
+        let claimed = self
+            .last_emit_ms
+            .compare_exchange(last, now_ms, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok();
+        if claimed {
+            self.emitted.store(true, Ordering::Relaxed);
+        }
+        claimed
+    }
+}
+
+fn write_progress_line(line: &str) {
+    let mut stderr = std::io::stderr().lock();
+    let _ = stderr.write_all(line.as_bytes());
+    let _ = stderr.flush();
+}
+
 /// Truncates a file path to fit within `max_len` characters.
 ///
 /// If the path is too long, it replaces the middle with "...".
@@ -734,6 +834,47 @@ mod tests {
     // Original tests
     // ============================================================
 
+    #[test]
+    fn throttled_progress_counts_and_formats() {
+        let progress = ThrottledProgress::new(3, Duration::from_secs(3600));
+        progress.tick();
+        progress.tick();
+        assert_eq!(progress.processed(), 2);
+        assert_eq!(progress.status_line(2), "Scanning: 2/3 files");
+    }
+
+    #[test]
+    fn throttled_progress_emits_once_per_interval() {
+        let progress = ThrottledProgress::new(10, Duration::from_millis(250));
+        assert!(
+            !progress.claim_emit(100),
+            "nothing before the first interval"
+        );
+        assert!(progress.claim_emit(250));
+        assert!(!progress.claim_emit(400), "throttled within the interval");
+        assert!(progress.claim_emit(500));
+    }
+
+    #[test]
+    fn throttled_progress_is_thread_safe() {
+        let progress = ThrottledProgress::new(400, Duration::from_secs(3600));
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        progress.tick();
+                    }
+                });
+            }
+        });
+        assert_eq!(progress.processed(), 400);
+    }
+
+    #[test]
+    fn throttled_progress_disabled_when_quiet() {
+        assert!(ThrottledProgress::new_if_enabled(100, true).is_none());
+    }
+
     #[test]
     fn test_truncate_path_short() {
         let path = "src/main.rs";
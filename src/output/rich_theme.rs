@@ -170,6 +170,51 @@ pub fn severity_badge_markup(theme: &Theme, severity: Severity) -> String {
     format!("[bold {color} reverse] {label} [/]")
 }
 
+/// ANSI-rendered counterpart of [`severity_badge_markup`] for builds without
+/// rich_rust, where console markup is stripped instead of rendered.
+#[cfg(not(feature = "rich-output"))]
+#[must_use]
+pub fn severity_badge_ansi(theme: &Theme, severity: Severity) -> String {
+    use colored::Colorize;
+
+    let label = theme.severity_label(severity);
+    if !theme.colors_enabled {
+        return label.to_string();
+    }
+
+    let badge = format!(" {label} ").bold().reversed();
+    match color_to_colored(theme.color_for_severity(severity)) {
+        Some(color) => badge.color(color).to_string(),
+        None => badge.to_string(),
+    }
+}
+
+/// Convert ratatui Color to a `colored` color (`None` for reset/indexed).
+#[cfg(not(feature = "rich-output"))]
+const fn color_to_colored(color: Color) -> Option<colored::Color> {
+    use colored::Color as C;
+
+    Some(match color {
+        Color::Black => C::Black,
+        Color::Red => C::Red,
+        Color::Green => C::Green,
+        Color::Yellow => C::Yellow,
+        Color::Blue => C::Blue,
+        Color::Magenta => C::Magenta,
+        Color::Cyan => C::Cyan,
+        Color::White => C::White,
+        Color::Gray | Color::DarkGray => C::BrightBlack,
+        Color::LightRed => C::BrightRed,
+        Color::LightGreen => C::BrightGreen,
+        Color::LightYellow => C::BrightYellow,
+        Color::LightBlue => C::BrightBlue,
+        Color::LightMagenta => C::BrightMagenta,
+        Color::LightCyan => C::BrightCyan,
+        Color::Rgb(r, g, b) => C::TrueColor { r, g, b },
+        Color::Indexed(_) | Color::Reset => return None,
+    })
+}
+
 /// Returns the border character set name for rich_rust box rendering.
 ///
 /// Maps dcg's BorderStyle to rich_rust's box type names.
@@ -279,6 +324,13 @@ mod tests {
         assert!(!badge.contains("red"));
     }
 
+    #[cfg(not(feature = "rich-output"))]
+    #[test]
+    fn test_severity_badge_ansi_no_color() {
+        let badge = severity_badge_ansi(&Theme::no_color(), Severity::High);
+        assert_eq!(badge, "HIGH");
+    }
+
     #[test]
     fn test_severity_panel_title() {
         let theme = Theme::default();
@@ -0,0 +1,70 @@
+//! Test utilities for pack authors.
+//!
+//! Built-in packs are tested with a small set of assertion helpers (see
+//! [`crate::packs::test_helpers`]). This module is the stable, public entry
+//! point to those same helpers so that authors of external YAML packs, or
+//! contributors working on a new built-in pack, can write the same style of
+//! tests in their own test files.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! use destructive_command_guard::pack_testing::*;
+//!
+//! #[test]
+//! fn deploy_pack_rules() {
+//!     let pack = load_pack_file("packs/mycompany-deploy.yaml");
+//!
+//!     // Structural checks: every regex compiles, no duplicate names, etc.
+//!     validate_pack(&pack);
+//!
+//!     // Behavioural checks against the rule IDs users will allowlist.
+//!     assert_blocks_with_rule_id(&pack, "deploy --env prod", "mycompany.deploy:prod-direct");
+//!     assert_allows(&pack, "deploy --env staging");
+//! }
+//! ```
+//!
+//! All assertions operate on a single [`Pack`] in isolation: they do not
+//! consult configuration, allowlists, or other enabled packs.
+
+use std::path::Path;
+
+use crate::packs::Pack;
+use crate::packs::external::{parse_pack_file, parse_pack_string};
+
+pub use crate::packs::test_helpers::{
+    PATTERN_MATCH_TIMEOUT, assert_all_patterns_have_reasons, assert_allows, assert_blocks,
+    assert_blocks_with_pattern, assert_blocks_with_rule_id, assert_blocks_with_severity,
+    assert_matches_within_budget, assert_no_match, assert_no_safe_match, assert_patterns_compile,
+    assert_safe_pattern_matches, assert_unique_pattern_names, debug_match_info, test_batch_allows,
+    test_batch_blocks, validate_pack,
+};
+
+/// Load an external pack from a YAML file and convert it into a runtime [`Pack`].
+///
+/// # Panics
+///
+/// Panics with the parse or validation error if the file is not a valid pack.
+#[must_use]
+#[track_caller]
+pub fn load_pack_file(path: impl AsRef<Path>) -> Pack {
+    let path = path.as_ref();
+    match parse_pack_file(path) {
+        Ok(pack) => pack.into_pack(),
+        Err(err) => panic!("Failed to load pack file {}: {err}", path.display()),
+    }
+}
+
+/// Parse an external pack from a YAML string and convert it into a runtime [`Pack`].
+///
+/// # Panics
+///
+/// Panics with the parse or validation error if the YAML is not a valid pack.
+#[must_use]
+#[track_caller]
+pub fn load_pack_str(yaml: &str) -> Pack {
+    match parse_pack_string(yaml) {
+        Ok(pack) => pack.into_pack(),
+        Err(err) => panic!("Failed to parse pack YAML: {err}"),
+    }
+}
@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         name: "Google Apigee",
         description: "Protects against destructive Google Apigee CLI and apigeecli operations.",
         keywords: &["apigee", "apigeecli"],
+        tags: &["gcp", "apigateway", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/apigateway.md#apigatewayapigee",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
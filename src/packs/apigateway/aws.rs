@@ -23,6 +23,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive AWS API Gateway CLI operations for both \
                       REST APIs and HTTP APIs.",
         keywords: &["aws", "apigateway", "apigatewayv2"],
+        tags: &["aws", "apigateway", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/apigateway.md#apigatewayaws",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Kong Gateway CLI, deck CLI, and Admin API \
                       operations.",
         keywords: &["kong", "deck", "8001"],
+        tags: &["apigateway", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/apigateway.md#apigatewaykong",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
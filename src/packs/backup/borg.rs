@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         name: "BorgBackup",
         description: "Protects against destructive borg operations like delete, prune, compact, and recreate.",
         keywords: &["borg"],
+        tags: &["backup", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/backup.md#backupborg",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         name: "Rclone",
         description: "Protects against destructive rclone operations like sync, delete, purge, dedupe, and move.",
         keywords: &["rclone"],
+        tags: &["backup", "storage", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/backup.md#backuprclone",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
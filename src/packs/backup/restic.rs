@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         name: "Restic",
         description: "Protects against destructive restic operations like forgetting snapshots, pruning data, removing keys, and cache cleanup.",
         keywords: &["restic"],
+        tags: &["backup", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/backup.md#backuprestic",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
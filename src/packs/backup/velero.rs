@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         name: "Velero",
         description: "Protects against destructive velero operations like deleting backups, schedules, and locations.",
         keywords: &["velero"],
+        tags: &["backup", "kubernetes", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/backup.md#backupvelero",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -19,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Cloudflare Workers, KV, R2, and D1 operations \
                       via the Wrangler CLI.",
         keywords: &["wrangler"],
+        tags: &["cloudflare", "cdn"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cdn.md#cdncloudflareworkers",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
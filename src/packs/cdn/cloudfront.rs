@@ -19,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive AWS CloudFront operations like deleting \
                       distributions, cache policies, and functions.",
         keywords: &["cloudfront"],
+        tags: &["aws", "cdn"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cdn.md#cdncloudfront",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
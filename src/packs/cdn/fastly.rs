@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Fastly CLI operations like service, domain, \
                       backend, and VCL deletion.",
         keywords: &["fastly"],
+        tags: &["cdn"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cdn.md#cdnfastly",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive CircleCI operations like deleting contexts, \
                       removing secrets, deleting orbs/namespaces, or removing pipelines.",
         keywords: &["circleci"],
+        tags: &["circleci", "cicd"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cicd.md#cicdcircleci",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -20,6 +20,10 @@ pub fn create_pack() -> Pack {
              or using gh api DELETE against /actions endpoints.",
         // Broad on purpose: global `gh` flags can appear before the subcommand.
         keywords: &["gh"],
+        tags: &["github", "cicd"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cicd.md#cicdgithubactions",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
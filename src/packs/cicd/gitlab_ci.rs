@@ -14,6 +14,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive GitLab CI/CD operations like deleting variables, \
                       removing artifacts, and unregistering runners.",
         keywords: &["glab", "gitlab-runner"],
+        tags: &["gitlab", "cicd"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cicd.md#cicdgitlabci",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
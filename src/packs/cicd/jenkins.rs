@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Jenkins CLI/API operations like deleting jobs, \
                       nodes, credentials, or build history.",
         keywords: &["jenkins-cli", "jenkins", "doDelete"],
+        tags: &["jenkins", "cicd"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cicd.md#cicdjenkins",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -27,6 +27,10 @@ pub fn create_pack() -> Pack {
             "ecr",
             "logs",
         ],
+        tags: &["aws", "cloud", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cloud.md#cloudaws",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
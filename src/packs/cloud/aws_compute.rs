@@ -0,0 +1,240 @@
+//! AWS compute patterns - protections against EC2/EKS infrastructure teardown.
+//!
+//! This includes patterns for:
+//! - ec2 terminate-instances, delete-volume, delete-snapshot
+//! - eks delete-cluster, delete-nodegroup
+//! - autoscaling delete-auto-scaling-group --force-delete
+//!
+//! All patterns tolerate global flags before the service name
+//! (e.g. `aws --region us-east-1 --profile prod ec2 ...`).
+
+use crate::packs::{DestructivePattern, Pack, SafePattern};
+use crate::{destructive_pattern, safe_pattern};
+
+/// Create the AWS compute pack.
+#[must_use]
+pub fn create_pack() -> Pack {
+    Pack {
+        id: "cloud.aws_compute".to_string(),
+        name: "AWS Compute",
+        description: "Protects against AWS compute teardown like ec2 terminate-instances, \
+                      eks delete-cluster, and force-deleting auto scaling groups",
+        keywords: &["aws"],
+        tags: &["aws", "cloud", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cloud.md#cloudaws_compute",
+        ),
+        safe_patterns: create_safe_patterns(),
+        destructive_patterns: create_destructive_patterns(),
+        keyword_matcher: None,
+        safe_regex_set: None,
+        safe_regex_set_is_complete: false,
+    }
+}
+
+fn create_safe_patterns() -> Vec<SafePattern> {
+    vec![
+        // describe/list operations are read-only; anchored to one command so a
+        // chained teardown is still checked
+        safe_pattern!(
+            "aws-compute-describe",
+            r"^aws(?:\s+--?\S+(?:\s+\S+)?)*\s+(?:ec2|eks|autoscaling)\s+describe-[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "aws-compute-list",
+            r"^aws(?:\s+--?\S+(?:\s+\S+)?)*\s+(?:ec2|eks|autoscaling)\s+list-[^;&|\n]*$"
+        ),
+    ]
+}
+
+fn create_destructive_patterns() -> Vec<DestructivePattern> {
+    vec![
+        destructive_pattern!(
+            "ec2-terminate-instances",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+ec2\s+terminate-instances\b",
+            "aws ec2 terminate-instances permanently destroys EC2 instances.",
+            Critical,
+            "Terminated instances cannot be recovered. Instance store data is lost, \
+             EBS volumes with DeleteOnTermination=true are deleted, and any workload \
+             running on the instances stops immediately.\n\n\
+             Safer alternatives:\n\
+             - aws ec2 describe-instances --instance-ids i-xxx: Verify targets first\n\
+             - aws ec2 stop-instances: Stop instead of terminating\n\
+             - Enable termination protection on critical instances"
+        ),
+        destructive_pattern!(
+            "ec2-delete-volume",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+ec2\s+delete-volume\b",
+            "aws ec2 delete-volume permanently destroys an EBS volume and its data.",
+            High,
+            "Deleting an EBS volume removes all data stored on it. Unless a snapshot \
+             exists, the data cannot be recovered.\n\n\
+             Safer alternatives:\n\
+             - aws ec2 create-snapshot --volume-id vol-xxx: Back up first\n\
+             - aws ec2 describe-volumes --volume-ids vol-xxx: Verify the target"
+        ),
+        destructive_pattern!(
+            "ec2-delete-snapshot",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+ec2\s+delete-snapshot\b",
+            "aws ec2 delete-snapshot permanently removes an EBS snapshot backup.",
+            High,
+            "Snapshots are often the only backup of a volume. Deleting one removes that \
+             restore point, and AMIs registered from it stop working.\n\n\
+             Safer alternatives:\n\
+             - aws ec2 describe-snapshots --snapshot-ids snap-xxx: Verify the target\n\
+             - aws ec2 describe-images --filters Name=block-device-mapping.snapshot-id,Values=snap-xxx: \
+             Check for dependent AMIs"
+        ),
+        destructive_pattern!(
+            "eks-delete-cluster",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+eks\s+delete-cluster\b",
+            "aws eks delete-cluster destroys the EKS control plane and every workload on it.",
+            Critical,
+            "Deleting an EKS cluster removes the Kubernetes control plane, making all \
+             workloads, services, and cluster state unreachable. It cannot be undone.\n\n\
+             Safer alternatives:\n\
+             - aws eks describe-cluster --name xxx: Verify the target cluster\n\
+             - Back up cluster resources (e.g. with velero) before deletion\n\
+             - Confirm the active profile/region with aws sts get-caller-identity"
+        ),
+        destructive_pattern!(
+            "eks-delete-nodegroup",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+eks\s+delete-nodegroup\b",
+            "aws eks delete-nodegroup terminates every node in the managed node group.",
+            High,
+            "Deleting a node group terminates its EC2 instances. Pods scheduled on those \
+             nodes are evicted and may not fit on the remaining capacity.\n\n\
+             Safer alternatives:\n\
+             - aws eks update-nodegroup-config --scaling-config: Scale down gradually\n\
+             - kubectl drain: Move workloads off the nodes first"
+        ),
+        destructive_pattern!(
+            "autoscaling-force-delete-group",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+autoscaling\s+delete-auto-scaling-group\b.*--force-delete\b",
+            "aws autoscaling delete-auto-scaling-group --force-delete terminates all instances in the group.",
+            High,
+            "With --force-delete the group is deleted along with all of its instances, \
+             without waiting for them to drain or scale in.\n\n\
+             Safer alternatives:\n\
+             - aws autoscaling update-auto-scaling-group --min-size 0 --desired-capacity 0: \
+             Scale in first\n\
+             - Omit --force-delete so the call fails while instances remain"
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "cloud.aws_compute");
+        assert_eq!(pack.name, "AWS Compute");
+        assert!(!pack.description.is_empty());
+        assert!(pack.keywords.contains(&"aws"));
+
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn allows_read_only_operations() {
+        let pack = create_pack();
+        assert_safe_pattern_matches(&pack, "aws ec2 describe-instances");
+        assert_safe_pattern_matches(&pack, "aws eks list-clusters");
+        assert_safe_pattern_matches(
+            &pack,
+            "aws --region us-east-1 ec2 describe-instances --instance-ids i-123",
+        );
+        assert_allows(&pack, "aws ec2 stop-instances --instance-ids i-123");
+        assert_allows(
+            &pack,
+            "aws autoscaling delete-auto-scaling-group --auto-scaling-group-name web",
+        );
+    }
+
+    #[test]
+    fn read_only_commands_do_not_mask_chained_teardown() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws ec2 describe-instances && aws ec2 terminate-instances --instance-ids i-1",
+            "ec2-terminate-instances",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws eks list-clusters; aws eks delete-cluster --name prod",
+            "eks-delete-cluster",
+        );
+    }
+
+    #[test]
+    fn blocks_ec2_teardown() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws ec2 terminate-instances --instance-ids i-123",
+            "ec2-terminate-instances",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws ec2 delete-volume --volume-id vol-123",
+            "ec2-delete-volume",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws ec2 delete-snapshot --snapshot-id snap-123",
+            "ec2-delete-snapshot",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "aws ec2 terminate-instances --instance-ids i-123",
+            Severity::Critical,
+        );
+    }
+
+    #[test]
+    fn blocks_eks_and_autoscaling_teardown() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws eks delete-cluster --name prod",
+            "eks-delete-cluster",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "aws eks delete-cluster --name prod",
+            Severity::Critical,
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws eks delete-nodegroup --cluster-name prod --nodegroup-name ng-1",
+            "eks-delete-nodegroup",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws autoscaling delete-auto-scaling-group --auto-scaling-group-name web --force-delete",
+            "autoscaling-force-delete-group",
+        );
+    }
+
+    #[test]
+    fn tolerates_leading_global_flags() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws --region us-east-1 --profile prod ec2 terminate-instances --instance-ids i-123",
+            "ec2-terminate-instances",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws --no-cli-pager eks delete-cluster --name prod",
+            "eks-delete-cluster",
+        );
+    }
+}
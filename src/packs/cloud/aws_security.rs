@@ -0,0 +1,267 @@
+//! AWS security resource patterns - protections against IAM/KMS/Secrets Manager deletion.
+//!
+//! This includes patterns for:
+//! - iam delete-user, delete-role, delete-policy
+//! - kms schedule-key-deletion, disable-key
+//! - secretsmanager delete-secret (especially `--force-delete-without-recovery`)
+//!
+//! All patterns tolerate global flags before the service name
+//! (e.g. `aws --region us-east-1 --profile prod kms ...`).
+
+use crate::packs::{DestructivePattern, Pack, SafePattern};
+use crate::{destructive_pattern, safe_pattern};
+
+/// Create the AWS security resources pack.
+#[must_use]
+pub fn create_pack() -> Pack {
+    Pack {
+        id: "cloud.aws_security".to_string(),
+        name: "AWS Security Resources",
+        description: "Protects against deleting AWS security resources like IAM users/roles/policies, \
+                      KMS keys, and Secrets Manager secrets",
+        keywords: &["aws"],
+        tags: &["aws", "cloud", "security", "secrets"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cloud.md#cloudaws_security",
+        ),
+        safe_patterns: create_safe_patterns(),
+        destructive_patterns: create_destructive_patterns(),
+        keyword_matcher: None,
+        safe_regex_set: None,
+        safe_regex_set_is_complete: false,
+    }
+}
+
+fn create_safe_patterns() -> Vec<SafePattern> {
+    vec![
+        // IAM list/get and KMS list-keys are read-only; anchored to one command
+        // so a chained deletion is still checked
+        safe_pattern!(
+            "aws-iam-list-get",
+            r"^aws(?:\s+--?\S+(?:\s+\S+)?)*\s+iam\s+(?:list|get)-[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "aws-kms-list-keys",
+            r"^aws(?:\s+--?\S+(?:\s+\S+)?)*\s+kms\s+list-keys\b[^;&|\n]*$"
+        ),
+    ]
+}
+
+fn create_destructive_patterns() -> Vec<DestructivePattern> {
+    vec![
+        destructive_pattern!(
+            "iam-delete-user",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+iam\s+delete-user(?:\s|$)",
+            "aws iam delete-user removes an IAM user and its access.",
+            High,
+            "Deleting an IAM user removes its identity. Any access keys, automation, or \
+             people relying on it lose access immediately, and the user ARN cannot be \
+             recreated with the same unique ID.\n\n\
+             Safer alternatives:\n\
+             - aws iam get-user --user-name xxx: Verify the target\n\
+             - aws iam update-access-key --status Inactive: Disable keys first\n\
+             - aws iam list-attached-user-policies --user-name xxx: Review permissions"
+        ),
+        destructive_pattern!(
+            "iam-delete-role",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+iam\s+delete-role\b",
+            "aws iam delete-role removes an IAM role that services may assume.",
+            High,
+            "Services, instances, and Lambda functions that assume the role start failing \
+             as soon as it is deleted. Trust relationships must be rebuilt by hand.\n\n\
+             Safer alternatives:\n\
+             - aws iam get-role --role-name xxx: Verify the target\n\
+             - aws iam get-role --role-name xxx --query Role.RoleLastUsed: Check recent use"
+        ),
+        destructive_pattern!(
+            "iam-delete-policy",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+iam\s+delete-policy\b",
+            "aws iam delete-policy removes a managed IAM policy and all of its versions.",
+            High,
+            "Deleting a managed policy removes every version of it. Principals that \
+             depended on its permissions lose access.\n\n\
+             Safer alternatives:\n\
+             - aws iam list-entities-for-policy --policy-arn xxx: Find attached principals\n\
+             - aws iam get-policy-version: Save the policy document first"
+        ),
+        destructive_pattern!(
+            "kms-schedule-key-deletion",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+kms\s+schedule-key-deletion\b",
+            "aws kms schedule-key-deletion makes data encrypted under the key permanently unrecoverable.",
+            Critical,
+            "Once the waiting period ends the KMS key is destroyed. Every ciphertext, EBS \
+             volume, S3 object, and database encrypted with it becomes permanently \
+             unreadable.\n\n\
+             Safer alternatives:\n\
+             - aws kms disable-key: Disable the key and watch for failures first\n\
+             - aws kms describe-key --key-id xxx: Verify the target\n\
+             - Use the maximum --pending-window-in-days 30 so it can be cancelled"
+        ),
+        destructive_pattern!(
+            "kms-disable-key",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+kms\s+disable-key(?:\s|$)",
+            "aws kms disable-key blocks all encrypt/decrypt operations that use the key.",
+            High,
+            "A disabled key cannot decrypt data, so every service reading data encrypted \
+             under it starts failing until the key is re-enabled.\n\n\
+             Safer alternatives:\n\
+             - aws kms describe-key --key-id xxx: Verify the target\n\
+             - Review CloudTrail for recent use of the key first"
+        ),
+        destructive_pattern!(
+            "secretsmanager-delete-secret-force",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+secretsmanager\s+delete-secret\b.*--force-delete-without-recovery\b",
+            "aws secretsmanager delete-secret --force-delete-without-recovery destroys the secret immediately with no recovery window.",
+            Critical,
+            "--force-delete-without-recovery skips the recovery window entirely. The \
+             credentials stored in the secret are lost at once and restore-secret cannot \
+             bring them back.\n\n\
+             Safer alternatives:\n\
+             - Omit --force-delete-without-recovery to keep a recovery window\n\
+             - aws secretsmanager get-secret-value --secret-id xxx: Back up the value first"
+        ),
+        destructive_pattern!(
+            "secretsmanager-delete-secret",
+            r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+secretsmanager\s+delete-secret\b",
+            "aws secretsmanager delete-secret schedules the secret (and its credentials) for deletion.",
+            Critical,
+            "Deleting a secret loses the credentials it stores once the recovery window \
+             ends. Applications reading the secret fail immediately.\n\n\
+             Safer alternatives:\n\
+             - aws secretsmanager describe-secret --secret-id xxx: Verify the target\n\
+             - Use --recovery-window-in-days 30 and never --force-delete-without-recovery\n\
+             - aws secretsmanager restore-secret: Undo within the recovery window"
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "cloud.aws_security");
+        assert_eq!(pack.name, "AWS Security Resources");
+        assert!(!pack.description.is_empty());
+        assert!(pack.keywords.contains(&"aws"));
+
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn allows_read_only_operations() {
+        let pack = create_pack();
+        assert_safe_pattern_matches(&pack, "aws iam list-users");
+        assert_safe_pattern_matches(&pack, "aws iam get-role --role-name deploy");
+        assert_safe_pattern_matches(&pack, "aws kms list-keys");
+        assert_safe_pattern_matches(&pack, "aws --profile prod iam list-policies");
+        assert_allows(&pack, "aws kms enable-key --key-id 1234");
+        assert_allows(&pack, "aws secretsmanager get-secret-value --secret-id db");
+    }
+
+    #[test]
+    fn read_only_commands_do_not_mask_chained_deletion() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws iam list-users && aws iam delete-user --user-name bob",
+            "iam-delete-user",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws kms list-keys; aws kms schedule-key-deletion --key-id k",
+            "kms-schedule-key-deletion",
+        );
+    }
+
+    #[test]
+    fn blocks_iam_deletion() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws iam delete-user --user-name alice",
+            "iam-delete-user",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws iam delete-role --role-name deploy",
+            "iam-delete-role",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws iam delete-policy --policy-arn arn:aws:iam::123:policy/ci",
+            "iam-delete-policy",
+        );
+    }
+
+    #[test]
+    fn blocks_kms_key_destruction() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws kms schedule-key-deletion --key-id 1234 --pending-window-in-days 7",
+            "kms-schedule-key-deletion",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "aws kms schedule-key-deletion --key-id 1234",
+            Severity::Critical,
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws kms disable-key --key-id 1234",
+            "kms-disable-key",
+        );
+    }
+
+    #[test]
+    fn longer_subcommands_do_not_match_shorter_names() {
+        let pack = create_pack();
+        assert_allows(
+            &pack,
+            "aws iam delete-user-policy --user-name alice --policy-name ci",
+        );
+        assert_allows(&pack, "aws kms disable-key-rotation --key-id 1234");
+    }
+
+    #[test]
+    fn blocks_secret_deletion() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws secretsmanager delete-secret --secret-id db --force-delete-without-recovery",
+            "secretsmanager-delete-secret-force",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws secretsmanager delete-secret --secret-id db",
+            "secretsmanager-delete-secret",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "aws secretsmanager delete-secret --secret-id db",
+            Severity::Critical,
+        );
+    }
+
+    #[test]
+    fn tolerates_leading_global_flags() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "aws --region us-east-1 --profile prod kms schedule-key-deletion --key-id 1234",
+            "kms-schedule-key-deletion",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "aws --no-cli-pager iam delete-role --role-name deploy",
+            "iam-delete-role",
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! - vm delete
 //! - storage account delete
 //! - sql server delete
+//! - aks delete
 //! - group delete
 
 use crate::packs::{DestructivePattern, Pack, SafePattern};
@@ -18,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Azure CLI operations like vm delete, \
                       storage account delete, and resource group delete",
         keywords: &["az", "delete", "vm", "storage", "acr", "registry"],
+        tags: &["azure", "cloud", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cloud.md#cloudazure",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -27,22 +32,44 @@ pub fn create_pack() -> Pack {
 }
 
 fn create_safe_patterns() -> Vec<SafePattern> {
+    // Every safe pattern refuses to match when the command also contains a
+    // delete/untag verb, so `az group delete ... $(az group list ...)` or
+    // `az group show ...; az group delete ...` cannot be allowed by the
+    // read-only half of the line.
     vec![
         // show/list operations are safe (read-only)
-        safe_pattern!("az-show", r"az\s+\S+\s+show"),
-        safe_pattern!("az-list", r"az\s+\S+\s+list"),
+        safe_pattern!(
+            "az-show",
+            r"^(?!.*\b(?:delete|untag)\b).*\baz\s+(?:[a-z][a-z0-9-]*\s+){1,3}show\b"
+        ),
+        safe_pattern!(
+            "az-list",
+            r"^(?!.*\b(?:delete|untag)\b).*\baz\s+(?:[a-z][a-z0-9-]*\s+){1,3}list\b"
+        ),
         // az account is safe
-        safe_pattern!("az-account", r"az\s+account"),
+        safe_pattern!(
+            "az-account",
+            r"^(?!.*\b(?:delete|untag)\b).*\baz\s+account\b"
+        ),
         // az configure is safe
-        safe_pattern!("az-configure", r"az\s+configure"),
+        safe_pattern!(
+            "az-configure",
+            r"^(?!.*\b(?:delete|untag)\b).*\baz\s+configure\b"
+        ),
         // az login is safe
-        safe_pattern!("az-login", r"az\s+login"),
+        safe_pattern!("az-login", r"^(?!.*\b(?:delete|untag)\b).*\baz\s+login\b"),
         // az version is safe
-        safe_pattern!("az-version", r"az\s+version"),
+        safe_pattern!(
+            "az-version",
+            r"^(?!.*\b(?:delete|untag)\b).*\baz\s+version\b"
+        ),
         // az --help is safe
-        safe_pattern!("az-help", r"az\s+.*--help"),
+        safe_pattern!("az-help", r"^(?!.*\b(?:delete|untag)\b).*\baz\s+.*--help"),
         // what-if is safe (preview)
-        safe_pattern!("az-what-if", r"az\s+.*--what-if"),
+        safe_pattern!(
+            "az-what-if",
+            r"^(?!.*\b(?:delete|untag)\b).*\baz\s+.*--what-if"
+        ),
     ]
 }
 
@@ -233,6 +260,91 @@ mod tests {
     use super::*;
     use crate::packs::test_helpers::*;
 
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "cloud.azure");
+        assert_eq!(pack.name, "Azure CLI");
+        assert!(!pack.description.is_empty());
+        assert!(pack.keywords.contains(&"az"));
+
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn allows_safe_commands() {
+        let pack = create_pack();
+        assert_safe_pattern_matches(&pack, "az group list");
+        assert_safe_pattern_matches(&pack, "az vm list -g rg -o table");
+        assert_safe_pattern_matches(&pack, "az storage account show -n myaccount");
+        assert_safe_pattern_matches(&pack, "az aks show -g rg -n cluster");
+        assert_safe_pattern_matches(&pack, "az account show");
+        assert_safe_pattern_matches(&pack, "az account set --subscription prod");
+        assert_safe_pattern_matches(
+            &pack,
+            "az deployment group create -g rg -f main.bicep --what-if",
+        );
+    }
+
+    #[test]
+    fn blocks_destructive_commands() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "az group delete --name rg", "group-delete");
+        assert_blocks_with_pattern(
+            &pack,
+            "az group delete --name rg --yes --no-wait",
+            "group-delete",
+        );
+        assert_blocks_with_pattern(&pack, "az group delete -n rg -y", "group-delete");
+        assert_blocks_with_pattern(
+            &pack,
+            "az vm delete -g rg -n vm1 --yes --force-deletion true",
+            "vm-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "az storage account delete -n myaccount -g rg --yes",
+            "storage-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "az aks delete -g rg -n cluster --yes --no-wait",
+            "aks-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "az sql server delete -g rg -n sqlsrv --yes",
+            "sql-delete",
+        );
+    }
+
+    #[test]
+    fn safe_patterns_do_not_mask_deletes() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "az group delete --yes --no-wait --name $(az group list --query \"[0].name\" -o tsv)",
+            "group-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "az group show -n rg; az group delete -n rg --yes --no-wait",
+            "group-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "az vm delete --ids $(az vm list -g rg --query \"[].id\" -o tsv) --yes",
+            "vm-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "az group delete -n rg --yes --no-wait --what-if",
+            "group-delete",
+        );
+    }
+
     #[test]
     fn acr_patterns_block() {
         let pack = create_pack();
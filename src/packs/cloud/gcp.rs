@@ -3,12 +3,45 @@
 //! This includes patterns for:
 //! - compute instances delete
 //! - sql instances delete
-//! - storage rm -r
+//! - storage rm -r (gcloud storage and gsutil)
 //! - projects delete
+//!
+//! Global flags may appear before the command group
+//! (`gcloud --project=x compute instances delete`), so every gcloud pattern
+//! skips them the same way the GitHub CLI pack skips `gh` global flags.
 
 use crate::packs::{DestructivePattern, Pack, SafePattern};
 use crate::{destructive_pattern, safe_pattern};
 
+/// Prefixes `tail` with `gcloud`, any global flags, and an optional
+/// `alpha`/`beta` release track.
+macro_rules! gcloud {
+    ($tail:literal) => {
+        concat!(
+            r"gcloud(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:=(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+)|\s+(?!(?:alpha|beta|compute|sql|storage|projects|container|functions|pubsub|firestore|artifacts|config|auth|info)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+(?:(?:alpha|beta)\s+)?",
+            $tail
+        )
+    };
+}
+
+/// Prefixes `tail` with `gsutil` and any top-level `gsutil` options.
+macro_rules! gsutil {
+    ($tail:literal) => {
+        concat!(
+            r"gsutil(?:\s+-[A-Za-z]+(?:\s+(?!(?:rm|rb|ls|cp)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+",
+            $tail
+        )
+    };
+}
+
+/// Safe patterns only apply when nothing in the command deletes anything, so
+/// a read-only command cannot mask a delete chained after it.
+macro_rules! read_only {
+    ($re:expr) => {
+        concat!(r"^(?!.*\b(?:delete|rm|rb)\b).*\b", $re)
+    };
+}
+
 /// Create the GCP pack.
 #[must_use]
 pub fn create_pack() -> Pack {
@@ -16,7 +49,7 @@ pub fn create_pack() -> Pack {
         id: "cloud.gcp".to_string(),
         name: "Google Cloud SDK",
         description: "Protects against destructive gcloud operations like instances delete, \
-                      sql instances delete, and gsutil rm -r",
+                      sql instances delete, and gcloud storage / gsutil rm -r",
         keywords: &[
             "gcloud",
             "gsutil",
@@ -26,6 +59,10 @@ pub fn create_pack() -> Pack {
             "images",
             "repositories",
         ],
+        tags: &["gcp", "cloud", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/cloud.md#cloudgcp",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -37,18 +74,28 @@ pub fn create_pack() -> Pack {
 fn create_safe_patterns() -> Vec<SafePattern> {
     vec![
         // describe/list operations are safe (read-only)
-        safe_pattern!("gcloud-describe", r"gcloud\s+\S+\s+\S+\s+describe"),
-        safe_pattern!("gcloud-list", r"gcloud\s+\S+\s+\S+\s+list"),
+        safe_pattern!(
+            "gcloud-describe",
+            read_only!(gcloud!(
+                r"(?:(?!(?:delete|rm|remove)\b)[a-z][a-z0-9-]*\s+){1,3}describe\b"
+            ))
+        ),
+        safe_pattern!(
+            "gcloud-list",
+            read_only!(gcloud!(
+                r"(?:(?!(?:delete|rm|remove)\b)[a-z][a-z0-9-]*\s+){1,3}list\b"
+            ))
+        ),
         // gsutil ls is safe
-        safe_pattern!("gsutil-ls", r"gsutil\s+ls"),
+        safe_pattern!("gsutil-ls", read_only!(gsutil!(r"ls\b"))),
         // gsutil cp is generally safe (copy)
-        safe_pattern!("gsutil-cp", r"gsutil\s+cp"),
+        safe_pattern!("gsutil-cp", read_only!(gsutil!(r"cp\b"))),
         // gcloud config is safe
-        safe_pattern!("gcloud-config", r"gcloud\s+config"),
+        safe_pattern!("gcloud-config", read_only!(gcloud!(r"config\b"))),
         // gcloud auth is safe
-        safe_pattern!("gcloud-auth", r"gcloud\s+auth"),
+        safe_pattern!("gcloud-auth", read_only!(gcloud!(r"auth\b"))),
         // gcloud info is safe
-        safe_pattern!("gcloud-info", r"gcloud\s+info"),
+        safe_pattern!("gcloud-info", read_only!(gcloud!(r"info\b"))),
     ]
 }
 
@@ -58,7 +105,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // compute instances delete
         destructive_pattern!(
             "compute-delete",
-            r"gcloud\s+compute\s+instances\s+delete",
+            gcloud!(r"compute\s+instances\s+delete\b"),
             "gcloud compute instances delete permanently destroys VM instances.",
             Critical,
             "compute instances delete permanently destroys VMs:\n\n\
@@ -71,7 +118,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // compute disks delete
         destructive_pattern!(
             "disk-delete",
-            r"gcloud\s+compute\s+disks\s+delete",
+            gcloud!(r"compute\s+disks\s+delete\b"),
             "gcloud compute disks delete permanently destroys disk data.",
             Critical,
             "compute disks delete permanently destroys persistent disks:\n\n\
@@ -83,7 +130,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // sql instances delete
         destructive_pattern!(
             "sql-delete",
-            r"gcloud\s+sql\s+instances\s+delete",
+            gcloud!(r"sql\s+instances\s+delete\b"),
             "gcloud sql instances delete permanently destroys the Cloud SQL instance.",
             Critical,
             "sql instances delete permanently destroys Cloud SQL:\n\n\
@@ -96,7 +143,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // gsutil rm -r
         destructive_pattern!(
             "gsutil-rm-recursive",
-            r"gsutil\s+(?:-m\s+)?rm\s+.*-r|gsutil\s+(?:-m\s+)?rm\s+-[a-z]*r",
+            gsutil!(r"rm\s+(?:.*\s)?(?:-[A-Za-z]*[rR][A-Za-z]*|--recursive)\b"),
             "gsutil rm -r permanently deletes all objects in the path.",
             Critical,
             "gsutil rm -r recursively deletes all objects:\n\n\
@@ -109,7 +156,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // gsutil rb (remove bucket)
         destructive_pattern!(
             "gsutil-rb",
-            r"gsutil\s+rb\b",
+            gsutil!(r"rb\b"),
             "gsutil rb removes the entire GCS bucket.",
             Critical,
             "gsutil rb removes the entire Cloud Storage bucket:\n\n\
@@ -118,10 +165,34 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
              - All bucket-level permissions lost\n\n\
              List contents first: gsutil ls gs://bucket/"
         ),
+        // gcloud storage rm -r
+        destructive_pattern!(
+            "storage-rm-recursive",
+            gcloud!(r"storage\s+rm\s+(?:.*\s)?(?:-[A-Za-z]*[rR][A-Za-z]*|--recursive)\b"),
+            "gcloud storage rm -r permanently deletes all objects in the path.",
+            Critical,
+            "gcloud storage rm -r recursively deletes all objects:\n\n\
+             - All objects under the path are deleted\n\
+             - Cannot be recovered without versioning enabled\n\
+             - Deleting a bucket's contents this way also removes the bucket\n\n\
+             List first: gcloud storage ls --recursive gs://bucket/path/"
+        ),
+        // gcloud storage buckets delete
+        destructive_pattern!(
+            "storage-buckets-delete",
+            gcloud!(r"storage\s+buckets\s+delete\b"),
+            "gcloud storage buckets delete removes the entire GCS bucket.",
+            Critical,
+            "gcloud storage buckets delete removes the entire Cloud Storage bucket:\n\n\
+             - Bucket must be empty\n\
+             - Bucket name becomes available to others\n\
+             - All bucket-level permissions lost\n\n\
+             List contents first: gcloud storage ls gs://bucket/"
+        ),
         // container clusters delete
         destructive_pattern!(
             "gke-delete",
-            r"gcloud\s+container\s+clusters\s+delete",
+            gcloud!(r"container\s+clusters\s+delete\b"),
             "gcloud container clusters delete removes the entire GKE cluster.",
             Critical,
             "container clusters delete removes the entire GKE cluster:\n\n\
@@ -134,7 +205,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // projects delete
         destructive_pattern!(
             "project-delete",
-            r"gcloud\s+projects\s+delete",
+            gcloud!(r"projects\s+delete\b"),
             "gcloud projects delete removes the entire GCP project and ALL its resources!",
             Critical,
             "projects delete removes the ENTIRE GCP project:\n\n\
@@ -147,7 +218,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // functions delete
         destructive_pattern!(
             "functions-delete",
-            r"gcloud\s+functions\s+delete",
+            gcloud!(r"functions\s+delete\b"),
             "gcloud functions delete removes the Cloud Function.",
             High,
             "functions delete removes Cloud Functions:\n\n\
@@ -159,7 +230,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // pubsub topics/subscriptions delete
         destructive_pattern!(
             "pubsub-delete",
-            r"gcloud\s+pubsub\s+(?:topics|subscriptions)\s+delete",
+            gcloud!(r"pubsub\s+(?:topics|subscriptions)\s+delete\b"),
             "gcloud pubsub delete removes Pub/Sub topics or subscriptions.",
             High,
             "pubsub delete removes messaging infrastructure:\n\n\
@@ -171,7 +242,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // firestore delete
         destructive_pattern!(
             "firestore-delete",
-            r"gcloud\s+firestore\s+.*delete",
+            gcloud!(r"firestore\s+.*delete"),
             "gcloud firestore delete removes Firestore data.",
             Critical,
             "firestore delete removes Firestore documents:\n\n\
@@ -183,7 +254,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // container registry image delete
         destructive_pattern!(
             "container-images-delete",
-            r"gcloud\s+container\s+images\s+delete",
+            gcloud!(r"container\s+images\s+delete\b"),
             "gcloud container images delete permanently deletes container images.",
             High,
             "container images delete removes images from GCR:\n\n\
@@ -195,7 +266,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // artifact registry docker image delete
         destructive_pattern!(
             "artifacts-docker-images-delete",
-            r"gcloud\s+artifacts\s+docker\s+images\s+delete",
+            gcloud!(r"artifacts\s+docker\s+images\s+delete\b"),
             "gcloud artifacts docker images delete permanently deletes container images.",
             High,
             "artifacts docker images delete removes images from Artifact Registry:\n\n\
@@ -207,7 +278,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // artifact registry repository delete
         destructive_pattern!(
             "artifacts-repositories-delete",
-            r"gcloud\s+artifacts\s+repositories\s+delete",
+            gcloud!(r"artifacts\s+repositories\s+delete\b"),
             "gcloud artifacts repositories delete permanently deletes the repository.",
             Critical,
             "artifacts repositories delete removes entire repository:\n\n\
@@ -224,6 +295,104 @@ mod tests {
     use super::*;
     use crate::packs::test_helpers::*;
 
+    #[test]
+    fn blocks_core_deletes_with_global_flags_interleaved() {
+        let pack = create_pack();
+        let checks = [
+            ("gcloud compute instances delete vm-1", "compute-delete"),
+            (
+                "gcloud --project=prod compute instances delete vm-1 --zone us-central1-a",
+                "compute-delete",
+            ),
+            (
+                "gcloud --quiet --project prod beta compute instances delete vm-1",
+                "compute-delete",
+            ),
+            (
+                "gcloud --account=ops@example.com sql instances delete db",
+                "sql-delete",
+            ),
+            ("gcloud -q projects delete my-project", "project-delete"),
+            (
+                "gcloud --project prod --verbosity debug container clusters delete gke-1",
+                "gke-delete",
+            ),
+            (
+                "gcloud storage rm -r gs://bucket/path",
+                "storage-rm-recursive",
+            ),
+            (
+                "gcloud --project=prod storage rm --recursive gs://bucket",
+                "storage-rm-recursive",
+            ),
+            (
+                "gcloud storage buckets delete gs://bucket",
+                "storage-buckets-delete",
+            ),
+            ("gsutil rm -r gs://bucket/path", "gsutil-rm-recursive"),
+            ("gsutil -m rm -rf gs://bucket/path", "gsutil-rm-recursive"),
+            (
+                "gsutil -o \"GSUtil:parallel_thread_count=4\" -m rm -r gs://bucket",
+                "gsutil-rm-recursive",
+            ),
+        ];
+        for (command, pattern) in checks {
+            assert_blocks_with_pattern(&pack, command, pattern);
+        }
+    }
+
+    #[test]
+    fn allows_read_only_commands_with_global_flags() {
+        let pack = create_pack();
+        test_batch_allows(
+            &pack,
+            &[
+                "gcloud compute instances list",
+                "gcloud --project=prod compute instances list --filter=status:RUNNING",
+                "gcloud --project prod sql instances describe db",
+                "gcloud projects list",
+                "gcloud config get-value project",
+                "gcloud --quiet config get-value account",
+                "gsutil ls gs://bucket",
+                "gsutil rm gs://bucket/my-report.txt",
+                "gcloud storage rm gs://bucket/my-report.txt",
+            ],
+        );
+    }
+
+    #[test]
+    fn list_safe_pattern_does_not_cover_deletes() {
+        let pack = create_pack();
+        assert_no_safe_match(&pack, "gcloud compute instances delete list");
+        assert_no_safe_match(&pack, "gcloud --project=prod projects delete describe");
+    }
+
+    #[test]
+    fn safe_patterns_do_not_mask_chained_deletes() {
+        let pack = create_pack();
+        let checks = [
+            (
+                "gcloud compute instances list && gcloud projects delete p",
+                "project-delete",
+            ),
+            (
+                "gcloud config get-value project; gcloud container clusters delete c",
+                "gke-delete",
+            ),
+            (
+                "gsutil ls gs://bucket && gsutil rm -r gs://bucket/path",
+                "gsutil-rm-recursive",
+            ),
+            (
+                "gsutil cp a gs://bucket/a; gsutil rb gs://bucket",
+                "gsutil-rb",
+            ),
+        ];
+        for (command, pattern) in checks {
+            assert_blocks_with_pattern(&pack, command, pattern);
+        }
+    }
+
     #[test]
     fn container_registry_patterns_block() {
         let pack = create_pack();
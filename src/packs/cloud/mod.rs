@@ -2,9 +2,13 @@
 //!
 //! This pack provides protection against destructive cloud operations:
 //! - AWS CLI (aws)
+//! - AWS compute teardown (ec2/eks/autoscaling)
+//! - AWS security resources (iam/kms/secretsmanager)
 //! - Google Cloud SDK (gcloud)
 //! - Azure CLI (az)
 
 pub mod aws;
+pub mod aws_compute;
+pub mod aws_security;
 pub mod azure;
 pub mod gcp;
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Docker Compose operations like \
                       'down -v' which removes volumes",
         keywords: &["docker-compose", "docker compose", "compose"],
+        tags: &["containers", "docker"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/containers.md#containerscompose",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -2,8 +2,8 @@
 //!
 //! This includes patterns for:
 //! - system prune (removes unused data)
-//! - rm/rmi with force flags
-//! - volume/network prune
+//! - rm/rmi with force flags (including `container rm`/`image rm`)
+//! - volume/network prune and rm
 //! - container stop/kill without confirmation
 
 use crate::packs::{DestructivePattern, Pack, PatternSuggestion, SafePattern};
@@ -133,6 +133,18 @@ const VOLUME_RM_SUGGESTIONS: &[PatternSuggestion] = &[
     ),
 ];
 
+/// Suggestions for `docker network rm` pattern.
+const NETWORK_RM_SUGGESTIONS: &[PatternSuggestion] = &[
+    PatternSuggestion::new(
+        "docker network inspect {network}",
+        "Check which containers are attached before removal",
+    ),
+    PatternSuggestion::new(
+        "docker network prune --filter 'until=24h'",
+        "Remove only networks that have been unused for a while",
+    ),
+];
+
 /// Suggestions for `docker stop/kill $(docker ps ...)` pattern.
 const STOP_ALL_SUGGESTIONS: &[PatternSuggestion] = &[
     PatternSuggestion::new(
@@ -158,6 +170,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Docker operations like system prune, \
                       volume prune, and force removal",
         keywords: &["docker", "prune", "rmi", "volume"],
+        tags: &["containers", "docker"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/containers.md#containersdocker",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -184,6 +200,17 @@ fn create_safe_patterns() -> Vec<SafePattern> {
         safe_pattern!("docker-exec", r"docker\s+exec"),
         // docker stats is safe
         safe_pattern!("docker-stats", r"docker\s+stats"),
+        // volume/network listing is read-only; anchored to one command so a
+        // listing piped or chained into a removal
+        // (`docker volume ls -q | xargs docker volume rm`) is still checked
+        safe_pattern!(
+            "docker-volume-ls",
+            r"^docker\s+volume\s+(?:ls|list)\b[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "docker-network-ls",
+            r"^docker\s+network\s+(?:ls|list)\b[^;&|\n]*$"
+        ),
         // Dry-run flags
         safe_pattern!("docker-dry-run", r"docker\s+.*--dry-run"),
     ]
@@ -287,7 +314,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // rm -f (force remove containers)
         destructive_pattern!(
             "rm-force",
-            r"docker\s+rm\s+.*(?:-[a-zA-Z0-9]*f|--force)",
+            r"docker\s+(?:container\s+(?:rm|remove)|rm)\s+.*(?:-[a-zA-Z0-9]*f|--force)",
             "docker rm -f forcibly removes containers, potentially losing data.",
             High,
             "docker rm -f forcibly stops and removes containers. This is dangerous because:\n\n\
@@ -305,7 +332,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // rmi -f (force remove images)
         destructive_pattern!(
             "rmi-force",
-            r"docker\s+rmi\s+.*(?:-[a-zA-Z0-9]*f|--force)",
+            r"docker\s+(?:image\s+(?:rm|remove)|rmi)\s+.*(?:-[a-zA-Z0-9]*f|--force)",
             "docker rmi -f forcibly removes images even if in use.",
             High,
             "docker rmi -f forcibly removes images, even if containers are using them. \
@@ -322,7 +349,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // volume rm
         destructive_pattern!(
             "volume-rm",
-            r"docker\s+volume\s+rm",
+            r"docker\s+volume\s+(?:rm|remove)\b",
             "docker volume rm permanently deletes volumes and their data.",
             High,
             "docker volume rm permanently deletes named volumes and all data stored in them. \
@@ -338,6 +365,20 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
              tar czf /backup/volume-backup.tar.gz /data",
             VOLUME_RM_SUGGESTIONS
         ),
+        // network rm (Medium: no data loss, but attached services lose connectivity)
+        destructive_pattern!(
+            "network-rm",
+            r"docker\s+network\s+(?:rm|remove)\b",
+            "docker network rm removes networks; containers relying on them lose connectivity.",
+            Medium,
+            "docker network rm deletes user-defined networks. No data is lost, but:\n\n\
+             - Containers that reconnect on restart fail to start\n\
+             - Service discovery between containers on that network breaks\n\
+             - Custom subnet and driver settings must be recreated by hand\n\n\
+             Check what is attached first:\n  \
+             docker network inspect <network>",
+            NETWORK_RM_SUGGESTIONS
+        ),
         // stop/kill all containers pattern
         destructive_pattern!(
             "stop-all",
@@ -363,6 +404,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::packs::Severity;
     use crate::packs::test_helpers::*;
 
     #[test]
@@ -376,6 +418,62 @@ mod tests {
         assert_allows(&pack, "docker rm container");
     }
 
+    #[test]
+    fn test_long_form_force_removal() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "docker container rm -f web", "rm-force");
+        assert_blocks_with_pattern(&pack, "docker container remove --force web", "rm-force");
+        assert_blocks_with_pattern(&pack, "docker image rm -f nginx:latest", "rmi-force");
+        assert_blocks_with_pattern(&pack, "docker image remove --force nginx", "rmi-force");
+
+        assert_allows(&pack, "docker container rm web");
+        assert_allows(&pack, "docker image rm nginx");
+    }
+
+    #[test]
+    fn test_volume_and_network_removal() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "docker volume rm pgdata", "volume-rm");
+        assert_blocks_with_severity(&pack, "docker volume rm pgdata", Severity::High);
+        assert_blocks_with_pattern(&pack, "docker volume remove pgdata", "volume-rm");
+        assert_blocks_with_pattern(&pack, "docker volume prune -f", "volume-prune");
+        assert_blocks_with_pattern(&pack, "docker container prune", "container-prune");
+        assert_blocks_with_pattern(&pack, "docker network rm backend", "network-rm");
+        assert_blocks_with_severity(&pack, "docker network rm backend", Severity::Medium);
+    }
+
+    #[test]
+    fn test_read_only_listing_allowed() {
+        let pack = create_pack();
+        assert_allows(&pack, "docker ps -a");
+        assert_allows(&pack, "docker images");
+        assert_allows(&pack, "docker volume ls");
+        assert_allows(&pack, "docker volume ls -f dangling=true");
+        assert_allows(&pack, "docker network ls");
+
+        // Listing piped into removal must not be whitelisted
+        assert_blocks_with_pattern(
+            &pack,
+            "docker volume ls -q | xargs docker volume rm",
+            "volume-rm",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "docker network ls -q | xargs docker network rm",
+            "network-rm",
+        );
+        assert_blocks(
+            &pack,
+            "docker volume ls && docker rmi -f img",
+            "forcibly removes",
+        );
+        assert_blocks(
+            &pack,
+            "docker network ls; docker rmi --force img",
+            "forcibly removes",
+        );
+    }
+
     #[test]
     fn test_rmi_force() {
         let pack = create_pack();
@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Podman operations like system prune, \
                       volume prune, and force removal",
         keywords: &["podman", "prune"],
+        tags: &["containers"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/containers.md#containerspodman",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
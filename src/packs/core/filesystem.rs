@@ -3,6 +3,9 @@
 //! This includes patterns for:
 //! - rm -rf outside temp directories (blocked)
 //! - rm -rf in /tmp, /var/tmp, $TMPDIR (allowed)
+//! - rm -rf on Windows drive roots, UNC shares and the user profile (critical)
+//! - `for f in $(find ...); do rm|shred|unlink ...` loops (critical when the
+//!   find root is `/`, `~`, or a top-level system directory)
 
 use crate::packs::{DestructivePattern, Pack, PatternSuggestion, Platform, SafePattern, Severity};
 use crate::{destructive_pattern, safe_pattern};
@@ -11,6 +14,18 @@ use crate::{destructive_pattern, safe_pattern};
 // Suggestion constants (must be 'static for the pattern struct)
 // ============================================================================
 
+/// Suggestions for `for f in $(find ...); do rm ...` loops.
+const FIND_LOOP_DELETE_SUGGESTIONS: &[PatternSuggestion] = &[
+    PatternSuggestion::new(
+        "find {path} -name '{glob}' -print | head -20",
+        "Preview what the loop would delete before running it",
+    ),
+    PatternSuggestion::new(
+        "find ./{subdir} -name '{glob}'",
+        "Scope the find to a project directory instead of / or a system path",
+    ),
+];
+
 /// Suggestions for `rm -rf` on root/home paths pattern.
 const RM_RF_ROOT_HOME_SUGGESTIONS: &[PatternSuggestion] = &[
     PatternSuggestion::new(
@@ -198,12 +213,23 @@ impl RmFlagTracker {
     }
 }
 
+/// Parse every `rm` segment of a (possibly chained) command.
+///
+/// Each `&&`/`||`/`;`/`|` segment is judged on its own. Navigation segments
+/// (`cd`, `pushd`, `popd`) and other non-`rm` commands are skipped, so
+/// `cd /data && rm -rf .` is decided by its `rm` segment alone. The command is
+/// allowed only if every recursive-force `rm` segment targets a temp path;
+/// otherwise the most severe denial wins (so `rm -rf /tmp/x && rm -rf /` is
+/// denied as critical).
 pub(crate) fn parse_rm_command(command: &str) -> RmParseDecision {
     let tokens = tokenize_for_normalization(command);
     if tokens.is_empty() {
         return RmParseDecision::NoMatch;
     }
 
+    let mut denied: Option<RmParseMatch> = None;
+    let mut allowed = false;
+
     let mut i = 0;
     while i < tokens.len() {
         let current = &tokens[i];
@@ -218,7 +244,18 @@ pub(crate) fn parse_rm_command(command: &str) -> RmParseDecision {
         };
 
         if text == "rm" {
-            return parse_rm_segment(command, &tokens, i + 1);
+            match parse_rm_segment(command, &tokens, i + 1) {
+                RmParseDecision::Deny(hit) => {
+                    let more_severe = denied.as_ref().is_none_or(|prev| {
+                        prev.severity != Severity::Critical && hit.severity == Severity::Critical
+                    });
+                    if more_severe {
+                        denied = Some(hit);
+                    }
+                }
+                RmParseDecision::Allow => allowed = true,
+                RmParseDecision::NoMatch => {}
+            }
         }
 
         // Skip to the next separator before scanning for another command word.
@@ -228,7 +265,11 @@ pub(crate) fn parse_rm_command(command: &str) -> RmParseDecision {
         }
     }
 
-    RmParseDecision::NoMatch
+    match denied {
+        Some(hit) => RmParseDecision::Deny(hit),
+        None if allowed => RmParseDecision::Allow,
+        None => RmParseDecision::NoMatch,
+    }
 }
 
 #[allow(clippy::too_many_lines)]
@@ -446,7 +487,44 @@ fn path_is_root_home(path: &PathToken<'_>) -> bool {
         return true;
     }
 
-    false
+    // PowerShell does not expand variables inside single quotes.
+    if path.quote == QuoteKind::Single && starts_with_ignore_ascii_case(text, "$env:") {
+        return false;
+    }
+
+    path_is_windows_root_or_profile(text)
+}
+
+/// Check whether `path` targets a Windows drive root, UNC share or the user
+/// profile directory.
+///
+/// These are the Windows counterparts of POSIX `/` and `~`, so deletions
+/// aimed at them are classified at the same (critical) severity:
+/// - drive specifications: `C:`, `C:\`, `C:/`, and anything below them
+/// - UNC roots: `\\server\share` (including `\\?\` device paths)
+/// - `%USERPROFILE%` (cmd) and `$env:USERPROFILE` (PowerShell)
+pub(crate) fn path_is_windows_root_or_profile(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    if bytes.len() >= 2
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && (bytes.len() == 2 || matches!(bytes[2], b'\\' | b'/'))
+    {
+        return true;
+    }
+
+    if path.starts_with("\\\\") {
+        return true;
+    }
+
+    ["%USERPROFILE%", "$env:USERPROFILE", "${env:USERPROFILE}"]
+        .iter()
+        .any(|prefix| starts_with_ignore_ascii_case(path, prefix))
+}
+
+fn starts_with_ignore_ascii_case(text: &str, prefix: &str) -> bool {
+    text.len() >= prefix.len()
+        && text.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
 }
 
 /// Create the core filesystem pack.
@@ -456,7 +534,11 @@ pub fn create_pack() -> Pack {
         id: "core.filesystem".to_string(),
         name: "Core Filesystem",
         description: "Protects against dangerous rm -rf commands outside temp directories",
-        keywords: &["rm"],
+        keywords: &["rm", "shred", "unlink"],
+        tags: &["filesystem", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/core.md#corefilesystem",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -608,11 +690,12 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // rm -rf on root or home paths (CRITICAL - catastrophic, never allow)
         destructive_pattern!(
             "rm-rf-root-home",
-            r"rm\s+-[a-zA-Z]*[rR][a-zA-Z]*f[a-zA-Z]*\s+[/~]|rm\s+-[a-zA-Z]*f[a-zA-Z]*[rR][a-zA-Z]*\s+[/~]",
+            r"rm\s+-[a-zA-Z]*[rR][a-zA-Z]*f[a-zA-Z]*\s+(?:[/~]|[a-zA-Z]:(?:[\\/]|\s|$)|\\\\|(?i:%userprofile%|\$env:userprofile))|rm\s+-[a-zA-Z]*f[a-zA-Z]*[rR][a-zA-Z]*\s+(?:[/~]|[a-zA-Z]:(?:[\\/]|\s|$)|\\\\|(?i:%userprofile%|\$env:userprofile))",
             "rm -rf on root or home paths is EXTREMELY DANGEROUS. This command will NOT be executed. Ask the user to run it manually if truly needed.",
             Critical,
             "This command would recursively delete files starting from the root filesystem (/) \
-             or home directory (~). This is catastrophic and will destroy:\n\n\
+             or home directory (~), or their Windows equivalents (a drive root such as C:\\, \
+             a UNC share, or %USERPROFILE%). This is catastrophic and will destroy:\n\n\
              - Your entire operating system\n\
              - All installed applications and libraries\n\
              - All user data, documents, and configurations\n\
@@ -688,6 +771,35 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
              find /path --maxdepth 2 -ls | head -30",
             RM_RECURSIVE_FORCE_SUGGESTIONS
         ),
+        // for f in $(find <system root> ...); do rm|shred|unlink ...; done
+        destructive_pattern!(
+            "find-loop-delete-system",
+            r#"for\s+\w+\s+in\s+(?:\$\(|`)\s*(?:sudo\s+)?find\s+(?:-[HLP]\s+)*["']?(?:~|/(?:bin|boot|dev|etc|home|lib|lib64|opt|root|sbin|srv|usr|var)?)/?["']?(?:\s|\)|`)[^)`]*[)`]\s*;?\s*do\s+(?:[^;&|\n]*(?:;|&&|\|\||\n)\s*)*?(?:sudo\s+)?(?:rm|shred|unlink)(?:\s|$)"#,
+            "Deleting every file a find over / or a system directory returns is EXTREMELY DANGEROUS. Scope the find to a project directory.",
+            Critical,
+            "This loop runs a delete command on each path printed by a find rooted at \
+             /, ~, or a top-level system directory such as /etc or /usr. A pattern \
+             like -name '*.log' still matches files the system depends on, and the \
+             loop deletes them one by one with no confirmation.\n\n\
+             Word splitting on $(find ...) also breaks paths with spaces into several \
+             arguments, so unrelated files can be deleted.\n\n\
+             Preview first and scope the search:\n  \
+             find /var/log/myapp -name '*.log' -print | head -20",
+            FIND_LOOP_DELETE_SUGGESTIONS
+        ),
+        // for f in $(find <dir> ...); do rm|shred|unlink ...; done (warn)
+        destructive_pattern!(
+            "find-loop-delete",
+            r"for\s+\w+\s+in\s+(?:\$\(|`)\s*(?:sudo\s+)?find\s[^)`]*[)`]\s*;?\s*do\s+(?:[^;&|\n]*(?:;|&&|\|\||\n)\s*)*?(?:sudo\s+)?(?:rm|shred|unlink)(?:\s|$)",
+            "Loop deletes every path a find returns; preview the find output first.",
+            Medium,
+            "This loop runs a delete command on each path printed by find. The set of \
+             matched files is only known at run time, and word splitting on \
+             $(find ...) breaks paths containing spaces into separate arguments.\n\n\
+             Preview the matches first:\n  \
+             find ./build -name '*.o' -print | head -20",
+            FIND_LOOP_DELETE_SUGGESTIONS
+        ),
     ]
 }
 
@@ -812,6 +924,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rm_parser_denies_rm_segment_after_navigation() {
+        let command = "cd /data && rm -rf .";
+        let RmParseDecision::Deny(hit) = parse_rm_command(command) else {
+            unreachable!("Expected rm parser to deny '{command}'");
+        };
+        assert_eq!(hit.pattern_name, RM_RF_GENERAL_NAME);
+        let span = hit.span.expect("deny should carry a span");
+        assert!(
+            span.start >= command.find("rm").unwrap(),
+            "span {span:?} should point into the rm segment, not the cd segment"
+        );
+
+        assert_rm_parser_denies("pushd x; rm -rf .", RM_RF_GENERAL_NAME, Severity::High);
+        assert_rm_parser_denies(
+            "popd || rm -r -f build",
+            RM_R_F_SEPARATE_NAME,
+            Severity::High,
+        );
+        assert_rm_parser_allows("cd /tmp && rm -rf /tmp/build");
+        assert_rm_parser_no_match("cd /data && ls");
+    }
+
+    #[test]
+    fn test_rm_parser_checks_every_rm_segment() {
+        assert_rm_parser_denies(
+            "rm -rf /tmp/x && rm -rf /",
+            RM_RF_ROOT_HOME_NAME,
+            Severity::Critical,
+        );
+        assert_rm_parser_denies(
+            "rm -rf ./build; rm -rf ~",
+            RM_RF_ROOT_HOME_NAME,
+            Severity::Critical,
+        );
+        assert_rm_parser_denies(
+            "rm -rf /tmp/a && rm -rf data",
+            RM_RF_GENERAL_NAME,
+            Severity::High,
+        );
+        assert_rm_parser_allows("rm -rf /tmp/a && rm -rf /var/tmp/b");
+    }
+
     #[test]
     fn test_rm_parser_allows_tmpdir_quotes() {
         assert_rm_parser_allows(r#"rm -rf "$TMPDIR/foo""#);
@@ -858,8 +1013,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rm_rf_windows_drive_root_critical() {
+        let pack = create_pack();
+        for cmd in [
+            r"rm -rf C:\",
+            "rm -rf C:/",
+            "rm -rf C:",
+            r"rm -rf C:\Users\me\project",
+            "rm -rf d:/data",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+            assert_rm_parser_denies(cmd, RM_RF_ROOT_HOME_NAME, Severity::Critical);
+        }
+        assert_rm_parser_denies(
+            r#"rm -rf "C:\Users""#,
+            RM_RF_ROOT_HOME_NAME,
+            Severity::Critical,
+        );
+    }
+
+    #[test]
+    fn test_rm_rf_windows_unc_and_profile_critical() {
+        let pack = create_pack();
+        for cmd in [
+            r"rm -rf \\server\share",
+            "rm -rf %USERPROFILE%",
+            r"rm -rf %userprofile%\Documents",
+            "rm -rf $env:USERPROFILE",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+            assert_rm_parser_denies(cmd, RM_RF_ROOT_HOME_NAME, Severity::Critical);
+        }
+        // PowerShell does not expand variables inside single quotes.
+        assert_rm_parser_denies(
+            "rm -rf '$env:USERPROFILE'",
+            RM_RF_GENERAL_NAME,
+            Severity::High,
+        );
+    }
+
+    #[test]
+    fn test_windows_relative_paths_stay_high() {
+        assert_rm_parser_denies(r"rm -rf build\out", RM_RF_GENERAL_NAME, Severity::High);
+        assert_rm_parser_denies("rm -rf node_modules", RM_RF_GENERAL_NAME, Severity::High);
+        assert!(!path_is_windows_root_or_profile("c:foo"));
+        assert!(!path_is_windows_root_or_profile("%USERPROFILE_BACKUP"));
+    }
+
     #[test]
     fn test_rm_parser_option_terminator() {
         assert_rm_parser_no_match("rm -- -rf /tmp/safe");
     }
+
+    #[test]
+    fn test_find_loop_delete_from_system_root_is_critical() {
+        let pack = create_pack();
+        for cmd in [
+            r#"for f in $(find / -name '*.log'); do rm -f "$f"; done"#,
+            r#"for f in $(find /etc -type f); do shred -u "$f"; done"#,
+            "for f in `find ~ -name '*.bak'`; do echo $f; rm $f; done",
+            "for f in $(sudo find /usr/ -name '*.pyc'); do sudo unlink $f; done",
+            "for f in $(find -L / -name core)\ndo\n  rm $f\ndone",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "find-loop-delete-system");
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+        }
+    }
+
+    #[test]
+    fn test_find_loop_delete_scoped_only_warns() {
+        let pack = create_pack();
+        for cmd in [
+            r#"for f in $(find ./build -name '*.o'); do rm -f "$f"; done"#,
+            r#"for f in $(find /var/log/myapp -name '*.gz'); do rm "$f"; done"#,
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "find-loop-delete");
+            assert_blocks_with_severity(&pack, cmd, Severity::Medium);
+        }
+    }
+
+    #[test]
+    fn test_find_loop_without_delete_allowed() {
+        let pack = create_pack();
+        assert_allows(
+            &pack,
+            r#"for f in $(find / -name '*.log'); do echo "$f"; done"#,
+        );
+        assert_allows(&pack, "for f in $(find . -name '*.rs'); do wc -l $f; done");
+        assert_allows(&pack, "for f in $(ls); do rm $f; done");
+    }
 }
@@ -3,7 +3,9 @@
 //! This includes patterns for:
 //! - Work destruction (reset --hard, checkout --, restore)
 //! - History rewriting (push --force, branch -D)
+//! - Remote branch deletion (push --delete, push :branch)
 //! - Stash destruction (stash drop, stash clear)
+//! - Remote and config edits that lose push targets (remote remove, set-url, config --unset-all)
 
 use crate::packs::{DestructivePattern, Pack, PatternSuggestion, SafePattern};
 use crate::{destructive_pattern, safe_pattern};
@@ -17,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive git commands that can lose uncommitted work, \
                       rewrite history, or destroy stashes",
         keywords: &["git"],
+        tags: &["git", "vcs", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/core.md#coregit",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -42,12 +48,35 @@ fn create_safe_patterns() -> Vec<SafePattern> {
             "restore-staged-short",
             r"git\s+(?:\S+\s+)*restore\s+-S\s+(?!.*--worktree)(?!.*-W\b)"
         ),
-        // clean dry-run just previews, doesn't delete
+        // clean dry-run just previews, doesn't delete (-n wins over -f). Anchored
+        // to one command: the flag only excuses the clean it belongs to.
         safe_pattern!(
             "clean-dry-run-short",
-            r"git\s+(?:\S+\s+)*clean\s+-[a-z]*n[a-z]*"
+            r"^git\s+(?:[^\s;&|]+\s+)*?clean\s+(?:[^\s;&|]+\s+)*?-[a-zA-Z]*n[a-zA-Z]*(?:\s[^;&|\n]*)?$"
+        ),
+        safe_pattern!(
+            "clean-dry-run-long",
+            r"^git\s+(?:[^\s;&|]+\s+)*?clean\s+(?:[^\s;&|]+\s+)*?--dry-run\b[^;&|\n]*$"
+        ),
+        // Plain push of a branch (no flags, no refspec); anchored so it never
+        // shadows a deletion or force push elsewhere in the command.
+        safe_pattern!(
+            "push-plain",
+            r"^git\s+push(?:\s+[A-Za-z0-9_][\w./-]*){0,2}\s*$"
+        ),
+        // Read-only remote/config inspection; anchored like push-plain.
+        safe_pattern!(
+            "remote-list-verbose",
+            r"^git\s+remote\s+(?:-v|--verbose)\s*$"
+        ),
+        safe_pattern!(
+            "config-list",
+            r"^git\s+config\s+(?:--(?:global|system|local)\s+)?(?:--list|-l)\s*$"
+        ),
+        safe_pattern!(
+            "config-get",
+            r"^git\s+config\s+(?:--(?:global|system|local)\s+)?--get(?:-all|-regexp)?\s+\S+\s*$"
         ),
-        safe_pattern!("clean-dry-run-long", r"git\s+(?:\S+\s+)*clean\s+--dry-run"),
     ]
 }
 
@@ -241,10 +270,45 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
                 ]
             }
         ),
+        // clean -f with -x/-X also deletes ignored files (.env, local secrets,
+        // build caches) - checked before plain clean-force so it wins.
+        destructive_pattern!(
+            "clean-force-ignored",
+            r"git\s+(?:\S+\s+)*clean(?=(?:\s+[^\s;&|]+)*?\s+(?:-[a-zA-Z]*f|--force\b))(?=(?:\s+[^\s;&|]+)*?\s+-[a-zA-Z]*[xX])",
+            "git clean -f with -x/-X removes ignored files too (.env, local secrets, build caches). Review with 'git clean -ndx' first.",
+            Critical,
+            "git clean -x (or -X) is especially dangerous: it extends the clean to files \
+             matched by .gitignore. \
+             Those are exactly the files git was told to leave alone: .env files, \
+             local credentials, IDE settings, downloaded dependencies, and build \
+             caches. None of them are in git history, so they cannot be recovered.\n\n\
+             - git clean -fdx: untracked AND ignored files and directories\n\
+             - git clean -fdX: only ignored files and directories\n\n\
+             Safer alternatives:\n\
+             - git clean -ndx: Dry-run, shows everything that would be deleted\n\
+             - git clean -fd: Remove untracked files but keep ignored ones\n\
+             - git clean -ix: Interactive mode",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git clean -ndx",
+                        "Dry run first, including ignored files",
+                    ),
+                    PatternSuggestion::new(
+                        "git clean -fd",
+                        "Remove untracked files but keep ignored ones (.env, caches)",
+                    ),
+                    PatternSuggestion::new(
+                        "git clean -ix",
+                        "Interactive mode, choose what to delete",
+                    ),
+                ]
+            }
+        ),
         // clean -f deletes untracked files (CRITICAL - permanently removes files)
         destructive_pattern!(
             "clean-force",
-            r"git\s+(?:\S+\s+)*clean\s+(?:-[a-z]*f|--force\b)",
+            r"git\s+(?:\S+\s+)*clean\s+(?:[^\s;&|]+\s+)*?(?:-[a-zA-Z]*f|--force\b)",
             "git clean -f/--force removes untracked files permanently. Review with 'git clean -n' first.",
             Critical,
             "git clean -f permanently deletes untracked files from your working directory. \
@@ -375,6 +439,63 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
                 ]
             }
         ),
+        // push --delete / push :branch removes a branch from the remote.
+        // Protected branches are Critical; other branches warn (Medium) since
+        // the ref can be re-pushed from any clone that still has it.
+        destructive_pattern!(
+            "push-delete-protected",
+            r"git\s+(?:[^\s;&|]+\s+)*push\s+(?:[^;&|]*\s)?(?:--delete|-d)\s+(?:[^;&|]*\s)?(?:refs/heads/)?(?:main|master|develop|production|trunk|release/\S+)(?:[\s;&|]|$)|git\s+(?:[^\s;&|]+\s+)*push\s+(?:[^;&|]*\s)?(?:refs/heads/)?(?:main|master|develop|production|trunk|release/\S+)\s+(?:[^;&|]*\s)?(?:--delete|-d)(?:[\s;&|]|$)",
+            "git push --delete on a protected branch removes it from the remote for everyone.",
+            Critical,
+            "git push --delete <branch> (or -d) removes the branch from the remote repository. \
+             Deleting a shared, protected branch such as main, master, develop, production, \
+             trunk, or release/* breaks every clone, CI pipeline, and open pull request that \
+             targets it.\n\n\
+             Safer alternatives:\n\
+             - git push <remote> --delete <feature-branch>: Delete only branches you own\n\
+             - git ls-remote --heads <remote>: Verify which branch you are about to delete\n\n\
+             Recovery if needed:\n\
+               git push <remote> <local-copy-of-branch>:refs/heads/<branch>",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git ls-remote --heads {remote}",
+                        "List remote branches to confirm the target",
+                    ),
+                    PatternSuggestion::new(
+                        "git push {remote} {branch}:refs/heads/{branch}",
+                        "Restore the branch from a local copy if it was deleted",
+                    ),
+                ]
+            }
+        ),
+        destructive_pattern!(
+            "push-refspec-delete-protected",
+            r"git\s+(?:[^\s;&|]+\s+)*push\s+(?:[^;&|]*\s)?\+?:(?:refs/heads/)?(?:main|master|develop|production|trunk|release/\S+)(?:[\s;&|]|$)",
+            "git push <remote> :<branch> deletes a protected branch from the remote for everyone.",
+            Critical,
+            "git push <remote> :<branch> pushes an empty source ref, which deletes <branch> \
+             on the remote. It is the older spelling of git push --delete. Deleting a shared, \
+             protected branch such as main, master, develop, production, trunk, or release/* \
+             breaks every clone, CI pipeline, and open pull request that targets it.\n\n\
+             Safer alternatives:\n\
+             - git push <remote> <branch>: Did you mean to push the branch instead?\n\
+             - git ls-remote --heads <remote>: Verify which branch you are about to delete\n\n\
+             Recovery if needed:\n\
+               git push <remote> <local-copy-of-branch>:refs/heads/<branch>",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git push {remote} {branch}",
+                        "Push the branch instead of deleting it",
+                    ),
+                    PatternSuggestion::new(
+                        "git ls-remote --heads {remote}",
+                        "List remote branches to confirm the target",
+                    ),
+                ]
+            }
+        ),
         // stash destruction (Medium: single stash, recoverable via fsck/unreachable objects)
         destructive_pattern!(
             "stash-drop",
@@ -444,6 +565,171 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
                 ]
             }
         ),
+        // Unprotected push deletions only warn, so they come after every Critical
+        // pattern; otherwise `git push -d origin x && git stash clear` would warn.
+        destructive_pattern!(
+            "push-delete",
+            r"git\s+(?:[^\s;&|]+\s+)*push\s+(?:[^;&|]*\s)?(?:--delete|-d)(?:\s|$)",
+            "git push --delete removes a branch or tag from the remote. Verify the target first.",
+            Medium,
+            "git push --delete <ref> (or -d) removes a branch or tag from the remote repository. \
+             Anyone who has not fetched the ref loses access to it, and open pull requests \
+             targeting it are closed. The ref can be re-pushed from any clone that still has it.\n\n\
+             Safer alternatives:\n\
+             - git ls-remote --heads <remote>: Verify which branch you are about to delete\n\
+             - git branch -r --merged: Only delete remote branches that are already merged",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git ls-remote --heads {remote}",
+                        "List remote branches to confirm the target",
+                    ),
+                    PatternSuggestion::new(
+                        "git branch -r --merged",
+                        "Check the remote branch is already merged before deleting",
+                    ),
+                ]
+            }
+        ),
+        destructive_pattern!(
+            "push-refspec-delete",
+            r"git\s+(?:[^\s;&|]+\s+)*push\s+(?:[^;&|]*\s)?\+?:[^\s:]",
+            "git push <remote> :<ref> deletes the ref from the remote. Verify the target first.",
+            Medium,
+            "git push <remote> :<ref> pushes an empty source ref, which deletes <ref> on the \
+             remote. It is the older spelling of git push --delete and is easy to type by \
+             accident when a source branch name is missing. The ref can be re-pushed from any \
+             clone that still has it.\n\n\
+             Safer alternatives:\n\
+             - git push <remote> --delete <ref>: Spell the deletion out explicitly\n\
+             - git push <remote> <src>:<ref>: Include the source branch if you meant to push",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git push {remote} --delete {ref}",
+                        "Spell the deletion out explicitly",
+                    ),
+                    PatternSuggestion::new(
+                        "git push {remote} {src}:{ref}",
+                        "Include the source branch if you meant to push",
+                    ),
+                ]
+            }
+        ),
+        // Remote and config edits don't destroy data, but they can silently cut a
+        // repo off from its push target (Medium: recoverable by re-adding the URL).
+        // Listed after the Critical stash patterns so a chained `git stash clear`
+        // is not reported as a mere warning.
+        destructive_pattern!(
+            "remote-remove",
+            r"git\s+(?:\S+\s+)*remote\s+(?:remove|rm)\s+\S",
+            "git remote remove drops the remote and its tracking branches. Recoverable with 'git remote add' if you know the URL.",
+            Medium,
+            "git remote remove <name> (or rm) deletes the remote's URL, its fetch/push \
+             configuration, and all remote-tracking branches (refs/remotes/<name>/*). No \
+             commits are lost, but upstream tracking for local branches is removed and pushes \
+             to that remote stop working until it is re-added.\n\n\
+             Safer alternatives:\n\
+             - git remote -v: Record the current URLs before removing anything\n\
+             - git remote rename <old> <new>: Keep the remote under a different name\n\n\
+             Recovery if needed:\n\
+               git remote add <name> <url> && git fetch <name>",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git remote -v",
+                        "Record the current remote URLs before removing",
+                    ),
+                    PatternSuggestion::new(
+                        "git remote rename {old} {new}",
+                        "Keep the remote under a different name instead",
+                    ),
+                ]
+            }
+        ),
+        destructive_pattern!(
+            "remote-set-url",
+            r"git\s+(?:\S+\s+)*remote\s+set-url\s+",
+            "git remote set-url replaces the remote's URL. Verify the new URL; the old one is not kept.",
+            Medium,
+            "git remote set-url <name> <url> overwrites the URL git fetches from and pushes \
+             to. A typo or wrong host means later pushes go to the wrong place or fail, and \
+             git keeps no record of the previous URL. Nothing in the repository itself is \
+             lost, so this is recoverable if you know the old URL.\n\n\
+             Safer alternatives:\n\
+             - git remote -v: Record the current URL first\n\
+             - git ls-remote <url>: Check the new URL is reachable before switching\n\n\
+             Recovery if needed:\n\
+               git remote set-url <name> <old-url>",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git remote -v",
+                        "Record the current URL before replacing it",
+                    ),
+                    PatternSuggestion::new(
+                        "git ls-remote {url}",
+                        "Confirm the new URL is reachable before switching",
+                    ),
+                ]
+            }
+        ),
+        destructive_pattern!(
+            "config-unset-all",
+            r"git\s+(?:\S+\s+)*config\s+(?:.*\s)?(?:--unset-all|unset\s+(?:.*\s)?--all)(?:\s|$)",
+            "git config --unset-all removes every value of a key. Recoverable only if you noted the values.",
+            Medium,
+            "git config --unset-all <key> removes every value of a multi-valued key, such as \
+             all push URLs or fetch refspecs of a remote, or all credential helpers. With \
+             --global or --system it affects every repository on the machine. Git keeps no \
+             history of config files, so the values must be re-entered by hand.\n\n\
+             Safer alternatives:\n\
+             - git config --get-all <key>: Record the current values first\n\
+             - git config --unset <key> <value-regex>: Remove only the matching value\n\n\
+             Recovery if needed:\n\
+               git config --add <key> <value>  # once per value",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git config --get-all {key}",
+                        "Record the current values before removing them",
+                    ),
+                    PatternSuggestion::new(
+                        "git config --unset {key} {value_regex}",
+                        "Remove only the value that matches",
+                    ),
+                ]
+            }
+        ),
+        // checkout . (no --) discards every uncommitted change under the pathspec.
+        // Warn only: it's a common, often intentional reset of a scratch tree. Kept
+        // after every High/Critical pattern so `git checkout . && git reset --hard`
+        // reports the reset (the first match in a pack decides).
+        destructive_pattern!(
+            "checkout-broad-pathspec",
+            r"git\s+(?:\S+\s+)*checkout\s+(?:(?:-f|--force)\s+)?(?:\.|\*|:/)(?:\s|$)",
+            "git checkout . discards all uncommitted changes in the tree. Use 'git stash' first.",
+            Medium,
+            "git checkout . (or *, :/) without a branch name restores every tracked file \
+             under the pathspec from the index, silently discarding all uncommitted \
+             modifications. Unlike a branch switch, nothing is preserved.\n\n\
+             Safer alternatives:\n\
+             - git stash: Save changes temporarily, restore later with 'git stash pop'\n\
+             - git checkout <branch>: Switch branches without discarding changes\n\n\
+             Preview changes first:\n  git status && git diff",
+            &const {
+                [
+                    PatternSuggestion::new(
+                        "git stash",
+                        "Save changes temporarily, restore later with 'git stash pop'",
+                    ),
+                    PatternSuggestion::new(
+                        "git status && git diff",
+                        "Review what would be lost before discarding",
+                    ),
+                ]
+            }
+        ),
     ]
 }
 
@@ -502,8 +788,115 @@ mod tests {
 
         assert_blocks_with_severity(&pack, "git clean -f", Severity::Critical);
         assert_blocks_with_pattern(&pack, "git clean -f", "clean-force");
-        assert_blocks(&pack, "git clean -fd", "removes untracked files");
-        assert_blocks(&pack, "git clean -xf", "removes untracked files");
+        assert_blocks_with_pattern(&pack, "git clean -fd", "clean-force");
+        assert_blocks_with_pattern(&pack, "git clean -d -f", "clean-force");
+        assert_blocks(&pack, "git clean --force src/", "removes untracked files");
+    }
+
+    #[test]
+    fn test_clean_force_ignored_escalates_to_critical() {
+        let pack = create_pack();
+
+        for cmd in [
+            "git clean -fdx",
+            "git clean -xf",
+            "git clean -fdX",
+            "git clean -x -f -d",
+            "git clean --force -x",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "clean-force-ignored");
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+        }
+        // -x in a later command does not escalate this one.
+        assert_blocks_with_pattern(&pack, "git clean -fd && ls -x", "clean-force");
+    }
+
+    #[test]
+    fn test_push_delete_protected_branch_critical() {
+        let pack = create_pack();
+
+        for cmd in [
+            "git push origin --delete main",
+            "git push --delete origin master",
+            "git push origin -d release/1.2",
+            "git push origin main --delete",
+            "git push origin --delete refs/heads/develop",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+            assert_blocks_with_pattern(&pack, cmd, "push-delete-protected");
+        }
+
+        for cmd in [
+            "git push origin :main",
+            "git push origin +:refs/heads/trunk",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+            assert_blocks_with_pattern(&pack, cmd, "push-refspec-delete-protected");
+        }
+    }
+
+    #[test]
+    fn test_push_delete_feature_branch_warns() {
+        let pack = create_pack();
+
+        assert_blocks_with_severity(
+            &pack,
+            "git push origin --delete feature/x",
+            Severity::Medium,
+        );
+        assert_blocks_with_pattern(&pack, "git push -d origin maintenance", "push-delete");
+        assert_blocks_with_pattern(&pack, "git push origin --delete v1.0.0", "push-delete");
+
+        assert_blocks_with_severity(&pack, "git push origin :feature/x", Severity::Medium);
+        assert_blocks_with_pattern(&pack, "git push origin :mainline", "push-refspec-delete");
+    }
+
+    #[test]
+    fn test_push_delete_stays_within_one_command() {
+        let pack = create_pack();
+
+        for cmd in [
+            "git push origin main && git branch -d feature-x",
+            "git push origin main; git tag -d v1",
+            "git push origin release/1.2 || echo --delete",
+        ] {
+            assert_allows(&pack, cmd);
+        }
+        assert_blocks_with_pattern(
+            &pack,
+            "git fetch && git push origin --delete main",
+            "push-delete-protected",
+        );
+    }
+
+    #[test]
+    fn test_push_delete_feature_branch_does_not_mask_stash_clear() {
+        let pack = create_pack();
+
+        assert_blocks_with_pattern(
+            &pack,
+            "git push -d origin feature-x && git stash clear",
+            "stash-clear",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "git push origin :feature-x; git stash clear",
+            "stash-clear",
+        );
+    }
+
+    #[test]
+    fn test_push_without_deletion_allowed() {
+        let pack = create_pack();
+
+        assert_safe_pattern_matches(&pack, "git push origin main");
+        assert_safe_pattern_matches(&pack, "git push origin feature/x");
+        assert_allows(&pack, "git push origin main:main");
+        assert_allows(&pack, "git push origin HEAD:refs/heads/feature");
+        assert_allows(&pack, "git push -u origin feature");
+        assert_allows(&pack, "git push origin :");
+        assert_no_safe_match(&pack, "git push origin :main");
+        assert_no_safe_match(&pack, "git push origin main --force");
     }
 
     #[test]
@@ -568,6 +961,46 @@ mod tests {
         assert_blocks_with_pattern(&pack, "git branch -f feature", "branch-force-delete");
     }
 
+    #[test]
+    fn test_checkout_broad_pathspec_medium() {
+        let pack = create_pack();
+
+        assert_blocks_with_severity(&pack, "git checkout .", Severity::Medium);
+        assert_blocks_with_pattern(&pack, "git checkout .", "checkout-broad-pathspec");
+        assert_blocks_with_pattern(&pack, "git checkout *", "checkout-broad-pathspec");
+        assert_blocks_with_pattern(&pack, "git checkout :/", "checkout-broad-pathspec");
+        assert_blocks_with_pattern(&pack, "git checkout -f .", "checkout-broad-pathspec");
+        assert_blocks_with_pattern(&pack, "git -C repo checkout .", "checkout-broad-pathspec");
+        // The explicit `--` form keeps its stricter rule.
+        assert_blocks_with_pattern(&pack, "git checkout -- .", "checkout-discard");
+    }
+
+    #[test]
+    fn test_checkout_broad_pathspec_does_not_mask_chained_destruction() {
+        let pack = create_pack();
+
+        assert_blocks_with_pattern(&pack, "git checkout . && git reset --hard", "reset-hard");
+        assert_blocks_with_pattern(&pack, "git checkout . && git clean -fd", "clean-force");
+        assert_blocks_with_severity(
+            &pack,
+            "git checkout . && git push --force origin main",
+            Severity::Critical,
+        );
+        assert_blocks_with_pattern(&pack, "git checkout . && git stash clear", "stash-clear");
+    }
+
+    #[test]
+    fn test_checkout_branch_switch_allowed() {
+        let pack = create_pack();
+
+        assert_allows(&pack, "git checkout main");
+        assert_allows(&pack, "git checkout feature/new-thing");
+        assert_allows(&pack, "git checkout -");
+        assert_allows(&pack, "git checkout v1.2.0");
+        assert_allows(&pack, "git checkout ./src/lib.rs");
+        assert_allows(&pack, "git checkout .github");
+    }
+
     #[test]
     fn test_stash_drop_medium() {
         // Stash drop is Medium severity (recoverable via fsck)
@@ -577,6 +1010,72 @@ mod tests {
         assert_blocks(&pack, "git stash drop stash@{0}", "Recoverable");
     }
 
+    #[test]
+    fn test_remote_removal_medium() {
+        let pack = create_pack();
+
+        assert_blocks_with_pattern(&pack, "git remote remove origin", "remote-remove");
+        assert_blocks_with_pattern(&pack, "git remote rm upstream", "remote-remove");
+        assert_blocks_with_severity(&pack, "git remote remove origin", Severity::Medium);
+        assert_blocks(&pack, "git remote rm origin", "git remote add");
+        assert_blocks_with_pattern(
+            &pack,
+            "git remote set-url origin git@example.com:wrong/repo.git",
+            "remote-set-url",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "git remote set-url --push origin https://example.com/r.git",
+            Severity::Medium,
+        );
+
+        assert_allows(&pack, "git remote -v");
+        assert_allows(&pack, "git remote add origin git@example.com:me/repo.git");
+        assert_allows(&pack, "git remote rename origin upstream");
+        assert_allows(&pack, "git remote show origin");
+    }
+
+    #[test]
+    fn test_remote_and_config_edits_do_not_mask_stash_clear() {
+        let pack = create_pack();
+
+        for cmd in [
+            "git remote remove origin && git stash clear",
+            "git remote set-url origin git@example.com:x/y.git; git stash clear",
+            "git config --unset-all user.email && git stash clear",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "stash-clear");
+        }
+    }
+
+    #[test]
+    fn test_config_unset_all_medium() {
+        let pack = create_pack();
+
+        assert_blocks_with_pattern(
+            &pack,
+            "git config --global --unset-all credential.helper",
+            "config-unset-all",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "git config --unset-all remote.origin.pushurl",
+            Severity::Medium,
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "git config unset --all remote.origin.fetch",
+            "config-unset-all",
+        );
+
+        assert_allows(&pack, "git config --list");
+        assert_allows(&pack, "git config --global --list");
+        assert_allows(&pack, "git config --get remote.origin.url");
+        assert_allows(&pack, "git config --get-all remote.origin.fetch");
+        assert_allows(&pack, "git config --unset user.signingkey");
+        assert_allows(&pack, "git config user.name 'A Developer'");
+    }
+
     // =========================================================================
     // Safe Pattern Tests
     // =========================================================================
@@ -613,6 +1112,22 @@ mod tests {
         assert_allows(&pack, "git clean -n");
         assert_allows(&pack, "git clean -dn");
         assert_allows(&pack, "git clean --dry-run");
+        assert_allows(&pack, "git clean -ndx");
+        assert_allows(&pack, "git clean -fd -n");
+        assert_allows(&pack, "git clean -x --dry-run");
+    }
+
+    #[test]
+    fn test_clean_dry_run_does_not_excuse_a_chained_clean() {
+        let pack = create_pack();
+
+        assert_blocks_with_pattern(
+            &pack,
+            "git clean -n && git clean -fdx",
+            "clean-force-ignored",
+        );
+        assert_blocks_with_pattern(&pack, "git clean -f; git clean -n", "clean-force");
+        assert_blocks_with_pattern(&pack, "git clean --dry-run && git clean -fd", "clean-force");
     }
 
     // =========================================================================
@@ -6,9 +6,42 @@
 //! - `MongoDB` (`mongosh`, `mongodump`)
 //! - `Redis` (`redis-cli`)
 //! - `SQLite` (`sqlite3`)
+//! - Vector databases (Qdrant, Weaviate, Pinecone)
 
 pub mod mongodb;
 pub mod mysql;
 pub mod postgresql;
 pub mod redis;
 pub mod sqlite;
+pub mod vector;
+
+#[cfg(test)]
+mod tests {
+    use crate::packs::Pack;
+
+    /// SQL and Redis verbs are case-insensitive on the server, so every
+    /// destructive pattern that spells one in upper case must carry `(?i)`;
+    /// otherwise `Drop Table` would slip past a `DROP\s+TABLE` pattern.
+    /// (Safe patterns may stay exact: a miss there only means no shortcut.)
+    #[test]
+    fn keyword_patterns_are_case_insensitive() {
+        let packs: [Pack; 4] = [
+            super::postgresql::create_pack(),
+            super::mysql::create_pack(),
+            super::sqlite::create_pack(),
+            super::redis::create_pack(),
+        ];
+        for pack in &packs {
+            for regex in pack.destructive_patterns.iter().map(|p| p.regex.as_str()) {
+                let spells_verb = regex
+                    .split(|c: char| !c.is_ascii_alphabetic())
+                    .any(|word| word.len() >= 3 && word.bytes().all(|b| b.is_ascii_uppercase()));
+                assert!(
+                    !spells_verb || regex.starts_with("(?i)"),
+                    "{}: pattern {regex:?} spells an upper-case verb without (?i)",
+                    pack.id
+                );
+            }
+        }
+    }
+}
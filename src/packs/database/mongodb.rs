@@ -3,7 +3,7 @@
 //! This includes patterns for:
 //! - dropDatabase/dropCollection commands
 //! - db.collection.remove({}) without criteria
-//! - mongosh destructive operations
+//! - mongosh/mongo `--eval` one-liners that call destructive shell methods
 
 use crate::packs::{DestructivePattern, Pack, SafePattern};
 use crate::{destructive_pattern, safe_pattern};
@@ -23,6 +23,10 @@ pub fn create_pack() -> Pack {
             "dropCollection",
             "deleteMany",
         ],
+        tags: &["database", "nosql", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/database.md#databasemongodb",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -32,17 +36,41 @@ pub fn create_pack() -> Pack {
 }
 
 fn create_safe_patterns() -> Vec<SafePattern> {
+    // Read-only shell methods are only safe when the same command does not also
+    // call a destructive method (e.g. `--eval 'db.a.find(); db.dropDatabase()'`).
     vec![
         // find operations are safe
-        safe_pattern!("mongo-find", r"\.find\s*\("),
+        safe_pattern!(
+            "mongo-find",
+            r"^(?!.*(?:dropDatabase|dropCollection|deleteMany|\.drop\s*\(|\.remove\s*\()).*\.find(?:One)?\s*\("
+        ),
         // count operations are safe
-        safe_pattern!("mongo-count", r"\.count(?:Documents)?\s*\("),
+        safe_pattern!(
+            "mongo-count",
+            r"^(?!.*(?:dropDatabase|dropCollection|deleteMany|\.drop\s*\(|\.remove\s*\()).*\.(?:count(?:Documents)?|estimatedDocumentCount)\s*\("
+        ),
         // aggregate operations are safe (read-only)
-        safe_pattern!("mongo-aggregate", r"\.aggregate\s*\("),
+        safe_pattern!(
+            "mongo-aggregate",
+            r"^(?!.*(?:dropDatabase|dropCollection|deleteMany|\.drop\s*\(|\.remove\s*\(|\$out\b|\$merge\b)).*\.aggregate\s*\("
+        ),
+        // listing collections is safe
+        safe_pattern!(
+            "mongo-list-collections",
+            r"^(?!.*(?:dropDatabase|dropCollection|deleteMany|\.drop\s*\(|\.remove\s*\()).*\.(?:listCollections|getCollectionNames|getCollectionInfos)\s*\("
+        ),
+        // database and collection stats are safe
+        safe_pattern!(
+            "mongo-stats",
+            r"^(?!.*(?:dropDatabase|dropCollection|deleteMany|\.drop\s*\(|\.remove\s*\()).*\.stats\s*\("
+        ),
         // mongodump without --drop is safe (backup only)
         safe_pattern!("mongodump-no-drop", r"mongodump\s+(?!.*--drop)"),
         // explain is safe
-        safe_pattern!("mongo-explain", r"\.explain\s*\("),
+        safe_pattern!(
+            "mongo-explain",
+            r"^(?!.*(?:dropDatabase|dropCollection|deleteMany|\.drop\s*\(|\.remove\s*\()).*\.explain\s*\("
+        ),
     ]
 }
 
@@ -131,5 +159,114 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
              Backup:\n  \
              mongodump --db=mydb --collection=mycollection"
         ),
+        // Shell one-liners that reach dropDatabase indirectly (e.g. `db["dropDatabase"]()`)
+        destructive_pattern!(
+            "mongo-shell-drop-database",
+            r"\b(?:mongo|mongosh)\b.*\bdropDatabase\b",
+            "mongo/mongosh command invokes dropDatabase.",
+            Critical,
+            "This mongo or mongosh invocation references dropDatabase, which permanently \
+             removes the target database with all of its collections, indexes, and \
+             documents.\n\n\
+             Safer alternatives:\n\
+             - Back up first: mongodump --db=dbname --out=/path/to/backup\n\
+             - Confirm the target with: mongosh --eval 'db.getName()'\n\
+             - Review contents with: mongosh --eval 'db.getCollectionNames()'"
+        ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "database.mongodb");
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn blocks_eval_one_liners() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh mongodb://localhost:27017/app --eval 'db.dropDatabase()'",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongo staging --quiet --eval \"db.getSiblingDB('app').dropDatabase()\"",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh app --eval 'db.sessions.drop()'",
+            "drop-collection",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh app --eval 'db.users.deleteMany({})'",
+            "delete-all",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongo app --eval 'db.events.remove( { } )'",
+            "delete-all",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh app --eval 'db[\"dropDatabase\"]()'",
+            "mongo-shell-drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongorestore --uri mongodb://prod --drop /backups/latest",
+            "mongorestore-drop",
+        );
+    }
+
+    #[test]
+    fn read_only_calls_do_not_mask_destructive_ones() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh app --eval 'db.users.find().limit(1); db.dropDatabase()'",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh app --eval 'db.users.countDocuments({}); db.users.deleteMany({})'",
+            "delete-all",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mongosh app --eval 'db.stats(); db.orders.drop()'",
+            "drop-collection",
+        );
+    }
+
+    #[test]
+    fn allows_read_only_eval_one_liners() {
+        let pack = create_pack();
+        assert_safe_pattern_matches(
+            &pack,
+            "mongosh app --eval 'db.users.find({ active: true }).limit(5)'",
+        );
+        assert_safe_pattern_matches(&pack, "mongosh app --eval 'db.users.countDocuments({})'");
+        assert_safe_pattern_matches(
+            &pack,
+            "mongosh app --quiet --eval 'db.runCommand({ listCollections: 1 }); db.getCollectionNames()'",
+        );
+        assert_safe_pattern_matches(&pack, "mongo app --eval 'db.stats()'");
+        assert_safe_pattern_matches(&pack, "mongodump --db=app --out=/backups/app");
+        assert_allows(
+            &pack,
+            "mongosh app --eval 'db.users.deleteMany({ expired: true })'",
+        );
+    }
+}
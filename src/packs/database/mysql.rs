@@ -6,6 +6,12 @@
 //! - DELETE without WHERE
 //! - mysqladmin drop
 //! - mysqldump with destructive flags
+//!
+//! SQL comments are only recognised when an `-e` payload consists of nothing
+//! but comments. Comments mixed with executable SQL are not stripped, so
+//! `/* DROP TABLE t */ INSERT ...` is still blocked: telling a comment apart
+//! from a quoted `/*` or `--` needs a real SQL lexer, and a false block is the
+//! cheaper mistake.
 
 use crate::packs::{DestructivePattern, Pack, PatternSuggestion, SafePattern};
 use crate::{destructive_pattern, safe_pattern};
@@ -192,6 +198,10 @@ pub fn create_pack() -> Pack {
             "truncate",
             "GRANT",
         ],
+        tags: &["database", "sql", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/database.md#databasemysql",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -208,15 +218,30 @@ fn create_safe_patterns() -> Vec<SafePattern> {
         safe_pattern!("show-command", r"(?i)^\s*SHOW\s+"),
         // DESCRIBE/DESC/EXPLAIN are safe
         safe_pattern!("describe-query", r"(?i)^\s*(?:DESCRIBE|DESC|EXPLAIN)\s+"),
-        // mysqldump without --add-drop is safe (backup only)
+        // mysqldump without --add-drop is safe (backup only). Anchored to a
+        // single command so it cannot whitelist a chained statement.
         safe_pattern!(
             "mysqldump-no-drop",
-            r"mysqldump\s+(?!.*--add-drop-database)(?!.*--add-drop-table)"
+            r"^mysqldump\s+(?![^;&|]*--add-drop-(?:database|table))[^;&|]*$"
         ),
-        // mysql with --execute for SELECT only
+        // mysql with --execute for a single SELECT, anchored the same way
+        // (no `; DROP ...` inside the query, no `&&`/`|` chained command)
         safe_pattern!(
             "mysql-select",
-            r#"mysql\s+.*(?:-e|--execute)\s*['"]?\s*SELECT"#
+            r#"^mysql\s+[^;&|]*(?:-e|--execute)\s*['"]?\s*SELECT\b[^;&|]*$"#
+        ),
+        // mysql --execute whose SQL is nothing but comments, e.g. a
+        // commented-out `-- DROP TABLE IF EXISTS users`. `/*! ... */` bodies
+        // are executed by MySQL, so they never count as comments, and quotes,
+        // `$`, backticks and backslashes end the match so nothing can hide in
+        // a shell expansion.
+        safe_pattern!(
+            "mysql-comment-only",
+            concat!(
+                r#"^mysql\s+[^;&|'"\n]*(?:-e|--execute)\s*['"]"#,
+                r#"(?:\s*(?:(?:--\s|#)[^'"$\x60\\\n]*|/\*(?:[^!*'"$\x60\\\n][^*'"$\x60\\\n]*)?\*/))+"#,
+                r#"\s*['"]\s*$"#
+            )
         ),
     ]
 }
@@ -286,7 +311,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // DELETE without WHERE
         destructive_pattern!(
             "delete-without-where",
-            r"(?i)DELETE\s+FROM\s+(?:(?:[a-zA-Z_][a-zA-Z0-9_]*|`[^`]+`)(?:\.(?:[a-zA-Z_][a-zA-Z0-9_]*|`[^`]+`))?)\s*(?:;|$)",
+            r#"(?i)DELETE\s+FROM\s+(?:(?:[a-zA-Z_][a-zA-Z0-9_]*|`[^`]+`)(?:\.(?:[a-zA-Z_][a-zA-Z0-9_]*|`[^`]+`))?)\s*(?:;|['"]|$)"#,
             "DELETE without WHERE clause deletes ALL rows. Add a WHERE clause.",
             High,
             "DELETE without WHERE removes ALL rows from the table:\n\n\
@@ -452,6 +477,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mixed_case_sql_is_blocked() {
+        let pack = create_pack();
+        assert_blocks(&pack, "DrOp TaBlE users;", "permanently deletes the table");
+        assert_blocks(
+            &pack,
+            "mysql -e 'Drop Database prod'",
+            "permanently deletes the entire database",
+        );
+        assert_blocks(&pack, "tRuNcAtE TaBlE logs", "TRUNCATE");
+        // Non-ASCII identifiers neither hide the verb nor panic.
+        assert_blocks(&pack, "DROP TABLE café;", "permanently deletes the table");
+        assert_allows(&pack, "mysql -e 'SELECT * FROM ｄｒｏｐ'");
+    }
+
     #[test]
     fn test_drop_table() {
         let pack = create_pack();
@@ -531,6 +571,119 @@ mod tests {
         assert_allows(&pack, "GRANT SELECT ON *.* TO 'readonly'@'%';");
     }
 
+    #[test]
+    fn test_mysql_execute_one_liners() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -u root -e \"DROP DATABASE prod\"",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e 'DELETE FROM users' shop",
+            "delete-without-where",
+        );
+        // A leading SELECT must not whitelist a chained destructive statement.
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e \"SELECT 1; DROP TABLE users\"",
+            "drop-table",
+        );
+        assert_allows(&pack, "mysql -e \"SELECT id FROM users WHERE name = 'x'\"");
+        assert_allows(&pack, "mysql -e 'DELETE FROM users WHERE id = 1'");
+        // Nor may it whitelist a separate chained mysql invocation.
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e 'SELECT 1' && mysql -e 'DROP DATABASE prod'",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e 'SELECT 1' | mysql -e 'DROP DATABASE prod'",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysqldump shop > shop.sql; mysql -e 'DROP DATABASE shop'",
+            "drop-database",
+        );
+    }
+
+    #[test]
+    fn test_commented_out_drop_is_allowed() {
+        let pack = create_pack();
+        assert_safe_pattern_matches(&pack, "mysql -e '-- DROP TABLE x'");
+        assert_allows(&pack, "mysql shop -e \"-- DROP TABLE IF EXISTS users\"");
+        assert_allows(&pack, "mysql shop -e '# DROP DATABASE shop'");
+        assert_allows(&pack, "mysql shop -e '/* DROP TABLE IF EXISTS users */'");
+        assert_allows(
+            &pack,
+            "mysql shop --execute '/* old */ -- DROP TABLE users\n# DROP DATABASE shop'",
+        );
+    }
+
+    #[test]
+    fn test_comments_mixed_with_sql_are_still_checked() {
+        let pack = create_pack();
+        // Executable comments run their body in MySQL.
+        assert_blocks_with_pattern(&pack, "mysql -e '/*! DROP TABLE users */'", "drop-table");
+        // Comment followed by real SQL on the next line.
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e '-- cleanup\nDROP TABLE users'",
+            "drop-table",
+        );
+        // Comment text is not stripped when real SQL shares the payload.
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql shop -e \"/* DROP TABLE IF EXISTS users */ INSERT INTO t VALUES (1)\"",
+            "drop-table",
+        );
+        // Shell expansion inside a double-quoted comment still runs.
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e \"-- $(mysql -e 'DROP DATABASE prod')\"",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e \"-- `echo DROP DATABASE prod | mysql`\"",
+            "drop-database",
+        );
+        // A comment-only payload cannot excuse a chained command.
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e '-- noop' && mysql -e 'DROP DATABASE prod'",
+            "drop-database",
+        );
+    }
+
+    #[test]
+    fn test_drop_near_sql_comment_markers_is_blocked() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e 'DROP DATABASE prod -- */'",
+            "drop-database",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql -e 'DROP TABLE users; SELECT \"*/\"'",
+            "drop-table",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql shop -e \"/* cleanup */ DROP TABLE users\"",
+            "drop-table",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "mysql shop -e \"DROP TABLE users; /* done */\"",
+            "drop-table",
+        );
+    }
+
     #[test]
     fn test_safe_patterns() {
         let pack = create_pack();
@@ -135,6 +135,10 @@ pub fn create_pack() -> Pack {
             "psql", "dropdb", "DROP", "TRUNCATE", "pg_dump", "postgres", "DELETE", "delete",
             "drop", "truncate",
         ],
+        tags: &["database", "sql", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/database.md#databasepostgresql",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -314,4 +318,16 @@ mod tests {
         assert_allows(&pack, "DELETE FROM users WHERE id = 1;");
         assert_allows(&pack, "DELETE FROM users WHERE active = false");
     }
+
+    #[test]
+    fn test_mixed_case_sql_is_blocked() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "psql -c 'DrOp TaBlE users'", "drop-table");
+        assert_blocks_with_pattern(&pack, "psql -c 'Drop Database prod'", "drop-database");
+        assert_blocks_with_pattern(&pack, "psql -c 'TrUnCaTe users'", "truncate-table");
+        assert_blocks(&pack, "DeLeTe FrOm users;", "DELETE without WHERE");
+        // Non-ASCII identifiers neither hide the verb nor panic.
+        assert_blocks_with_pattern(&pack, "psql -c 'DROP TABLE \"Ünïcødé\"'", "drop-table");
+        assert_allows(&pack, "psql -c 'SELECT ｄｒｏｐ FROM t'");
+    }
 }
@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Redis operations like FLUSHALL, \
                       FLUSHDB, and mass key deletion",
         keywords: &["redis", "FLUSHALL", "FLUSHDB", "DEBUG"],
+        tags: &["database", "nosql", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/database.md#databaseredis",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
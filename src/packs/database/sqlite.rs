@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive SQLite operations like DROP TABLE, \
                       DELETE without WHERE, and accidental data loss",
         keywords: &["sqlite", "sqlite3", "DROP", "TRUNCATE", "DELETE"],
+        tags: &["database", "sql", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/database.md#databasesqlite",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
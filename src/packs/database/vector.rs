@@ -0,0 +1,381 @@
+//! Vector database patterns - protections against destructive Qdrant,
+//! Weaviate, and Pinecone operations.
+//!
+//! These databases are mostly driven over REST, so most patterns match
+//! `curl -X DELETE` (or `http DELETE`) against their endpoints:
+//! - Qdrant: `DELETE /collections/<name>`, point and snapshot deletion
+//! - Weaviate: `DELETE /v1/schema/<class>`, batch and object deletion
+//! - Pinecone: `DELETE /indexes/<name>`, `/collections/<name>`, vector deletion
+//!
+//! plus the Pinecone CLI and client one-liners (`delete_collection(`,
+//! `delete_index(`, ...). Dropping a collection loses embeddings that are
+//! expensive to recompute, so those are High.
+
+use crate::packs::{DestructivePattern, Pack, SafePattern};
+use crate::{destructive_pattern, safe_pattern};
+
+/// Create the vector database pack.
+#[must_use]
+pub fn create_pack() -> Pack {
+    Pack {
+        id: "database.vector".to_string(),
+        name: "Vector Databases",
+        description: "Protects against destructive Qdrant, Weaviate, and Pinecone operations \
+                      like dropping collections, classes, and indexes or deleting vectors \
+                      via REST (curl/httpie), CLI, and client one-liners",
+        keywords: &[
+            "qdrant",
+            ":6333",
+            "weaviate",
+            "/v1/schema",
+            "/v1/batch/objects",
+            "/v1/objects",
+            "pinecone",
+        ],
+        tags: &["database", "vector", "ai", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/database.md#databasevector",
+        ),
+        safe_patterns: create_safe_patterns(),
+        destructive_patterns: create_destructive_patterns(),
+        keyword_matcher: None,
+        safe_regex_set: None,
+        safe_regex_set_is_complete: false,
+    }
+}
+
+fn create_safe_patterns() -> Vec<SafePattern> {
+    vec![
+        // Plain GET of any Qdrant endpoint (list/describe collections, cluster info).
+        safe_pattern!(
+            "qdrant-curl-get",
+            r#"^curl\s+(?:-[sSfLkv]+\s+|-H\s+(?:'[^']*'|"[^"]*")\s+|-X\s*GET\s+)*['"]?https?://[^\s'"]*(?:qdrant|:633[34])[^\s'"]*['"]?\s*$"#
+        ),
+        // Search/query/scroll requests only read points.
+        safe_pattern!(
+            "qdrant-curl-query",
+            r#"^curl\s+[^|;&\n]*https?://[^\s'"]*(?:qdrant|:633[34])[^\s'"]*/collections/[\w-]+/points/(?:search|query|scroll|count|recommend)(?:/batch)?['"]?(?:\s[^|;&\n]*)?$"#
+        ),
+        // Plain GET of Weaviate schema/objects/meta.
+        safe_pattern!(
+            "weaviate-curl-get",
+            r#"^curl\s+(?:-[sSfLkv]+\s+|-H\s+(?:'[^']*'|"[^"]*")\s+|-X\s*GET\s+)*['"]?https?://[^\s'"]*/v1/(?:schema|objects|meta|nodes)\b[^\s'"]*['"]?\s*$"#
+        ),
+        // Weaviate GraphQL (Get/Aggregate/Explore) is read-only.
+        safe_pattern!(
+            "weaviate-curl-graphql",
+            r#"^curl\s+[^|;&\n]*https?://[^\s'"]*/v1/graphql['"]?(?:\s[^|;&\n]*)?$"#
+        ),
+        // Pinecone CLI list/describe.
+        safe_pattern!(
+            "pinecone-cli-read",
+            r"^pinecone\s+(?:index|collection)\s+(?:list|describe|describe-stats)\b[^|;&\n]*$"
+        ),
+        // Plain GET of a Pinecone endpoint (list/describe indexes).
+        safe_pattern!(
+            "pinecone-curl-get",
+            r#"^curl\s+(?:-[sSfLkv]+\s+|-H\s+(?:'[^']*'|"[^"]*")\s+|-X\s*GET\s+)*['"]?https?://[^\s'"]*pinecone\.io[^\s'"]*['"]?\s*$"#
+        ),
+        // Pinecone query and index stats only read vectors.
+        safe_pattern!(
+            "pinecone-curl-query",
+            r#"^curl\s+[^|;&\n]*https?://[^\s'"]*pinecone\.io/(?:query|describe_index_stats|vectors/fetch)\b[^|;&\n]*$"#
+        ),
+    ]
+}
+
+#[allow(clippy::too_many_lines)]
+fn create_destructive_patterns() -> Vec<DestructivePattern> {
+    vec![
+        // ---- Qdrant ----
+        destructive_pattern!(
+            "qdrant-delete-collection",
+            r#"(?:\bcurl\b[^|;&\n]*(?:-X\s*|--request[\s=]+)['"]?DELETE\b[^|;&\n]*|\bhttps?\s+(?:-\S+\s+)*DELETE\s+['"]?)https?://[^\s'"]*(?:qdrant|:633[34])[^\s'"]*/collections/[\w-]+/?(?:[\s'"?]|$)"#,
+            "DELETE /collections/<name> drops a Qdrant collection and all of its vectors.",
+            High,
+            "Deleting a Qdrant collection removes every point, vector, and payload in \
+             it along with its index configuration. Re-creating it means re-embedding \
+             the source data, which is slow and often expensive.\n\n\
+             Safer alternatives:\n\
+             - GET /collections/<name> to check what it contains\n\
+             - POST /collections/<name>/snapshots to snapshot it first\n\
+             - Use collection aliases to switch traffic instead of deleting"
+        ),
+        destructive_pattern!(
+            "qdrant-delete-points",
+            r#"https?://[^\s'"]*(?:qdrant|:633[34])[^\s'"]*/collections/[\w-]+/points/delete\b"#,
+            "POST /collections/<name>/points/delete removes points (vectors) from a Qdrant collection.",
+            High,
+            "The points/delete endpoint deletes every point matching the given ids or \
+             filter. A broad or empty filter can wipe most of the collection.\n\n\
+             Safer alternatives:\n\
+             - Run the same filter through points/scroll or points/count first\n\
+             - Snapshot the collection before bulk deletes"
+        ),
+        destructive_pattern!(
+            "qdrant-delete-snapshot",
+            r#"(?:\bcurl\b[^|;&\n]*(?:-X\s*|--request[\s=]+)['"]?DELETE\b[^|;&\n]*|\bhttps?\s+(?:-\S+\s+)*DELETE\s+['"]?)https?://[^\s'"]*(?:qdrant|:633[34])[^\s'"]*/snapshots/[^\s'"/?]+"#,
+            "DELETE on a Qdrant snapshot removes a backup.",
+            Medium,
+            "Snapshots are the recovery point for a collection. Deleting one is only \
+             safe if a newer snapshot exists.\n\n\
+             Safer alternatives:\n\
+             - GET /collections/<name>/snapshots to list remaining snapshots first"
+        ),
+        // ---- Weaviate ----
+        destructive_pattern!(
+            "weaviate-delete-class",
+            r#"(?:\bcurl\b[^|;&\n]*(?:-X\s*|--request[\s=]+)['"]?DELETE\b[^|;&\n]*|\bhttps?\s+(?:-\S+\s+)*DELETE\s+['"]?)https?://[^\s'"]*/v1/schema(?:/[\w-]+)?/?(?:[\s'"?]|$)"#,
+            "DELETE /v1/schema/<class> drops a Weaviate class (collection) and all of its objects.",
+            High,
+            "Deleting a Weaviate class removes its schema, every object in it, and the \
+             vectors stored with them. Re-importing means re-vectorizing the data.\n\n\
+             Safer alternatives:\n\
+             - GET /v1/schema/<class> to inspect it first\n\
+             - Create a backup with POST /v1/backups/<backend>\n\
+             - Delete specific objects instead of the whole class"
+        ),
+        destructive_pattern!(
+            "weaviate-batch-delete",
+            r#"(?:\bcurl\b[^|;&\n]*(?:-X\s*|--request[\s=]+)['"]?DELETE\b[^|;&\n]*|\bhttps?\s+(?:-\S+\s+)*DELETE\s+['"]?)https?://[^\s'"]*(?:weaviate|:8080)[^\s'"]*/v1/batch/objects\b"#,
+            "DELETE /v1/batch/objects deletes every Weaviate object matching a filter.",
+            High,
+            "Batch delete removes all objects matching the `where` filter in one call. \
+             A broad filter can empty a class.\n\n\
+             Safer alternatives:\n\
+             - Send the request with \"dryRun\": true to see how many objects match\n\
+             - Back up the class first"
+        ),
+        destructive_pattern!(
+            "weaviate-delete-object",
+            r#"(?:\bcurl\b[^|;&\n]*(?:-X\s*|--request[\s=]+)['"]?DELETE\b[^|;&\n]*|\bhttps?\s+(?:-\S+\s+)*DELETE\s+['"]?)https?://[^\s'"]*(?:weaviate|:8080)[^\s'"]*/v1/objects/[^\s'"?]+"#,
+            "DELETE /v1/objects/... deletes a Weaviate object and its vector.",
+            Medium,
+            "Deleting a single object is narrow, but its vector has to be recomputed \
+             if it is needed again.\n\n\
+             Safer alternatives:\n\
+             - GET the object first to confirm the id"
+        ),
+        // ---- Pinecone ----
+        destructive_pattern!(
+            "pinecone-cli-delete",
+            r"\bpinecone\s+(?:index\s+delete|collection\s+delete|delete-index|delete-collection)\b",
+            "pinecone index/collection delete drops a Pinecone index or collection.",
+            High,
+            "Deleting a Pinecone index removes all of its vectors and metadata; a \
+             deleted collection cannot be used to restore an index anymore.\n\n\
+             Safer alternatives:\n\
+             - pinecone index describe <name> to check it first\n\
+             - Create a collection (backup) from the index before deleting it"
+        ),
+        destructive_pattern!(
+            "pinecone-delete-index",
+            r#"(?:\bcurl\b[^|;&\n]*(?:-X\s*|--request[\s=]+)['"]?DELETE\b[^|;&\n]*|\bhttps?\s+(?:-\S+\s+)*DELETE\s+['"]?)https?://[^\s'"]*pinecone\.io/(?:indexes|collections|databases)/[\w-]+"#,
+            "DELETE /indexes/<name> (or /collections/<name>) drops a Pinecone index or collection.",
+            High,
+            "Deleting a Pinecone index removes all of its vectors and metadata. \
+             Re-creating it means re-embedding and re-upserting the source data.\n\n\
+             Safer alternatives:\n\
+             - GET /indexes/<name> to check it first\n\
+             - POST /collections to snapshot the index before deleting it"
+        ),
+        destructive_pattern!(
+            "pinecone-delete-all-vectors",
+            r#"https?://[^\s'"]*pinecone\.io/vectors/delete\b[^|;&\n]*deleteAll["']?\s*:\s*true"#,
+            "Pinecone vectors/delete with deleteAll wipes every vector in the namespace.",
+            High,
+            "deleteAll removes every vector in the target namespace. The index itself \
+             stays, but it is empty.\n\n\
+             Safer alternatives:\n\
+             - Delete by ids or metadata filter instead\n\
+             - Check describe_index_stats for the namespace size first"
+        ),
+        destructive_pattern!(
+            "pinecone-delete-vectors",
+            r#"https?://[^\s'"]*pinecone\.io/vectors/delete\b"#,
+            "Pinecone vectors/delete removes vectors from an index.",
+            Medium,
+            "Deleting vectors by id or filter is scoped, but a broad filter can remove \
+             far more than intended.\n\n\
+             Safer alternatives:\n\
+             - Run the same filter through /query first"
+        ),
+        // ---- Client one-liners (python -c, node -e, ...) ----
+        destructive_pattern!(
+            "vector-client-drop",
+            r"(?i:qdrant|weaviate|pinecone)[^\n]*\.(?:delete_collection|delete_index|delete_class|delete_all|recreate_collection|deleteCollection|deleteIndex)\s*\(",
+            "Vector DB client call drops a collection, class, or index.",
+            High,
+            "Client calls like delete_collection(), recreate_collection(), \
+             schema.delete_class(), and delete_index() drop all stored vectors, just \
+             like the REST endpoints they wrap.\n\n\
+             Safer alternatives:\n\
+             - Snapshot or back up the collection first\n\
+             - Use aliases (Qdrant) to swap collections without deleting"
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "database.vector");
+        validate_pack(&pack);
+    }
+
+    #[test]
+    fn qdrant_blocks_collection_and_point_deletion() {
+        let pack = create_pack();
+        for cmd in [
+            "curl -X DELETE http://localhost:6333/collections/docs",
+            "curl -s -XDELETE 'https://qdrant.internal/collections/docs'",
+            "curl --request DELETE http://qdrant:6333/collections/docs?timeout=30",
+            "http DELETE http://localhost:6333/collections/docs",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "qdrant-delete-collection");
+            assert_blocks_with_severity(&pack, cmd, Severity::High);
+        }
+        assert_blocks_with_pattern(
+            &pack,
+            r#"curl -X POST http://localhost:6333/collections/docs/points/delete -d '{"filter":{}}'"#,
+            "qdrant-delete-points",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "curl -X DELETE http://localhost:6333/collections/docs/snapshots/snap-1.snapshot",
+            "qdrant-delete-snapshot",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            r#"python -c "from qdrant_client import QdrantClient; QdrantClient('localhost').delete_collection('docs')""#,
+            "vector-client-drop",
+        );
+    }
+
+    #[test]
+    fn qdrant_allows_reads_and_queries() {
+        let pack = create_pack();
+        assert_allows(&pack, "curl http://localhost:6333/collections");
+        assert_allows(
+            &pack,
+            "curl -s -X GET http://localhost:6333/collections/docs",
+        );
+        assert_allows(
+            &pack,
+            r#"curl -X POST http://localhost:6333/collections/docs/points/search -d '{"vector":[0.1],"limit":3}'"#,
+        );
+        assert_allows(
+            &pack,
+            "curl -X POST http://localhost:6333/collections/docs/points/scroll",
+        );
+    }
+
+    #[test]
+    fn weaviate_blocks_class_and_object_deletion() {
+        let pack = create_pack();
+        for cmd in [
+            "curl -X DELETE http://localhost:8080/v1/schema/Article",
+            "curl -X DELETE https://demo.weaviate.network/v1/schema/Article",
+            "http DELETE http://localhost:8080/v1/schema/Article",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "weaviate-delete-class");
+        }
+        assert_blocks_with_pattern(
+            &pack,
+            r#"curl -X DELETE http://localhost:8080/v1/batch/objects -d '{"match":{"class":"Article"}}'"#,
+            "weaviate-batch-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "curl -X DELETE http://localhost:8080/v1/objects/Article/36ddd591-2dee-4e7e-a3cc-eb86d30a4303",
+            "weaviate-delete-object",
+        );
+        assert_blocks_with_severity(
+            &pack,
+            "curl -X DELETE http://localhost:8080/v1/objects/Article/36ddd591",
+            Severity::Medium,
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            r#"python -c "import weaviate; weaviate.Client('http://localhost:8080').schema.delete_class('Article')""#,
+            "vector-client-drop",
+        );
+    }
+
+    #[test]
+    fn weaviate_allows_reads_and_graphql() {
+        let pack = create_pack();
+        assert_allows(&pack, "curl http://localhost:8080/v1/schema");
+        assert_allows(&pack, "curl -X GET http://localhost:8080/v1/schema/Article");
+        assert_allows(
+            &pack,
+            r#"curl -X POST http://localhost:8080/v1/graphql -d '{"query":"{ Get { Article { title } } }"}'"#,
+        );
+    }
+
+    #[test]
+    fn pinecone_blocks_index_and_vector_deletion() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "curl -X DELETE https://api.pinecone.io/indexes/docs -H 'Api-Key: xyz'",
+            "pinecone-delete-index",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "curl -X DELETE https://api.pinecone.io/collections/docs-backup",
+            "pinecone-delete-index",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "pinecone index delete --name docs",
+            "pinecone-cli-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            r#"curl -X POST https://docs-abc123.svc.pinecone.io/vectors/delete -d '{"deleteAll": true, "namespace": "prod"}'"#,
+            "pinecone-delete-all-vectors",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            r#"curl -X POST https://docs-abc123.svc.pinecone.io/vectors/delete -d '{"ids": ["a"]}'"#,
+            "pinecone-delete-vectors",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            r#"python -c "from pinecone import Pinecone; Pinecone().delete_index('docs')""#,
+            "vector-client-drop",
+        );
+    }
+
+    #[test]
+    fn pinecone_allows_list_describe_query() {
+        let pack = create_pack();
+        assert_allows(&pack, "pinecone index list");
+        assert_allows(&pack, "pinecone index describe --name docs");
+        assert_allows(
+            &pack,
+            "curl -H 'Api-Key: xyz' https://api.pinecone.io/indexes",
+        );
+        assert_allows(
+            &pack,
+            r#"curl -X POST https://docs-abc123.svc.pinecone.io/query -d '{"topK": 3}'"#,
+        );
+    }
+
+    #[test]
+    fn delete_elsewhere_in_chain_is_not_masked_by_safe_read() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "curl http://localhost:6333/collections && curl -X DELETE http://localhost:6333/collections/docs",
+            "qdrant-delete-collection",
+        );
+    }
+}
@@ -21,6 +21,10 @@ pub fn create_pack() -> Pack {
             "api.cloudflare.com",
             "dns-records",
         ],
+        tags: &["cloudflare", "dns", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/dns.md#dnscloudflare",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
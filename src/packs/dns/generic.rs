@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         name: "Generic DNS Tools",
         description: "Protects against destructive or risky DNS tooling usage (nsupdate deletes, zone transfers).",
         keywords: &["nsupdate", "dig", "host", "nslookup"],
+        tags: &["dns", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/dns.md#dnsgeneric",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
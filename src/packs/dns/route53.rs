@@ -20,6 +20,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive AWS Route53 DNS operations like hosted zone deletion \
                       and record set DELETE changes.",
         keywords: &["aws", "route53"],
+        tags: &["aws", "dns", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/dns.md#dnsroute53",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
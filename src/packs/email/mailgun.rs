@@ -20,6 +20,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Mailgun API operations like domain deletion, \
                       route deletion, and mailing list removal.",
         keywords: &["mailgun", "api.mailgun.net"],
+        tags: &["email"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/email.md#emailmailgun",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -21,6 +21,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Postmark API operations like server deletion, \
                       template deletion, and sender signature removal.",
         keywords: &["postmark", "api.postmarkapp.com"],
+        tags: &["email"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/email.md#emailpostmark",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -19,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive SendGrid API operations like template deletion, \
                       API key deletion, and domain authentication removal.",
         keywords: &["sendgrid", "api.sendgrid.com"],
+        tags: &["email"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/email.md#emailsendgrid",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
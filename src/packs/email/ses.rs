@@ -19,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive AWS Simple Email Service operations like \
                       identity deletion, template deletion, and configuration set removal.",
         keywords: &["ses", "sesv2"],
+        tags: &["aws", "email"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/email.md#emailses",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
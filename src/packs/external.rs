@@ -79,6 +79,14 @@ pub struct ExternalPack {
     #[serde(default)]
     pub keywords: Vec<String>,
 
+    /// Optional tags for grouping and filtering (e.g., `["internal", "data-loss"]`).
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Optional link to documentation for this pack.
+    #[serde(default)]
+    pub docs_url: Option<String>,
+
     /// Destructive patterns that block or warn based on severity.
     #[serde(default)]
     pub destructive_patterns: Vec<ExternalDestructivePattern>,
@@ -117,6 +125,11 @@ pub struct ExternalDestructivePattern {
     /// Safer command alternatives to suggest when this pattern matches.
     #[serde(default)]
     pub suggestions: Vec<ExternalSuggestion>,
+
+    /// Scan extractor contexts this pattern is limited to (e.g. `github_actions`).
+    /// Empty means every context; hook mode always applies the pattern.
+    #[serde(default)]
+    pub contexts: Vec<String>,
 }
 
 /// A safer command suggestion from an external pack file.
@@ -559,6 +572,17 @@ impl ExternalPack {
                     Box::leak(suggestion_vec.into_boxed_slice())
                 };
 
+                let context_filter: &'static [&'static str] = if p.contexts.is_empty() {
+                    &[]
+                } else {
+                    let context_vec: Vec<&'static str> = p
+                        .contexts
+                        .into_iter()
+                        .map(|s| Box::leak(s.into_boxed_str()) as &'static str)
+                        .collect();
+                    Box::leak(context_vec.into_boxed_slice())
+                };
+
                 DestructivePattern {
                     regex: LazyCompiledRegex::new(Box::leak(p.pattern.into_boxed_str())),
                     reason,
@@ -566,18 +590,36 @@ impl ExternalPack {
                     severity: p.severity.into(),
                     explanation,
                     suggestions,
+                    context_filter,
                 }
             })
             .collect();
 
-        Pack::new(
+        let tags: &'static [&'static str] = if self.tags.is_empty() {
+            &[]
+        } else {
+            let tag_vec: Vec<&'static str> = self
+                .tags
+                .into_iter()
+                .map(|s| Box::leak(s.into_boxed_str()) as &'static str)
+                .collect();
+            Box::leak(tag_vec.into_boxed_slice())
+        };
+        let docs_url: Option<&'static str> = self
+            .docs_url
+            .map(|s| Box::leak(s.into_boxed_str()) as &'static str);
+
+        let mut pack = Pack::new(
             self.id,
             name,
             description,
             keywords,
             safe_patterns,
             destructive_patterns,
-        )
+        );
+        pack.tags = tags;
+        pack.docs_url = docs_url;
+        pack
     }
 }
 
@@ -857,6 +899,27 @@ safe_patterns:
         assert_eq!(pack.safe_patterns.len(), 1);
     }
 
+    #[test]
+    fn test_pattern_contexts_become_context_filter() {
+        let yaml = r"
+id: ci.deploy
+name: CI deploy
+version: 1.0.0
+destructive_patterns:
+  - name: deploy-auto-confirm
+    pattern: deploy --yes
+    contexts: [github_actions]
+  - name: deploy-anywhere
+    pattern: deploy --force
+";
+        let pack = parse_pack_string(yaml).unwrap().into_pack();
+        let filtered = &pack.destructive_patterns[0];
+        assert_eq!(filtered.context_filter, &["github_actions"]);
+        assert!(filtered.applies_in_context("github_actions.steps.run"));
+        assert!(!filtered.applies_in_context("shell.script"));
+        assert!(pack.destructive_patterns[1].context_filter.is_empty());
+    }
+
     #[test]
     fn test_parse_minimal_pack() {
         let yaml = r#"
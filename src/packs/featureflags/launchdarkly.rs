@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         name: "LaunchDarkly",
         description: "Protects against destructive LaunchDarkly CLI and API operations.",
         keywords: &["ldcli", "launchdarkly"],
+        tags: &["featureflags"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/featureflags.md#featureflagslaunchdarkly",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
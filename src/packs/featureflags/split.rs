@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         name: "Split.io",
         description: "Protects against destructive Split.io CLI and API operations.",
         keywords: &["split", "api.split.io"],
+        tags: &["featureflags"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/featureflags.md#featureflagssplit",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
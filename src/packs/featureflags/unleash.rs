@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         name: "Unleash",
         description: "Protects against destructive Unleash CLI and API operations.",
         keywords: &["unleash"],
+        tags: &["featureflags"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/featureflags.md#featureflagsunleash",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -0,0 +1,329 @@
+//! Destructive verbs heuristic - high-risk verbs aimed at CLIs no pack covers.
+//!
+//! A brand-new tool (`mytool purge-all`) passes every built-in pack because
+//! none of them know its name. This pack closes that gap on a best-effort
+//! basis: for each command segment whose program is *not* a keyword of any
+//! built-in or external pack, it warns if an argument contains a high-risk
+//! verb such as `delete`, `purge`, or `--force`.
+//!
+//! Matching is token-based and implemented by [`find_unknown_cli_verb`]; the
+//! evaluator calls it directly instead of running the regex in the pack
+//! definition, which only documents the rule for `dcg packs` and the docs.
+//! The verb list can be replaced with `[heuristics] destructive_verbs`.
+
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::{LazyLock, OnceLock};
+
+use crate::destructive_pattern;
+use crate::normalize::{NormalizeToken, NormalizeTokenKind, tokenize_for_normalization};
+use crate::packs::{DestructivePattern, Pack};
+
+/// Pack ID of the destructive verbs heuristic.
+pub const PACK_ID: &str = "heuristic.destructive_verbs";
+
+/// Pattern name reported for heuristic matches (used in rule IDs and allowlists).
+pub const PATTERN_NAME: &str = "unknown-cli-verb";
+
+/// Verbs checked when the config does not provide its own list.
+///
+/// Verbs starting with `-` must match an argument exactly (`-rf`, `--force`);
+/// other verbs match a whole word inside an argument (`purge-all`, `--delete`).
+pub const DEFAULT_VERBS: &[&str] = &[
+    "delete", "destroy", "purge", "wipe", "drop", "truncate", "--force", "-rf",
+];
+
+/// Programs whose arguments are data rather than actions.
+///
+/// These are treated like pack-covered tools so `echo "drop it"` or
+/// `grep -rf patterns.txt` never warn.
+const IGNORED_PROGRAMS: &[&str] = &[
+    "echo", "printf", "cat", "less", "more", "head", "tail", "grep", "egrep", "fgrep", "rg", "ag",
+    "ack", "man", "help", "which", "type", "command", "cd", "pushd", "popd", "export", "alias",
+    "unalias", "history", "test", "true", "false", "mkdir", "ln",
+];
+
+static CONFIGURED_VERBS: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+/// Every program name some pack already covers, plus [`IGNORED_PROGRAMS`].
+static KNOWN_PROGRAMS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    crate::packs::PACK_ENTRIES
+        .iter()
+        .filter(|entry| entry.id != PACK_ID)
+        .flat_map(|entry| entry.keywords.iter().copied())
+        .chain(IGNORED_PROGRAMS.iter().copied())
+        .collect()
+});
+
+/// Install the verb list from config.
+///
+/// This should be called once at startup after config is loaded; subsequent
+/// calls are no-ops and return the list already in effect. `None` or an empty
+/// list keeps [`DEFAULT_VERBS`]. Verbs are lowercased and leaked so they can be
+/// merged into the `'static` quick-reject keywords.
+pub fn configure_verbs(verbs: Option<&[String]>) -> &'static [&'static str] {
+    CONFIGURED_VERBS.get_or_init(|| {
+        let configured: Vec<&'static str> = verbs
+            .unwrap_or_default()
+            .iter()
+            .map(|verb| verb.trim().to_ascii_lowercase())
+            .filter(|verb| !verb.is_empty())
+            .map(|verb| &*Box::leak(verb.into_boxed_str()))
+            .collect();
+        if configured.is_empty() {
+            DEFAULT_VERBS.to_vec()
+        } else {
+            configured
+        }
+    })
+}
+
+/// The verb list currently in effect.
+#[must_use]
+pub fn active_verbs() -> &'static [&'static str] {
+    CONFIGURED_VERBS.get().map_or(DEFAULT_VERBS, Vec::as_slice)
+}
+
+/// A high-risk verb passed to a CLI that no pack covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeuristicHit<'a> {
+    /// Program name (basename) of the segment that matched.
+    pub program: &'a str,
+    /// The configured verb that matched.
+    pub verb: &'static str,
+    /// Byte range of the matching argument in the evaluated command.
+    pub span: Range<usize>,
+}
+
+impl HeuristicHit<'_> {
+    /// Human-readable reason for the warning.
+    #[must_use]
+    pub fn reason(&self) -> String {
+        format!(
+            "Unknown CLI '{}' invoked with high-risk verb '{}' (heuristic match; no pack covers this tool)",
+            self.program, self.verb
+        )
+    }
+}
+
+/// Find the first segment that passes an active verb to an unknown CLI.
+#[must_use]
+pub fn find_unknown_cli_verb(command: &str) -> Option<HeuristicHit<'_>> {
+    find_with_verbs(command, active_verbs())
+}
+
+fn find_with_verbs<'a>(command: &'a str, verbs: &[&'static str]) -> Option<HeuristicHit<'a>> {
+    let tokens = tokenize_for_normalization(command);
+    tokens
+        .split(|token| token.kind == NormalizeTokenKind::Separator)
+        .find_map(|segment| check_segment(command, segment, verbs))
+}
+
+fn check_segment<'a>(
+    command: &'a str,
+    segment: &[NormalizeToken],
+    verbs: &[&'static str],
+) -> Option<HeuristicHit<'a>> {
+    let mut words = segment
+        .iter()
+        .filter_map(|token| Some((token.text(command)?, token.byte_range.clone())));
+
+    let (program, _) = words.by_ref().find(|(text, _)| !is_env_assignment(text))?;
+    let program = program.rsplit('/').next().unwrap_or(program);
+    if !program
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        || is_known_program(program)
+    {
+        return None;
+    }
+
+    words.find_map(|(arg, span)| {
+        matching_verb(arg, verbs).map(|verb| HeuristicHit {
+            program,
+            verb,
+            span,
+        })
+    })
+}
+
+fn is_known_program(program: &str) -> bool {
+    if KNOWN_PROGRAMS.contains(program)
+        || KNOWN_PROGRAMS.contains(program.to_ascii_lowercase().as_str())
+    {
+        return true;
+    }
+    crate::packs::get_external_packs().is_some_and(|store| store.keywords().contains(&program))
+}
+
+fn is_env_assignment(text: &str) -> bool {
+    text.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Match one argument against the verb list.
+///
+/// Quoted arguments, paths, and substitutions are skipped: they are usually
+/// data (`mytool upload /srv/drop/file`) rather than the action being taken.
+fn matching_verb(arg: &str, verbs: &[&'static str]) -> Option<&'static str> {
+    if arg.starts_with(['"', '\'']) || arg.contains(['/', '$', '`']) {
+        return None;
+    }
+    let key = arg.split_once('=').map_or(arg, |(key, _)| key);
+
+    verbs.iter().copied().find(|verb| {
+        if verb.starts_with('-') {
+            key == *verb
+        } else {
+            key.split(|c: char| !c.is_ascii_alphanumeric())
+                .any(|word| word.eq_ignore_ascii_case(verb))
+        }
+    })
+}
+
+/// Create the destructive verbs heuristic pack.
+#[must_use]
+pub fn create_pack() -> Pack {
+    Pack {
+        id: PACK_ID.to_string(),
+        name: "Destructive Verbs (Heuristic)",
+        description: "Warns when a CLI that no other pack covers is invoked with a high-risk verb \
+                      (delete, destroy, purge, wipe, drop, truncate, --force, -rf). \
+                      Opt-in and warn-only",
+        keywords: DEFAULT_VERBS,
+        tags: &["heuristic", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/heuristic.md#heuristicdestructive_verbs",
+        ),
+        safe_patterns: vec![],
+        destructive_patterns: create_destructive_patterns(),
+        keyword_matcher: None,
+        safe_regex_set: None,
+        safe_regex_set_is_complete: false,
+    }
+}
+
+fn create_destructive_patterns() -> Vec<DestructivePattern> {
+    vec![destructive_pattern!(
+        "unknown-cli-verb",
+        r"(?i)(?:^|[\s_:.-])(?:delete|destroy|purge|wipe|drop|truncate)(?:$|[\s_:.=-])|(?:^|\s)(?:--force|-rf)(?:$|[\s=])",
+        "Unknown CLI invoked with a high-risk verb (heuristic match; no pack covers this tool)",
+        Medium,
+        "This is a heuristic, not a curated rule: the program is not covered by any \
+         built-in or external pack, and one of its arguments looks like a destructive \
+         action. Double-check what the tool will do, or allowlist \
+         heuristic.destructive_verbs:unknown-cli-verb for tools you trust."
+    )]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::test_helpers::*;
+
+    fn hit(command: &str) -> Option<(String, &'static str)> {
+        find_with_verbs(command, DEFAULT_VERBS).map(|hit| (hit.program.to_string(), hit.verb))
+    }
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, PACK_ID);
+        validate_pack(&pack);
+        assert!(
+            pack.destructive_patterns
+                .iter()
+                .all(|p| p.severity == crate::packs::Severity::Medium),
+            "heuristic matches must stay warn-only"
+        );
+    }
+
+    #[test]
+    fn flags_verbs_on_unknown_cli() {
+        assert_eq!(hit("mytool purge-all"), Some(("mytool".into(), "purge")));
+        assert_eq!(
+            hit("mytool --delete-everything"),
+            Some(("mytool".into(), "delete"))
+        );
+        assert_eq!(hit("./bin/mytool db drop"), Some(("mytool".into(), "drop")));
+        assert_eq!(
+            hit("mytool sync --force"),
+            Some(("mytool".into(), "--force"))
+        );
+        assert_eq!(hit("mytool -rf cache"), Some(("mytool".into(), "-rf")));
+        assert_eq!(
+            hit("MYTOOL_ENV=prod mytool WIPE"),
+            Some(("mytool".into(), "wipe"))
+        );
+    }
+
+    #[test]
+    fn span_covers_matching_argument() {
+        let command = "mytool purge-all";
+        let found = find_with_verbs(command, DEFAULT_VERBS).expect("should match");
+        assert_eq!(&command[found.span], "purge-all");
+    }
+
+    #[test]
+    fn checks_every_segment() {
+        assert_eq!(
+            hit("ls -la && mytool destroy-cluster"),
+            Some(("mytool".into(), "destroy"))
+        );
+        assert_eq!(
+            hit("mytool status | othertool truncate"),
+            Some(("othertool".into(), "truncate"))
+        );
+    }
+
+    #[test]
+    fn ignores_cli_covered_by_a_pack() {
+        assert_eq!(hit("git push origin --delete feature"), None);
+        assert_eq!(hit("kubectl delete pod web-1"), None);
+        assert_eq!(hit("rm -rf build"), None);
+        assert_eq!(hit("docker system prune --force"), None);
+        assert_eq!(hit("npm install --force"), None);
+    }
+
+    #[test]
+    fn ignores_data_only_programs_and_data_arguments() {
+        assert_eq!(hit("echo delete"), None);
+        assert_eq!(hit("grep -rf patterns.txt src"), None);
+        assert_eq!(hit("mytool upload /srv/drop/file"), None);
+        assert_eq!(hit("mytool note \"please delete later\""), None);
+        assert_eq!(hit("mytool run $DELETE_FLAG"), None);
+        assert_eq!(hit("$TOOL purge"), None);
+    }
+
+    #[test]
+    fn verbs_match_whole_words_and_exact_flags() {
+        assert_eq!(hit("mytool deleted-items list"), None);
+        assert_eq!(hit("mytool dropbox sync"), None);
+        assert_eq!(hit("mytool push --force-with-lease"), None);
+        assert_eq!(hit("mytool -rfx"), None);
+        assert_eq!(hit("mytool --mode=delete"), None);
+        assert_eq!(
+            hit("mytool --delete=all"),
+            Some(("mytool".into(), "delete"))
+        );
+    }
+
+    #[test]
+    fn custom_verb_list_replaces_defaults() {
+        let verbs: &[&'static str] = &["nuke", "-y"];
+        let found = find_with_verbs("mytool nuke-staging -y", verbs).expect("should match");
+        assert_eq!(found.verb, "nuke");
+        assert!(find_with_verbs("mytool purge-all", verbs).is_none());
+    }
+
+    #[test]
+    fn no_match_without_arguments() {
+        assert_eq!(hit("mytool"), None);
+        assert_eq!(hit("purge"), None);
+        assert_eq!(hit(""), None);
+    }
+}
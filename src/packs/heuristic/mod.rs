@@ -0,0 +1,10 @@
+//! Heuristic packs - best-effort detection for tools without a dedicated pack.
+//!
+//! These packs trade precision for coverage, so they are disabled by default
+//! and report their findings with [`crate::evaluator::MatchSource::Heuristic`]
+//! so they can't be mistaken for a curated pack rule. They only warn (Medium
+//! severity), except `heuristic.exfil`, whose chains already contain an
+//! `rm -rf` that core blocks anyway.
+
+pub mod destructive_verbs;
+pub mod exfil;
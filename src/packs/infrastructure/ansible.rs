@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Ansible operations like dangerous shell \
                       commands and unchecked playbook runs",
         keywords: &["ansible", "playbook"],
+        tags: &["iac", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/infrastructure.md#infrastructureansible",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Pulumi operations like destroy \
                       and up with -y (auto-approve)",
         keywords: &["pulumi", "destroy", "state"],
+        tags: &["iac", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/infrastructure.md#infrastructurepulumi",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Terraform operations like destroy, \
                       taint, and apply with -auto-approve",
         keywords: &["terraform", "destroy", "taint", "state"],
+        tags: &["iac", "infrastructure"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/infrastructure.md#infrastructureterraform",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -27,37 +31,49 @@ pub fn create_pack() -> Pack {
 }
 
 fn create_safe_patterns() -> Vec<SafePattern> {
+    // Anchored to a single command so a read-only subcommand cannot mask a
+    // destroy chained before or after it.
     vec![
         // plan is safe (read-only)
-        safe_pattern!("terraform-plan", r"terraform\s+plan(?!\s+.*-destroy)"),
+        safe_pattern!(
+            "terraform-plan",
+            r"^terraform\s+plan(?![^;&|\n]*-destroy)[^;&|\n]*$"
+        ),
         // init is safe
-        safe_pattern!("terraform-init", r"terraform\s+init"),
+        safe_pattern!("terraform-init", r"^terraform\s+init[^;&|\n]*$"),
         // validate is safe
-        safe_pattern!("terraform-validate", r"terraform\s+validate"),
+        safe_pattern!("terraform-validate", r"^terraform\s+validate[^;&|\n]*$"),
         // fmt is safe
-        safe_pattern!("terraform-fmt", r"terraform\s+fmt"),
+        safe_pattern!("terraform-fmt", r"^terraform\s+fmt[^;&|\n]*$"),
         // show is safe
-        safe_pattern!("terraform-show", r"terraform\s+show"),
+        safe_pattern!("terraform-show", r"^terraform\s+show[^;&|\n]*$"),
         // output is safe
-        safe_pattern!("terraform-output", r"terraform\s+output"),
+        safe_pattern!("terraform-output", r"^terraform\s+output[^;&|\n]*$"),
         // state list/show are safe (read-only)
-        safe_pattern!("terraform-state-list", r"terraform\s+state\s+list"),
-        safe_pattern!("terraform-state-show", r"terraform\s+state\s+show"),
+        safe_pattern!(
+            "terraform-state-list",
+            r"^terraform\s+state\s+list[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "terraform-state-show",
+            r"^terraform\s+state\s+show[^;&|\n]*$"
+        ),
         // graph is safe
-        safe_pattern!("terraform-graph", r"terraform\s+graph"),
+        safe_pattern!("terraform-graph", r"^terraform\s+graph[^;&|\n]*$"),
         // version is safe
-        safe_pattern!("terraform-version", r"terraform\s+version"),
+        safe_pattern!("terraform-version", r"^terraform\s+version[^;&|\n]*$"),
         // providers is safe
-        safe_pattern!("terraform-providers", r"terraform\s+providers"),
+        safe_pattern!("terraform-providers", r"^terraform\s+providers[^;&|\n]*$"),
     ]
 }
 
 fn create_destructive_patterns() -> Vec<DestructivePattern> {
     vec![
-        // destroy
+        // destroy without -target. Listed before destroy-targeted so a full
+        // teardown chained after a scoped one is reported as Critical.
         destructive_pattern!(
             "destroy",
-            r"terraform\s+destroy",
+            r"terraform\s+destroy(?!\b[^;&|\n]*\s--?target(?:=|\s))",
             "terraform destroy removes ALL managed infrastructure. Use 'terraform plan -destroy' first.",
             Critical,
             "terraform destroy removes ALL managed infrastructure:\n\n\
@@ -67,6 +83,20 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
              - Use -target to destroy specific resources only\n\n\
              Preview first: terraform plan -destroy"
         ),
+        // destroy scoped with -target: still destructive, but limited to the
+        // addressed resources, so it ranks below a full teardown. The -target
+        // must be in the same command segment as destroy.
+        destructive_pattern!(
+            "destroy-targeted",
+            r"terraform\s+destroy\b[^;&|\n]*\s--?target(?:=|\s)",
+            "terraform destroy -target removes the targeted resources (scoped destroy). Preview with 'terraform plan -destroy -target=...' first.",
+            High,
+            "terraform destroy -target is scoped to specific resources:\n\n\
+             - Only the addressed resources (and their dependents) are destroyed\n\
+             - Common for tearing down ephemeral test resources\n\
+             - Cannot be undone without backups/recreation\n\n\
+             Preview first: terraform plan -destroy -target=<address>"
+        ),
         // plan -destroy is a preview but can be scary
         destructive_pattern!(
             "plan-destroy",
@@ -158,3 +188,53 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn full_destroy_is_critical() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "terraform destroy", "destroy");
+        assert_blocks_with_severity(&pack, "terraform destroy -auto-approve", Severity::Critical);
+        // A resource address mentioning "target" is not a -target flag
+        assert_blocks_with_pattern(&pack, "terraform destroy -var env=target", "destroy");
+    }
+
+    #[test]
+    fn targeted_destroy_is_high() {
+        let pack = create_pack();
+        for cmd in [
+            "terraform destroy -target=module.test.aws_s3_bucket.tmp",
+            "terraform destroy --target=aws_instance.web",
+            "terraform destroy -auto-approve -target aws_instance.web",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "destroy-targeted");
+            assert_blocks_with_severity(&pack, cmd, Severity::High);
+        }
+        assert_blocks(
+            &pack,
+            "terraform destroy -target=aws_instance.web",
+            "scoped destroy",
+        );
+    }
+
+    #[test]
+    fn target_in_a_chained_command_does_not_downgrade_destroy() {
+        let pack = create_pack();
+        for cmd in [
+            "terraform destroy; terraform plan -target=x",
+            "terraform destroy && terraform apply -target=x",
+            "terraform plan && terraform destroy",
+            "terraform destroy -target=a && terraform destroy",
+        ] {
+            assert_blocks_with_pattern(&pack, cmd, "destroy");
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+        }
+        assert_allows(&pack, "terraform plan -target=aws_instance.web");
+        assert_allows(&pack, "terraform init -upgrade");
+    }
+}
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Helm operations like uninstall \
                       and rollback without dry-run",
         keywords: &["helm", "uninstall", "delete", "rollback"],
+        tags: &["kubernetes", "orchestration"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/kubernetes.md#kuberneteshelm",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
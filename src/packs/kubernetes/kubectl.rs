@@ -122,6 +122,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive kubectl operations like delete namespace, \
                       drain, and mass deletion",
         keywords: &["kubectl", "delete", "drain", "cordon", "taint"],
+        tags: &["kubernetes", "orchestration"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/kubernetes.md#kuberneteskubectl",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -136,6 +140,12 @@ fn create_safe_patterns() -> Vec<SafePattern> {
         safe_pattern!("kubectl-get", r"kubectl\s+get"),
         safe_pattern!("kubectl-describe", r"kubectl\s+describe"),
         safe_pattern!("kubectl-logs", r"kubectl\s+logs"),
+        // rollout status/history only report progress and revisions; anchored
+        // to one command so a chained delete is still checked
+        safe_pattern!(
+            "kubectl-rollout-status",
+            r"^kubectl\s+rollout\s+(?:status|history)\b[^;&|\n]*$"
+        ),
         // dry-run is safe
         safe_pattern!(
             "kubectl-dry-run",
@@ -322,7 +332,7 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         // scale to 0
         destructive_pattern!(
             "scale-to-zero",
-            r"kubectl\s+scale\s+.*--replicas=0",
+            r"kubectl\s+scale\s+.*--replicas(?:=|\s+)0+\b",
             "kubectl scale --replicas=0 stops all pods for the workload.",
             High,
             "Scaling to zero replicas terminates ALL pods for the workload:\n\n\
@@ -393,3 +403,69 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "kubernetes.kubectl");
+        assert_eq!(pack.name, "kubectl");
+        assert!(!pack.description.is_empty());
+        assert!(pack.keywords.contains(&"kubectl"));
+
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn blocks_destructive_commands() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "kubectl delete namespace prod", "delete-namespace");
+        assert_blocks_with_pattern(&pack, "kubectl delete deployment api", "delete-workload");
+        assert_blocks_with_pattern(&pack, "kubectl delete pvc data-db-0", "delete-pvc");
+        assert_blocks_with_pattern(&pack, "kubectl delete pods --all", "delete-all");
+        assert_blocks_with_pattern(
+            &pack,
+            "kubectl drain node-1 --ignore-daemonsets",
+            "drain-node",
+        );
+        assert_blocks_with_pattern(&pack, "kubectl cordon node-1", "cordon-node");
+        assert_blocks_with_pattern(
+            &pack,
+            "kubectl scale deployment api --replicas=0",
+            "scale-to-zero",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "kubectl scale deployment api --replicas 0",
+            "scale-to-zero",
+        );
+    }
+
+    #[test]
+    fn allows_safe_commands() {
+        let pack = create_pack();
+        assert_allows(&pack, "kubectl get pods -n prod");
+        assert_allows(&pack, "kubectl describe deployment api");
+        assert_allows(&pack, "kubectl logs deploy/api --tail 100");
+        assert_allows(&pack, "kubectl rollout status deployment/api");
+        assert_allows(&pack, "kubectl rollout history deployment/api");
+        assert_allows(&pack, "kubectl scale deployment api --replicas=05");
+        assert_allows(&pack, "kubectl delete namespace prod --dry-run=client");
+    }
+
+    #[test]
+    fn rollout_status_does_not_mask_chained_delete() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "kubectl rollout status deploy/x && kubectl delete namespace prod",
+            "delete-namespace",
+        );
+    }
+}
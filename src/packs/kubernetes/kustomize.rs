@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Kustomize operations when combined \
                       with kubectl delete or applied without review",
         keywords: &["kustomize", "kubectl"],
+        tags: &["kubernetes", "orchestration"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/kubernetes.md#kuberneteskustomize",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
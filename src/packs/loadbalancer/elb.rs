@@ -27,6 +27,10 @@ pub fn create_pack() -> Pack {
             "delete-rule",
             "deregister-instances-from-load-balancer",
         ],
+        tags: &["aws", "loadbalancer", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/loadbalancer.md#loadbalancerelb",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
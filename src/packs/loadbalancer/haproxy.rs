@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive HAProxy load balancer operations like stopping \
                       the service or disabling backends via runtime API.",
         keywords: &["haproxy", "socat"],
+        tags: &["loadbalancer", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/loadbalancer.md#loadbalancerhaproxy",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
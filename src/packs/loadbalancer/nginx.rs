@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive nginx load balancer operations like stopping \
                       the service or deleting config files.",
         keywords: &["nginx", "/etc/nginx"],
+        tags: &["loadbalancer", "networking"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/loadbalancer.md#loadbalancernginx",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Traefik load balancer operations like stopping \
                       containers, deleting config, or API deletions.",
         keywords: &["traefik", "ingressroute"],
+        tags: &["loadbalancer", "networking", "kubernetes"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/loadbalancer.md#loadbalancertraefik",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
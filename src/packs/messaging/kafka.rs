@@ -30,6 +30,10 @@ pub fn create_pack() -> Pack {
             "kafka-broker-api-versions",
             "rpk",
         ],
+        tags: &["messaging", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/messaging.md#messagingkafka",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
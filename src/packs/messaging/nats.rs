@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive NATS/JetStream operations like deleting streams, consumers, \
                       key-value entries, objects, and accounts.",
         keywords: &["nats"],
+        tags: &["messaging", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/messaging.md#messagingnats",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive RabbitMQ operations like deleting queues/exchanges, \
                       purging queues, deleting vhosts, and resetting cluster state.",
         keywords: &["rabbitmqadmin", "rabbitmqctl"],
+        tags: &["messaging", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/messaging.md#messagingrabbitmq",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive AWS SQS and SNS operations like deleting queues, \
                       purging messages, deleting topics, and removing subscriptions.",
         keywords: &["aws", "sqs", "sns"],
+        tags: &["aws", "messaging", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/messaging.md#messagingsqssns",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
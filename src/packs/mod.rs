@@ -25,6 +25,7 @@ pub mod dns;
 pub mod email;
 pub mod external;
 pub mod featureflags;
+pub mod heuristic;
 pub mod infrastructure;
 pub mod kubernetes;
 pub mod loadbalancer;
@@ -50,7 +51,7 @@ mod test_template;
 pub use crate::normalize::normalize_command;
 use memchr::memmem;
 use regex_engine::LazyCompiledRegex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use std::collections::{HashMap, HashSet};
 use std::sync::{LazyLock, OnceLock};
@@ -103,6 +104,23 @@ impl Severity {
         matches!(self, Self::Critical | Self::High)
     }
 
+    /// Numeric rank for threshold comparisons (Low = 1 .. Critical = 4).
+    #[must_use]
+    pub const fn rank(&self) -> u8 {
+        match self {
+            Self::Critical => 4,
+            Self::High => 3,
+            Self::Medium => 2,
+            Self::Low => 1,
+        }
+    }
+
+    /// Returns true if this severity is at or above `threshold`.
+    #[must_use]
+    pub const fn meets(&self, threshold: Self) -> bool {
+        self.rank() >= threshold.rank()
+    }
+
     /// Get a human-readable label for this severity.
     #[must_use]
     pub const fn label(&self) -> &'static str {
@@ -115,6 +133,160 @@ impl Severity {
     }
 }
 
+/// Stable, machine-readable category for why a rule blocks a command.
+///
+/// `reason` is prose for humans and may be reworded between releases; these
+/// variant names are a stable contract for integrations. Rules without an
+/// assigned code report `Unspecified`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ReasonCode {
+    /// Discards uncommitted or stashed work (`git reset --hard`, `git clean -f`).
+    DestroysUncommittedChanges,
+    /// Overwrites history on a remote (`git push --force`).
+    RewritesRemoteHistory,
+    /// Deletes local git refs with unmerged work (`git branch -D`).
+    DeletesGitRefs,
+    /// Recursively deletes files (`rm -rf`).
+    WipesFilesystem,
+    /// Drops, truncates, or bulk-deletes database data (`DROP TABLE`).
+    DestroysDatabaseData,
+    /// Deletes a cloud, cluster, or hosted resource (`terraform destroy`).
+    DeletesRemoteResource,
+    /// No code assigned to this rule yet.
+    #[default]
+    Unspecified,
+}
+
+/// `(pack_id, pattern names, code)` for rules with an assigned [`ReasonCode`].
+const REASON_CODES: &[(&str, &[&str], ReasonCode)] = &[
+    (
+        "core.git",
+        &[
+            "checkout-discard",
+            "checkout-ref-discard",
+            "restore-worktree",
+            "restore-worktree-explicit",
+            "reset-hard",
+            "reset-merge",
+            "clean-force",
+            "clean-force-ignored",
+            "stash-drop",
+            "stash-clear",
+        ],
+        ReasonCode::DestroysUncommittedChanges,
+    ),
+    (
+        "core.git",
+        &["push-force-long", "push-force-short"],
+        ReasonCode::RewritesRemoteHistory,
+    ),
+    (
+        "core.git",
+        &[
+            "branch-force-delete",
+            "push-delete-protected",
+            "push-refspec-delete-protected",
+            "push-delete",
+            "push-refspec-delete",
+        ],
+        ReasonCode::DeletesGitRefs,
+    ),
+    (
+        "core.filesystem",
+        &[
+            "rm-rf-root-home",
+            "rm-rf-general",
+            "rm-r-f-separate",
+            "rm-recursive-force-long",
+            "find-loop-delete-system",
+            "find-loop-delete",
+        ],
+        ReasonCode::WipesFilesystem,
+    ),
+    (
+        "database.postgresql",
+        &[
+            "drop-database",
+            "drop-table",
+            "drop-schema",
+            "truncate-table",
+            "delete-without-where",
+            "dropdb-cli",
+        ],
+        ReasonCode::DestroysDatabaseData,
+    ),
+    (
+        "infrastructure.terraform",
+        &["destroy", "plan-destroy", "workspace-delete"],
+        ReasonCode::DeletesRemoteResource,
+    ),
+    (
+        "kubernetes.kubectl",
+        &[
+            "delete-namespace",
+            "delete-all",
+            "delete-all-namespaces",
+            "delete-workload",
+            "delete-pvc",
+            "delete-pv",
+        ],
+        ReasonCode::DeletesRemoteResource,
+    ),
+    (
+        "cloud.aws",
+        &[
+            "ec2-terminate",
+            "s3-rm-recursive",
+            "s3-rb",
+            "s3api-delete-bucket",
+            "rds-delete",
+            "cfn-delete-stack",
+            "dynamodb-delete",
+            "eks-delete",
+            "ecr-delete-repository",
+        ],
+        ReasonCode::DeletesRemoteResource,
+    ),
+    (
+        "platform.github",
+        &["gh-repo-delete", "gh-api-delete-repo", "gh-release-delete"],
+        ReasonCode::DeletesRemoteResource,
+    ),
+];
+
+impl ReasonCode {
+    /// Look up the code for a rule; unknown rules are `Unspecified`.
+    #[must_use]
+    pub fn for_rule(pack_id: &str, pattern_name: &str) -> Self {
+        REASON_CODES
+            .iter()
+            .find(|(pack, names, _)| *pack == pack_id && names.contains(&pattern_name))
+            .map_or(Self::Unspecified, |(_, _, code)| *code)
+    }
+
+    /// Look up the code for a `pack_id:pattern_name` rule ID.
+    #[must_use]
+    pub fn for_rule_id(rule_id: &str) -> Self {
+        rule_id
+            .split_once(':')
+            .map_or(Self::Unspecified, |(pack, name)| Self::for_rule(pack, name))
+    }
+
+    /// The stable variant name (matches the serialized form).
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::DestroysUncommittedChanges => "DestroysUncommittedChanges",
+            Self::RewritesRemoteHistory => "RewritesRemoteHistory",
+            Self::DeletesGitRefs => "DeletesGitRefs",
+            Self::WipesFilesystem => "WipesFilesystem",
+            Self::DestroysDatabaseData => "DestroysDatabaseData",
+            Self::DeletesRemoteResource => "DeletesRemoteResource",
+            Self::Unspecified => "Unspecified",
+        }
+    }
+}
+
 /// Decision mode for how to handle a matched pattern.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum DecisionMode {
@@ -281,6 +453,25 @@ pub struct DestructivePattern {
     /// Safer command alternatives to suggest when this pattern matches.
     /// Each suggestion includes the command, why it's safer, and which platforms it applies to.
     pub suggestions: &'static [PatternSuggestion],
+    /// Scan extractor contexts this pattern is limited to (e.g. `github_actions`).
+    ///
+    /// Entries match an `extractor_id` exactly or as a dotted prefix, so
+    /// `gitlab_ci` covers `gitlab_ci.script`. Empty means every context.
+    /// Only `dcg scan` consults this; hook mode always applies the pattern.
+    pub context_filter: &'static [&'static str],
+}
+
+impl DestructivePattern {
+    /// Returns `true` if this pattern applies to commands from `extractor_id`.
+    #[must_use]
+    pub fn applies_in_context(&self, extractor_id: &str) -> bool {
+        self.context_filter.is_empty()
+            || self.context_filter.iter().any(|context| {
+                extractor_id
+                    .strip_prefix(context)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })
+    }
 }
 
 impl std::fmt::Debug for DestructivePattern {
@@ -292,6 +483,7 @@ impl std::fmt::Debug for DestructivePattern {
             .field("severity", &self.severity)
             .field("explanation", &self.explanation)
             .field("suggestions", &self.suggestions)
+            .field("context_filter", &self.context_filter)
             .finish()
     }
 }
@@ -301,7 +493,7 @@ impl std::fmt::Debug for DestructivePattern {
 /// The pattern is lazily compiled on first use, not at construction time.
 #[macro_export]
 macro_rules! safe_pattern {
-    ($name:literal, $re:literal) => {
+    ($name:literal, $re:expr) => {
         $crate::packs::SafePattern {
             regex: $crate::packs::regex_engine::LazyCompiledRegex::new($re),
             name: $name,
@@ -320,10 +512,13 @@ macro_rules! safe_pattern {
 /// - `destructive_pattern!("name", "regex", "reason", Critical)` - named with explicit severity
 /// - `destructive_pattern!("name", "regex", "reason", Critical, "explanation")` - with explanation
 /// - `destructive_pattern!("name", "regex", "reason", Critical, "explanation", &[...])` - with suggestions
+///
+/// To limit a pattern to certain scan contexts, use struct update syntax:
+/// `DestructivePattern { context_filter: &["github_actions"], ..destructive_pattern!(...) }`.
 #[macro_export]
 macro_rules! destructive_pattern {
     // Unnamed pattern, default severity (High)
-    ($re:literal, $reason:literal) => {
+    ($re:expr, $reason:literal) => {
         $crate::packs::DestructivePattern {
             regex: $crate::packs::regex_engine::LazyCompiledRegex::new($re),
             reason: $reason,
@@ -331,10 +526,11 @@ macro_rules! destructive_pattern {
             severity: $crate::packs::Severity::High,
             explanation: None,
             suggestions: &[],
+            context_filter: &[],
         }
     };
     // Named pattern, default severity (High)
-    ($name:literal, $re:literal, $reason:literal) => {
+    ($name:literal, $re:expr, $reason:literal) => {
         $crate::packs::DestructivePattern {
             regex: $crate::packs::regex_engine::LazyCompiledRegex::new($re),
             reason: $reason,
@@ -342,10 +538,11 @@ macro_rules! destructive_pattern {
             severity: $crate::packs::Severity::High,
             explanation: None,
             suggestions: &[],
+            context_filter: &[],
         }
     };
     // Named pattern with explicit severity
-    ($name:literal, $re:literal, $reason:literal, $severity:ident) => {
+    ($name:literal, $re:expr, $reason:literal, $severity:ident) => {
         $crate::packs::DestructivePattern {
             regex: $crate::packs::regex_engine::LazyCompiledRegex::new($re),
             reason: $reason,
@@ -353,10 +550,11 @@ macro_rules! destructive_pattern {
             severity: $crate::packs::Severity::$severity,
             explanation: None,
             suggestions: &[],
+            context_filter: &[],
         }
     };
     // Named pattern with explicit severity and explanation
-    ($name:literal, $re:literal, $reason:literal, $severity:ident, $explanation:literal) => {
+    ($name:literal, $re:expr, $reason:literal, $severity:ident, $explanation:literal) => {
         $crate::packs::DestructivePattern {
             regex: $crate::packs::regex_engine::LazyCompiledRegex::new($re),
             reason: $reason,
@@ -364,10 +562,11 @@ macro_rules! destructive_pattern {
             severity: $crate::packs::Severity::$severity,
             explanation: Some($explanation),
             suggestions: &[],
+            context_filter: &[],
         }
     };
     // Named pattern with explicit severity, explanation, and suggestions
-    ($name:literal, $re:literal, $reason:literal, $severity:ident, $explanation:literal, $suggestions:expr) => {
+    ($name:literal, $re:expr, $reason:literal, $severity:ident, $explanation:literal, $suggestions:expr) => {
         $crate::packs::DestructivePattern {
             regex: $crate::packs::regex_engine::LazyCompiledRegex::new($re),
             reason: $reason,
@@ -375,6 +574,7 @@ macro_rules! destructive_pattern {
             severity: $crate::packs::Severity::$severity,
             explanation: Some($explanation),
             suggestions: $suggestions,
+            context_filter: &[],
         }
     };
 }
@@ -393,8 +593,16 @@ pub struct Pack {
 
     /// Keywords for quick-reject filtering (e.g., `["psql", "dropdb", "DROP"]`).
     /// Commands without any of these keywords skip pattern matching for this pack.
+    /// All-uppercase keywords (SQL/Redis verbs) match in any ASCII case.
     pub keywords: &'static [&'static str],
 
+    /// Free-form tags for grouping and filtering (e.g., `["aws", "data-loss"]`).
+    /// Empty when a pack has not been tagged.
+    pub tags: &'static [&'static str],
+
+    /// Link to documentation explaining what this pack protects against.
+    pub docs_url: Option<&'static str>,
+
     /// Safe patterns (whitelist) - checked first.
     pub safe_patterns: Vec<SafePattern>,
 
@@ -437,6 +645,8 @@ impl Pack {
             name,
             description,
             keywords,
+            tags: &[],
+            docs_url: None,
             safe_patterns,
             destructive_patterns,
             keyword_matcher: None,
@@ -445,6 +655,12 @@ impl Pack {
         }
     }
 
+    /// Returns true if this pack carries the given tag (ASCII case-insensitive).
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
     /// Check if a command contains any of this pack's keywords.
     /// Returns false if the command doesn't contain any keywords (quick reject).
     ///
@@ -463,18 +679,14 @@ impl Pack {
                 return true;
             }
 
-            if !self
-                .keywords
-                .iter()
-                .any(|kw| keyword_contains_whitespace(kw))
-            {
+            if !self.keywords.iter().any(|kw| keyword_needs_slow_path(kw)) {
                 return false;
             }
 
             return self
                 .keywords
                 .iter()
-                .any(|kw| keyword_contains_whitespace(kw) && keyword_matches_substring(cmd, kw));
+                .any(|kw| keyword_needs_slow_path(kw) && keyword_matches_substring(cmd, kw));
         }
 
         // Fallback: sequential memchr-based search (O(k * n) where k = keyword count).
@@ -706,7 +918,7 @@ impl PackEntry {
 
         self.keywords
             .iter()
-            .filter(|kw| keyword_contains_whitespace(kw))
+            .filter(|kw| keyword_needs_slow_path(kw))
             .any(|kw| keyword_matches_substring(cmd, kw))
     }
 
@@ -745,8 +957,9 @@ pub struct EnabledKeywordIndex {
     always_check_mask: u128,
     keyword_matcher: Option<aho_corasick::AhoCorasick>,
     keyword_pack_masks: Vec<u128>,
-    whitespace_keywords: Vec<&'static str>,
-    whitespace_pack_masks: Vec<u128>,
+    /// Multi-word and case-insensitive keywords, which the automaton can miss.
+    slow_path_keywords: Vec<&'static str>,
+    slow_path_pack_masks: Vec<u128>,
 }
 
 impl EnabledKeywordIndex {
@@ -774,11 +987,11 @@ impl EnabledKeywordIndex {
             }
         }
 
-        if !self.whitespace_keywords.is_empty() && mask != self.full_mask {
+        if !self.slow_path_keywords.is_empty() && mask != self.full_mask {
             for (keyword, pack_mask) in self
-                .whitespace_keywords
+                .slow_path_keywords
                 .iter()
-                .zip(self.whitespace_pack_masks.iter())
+                .zip(self.slow_path_pack_masks.iter())
             {
                 if keyword_matches_substring(cmd, keyword) {
                     mask |= *pack_mask;
@@ -795,11 +1008,11 @@ impl EnabledKeywordIndex {
 
 /// Static pack entries - metadata is available without instantiating packs.
 /// Packs are built lazily on first access.
-static PACK_ENTRIES: [PackEntry; 82] = [
+static PACK_ENTRIES: [PackEntry; 88] = [
     PackEntry::new("core.git", &["git"], core::git::create_pack),
     PackEntry::new(
         "core.filesystem",
-        &["rm", "/rm"],
+        &["rm", "/rm", "shred", "unlink"],
         core::filesystem::create_pack,
     ),
     PackEntry::new("storage.s3", &["s3", "s3api"], storage::s3::create_pack),
@@ -1110,6 +1323,19 @@ static PACK_ENTRIES: [PackEntry; 82] = [
         &["sqlite3", "DROP", "DELETE", "TRUNCATE"],
         database::sqlite::create_pack,
     ),
+    PackEntry::new(
+        "database.vector",
+        &[
+            "qdrant",
+            ":6333",
+            "weaviate",
+            "/v1/schema",
+            "/v1/batch/objects",
+            "/v1/objects",
+            "pinecone",
+        ],
+        database::vector::create_pack,
+    ),
     PackEntry::new(
         "containers.docker",
         &["docker"],
@@ -1137,6 +1363,16 @@ static PACK_ENTRIES: [PackEntry; 82] = [
         kubernetes::kustomize::create_pack,
     ),
     PackEntry::new("cloud.aws", &["aws"], cloud::aws::create_pack),
+    PackEntry::new(
+        "cloud.aws_compute",
+        &["aws"],
+        cloud::aws_compute::create_pack,
+    ),
+    PackEntry::new(
+        "cloud.aws_security",
+        &["aws"],
+        cloud::aws_security::create_pack,
+    ),
     PackEntry::new(
         "cloud.gcp",
         &["gcloud", "gsutil", "bq"],
@@ -1188,6 +1424,10 @@ static PACK_ENTRIES: [PackEntry; 82] = [
         "system.disk",
         &[
             "dd",
+            "tee",
+            "/etc/",
+            "/boot/",
+            "/dev/",
             "mkfs",
             "fdisk",
             "parted",
@@ -1209,7 +1449,7 @@ static PACK_ENTRIES: [PackEntry; 82] = [
     ),
     PackEntry::new(
         "system.permissions",
-        &["chmod", "chown", "setfacl"],
+        &["chmod", "chown", "chgrp", "setfacl"],
         system::permissions::create_pack,
     ),
     PackEntry::new(
@@ -1217,6 +1457,11 @@ static PACK_ENTRIES: [PackEntry; 82] = [
         &["systemctl", "service"],
         system::services::create_pack,
     ),
+    PackEntry::new(
+        "system.package_manager",
+        &["apt-get", "apt", "dpkg", "yum", "dnf", "pacman", "brew"],
+        system::package_manager::create_pack,
+    ),
     PackEntry::new("strict_git", &["git"], strict_git::create_pack),
     PackEntry::new(
         "package_managers",
@@ -1225,6 +1470,12 @@ static PACK_ENTRIES: [PackEntry; 82] = [
         ],
         package_managers::create_pack,
     ),
+    PackEntry::new(
+        "heuristic.destructive_verbs",
+        heuristic::destructive_verbs::DEFAULT_VERBS,
+        heuristic::destructive_verbs::create_pack,
+    ),
+    PackEntry::new("heuristic.exfil", &["rm"], heuristic::exfil::create_pack),
 ];
 
 impl PackRegistry {
@@ -1243,6 +1494,11 @@ impl PackRegistry {
             }
         }
 
+        // Configured heuristic verbs may differ from the pack's default keywords.
+        if expanded.contains(heuristic::destructive_verbs::PACK_ID) {
+            keywords.extend(heuristic::destructive_verbs::active_verbs().iter().copied());
+        }
+
         // Deduplicate while preserving order (first occurrence wins)
         let mut seen = HashSet::new();
         keywords.retain(|kw| seen.insert(*kw));
@@ -1340,9 +1596,10 @@ impl PackRegistry {
     /// This is used by `check_command` to ensure consistent attribution when
     /// multiple packs could match the same command. The ordering is:
     ///
-    /// 0. **Tier 0 (safe)**: `safe.*` packs - safe patterns checked first to whitelist
+    /// 0. **Tier 0 (safe)**: `safe.*` packs - safe patterns checked first to whitelist;
+    ///    also `heuristic.exfil`, so its combined finding wins over core's `rm -rf`
     /// 1. **Tier 1 (core/storage/remote)**: `core.*`, `storage.*`, `remote.*` packs - most fundamental protections
-    /// 2. **Tier 2 (system)**: `system.*` - disk, permissions, services
+    /// 2. **Tier 2 (system)**: `system.*` - disk, permissions, services, package managers
     /// 3. **Tier 3 (infrastructure)**: `infrastructure.*` - terraform, ansible, pulumi
     /// 4. **Tier 4 (apigateway/cloud/dns/platform/cdn/loadbalancer)**: `apigateway.*`, `cloud.*`, `dns.*`, `platform.*`, `cdn.*`, `loadbalancer.*`
     /// 5. **Tier 5 (kubernetes)**: `kubernetes.*` - kubectl, helm, kustomize
@@ -1351,6 +1608,7 @@ impl PackRegistry {
     /// 8. **Tier 8 (`package_managers`)**: package manager protections
     /// 9. **Tier 9 (`strict_git`)**: extra git paranoia
     /// 10. **Tier 10 (services)**: `cicd.*`, `email.*`, `featureflags.*`, `secrets.*`, `monitoring.*`, `payment.*`
+    /// 12. **Tier 12 (heuristic)**: other `heuristic.*` packs, after every specific pack
     ///
     /// Within each tier, packs are sorted lexicographically by ID.
     #[must_use]
@@ -1373,11 +1631,46 @@ impl PackRegistry {
         pack_ids
     }
 
+    /// Like [`Self::expand_enabled_ordered`], but packs named in `pack_order`
+    /// (the `packs.pack_order` config) are evaluated first, in that order.
+    ///
+    /// Entries may be pack IDs or categories; a category entry places all of
+    /// its enabled packs at that position, and an exact pack ID takes
+    /// precedence over its category. Unlisted packs keep the default order.
+    #[must_use]
+    pub fn expand_enabled_ordered_with(
+        &self,
+        enabled: &HashSet<String>,
+        pack_order: &[String],
+    ) -> Vec<String> {
+        let mut pack_ids = self.expand_enabled_ordered(enabled);
+        if pack_order.is_empty() {
+            return pack_ids;
+        }
+
+        let rank = |pack_id: &str| {
+            let category = pack_id.split('.').next().unwrap_or(pack_id);
+            pack_order
+                .iter()
+                .position(|entry| entry == pack_id)
+                .or_else(|| pack_order.iter().position(|entry| entry == category))
+                .unwrap_or(pack_order.len())
+        };
+        // Stable sort: packs sharing a rank keep their tier order.
+        pack_ids.sort_by_cached_key(|pack_id| rank(pack_id));
+        pack_ids
+    }
+
     /// Get the priority tier for a pack ID (lower = higher priority).
     ///
     /// Safe packs (tier 0) are evaluated first so their safe patterns can
     /// whitelist commands before other packs' destructive patterns match.
     fn pack_tier(pack_id: &str) -> u8 {
+        // The exfil-then-delete combination must be attributed before
+        // core.filesystem reports the `rm -rf` half on its own.
+        if pack_id == heuristic::exfil::PACK_ID {
+            return 0;
+        }
         let category = pack_id.split('.').next().unwrap_or(pack_id);
         match category {
             "safe" => 0,
@@ -1391,7 +1684,9 @@ impl PackRegistry {
             "package_managers" => 8,
             "strict_git" => 9,
             "cicd" | "email" | "featureflags" | "secrets" | "monitoring" | "payment" => 10, // CI/CD + email + feature flags + secrets + monitoring + payment tooling
-            _ => 11, // Unknown categories go last
+            // Heuristics run after every specific pack, including unknown categories
+            "heuristic" => 12,
+            _ => 11, // Unknown categories go last among specific packs
         }
     }
 
@@ -1478,6 +1773,8 @@ impl PackRegistry {
                     id: pack.id.clone(),
                     name: pack.name,
                     description: pack.description,
+                    tags: pack.tags,
+                    docs_url: pack.docs_url,
                     enabled: expanded.contains(&pack.id),
                     safe_pattern_count: pack.safe_patterns.len(),
                     destructive_pattern_count: pack.destructive_patterns.len(),
@@ -1524,9 +1821,9 @@ impl PackRegistry {
         let mut keyword_to_index: HashMap<&'static str, usize> = HashMap::new();
         let mut patterns: Vec<&'static str> = Vec::new();
         let mut keyword_pack_masks: Vec<u128> = Vec::new();
-        let mut whitespace_keywords: Vec<&'static str> = Vec::new();
-        let mut whitespace_pack_masks: Vec<u128> = Vec::new();
-        let mut whitespace_keyword_to_index: HashMap<&'static str, usize> = HashMap::new();
+        let mut slow_path_keywords: Vec<&'static str> = Vec::new();
+        let mut slow_path_pack_masks: Vec<u128> = Vec::new();
+        let mut slow_path_keyword_to_index: HashMap<&'static str, usize> = HashMap::new();
 
         for (pack_idx, pack_id) in ordered_packs.iter().enumerate() {
             let Some(entry) = self.get_entry(pack_id.as_str()) else {
@@ -1545,14 +1842,14 @@ impl PackRegistry {
                     continue;
                 }
 
-                if keyword_contains_whitespace(kw) {
-                    if let Some(&idx) = whitespace_keyword_to_index.get(kw) {
-                        whitespace_pack_masks[idx] |= bit;
+                if keyword_needs_slow_path(kw) {
+                    if let Some(&idx) = slow_path_keyword_to_index.get(kw) {
+                        slow_path_pack_masks[idx] |= bit;
                     } else {
-                        let idx = whitespace_keywords.len();
-                        whitespace_keywords.push(kw);
-                        whitespace_pack_masks.push(bit);
-                        whitespace_keyword_to_index.insert(kw, idx);
+                        let idx = slow_path_keywords.len();
+                        slow_path_keywords.push(kw);
+                        slow_path_pack_masks.push(bit);
+                        slow_path_keyword_to_index.insert(kw, idx);
                     }
                 }
 
@@ -1583,8 +1880,8 @@ impl PackRegistry {
             always_check_mask,
             keyword_matcher,
             keyword_pack_masks,
-            whitespace_keywords,
-            whitespace_pack_masks,
+            slow_path_keywords,
+            slow_path_pack_masks,
         })
     }
 }
@@ -1604,6 +1901,10 @@ pub struct PackInfo {
     pub name: &'static str,
     /// Description.
     pub description: &'static str,
+    /// Tags for grouping and filtering.
+    pub tags: &'static [&'static str],
+    /// Documentation link (if available).
+    pub docs_url: Option<&'static str>,
     /// Whether the pack is enabled.
     pub enabled: bool,
     /// Number of safe patterns.
@@ -1623,6 +1924,7 @@ pub static REGISTRY: LazyLock<PackRegistry> = LazyLock::new(PackRegistry::new);
 ///
 /// External packs are loaded once at startup based on config.packs.custom_paths
 /// and stored here for evaluation alongside built-in packs.
+#[derive(Debug)]
 pub struct ExternalPackStore {
     /// Loaded packs keyed by pack ID.
     packs: HashMap<String, Pack>,
@@ -1642,6 +1944,26 @@ impl ExternalPackStore {
         }
     }
 
+    /// Create a store from already-converted packs, keyed by their IDs.
+    #[must_use]
+    pub fn from_packs(packs: impl IntoIterator<Item = Pack>) -> Self {
+        let mut store = Self::new();
+        for pack in packs {
+            store.insert(pack);
+        }
+        store
+    }
+
+    /// Add a pack, merging its keywords into the store's keyword list.
+    fn insert(&mut self, pack: Pack) {
+        for kw in pack.keywords {
+            if !self.keywords.contains(kw) {
+                self.keywords.push(kw);
+            }
+        }
+        self.packs.insert(pack.id.clone(), pack);
+    }
+
     /// Get a pack by ID.
     #[must_use]
     pub fn get(&self, id: &str) -> Option<&Pack> {
@@ -1722,6 +2044,30 @@ impl ExternalPackStore {
         &self,
         cmd: &str,
         enabled_ids: &HashSet<String>,
+    ) -> Option<ExternalCheckResult> {
+        self.check_command_in_context(cmd, enabled_ids, None)
+    }
+
+    /// Like [`Self::check_command_with_details`], for a command `dcg scan`
+    /// extracted with `extractor_id`.
+    ///
+    /// Destructive patterns whose `context_filter` excludes `extractor_id` are
+    /// skipped, so a later pattern can still match.
+    #[must_use]
+    pub fn check_command_in_scan_context(
+        &self,
+        cmd: &str,
+        enabled_ids: &HashSet<String>,
+        extractor_id: &str,
+    ) -> Option<ExternalCheckResult> {
+        self.check_command_in_context(cmd, enabled_ids, Some(extractor_id))
+    }
+
+    fn check_command_in_context(
+        &self,
+        cmd: &str,
+        enabled_ids: &HashSet<String>,
+        scan_context: Option<&str>,
     ) -> Option<ExternalCheckResult> {
         // Check safe patterns first (across all enabled external packs)
         for (id, pack) in &self.packs {
@@ -1746,7 +2092,11 @@ impl ExternalPackStore {
             if !enabled_ids.contains(id) {
                 continue;
             }
-            if let Some(matched) = pack.matches_destructive(cmd) {
+            let matched = pack.destructive_patterns.iter().find(|pattern| {
+                scan_context.is_none_or(|context| pattern.applies_in_context(context))
+                    && pattern.regex.is_match(cmd)
+            });
+            if let Some(matched) = matched {
                 return Some(ExternalCheckResult {
                     blocked: true,
                     reason: Some(matched.reason.to_string()),
@@ -1819,17 +2169,7 @@ pub fn load_external_packs(paths: &[String]) -> &'static ExternalPackStore {
 
         // Convert and store loaded packs
         for loaded in result.packs {
-            let id = loaded.id.clone();
-            let pack = loaded.pack.into_pack();
-
-            // Collect keywords
-            for kw in pack.keywords {
-                if !store.keywords.contains(kw) {
-                    store.keywords.push(kw);
-                }
-            }
-
-            store.packs.insert(id, pack);
+            store.insert(loaded.pack.into_pack());
         }
 
         store
@@ -1858,14 +2198,52 @@ fn keyword_contains_whitespace(keyword: &str) -> bool {
     keyword.bytes().any(|byte| byte.is_ascii_whitespace())
 }
 
+/// Keywords written entirely in upper case (SQL and Redis verbs such as
+/// `DROP` or `FLUSHALL`) match in any ASCII case, because the servers accept
+/// them that way: `Drop Table` must not slip past a `DROP` keyword.
+///
+/// Only ASCII letters fold, so matching never depends on the locale.
+#[inline]
+fn keyword_is_case_insensitive(keyword: &str) -> bool {
+    keyword.bytes().any(|byte| byte.is_ascii_uppercase())
+        && !keyword.bytes().any(|byte| byte.is_ascii_lowercase())
+}
+
+/// Keywords the exact-match fast paths (Aho-Corasick, memchr) can miss.
+#[inline]
+fn keyword_needs_slow_path(keyword: &str) -> bool {
+    keyword_contains_whitespace(keyword) || keyword_is_case_insensitive(keyword)
+}
+
+/// Find `needle` in `haystack`, ignoring ASCII case when `ignore_case`.
+#[inline]
+fn find_keyword_bytes(haystack: &[u8], needle: &[u8], ignore_case: bool) -> Option<usize> {
+    if !ignore_case {
+        return memmem::find(haystack, needle);
+    }
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+}
+
+/// Substring keyword match (no word boundaries), honoring multi-word and
+/// case-insensitive keywords.
 #[inline]
-fn keyword_matches_substring(haystack: &str, keyword: &str) -> bool {
+pub(crate) fn keyword_matches_substring(haystack: &str, keyword: &str) -> bool {
     if keyword.is_empty() {
         return false;
     }
 
     if !keyword_contains_whitespace(keyword) {
-        return memmem::find(haystack.as_bytes(), keyword.as_bytes()).is_some();
+        return find_keyword_bytes(
+            haystack.as_bytes(),
+            keyword.as_bytes(),
+            keyword_is_case_insensitive(keyword),
+        )
+        .is_some();
     }
 
     keyword_matches_with_whitespace(haystack, keyword, false)
@@ -1908,12 +2286,13 @@ fn keyword_matches_with_whitespace(
         return false;
     }
 
+    let ignore_case = keyword_is_case_insensitive(keyword);
     let first_is_word = first.first().is_some_and(|b| is_word_byte(*b));
     let last = parts[parts.len() - 1].as_bytes();
     let last_is_word = last.last().is_some_and(|b| is_word_byte(*b));
     let mut offset = 0;
 
-    while let Some(pos) = memmem::find(&hay[offset..], first) {
+    while let Some(pos) = find_keyword_bytes(&hay[offset..], first, ignore_case) {
         let start = offset + pos;
         if enforce_boundaries && first_is_word {
             let start_ok = start == 0 || !is_word_byte(hay[start.saturating_sub(1)]);
@@ -1937,8 +2316,14 @@ fn keyword_matches_with_whitespace(
             idx = ws;
 
             let part_bytes = part.as_bytes();
-            if idx + part_bytes.len() > hay.len() || &hay[idx..idx + part_bytes.len()] != part_bytes
-            {
+            let part_matches = hay.get(idx..idx + part_bytes.len()).is_some_and(|window| {
+                if ignore_case {
+                    window.eq_ignore_ascii_case(part_bytes)
+                } else {
+                    window == part_bytes
+                }
+            });
+            if !part_matches {
                 matched = false;
                 break;
             }
@@ -1978,11 +2363,12 @@ fn keyword_matches_span(span_text: &str, keyword: &str) -> bool {
         return false;
     }
 
+    let ignore_case = keyword_is_case_insensitive(keyword);
     let first_is_word = needle.first().is_some_and(|b| is_word_byte(*b));
     let last_is_word = needle.last().is_some_and(|b| is_word_byte(*b));
     let mut offset = 0;
 
-    while let Some(pos) = memmem::find(&haystack[offset..], needle) {
+    while let Some(pos) = find_keyword_bytes(&haystack[offset..], needle, ignore_case) {
         let start = offset + pos;
         let end = start + needle.len();
         let start_ok =
@@ -2064,7 +2450,7 @@ pub fn pack_aware_quick_reject_with_normalized<'a>(
     if !any_substring {
         any_substring = enabled_keywords
             .iter()
-            .filter(|keyword| keyword_contains_whitespace(keyword))
+            .filter(|keyword| keyword_needs_slow_path(keyword))
             .any(|keyword| keyword_matches_substring(cmd, keyword));
     }
     if !any_substring {
@@ -2113,6 +2499,27 @@ pub fn pack_aware_quick_reject_with_normalized<'a>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn context_filtered_pattern_applies_only_in_listed_contexts() {
+        let ci_only = DestructivePattern {
+            context_filter: &["github_actions", "gitlab_ci"],
+            ..crate::destructive_pattern!(
+                "ci-auto-confirm",
+                r"deploy\s+--yes",
+                "auto-confirmed deploy in CI"
+            )
+        };
+        assert!(ci_only.regex.is_match("deploy --yes"));
+        assert!(ci_only.applies_in_context("github_actions.steps.run"));
+        assert!(ci_only.applies_in_context("gitlab_ci.script"));
+        assert!(!ci_only.applies_in_context("shell.script"));
+        assert!(!ci_only.applies_in_context("github_actions_extra.run"));
+
+        let unfiltered = crate::destructive_pattern!("any", r"deploy", "deploys");
+        assert!(unfiltered.context_filter.is_empty());
+        assert!(unfiltered.applies_in_context("shell.script"));
+    }
+
     #[test]
     fn pack_aware_quick_reject_empty_keywords_is_conservative() {
         assert!(
@@ -2279,6 +2686,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn uppercase_keywords_match_in_any_ascii_case() {
+        let keywords: Vec<&str> = vec!["FLUSHALL", "DROP TABLE"];
+
+        for cmd in [
+            "$R FlushAll",
+            "$R flushall",
+            "$R FLUSHALL",
+            "$DB DrOp   TaBlE users",
+        ] {
+            assert!(
+                !pack_aware_quick_reject(cmd, &keywords),
+                "{cmd} should not be quick-rejected"
+            );
+        }
+        // Lowercase keywords stay exact.
+        assert!(pack_aware_quick_reject("GIT status", &["git"]));
+        // Non-ASCII never folds onto ASCII keywords, and never panics.
+        assert!(pack_aware_quick_reject("$R ｆｌｕｓｈａｌｌ", &keywords));
+        assert!(pack_aware_quick_reject("echo é", &["É"]));
+        assert!(!pack_aware_quick_reject("$R flushAll café", &keywords));
+    }
+
+    #[test]
+    fn keyword_gates_accept_mixed_case_sql_verbs() {
+        let mut enabled = HashSet::new();
+        enabled.insert("database.redis".to_string());
+        let ordered = REGISTRY.expand_enabled_ordered(&enabled);
+        let index = REGISTRY
+            .build_enabled_keyword_index(&ordered)
+            .expect("keyword index should build for small pack set");
+        assert_eq!(index.candidate_pack_mask("$R FlushDb") & 1, 1);
+        assert_eq!(index.candidate_pack_mask("$R status") & 1, 0);
+
+        let entry = REGISTRY.get_entry("database.sqlite").expect("sqlite entry");
+        assert!(entry.might_match("$DB \"Drop Table users\""));
+        assert!(entry.get_pack().might_match("$DB \"dElEtE from users\""));
+        assert!(!entry.get_pack().might_match("$DB status"));
+    }
+
     /// Test that `pack_tier` returns correct tiers for all known categories.
     #[test]
     fn pack_tier_ordering() {
@@ -2291,12 +2738,15 @@ mod tests {
         // System should be tier 2
         assert_eq!(PackRegistry::pack_tier("system.disk"), 2);
         assert_eq!(PackRegistry::pack_tier("system.permissions"), 2);
+        assert_eq!(PackRegistry::pack_tier("system.package_manager"), 2);
 
         // Infrastructure should be tier 3
         assert_eq!(PackRegistry::pack_tier("infrastructure.terraform"), 3);
 
         // Tier 4 packs should be tier 4
         assert_eq!(PackRegistry::pack_tier("cloud.aws"), 4);
+        assert_eq!(PackRegistry::pack_tier("cloud.aws_compute"), 4);
+        assert_eq!(PackRegistry::pack_tier("cloud.aws_security"), 4);
         assert_eq!(PackRegistry::pack_tier("apigateway.aws"), 4);
         assert_eq!(PackRegistry::pack_tier("dns.cloudflare"), 4);
         assert_eq!(PackRegistry::pack_tier("dns.route53"), 4);
@@ -2339,6 +2789,37 @@ mod tests {
 
         // Unknown should be tier 11
         assert_eq!(PackRegistry::pack_tier("unknown.pack"), 11);
+        assert_eq!(PackRegistry::pack_tier("heuristic.destructive_verbs"), 12);
+        assert_eq!(PackRegistry::pack_tier("heuristic.exfil"), 0);
+    }
+
+    #[test]
+    fn expand_enabled_ordered_with_puts_configured_packs_first() {
+        let enabled: HashSet<String> = ["core", "cloud", "storage.s3"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let default = REGISTRY.expand_enabled_ordered(&enabled);
+        assert_eq!(REGISTRY.expand_enabled_ordered_with(&enabled, &[]), default);
+
+        let pack_order = vec!["cloud.aws_security".to_string(), "cloud".to_string()];
+        let ordered = REGISTRY.expand_enabled_ordered_with(&enabled, &pack_order);
+        assert_eq!(ordered[0], "cloud.aws_security");
+        let cloud_end = ordered
+            .iter()
+            .position(|id| !id.starts_with("cloud."))
+            .expect("non-cloud packs follow");
+        assert!(
+            ordered[cloud_end..]
+                .iter()
+                .all(|id| !id.starts_with("cloud."))
+        );
+        // Unlisted packs keep their relative default order.
+        let rest: Vec<&String> = default
+            .iter()
+            .filter(|id| !id.starts_with("cloud."))
+            .collect();
+        assert_eq!(ordered[cloud_end..].iter().collect::<Vec<_>>(), rest);
     }
 
     /// Test that `expand_enabled_ordered` returns packs in deterministic order.
@@ -2728,6 +3209,51 @@ mod tests {
         assert_eq!(default, DecisionMode::Deny);
     }
 
+    /// Every rule with a reason code must name a real destructive pattern,
+    /// otherwise a rename would silently fall back to `Unspecified`.
+    #[test]
+    fn reason_codes_reference_existing_rules() {
+        for (pack_id, names, _) in REASON_CODES {
+            let pack = REGISTRY
+                .get(pack_id)
+                .unwrap_or_else(|| panic!("unknown pack {pack_id}"));
+            for name in *names {
+                assert!(
+                    pack.destructive_patterns
+                        .iter()
+                        .any(|p| p.name == Some(*name)),
+                    "{pack_id}:{name} has a reason code but no such pattern"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reason_code_lookup_and_serialization() {
+        assert_eq!(
+            ReasonCode::for_rule_id("core.git:reset-hard"),
+            ReasonCode::DestroysUncommittedChanges
+        );
+        assert_eq!(
+            ReasonCode::for_rule("core.filesystem", "rm-rf-general"),
+            ReasonCode::WipesFilesystem
+        );
+        assert_eq!(
+            ReasonCode::for_rule_id("core.git:no-such-rule"),
+            ReasonCode::Unspecified
+        );
+        assert_eq!(
+            ReasonCode::for_rule_id("not-a-rule-id"),
+            ReasonCode::Unspecified
+        );
+
+        let json = serde_json::to_string(&ReasonCode::DeletesRemoteResource).unwrap();
+        assert_eq!(
+            json,
+            format!("\"{}\"", ReasonCode::DeletesRemoteResource.as_str())
+        );
+    }
+
     // =========================================================================
     // Severity regression tests (git_safety_guard-1gt.3.2)
     // =========================================================================
@@ -2746,6 +3272,7 @@ mod tests {
         let critical_rules = [
             "reset-hard",
             "clean-force",
+            "clean-force-ignored",
             "push-force-long",
             "push-force-short",
             "stash-clear",
@@ -2832,10 +3359,17 @@ mod tests {
     /// expected severity distribution.
     #[test]
     fn core_rules_have_appropriate_severity() {
-        // Patterns that should be Medium (recoverable operations)
+        // Patterns that should be Medium (recoverable or commonly intentional)
         let medium_patterns = [
-            ("core.git", "branch-force-delete"), // Recoverable via reflog
-            ("core.git", "stash-drop"),          // Recoverable via fsck
+            ("core.git", "branch-force-delete"),     // Recoverable via reflog
+            ("core.git", "stash-drop"),              // Recoverable via fsck
+            ("core.git", "push-delete"),             // Re-pushable from any clone
+            ("core.git", "push-refspec-delete"),     // Re-pushable from any clone
+            ("core.git", "checkout-broad-pathspec"), // Common, often intentional reset
+            ("core.git", "remote-remove"),           // Re-add with git remote add
+            ("core.git", "remote-set-url"),          // Restore the previous URL
+            ("core.git", "config-unset-all"),        // Values can be re-added by hand
+            ("core.filesystem", "find-loop-delete"), // Scoped cleanup is often intentional
         ];
 
         for pack_id in ["core.git", "core.filesystem"] {
@@ -2850,7 +3384,7 @@ mod tests {
                 if is_expected_medium {
                     assert!(
                         matches!(pattern.severity, Severity::Medium),
-                        "Core pack rule {pack_id}:{name} should be Medium severity (recoverable)"
+                        "Core pack rule {pack_id}:{name} should be Medium severity"
                     );
                 } else {
                     assert!(
@@ -2862,6 +3396,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn builtin_packs_have_tags_and_docs_url() {
+        for id in REGISTRY.all_pack_ids() {
+            let pack = REGISTRY.get(id).expect("Pack should exist");
+            assert!(!pack.tags.is_empty(), "Pack {id} should declare tags");
+            let url = pack
+                .docs_url
+                .expect("built-in packs should have a docs_url");
+            assert!(url.starts_with("https://"), "Pack {id} docs_url: {url}");
+        }
+    }
+
+    #[test]
+    fn pack_has_tag_is_case_insensitive() {
+        let pack = REGISTRY.get("cloud.aws").expect("Pack should exist");
+        assert!(pack.has_tag("aws"));
+        assert!(pack.has_tag("AWS"));
+        assert!(!pack.has_tag("gcp"));
+    }
+
     mod normalization_tests {
         use super::*;
 
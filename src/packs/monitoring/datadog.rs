@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         name: "Datadog",
         description: "Protects against destructive Datadog CLI/API operations like deleting monitors and dashboards.",
         keywords: &["datadog-ci", "datadoghq", "datadog"],
+        tags: &["monitoring", "observability"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/monitoring.md#monitoringdatadog",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive New Relic CLI/API operations like deleting entities \
                       or alerting resources.",
         keywords: &["newrelic", "api.newrelic.com", "graphql"],
+        tags: &["monitoring", "observability"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/monitoring.md#monitoringnewrelic",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
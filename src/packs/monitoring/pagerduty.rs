@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive PagerDuty CLI/API operations like deleting \
                       services and schedules (which can break incident routing).",
         keywords: &["pd", "pagerduty", "api.pagerduty.com"],
+        tags: &["monitoring", "incident-response"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/monitoring.md#monitoringpagerduty",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -32,6 +32,10 @@ pub fn create_pack() -> Pack {
             "servicemonitor",
             "podmonitor",
         ],
+        tags: &["monitoring", "observability"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/monitoring.md#monitoringprometheus",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Splunk CLI/API operations like index removal \
                       and REST API DELETE calls",
         keywords: &["splunk"],
+        tags: &["monitoring", "observability"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/monitoring.md#monitoringsplunk",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -21,6 +21,10 @@ pub fn create_pack() -> Pack {
             "npm", "yarn", "pnpm", "pip", "apt", "yum", "dnf", "cargo", "gem", "brew", "poetry",
             "mvn", "mvnw", "gradle", "gradlew", "publish",
         ],
+        tags: &["package-managers", "supply-chain"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/package_managers.md",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -27,6 +27,10 @@ pub fn create_pack() -> Pack {
             "gateway.payment_method.",
             "gateway.subscription.",
         ],
+        tags: &["payment", "billing"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/payment.md#paymentbraintree",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
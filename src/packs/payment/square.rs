@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Square CLI/API operations like deleting catalog objects \
                       or customers (which can break payment flows).",
         keywords: &["square", "api.squareup.com"],
+        tags: &["payment", "billing"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/payment.md#paymentsquare",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
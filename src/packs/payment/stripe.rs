@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Stripe CLI/API operations like deleting webhook endpoints \
                       and customers, or rotating API keys without coordination.",
         keywords: &["stripe", "api.stripe.com"],
+        tags: &["payment", "billing"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/payment.md#paymentstripe",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
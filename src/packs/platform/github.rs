@@ -8,6 +8,7 @@
 //! - Deleting releases
 //! - Deleting issues
 //! - Deleting SSH keys
+//! - Deleting Actions secrets, variables, runs, and caches
 
 use crate::packs::{DestructivePattern, Pack, SafePattern};
 use crate::{destructive_pattern, safe_pattern};
@@ -21,6 +22,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive GitHub CLI operations like deleting repositories, gists, releases, or SSH keys.",
         // Broad on purpose: global `gh` flags can appear before the subcommand.
         keywords: &["gh"],
+        tags: &["github", "vcs", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/platform.md#platformgithub",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -68,6 +73,18 @@ fn create_safe_patterns() -> Vec<SafePattern> {
             "gh-variable-list",
             r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+variable\s+list\b"
         ),
+        safe_pattern!(
+            "gh-run-list-view",
+            r"^gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+run\s+(?:list|view|watch)\b[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "gh-cache-list",
+            r"^gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+cache\s+list\b[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "gh-workflow-list-view",
+            r"^gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+workflow\s+(?:list|view)\b[^;&|\n]*$"
+        ),
         safe_pattern!(
             "gh-auth-status",
             r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+auth\s+status\b"
@@ -119,12 +136,12 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         destructive_pattern!(
             "gh-secret-delete",
             r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+secret\s+(?:delete|remove)\b",
-            "gh secret delete removes GitHub Actions secrets."
+            "gh secret delete removes a GitHub Actions secret. Workflows that read it will fail or silently run with an empty value."
         ),
         destructive_pattern!(
             "gh-variable-delete",
             r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+variable\s+(?:delete|remove)\b",
-            "gh variable delete removes GitHub Actions variables."
+            "gh variable delete removes a GitHub Actions variable. Workflows that read it will silently see an empty value."
         ),
         destructive_pattern!(
             "gh-repo-deploy-key-delete",
@@ -136,6 +153,28 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
             r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+run\s+cancel\b",
             "gh run cancel stops a workflow run and may interrupt deployments."
         ),
+        destructive_pattern!(
+            "gh-run-delete",
+            r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+run\s+delete\b",
+            "gh run delete permanently deletes a workflow run along with its logs and artifacts."
+        ),
+        // Checked before the single-key form so the wipe gets its own rule.
+        destructive_pattern!(
+            "gh-cache-delete-all",
+            r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+cache\s+delete\b.*\s(?:--all|-a)\b",
+            "gh cache delete --all wipes every Actions cache in the repository. All workflows rebuild from scratch."
+        ),
+        destructive_pattern!(
+            "gh-cache-delete",
+            r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+cache\s+delete\b",
+            "gh cache delete removes an Actions cache; the next workflow run rebuilds it from scratch.",
+            Medium
+        ),
+        destructive_pattern!(
+            "gh-workflow-disable",
+            r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+workflow\s+disable\b",
+            "gh workflow disable stops a workflow from running, including scheduled and required checks."
+        ),
         destructive_pattern!(
             "gh-api-delete-actions-secret",
             r"gh(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|gist|release|issue|ssh-key|secret|variable|run|auth|status|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?repos/[^/\s]+/[^/\s]+/actions/secrets/",
@@ -188,6 +227,10 @@ mod tests {
         assert!(pack.check("gh variable list").is_none());
         assert!(pack.check("gh auth status").is_none());
         assert!(pack.check("gh status").is_none());
+        assert!(pack.check("gh run list").is_none());
+        assert!(pack.check("gh run view 123456 --log").is_none());
+        assert!(pack.check("gh cache list").is_none());
+        assert!(pack.check("gh workflow list").is_none());
 
         // With global flags
         assert!(pack.check("gh -R owner/repo repo view").is_none());
@@ -214,6 +257,13 @@ mod tests {
             ("gh variable remove VAR_NAME", "gh-variable-delete"),
             ("gh repo deploy-key delete 123", "gh-repo-deploy-key-delete"),
             ("gh run cancel 123456", "gh-run-cancel"),
+            ("gh run delete 123456", "gh-run-delete"),
+            ("gh -R owner/repo run delete 123456", "gh-run-delete"),
+            ("gh cache delete --all", "gh-cache-delete-all"),
+            ("gh cache delete -a", "gh-cache-delete-all"),
+            ("gh -R owner/repo cache delete --all", "gh-cache-delete-all"),
+            ("gh cache delete 1234", "gh-cache-delete"),
+            ("gh workflow disable ci.yml", "gh-workflow-disable"),
             (
                 "gh api -X DELETE /repos/owner/repo/actions/secrets/SECRET",
                 "gh-api-delete-actions-secret",
@@ -244,4 +294,26 @@ mod tests {
             assert_eq!(matched.name, Some(expected_rule), "Command: {cmd}");
         }
     }
+
+    #[test]
+    fn actions_list_view_does_not_mask_chained_deletes() {
+        let pack = create_pack();
+        let checks = [
+            ("gh run list && gh run delete 123", "gh-run-delete"),
+            (
+                "gh cache list; gh cache delete --all",
+                "gh-cache-delete-all",
+            ),
+            (
+                "gh workflow view ci && gh workflow disable ci",
+                "gh-workflow-disable",
+            ),
+        ];
+        for (cmd, expected_rule) in checks {
+            let matched = pack
+                .check(cmd)
+                .unwrap_or_else(|| panic!("Should block: {cmd}"));
+            assert_eq!(matched.name, Some(expected_rule), "Command: {cmd}");
+        }
+    }
 }
@@ -1,7 +1,11 @@
 //! GitLab Platform pack - protections for destructive GitLab platform operations.
 //!
 //! This pack focuses on non-CI GitLab operations like deleting projects,
-//! archiving repositories, removing protected branches, and deleting webhooks.
+//! issues, merge requests, archiving repositories, removing protected branches,
+//! and deleting webhooks.
+//!
+//! `glab` patterns share the GitHub pack's global-flag matcher, so flags like
+//! `-R group/project` before the subcommand don't cause misses.
 
 use crate::packs::{DestructivePattern, Pack, SafePattern};
 use crate::{destructive_pattern, safe_pattern};
@@ -15,6 +19,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive GitLab platform operations like deleting projects, \
                       releases, protected branches, and webhooks.",
         keywords: &["glab", "gitlab-rails", "gitlab-rake"],
+        tags: &["gitlab", "vcs", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/platform.md#platformgitlab",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -27,47 +35,47 @@ fn create_safe_patterns() -> Vec<SafePattern> {
     vec![
         safe_pattern!(
             "glab-repo-list",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+repo\s+list\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+repo\s+list\b"
         ),
         safe_pattern!(
             "glab-repo-view",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+repo\s+view\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+repo\s+view\b"
         ),
         safe_pattern!(
             "glab-repo-clone",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+repo\s+clone\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+repo\s+clone\b"
         ),
         safe_pattern!(
             "glab-mr-list",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+mr\s+list\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+mr\s+list\b"
         ),
         safe_pattern!(
             "glab-mr-view",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+mr\s+view\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+mr\s+view\b"
         ),
         safe_pattern!(
             "glab-issue-list",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+issue\s+list\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+issue\s+list\b"
         ),
         safe_pattern!(
             "glab-issue-view",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+issue\s+view\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+issue\s+view\b"
         ),
         safe_pattern!(
             "glab-variable-list",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+variable\s+list\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+variable\s+list\b"
         ),
         safe_pattern!(
             "glab-release-list",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+release\s+list\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+release\s+list\b"
         ),
         safe_pattern!(
             "glab-release-view",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+release\s+view\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+release\s+view\b"
         ),
         safe_pattern!(
             "glab-api-explicit-get",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+api\b.*(?:-X|--method)\s+GET\b"
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+GET\b"
         ),
     ]
 }
@@ -76,47 +84,57 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
     vec![
         destructive_pattern!(
             "glab-repo-delete",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+repo\s+delete\b",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+repo\s+delete\b",
             "glab repo delete permanently deletes a GitLab project."
         ),
         destructive_pattern!(
             "glab-repo-archive",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+repo\s+archive\b",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+repo\s+archive\b",
             "glab repo archive makes a GitLab project read-only."
         ),
         destructive_pattern!(
             "glab-release-delete",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+release\s+delete\b",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+release\s+delete\b",
             "glab release delete removes GitLab releases."
         ),
+        destructive_pattern!(
+            "glab-issue-delete",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+issue\s+delete\b",
+            "glab issue delete permanently deletes a GitLab issue."
+        ),
+        destructive_pattern!(
+            "glab-mr-delete",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+mr\s+delete\b",
+            "glab mr delete permanently deletes a GitLab merge request."
+        ),
         destructive_pattern!(
             "glab-variable-delete",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+variable\s+(?:delete|remove)\b",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+variable\s+(?:delete|remove)\b",
             "glab variable delete removes GitLab CI/CD variables."
         ),
         destructive_pattern!(
             "glab-api-delete-project",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?projects/[^/\s]+(?:\s|$)",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?projects/[^/\s]+(?:\s|$)",
             "glab api DELETE /projects/* deletes a GitLab project."
         ),
         destructive_pattern!(
             "glab-api-delete-release",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?projects/[^/\s]+/releases/",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?projects/[^/\s]+/releases/",
             "glab api DELETE releases removes GitLab releases."
         ),
         destructive_pattern!(
             "glab-api-delete-variable",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?projects/[^/\s]+/variables/",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?projects/[^/\s]+/variables/",
             "glab api DELETE variables removes CI/CD variables."
         ),
         destructive_pattern!(
             "glab-api-delete-protected-branch",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?protected_branches/",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?protected_branches/",
             "glab api DELETE protected_branches removes branch protections."
         ),
         destructive_pattern!(
             "glab-api-delete-hook",
-            r"glab(?:\s+--?\S+(?:\s+\S+)?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?hooks/",
+            r"glab(?:\s+--?[A-Za-z][A-Za-z0-9-]*\b(?:\s+(?!(?:repo|mr|issue|release|variable|api)\b)(?:(?:\x22[^\x22]*\x22)|(?:'[^']*')|\S+))?)*\s+api\b.*(?:-X|--method)\s+DELETE\b.*(?:/)?hooks/",
             "glab api DELETE hooks removes GitLab webhooks."
         ),
         destructive_pattern!(
@@ -168,6 +186,46 @@ mod tests {
         assert_blocks_with_pattern(&pack, "glab release delete v1.2.3", "glab-release-delete");
     }
 
+    #[test]
+    fn test_issue_delete_blocked() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "glab issue delete 42", "glab-issue-delete");
+    }
+
+    #[test]
+    fn test_mr_delete_blocked() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "glab mr delete 17", "glab-mr-delete");
+    }
+
+    #[test]
+    fn test_global_flags_before_subcommand() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "glab -R group/project repo delete",
+            "glab-repo-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "glab -R group/project issue delete 42",
+            "glab-issue-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "glab --repo \"group/sub project\" mr delete 17",
+            "glab-mr-delete",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "glab -R group/project release delete v1.2.3",
+            "glab-release-delete",
+        );
+        assert_allows(&pack, "glab -R group/project repo view");
+        assert_allows(&pack, "glab -R group/project issue list");
+        assert_allows(&pack, "glab -R group/project mr list");
+    }
+
     #[test]
     fn test_variable_delete_blocked() {
         let pack = create_pack();
@@ -14,6 +14,10 @@ pub fn create_pack() -> Pack {
         name: "rsync",
         description: "Protects against destructive rsync operations like --delete and its variants.",
         keywords: &["rsync"],
+        tags: &["remote", "filesystem", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/remote.md#remotersync",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         name: "scp",
         description: "Protects against destructive SCP operations like overwrites to system paths.",
         keywords: &["scp"],
+        tags: &["remote", "filesystem"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/remote.md#remotescp",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
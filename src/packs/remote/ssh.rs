@@ -16,6 +16,10 @@ pub fn create_pack() -> Pack {
         name: "ssh",
         description: "Protects against destructive SSH operations like remote command execution and key management.",
         keywords: &["ssh", "ssh-keygen", "ssh-keyscan"],
+        tags: &["remote", "credentials"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/remote.md#remotessh",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
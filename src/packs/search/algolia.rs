@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Algolia operations like deleting indices, clearing objects, \
                       removing rules/synonyms, and deleting API keys.",
         keywords: &["algolia", "algoliasearch"],
+        tags: &["search", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/search.md#searchalgolia",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -30,6 +30,10 @@ pub fn create_pack() -> Pack {
             "_all",
             "_delete_by_query",
         ],
+        tags: &["search", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/search.md#searchelasticsearch",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
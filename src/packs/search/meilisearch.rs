@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Meilisearch REST API operations like index deletion, \
                       document deletion, delete-batch, and API key removal.",
         keywords: &["meili", "meilisearch", "7700", "/indexes", "/keys"],
+        tags: &["search", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/search.md#searchmeilisearch",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
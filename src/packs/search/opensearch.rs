@@ -28,6 +28,10 @@ pub fn create_pack() -> Pack {
             "_all",
             "_delete_by_query",
         ],
+        tags: &["aws", "search", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/search.md#searchopensearch",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -14,6 +14,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive AWS Secrets Manager and SSM Parameter Store \
                       operations like delete-secret and delete-parameter.",
         keywords: &["aws", "secretsmanager", "ssm"],
+        tags: &["aws", "secrets", "credentials"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/secrets.md#secretsawssecrets",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
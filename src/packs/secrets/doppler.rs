@@ -14,6 +14,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Doppler CLI operations like deleting secrets, \
                       configs, environments, or projects.",
         keywords: &["doppler"],
+        tags: &["secrets", "credentials"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/secrets.md#secretsdoppler",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -14,6 +14,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive 1Password CLI operations like deleting items, \
                       documents, users, groups, and vaults.",
         keywords: &["op"],
+        tags: &["secrets", "credentials"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/secrets.md#secretsonepassword",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -15,6 +15,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Vault CLI operations like deleting secrets, \
                       disabling auth/secret engines, revoking leases/tokens, and deleting policies.",
         keywords: &["vault"],
+        tags: &["secrets", "credentials"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/secrets.md#secretsvault",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
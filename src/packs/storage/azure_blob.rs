@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive Azure Blob Storage operations like container \
                       deletion, blob deletion, and azcopy remove.",
         keywords: &["az storage", "azcopy"],
+        tags: &["azure", "storage", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/storage.md#storageazureblob",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -17,6 +17,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive GCS operations like bucket removal, \
                       object deletion, and recursive deletes.",
         keywords: &["gsutil", "gcloud storage"],
+        tags: &["gcp", "storage", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/storage.md#storagegcs",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against destructive MinIO Client (mc) operations like bucket \
                       removal, object deletion, and admin operations.",
         keywords: &["mc"],
+        tags: &["storage", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/storage.md#storageminio",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
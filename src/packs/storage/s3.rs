@@ -25,6 +25,10 @@ pub fn create_pack() -> Pack {
             "delete-objects",
             "--delete",
         ],
+        tags: &["aws", "storage", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/storage.md#storages3",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
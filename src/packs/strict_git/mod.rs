@@ -18,6 +18,10 @@ pub fn create_pack() -> Pack {
         description: "Stricter git protections: blocks all force pushes, rebases, and \
                       history rewriting operations",
         keywords: &["git"],
+        tags: &["git", "vcs"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/strict_git.md",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -2,6 +2,8 @@
 //!
 //! This includes patterns for:
 //! - dd to block devices
+//! - truncating shell redirects (`>`) onto block devices or critical files
+//! - `tee` overwrites (and, at lower severity, `tee -a` appends) of critical files
 //! - fdisk/parted operations
 //! - mkfs (formatting)
 //! - mount/umount operations
@@ -21,10 +23,13 @@ pub fn create_pack() -> Pack {
         id: "system.disk".to_string(),
         name: "Disk Operations",
         description: "Protects against destructive disk operations like dd to devices, \
-                      mkfs, partition table modifications, RAID management, \
+                      truncating redirects or tee writes onto devices or critical files, mkfs, partition table modifications, RAID management, \
                       btrfs/LVM/device-mapper operations, and network block devices",
         keywords: &[
             "dd",
+            "tee",
+            "/etc/",
+            "/boot/",
             "fdisk",
             "mkfs",
             "parted",
@@ -43,6 +48,10 @@ pub fn create_pack() -> Pack {
             "lvresize",
             "pvmove",
         ],
+        tags: &["system", "disk", "data-loss"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/system.md#systemdisk",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -137,6 +146,45 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
             r"dd\s+.*if=/dev/(?:zero|urandom|random).*of=/dev/",
             "dd from /dev/zero or /dev/urandom to a device will WIPE all data!"
         ),
+        // `>`/`>|`/`&>` onto a block device overwrites it from the first sector.
+        // `>>` (append) and fd duplication (`>&2`) are deliberately not matched.
+        destructive_pattern!(
+            "redirect-device",
+            r"(?:^|[^<>])[0-9&]?>\|?\s*/dev/(?:sd[a-z]|hd[a-z]|vd[a-z]|xvd[a-z]|nvme[0-9]|mmcblk[0-9]|r?disk[0-9]|md[0-9]|dm-[0-9]|mapper/)",
+            "Redirecting output onto a block device OVERWRITES the disk, destroying its partition table and data.",
+            Critical
+        ),
+        // `>` onto critical system files truncates them before anything is written.
+        destructive_pattern!(
+            "redirect-critical-file",
+            r"(?:^|[^<>])[0-9&]?>\|?\s*(?:/etc/(?:passwd|shadow|group|gshadow|sudoers|fstab|crypttab|hosts|resolv\.conf)|/boot/grub2?/grub\.cfg)(?:$|[\s;&|)])",
+            "Redirecting with > TRUNCATES this critical system file. Use >> to append or edit it with a proper tool.",
+            High
+        ),
+        // `tee FILE` (typically `| sudo tee`) overwrites just like `>`; it is how
+        // agents get root-owned writes past redirect restrictions. Same critical
+        // files as `redirect-critical-file`, plus anything under /boot/. Options
+        // containing `a` (`-a`, `-ia`, `--append`) are left to the append pattern;
+        // `--` ends options and is skipped like any other flag.
+        destructive_pattern!(
+            "tee-boot-critical-file",
+            r"\btee\s+(?:(?:-[b-zA-Z]+|--(?:ignore-interrupts|output-error)\S*|--|[^\s\-]\S*)\s+)*(?:/etc/(?:fstab|crypttab)|/boot/\S+)(?:$|[\s;&|)])",
+            "tee OVERWRITES this boot-critical file; a bad fstab, crypttab, or /boot file leaves the system unbootable. Use tee -a to append or edit it with a proper tool.",
+            Critical
+        ),
+        destructive_pattern!(
+            "tee-critical-file",
+            r"\btee\s+(?:(?:-[b-zA-Z]+|--(?:ignore-interrupts|output-error)\S*|--|[^\s\-]\S*)\s+)*/etc/(?:passwd|shadow|group|gshadow|sudoers|hosts|resolv\.conf)(?:$|[\s;&|)])",
+            "tee OVERWRITES this critical system file. Use tee -a to append or edit it with a proper tool.",
+            High
+        ),
+        // `tee -a` only appends, but still edits accounts, sudo rules, or mounts.
+        destructive_pattern!(
+            "tee-append-critical-file",
+            r"\btee\s+(?:\S+\s+)*(?:-[a-zA-Z]*a[a-zA-Z]*|--append)\s+(?:\S+\s+)*(?:/etc/(?:passwd|shadow|group|gshadow|sudoers|fstab|crypttab|hosts|resolv\.conf)|/boot/\S+)(?:$|[\s;&|)])",
+            "tee -a appends to a critical system file. Review the appended content before running.",
+            Medium
+        ),
         // fdisk (partition editing)
         destructive_pattern!(
             "fdisk-edit",
@@ -364,6 +412,8 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
 
     #[test]
     fn wipefs_is_reachable_via_keywords() {
@@ -378,6 +428,149 @@ mod tests {
         assert_eq!(matched.name, Some("wipefs"));
     }
 
+    #[test]
+    fn redirect_onto_block_device_is_critical() {
+        let pack = create_pack();
+        for cmd in [
+            "echo x > /dev/sda",
+            "cat /dev/zero > /dev/sdb",
+            "cat image.iso >/dev/nvme0n1",
+            "head -c 1M /dev/urandom >| /dev/mmcblk0",
+            "cat backup.img &> /dev/mapper/vg0-root",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+            assert_blocks_with_pattern(&pack, cmd, "redirect-device");
+        }
+    }
+
+    #[test]
+    fn redirect_truncating_critical_file_is_blocked() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "> /etc/fstab", "redirect-critical-file");
+        assert_blocks_with_pattern(&pack, "echo root > /etc/passwd", "redirect-critical-file");
+        assert_blocks_with_severity(&pack, "cat new >/etc/sudoers; id", Severity::High);
+    }
+
+    #[test]
+    fn redirects_reach_the_pack_through_keyword_gating() {
+        let mut config = crate::config::Config::default();
+        config.packs.enabled = vec!["system.disk".to_string()];
+        for (cmd, rule) in [
+            ("echo x > /etc/fstab", "system.disk:redirect-critical-file"),
+            (
+                "cat new >/etc/sudoers",
+                "system.disk:redirect-critical-file",
+            ),
+            ("printf x > /dev/sda", "system.disk:redirect-device"),
+        ] {
+            let snapshot = eval_snapshot_with_config(cmd, &config);
+            assert_eq!(snapshot.decision, "deny", "{cmd}");
+            assert_eq!(snapshot.rule_id.as_deref(), Some(rule), "{cmd}");
+        }
+        for cmd in ["make 2>/dev/null", "echo 127.0.0.1 >> /etc/hosts"] {
+            assert_eq!(
+                eval_snapshot_with_config(cmd, &config).decision,
+                "allow",
+                "{cmd}"
+            );
+        }
+    }
+
+    #[test]
+    fn redirect_lookalikes_are_not_device_truncation() {
+        let pack = create_pack();
+        for cmd in [
+            "make 2>/dev/null",
+            "ls > /dev/stdout",
+            "cmd >/dev/null 2>&1",
+            "echo x >> /dev/sda",
+            "echo 127.0.0.1 >> /etc/hosts",
+            "cat < /dev/sda",
+            "echo x > /etc/passwd.bak",
+            "sudo cat /etc/passwd | grep root",
+        ] {
+            assert_allows(&pack, cmd);
+        }
+    }
+
+    #[test]
+    fn tee_overwriting_critical_file_is_blocked() {
+        let pack = create_pack();
+        for cmd in [
+            "echo '/dev/sdb1 /data ext4 defaults 0 2' | sudo tee /etc/fstab",
+            "cat grub.cfg | sudo tee /boot/grub/grub.cfg > /dev/null",
+            "tee -i /etc/crypttab < new",
+            "echo x | tee /tmp/copy /etc/fstab",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Critical);
+            assert_blocks_with_pattern(&pack, cmd, "tee-boot-critical-file");
+        }
+        assert_blocks_with_pattern(
+            &pack,
+            "echo 'agent ALL=(ALL) NOPASSWD:ALL' | sudo tee /etc/sudoers",
+            "tee-critical-file",
+        );
+        assert_blocks_with_severity(&pack, "echo root | tee /etc/passwd; id", Severity::High);
+    }
+
+    #[test]
+    fn tee_append_to_critical_file_is_lower_risk() {
+        let pack = create_pack();
+        for cmd in [
+            "echo '/dev/sdb1 /data ext4 defaults 0 2' | sudo tee -a /etc/fstab",
+            "echo 10.0.0.5 db | sudo tee --append /etc/hosts",
+            "echo x | tee -ai /etc/passwd",
+        ] {
+            assert_blocks_with_severity(&pack, cmd, Severity::Medium);
+            assert_blocks_with_pattern(&pack, cmd, "tee-append-critical-file");
+        }
+    }
+
+    #[test]
+    fn tee_writes_reach_the_pack_through_keyword_gating() {
+        let mut config = crate::config::Config::default();
+        config.packs.enabled = vec!["system.disk".to_string()];
+        for (cmd, rule) in [
+            (
+                "echo x | sudo tee /etc/fstab",
+                "system.disk:tee-boot-critical-file",
+            ),
+            (
+                "tee -- /etc/fstab < new",
+                "system.disk:tee-boot-critical-file",
+            ),
+            (
+                "echo x | tee -- /etc/sudoers",
+                "system.disk:tee-critical-file",
+            ),
+            (
+                "tee -ia /etc/fstab < new",
+                "system.disk:tee-append-critical-file",
+            ),
+        ] {
+            let snapshot = eval_snapshot_with_config(cmd, &config);
+            assert_eq!(snapshot.decision, "deny", "{cmd}");
+            assert_eq!(snapshot.rule_id.as_deref(), Some(rule), "{cmd}");
+        }
+        assert_eq!(
+            eval_snapshot_with_config("echo x | tee /tmp/out", &config).decision,
+            "allow"
+        );
+    }
+
+    #[test]
+    fn tee_lookalikes_are_not_critical_writes() {
+        let pack = create_pack();
+        for cmd in [
+            "echo x | tee /etc/fstab.new",
+            "sudo tee /etc/nginx/nginx.conf < site.conf",
+            "cat /etc/fstab | tee backup/fstab",
+            "echo x | tee /tmp/boot.log",
+        ] {
+            assert_allows(&pack, cmd);
+        }
+    }
+
     #[test]
     fn keyword_absent_skips_pack() {
         let pack = create_pack();
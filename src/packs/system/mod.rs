@@ -4,7 +4,9 @@
 //! - Disk operations (dd, fdisk, mkfs)
 //! - Permission changes (chmod, chown with dangerous patterns)
 //! - Service management (systemctl, service)
+//! - OS package removal (apt, dpkg, yum/dnf, pacman, brew)
 
 pub mod disk;
+pub mod package_manager;
 pub mod permissions;
 pub mod services;
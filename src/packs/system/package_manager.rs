@@ -0,0 +1,295 @@
+//! System package manager patterns - protections against destructive OS package operations.
+//!
+//! This includes patterns for:
+//! - apt/apt-get purge, remove, and autoremove
+//! - dpkg --purge / --remove
+//! - yum/dnf remove, erase, and autoremove
+//! - pacman -R (including -Rns)
+//! - brew uninstall (especially --force)
+//! - mass removal via `'*'` globs or `$(pacman -Qq)`
+
+use crate::packs::{DestructivePattern, Pack, SafePattern};
+use crate::{destructive_pattern, safe_pattern};
+
+/// Create the System Package Managers pack.
+#[must_use]
+pub fn create_pack() -> Pack {
+    Pack {
+        id: "system.package_manager".to_string(),
+        name: "System Package Managers",
+        description: "Protects against destructive OS package operations like apt-get purge, \
+                      dpkg --purge, yum/dnf remove, pacman -Rns, and brew uninstall --force",
+        keywords: &["apt-get", "apt", "dpkg", "yum", "dnf", "pacman", "brew"],
+        tags: &["system", "packages"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/system.md#systempackage_manager",
+        ),
+        safe_patterns: create_safe_patterns(),
+        destructive_patterns: create_destructive_patterns(),
+        keyword_matcher: None,
+        safe_regex_set: None,
+        safe_regex_set_is_complete: false,
+    }
+}
+
+fn create_safe_patterns() -> Vec<SafePattern> {
+    // Anchored to a single command (no `;`, `&&`, `|`) so a safe query or
+    // install cannot mask a removal chained before or after it.
+    vec![
+        safe_pattern!(
+            "apt-install",
+            r"^apt(?:-get)?\s+(?:-\S+\s+)*install\b[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "apt-query",
+            r"^apt(?:-cache)?\s+(?:list|show|search|policy)\b[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "dnf-yum-query",
+            r"^(?:dnf|yum)\s+(?:-\S+\s+)*(?:info|list|search|repolist|provides)\b[^;&|\n]*$"
+        ),
+        safe_pattern!(
+            "brew-query",
+            r"^brew\s+(?:list|ls|info|search|outdated|deps)\b[^;&|\n]*$"
+        ),
+    ]
+}
+
+fn create_destructive_patterns() -> Vec<DestructivePattern> {
+    vec![
+        // Mass removal must come first so it wins over the per-tool patterns.
+        destructive_pattern!(
+            "remove-all-packages",
+            r#"\b(?:apt(?:-get)?|yum|dnf)\s+(?:-\S+\s+)*(?:remove|purge|erase)\b.*\s['"]?\.?\*['"]?(?:\s|$)"#,
+            "Removing packages matching '*' uninstalls every package on the system.",
+            Critical,
+            "A bare '*' glob matches every installed package, including the kernel, libc, \
+             the package manager itself, and the shell. The system will not boot and \
+             usually has to be reinstalled.\n\n\
+             Safer alternatives:\n\
+             - Name the exact packages to remove\n\
+             - apt-get remove -s <pkg> / dnf remove --assumeno <pkg>: Preview first"
+        ),
+        destructive_pattern!(
+            "pacman-remove-all",
+            r"\bpacman\s+(?:-\S+\s+)*(?:-R\w*|--remove)\b.*(?:\$\(|`)\s*pacman\s+-Q",
+            "pacman -R $(pacman -Qq) removes every installed package.",
+            Critical,
+            "Feeding the full package list from pacman -Q into a removal removes the \
+             base system along with everything else. The machine is left unbootable.\n\n\
+             Safer alternatives:\n\
+             - pacman -Qdtq: List only orphaned packages\n\
+             - pacman -Rns $(pacman -Qdtq): Remove orphans only"
+        ),
+        destructive_pattern!(
+            "apt-purge",
+            r"\bapt(?:-get)?\s+(?:-\S+\s+)*(?:purge\b|remove\b.*\s--purge\b)",
+            "apt-get purge removes packages along with their system-wide configuration files.",
+            High,
+            "Purging deletes the package's configuration under /etc as well as the \
+             binaries. Local configuration changes are lost and cannot be recovered by \
+             reinstalling.\n\n\
+             Safer alternatives:\n\
+             - apt-get remove <pkg>: Keep configuration files\n\
+             - apt-get purge -s <pkg>: Simulate and review what will be removed"
+        ),
+        destructive_pattern!(
+            "apt-autoremove",
+            r"\bapt(?:-get)?\s+(?:-\S+\s+)*autoremove\b",
+            "apt-get autoremove removes every package apt considers no longer needed.",
+            Medium,
+            "Packages that were installed as dependencies but are now unmarked get \
+             removed, which can include tools that were used directly.\n\n\
+             Safer alternatives:\n\
+             - apt-get autoremove -s: Simulate and review the list first\n\
+             - apt-mark manual <pkg>: Keep packages you rely on"
+        ),
+        destructive_pattern!(
+            "apt-remove",
+            r"\bapt(?:-get)?\s+(?:-\S+\s+)*remove\b",
+            "apt-get remove uninstalls packages and anything that depends on them.",
+            Medium
+        ),
+        destructive_pattern!(
+            "dpkg-purge",
+            r"\bdpkg\s+(?:-\S+\s+)*(?:--purge|-P)\b",
+            "dpkg --purge removes a package and its configuration without dependency checks.",
+            High,
+            "dpkg does not resolve dependencies, so purging a package other packages need \
+             leaves the system in a broken state. Configuration files are deleted too.\n\n\
+             Safer alternatives:\n\
+             - apt-get remove <pkg>: Let apt handle dependencies\n\
+             - dpkg -L <pkg>: Review what the package owns first"
+        ),
+        destructive_pattern!(
+            "dpkg-remove",
+            r"\bdpkg\s+(?:-\S+\s+)*(?:--remove|-r)\b",
+            "dpkg --remove uninstalls a package without dependency resolution.",
+            Medium
+        ),
+        destructive_pattern!(
+            "dnf-yum-remove",
+            r"\b(?:dnf|yum)\s+(?:-\S+\s+)*(?:remove|erase|autoremove)\b",
+            "yum/dnf remove uninstalls packages and everything that depends on them.",
+            High,
+            "yum and dnf remove reverse dependencies along with the named package, which \
+             can pull out large parts of the system (removing glibc-adjacent packages or \
+             the kernel is not unusual).\n\n\
+             Safer alternatives:\n\
+             - dnf remove --assumeno <pkg>: Review the transaction without applying it\n\
+             - dnf history undo: Roll back a transaction that removed too much"
+        ),
+        destructive_pattern!(
+            "pacman-remove",
+            r"\bpacman\s+(?:-\S+\s+)*(?:-R\w*|--remove)\b",
+            "pacman -R removes packages (with -ns, also dependencies and config files).",
+            High,
+            "-Rns removes the package, its now-unneeded dependencies, and its saved \
+             configuration files. Nothing is kept for a later reinstall.\n\n\
+             Safer alternatives:\n\
+             - pacman -Rp <pkg>: Print what would be removed\n\
+             - pacman -R <pkg>: Remove only the named package"
+        ),
+        destructive_pattern!(
+            "brew-uninstall-force",
+            r"\bbrew\s+(?:uninstall|remove|rm)\b.*\s(?:--force|-f)\b",
+            "brew uninstall --force removes every installed version of a formula.",
+            High,
+            "--force deletes all installed versions, not just the linked one, and skips \
+             the dependents check when combined with --ignore-dependencies.\n\n\
+             Safer alternatives:\n\
+             - brew uninstall <formula>: Remove only the current version\n\
+             - brew uses --installed <formula>: Check dependents first"
+        ),
+        destructive_pattern!(
+            "brew-uninstall",
+            r"\bbrew\s+(?:uninstall|remove|rm)\b",
+            "brew uninstall removes an installed formula or cask.",
+            Medium
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "system.package_manager");
+        assert_eq!(pack.name, "System Package Managers");
+        assert!(!pack.description.is_empty());
+        for keyword in ["apt-get", "apt", "dpkg", "yum", "dnf", "pacman", "brew"] {
+            assert!(
+                pack.keywords.contains(&keyword),
+                "missing keyword {keyword}"
+            );
+        }
+
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn allows_install_and_queries() {
+        let pack = create_pack();
+        assert_safe_pattern_matches(&pack, "apt-get install -y curl");
+        assert_safe_pattern_matches(&pack, "apt install git");
+        assert_safe_pattern_matches(&pack, "apt list --installed");
+        assert_safe_pattern_matches(&pack, "dnf info nginx");
+        assert_safe_pattern_matches(&pack, "yum list installed");
+        assert_safe_pattern_matches(&pack, "brew list");
+        assert_allows(&pack, "apt-get update");
+        assert_allows(&pack, "pacman -Syu");
+        assert_allows(&pack, "pacman -Qq");
+        assert_allows(&pack, "dpkg -l");
+        assert_allows(&pack, "brew install jq");
+    }
+
+    #[test]
+    fn safe_patterns_do_not_mask_later_removals() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "apt-get install -y curl && apt-get purge nginx",
+            "apt-purge",
+        );
+        assert_blocks_with_pattern(&pack, "brew list && brew uninstall wget", "brew-uninstall");
+        // A query for one tool must not mask a removal with another.
+        assert_blocks_with_pattern(
+            &pack,
+            "brew list && pacman -Rns $(pacman -Qq)",
+            "pacman-remove-all",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "apt-get install -y curl && pacman -Rns linux",
+            "pacman-remove",
+        );
+        assert_blocks_with_pattern(&pack, "brew list && apt-get purge nginx", "apt-purge");
+        assert_blocks_with_pattern(&pack, "apt-get purge nginx; brew list", "apt-purge");
+    }
+
+    #[test]
+    fn blocks_apt() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "apt-get purge nginx", "apt-purge");
+        assert_blocks_with_pattern(&pack, "apt-get remove --purge nginx", "apt-purge");
+        assert_blocks_with_pattern(&pack, "sudo apt -y purge nginx", "apt-purge");
+        assert_blocks_with_pattern(&pack, "apt-get autoremove -y", "apt-autoremove");
+        assert_blocks_with_severity(&pack, "apt-get autoremove", Severity::Medium);
+        assert_blocks_with_pattern(&pack, "apt-get remove nginx", "apt-remove");
+    }
+
+    #[test]
+    fn blocks_dpkg() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "dpkg --purge nginx", "dpkg-purge");
+        assert_blocks_with_pattern(&pack, "dpkg -P nginx", "dpkg-purge");
+        assert_blocks_with_pattern(&pack, "dpkg -r nginx", "dpkg-remove");
+    }
+
+    #[test]
+    fn blocks_yum_and_dnf() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "yum remove httpd", "dnf-yum-remove");
+        assert_blocks_with_pattern(&pack, "dnf -y remove httpd", "dnf-yum-remove");
+        assert_blocks_with_pattern(&pack, "dnf erase httpd", "dnf-yum-remove");
+        assert_blocks_with_severity(&pack, "dnf autoremove", Severity::High);
+    }
+
+    #[test]
+    fn blocks_pacman() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "pacman -Rns firefox", "pacman-remove");
+        assert_blocks_with_pattern(&pack, "sudo pacman -R firefox", "pacman-remove");
+        assert_blocks_with_pattern(&pack, "pacman --remove firefox", "pacman-remove");
+    }
+
+    #[test]
+    fn blocks_brew() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "brew uninstall --force node", "brew-uninstall-force");
+        assert_blocks_with_pattern(&pack, "brew rm -f node", "brew-uninstall-force");
+        assert_blocks_with_pattern(&pack, "brew uninstall node", "brew-uninstall");
+        assert_blocks_with_severity(&pack, "brew uninstall node", Severity::Medium);
+    }
+
+    #[test]
+    fn mass_removal_is_critical() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "apt-get remove --purge '*'", "remove-all-packages");
+        assert_blocks_with_pattern(&pack, "apt-get purge \"*\"", "remove-all-packages");
+        assert_blocks_with_pattern(&pack, "dnf remove '*'", "remove-all-packages");
+        assert_blocks_with_pattern(&pack, "yum -y erase *", "remove-all-packages");
+        assert_blocks_with_severity(&pack, "apt-get remove --purge '*'", Severity::Critical);
+        assert_blocks_with_pattern(&pack, "pacman -Rns $(pacman -Qq)", "pacman-remove-all");
+        assert_blocks_with_severity(&pack, "pacman -Rns `pacman -Qq`", Severity::Critical);
+        // A glob with a prefix only targets matching packages.
+        assert_blocks_with_pattern(&pack, "apt-get purge 'php7*'", "apt-purge");
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! This includes patterns for:
 //! - chmod 777 (world writable)
+//! - chmod/chown -R on the filesystem root (critical)
+//! - chmod -R 000 (strips all access recursively)
 //! - chmod -R on system directories
 //! - chown -R on system directories
 //! - setfacl with dangerous patterns
@@ -44,6 +46,10 @@ pub fn create_pack() -> Pack {
         description: "Protects against dangerous permission changes like chmod 777, \
                       recursive chmod/chown on system directories",
         keywords: &["chmod", "chown", "chgrp", "setfacl"],
+        tags: &["system", "permissions"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/system.md#systempermissions",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
@@ -53,25 +59,59 @@ pub fn create_pack() -> Pack {
 }
 
 fn create_safe_patterns() -> Vec<SafePattern> {
+    // Anchored to one command: these short-circuit the pack, so a chained
+    // recursive change must not be hidden behind them.
     vec![
         // chmod on files (not directories recursively)
         safe_pattern!(
             "chmod-non-recursive",
-            r"chmod\s+(?!-[rR])(?:\d{3,4}|[ugoa][+-][rwxXst]+)\s+[^/]"
+            r"^chmod\s+(?!-[rR])(?:\d{3,4}|[ugoa][+-][rwxXst]+)\s+[^/\-;&|\n][^;&|\n]*$"
         ),
         // stat is safe (read-only)
-        safe_pattern!("stat", r"\bstat\b"),
+        safe_pattern!("stat", r"^stat\b[^;&|\n]*$"),
         // ls -l is safe
-        safe_pattern!("ls-perms", r"ls\s+.*-[a-zA-Z]*l"),
+        safe_pattern!("ls-perms", r"^ls\s+[^;&|\n]*-[a-zA-Z]*l[^;&|\n]*$"),
         // getfacl is safe (read-only)
-        safe_pattern!("getfacl", r"\bgetfacl\b"),
+        safe_pattern!("getfacl", r"^getfacl\b[^;&|\n]*$"),
         // namei is safe
-        safe_pattern!("namei", r"\bnamei\b"),
+        safe_pattern!("namei", r"^namei\b[^;&|\n]*$"),
     ]
 }
 
 fn create_destructive_patterns() -> Vec<DestructivePattern> {
     vec![
+        // Recursive chmod/chown on `/` itself. Listed first so it wins over
+        // the broader 777 and chown-to-root rules.
+        destructive_pattern!(
+            "chmod-recursive-root-target",
+            r"chmod\b[^;&|]*\s(?:-[A-Za-z]*R[A-Za-z]*|--recursive)\b[^;&|]*\s/\*?(?:[\s;&|]|$)",
+            "chmod -R on / rewrites permissions for the entire filesystem.",
+            Critical,
+            "Recursively changing modes on the root filesystem breaks setuid binaries \
+             (sudo, su), SSH key permissions, and service sockets. The system often \
+             cannot boot or be logged into afterwards, and the original modes cannot \
+             be restored without a backup or reinstall."
+        ),
+        destructive_pattern!(
+            "chown-recursive-root-target",
+            r"ch(?:own|grp)\b[^;&|]*\s(?:-[A-Za-z]*R[A-Za-z]*|--recursive)\b[^;&|]*\s/\*?(?:[\s;&|]|$)",
+            "chown -R on / rewrites ownership for the entire filesystem.",
+            Critical,
+            "Recursively changing ownership of the root filesystem breaks package-managed \
+             files, home directories, and daemons that check file ownership. Recovery \
+             usually means restoring from backup or reinstalling.",
+            CHOWN_RECURSIVE_SUGGESTIONS
+        ),
+        // chmod -R 000 strips every permission bit from a tree
+        destructive_pattern!(
+            "chmod-recursive-000",
+            r"chmod\b[^;&|]*\s(?:-[A-Za-z]*R[A-Za-z]*|--recursive)\b[^;&|]*\s0{3,4}(?:\s|$)|chmod\s+0{3,4}\s+(?:[^;&|]*\s)?(?:-[A-Za-z]*R[A-Za-z]*|--recursive)\b",
+            "chmod -R 000 removes all access to every file in the tree.",
+            High,
+            "Mode 000 removes read, write, and execute for owner, group, and others. \
+             Applied recursively, directories can no longer be traversed, so even \
+             undoing the change requires root and a separate recursive chmod."
+        ),
         // chmod 777 (world writable)
         destructive_pattern!(
             "chmod-777",
@@ -126,3 +166,118 @@ fn create_destructive_patterns() -> Vec<DestructivePattern> {
         ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packs::Severity;
+    use crate::packs::test_helpers::*;
+
+    #[test]
+    fn test_pack_creation() {
+        let pack = create_pack();
+        assert_eq!(pack.id, "system.permissions");
+        assert_patterns_compile(&pack);
+        assert_all_patterns_have_reasons(&pack);
+        assert_unique_pattern_names(&pack);
+    }
+
+    #[test]
+    fn blocks_recursive_chmod_on_root_as_critical() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "chmod -R 777 /", "chmod-recursive-root-target");
+        assert_blocks_with_severity(&pack, "chmod -R 777 /", Severity::Critical);
+        assert_blocks_with_pattern(
+            &pack,
+            "chmod --recursive 755 /*",
+            "chmod-recursive-root-target",
+        );
+        assert_blocks_with_pattern(&pack, "chmod 755 -R /", "chmod-recursive-root-target");
+    }
+
+    #[test]
+    fn blocks_recursive_chown_on_root_as_critical() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "chown -R root:root /", "chown-recursive-root-target");
+        assert_blocks_with_severity(&pack, "chown -R root:root /", Severity::Critical);
+        assert_blocks_with_pattern(&pack, "chgrp -R wheel /", "chown-recursive-root-target");
+    }
+
+    #[test]
+    fn blocks_recursive_changes_on_system_directories() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "chown -R nobody /etc", "chown-recursive-root");
+        assert_blocks_with_pattern(
+            &pack,
+            "chown -R nobody:nogroup /usr",
+            "chown-recursive-root",
+        );
+        assert_blocks_with_pattern(&pack, "chmod -R 755 /usr/lib", "chmod-recursive-root");
+    }
+
+    #[test]
+    fn blocks_recursive_000() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(&pack, "chmod -R 000 ./build", "chmod-recursive-000");
+        assert_blocks_with_pattern(&pack, "chmod 0000 -R data", "chmod-recursive-000");
+    }
+
+    #[test]
+    fn allows_non_recursive_and_project_scoped_changes() {
+        let pack = create_pack();
+        assert_allows(&pack, "chmod 644 file.txt");
+        assert_allows(&pack, "chmod u+x script.sh");
+        assert_allows(&pack, "chown alice file.txt");
+        assert_allows(&pack, "chmod -R 755 ./src");
+        assert_allows(&pack, "chmod -R u+w /home/alice/project");
+        assert_allows(&pack, "chown -R alice:staff ./project");
+        assert_allows(&pack, "chmod 000 secret.key");
+    }
+
+    #[test]
+    fn chgrp_reaches_the_pack_through_keyword_gating() {
+        let mut config = crate::config::Config::default();
+        config.packs.enabled = vec!["system.permissions".to_string()];
+        let snapshot = eval_snapshot_with_config("chgrp -R wheel /", &config);
+        assert_eq!(snapshot.decision, "deny");
+        assert_eq!(
+            snapshot.rule_id.as_deref(),
+            Some("system.permissions:chown-recursive-root-target")
+        );
+    }
+
+    #[test]
+    fn root_target_does_not_span_chained_commands() {
+        let pack = create_pack();
+        assert_allows(&pack, "chmod -R 755 ./src && ls /");
+        assert_allows(&pack, "chown -R alice ./project; cd /");
+        assert_allows(&pack, "chmod -R 755 ./src || echo 000");
+        assert_blocks_with_pattern(
+            &pack,
+            "cd /tmp && chmod -R 777 /",
+            "chmod-recursive-root-target",
+        );
+    }
+
+    #[test]
+    fn safe_patterns_do_not_mask_chained_recursive_changes() {
+        let pack = create_pack();
+        assert_blocks_with_pattern(
+            &pack,
+            "chmod 644 f && chmod -R 777 /",
+            "chmod-recursive-root-target",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "stat f; chown -R root:root /",
+            "chown-recursive-root-target",
+        );
+        assert_blocks_with_pattern(
+            &pack,
+            "ls -l /etc && chmod -R 777 /",
+            "chmod-recursive-root-target",
+        );
+        assert_allows(&pack, "stat -c %a file.txt");
+        assert_allows(&pack, "ls -la ./src");
+    }
+}
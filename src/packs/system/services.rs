@@ -24,6 +24,10 @@ pub fn create_pack() -> Pack {
             "shutdown",
             "reboot",
         ],
+        tags: &["system", "services"],
+        docs_url: Some(
+            "https://github.com/Dicklesworthstone/destructive_command_guard/blob/main/docs/packs/system.md#systemservices",
+        ),
         safe_patterns: create_safe_patterns(),
         destructive_patterns: create_destructive_patterns(),
         keyword_matcher: None,
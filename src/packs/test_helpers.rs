@@ -24,6 +24,7 @@
 //! }
 //! ```
 
+use crate::packs::regex_engine::CompiledRegex;
 use crate::packs::{Pack, Severity};
 use std::fmt::Write;
 use std::time::{Duration, Instant};
@@ -152,6 +153,36 @@ pub fn assert_blocks_with_severity(pack: &Pack, command: &str, expected_severity
     }
 }
 
+/// Assert that a pack blocks a command under the expected rule ID.
+///
+/// Rule IDs have the form `pack_id:pattern_name` and are what users put in
+/// allowlists, so this checks the pack ID and pattern name together.
+///
+/// # Panics
+///
+/// Panics if:
+/// - The pack does not block the command
+/// - The matched pattern is unnamed or its rule ID differs from `expected_rule_id`
+#[track_caller]
+pub fn assert_blocks_with_rule_id(pack: &Pack, command: &str, expected_rule_id: &str) {
+    let Some(matched) = pack.check(command) else {
+        panic!(
+            "Expected pack '{}' to block command '{}' as '{}' but it was allowed",
+            pack.id, command, expected_rule_id
+        );
+    };
+
+    let rule_id = matched.name.map(|name| format!("{}:{name}", pack.id));
+    assert_eq!(
+        rule_id.as_deref(),
+        Some(expected_rule_id),
+        "Command '{}' was blocked under an unexpected rule ID.\n\
+         Reason: '{}'",
+        command,
+        matched.reason
+    );
+}
+
 /// Assert that a pack allows a command (no destructive pattern matches).
 ///
 /// This can mean either:
@@ -479,18 +510,31 @@ pub fn validate_pack(pack: &Pack) {
 
 /// Verify that all patterns in a pack compile successfully.
 ///
-/// This is a sanity check to ensure no regex syntax errors exist.
+/// Pack regexes are compiled lazily on first use, and a pattern that fails to
+/// compile silently never matches. This forces compilation of every pattern so
+/// syntax errors surface as test failures instead.
+///
+/// # Panics
+///
+/// Panics if any safe or destructive pattern fails to compile.
 #[track_caller]
 pub fn assert_patterns_compile(pack: &Pack) {
-    // Safe patterns
     for pattern in &pack.safe_patterns {
-        // Just accessing the regex is enough - it's compiled at pack creation
-        let _ = pattern.regex.as_str();
+        if let Err(err) = CompiledRegex::new(pattern.regex.as_str()) {
+            panic!(
+                "Safe pattern '{}' in pack '{}' does not compile: {err}",
+                pattern.name, pack.id
+            );
+        }
     }
 
-    // Destructive patterns
     for pattern in &pack.destructive_patterns {
-        let _ = pattern.regex.as_str();
+        if let Err(err) = CompiledRegex::new(pattern.regex.as_str()) {
+            panic!(
+                "Destructive pattern {:?} in pack '{}' does not compile: {err}",
+                pattern.name, pack.id
+            );
+        }
     }
 }
 
@@ -708,7 +752,7 @@ impl<'a> LoggedPackTestRunner<'a> {
         let total = self.logger.test_result_count();
         // All tests passed if we got here without panic
         self.logger.log_summary(total, total, 0);
-        self.logger.report_json()
+        self.logger.report_json(false)
     }
 
     /// Get the underlying logger for additional customization.
@@ -809,6 +853,7 @@ impl EvalSnapshot {
                         MatchSource::Pack => "pack".to_string(),
                         MatchSource::ConfigOverride => "config".to_string(),
                         MatchSource::HeredocAst => "heredoc".to_string(),
+                        MatchSource::Heuristic => "heuristic".to_string(),
                         MatchSource::LegacyPattern => "legacy".to_string(),
                     });
                     // Truncate reason to ~100 chars, but safely handle UTF-8 boundaries
@@ -109,6 +109,41 @@ impl AllowOnceEntry {
         }
     }
 
+    /// Mint a single-use approval for `command` ahead of time (`dcg approve`).
+    ///
+    /// Unlike [`Self::from_pending`] there is no prior denial: the token is
+    /// derived from the command and scope, and the entry is consumed the
+    /// first time the exact command arrives in hook mode.
+    #[must_use]
+    pub fn approval(
+        command: &str,
+        now: DateTime<Utc>,
+        ttl: Duration,
+        scope_kind: AllowOnceScopeKind,
+        scope_path: &str,
+        reason: &str,
+        redaction: &RedactionConfig,
+    ) -> Self {
+        let created_at = format_timestamp(now);
+        let full_hash = compute_full_hash(&created_at, scope_path, command);
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            source_short_code: short_code_from_hash(&full_hash),
+            source_full_hash: full_hash,
+            expires_at: format_timestamp(now + ttl),
+            created_at,
+            scope_kind,
+            scope_path: scope_path.to_string(),
+            command_raw: command.to_string(),
+            command_redacted: redact_for_pending(command, redaction),
+            reason: reason.to_string(),
+            single_use: true,
+            consumed_at: None,
+            force_allow_config: false,
+        }
+    }
+
     #[must_use]
     pub const fn is_consumed(&self) -> bool {
         self.consumed_at.is_some()
@@ -1513,6 +1548,79 @@ mod tests {
         assert!(second.is_none());
     }
 
+    #[test]
+    fn test_approval_is_consumed_once_and_logged() {
+        let dir = TempDir::new().expect("tempdir");
+        let store = AllowOnceStore::new(dir.path().join("allow_once.jsonl"));
+        let log_path = dir.path().join("dcg.log");
+        let now = DateTime::parse_from_rfc3339("2026-01-10T06:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let redaction = redaction_config();
+
+        let entry = AllowOnceEntry::approval(
+            "git reset --hard",
+            now,
+            Duration::minutes(30),
+            AllowOnceScopeKind::Cwd,
+            "/repo",
+            "incident cleanup",
+            &redaction,
+        );
+        assert!(entry.single_use);
+        assert_eq!(entry.source_short_code.len(), 5);
+        assert_eq!(entry.expires_at, "2026-01-10T07:00:00Z");
+        store.add_entry(&entry, now).unwrap();
+
+        let log_file = log_path.to_string_lossy().to_string();
+        let audit = AllowOnceAuditConfig {
+            log_file: &log_file,
+            format: AllowOnceLogFormat::Json,
+            redaction: &redaction,
+        };
+        let cwd = Path::new("/repo");
+        let first = store
+            .match_command("git reset --hard", cwd, now, Some(&audit))
+            .unwrap()
+            .expect("approval should allow the first run");
+        assert!(first.is_consumed());
+
+        let second = store
+            .match_command("git reset --hard", cwd, now, Some(&audit))
+            .unwrap();
+        assert!(second.is_none(), "approval must not be reusable");
+
+        let log = std::fs::read_to_string(&log_path).unwrap();
+        assert!(log.contains("entry_consumed"), "log: {log}");
+        assert!(log.contains(&entry.source_short_code));
+    }
+
+    #[test]
+    fn test_approval_expires() {
+        let dir = TempDir::new().expect("tempdir");
+        let store = AllowOnceStore::new(dir.path().join("allow_once.jsonl"));
+        let now = DateTime::parse_from_rfc3339("2026-01-10T06:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let entry = AllowOnceEntry::approval(
+            "git reset --hard",
+            now,
+            Duration::minutes(5),
+            AllowOnceScopeKind::Cwd,
+            "/repo",
+            "",
+            &redaction_config(),
+        );
+        store.add_entry(&entry, now).unwrap();
+
+        let later = now + Duration::minutes(6);
+        let matched = store
+            .match_command("git reset --hard", Path::new("/repo"), later, None)
+            .unwrap();
+        assert!(matched.is_none());
+    }
+
     #[test]
     fn test_allow_once_project_scope_matches_subdir() {
         let dir = TempDir::new().expect("tempdir");
@@ -0,0 +1,372 @@
+//! Effective policy export for `dcg policy export`.
+//!
+//! Serializes the fully-resolved policy (enabled packs, per-rule decision
+//! modes after `[policy]` overrides, config overrides, allowlist entries
+//! across layers, project-scoped pack settings, and the active agent profile)
+//! into one document that teams can commit and review.
+//!
+//! The output is deterministic: packs and rules follow evaluation order, maps
+//! are sorted, and nothing machine-specific (timestamps, absolute allowlist
+//! paths) is included, so diffs only show real policy changes.
+
+use crate::allowlist::{AllowEntry, AllowSelector, LayeredAllowlist};
+use crate::config::{AgentProfile, Config, OverridesConfig, PolicyMode, TrustLevel};
+use crate::packs::{Pack, REGISTRY};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Schema version for the policy export document.
+pub const POLICY_EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// The effective policy, as emitted by `dcg policy export`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PolicyExport {
+    pub schema_version: u32,
+    /// dcg version that resolved this policy (rule sets change between releases).
+    pub dcg_version: String,
+    /// `[policy]` settings as configured.
+    pub policy: ExportedPolicySettings,
+    /// Agent profile in effect.
+    pub profile: ExportedProfile,
+    /// Enabled and disabled packs.
+    pub packs: ExportedPacks,
+    /// Every named destructive rule in the enabled packs with its effective mode.
+    pub rules: Vec<ExportedRule>,
+    /// `[overrides]` from the config.
+    pub overrides: OverridesConfig,
+    /// Allowlist entries, in precedence order (project > user > system).
+    pub allowlist: Vec<ExportedAllowEntry>,
+    /// Project-scoped pack settings (`[projects."<path>"]`), sorted by path.
+    pub projects: Vec<ExportedProject>,
+}
+
+/// `[policy]` settings, with maps sorted for stable output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedPolicySettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_mode: Option<PolicyMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observe_until: Option<String>,
+    pub packs: BTreeMap<String, PolicyMode>,
+    pub rules: BTreeMap<String, PolicyMode>,
+}
+
+/// The agent profile that applies to this export.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedProfile {
+    /// Agent key the profile was resolved for (e.g. `claude-code`, `unknown`).
+    pub agent: String,
+    pub trust_level: TrustLevel,
+    pub disabled_packs: Vec<String>,
+    pub extra_packs: Vec<String>,
+    pub additional_allowlist: Vec<String>,
+    pub disabled_allowlist: bool,
+}
+
+/// Pack selection after category expansion.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedPacks {
+    /// Enabled pack IDs in evaluation order, external packs last.
+    pub enabled: Vec<String>,
+    /// Explicitly disabled pack IDs, sorted.
+    pub disabled: Vec<String>,
+    /// `custom_paths` globs for external packs, as configured.
+    pub custom_paths: Vec<String>,
+}
+
+/// One destructive rule and the decision it produces.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedRule {
+    pub rule_id: String,
+    pub severity: String,
+    /// Effective decision mode (`deny`, `warn`, or `log`).
+    pub mode: String,
+}
+
+/// One allowlist entry.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedAllowEntry {
+    pub layer: String,
+    /// Selector kind (`rule`, `exact_command`, `command_prefix`, `pattern`).
+    pub kind: String,
+    pub value: String,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub conditions: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub environments: Vec<String>,
+}
+
+/// Pack settings scoped to a project path.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedProject {
+    pub path: String,
+    /// Enabled pack IDs in evaluation order; absent when the project does
+    /// not override `[packs]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_packs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<OverridesConfig>,
+}
+
+/// Resolve the effective policy for `config`, `allowlists`, and the profile of
+/// `agent_key`.
+#[must_use]
+pub fn build_policy_export(
+    config: &Config,
+    allowlists: &LayeredAllowlist,
+    agent_key: &str,
+) -> PolicyExport {
+    let policy = config.policy();
+    let external_store = crate::packs::load_external_packs(&config.packs.expand_custom_paths());
+    let mut external_ids: Vec<&String> = external_store.pack_ids().collect();
+    external_ids.sort();
+
+    let mut enabled = REGISTRY
+        .expand_enabled_ordered_with(&config.packs.enabled_pack_ids(), &config.packs.pack_order);
+    enabled.extend(external_ids.iter().map(|id| (*id).clone()));
+
+    let rules = enabled
+        .iter()
+        .filter_map(|id| {
+            REGISTRY
+                .get(id)
+                .or_else(|| external_store.get(id))
+                .map(|pack| (id, pack))
+        })
+        .flat_map(|(id, pack)| pack_rules(id, pack, config))
+        .collect();
+
+    let mut disabled = config.packs.disabled.clone();
+    disabled.sort();
+
+    let profile = config.agents.profile_for(agent_key);
+
+    let mut projects: Vec<ExportedProject> = config
+        .projects
+        .iter()
+        .map(|(path, project)| ExportedProject {
+            path: path.clone(),
+            enabled_packs: project.packs.as_ref().map(|packs| {
+                // A project's own ordering wins, as when its layer is merged.
+                let pack_order = if packs.pack_order.is_empty() {
+                    &config.packs.pack_order
+                } else {
+                    &packs.pack_order
+                };
+                REGISTRY.expand_enabled_ordered_with(&packs.enabled_pack_ids(), pack_order)
+            }),
+            overrides: project.overrides.clone(),
+        })
+        .collect();
+    projects.sort_by(|a, b| a.path.cmp(&b.path));
+
+    PolicyExport {
+        schema_version: POLICY_EXPORT_SCHEMA_VERSION,
+        dcg_version: env!("CARGO_PKG_VERSION").to_string(),
+        policy: ExportedPolicySettings {
+            default_mode: policy.default_mode,
+            observe_until: policy.observe_until.as_ref().map(|until| until.to_string()),
+            packs: policy.packs.clone().into_iter().collect(),
+            rules: policy.rules.clone().into_iter().collect(),
+        },
+        profile: export_profile(agent_key, profile),
+        packs: ExportedPacks {
+            enabled,
+            disabled,
+            custom_paths: config.packs.custom_paths.clone(),
+        },
+        rules,
+        overrides: config.overrides.clone(),
+        allowlist: allowlists
+            .layers
+            .iter()
+            .flat_map(|loaded| {
+                loaded
+                    .file
+                    .entries
+                    .iter()
+                    .map(|entry| export_allow_entry(loaded.layer.label(), entry))
+            })
+            .collect(),
+        projects,
+    }
+}
+
+fn pack_rules(pack_id: &str, pack: &Pack, config: &Config) -> Vec<ExportedRule> {
+    pack.destructive_patterns
+        .iter()
+        .filter_map(|pattern| {
+            let name = pattern.name?;
+            let mode =
+                config
+                    .policy()
+                    .resolve_mode(Some(pack_id), Some(name), Some(pattern.severity));
+            Some(ExportedRule {
+                rule_id: format!("{pack_id}:{name}"),
+                severity: pattern.severity.label().to_string(),
+                mode: mode.label().to_string(),
+            })
+        })
+        .collect()
+}
+
+fn export_profile(agent_key: &str, profile: &AgentProfile) -> ExportedProfile {
+    ExportedProfile {
+        agent: agent_key.to_string(),
+        trust_level: profile.trust_level,
+        disabled_packs: profile.disabled_packs.clone(),
+        extra_packs: profile.extra_packs.clone(),
+        additional_allowlist: profile.additional_allowlist.clone(),
+        disabled_allowlist: profile.disabled_allowlist,
+    }
+}
+
+fn export_allow_entry(layer: &str, entry: &AllowEntry) -> ExportedAllowEntry {
+    let value = match &entry.selector {
+        AllowSelector::Rule(rule_id) => rule_id.to_string(),
+        AllowSelector::ExactCommand(value)
+        | AllowSelector::CommandPrefix(value)
+        | AllowSelector::RegexPattern(value) => value.clone(),
+    };
+    ExportedAllowEntry {
+        layer: layer.to_string(),
+        kind: entry.selector.kind_label().to_string(),
+        value,
+        reason: entry.reason.clone(),
+        expires_at: entry.expires_at.clone(),
+        ttl: entry.ttl.clone(),
+        paths: entry.paths.clone(),
+        conditions: entry.conditions.clone().into_iter().collect(),
+        environments: entry.environments.clone(),
+    }
+}
+
+impl PolicyExport {
+    /// Render as TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value cannot be represented in TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_from_toml(toml: &str) -> Config {
+        toml::from_str(toml).expect("test config should parse")
+    }
+
+    #[test]
+    fn rules_reflect_policy_overrides() {
+        let config = config_from_toml(
+            r#"
+[packs]
+enabled = ["core.git"]
+
+[policy.rules]
+"core.git:push-force-long" = "warn"
+"#,
+        );
+        let export = build_policy_export(&config, &LayeredAllowlist::default(), "unknown");
+
+        let mode_of = |rule_id: &str| {
+            export
+                .rules
+                .iter()
+                .find(|rule| rule.rule_id == rule_id)
+                .map(|rule| rule.mode.as_str())
+        };
+        assert_eq!(mode_of("core.git:push-force-long"), Some("warn"));
+        assert_eq!(mode_of("core.git:reset-hard"), Some("deny"));
+        assert!(export.packs.enabled.iter().any(|id| id == "core.git"));
+        assert_eq!(
+            export.policy.rules.get("core.git:push-force-long"),
+            Some(&PolicyMode::Warn)
+        );
+    }
+
+    #[test]
+    fn enabled_packs_follow_pack_order() {
+        let config = config_from_toml(
+            r#"
+[packs]
+enabled = ["core.git", "containers.docker", "database.postgresql"]
+pack_order = ["database.postgresql", "containers"]
+
+[projects."/srv/app"]
+packs = { enabled = ["containers.docker", "database.postgresql"] }
+"#,
+        );
+        let export = build_policy_export(&config, &LayeredAllowlist::default(), "unknown");
+
+        assert_eq!(
+            export.packs.enabled[..2],
+            ["database.postgresql", "containers.docker"]
+        );
+        assert_eq!(
+            export.projects[0]
+                .enabled_packs
+                .as_deref()
+                .map(|ids| &ids[..2]),
+            Some(
+                &[
+                    "database.postgresql".to_string(),
+                    "containers.docker".to_string()
+                ][..]
+            )
+        );
+    }
+
+    #[test]
+    fn export_is_deterministic_and_renders_as_toml() {
+        let config = config_from_toml(
+            r#"
+[packs]
+enabled = ["containers.docker", "database.postgresql"]
+disabled = ["strict_git", "core.git"]
+
+[policy.packs]
+"containers.docker" = "warn"
+"database.postgresql" = "log"
+
+[projects."/srv/b"]
+packs = { enabled = ["kubernetes"] }
+
+[projects."/srv/a"]
+packs = { enabled = ["cloud.aws"] }
+"#,
+        );
+        let first = build_policy_export(&config, &LayeredAllowlist::default(), "unknown");
+        let second = build_policy_export(&config, &LayeredAllowlist::default(), "unknown");
+
+        let toml = first.to_toml().expect("export should render as TOML");
+        assert_eq!(toml, second.to_toml().unwrap());
+        assert_eq!(first.packs.disabled, ["core.git", "strict_git"]);
+        assert_eq!(
+            first
+                .projects
+                .iter()
+                .map(|p| p.path.as_str())
+                .collect::<Vec<_>>(),
+            ["/srv/a", "/srv/b"]
+        );
+
+        let parsed: toml::Value = toml::from_str(&toml).expect("rendered TOML should parse");
+        assert_eq!(
+            parsed["schema_version"].as_integer(),
+            Some(i64::from(POLICY_EXPORT_SCHEMA_VERSION))
+        );
+        assert!(parsed["rules"].as_array().is_some_and(|r| !r.is_empty()));
+    }
+}
@@ -0,0 +1,173 @@
+//! Prometheus Pushgateway client (`dcg stats --push-gateway`).
+//!
+//! Short-lived CI jobs cannot be scraped, so they push their metrics instead:
+//! the Prometheus text from [`crate::stats::format_stats_prometheus`] is
+//! `POST`ed to `<gateway>/metrics/job/<job>[/instance/<instance>]`, which
+//! replaces the metrics of the same name in that grouping.
+//!
+//! Like `dcg serve --http`, this speaks just enough HTTP/1.1 on top of
+//! `std::net` to avoid new dependencies, and is only compiled with the
+//! `http-server` cargo feature. Only plain `http://` gateways are supported.
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use base64::Engine as _;
+
+/// Content type of the Prometheus text exposition format.
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Connect, read, and write timeout for the push.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A parsed `http://host[:port][/prefix]` gateway URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GatewayUrl {
+    /// `host:port`, for connecting and the `Host` header.
+    authority: String,
+    /// Path prefix without a trailing slash (often empty).
+    prefix: String,
+}
+
+impl GatewayUrl {
+    fn parse(url: &str) -> Result<Self, String> {
+        let Some(rest) = url.strip_prefix("http://") else {
+            return Err(format!(
+                "unsupported push gateway URL `{url}` (expected http://host:port)"
+            ));
+        };
+        let (authority, prefix) = rest.split_once('/').unwrap_or((rest, ""));
+        if authority.is_empty() {
+            return Err(format!("push gateway URL `{url}` has no host"));
+        }
+        let authority = if authority
+            .rsplit_once(':')
+            .is_some_and(|(_, port)| !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()))
+        {
+            authority.to_string()
+        } else {
+            format!("{authority}:80")
+        };
+        let prefix = prefix.trim_end_matches('/');
+        let prefix = if prefix.is_empty() {
+            String::new()
+        } else {
+            format!("/{prefix}")
+        };
+        Ok(Self { authority, prefix })
+    }
+}
+
+/// Path segment for one grouping label. Values that are not plain URL-safe
+/// text use the Pushgateway's `@base64` encoding.
+fn label_segment(name: &str, value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~'));
+    if plain {
+        format!("/{name}/{value}")
+    } else {
+        let encoded = base64::engine::general_purpose::URL_SAFE.encode(value);
+        let encoded = if encoded.is_empty() {
+            "=".to_string()
+        } else {
+            encoded
+        };
+        format!("/{name}@base64/{encoded}")
+    }
+}
+
+/// Request path for `job` and optional `instance`.
+#[must_use]
+pub fn grouping_path(job: &str, instance: Option<&str>) -> String {
+    let mut path = format!("/metrics{}", label_segment("job", job));
+    if let Some(instance) = instance {
+        path.push_str(&label_segment("instance", instance));
+    }
+    path
+}
+
+/// Push `metrics` (Prometheus text format) to the gateway at `url`, grouped
+/// under `job` and, if given, `instance`.
+///
+/// # Errors
+///
+/// Returns an error if the URL is not `http://`, the gateway cannot be
+/// reached, or it answers with a non-2xx status.
+pub fn push(
+    url: &str,
+    job: &str,
+    instance: Option<&str>,
+    metrics: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let gateway = GatewayUrl::parse(url)?;
+    let path = format!("{}{}", gateway.prefix, grouping_path(job, instance));
+
+    let addr = gateway
+        .authority
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| format!("push gateway `{url}` did not resolve"))?;
+    let mut stream = TcpStream::connect_timeout(&addr, TIMEOUT)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: {}\r\nContent-Type: {CONTENT_TYPE}\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{metrics}",
+        gateway.authority,
+        metrics.len()
+    )?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let status: u16 = response
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| format!("push gateway `{url}` sent a malformed response"))?;
+    if !(200..300).contains(&status) {
+        let body = response
+            .split_once("\r\n\r\n")
+            .map_or("", |(_, body)| body.trim());
+        return Err(format!("push gateway `{url}` rejected the push: HTTP {status} {body}").into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_gateway_urls() {
+        assert_eq!(
+            GatewayUrl::parse("http://pushgw:9091").unwrap(),
+            GatewayUrl {
+                authority: "pushgw:9091".to_string(),
+                prefix: String::new(),
+            }
+        );
+        assert_eq!(
+            GatewayUrl::parse("http://pushgw/prom/").unwrap(),
+            GatewayUrl {
+                authority: "pushgw:80".to_string(),
+                prefix: "/prom".to_string(),
+            }
+        );
+        assert!(GatewayUrl::parse("https://pushgw:9091").is_err());
+        assert!(GatewayUrl::parse("http:///metrics").is_err());
+    }
+
+    #[test]
+    fn grouping_path_encodes_unsafe_label_values() {
+        assert_eq!(grouping_path("dcg-ci", None), "/metrics/job/dcg-ci");
+        assert_eq!(
+            grouping_path("dcg-ci", Some("runner/7")),
+            "/metrics/job/dcg-ci/instance@base64/cnVubmVyLzc="
+        );
+        assert_eq!(grouping_path("", None), "/metrics/job@base64/=");
+    }
+}
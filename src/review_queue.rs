@@ -0,0 +1,420 @@
+//! Review queue for denied commands.
+//!
+//! When `[review_queue] enabled = true`, hook-mode denials are still blocked
+//! but are also appended to a pending-approvals file. A human later runs
+//! `dcg review` to approve (adds an exact-command allowlist entry) or reject
+//! each queued command, turning dcg into an asynchronous approval gate.
+//!
+//! The queue is a JSONL file with the same properties as the pending
+//! exceptions store:
+//! - Append-friendly for concurrent hooks (exclusive file lock)
+//! - Fail-open on parse errors (corrupt lines are skipped)
+
+use chrono::{DateTime, Utc};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::resolve_config_path_value;
+use crate::fingerprint::command_fingerprint;
+
+/// Environment override for the review queue file path.
+pub const ENV_REVIEW_QUEUE_PATH: &str = "DCG_REVIEW_QUEUE_PATH";
+
+const REVIEW_QUEUE_FILE: &str = "review_queue.jsonl";
+const SCHEMA_VERSION: u32 = 1;
+const ID_LEN: usize = 8;
+
+/// Review queue configuration (`[review_queue]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewQueueConfig {
+    /// Queue hook-mode denials for human review.
+    pub enabled: bool,
+    /// Optional queue file path override.
+    pub path: Option<String>,
+}
+
+/// Review state of a queued command.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A queued denied command (JSONL line).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReviewQueueEntry {
+    pub schema_version: u32,
+    pub id: String,
+    /// Canonical command fingerprint, used to avoid queueing duplicates.
+    pub fingerprint: String,
+    pub created_at: String,
+    pub cwd: String,
+    pub command: String,
+    pub reason: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    /// Allowlist command that would permit this command.
+    pub allowlist_suggestion: String,
+    pub status: ReviewStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<String>,
+}
+
+impl ReviewQueueEntry {
+    #[must_use]
+    pub fn new(
+        timestamp: DateTime<Utc>,
+        cwd: &str,
+        command: &str,
+        reason: &str,
+        rule_id: Option<&str>,
+    ) -> Self {
+        let created_at = format_timestamp(timestamp);
+        let id = compute_id(&created_at, cwd, command);
+        let escaped = command.replace('"', "\\\"");
+
+        Self {
+            schema_version: SCHEMA_VERSION,
+            id,
+            fingerprint: command_fingerprint(command),
+            created_at,
+            cwd: cwd.to_string(),
+            command: command.to_string(),
+            reason: reason.to_string(),
+            rule_id: rule_id.map(String::from),
+            allowlist_suggestion: format!(
+                "dcg allowlist add-command \"{escaped}\" --reason \"approved in review\""
+            ),
+            status: ReviewStatus::Pending,
+            resolved_at: None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.status == ReviewStatus::Pending
+    }
+}
+
+/// Review queue store wrapper.
+#[derive(Debug, Clone)]
+pub struct ReviewQueueStore {
+    path: PathBuf,
+}
+
+impl ReviewQueueStore {
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Resolve the queue path (env override, config `path`, or ~/.config/dcg/..).
+    #[must_use]
+    pub fn default_path(config: &ReviewQueueConfig, cwd: Option<&Path>) -> PathBuf {
+        let configured = env::var(ENV_REVIEW_QUEUE_PATH)
+            .ok()
+            .or_else(|| config.path.clone());
+        if let Some(value) = configured {
+            if let Some(path) = resolve_config_path_value(&value, cwd) {
+                return path;
+            }
+        }
+
+        // Check XDG-style path first (~/.config/dcg/), then platform-native
+        if let Some(home) = dirs::home_dir() {
+            let xdg_dir = home.join(".config").join("dcg");
+            if xdg_dir.exists() {
+                return xdg_dir.join(REVIEW_QUEUE_FILE);
+            }
+        }
+
+        dirs::config_dir()
+            .unwrap_or_else(|| dirs::home_dir().unwrap_or_default().join(".config"))
+            .join("dcg")
+            .join(REVIEW_QUEUE_FILE)
+    }
+
+    /// Queue a denied command for review.
+    ///
+    /// If the same command (by fingerprint) is already pending for `cwd`, the
+    /// existing entry is returned instead of appending a duplicate.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O errors encountered while opening, locking, or writing the queue file.
+    pub fn enqueue(
+        &self,
+        command: &str,
+        cwd: &str,
+        reason: &str,
+        rule_id: Option<&str>,
+    ) -> io::Result<ReviewQueueEntry> {
+        let entry = ReviewQueueEntry::new(Utc::now(), cwd, command, reason, rule_id);
+
+        let mut file = open_locked(&self.path)?;
+        let (entries, _) = read_entries(&mut file);
+        if let Some(existing) = entries
+            .into_iter()
+            .find(|e| e.is_pending() && e.cwd == entry.cwd && e.fingerprint == entry.fingerprint)
+        {
+            return Ok(existing);
+        }
+
+        append_entry(&mut file, &entry)?;
+        Ok(entry)
+    }
+
+    /// Load all queue entries (pending and resolved).
+    ///
+    /// Returns the entries plus the number of unparseable lines skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O errors encountered while opening or locking the queue file.
+    pub fn load(&self) -> io::Result<(Vec<ReviewQueueEntry>, usize)> {
+        let mut file = open_locked(&self.path)?;
+        Ok(read_entries(&mut file))
+    }
+
+    /// Load entries still awaiting review, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O errors encountered while opening or locking the queue file.
+    pub fn pending(&self) -> io::Result<Vec<ReviewQueueEntry>> {
+        let (entries, _) = self.load()?;
+        Ok(entries
+            .into_iter()
+            .filter(ReviewQueueEntry::is_pending)
+            .collect())
+    }
+
+    /// Mark a pending entry as approved or rejected.
+    ///
+    /// Returns `false` if no pending entry has that id.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O errors encountered while opening, locking, or rewriting the queue file.
+    pub fn resolve(&self, id: &str, status: ReviewStatus, now: DateTime<Utc>) -> io::Result<bool> {
+        let mut file = open_locked(&self.path)?;
+        let (mut entries, _) = read_entries(&mut file);
+
+        let Some(entry) = entries.iter_mut().find(|e| e.id == id && e.is_pending()) else {
+            return Ok(false);
+        };
+        entry.status = status;
+        entry.resolved_at = Some(format_timestamp(now));
+
+        rewrite_entries(&mut file, &entries)?;
+        Ok(true)
+    }
+
+    /// Drop resolved entries, keeping only those still pending.
+    ///
+    /// Returns the number of entries removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O errors encountered while opening, locking, or rewriting the queue file.
+    pub fn prune_resolved(&self) -> io::Result<usize> {
+        let mut file = open_locked(&self.path)?;
+        let (entries, _) = read_entries(&mut file);
+        let before = entries.len();
+        let pending: Vec<_> = entries
+            .into_iter()
+            .filter(ReviewQueueEntry::is_pending)
+            .collect();
+        let removed = before - pending.len();
+        if removed > 0 {
+            rewrite_entries(&mut file, &pending)?;
+        }
+        Ok(removed)
+    }
+}
+
+fn open_locked(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(path)?;
+    file.lock_exclusive()?;
+    Ok(file)
+}
+
+fn read_entries(file: &mut File) -> (Vec<ReviewQueueEntry>, usize) {
+    let mut entries = Vec::new();
+    let mut parse_errors = 0;
+
+    if file.seek(SeekFrom::Start(0)).is_err() {
+        return (entries, 1);
+    }
+    for line in BufReader::new(file).lines() {
+        let Ok(line) = line else {
+            parse_errors += 1;
+            continue;
+        };
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<ReviewQueueEntry>(trimmed) {
+            Ok(entry) => entries.push(entry),
+            Err(_) => parse_errors += 1,
+        }
+    }
+
+    (entries, parse_errors)
+}
+
+fn rewrite_entries(file: &mut File, entries: &[ReviewQueueEntry]) -> io::Result<()> {
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    for entry in entries {
+        let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+        file.write_all(line.as_bytes())?;
+        file.write_all(b"\n")?;
+    }
+    file.sync_data()?;
+    Ok(())
+}
+
+fn append_entry(file: &mut File, entry: &ReviewQueueEntry) -> io::Result<()> {
+    file.seek(SeekFrom::End(0))?;
+    let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+    file.write_all(line.as_bytes())?;
+    file.write_all(b"\n")?;
+    file.sync_data()?;
+    Ok(())
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+fn compute_id(created_at: &str, cwd: &str, command: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(created_at.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(cwd.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(command.as_bytes());
+    let digest = hasher.finalize();
+
+    let mut id = String::with_capacity(ID_LEN);
+    for byte in digest.iter().take(ID_LEN / 2) {
+        let _ = write!(id, "{byte:02x}");
+    }
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn store(dir: &TempDir) -> ReviewQueueStore {
+        ReviewQueueStore::new(dir.path().join("review_queue.jsonl"))
+    }
+
+    #[test]
+    fn enqueue_appends_pending_entry_with_suggestion() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        let entry = store
+            .enqueue(
+                "git reset --hard",
+                "/repo",
+                "destroys uncommitted changes",
+                Some("core.git:reset-hard"),
+            )
+            .unwrap();
+
+        assert_eq!(entry.id.len(), ID_LEN);
+        assert_eq!(entry.status, ReviewStatus::Pending);
+        assert!(entry.allowlist_suggestion.contains("add-command"));
+        assert!(entry.allowlist_suggestion.contains("git reset --hard"));
+
+        let pending = store.pending().unwrap();
+        assert_eq!(pending, vec![entry]);
+    }
+
+    #[test]
+    fn enqueue_deduplicates_pending_commands() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        let first = store
+            .enqueue("git reset --hard", "/repo", "r", None)
+            .unwrap();
+        let second = store
+            .enqueue("git  reset --hard", "/repo", "r", None)
+            .unwrap();
+        assert_eq!(first.id, second.id);
+
+        store
+            .enqueue("git reset --hard", "/other", "r", None)
+            .unwrap();
+        assert_eq!(store.pending().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn resolve_marks_entry_and_removes_it_from_pending() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+        let entry = store.enqueue("rm -rf build", "/repo", "r", None).unwrap();
+
+        assert!(
+            store
+                .resolve(&entry.id, ReviewStatus::Approved, Utc::now())
+                .unwrap()
+        );
+        assert!(
+            !store
+                .resolve(&entry.id, ReviewStatus::Rejected, Utc::now())
+                .unwrap()
+        );
+        assert!(store.pending().unwrap().is_empty());
+
+        let (all, _) = store.load().unwrap();
+        assert_eq!(all[0].status, ReviewStatus::Approved);
+        assert!(all[0].resolved_at.is_some());
+
+        assert_eq!(store.prune_resolved().unwrap(), 1);
+        assert!(store.load().unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn corrupt_lines_are_skipped() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+        store.enqueue("rm -rf build", "/repo", "r", None).unwrap();
+        let mut file = OpenOptions::new().append(true).open(store.path()).unwrap();
+        writeln!(file, "not json").unwrap();
+
+        let (entries, parse_errors) = store.load().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(parse_errors, 1);
+    }
+}
@@ -10,9 +10,9 @@
 //!
 //! Reference: <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
 
-use crate::scan::{ScanDecision, ScanFinding, ScanReport, ScanSeverity};
+use crate::scan::{ScanDecision, ScanFinding, ScanReport, ScanSeverity, sort_findings};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// SARIF 2.1.0 schema URI.
 pub const SARIF_SCHEMA: &str = "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/sarif-2.1/schema/sarif-schema-2.1.0.json";
@@ -363,7 +363,7 @@ pub struct SarifInvocation {
 pub struct SarifPropertyBag {
     /// Additional properties.
     #[serde(flatten)]
-    pub properties: HashMap<String, serde_json::Value>,
+    pub properties: BTreeMap<String, serde_json::Value>,
 }
 
 impl SarifPropertyBag {
@@ -387,8 +387,8 @@ impl SarifReport {
     pub fn from_scan_report(report: &ScanReport) -> Self {
         let version = env!("CARGO_PKG_VERSION");
 
-        // Collect unique rules from findings
-        let mut rules_map: HashMap<String, SarifReportingDescriptor> = HashMap::new();
+        // Collect unique rules from findings, ordered by id so output is stable
+        let mut rules_map: BTreeMap<String, SarifReportingDescriptor> = BTreeMap::new();
 
         for finding in &report.findings {
             let rule_id = finding
@@ -416,13 +416,15 @@ impl SarifReport {
 
         let rules: Vec<_> = rules_map.into_values().collect();
 
-        // Convert findings to results
-        let results: Vec<_> = report
+        // Convert findings to results, in the same order as the JSON report
+        let mut reported: Vec<ScanFinding> = report
             .findings
             .iter()
             .filter(|f| f.decision != ScanDecision::Allow) // Only include warns/denies
-            .map(finding_to_result)
+            .cloned()
             .collect();
+        sort_findings(&mut reported);
+        let results: Vec<_> = reported.iter().map(finding_to_result).collect();
 
         Self {
             schema: SARIF_SCHEMA.to_string(),
@@ -461,11 +463,7 @@ fn finding_to_result(finding: &ScanFinding) -> SarifResult {
         .clone()
         .unwrap_or_else(|| finding.extractor_id.clone());
 
-    let level = match finding.decision {
-        ScanDecision::Deny => SarifLevel::Error,
-        ScanDecision::Warn => SarifLevel::Warning,
-        ScanDecision::Allow => SarifLevel::Note,
-    };
+    let level = finding.severity.into();
 
     let message = finding.reason.clone().unwrap_or_else(|| {
         format!(
@@ -478,6 +476,7 @@ fn finding_to_result(finding: &ScanFinding) -> SarifResult {
     properties.insert("extractor_id", &finding.extractor_id);
     properties.insert("extracted_command", &finding.extracted_command);
     properties.insert("decision", format!("{:?}", finding.decision));
+    properties.insert("reason_code", finding.reason_code);
 
     // Build location
     let location = SarifLocation {
@@ -545,11 +544,17 @@ mod tests {
             col: Some(5),
             extractor_id: "dockerfile.run".to_string(),
             extracted_command: "rm -rf /".to_string(),
+            normalized_command: None,
+            metadata: None,
             decision,
             severity,
             rule_id: Some("core.filesystem:recursive-delete-root".to_string()),
             reason: Some("Recursively deletes the entire filesystem".to_string()),
+            reason_code: crate::packs::ReasonCode::Unspecified,
             suggestion: Some("Use a specific path instead of root".to_string()),
+            confidence: None,
+            suppression: None,
+            owner: None,
         }
     }
 
@@ -559,17 +564,24 @@ mod tests {
             summary: ScanSummary {
                 files_scanned: 5,
                 files_skipped: 0,
+                skipped: crate::scan::ScanSkipCounts::default(),
                 commands_extracted: 2,
                 findings_total: 2,
                 decisions: crate::scan::ScanDecisionCounts::default(),
                 severities: crate::scan::ScanSeverityCounts::default(),
                 max_findings_reached: false,
+                risk_score: 13.0,
+                files_dcgignored: 0,
+                findings_suppressed: 0,
+                findings_scan_allowed: 0,
+                findings_baselined: 0,
                 elapsed_ms: None,
             },
             findings: vec![
                 mock_finding(ScanDecision::Deny, ScanSeverity::Error),
                 mock_finding(ScanDecision::Warn, ScanSeverity::Warning),
             ],
+            suppressed: Vec::new(),
         }
     }
 
@@ -614,6 +626,61 @@ mod tests {
         assert_eq!(SarifLevel::from(ScanSeverity::Info), SarifLevel::Note);
     }
 
+    #[test]
+    fn test_sarif_result_level_follows_severity() {
+        let mut report = mock_report();
+        report.findings = vec![mock_finding(ScanDecision::Warn, ScanSeverity::Info)];
+        let sarif = SarifReport::from_scan_report(&report);
+
+        assert_eq!(sarif.runs[0].results[0].level, SarifLevel::Note);
+    }
+
+    #[test]
+    fn test_sarif_rules_and_results_are_sorted() {
+        let finding = |file: &str, line: usize, rule_id: &str| ScanFinding {
+            file: file.to_string(),
+            line,
+            rule_id: Some(rule_id.to_string()),
+            ..mock_finding(ScanDecision::Deny, ScanSeverity::Error)
+        };
+        let mut report = mock_report();
+        report.findings = vec![
+            finding("b.sh", 1, "core.git:reset-hard"),
+            finding("a.sh", 9, "core.filesystem:rm-rf-root-home"),
+            finding("a.sh", 2, "core.git:clean-force"),
+        ];
+        let sarif = SarifReport::from_scan_report(&report);
+
+        let rule_ids: Vec<_> = sarif.runs[0]
+            .tool
+            .driver
+            .rules
+            .iter()
+            .map(|r| r.id.as_str())
+            .collect();
+        assert_eq!(
+            rule_ids,
+            vec![
+                "core.filesystem:rm-rf-root-home",
+                "core.git:clean-force",
+                "core.git:reset-hard"
+            ]
+        );
+        let result_rules: Vec<_> = sarif.runs[0]
+            .results
+            .iter()
+            .map(|r| r.rule_id.as_str())
+            .collect();
+        assert_eq!(
+            result_rules,
+            vec![
+                "core.git:clean-force",
+                "core.filesystem:rm-rf-root-home",
+                "core.git:reset-hard"
+            ]
+        );
+    }
+
     #[test]
     fn test_sarif_location_info() {
         let report = mock_report();
@@ -29,18 +29,66 @@
 
 use crate::config::{Config, HeredocSettings};
 use crate::evaluator::{
-    EvaluationDecision, MatchSource, PatternMatch, evaluate_command_with_pack_order_at_path,
+    EvaluationDecision, MatchSource, PatternMatch, ast_severity_to_pack_severity,
+    evaluate_command_in_scan_context, normalized_command_for_display, split_ast_rule_id,
 };
-use crate::packs::{DecisionMode, REGISTRY, Severity};
+use crate::packs::{DecisionMode, REGISTRY, ReasonCode, Severity};
 use crate::suggestions::{SuggestionKind, get_suggestion_by_kind};
 use clap::ValueEnum;
-use memchr::memmem;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
 pub const SCAN_SCHEMA_VERSION: u32 = 1;
 
+/// Errors returned by [`scan_paths`] and [`extract_paths`].
+#[derive(Debug)]
+pub enum ScanError {
+    /// Reading a scan input (e.g. `.dcgignore`) failed.
+    Io {
+        /// File that could not be read.
+        path: PathBuf,
+        /// Underlying I/O error.
+        source: std::io::Error,
+    },
+    /// Scan configuration is invalid (e.g. a malformed `.dcgignore` pattern).
+    Config {
+        /// Configuration file at fault.
+        path: PathBuf,
+        /// Human-readable description of the problem.
+        message: String,
+    },
+    /// A path cannot be scanned.
+    InvalidPath {
+        /// Offending path.
+        path: PathBuf,
+        /// Why the path was rejected.
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "Failed to read {}: {source}", path.display()),
+            Self::Config { path, message } => write!(f, "Invalid {}: {message}", path.display()),
+            Self::InvalidPath { path, reason } => {
+                write!(f, "Invalid scan path {}: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScanError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
 /// Project-level scan config for repo integrations (pre-commit/CI).
 ///
 /// Loaded from `.dcg/hooks.toml` (if present).
@@ -53,6 +101,9 @@ pub struct HooksToml {
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct HooksTomlScan {
     pub fail_on: Option<ScanFailOn>,
+    #[serde(default)]
+    pub fail_only_tags: Vec<String>,
+    pub fail_on_score: Option<f64>,
     pub format: Option<ScanFormat>,
     pub max_file_size: Option<u64>,
     pub max_findings: Option<usize>,
@@ -60,6 +111,8 @@ pub struct HooksTomlScan {
     pub truncate: Option<usize>,
     #[serde(default)]
     pub paths: HooksTomlScanPaths,
+    #[serde(default)]
+    pub allow: Vec<ScanAllowEntry>,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -68,6 +121,10 @@ pub struct HooksTomlScanPaths {
     pub include: Vec<String>,
     #[serde(default)]
     pub exclude: Vec<String>,
+    /// Apply [`DEFAULT_SCAN_EXCLUDES`] (default: true).
+    pub default_excludes: Option<bool>,
+    /// Honor `.gitignore` files while walking directories (default: true).
+    pub respect_gitignore: Option<bool>,
 }
 
 /// Parse `.dcg/hooks.toml` and return (typed config, warnings).
@@ -97,14 +154,22 @@ fn warn_unknown_hooks_toml_keys(value: &toml::Value, path: &str, warnings: &mut
         "" => &["scan"],
         "scan" => &[
             "fail_on",
+            "fail_only_tags",
+            "fail_on_score",
             "format",
             "max_file_size",
             "max_findings",
             "redact",
             "truncate",
             "paths",
+            "allow",
+        ],
+        "scan.paths" => &[
+            "include",
+            "exclude",
+            "default_excludes",
+            "respect_gitignore",
         ],
-        "scan.paths" => &["include", "exclude"],
         _ => &[],
     };
 
@@ -139,6 +204,14 @@ pub enum ScanFormat {
     Markdown,
     /// SARIF 2.1.0 output (for code scanning tools)
     Sarif,
+    /// CSV, one row per finding (for spreadsheet triage)
+    Csv,
+    /// Markdown tuned for GitHub Actions job summaries (`$GITHUB_STEP_SUMMARY`)
+    #[serde(rename = "github-summary")]
+    GithubSummary,
+    /// GitHub Actions workflow commands that annotate the PR diff inline
+    #[serde(rename = "github-annotations")]
+    GithubAnnotations,
 }
 
 /// Controls scan failure behavior (CI integration).
@@ -201,6 +274,16 @@ impl ScanSeverity {
             Self::Info => 1,
         }
     }
+
+    /// Weight used for the aggregate risk score (`--fail-on-score`).
+    #[must_use]
+    pub const fn risk_weight(&self) -> u32 {
+        match self {
+            Self::Error => 10,
+            Self::Warning => 3,
+            Self::Info => 1,
+        }
+    }
 }
 
 /// Extracted executable command from a file.
@@ -214,6 +297,16 @@ pub struct ExtractedCommand {
     pub command: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+    /// Inline `# dcg:ignore` comment attached to this command, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppression: Option<InlineSuppression>,
+}
+
+/// Inline suppression parsed from a `# dcg:ignore <rule_id> <reason>` comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InlineSuppression {
+    pub rule_id: String,
+    pub reason: String,
 }
 
 /// A scan finding produced by evaluating an extracted command.
@@ -225,14 +318,37 @@ pub struct ScanFinding {
     pub col: Option<usize>,
     pub extractor_id: String,
     pub extracted_command: String,
+    /// Normalized form the evaluator matched against (redacted/truncated like
+    /// `extracted_command`); `None` when normalization changed nothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalized_command: Option<String>,
+    /// Extractor-provided context for the command (e.g. the `language` of an
+    /// embedded heredoc script).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
     pub decision: ScanDecision,
     pub severity: ScanSeverity,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rule_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    /// Stable category for `reason`, for programmatic handling.
+    #[serde(default)]
+    pub reason_code: ReasonCode,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suggestion: Option<String>,
+    /// Match confidence (0.0 - 1.0, two decimals); `None` when no match span
+    /// was available, which the risk score treats as full confidence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Set when an inline `# dcg:ignore` comment suppressed this finding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suppression: Option<InlineSuppression>,
+    /// Owners from `--codeowners` (space-separated, e.g. `@org/infra @alice`).
+    /// Omitted without `--codeowners`; `null` when no rule owns the file.
+    #[allow(clippy::option_option)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<Option<String>>,
 }
 
 /// Counts of findings by decision.
@@ -251,16 +367,78 @@ pub struct ScanSeverityCounts {
     pub error: usize,
 }
 
+/// Why a file was skipped instead of scanned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanSkipReason {
+    /// Larger than `max_file_size_bytes`.
+    TooLarge,
+    /// Contains null bytes (images, compiled artifacts, UTF-16 text).
+    Binary,
+    /// Metadata or contents could not be read.
+    Unreadable,
+    /// Not a regular file, or no extractor handles this file type.
+    Excluded,
+}
+
+/// Breakdown of `files_skipped` by [`ScanSkipReason`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanSkipCounts {
+    pub too_large: usize,
+    pub binary: usize,
+    pub unreadable: usize,
+    pub excluded: usize,
+}
+
+impl ScanSkipCounts {
+    /// Count one skipped file.
+    pub const fn record(&mut self, reason: ScanSkipReason) {
+        match reason {
+            ScanSkipReason::TooLarge => self.too_large += 1,
+            ScanSkipReason::Binary => self.binary += 1,
+            ScanSkipReason::Unreadable => self.unreadable += 1,
+            ScanSkipReason::Excluded => self.excluded += 1,
+        }
+    }
+
+    /// Total number of skipped files.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.too_large + self.binary + self.unreadable + self.excluded
+    }
+}
+
 /// Summary statistics for a scan run.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanSummary {
     pub files_scanned: usize,
     pub files_skipped: usize,
+    /// Why each skipped file was skipped.
+    #[serde(default)]
+    pub skipped: ScanSkipCounts,
     pub commands_extracted: usize,
     pub findings_total: usize,
     pub decisions: ScanDecisionCounts,
     pub severities: ScanSeverityCounts,
     pub max_findings_reached: bool,
+    /// Aggregate risk score: sum of `severity weight × confidence` over all
+    /// findings (see [`risk_score`]).
+    #[serde(default)]
+    pub risk_score: f64,
+    /// Files dropped before scanning because they matched `.dcgignore`.
+    #[serde(default)]
+    pub files_dcgignored: usize,
+    /// Findings suppressed by inline `# dcg:ignore` comments. These are
+    /// listed separately and never fail the scan.
+    #[serde(default)]
+    pub findings_suppressed: usize,
+    /// Findings dropped by scan-only `--scan-allow` entries. These are
+    /// counted but not listed.
+    #[serde(default)]
+    pub findings_scan_allowed: usize,
+    /// Findings already recorded in the `--baseline` file. These are
+    /// counted but not listed and never fail the scan.
+    #[serde(default)]
+    pub findings_baselined: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub elapsed_ms: Option<u64>,
 }
@@ -271,6 +449,28 @@ pub struct ScanReport {
     pub schema_version: u32,
     pub summary: ScanSummary,
     pub findings: Vec<ScanFinding>,
+    /// Findings suppressed by inline `# dcg:ignore` comments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<ScanFinding>,
+}
+
+/// JSON Schema (Draft 2020-12) for [`ScanReport`], as printed by
+/// `dcg scan --print-schema`.
+///
+/// The `$id` embeds [`SCAN_SCHEMA_VERSION`] so consumers can pin to it.
+///
+/// # Panics
+///
+/// Only if the embedded schema file is not valid JSON, which tests rule out.
+#[must_use]
+pub fn scan_report_json_schema() -> serde_json::Value {
+    let mut schema: serde_json::Value =
+        serde_json::from_str(include_str!("../docs/json-schema/scan-results.json"))
+            .expect("docs/json-schema/scan-results.json is valid JSON");
+    schema["$id"] = serde_json::Value::String(format!(
+        "https://github.com/Dicklesworthstone/destructive_command_guard/docs/json-schema/scan-results-v{SCAN_SCHEMA_VERSION}.json"
+    ));
+    schema
 }
 
 /// In-memory scan configuration (CLI + defaults).
@@ -283,6 +483,113 @@ pub struct ScanOptions {
     pub redact: ScanRedactMode,
     /// Truncate extracted commands in output (chars). 0 disables truncation.
     pub truncate: usize,
+    /// Scan-only allowlist; matching findings are counted, not reported.
+    pub scan_allow: Vec<ScanAllowEntry>,
+    /// Extractor ids to run (see [`SCAN_EXTRACTORS`]); `None` runs them all.
+    pub extractors: Option<Vec<&'static str>>,
+    /// Only scan files matching one of these globs (relative to the scan
+    /// root). Empty includes everything.
+    pub include: Vec<String>,
+    /// Skip files, and prune directories, matching any of these globs
+    /// (relative to the scan root).
+    pub exclude: Vec<String>,
+    /// Skip paths ignored by `.gitignore` files found while walking
+    /// directories. Explicitly listed files are always scanned.
+    pub respect_gitignore: bool,
+    /// Files evaluated in parallel (0 = one per CPU). Requires the `rayon`
+    /// feature; otherwise files are always scanned one at a time. Reports are
+    /// identical for any value.
+    pub jobs: usize,
+}
+
+/// Exclude globs applied by `dcg scan` unless `--no-default-excludes` (or
+/// `scan.paths.default_excludes = false`) is given: VCS metadata, build
+/// output, and dependency trees.
+pub const DEFAULT_SCAN_EXCLUDES: &[&str] = &[
+    "**/.git/**",
+    "**/target/**",
+    "**/node_modules/**",
+    "**/.venv/**",
+];
+
+/// Scan-only allowlist entry: `<rule_id glob> in <path glob>`.
+///
+/// Example: `core.filesystem:* in tests/fixtures/**`. Unlike the runtime
+/// `LayeredAllowlist`, these entries never affect live hook decisions; they
+/// only keep known-dangerous fixtures from failing `dcg scan`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct ScanAllowEntry {
+    pub rule_glob: String,
+    pub path_glob: String,
+}
+
+impl ScanAllowEntry {
+    /// Parse `<rule_id glob> in <path glob>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either side is missing.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let Some((rule_glob, path_glob)) = spec.split_once(" in ") else {
+            return Err(format!(
+                "invalid scan allow entry `{spec}` (expected `<rule_id glob> in <path glob>`)"
+            ));
+        };
+        let (rule_glob, path_glob) = (rule_glob.trim(), path_glob.trim());
+        if rule_glob.is_empty() || path_glob.is_empty() {
+            return Err(format!(
+                "invalid scan allow entry `{spec}` (rule and path globs must be non-empty)"
+            ));
+        }
+        Ok(Self {
+            rule_glob: rule_glob.to_string(),
+            path_glob: path_glob.to_string(),
+        })
+    }
+
+    /// Whether this entry covers `finding`. Findings without a `rule_id`
+    /// are never matched.
+    #[must_use]
+    pub fn matches(&self, finding: &ScanFinding, repo_root: Option<&Path>) -> bool {
+        let Some(rule_id) = finding.rule_id.as_deref() else {
+            return false;
+        };
+        wildcard_match(&self.rule_glob, rule_id)
+            && build_glob_candidates(Path::new(&finding.file), repo_root)
+                .iter()
+                .any(|candidate| glob_match(&self.path_glob, candidate))
+    }
+}
+
+impl TryFrom<String> for ScanAllowEntry {
+    type Error = String;
+
+    fn try_from(spec: String) -> Result<Self, Self::Error> {
+        Self::parse(&spec)
+    }
+}
+
+/// Match `text` against a pattern where `*` matches any run of characters.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return true;
+    };
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
 }
 
 /// Precomputed evaluator context for scanning.
@@ -294,18 +601,27 @@ pub struct ScanEvalContext {
     pub compiled_overrides: crate::config::CompiledOverrides,
     pub allowlists: crate::allowlist::LayeredAllowlist,
     pub heredoc_settings: HeredocSettings,
+    /// External packs from `packs.custom_paths`, checked after built-in packs.
+    pub external_store: &'static crate::packs::ExternalPackStore,
+    pub external_pack_ids: HashSet<String>,
 }
 
 impl ScanEvalContext {
     #[must_use]
     pub fn from_config(config: &Config) -> Self {
+        crate::packs::heuristic::destructive_verbs::configure_verbs(
+            config.heuristics.destructive_verbs.as_deref(),
+        );
         let enabled_packs: HashSet<String> = config.enabled_pack_ids();
         let enabled_keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
-        let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+        let ordered_packs =
+            REGISTRY.expand_enabled_ordered_with(&enabled_packs, &config.packs.pack_order);
         let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
         let compiled_overrides = config.overrides.compile();
         let allowlists = crate::load_default_allowlists();
         let heredoc_settings = config.heredoc_settings();
+        let external_store = crate::packs::load_external_packs(&config.packs.expand_custom_paths());
+        let external_pack_ids = external_store.pack_ids().cloned().collect();
 
         Self {
             enabled_keywords,
@@ -314,13 +630,372 @@ impl ScanEvalContext {
             compiled_overrides,
             allowlists,
             heredoc_settings,
+            external_store,
+            external_pack_ids,
         }
     }
 }
 
 #[must_use]
 pub fn should_fail(report: &ScanReport, fail_on: ScanFailOn) -> bool {
-    report.findings.iter().any(|f| fail_on.blocks(f.severity))
+    should_fail_with_tags(report, fail_on, &[])
+}
+
+/// Like [`should_fail`], but only findings from packs carrying one of
+/// `only_tags` can fail the scan (`--fail-only-tags`).
+///
+/// An empty tag list gates on every finding. Findings without a `rule_id`
+/// (or whose pack is unknown) never match a tag filter and stay advisory.
+#[must_use]
+pub fn should_fail_with_tags(
+    report: &ScanReport,
+    fail_on: ScanFailOn,
+    only_tags: &[String],
+) -> bool {
+    report.findings.iter().any(|f| {
+        fail_on.blocks(f.severity)
+            && (only_tags.is_empty()
+                || f.rule_id
+                    .as_deref()
+                    .is_some_and(|rule_id| rule_pack_has_any_tag(rule_id, only_tags)))
+    })
+}
+
+/// Check the aggregate risk score against a `--fail-on-score` budget.
+///
+/// Fails only when the score strictly exceeds `threshold`.
+#[must_use]
+pub fn should_fail_on_score(report: &ScanReport, threshold: f64) -> bool {
+    report.summary.risk_score > threshold
+}
+
+/// One-line, grep-able outcome summary for CI logs.
+///
+/// Example: `dcg-scan: 3 findings (2 error, 1 warning) across 57 files in 412ms; exit=1`.
+/// Written to stderr so it never mixes with machine-readable stdout.
+#[must_use]
+pub fn summary_line(summary: &ScanSummary, exit_code: i32) -> String {
+    let total = summary.findings_total;
+    let mut line = format!(
+        "dcg-scan: {total} finding{}",
+        if total == 1 { "" } else { "s" }
+    );
+
+    let breakdown: Vec<String> = [
+        (summary.severities.error, "error"),
+        (summary.severities.warning, "warning"),
+        (summary.severities.info, "info"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, label)| format!("{count} {label}"))
+    .collect();
+    if !breakdown.is_empty() {
+        let _ = write!(line, " ({})", breakdown.join(", "));
+    }
+
+    let files = summary.files_scanned;
+    let _ = write!(
+        line,
+        " across {files} file{}",
+        if files == 1 { "" } else { "s" }
+    );
+    if let Some(elapsed_ms) = summary.elapsed_ms {
+        let _ = write!(line, " in {elapsed_ms}ms");
+    }
+    let _ = write!(line, "; exit={exit_code}");
+    line
+}
+
+/// Header row for `--format csv`. Column order is stable.
+pub const SCAN_CSV_HEADER: &str = "file,line,col,rule_id,decision,severity,reason,command";
+
+/// Render findings as CSV, one row per finding, preceded by [`SCAN_CSV_HEADER`].
+///
+/// Fields containing commas, quotes, or line breaks are quoted per RFC 4180.
+/// The command column is the already redacted/truncated `extracted_command`.
+#[must_use]
+pub fn format_scan_csv(report: &ScanReport) -> String {
+    let mut out = String::with_capacity(64 * (report.findings.len() + 1));
+    out.push_str(SCAN_CSV_HEADER);
+    out.push('\n');
+
+    for finding in &report.findings {
+        let decision = match finding.decision {
+            ScanDecision::Allow => "allow",
+            ScanDecision::Warn => "warn",
+            ScanDecision::Deny => "deny",
+        };
+        let severity = match finding.severity {
+            ScanSeverity::Info => "info",
+            ScanSeverity::Warning => "warning",
+            ScanSeverity::Error => "error",
+        };
+        let line = finding.line.to_string();
+        let col = finding.col.map(|col| col.to_string()).unwrap_or_default();
+        let fields = [
+            finding.file.as_str(),
+            line.as_str(),
+            col.as_str(),
+            finding.rule_id.as_deref().unwrap_or_default(),
+            decision,
+            severity,
+            finding.reason.as_deref().unwrap_or_default(),
+            finding.extracted_command.as_str(),
+        ];
+        let row: Vec<std::borrow::Cow<'_, str>> = fields.into_iter().map(csv_field).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> std::borrow::Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        std::borrow::Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        std::borrow::Cow::Borrowed(value)
+    }
+}
+
+/// Render findings as GitHub Actions workflow commands, one per line, e.g.
+/// `::error file=deploy.sh,line=7,col=3::core.git:reset-hard destroys work`.
+///
+/// Error findings become `::error`, warnings `::warning`, and info findings
+/// `::notice`. Property values and the message are escaped so that `%`, line
+/// breaks, and (in properties) `:`/`,` cannot end the command early.
+#[must_use]
+pub fn format_scan_github_annotations(report: &ScanReport) -> String {
+    let mut out = String::with_capacity(96 * report.findings.len());
+
+    for finding in &report.findings {
+        let level = match finding.severity {
+            ScanSeverity::Error => "error",
+            ScanSeverity::Warning => "warning",
+            ScanSeverity::Info => "notice",
+        };
+        let _ = write!(
+            out,
+            "::{level} file={},line={}",
+            escape_workflow_property(&finding.file),
+            finding.line
+        );
+        if let Some(col) = finding.col {
+            let _ = write!(out, ",col={col}");
+        }
+
+        let message = match (finding.rule_id.as_deref(), finding.reason.as_deref()) {
+            (Some(rule_id), Some(reason)) => format!("{rule_id} {reason}"),
+            (Some(rule_id), None) => rule_id.to_string(),
+            (None, Some(reason)) => reason.to_string(),
+            (None, None) => finding.extracted_command.clone(),
+        };
+        let _ = writeln!(out, "::{}", escape_workflow_data(&message));
+    }
+    out
+}
+
+/// Escape a workflow command message (`%`, `\r`, `\n`).
+fn escape_workflow_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value (message escapes plus `:` and `,`).
+fn escape_workflow_property(value: &str) -> String {
+    escape_workflow_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Render a scan report for a GitHub Actions job summary, e.g.
+/// `dcg scan --format github-summary >> "$GITHUB_STEP_SUMMARY"`.
+///
+/// Unlike `--format markdown` (aimed at PR comments), this leads with a
+/// per-file severity table and lists each file's findings in a collapsed
+/// `<details>` block as a task list, using emoji badges that render in the
+/// Actions UI. `top` limits the findings listed (0 = all); the table always
+/// covers every file.
+#[must_use]
+pub fn format_scan_github_summary(report: &ScanReport, top: usize) -> String {
+    use std::collections::BTreeMap;
+
+    let mut out = String::new();
+    let summary = &report.summary;
+    let _ = writeln!(out, "## \u{1f6e1}\u{fe0f} dcg scan\n");
+
+    if report.findings.is_empty() {
+        let _ = writeln!(
+            out,
+            "\u{2705} **No findings** \u{2014} {} command(s) checked across {} file(s).",
+            summary.commands_extracted, summary.files_scanned
+        );
+        return out;
+    }
+
+    let mut by_file: BTreeMap<&str, Vec<&ScanFinding>> = BTreeMap::new();
+    for finding in &report.findings {
+        by_file.entry(&finding.file).or_default().push(finding);
+    }
+
+    let _ = writeln!(
+        out,
+        "{} **{} error(s)** \u{b7} {} **{} warning(s)** \u{b7} {} **{} info** \u{2014} \
+         {} finding(s) in {} of {} scanned file(s)\n",
+        severity_emoji(ScanSeverity::Error),
+        summary.severities.error,
+        severity_emoji(ScanSeverity::Warning),
+        summary.severities.warning,
+        severity_emoji(ScanSeverity::Info),
+        summary.severities.info,
+        summary.findings_total,
+        by_file.len(),
+        summary.files_scanned
+    );
+
+    let _ = writeln!(out, "| File | Errors | Warnings | Info |");
+    let _ = writeln!(out, "|------|-------:|---------:|-----:|");
+    for (file, findings) in &by_file {
+        let count = |severity: ScanSeverity| {
+            findings
+                .iter()
+                .filter(|finding| finding.severity == severity)
+                .count()
+        };
+        let _ = writeln!(
+            out,
+            "| `{}` | {} | {} | {} |",
+            file.replace('|', "\\|"),
+            count(ScanSeverity::Error),
+            count(ScanSeverity::Warning),
+            count(ScanSeverity::Info)
+        );
+    }
+    let _ = writeln!(out);
+
+    let limit = if top == 0 { usize::MAX } else { top };
+    let mut shown = 0;
+    for (file, findings) in &by_file {
+        if shown >= limit {
+            break;
+        }
+        let worst = findings
+            .iter()
+            .map(|finding| finding.severity)
+            .max_by_key(ScanSeverity::rank)
+            .unwrap_or(ScanSeverity::Info);
+        let _ = writeln!(out, "<details>");
+        let _ = writeln!(
+            out,
+            "<summary>{} <code>{}</code> ({} finding(s))</summary>\n",
+            severity_emoji(worst),
+            html_escape(file),
+            findings.len()
+        );
+
+        for finding in findings.iter().take(limit - shown) {
+            let decision = match finding.decision {
+                ScanDecision::Allow => "ALLOW",
+                ScanDecision::Warn => "WARN",
+                ScanDecision::Deny => "DENY",
+            };
+            let location = finding.col.map_or_else(
+                || finding.line.to_string(),
+                |col| format!("{}:{col}", finding.line),
+            );
+            let _ = write!(
+                out,
+                "- [ ] {} **{decision}** line {location}",
+                severity_emoji(finding.severity)
+            );
+            if let Some(rule_id) = &finding.rule_id {
+                let _ = write!(out, " `{rule_id}`");
+            }
+            if let Some(reason) = &finding.reason {
+                let _ = write!(out, " \u{2014} {reason}");
+            }
+            let _ = writeln!(out);
+
+            let fence = code_fence_for(&finding.extracted_command);
+            let _ = writeln!(out, "  {fence}sh");
+            for line in finding.extracted_command.lines() {
+                let _ = writeln!(out, "  {line}");
+            }
+            let _ = writeln!(out, "  {fence}");
+            if let Some(suggestion) = &finding.suggestion {
+                let _ = writeln!(out, "  \u{1f4a1} {suggestion}");
+            }
+            shown += 1;
+        }
+        let _ = writeln!(out, "\n</details>\n");
+    }
+
+    if shown < report.findings.len() {
+        let _ = writeln!(
+            out,
+            "_Showing {shown} of {} findings. Use `--top 0` to show all._",
+            report.findings.len()
+        );
+    }
+    out
+}
+
+const fn severity_emoji(severity: ScanSeverity) -> &'static str {
+    match severity {
+        ScanSeverity::Error => "\u{274c}",
+        ScanSeverity::Warning => "\u{26a0}\u{fe0f}",
+        ScanSeverity::Info => "\u{2139}\u{fe0f}",
+    }
+}
+
+/// A backtick fence longer than any backtick run in `text`, so the fenced
+/// block cannot be closed early by the command itself.
+fn code_fence_for(text: &str) -> String {
+    let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat(longest_run.max(2) + 1)
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Compute the aggregate risk score for a set of findings.
+///
+/// Each finding contributes `severity weight × confidence`, with weights
+/// error = 10, warning = 3, info = 1 and a missing confidence counted as 1.0.
+/// Contributions are summed in integer hundredths so the result does not
+/// depend on finding order or float accumulation, then reported with two
+/// decimals.
+#[must_use]
+pub fn risk_score(findings: &[ScanFinding]) -> f64 {
+    let hundredths: u64 = findings
+        .iter()
+        .map(|f| {
+            let confidence = f.confidence.unwrap_or(1.0).clamp(0.0, 1.0);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let confidence_pct = (confidence * 100.0).round() as u64;
+            u64::from(f.severity.risk_weight()) * confidence_pct
+        })
+        .sum();
+    #[allow(clippy::cast_precision_loss)]
+    let score = hundredths as f64 / 100.0;
+    score
+}
+
+/// Resolve the pack for a `pack_id:pattern_name` rule and check its tags.
+fn rule_pack_has_any_tag(rule_id: &str, tags: &[String]) -> bool {
+    let pack_id = rule_id
+        .split_once(':')
+        .map_or(rule_id, |(pack_id, _)| pack_id);
+    let pack = REGISTRY
+        .get(pack_id)
+        .or_else(|| crate::packs::get_external_packs().and_then(|store| store.get(pack_id)));
+    pack.is_some_and(|pack| tags.iter().any(|tag| pack.has_tag(tag)))
 }
 
 pub fn sort_findings(findings: &mut [ScanFinding]) {
@@ -362,34 +1037,49 @@ pub fn evaluate_extracted_command(
             std::env::current_dir().ok().map(|cwd| cwd.join(candidate))
         }
     };
-    let result = evaluate_command_with_pack_order_at_path(
-        &extracted.command,
-        &ctx.enabled_keywords,
-        &ctx.ordered_packs,
-        ctx.keyword_index.as_ref(),
-        &ctx.compiled_overrides,
-        &ctx.allowlists,
-        &ctx.heredoc_settings,
-        project_path.as_deref(),
-    );
-
-    if result.decision == EvaluationDecision::Allow {
-        return None;
-    }
+    let (pattern, line) = if extracted.extractor_id == EXTRACTOR_ID_SHELL_HEREDOC {
+        let (pattern, body_line) =
+            match_embedded_heredoc(&extracted.command, ctx, project_path.as_deref())?;
+        (pattern, extracted.line + body_line)
+    } else {
+        let result = evaluate_command_in_scan_context(
+            &extracted.command,
+            &extracted.extractor_id,
+            &ctx.enabled_keywords,
+            &ctx.ordered_packs,
+            ctx.keyword_index.as_ref(),
+            &ctx.compiled_overrides,
+            &ctx.allowlists,
+            &ctx.heredoc_settings,
+            project_path.as_deref(),
+        );
 
-    let Some(pattern) = result.pattern_info else {
-        return Some(ScanFinding {
-            file: extracted.file.clone(),
-            line: extracted.line,
-            col: extracted.col,
-            extractor_id: extracted.extractor_id.clone(),
-            extracted_command: extracted.command.clone(),
-            decision: ScanDecision::Deny,
-            severity: ScanSeverity::Error,
-            rule_id: None,
-            reason: Some("Blocked (missing match metadata)".to_string()),
-            suggestion: None,
-        });
+        if result.decision == EvaluationDecision::Allow {
+            let pattern = match_external_packs(&extracted.command, &extracted.extractor_id, ctx)?;
+            (pattern, extracted.line)
+        } else {
+            let Some(pattern) = result.pattern_info else {
+                return Some(ScanFinding {
+                    file: extracted.file.clone(),
+                    line: extracted.line,
+                    col: extracted.col,
+                    extractor_id: extracted.extractor_id.clone(),
+                    extracted_command: redact_and_truncate(&extracted.command, options),
+                    normalized_command: redacted_normalized_command(&extracted.command, options),
+                    metadata: extracted.metadata.clone(),
+                    decision: ScanDecision::Deny,
+                    severity: ScanSeverity::Error,
+                    rule_id: None,
+                    reason: Some("Blocked (missing match metadata)".to_string()),
+                    reason_code: ReasonCode::Unspecified,
+                    suggestion: None,
+                    confidence: None,
+                    suppression: None,
+                    owner: None,
+                });
+            };
+            (pattern, extracted.line)
+        }
     };
 
     let (rule_id, severity, decision_mode) = resolve_severity_and_rule_id(config, &pattern);
@@ -406,27 +1096,122 @@ pub fn evaluate_extracted_command(
         Some(Severity::Critical | Severity::High) | None => ScanSeverity::Error,
     };
 
+    let reason_code = rule_id
+        .as_deref()
+        .map_or(ReasonCode::Unspecified, ReasonCode::for_rule_id);
     let suggestion = rule_id
         .as_deref()
         .and_then(|id| get_suggestion_by_kind(id, SuggestionKind::SaferAlternative))
         .map(|s| s.text.clone());
 
+    let confidence = match_confidence(&extracted.command, &pattern);
     let extracted_command = redact_and_truncate(&extracted.command, options);
+    let normalized_command = redacted_normalized_command(&extracted.command, options);
+    let suppression = extracted
+        .suppression
+        .clone()
+        .filter(|s| rule_id.as_deref() == Some(s.rule_id.as_str()));
 
     Some(ScanFinding {
         file: extracted.file.clone(),
-        line: extracted.line,
+        line,
         col: extracted.col,
         extractor_id: extracted.extractor_id.clone(),
         extracted_command,
+        normalized_command,
+        metadata: extracted.metadata.clone(),
         decision: scan_decision,
         severity: scan_severity,
         rule_id,
         reason: Some(pattern.reason),
+        reason_code,
         suggestion,
+        confidence,
+        suppression,
+        owner: None,
     })
 }
 
+/// Match a `shell.heredoc` body with the AST rules for its language.
+///
+/// Returns the most severe non-allowlisted match and its 1-based line within
+/// the body. Heredoc language and content allowlists are honored.
+fn match_embedded_heredoc(
+    command: &str,
+    ctx: &ScanEvalContext,
+    project_path: Option<&Path>,
+) -> Option<(PatternMatch, usize)> {
+    let (head, rest) = command.split_once('\n')?;
+    // The last line is the terminator.
+    let body = rest.rsplit_once('\n').map_or("", |(body, _)| body);
+    let (language, _) = crate::heredoc::ScriptLanguage::detect(head, body);
+
+    let settings = &ctx.heredoc_settings;
+    if settings
+        .allowed_languages
+        .as_ref()
+        .is_some_and(|allowed| !allowed.contains(&language))
+    {
+        return None;
+    }
+    if settings
+        .content_allowlist
+        .as_ref()
+        .is_some_and(|allowlist| {
+            allowlist
+                .is_content_allowlisted(body, language, project_path)
+                .is_some()
+        })
+    {
+        return None;
+    }
+
+    let matches = crate::ast_matcher::DEFAULT_MATCHER
+        .find_matches(body, language)
+        .ok()?;
+    // Unlike the hook, scan reports non-blocking matches too (policy turns
+    // them into warnings), so take the most severe rather than the first
+    // blocking one.
+    matches
+        .into_iter()
+        .filter_map(|m| {
+            let (pack_id, pattern_name) = split_ast_rule_id(&m.rule_id);
+            if ctx.allowlists.match_rule(&pack_id, &pattern_name).is_some() {
+                return None;
+            }
+            Some((m, pack_id, pattern_name))
+        })
+        .min_by_key(|(m, _, _)| m.severity as u8)
+        .map(|(m, pack_id, pattern_name)| {
+            let pattern = PatternMatch {
+                pack_id: Some(pack_id),
+                pattern_name: Some(pattern_name),
+                severity: Some(ast_severity_to_pack_severity(m.severity)),
+                reason: m.reason,
+                source: MatchSource::HeredocAst,
+                matched_span: None,
+                matched_text_preview: Some(m.matched_text_preview),
+                explanation: None,
+                suggestions: &[],
+            };
+            (pattern, m.line_number)
+        })
+}
+
+/// Confidence that the match is truly destructive, rounded to two decimals.
+fn match_confidence(command: &str, pattern: &PatternMatch) -> Option<f64> {
+    let span = pattern.matched_span.as_ref()?;
+    let sanitized = crate::context::sanitize_for_pattern_matching(command);
+    let ctx = crate::confidence::ConfidenceContext {
+        command,
+        sanitized_command: Some(sanitized.as_ref()),
+        match_start: span.start,
+        match_end: span.end,
+    };
+    let score = crate::confidence::compute_match_confidence(&ctx);
+    Some((f64::from(score.value) * 100.0).round() / 100.0)
+}
+
 fn resolve_severity_and_rule_id(
     config: &Config,
     pattern: &PatternMatch,
@@ -445,25 +1230,66 @@ fn resolve_severity_and_rule_id(
 
     // Never downgrade explicit blocks; packs/AST matches are policy-controlled.
     let mode = match pattern.source {
-        MatchSource::Pack | MatchSource::HeredocAst => {
-            config
-                .policy()
-                .resolve_mode(Some(pack_id), Some(pattern_name), severity)
-        }
+        MatchSource::Pack | MatchSource::HeredocAst | MatchSource::Heuristic => config
+            .policy()
+            .resolve_mode(Some(pack_id), Some(pattern_name), severity),
         MatchSource::ConfigOverride | MatchSource::LegacyPattern => DecisionMode::Deny,
     };
 
     (rule_id, severity, Some(mode))
 }
 
+/// Check the external packs for a command the built-in packs allowed.
+///
+/// Patterns whose `context_filter` excludes `extractor_id` are skipped.
+fn match_external_packs(
+    command: &str,
+    extractor_id: &str,
+    ctx: &ScanEvalContext,
+) -> Option<PatternMatch> {
+    if ctx.external_store.is_empty() {
+        return None;
+    }
+    let normalized = crate::normalize::normalize_command(command);
+    let cmd_for_match = crate::sanitize_for_pattern_matching(&normalized);
+    let matched = ctx.external_store.check_command_in_scan_context(
+        &cmd_for_match,
+        &ctx.external_pack_ids,
+        extractor_id,
+    )?;
+    if !matched.blocked {
+        return None;
+    }
+    Some(PatternMatch {
+        pack_id: matched.pack_id,
+        pattern_name: matched.pattern_name,
+        severity: matched.severity,
+        reason: matched.reason.unwrap_or_default(),
+        source: MatchSource::Pack,
+        matched_span: None,
+        matched_text_preview: None,
+        explanation: matched.explanation,
+        suggestions: &[],
+    })
+}
+
 fn redact_and_truncate(command: &str, options: &ScanOptions) -> String {
-    let redacted = match options.redact {
+    truncate_utf8(&redact_command(command, options.redact), options.truncate)
+}
+
+/// Normalize the redacted text: normalization strips quotes, which would
+/// otherwise expose strings that quoted redaction hides.
+fn redacted_normalized_command(command: &str, options: &ScanOptions) -> Option<String> {
+    normalized_command_for_display(&redact_command(command, options.redact))
+        .map(|normalized| truncate_utf8(&normalized, options.truncate))
+}
+
+fn redact_command(command: &str, mode: ScanRedactMode) -> String {
+    match mode {
         ScanRedactMode::None => command.to_string(),
         ScanRedactMode::Quoted => redact_quoted_strings(command),
         ScanRedactMode::Aggressive => redact_aggressively(command),
-    };
-
-    truncate_utf8(&redacted, options.truncate)
+    }
 }
 
 fn truncate_utf8(s: &str, max_chars: usize) -> String {
@@ -610,20 +1436,21 @@ pub type ScanProgressCallback<'a> = &'a mut dyn FnMut(usize, usize, &str);
 /// - package.json extractor (`package.json` - scripts only)
 /// - Terraform extractor (`*.tf` - provisioner blocks)
 /// - docker-compose extractor (`docker-compose.yml`, `compose.yml` - command/entrypoint)
-#[allow(clippy::missing_errors_doc)]
+///
+/// # Errors
+///
+/// Returns [`ScanError::Io`] or [`ScanError::Config`] if `.dcgignore` exists
+/// but cannot be read or parsed. Unreadable scan targets are counted as skips,
+/// not errors.
 #[allow(clippy::too_many_lines)]
 pub fn scan_paths(
     paths: &[PathBuf],
     options: &ScanOptions,
     config: &Config,
     ctx: &ScanEvalContext,
-    include: &[String],
-    exclude: &[String],
     repo_root: Option<&Path>,
-) -> Result<ScanReport, String> {
-    scan_paths_with_progress(
-        paths, options, config, ctx, include, exclude, repo_root, None,
-    )
+) -> Result<ScanReport, ScanError> {
+    scan_paths_with_progress(paths, options, config, ctx, repo_root, None)
 }
 
 /// Scan file paths with optional progress reporting.
@@ -648,36 +1475,30 @@ pub fn scan_paths(
 ///
 /// let report = scan_paths_with_progress(
 ///     &paths, &options, &config, &ctx,
-///     &[], &[], None,
+///     None,
 ///     Some(&mut progress),
 /// )?;
 /// ```
-#[allow(clippy::missing_errors_doc)]
+///
+/// # Errors
+///
+/// Same as [`scan_paths`].
 #[allow(clippy::too_many_lines)]
 pub fn scan_paths_with_progress(
     paths: &[PathBuf],
     options: &ScanOptions,
     config: &Config,
     ctx: &ScanEvalContext,
-    include: &[String],
-    exclude: &[String],
     repo_root: Option<&Path>,
     progress: Option<ScanProgressCallback<'_>>,
-) -> Result<ScanReport, String> {
+) -> Result<ScanReport, ScanError> {
     let started = std::time::Instant::now();
 
-    let mut files: Vec<PathBuf> = Vec::new();
-    let mut visited: HashSet<PathBuf> = HashSet::new();
-    for path in paths {
-        collect_files_recursively(path, &mut files, &mut visited);
-    }
-
-    files.sort();
-    files.dedup();
-
-    if !include.is_empty() || !exclude.is_empty() {
-        files = filter_paths(&files, include, exclude, repo_root);
-    }
+    let CollectedFiles {
+        files,
+        dcgignored: files_dcgignored,
+        excluded,
+    } = collect_scan_files(paths, options, repo_root)?;
 
     let total_files = files.len();
     let mut progress = progress;
@@ -687,188 +1508,649 @@ pub fn scan_paths_with_progress(
         cb(0, total_files, "");
     }
 
-    let mut files_scanned = 0usize;
-    let mut files_skipped = 0usize;
-    let mut commands_extracted = 0usize;
-    let mut findings: Vec<ScanFinding> = Vec::new();
-    let mut max_findings_reached = false;
-
-    for (file_idx, file) in files.iter().enumerate() {
-        // Report progress
-        if let Some(ref mut cb) = progress {
-            cb(file_idx + 1, total_files, &file.to_string_lossy());
-        }
-        if findings.len() >= options.max_findings {
-            max_findings_reached = true;
-            break;
+    let mut tally = ScanTally {
+        skipped: ScanSkipCounts {
+            excluded,
+            ..ScanSkipCounts::default()
+        },
+        ..ScanTally::default()
+    };
+
+    // Files are evaluated out of order in parallel, but merged in file order
+    // so `max_findings` truncation matches the sequential scan exactly.
+    #[cfg(feature = "rayon")]
+    let parallel = if options.jobs == 1 {
+        None
+    } else {
+        scan_files_parallel(&files, options, config, ctx, &mut progress)
+    };
+    #[cfg(not(feature = "rayon"))]
+    let parallel: Option<Vec<FileScan>> = None;
+
+    if let Some(scans) = parallel {
+        for scan in scans {
+            if tally.is_full(options) || !tally.absorb(scan, options, repo_root) {
+                break;
+            }
+        }
+    } else {
+        for (file_idx, file) in files.iter().enumerate() {
+            // Report progress
+            if let Some(ref mut cb) = progress {
+                cb(file_idx + 1, total_files, &file.to_string_lossy());
+            }
+            if tally.is_full(options)
+                || !tally.absorb(scan_file(file, options, config, ctx), options, repo_root)
+            {
+                break;
+            }
         }
+    }
 
-        let Ok(meta) = std::fs::metadata(file) else {
-            files_skipped += 1;
-            continue;
-        };
+    let ScanTally {
+        files_scanned,
+        skipped,
+        commands_extracted,
+        findings,
+        suppressed,
+        findings_scan_allowed,
+        max_findings_reached,
+    } = tally;
 
-        if !meta.is_file() {
-            files_skipped += 1;
-            continue;
+    let elapsed_ms = u64::try_from(started.elapsed().as_millis()).ok();
+    let mut report = build_report(
+        findings,
+        files_scanned,
+        skipped.total(),
+        commands_extracted,
+        max_findings_reached,
+        elapsed_ms,
+    );
+    report.summary.skipped = skipped;
+    report.summary.files_dcgignored = files_dcgignored;
+    report.summary.findings_suppressed = suppressed.len();
+    report.summary.findings_scan_allowed = findings_scan_allowed;
+    report.suppressed = suppressed;
+    Ok(report)
+}
+
+/// What scanning one file produced, before `max_findings` is applied.
+enum FileScan {
+    Skipped(ScanSkipReason),
+    /// One evaluation result per extracted command, in extraction order.
+    Scanned(Vec<Option<ScanFinding>>),
+}
+
+/// Read, extract, and evaluate one file. Pure given its inputs, so files can
+/// be scanned in any order.
+fn scan_file(
+    file: &Path,
+    options: &ScanOptions,
+    config: &Config,
+    ctx: &ScanEvalContext,
+) -> FileScan {
+    let bytes = match read_scannable_file(file, options) {
+        Ok(bytes) => bytes,
+        Err(reason) => return FileScan::Skipped(reason),
+    };
+    let content = String::from_utf8_lossy(&bytes);
+    let extracted = extract_commands_for_file(file, &content, options, &ctx.enabled_keywords);
+    FileScan::Scanned(
+        extracted
+            .iter()
+            .map(|cmd| evaluate_extracted_command(cmd, options, config, ctx))
+            .collect(),
+    )
+}
+
+/// Evaluate `files` on a rayon pool of `options.jobs` threads (0 = one per
+/// CPU), returning results in file order. `progress` is called as files
+/// finish. Returns `None` if the pool cannot be started.
+#[cfg(feature = "rayon")]
+fn scan_files_parallel(
+    files: &[PathBuf],
+    options: &ScanOptions,
+    config: &Config,
+    ctx: &ScanEvalContext,
+    progress: &mut Option<ScanProgressCallback<'_>>,
+) -> Option<Vec<FileScan>> {
+    use rayon::prelude::*;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+        .ok()?;
+    let (done_tx, done_rx) = std::sync::mpsc::channel::<usize>();
+
+    std::thread::scope(|scope| {
+        let worker = scope.spawn(move || {
+            pool.install(|| {
+                files
+                    .par_iter()
+                    .enumerate()
+                    .map_with(done_tx, |done_tx, (idx, file)| {
+                        let scan = scan_file(file, options, config, ctx);
+                        let _ = done_tx.send(idx);
+                        scan
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        // Ends once every worker has dropped its sender.
+        for (finished, idx) in done_rx.iter().enumerate() {
+            if let Some(cb) = progress.as_mut() {
+                cb(finished + 1, files.len(), &files[idx].to_string_lossy());
+            }
         }
 
-        if meta.len() > options.max_file_size_bytes {
-            files_skipped += 1;
-            continue;
+        match worker.join() {
+            Ok(scans) => Some(scans),
+            Err(panic) => std::panic::resume_unwind(panic),
         }
+    })
+}
 
-        // Determine which extractor(s) to use
-        let is_shell = is_shell_script_path(file);
-        let is_docker = is_dockerfile_path(file);
-        let is_actions = is_github_actions_workflow_path(file);
-        let is_gitlab = is_gitlab_ci_path(file);
-        let is_azure = is_azure_pipelines_path(file);
-        let is_circleci = is_circleci_path(file);
-        let is_makefile = is_makefile_path(file);
-        let is_package_json = is_package_json_path(file);
-        let is_terraform = is_terraform_path(file);
-        let is_compose = is_docker_compose_path(file);
-
-        if !is_shell
-            && !is_docker
-            && !is_actions
-            && !is_gitlab
-            && !is_azure
-            && !is_circleci
-            && !is_makefile
-            && !is_package_json
-            && !is_terraform
-            && !is_compose
-        {
-            files_skipped += 1;
-            continue;
+/// Running totals for [`scan_paths_with_progress`].
+#[derive(Default)]
+struct ScanTally {
+    files_scanned: usize,
+    skipped: ScanSkipCounts,
+    commands_extracted: usize,
+    findings: Vec<ScanFinding>,
+    suppressed: Vec<ScanFinding>,
+    findings_scan_allowed: usize,
+    max_findings_reached: bool,
+}
+
+impl ScanTally {
+    /// Whether `max_findings` has been hit (recorded as reached if so).
+    fn is_full(&mut self, options: &ScanOptions) -> bool {
+        if self.findings.len() >= options.max_findings {
+            self.max_findings_reached = true;
         }
+        self.max_findings_reached
+    }
 
-        let Ok(bytes) = std::fs::read(file) else {
+    /// Add one file's results. Returns false once `max_findings` is reached.
+    fn absorb(&mut self, scan: FileScan, options: &ScanOptions, repo_root: Option<&Path>) -> bool {
+        let results = match scan {
+            FileScan::Skipped(reason) => {
+                self.skipped.record(reason);
+                return true;
+            }
+            FileScan::Scanned(results) => results,
+        };
+
+        self.files_scanned += 1;
+        self.commands_extracted += results.len();
+
+        for finding in results {
+            if self.is_full(options) {
+                return false;
+            }
+
+            let Some(finding) = finding else {
+                continue;
+            };
+            if finding.suppression.is_some() {
+                self.suppressed.push(finding);
+            } else if options
+                .scan_allow
+                .iter()
+                .any(|entry| entry.matches(&finding, repo_root))
+            {
+                self.findings_scan_allowed += 1;
+            } else {
+                self.findings.push(finding);
+            }
+        }
+        true
+    }
+}
+
+/// Result of `dcg scan --print-extracted`: every command the extractors
+/// produced, before any evaluation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionReport {
+    pub schema_version: u32,
+    pub files_scanned: usize,
+    pub files_skipped: usize,
+    pub commands: Vec<ExtractedCommand>,
+}
+
+/// Run the extractors over `paths` without evaluating anything.
+///
+/// File selection (recursion, `.dcgignore`, include/exclude, size limit, binary detection) is
+/// identical to [`scan_paths`], so the output shows exactly what a scan would
+/// evaluate. Intended for debugging extractors.
+///
+/// # Errors
+///
+/// Returns an error if `.dcgignore` exists but cannot be loaded.
+pub fn extract_paths(
+    paths: &[PathBuf],
+    options: &ScanOptions,
+    ctx: &ScanEvalContext,
+    repo_root: Option<&Path>,
+) -> Result<ExtractionReport, ScanError> {
+    let CollectedFiles {
+        files, excluded, ..
+    } = collect_scan_files(paths, options, repo_root)?;
+
+    let mut files_scanned = 0usize;
+    let mut files_skipped = excluded;
+    let mut commands = Vec::new();
+
+    for file in &files {
+        let Ok(bytes) = read_scannable_file(file, options) else {
             files_skipped += 1;
             continue;
         };
 
-        let content = String::from_utf8_lossy(&bytes);
-        let file_label = file.to_string_lossy();
         files_scanned += 1;
+        let content = String::from_utf8_lossy(&bytes);
+        commands.extend(extract_commands_for_file(
+            file,
+            &content,
+            options,
+            &ctx.enabled_keywords,
+        ));
+    }
+
+    Ok(ExtractionReport {
+        schema_version: SCAN_SCHEMA_VERSION,
+        files_scanned,
+        files_skipped,
+        commands,
+    })
+}
 
-        // Extract commands using appropriate extractor(s)
-        let mut extracted: Vec<ExtractedCommand> = Vec::new();
+/// How many leading bytes are checked for null bytes (same window as git).
+const BINARY_SNIFF_BYTES: usize = 8000;
 
-        if is_shell {
-            extracted.extend(extract_shell_script_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
-        }
+/// Heuristic binary detection: text files never contain null bytes.
+fn looks_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0)
+}
 
-        if is_docker {
-            extracted.extend(extract_dockerfile_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
-        }
+/// Read `file` if a scan should look at it, or say why it is skipped.
+fn read_scannable_file(file: &Path, options: &ScanOptions) -> Result<Vec<u8>, ScanSkipReason> {
+    let meta = std::fs::metadata(file).map_err(|_| ScanSkipReason::Unreadable)?;
+    if !meta.is_file() || !has_extractor_for_path(file, options) {
+        return Err(ScanSkipReason::Excluded);
+    }
+    if meta.len() > options.max_file_size_bytes {
+        return Err(ScanSkipReason::TooLarge);
+    }
+    let bytes = std::fs::read(file).map_err(|_| ScanSkipReason::Unreadable)?;
+    if looks_binary(&bytes) {
+        return Err(ScanSkipReason::Binary);
+    }
+    Ok(bytes)
+}
 
-        if is_actions {
-            extracted.extend(extract_github_actions_workflow_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
-        }
+/// Parse a `--paths-from` list: one path per line, skipping blank lines and
+/// `#` comments.
+#[must_use]
+pub fn parse_path_list(content: &str) -> Vec<PathBuf> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(PathBuf::from)
+        .collect()
+}
 
-        if is_gitlab {
-            extracted.extend(extract_gitlab_ci_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
-        }
+/// Files a scan visits, plus how many were dropped while collecting them.
+struct CollectedFiles {
+    files: Vec<PathBuf>,
+    /// Removed by `.dcgignore`.
+    dcgignored: usize,
+    /// Removed by the `include`/`exclude` globs in [`ScanOptions`].
+    excluded: usize,
+}
 
-        if is_azure {
-            extracted.extend(extract_azure_pipelines_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
-        }
+/// Expand `paths` into the sorted list of files a scan visits.
+///
+/// Directories matching an `exclude` glob are pruned without being read, so
+/// their contents are not counted as excluded.
+fn collect_scan_files(
+    paths: &[PathBuf],
+    options: &ScanOptions,
+    repo_root: Option<&Path>,
+) -> Result<CollectedFiles, ScanError> {
+    // Globs and `.dcgignore` resolve against the repo root (or cwd).
+    let scan_root = repo_root
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::current_dir().ok());
+
+    let walk = FileWalk {
+        exclude: &options.exclude,
+        root: scan_root.as_deref(),
+        respect_gitignore: options.respect_gitignore,
+    };
 
-        if is_circleci {
-            extracted.extend(extract_circleci_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
+    let mut files: Vec<PathBuf> = Vec::new();
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+    for path in paths {
+        // Keep missing paths so they are reported as unreadable skips
+        // instead of vanishing silently.
+        if std::fs::symlink_metadata(path).is_err() {
+            files.push(path.clone());
+            continue;
         }
+        let gitignores = match &scan_root {
+            Some(root) if options.respect_gitignore => GitignoreStack::for_ancestors(root, path),
+            _ => GitignoreStack::default(),
+        };
+        collect_files_recursively(path, &mut files, &mut visited, &walk, &gitignores);
+    }
 
-        if is_makefile {
-            extracted.extend(extract_makefile_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
-        }
+    files.sort();
+    files.dedup();
 
-        if is_package_json {
-            extracted.extend(extract_package_json_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
+    // `.dcgignore` is scan-specific and applies regardless of any other
+    // ignore handling.
+    let mut dcgignored = 0usize;
+    if let Some(root) = &scan_root {
+        if let Some(dcgignore) = DcgIgnore::load(root)? {
+            let before = files.len();
+            files.retain(|f| !dcgignore.is_ignored(f));
+            dcgignored = before - files.len();
         }
+    }
 
-        if is_terraform {
-            extracted.extend(extract_terraform_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
+    let excluded = if options.include.is_empty() && options.exclude.is_empty() {
+        0
+    } else {
+        let before = files.len();
+        files = filter_paths(
+            &files,
+            &options.include,
+            &options.exclude,
+            scan_root.as_deref(),
+        );
+        before - files.len()
+    };
+
+    Ok(CollectedFiles {
+        files,
+        dcgignored,
+        excluded,
+    })
+}
+
+type PathPredicate = fn(&Path) -> bool;
+type ExtractorFn = fn(&str, &str, &[&'static str]) -> Vec<ExtractedCommand>;
+
+/// A file-type extractor that `dcg scan` can run.
+///
+/// `id` is the name accepted by `--extractors`; it groups every
+/// `extractor_id` the extractor emits (e.g. `shell.script` also produces
+/// `shell.heredoc` commands). `file_patterns` is a human-readable summary of
+/// the files it claims, shown by `--list-extractors`.
+#[derive(Debug, Clone, Copy)]
+pub struct ScanExtractor {
+    pub id: &'static str,
+    pub file_patterns: &'static str,
+    applies: PathPredicate,
+    extract: ExtractorFn,
+}
+
+/// Every extractor, in dispatch order.
+///
+/// A file may match several entries (e.g. a shell script under
+/// `.github/workflows/`); every matching, selected extractor runs.
+pub const SCAN_EXTRACTORS: &[ScanExtractor] = &[
+    ScanExtractor {
+        id: "shell.script",
+        file_patterns: "*.sh, *.bash, *.zsh, *.dash, *.ksh",
+        applies: is_shell_script_path,
+        extract: extract_shell_script_from_str,
+    },
+    ScanExtractor {
+        id: "docker.dockerfile",
+        file_patterns: "Dockerfile, Dockerfile.*, *.dockerfile",
+        applies: is_dockerfile_path,
+        extract: extract_dockerfile_from_str,
+    },
+    ScanExtractor {
+        id: "github_actions",
+        file_patterns: ".github/workflows/*.yml, .github/workflows/*.yaml",
+        applies: is_github_actions_workflow_path,
+        extract: extract_github_actions_workflow_from_str,
+    },
+    ScanExtractor {
+        id: "gitlab_ci",
+        file_patterns: ".gitlab-ci.yml, *.gitlab-ci.yml",
+        applies: is_gitlab_ci_path,
+        extract: extract_gitlab_ci_from_str,
+    },
+    ScanExtractor {
+        id: "azure_pipelines",
+        file_patterns: "azure-pipelines*.yml, azure-pipelines*.yaml",
+        applies: is_azure_pipelines_path,
+        extract: extract_azure_pipelines_from_str,
+    },
+    ScanExtractor {
+        id: "circleci",
+        file_patterns: ".circleci/config.yml, .circleci/config.yaml",
+        applies: is_circleci_path,
+        extract: extract_circleci_from_str,
+    },
+    ScanExtractor {
+        id: "makefile",
+        file_patterns: "Makefile",
+        applies: is_makefile_path,
+        extract: extract_makefile_from_str,
+    },
+    ScanExtractor {
+        id: "package_json",
+        file_patterns: "package.json",
+        applies: is_package_json_path,
+        extract: extract_package_json_from_str,
+    },
+    ScanExtractor {
+        id: "terraform",
+        file_patterns: "*.tf",
+        applies: is_terraform_path,
+        extract: extract_terraform_from_str,
+    },
+    ScanExtractor {
+        id: "docker.compose",
+        file_patterns: "docker-compose.yml, docker-compose.yaml, compose.yml, compose.yaml",
+        applies: is_docker_compose_path,
+        extract: extract_docker_compose_from_str,
+    },
+    ScanExtractor {
+        id: "config.lifecycle",
+        file_patterns: "Procfile, netlify.toml, vercel.json",
+        applies: is_lifecycle_config_path,
+        extract: extract_lifecycle_config_from_str,
+    },
+];
+
+/// Parse a `--extractors` selection into known extractor ids.
+///
+/// # Errors
+///
+/// Returns an error naming the first unknown id, with the closest known id
+/// as a suggestion when one is near enough.
+pub fn parse_extractor_selection<S: AsRef<str>>(ids: &[S]) -> Result<Vec<&'static str>, String> {
+    let mut selected = Vec::new();
+    for id in ids
+        .iter()
+        .map(|id| id.as_ref().trim())
+        .filter(|id| !id.is_empty())
+    {
+        let Some(extractor) = SCAN_EXTRACTORS.iter().find(|e| e.id == id) else {
+            let hint = suggest_extractor_id(id)
+                .map_or_else(String::new, |known| format!(" (did you mean `{known}`?)"));
+            return Err(format!(
+                "unknown extractor `{id}`{hint}; see `dcg scan --list-extractors`"
             ));
+        };
+        if !selected.contains(&extractor.id) {
+            selected.push(extractor.id);
         }
+    }
+    if selected.is_empty() {
+        return Err("--extractors needs at least one extractor id".to_string());
+    }
+    Ok(selected)
+}
 
-        if is_compose {
-            extracted.extend(extract_docker_compose_from_str(
-                &file_label,
-                &content,
-                &ctx.enabled_keywords,
-            ));
+/// Closest known extractor id to `id`: a segment match (`dockerfile` ->
+/// `docker.dockerfile`) or a small edit distance.
+fn suggest_extractor_id(id: &str) -> Option<&'static str> {
+    let lower = id.to_ascii_lowercase();
+    if let Some(extractor) = SCAN_EXTRACTORS
+        .iter()
+        .find(|e| e.id.split('.').any(|segment| segment == lower))
+    {
+        return Some(extractor.id);
+    }
+    SCAN_EXTRACTORS
+        .iter()
+        .map(|e| (edit_distance(&lower, e.id), e.id))
+        .filter(|(distance, known)| *distance <= known.len() / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            row[j + 1] = substitution.min(prev[j + 1] + 1).min(row[j] + 1);
         }
+        prev = row;
+    }
+    prev[b.len()]
+}
 
-        commands_extracted += extracted.len();
+/// Extractors that are selected by `options` and apply to `file`.
+fn selected_extractors<'a>(
+    file: &'a Path,
+    options: &'a ScanOptions,
+) -> impl Iterator<Item = &'static ScanExtractor> + 'a {
+    SCAN_EXTRACTORS.iter().filter(move |extractor| {
+        options
+            .extractors
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(&extractor.id))
+            && (extractor.applies)(file)
+    })
+}
 
-        for cmd in extracted {
-            if findings.len() >= options.max_findings {
-                max_findings_reached = true;
-                break;
-            }
+fn has_extractor_for_path(file: &Path, options: &ScanOptions) -> bool {
+    selected_extractors(file, options).next().is_some()
+}
 
-            if let Some(finding) = evaluate_extracted_command(&cmd, options, config, ctx) {
-                findings.push(finding);
+/// Run every selected extractor that applies to `file` over its `content`.
+fn extract_commands_for_file(
+    file: &Path,
+    content: &str,
+    options: &ScanOptions,
+    enabled_keywords: &[&'static str],
+) -> Vec<ExtractedCommand> {
+    let file_label = file.to_string_lossy();
+    selected_extractors(file, options)
+        .flat_map(|extractor| (extractor.extract)(&file_label, content, enabled_keywords))
+        .collect()
+}
+
+/// File name of the scan-specific ignore file (gitignore syntax).
+pub const DCGIGNORE_FILE: &str = ".dcgignore";
+
+/// Exclusion rules loaded from a `.dcgignore` file at the scan root.
+///
+/// Unlike `.gitignore`, this file only affects `dcg scan`, which lets
+/// projects keep fixtures or vendored scripts out of scans while still
+/// tracking them in git.
+#[derive(Debug)]
+pub struct DcgIgnore {
+    root: PathBuf,
+    matcher: ignore::gitignore::Gitignore,
+}
+
+impl DcgIgnore {
+    /// Load `<root>/.dcgignore`. Returns `Ok(None)` when the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or contains an
+    /// invalid pattern.
+    pub fn load(root: &Path) -> Result<Option<Self>, ScanError> {
+        let path = root.join(DCGIGNORE_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let into_scan_error = |err: ignore::Error| {
+            let message = err.to_string();
+            match err.into_io_error() {
+                Some(source) => ScanError::Io {
+                    path: path.clone(),
+                    source,
+                },
+                None => ScanError::Config {
+                    path: path.clone(),
+                    message,
+                },
             }
-        }
+        };
 
-        if max_findings_reached {
-            break;
+        let mut builder = ignore::gitignore::GitignoreBuilder::new(root);
+        if let Some(err) = builder.add(&path) {
+            return Err(into_scan_error(err));
         }
+        let matcher = builder.build().map_err(into_scan_error)?;
+
+        Ok(Some(Self {
+            root: root.to_path_buf(),
+            matcher,
+        }))
     }
 
-    let elapsed_ms = u64::try_from(started.elapsed().as_millis()).ok();
-    Ok(build_report(
-        findings,
-        files_scanned,
-        files_skipped,
-        commands_extracted,
-        max_findings_reached,
-        elapsed_ms,
-    ))
+    /// Returns true if `path` (or one of its parent directories) is ignored.
+    ///
+    /// Relative paths are resolved against the current directory; paths
+    /// outside the root are never ignored.
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let absolute = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            match std::env::current_dir() {
+                Ok(cwd) => cwd.join(path),
+                Err(_) => return false,
+            }
+        };
+
+        let Ok(rel) = absolute.strip_prefix(&self.root) else {
+            return false;
+        };
+        if rel.as_os_str().is_empty() {
+            return false;
+        }
+
+        self.matcher
+            .matched_path_or_any_parents(rel, false)
+            .is_ignore()
+    }
 }
 fn collect_files_recursively(
     path: &PathBuf,
     out: &mut Vec<PathBuf>,
     visited: &mut HashSet<PathBuf>,
+    walk: &FileWalk<'_>,
+    gitignores: &GitignoreStack,
 ) {
     // Resolve symlinks to prevent infinite loops from circular symlinks
     let Ok(canonical) = std::fs::canonicalize(path) else {
@@ -892,19 +2174,114 @@ fn collect_files_recursively(
         return;
     }
 
+    // Prune excluded directories (e.g. `**/node_modules/**`) before reading them.
+    let candidates = build_glob_candidates(path, walk.root);
+    if walk.exclude.iter().any(|pattern| {
+        candidates
+            .iter()
+            .any(|candidate| glob_match(pattern, candidate))
+    }) {
+        return;
+    }
+
     let Ok(read_dir) = std::fs::read_dir(path) else {
         return;
     };
 
+    let gitignores = if walk.respect_gitignore {
+        gitignores.with_dir(path)
+    } else {
+        GitignoreStack::default()
+    };
+
     // Deterministic traversal: sort entries by path.
     let mut entries: Vec<PathBuf> = read_dir.filter_map(|e| e.ok().map(|e| e.path())).collect();
     entries.sort();
 
     for entry in entries {
-        collect_files_recursively(&entry, out, visited);
+        if gitignores.is_ignored(&entry) {
+            continue;
+        }
+        collect_files_recursively(&entry, out, visited, walk, &gitignores);
     }
 }
 
+/// Traversal settings shared by every level of [`collect_files_recursively`].
+struct FileWalk<'a> {
+    exclude: &'a [String],
+    /// Scan root that globs and `.gitignore` lookup resolve against.
+    root: Option<&'a Path>,
+    respect_gitignore: bool,
+}
+
+/// `.gitignore` files in effect for a directory, outermost first.
+///
+/// Like git, a deeper file overrides its parents and `!pattern` re-includes
+/// paths within the same file. Ignored directories are never entered, so
+/// their contents cannot be re-included.
+#[derive(Debug, Clone, Default)]
+struct GitignoreStack(Vec<ignore::gitignore::Gitignore>);
+
+impl GitignoreStack {
+    /// The `.gitignore` files from `root` down to (not including) `dir`,
+    /// so a scan that starts below the root still honors parent rules.
+    fn for_ancestors(root: &Path, dir: &Path) -> Self {
+        let (Ok(root), Ok(dir)) = (std::path::absolute(root), std::path::absolute(dir)) else {
+            return Self::default();
+        };
+        let Ok(rel) = dir.strip_prefix(&root) else {
+            return Self::default();
+        };
+
+        let mut stack = Self::default();
+        let mut current = root;
+        for component in rel.components() {
+            stack = stack.with_dir(&current);
+            current.push(component);
+        }
+        stack
+    }
+
+    /// This stack plus `dir/.gitignore`, if present.
+    ///
+    /// Malformed lines are skipped, as git does.
+    fn with_dir(&self, dir: &Path) -> Self {
+        let mut stack = self.clone();
+        let Ok(dir) = std::path::absolute(dir) else {
+            return stack;
+        };
+        let path = dir.join(GITIGNORE_FILE);
+        if path.is_file() {
+            let (matcher, _partial_error) = ignore::gitignore::Gitignore::new(&path);
+            if !matcher.is_empty() {
+                stack.0.push(matcher);
+            }
+        }
+        stack
+    }
+
+    fn is_ignored(&self, path: &Path) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        let Ok(absolute) = std::path::absolute(path) else {
+            return false;
+        };
+        let is_dir = absolute.is_dir();
+        for matcher in self.0.iter().rev() {
+            match matcher.matched(&absolute, is_dir) {
+                ignore::Match::Ignore(_) => return true,
+                ignore::Match::Whitelist(_) => return false,
+                ignore::Match::None => {}
+            }
+        }
+        false
+    }
+}
+
+/// File name of git's ignore file, honored by `respect_gitignore` scans.
+const GITIGNORE_FILE: &str = ".gitignore";
+
 /// Filter paths by include/exclude glob patterns.
 pub(crate) fn filter_paths(
     paths: &[PathBuf],
@@ -945,7 +2322,12 @@ fn build_glob_candidates(path: &Path, repo_root: Option<&Path>) -> Vec<String> {
 
     let raw = path.to_string_lossy().to_string();
     let raw_norm = raw.replace('\\', "/");
-    candidates.push(raw_norm.clone());
+    // Absolute paths under the root only match root-relative, so a checkout
+    // living in e.g. `/home/me/target/` is not swallowed by `**/target/**`.
+    let under_root = path.is_absolute() && repo_root.is_some_and(|root| path.starts_with(root));
+    if !under_root {
+        candidates.push(raw_norm.clone());
+    }
 
     if let Some(stripped) = raw_norm.strip_prefix("./") {
         candidates.push(stripped.to_string());
@@ -1029,6 +2411,18 @@ fn glob_match(pattern: &str, path: &str) -> bool {
             return true;
         }
 
+        // A second `**` (e.g. `**/node_modules/**`): try the rest of the
+        // pattern against every tail of the path that starts a segment.
+        if suffix.contains("**") {
+            let rest = path
+                .strip_prefix(prefix_raw)
+                .unwrap_or(path)
+                .trim_start_matches('/');
+            return std::iter::once(rest)
+                .chain(rest.match_indices('/').map(|(i, _)| &rest[i + 1..]))
+                .any(|tail| glob_match(suffix, tail));
+        }
+
         // For suffix like "*.rs", check the final path component.
         if suffix.contains('*') && !suffix.contains('/') {
             let last = path.rsplit('/').next().unwrap_or(path);
@@ -1089,7 +2483,19 @@ fn is_shell_script_path(path: &Path) -> bool {
         })
 }
 
+/// Extractor id for heredocs whose body is another language (see
+/// [`extract_shell_script_from_str`]).
+const EXTRACTOR_ID_SHELL_HEREDOC: &str = "shell.heredoc";
+
 /// Extract commands from shell scripts (.sh, .bash files)
+///
+/// A trailing `# dcg:ignore <rule_id> <reason>` comment on any line of a
+/// command is attached to it as an [`InlineSuppression`].
+///
+/// Heredocs feeding a non-shell interpreter (e.g. `python3 <<EOF`) are
+/// emitted whole as a single `shell.heredoc` command so the evaluator scans
+/// the body as that language; see [`extract_embedded_heredocs`]. The
+/// heredoc's head line is still extracted as a shell command.
 #[must_use]
 pub fn extract_shell_script_from_str(
     file: &str,
@@ -1101,10 +2507,48 @@ pub fn extract_shell_script_from_str(
 
     let mut out = Vec::new();
     let mut buffer: Option<(usize, String, usize)> = None;
+    let mut suppression: Option<InlineSuppression> = None;
+    let mut heredocs = extract_embedded_heredocs(file, content)
+        .into_iter()
+        .peekable();
+    let mut embedded = Vec::new();
+
+    let mut emit = |line: usize, candidate: &str, suppression: &mut Option<InlineSuppression>| {
+        let suppression = suppression.take();
+        if let Some(mut cmd) = extract_shell_command_line(file, line, candidate, enabled_keywords) {
+            cmd.suppression = suppression;
+            out.push(cmd);
+        }
+    };
 
+    let mut skip_until = 0usize;
     for (idx, raw_line) in content.lines().enumerate() {
         let line_no = idx + 1;
 
+        if line_no <= skip_until {
+            continue;
+        }
+
+        if let Some(parsed) = shell_inline_comment(raw_line).and_then(parse_dcg_ignore_comment) {
+            suppression = Some(parsed);
+        }
+
+        if buffer.is_none() && heredocs.peek().is_some_and(|(cmd, _)| cmd.line == line_no) {
+            if let Some((mut cmd, end_line)) = heredocs.next() {
+                // The head line may run other shell commands before the
+                // interpreter (`git clean -fdx; python3 <<EOF`).
+                let (head, _) = split_shell_line_continuation(raw_line);
+                emit(line_no, head.trim(), &mut suppression.clone());
+                cmd.suppression = suppression.take();
+                embedded.push(cmd);
+                skip_until = end_line;
+                continue;
+            }
+        }
+        while heredocs.peek().is_some_and(|(cmd, _)| cmd.line <= line_no) {
+            heredocs.next();
+        }
+
         let (segment, continues) = split_shell_line_continuation(raw_line);
         let segment = segment.trim();
 
@@ -1119,11 +2563,7 @@ pub fn extract_shell_script_from_str(
                 continue;
             }
 
-            if let Some(cmd) =
-                extract_shell_command_line(file, start_line, &joined, enabled_keywords)
-            {
-                out.push(cmd);
-            }
+            emit(start_line, &joined, &mut suppression);
             continue;
         }
 
@@ -1132,20 +2572,134 @@ pub fn extract_shell_script_from_str(
             continue;
         }
 
-        if let Some(cmd) = extract_shell_command_line(file, line_no, segment, enabled_keywords) {
-            out.push(cmd);
-        }
+        emit(line_no, segment, &mut suppression);
     }
 
     if let Some((start_line, joined, _)) = buffer.take() {
-        if let Some(cmd) = extract_shell_command_line(file, start_line, &joined, enabled_keywords) {
-            out.push(cmd);
+        emit(start_line, &joined, &mut suppression);
+    }
+
+    if !embedded.is_empty() {
+        out.extend(embedded);
+        out.sort_by_key(|cmd| cmd.line);
+    }
+
+    out
+}
+
+/// Find heredocs in a shell script whose body is another language.
+///
+/// Returns one command per heredoc spanning its first line through the
+/// terminator (so the evaluator's heredoc AST scan sees the whole body),
+/// paired with the terminator's 1-based line. The detected language is
+/// recorded in `metadata`. Shell and unknown-language bodies, and heredocs fed
+/// to non-executing commands like `cat`, are left to line-based extraction.
+fn extract_embedded_heredocs(file: &str, content: &str) -> Vec<(ExtractedCommand, usize)> {
+    use crate::heredoc::{
+        ExtractionLimits, ExtractionResult, HeredocType, ScriptLanguage, extract_content,
+        is_non_executing_heredoc_command,
+    };
+
+    if !content.contains("<<") {
+        return Vec::new();
+    }
+
+    // A script may hold many more heredocs and `-c` scripts than one command.
+    let limits = ExtractionLimits {
+        max_heredocs: 256,
+        ..ExtractionLimits::default()
+    };
+    let extracted = match extract_content(content, &limits) {
+        ExtractionResult::Extracted(extracted) | ExtractionResult::Partial { extracted, .. } => {
+            extracted
         }
+        ExtractionResult::NoContent
+        | ExtractionResult::Skipped(_)
+        | ExtractionResult::Failed(_) => return Vec::new(),
+    };
+
+    let line_of =
+        |offset: usize| memchr::memchr_iter(b'\n', &content.as_bytes()[..offset]).count() + 1;
+
+    let mut out: Vec<(ExtractedCommand, usize)> = Vec::new();
+    for heredoc in extracted {
+        if heredoc.delimiter.is_none() || heredoc.heredoc_type == Some(HeredocType::HereString) {
+            continue;
+        }
+        if heredoc
+            .target_command
+            .as_deref()
+            .is_some_and(is_non_executing_heredoc_command)
+        {
+            continue;
+        }
+
+        let start = content[..heredoc.byte_range.start]
+            .rfind('\n')
+            .map_or(0, |pos| pos + 1);
+        let head = content[start..].lines().next().unwrap_or_default();
+        // Extraction detected the language against the whole file; re-detect
+        // against the heredoc's own command line.
+        let (language, _) = ScriptLanguage::detect(head, &heredoc.content);
+        if matches!(language, ScriptLanguage::Bash | ScriptLanguage::Unknown) {
+            continue;
+        }
+
+        let end = heredoc.byte_range.end.min(content.len());
+        let text = content[start..end].trim_end_matches(['\n', '\r']);
+        let line = line_of(start);
+        let end_line = line_of(start + text.len());
+
+        // Nested or overlapping matches are already covered by the outer heredoc.
+        if out.last().is_some_and(|(_, last_end)| line <= *last_end) {
+            continue;
+        }
+
+        out.push((
+            ExtractedCommand {
+                file: file.to_string(),
+                line,
+                col: None,
+                extractor_id: EXTRACTOR_ID_SHELL_HEREDOC.to_string(),
+                command: text.to_string(),
+                metadata: Some(serde_json::json!({ "language": language.label() })),
+                suppression: None,
+            },
+            end_line,
+        ));
     }
 
     out
 }
 
+/// Returns the text of a trailing shell comment (after `#`), if any.
+fn shell_inline_comment(line: &str) -> Option<&str> {
+    let code = strip_shell_inline_comment(line);
+    line.get(code.len()..)
+        .and_then(|rest| rest.strip_prefix('#'))
+}
+
+/// Parse `dcg:ignore <rule_id> <reason>` from a comment body.
+///
+/// Both the rule id (`pack:pattern`) and a non-empty reason are required;
+/// anything else is not treated as a suppression.
+fn parse_dcg_ignore_comment(comment: &str) -> Option<InlineSuppression> {
+    let rest = comment.trim_start().strip_prefix("dcg:ignore")?;
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = rest.trim_start();
+    let (rule_id, reason) = rest.split_once(char::is_whitespace)?;
+    let reason = reason.trim();
+    if !rule_id.contains(':') || reason.is_empty() {
+        return None;
+    }
+    Some(InlineSuppression {
+        rule_id: rule_id.to_string(),
+        reason: reason.to_string(),
+    })
+}
+
 /// Split a shell line into (segment, continues) where `continues` is true when
 /// the line ends with an unescaped backslash outside of single quotes and
 /// before any inline comment.
@@ -1201,7 +2755,10 @@ fn extract_shell_script_with_offset_and_id(
 
     for cmd in &mut extracted {
         cmd.line = cmd.line.saturating_add(offset);
-        cmd.extractor_id = extractor_id.to_string();
+        // Embedded-language heredocs keep their id so they are AST-matched.
+        if cmd.extractor_id != EXTRACTOR_ID_SHELL_HEREDOC {
+            cmd.extractor_id = extractor_id.to_string();
+        }
     }
 
     extracted
@@ -1242,6 +2799,7 @@ fn extract_shell_command_line(
         extractor_id: "shell.script".to_string(),
         command: candidate.to_string(),
         metadata: None,
+        suppression: None,
     })
 }
 
@@ -1331,70 +2889,10 @@ fn is_shell_var_name(s: &str) -> bool {
     it.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
 
-fn keyword_contains_whitespace(keyword: &str) -> bool {
-    keyword.bytes().any(|byte| byte.is_ascii_whitespace())
-}
-
-fn keyword_matches_with_whitespace(haystack: &str, keyword: &str) -> bool {
-    let parts: Vec<&str> = keyword.split_whitespace().collect();
-    if parts.is_empty() {
-        return false;
-    }
-
-    let hay = haystack.as_bytes();
-    let first = parts[0].as_bytes();
-    if first.len() > hay.len() {
-        return false;
-    }
-
-    let mut offset = 0;
-    while let Some(pos) = memmem::find(&hay[offset..], first) {
-        let start = offset + pos;
-        let mut idx = start + first.len();
-        let mut matched = true;
-
-        for part in parts.iter().skip(1) {
-            let mut ws = idx;
-            while ws < hay.len() && hay[ws].is_ascii_whitespace() {
-                ws += 1;
-            }
-            if ws == idx {
-                matched = false;
-                break;
-            }
-            idx = ws;
-
-            let part_bytes = part.as_bytes();
-            if idx + part_bytes.len() > hay.len() || &hay[idx..idx + part_bytes.len()] != part_bytes
-            {
-                matched = false;
-                break;
-            }
-            idx += part_bytes.len();
-        }
-
-        if matched {
-            return true;
-        }
-
-        offset = start + 1;
-    }
-
-    false
-}
-
 fn contains_any_keyword(haystack: &str, keywords: &[&'static str]) -> bool {
-    keywords.iter().any(|keyword| {
-        if keyword.is_empty() {
-            return false;
-        }
-
-        if keyword_contains_whitespace(keyword) {
-            keyword_matches_with_whitespace(haystack, keyword)
-        } else {
-            haystack.contains(keyword)
-        }
-    })
+    keywords
+        .iter()
+        .any(|keyword| crate::packs::keyword_matches_substring(haystack, keyword))
 }
 
 fn strip_shell_inline_comment(s: &str) -> &str {
@@ -1551,7 +3049,7 @@ pub fn extract_dockerfile_from_str(
 
         let full_trimmed = command.trim();
         let cmd_part = if full_trimmed.len() > 4 {
-            full_trimmed[4..].trim_start()
+            strip_dockerfile_run_flags(full_trimmed[4..].trim_start())
         } else {
             continue;
         };
@@ -1572,6 +3070,7 @@ pub fn extract_dockerfile_from_str(
                         extractor_id: "dockerfile.run.exec".to_string(),
                         command: joined,
                         metadata: None,
+                        suppression: None,
                     });
                 }
             }
@@ -1595,12 +3094,23 @@ pub fn extract_dockerfile_from_str(
             extractor_id: "dockerfile.run".to_string(),
             command: cmd_part.to_string(),
             metadata: None,
+            suppression: None,
         });
     }
 
     out
 }
 
+/// Skip BuildKit `RUN` flags (`--mount=...`, `--network=...`, `--security=...`)
+/// so the command text starts at the command itself.
+fn strip_dockerfile_run_flags(mut rest: &str) -> &str {
+    while rest.starts_with("--") {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        rest = rest[end..].trim_start();
+    }
+    rest
+}
+
 fn join_dockerfile_continuation(
     lines: &[&str],
     start_idx: usize,
@@ -2800,6 +4310,7 @@ pub fn extract_package_json_from_str(
                 extractor_id: EXTRACTOR_ID.to_string(),
                 command: script_cmd.to_string(),
                 metadata: Some(serde_json::json!({ "script_name": script_name })),
+                suppression: None,
             });
         }
     }
@@ -2927,6 +4438,7 @@ pub fn extract_terraform_from_str(
                                     metadata: Some(serde_json::Value::String(
                                         "provisioner: local-exec".to_string(),
                                     )),
+                                    suppression: None,
                                 });
                             }
                         }
@@ -2995,6 +4507,7 @@ pub fn extract_terraform_from_str(
                                             metadata: Some(serde_json::Value::String(
                                                 "provisioner: remote-exec".to_string(),
                                             )),
+                                            suppression: None,
                                         });
                                     }
                                 }
@@ -3018,6 +4531,7 @@ pub fn extract_terraform_from_str(
                                                 metadata: Some(serde_json::Value::String(
                                                     "provisioner: remote-exec".to_string(),
                                                 )),
+                                                suppression: None,
                                             });
                                         }
                                     }
@@ -3344,6 +4858,7 @@ fn extract_docker_compose_command(
                 extractor_id: extractor_id.to_string(),
                 command: cmd,
                 metadata: None,
+                suppression: None,
             });
         }
         return out;
@@ -3398,36 +4913,214 @@ fn extract_docker_compose_command(
                     extractor_id: extractor_id.to_string(),
                     command: cmd,
                     metadata: None,
+                    suppression: None,
                 });
             }
         }
-        return out;
+        return out;
+    }
+
+    // Handle inline string: command: /bin/sh -c "rm -rf /"
+    // Only strip quotes if they form a matching pair at both ends
+    // (don't strip trailing quote from `sh -c "cmd"` which has internal quotes)
+    let cmd = if (value.starts_with('"') && value.ends_with('"'))
+        || (value.starts_with('\'') && value.ends_with('\''))
+    {
+        value[1..value.len() - 1].to_string()
+    } else {
+        value.to_string()
+    };
+    if enabled_keywords.is_empty() || contains_any_keyword(&cmd, enabled_keywords) {
+        out.push(ExtractedCommand {
+            file: file.to_string(),
+            line: line_no,
+            col: None,
+            extractor_id: extractor_id.to_string(),
+            command: cmd,
+            metadata: None,
+            suppression: None,
+        });
+    }
+
+    out
+}
+
+// ============================================================================
+// Deployment lifecycle config extractor (Procfile, netlify.toml, vercel.json)
+// ============================================================================
+
+/// Keys in `vercel.json` whose values are shell commands.
+const VERCEL_COMMAND_KEYS: &[&str] = &[
+    "buildCommand",
+    "installCommand",
+    "devCommand",
+    "ignoreCommand",
+];
+
+/// Keys in a `netlify.toml` table whose values are shell commands.
+const NETLIFY_COMMAND_KEYS: &[&str] = &["command", "ignore"];
+
+fn is_lifecycle_config_path(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(std::ffi::OsStr::to_str) else {
+        return false;
+    };
+    matches!(file_name, "Procfile" | "netlify.toml" | "vercel.json")
+}
+
+/// Extract deploy-time commands from platform lifecycle configs.
+///
+/// Extracts:
+/// - `Procfile`: every `<process>: <command>` entry (including Heroku `release:`)
+/// - `netlify.toml`: `command` / `ignore` in `[build]`, `[dev]`, and `[context.*]`
+/// - `vercel.json`: top-level `buildCommand`, `installCommand`, `devCommand`,
+///   `ignoreCommand`
+///
+/// The originating key is recorded as `metadata.source_key`. Any other key is
+/// ignored, even if it looks like a command.
+#[must_use]
+pub fn extract_lifecycle_config_from_str(
+    file: &str,
+    content: &str,
+    enabled_keywords: &[&'static str],
+) -> Vec<ExtractedCommand> {
+    let file_name = Path::new(file)
+        .file_name()
+        .and_then(std::ffi::OsStr::to_str)
+        .unwrap_or(file);
+
+    let commands = match file_name {
+        "Procfile" => lifecycle_procfile_commands(content),
+        "netlify.toml" => lifecycle_netlify_commands(content),
+        "vercel.json" => lifecycle_vercel_commands(content),
+        _ => Vec::new(),
+    };
+
+    commands
+        .into_iter()
+        .filter(|(_, _, cmd)| {
+            enabled_keywords.is_empty() || contains_any_keyword(cmd, enabled_keywords)
+        })
+        .map(|(line, source_key, cmd)| ExtractedCommand {
+            file: file.to_string(),
+            line,
+            col: None,
+            extractor_id: "config.lifecycle".to_string(),
+            command: cmd,
+            metadata: Some(serde_json::json!({ "source_key": source_key })),
+            suppression: None,
+        })
+        .collect()
+}
+
+/// `(line, source_key, command)` for each `<process>: <command>` line.
+fn lifecycle_procfile_commands(content: &str) -> Vec<(usize, String, String)> {
+    let mut out = Vec::new();
+    for (idx, raw_line) in content.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((process, cmd)) = line.split_once(':') else {
+            continue;
+        };
+        let process = process.trim();
+        let cmd = cmd.trim();
+        let valid_process = !process.is_empty()
+            && process
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+        if valid_process && !cmd.is_empty() {
+            out.push((idx + 1, process.to_string(), cmd.to_string()));
+        }
+    }
+    out
+}
+
+/// `(line, source_key, command)` for known command keys in `netlify.toml`.
+fn lifecycle_netlify_commands(content: &str) -> Vec<(usize, String, String)> {
+    let Ok(doc) = toml::from_str::<toml::Value>(content) else {
+        return Vec::new();
+    };
+
+    let mut tables: Vec<(String, &toml::Value)> = Vec::new();
+    for name in ["build", "dev"] {
+        if let Some(table) = doc.get(name) {
+            tables.push((name.to_string(), table));
+        }
+    }
+    if let Some(contexts) = doc.get("context").and_then(toml::Value::as_table) {
+        for (name, table) in contexts {
+            tables.push((format!("context.{name}"), table));
+        }
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut out = Vec::new();
+    for (table_path, table) in tables {
+        for key in NETLIFY_COMMAND_KEYS {
+            let Some(cmd) = table.get(*key).and_then(toml::Value::as_str) else {
+                continue;
+            };
+            let line = find_toml_key_line(&lines, &table_path, key);
+            out.push((line, format!("{table_path}.{key}"), cmd.to_string()));
+        }
     }
+    out
+}
 
-    // Handle inline string: command: /bin/sh -c "rm -rf /"
-    // Only strip quotes if they form a matching pair at both ends
-    // (don't strip trailing quote from `sh -c "cmd"` which has internal quotes)
-    let cmd = if (value.starts_with('"') && value.ends_with('"'))
-        || (value.starts_with('\'') && value.ends_with('\''))
-    {
-        value[1..value.len() - 1].to_string()
-    } else {
-        value.to_string()
+/// `(line, source_key, command)` for known top-level command keys in `vercel.json`.
+fn lifecycle_vercel_commands(content: &str) -> Vec<(usize, String, String)> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
     };
-    if enabled_keywords.is_empty() || contains_any_keyword(&cmd, enabled_keywords) {
-        out.push(ExtractedCommand {
-            file: file.to_string(),
-            line: line_no,
-            col: None,
-            extractor_id: extractor_id.to_string(),
-            command: cmd,
-            metadata: None,
-        });
-    }
 
+    let lines = build_json_line_map(content);
+    let mut out = Vec::new();
+    for key in VERCEL_COMMAND_KEYS {
+        let Some(cmd) = json.get(*key).and_then(serde_json::Value::as_str) else {
+            continue;
+        };
+        let needle = format!("\"{key}\"");
+        let line = lines
+            .iter()
+            .position(|line| line.contains(&needle))
+            .map_or(1, |idx| idx + 1);
+        out.push((line, (*key).to_string(), cmd.to_string()));
+    }
     out
 }
 
+/// Find the 1-based line of `key = ...` inside the `[table_path]` header.
+///
+/// Falls back to line 1 for layouts this simple scan doesn't follow (inline
+/// tables, dotted keys).
+fn find_toml_key_line(lines: &[&str], table_path: &str, key: &str) -> usize {
+    let mut current = String::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.split(']').next())
+        {
+            current = header
+                .split('.')
+                .map(|part| part.trim().trim_matches('"'))
+                .collect::<Vec<_>>()
+                .join(".");
+            continue;
+        }
+        if current == table_path {
+            let Some(rest) = trimmed.strip_prefix(key) else {
+                continue;
+            };
+            if rest.trim_start().starts_with('=') {
+                return idx + 1;
+            }
+        }
+    }
+    1
+}
+
 #[must_use]
 pub fn build_report(
     mut findings: Vec<ScanFinding>,
@@ -3461,15 +5154,478 @@ pub fn build_report(
         summary: ScanSummary {
             files_scanned,
             files_skipped,
+            skipped: ScanSkipCounts::default(),
             commands_extracted,
             findings_total: findings.len(),
             decisions,
             severities,
             max_findings_reached,
+            risk_score: risk_score(&findings),
+            files_dcgignored: 0,
+            findings_suppressed: 0,
+            findings_scan_allowed: 0,
+            findings_baselined: 0,
             elapsed_ms,
         },
         findings,
+        suppressed: Vec::new(),
+    }
+}
+
+/// Identity of a finding for baseline matching: rule ID, file, and the
+/// normalized command with whitespace collapsed. Line numbers are ignored so
+/// small edits elsewhere in a file don't churn the baseline.
+fn baseline_key(finding: &ScanFinding) -> (String, String, String) {
+    let command = finding
+        .normalized_command
+        .as_deref()
+        .unwrap_or(&finding.extracted_command);
+    (
+        finding.rule_id.clone().unwrap_or_default(),
+        finding.file.clone(),
+        command.split_whitespace().collect::<Vec<_>>().join(" "),
+    )
+}
+
+/// Load a baseline: a `ScanReport` previously written by
+/// `dcg scan --format json`.
+///
+/// # Errors
+///
+/// Returns a message if the file cannot be read or is not a scan report.
+pub fn load_baseline(path: &Path) -> Result<ScanReport, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read baseline {}: {e}", path.display()))?;
+    serde_json::from_str(&content).map_err(|e| {
+        format!(
+            "invalid baseline {} (expected a scan JSON report): {e}",
+            path.display()
+        )
+    })
+}
+
+/// Drop findings already recorded in `baseline` from `report`, so only new
+/// findings are listed and can fail the scan.
+///
+/// Matching is by [`baseline_key`] and counts occurrences: a second copy of a
+/// baselined command in the same file is still new. Returns the current
+/// findings that matched a baseline entry; baseline entries with no match
+/// have been resolved.
+pub fn apply_baseline(report: &mut ScanReport, baseline: &ScanReport) -> Vec<ScanFinding> {
+    let mut remaining: HashMap<(String, String, String), usize> = HashMap::new();
+    for finding in &baseline.findings {
+        *remaining.entry(baseline_key(finding)).or_default() += 1;
+    }
+
+    let (known, new): (Vec<ScanFinding>, Vec<ScanFinding>) = std::mem::take(&mut report.findings)
+        .into_iter()
+        .partition(|finding| match remaining.get_mut(&baseline_key(finding)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        });
+
+    let summary = &report.summary;
+    let mut rebuilt = build_report(
+        new,
+        summary.files_scanned,
+        summary.files_skipped,
+        summary.commands_extracted,
+        summary.max_findings_reached,
+        summary.elapsed_ms,
+    );
+    rebuilt.summary.skipped = summary.skipped.clone();
+    rebuilt.summary.files_dcgignored = summary.files_dcgignored;
+    rebuilt.summary.findings_suppressed = summary.findings_suppressed;
+    rebuilt.summary.findings_scan_allowed = summary.findings_scan_allowed;
+    rebuilt.summary.findings_baselined = known.len();
+    rebuilt.suppressed = std::mem::take(&mut report.suppressed);
+    *report = rebuilt;
+    known
+}
+
+/// A baseline recording every finding in `report`, for
+/// `dcg scan --write-baseline`.
+///
+/// Timing is dropped so rewriting an unchanged baseline yields the same file.
+#[must_use]
+pub fn baseline_from_report(report: &ScanReport) -> ScanReport {
+    build_report(
+        report.findings.clone(),
+        report.summary.files_scanned,
+        report.summary.files_skipped,
+        report.summary.commands_extracted,
+        report.summary.max_findings_reached,
+        None,
+    )
+}
+
+/// The baseline to write after a passing run.
+///
+/// Holds only the entries still present (`known`, as returned by
+/// [`apply_baseline`]), with current line numbers. Resolved entries are
+/// dropped and new findings are never added.
+#[must_use]
+pub fn pruned_baseline(known: Vec<ScanFinding>, report: &ScanReport) -> ScanReport {
+    build_report(
+        known,
+        report.summary.files_scanned,
+        report.summary.files_skipped,
+        report.summary.commands_extracted,
+        false,
+        None,
+    )
+}
+
+/// One source line rewritten by `dcg scan --fix`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScanFix {
+    pub file: String,
+    pub line: usize,
+    /// Rules whose auto-fix produced `after` (usually one).
+    pub rule_ids: Vec<String>,
+    pub before: String,
+    pub after: String,
+}
+
+/// A file written by [`apply_fixes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedFile {
+    pub path: PathBuf,
+    /// Copy of the original, made when git could not restore it.
+    pub backup: Option<PathBuf>,
+}
+
+/// Plan in-place fixes for findings whose rule registers an
+/// [`AutoFixRewrite`](crate::suggestions::AutoFixRewrite).
+///
+/// A rewrite is kept only if the finding's source line triggers the rule on
+/// its own and the rewritten line no longer does. Everything else (no
+/// registered rewrite, multi-line commands, text the rewrite does not
+/// expect) is left untouched.
+#[must_use]
+pub fn plan_fixes(
+    findings: &[ScanFinding],
+    options: &ScanOptions,
+    config: &Config,
+    ctx: &ScanEvalContext,
+) -> Vec<ScanFix> {
+    let mut sources: HashMap<&str, Option<Vec<String>>> = HashMap::new();
+    let mut fixes: Vec<ScanFix> = Vec::new();
+
+    for finding in findings {
+        let Some(rule_id) = finding.rule_id.as_deref() else {
+            continue;
+        };
+        let Some(rewrite) = crate::suggestions::get_auto_fix(rule_id) else {
+            continue;
+        };
+        let Some(lines) = sources
+            .entry(finding.file.as_str())
+            .or_insert_with(|| {
+                std::fs::read_to_string(&finding.file)
+                    .ok()
+                    .map(|content| content.lines().map(str::to_string).collect())
+            })
+            .as_ref()
+        else {
+            continue;
+        };
+        let Some(original) = finding.line.checked_sub(1).and_then(|idx| lines.get(idx)) else {
+            continue;
+        };
+
+        let existing = fixes
+            .iter()
+            .position(|fix| fix.file == finding.file && fix.line == finding.line);
+        let current = existing.map_or(original.as_str(), |idx| fixes[idx].after.as_str());
+        let Some(after) = rewrite.apply(current) else {
+            continue;
+        };
+        let triggers = |text: &str| {
+            let command = ExtractedCommand {
+                file: finding.file.clone(),
+                line: finding.line,
+                col: None,
+                extractor_id: finding.extractor_id.clone(),
+                command: text.to_string(),
+                metadata: None,
+                suppression: None,
+            };
+            evaluate_extracted_command(&command, options, config, ctx)
+                .is_some_and(|hit| hit.rule_id.as_deref() == Some(rule_id))
+        };
+        if !triggers(current) || triggers(&after) {
+            continue;
+        }
+
+        match existing {
+            Some(idx) => {
+                fixes[idx].after = after;
+                fixes[idx].rule_ids.push(rule_id.to_string());
+            }
+            None => fixes.push(ScanFix {
+                file: finding.file.clone(),
+                line: finding.line,
+                rule_ids: vec![rule_id.to_string()],
+                before: original.clone(),
+                after,
+            }),
+        }
+    }
+
+    fixes
+}
+
+/// Write `fixes` to disk, preserving line endings.
+///
+/// Files that are tracked and unmodified in git are rewritten in place (git
+/// holds the original). Any other file is first copied to `<file>.dcg.bak`.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or written, or if a fixed line
+/// no longer matches what was scanned.
+pub fn apply_fixes(fixes: &[ScanFix]) -> Result<Vec<FixedFile>, ScanError> {
+    let mut by_file: Vec<(&str, Vec<&ScanFix>)> = Vec::new();
+    for fix in fixes {
+        match by_file.iter_mut().find(|(file, _)| *file == fix.file) {
+            Some((_, file_fixes)) => file_fixes.push(fix),
+            None => by_file.push((fix.file.as_str(), vec![fix])),
+        }
+    }
+
+    let mut written = Vec::new();
+    for (file, file_fixes) in by_file {
+        let path = PathBuf::from(file);
+        let io_error = |source| ScanError::Io {
+            path: path.clone(),
+            source,
+        };
+        let content = std::fs::read_to_string(&path).map_err(io_error)?;
+
+        let mut out = String::with_capacity(content.len());
+        for (idx, raw) in content.split_inclusive('\n').enumerate() {
+            let body = raw
+                .strip_suffix("\r\n")
+                .or_else(|| raw.strip_suffix('\n'))
+                .unwrap_or(raw);
+            let Some(fix) = file_fixes.iter().find(|fix| fix.line == idx + 1) else {
+                out.push_str(raw);
+                continue;
+            };
+            if body != fix.before {
+                return Err(ScanError::InvalidPath {
+                    path,
+                    reason: format!("line {} changed since it was scanned", fix.line),
+                });
+            }
+            out.push_str(&fix.after);
+            out.push_str(&raw[body.len()..]);
+        }
+
+        let backup = if is_clean_in_git(&path) {
+            None
+        } else {
+            let mut backup = path.clone().into_os_string();
+            backup.push(".dcg.bak");
+            let backup = PathBuf::from(backup);
+            std::fs::copy(&path, &backup).map_err(io_error)?;
+            Some(backup)
+        };
+        std::fs::write(&path, out).map_err(io_error)?;
+        written.push(FixedFile { path, backup });
+    }
+
+    Ok(written)
+}
+
+/// Whether `path` is tracked by git with no uncommitted changes.
+fn is_clean_in_git(path: &Path) -> bool {
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let Some(name) = path.file_name() else {
+        return false;
+    };
+    std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["status", "--porcelain", "--ignored", "--"])
+        .arg(name)
+        .output()
+        .is_ok_and(|output| output.status.success() && output.stdout.is_empty())
+}
+
+/// Render `fixes` as a unified diff (one hunk per rewritten line).
+#[must_use]
+pub fn format_fix_diff(fixes: &[ScanFix]) -> String {
+    let mut out = String::new();
+    let mut current_file: Option<&str> = None;
+    for fix in fixes {
+        if current_file != Some(fix.file.as_str()) {
+            let _ = writeln!(out, "--- a/{}\n+++ b/{}", fix.file, fix.file);
+            current_file = Some(fix.file.as_str());
+        }
+        let _ = writeln!(
+            out,
+            "@@ -{line} +{line} @@ {}\n-{}\n+{}",
+            fix.rule_ids.join(", "),
+            fix.before,
+            fix.after,
+            line = fix.line
+        );
+    }
+    out
+}
+
+/// Parsed `CODEOWNERS` file for `dcg scan --codeowners`.
+///
+/// Patterns follow GitHub's rules: gitignore-style globs, anchored when they
+/// contain a `/`, and the last matching line wins. A pattern with no owners
+/// marks its paths as unowned.
+#[derive(Debug, Clone)]
+pub struct CodeOwners {
+    /// Directory the patterns are relative to.
+    root: PathBuf,
+    rules: Vec<(regex::Regex, Vec<String>)>,
+}
+
+impl CodeOwners {
+    /// Parse `content` with patterns relative to `root`. Lines whose pattern
+    /// cannot be compiled are ignored, as GitHub does.
+    #[must_use]
+    pub fn parse(content: &str, root: PathBuf) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let mut tokens = line
+                    .split_whitespace()
+                    .take_while(|token| !token.starts_with('#'));
+                let pattern = codeowners_pattern_regex(tokens.next()?)?;
+                Some((pattern, tokens.map(str::to_string).collect()))
+            })
+            .collect();
+        Self { root, rules }
+    }
+
+    /// Load a `CODEOWNERS` file. Patterns are resolved against the directory
+    /// holding it, or that directory's parent for `.github/` and `docs/`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScanError::Io`] if the file cannot be read.
+    pub fn load(path: &Path) -> Result<Self, ScanError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ScanError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let mut root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        if root
+            .file_name()
+            .is_some_and(|name| name == ".github" || name == "docs")
+        {
+            root.pop();
+        }
+        Ok(Self::parse(&content, root))
+    }
+
+    /// Owners of `rel_path` (relative to the root, `/`-separated); `None`
+    /// when no line matches or the last match lists no owners.
+    #[must_use]
+    pub fn owners_of(&self, rel_path: &str) -> Option<&[String]> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.is_match(rel_path))
+            .map(|(_, owners)| owners.as_slice())
+            .filter(|owners| !owners.is_empty())
+    }
+
+    /// Owners of a scanned `file` (as reported in a finding), joined with
+    /// spaces.
+    #[must_use]
+    pub fn owner_of_file(&self, file: &str) -> Option<String> {
+        let path = Path::new(file);
+        let absolute = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+        let absolute = std::fs::canonicalize(&absolute).unwrap_or(absolute);
+        let rel = absolute.strip_prefix(&self.root).ok()?;
+        let rel = rel
+            .components()
+            .filter_map(|component| match component {
+                std::path::Component::Normal(part) => Some(part.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        self.owners_of(&rel).map(|owners| owners.join(" "))
+    }
+
+    /// Set `owner` on every finding in `report`, suppressed ones included.
+    pub fn annotate(&self, report: &mut ScanReport) {
+        for finding in report
+            .findings
+            .iter_mut()
+            .chain(report.suppressed.iter_mut())
+        {
+            finding.owner = Some(self.owner_of_file(&finding.file));
+        }
+    }
+}
+
+/// Compile a `CODEOWNERS` pattern into a regex over root-relative paths.
+fn codeowners_pattern_regex(pattern: &str) -> Option<regex::Regex> {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let body = trimmed.trim_start_matches('/');
+    if body.is_empty() {
+        return None;
+    }
+
+    let mut re = String::from("^");
+    if !anchored {
+        re.push_str("(?:.*/)?");
+    }
+    let segments: Vec<&str> = body.split('/').collect();
+    for (idx, segment) in segments.iter().enumerate() {
+        let last = idx + 1 == segments.len();
+        if *segment == "**" {
+            re.push_str(if last { ".*" } else { "(?:.*/)?" });
+            continue;
+        }
+        for ch in segment.chars() {
+            match ch {
+                '*' => re.push_str("[^/]*"),
+                '?' => re.push_str("[^/]"),
+                _ => re.push_str(&regex::escape(ch.encode_utf8(&mut [0; 4]))),
+            }
+        }
+        if !last {
+            re.push('/');
+        }
+    }
+
+    // `docs/` owns everything below it; `docs/*` only its direct children;
+    // a plain name owns the file or the whole directory it names.
+    let last_has_wildcard = segments
+        .last()
+        .is_some_and(|segment| *segment != "**" && segment.contains('*'));
+    if dir_only {
+        re.push_str("/.*$");
+    } else if last_has_wildcard {
+        re.push('$');
+    } else {
+        re.push_str("(?:/.*)?$");
     }
+    regex::Regex::new(&re).ok()
 }
 
 #[cfg(test)]
@@ -3547,28 +5703,248 @@ extra = ["x"]
 fail_on = "nope"
 "#;
 
-        let err = parse_hooks_toml(input).expect_err("should fail");
-        assert!(
-            err.to_lowercase().contains("fail_on") || err.to_lowercase().contains("unknown"),
-            "error should mention the invalid value: {err}"
+        let err = parse_hooks_toml(input).expect_err("should fail");
+        assert!(
+            err.to_lowercase().contains("fail_on") || err.to_lowercase().contains("unknown"),
+            "error should mention the invalid value: {err}"
+        );
+    }
+
+    #[test]
+    fn filter_paths_matches_repo_relative_include_for_absolute_paths() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let repo_root = temp.path().join("repo");
+        std::fs::create_dir_all(repo_root.join("src")).unwrap();
+
+        let file_path = repo_root.join("src").join("main.rs");
+        let paths = vec![file_path.clone()];
+        let include = vec!["src/**".to_string()];
+        let exclude = Vec::new();
+
+        let filtered = filter_paths(&paths, &include, &exclude, Some(&repo_root));
+        assert_eq!(filtered, vec![file_path]);
+    }
+
+    #[test]
+    fn dcgignore_excludes_matching_files_and_directories() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(
+            root.join(DCGIGNORE_FILE),
+            "# fixtures are intentionally destructive\nfixtures/\n*.gen.sh\n!keep.gen.sh\n",
+        )
+        .unwrap();
+
+        let ignore = DcgIgnore::load(root).unwrap().expect(".dcgignore present");
+        assert!(ignore.is_ignored(&root.join("fixtures").join("nested").join("a.sh")));
+        assert!(ignore.is_ignored(&root.join("scripts").join("build.gen.sh")));
+        assert!(!ignore.is_ignored(&root.join("scripts").join("keep.gen.sh")));
+        assert!(!ignore.is_ignored(&root.join("scripts").join("deploy.sh")));
+        assert!(!ignore.is_ignored(Path::new("/elsewhere/fixtures/a.sh")));
+    }
+
+    #[test]
+    fn dcgignore_invalid_pattern_is_config_error() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join(DCGIGNORE_FILE), "scripts/[z-a].sh\n").unwrap();
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let err = scan_paths(
+            &[temp.path().to_path_buf()],
+            &options,
+            &config,
+            &ctx,
+            Some(temp.path()),
+        )
+        .expect_err("invalid .dcgignore must fail the scan");
+
+        assert!(
+            matches!(&err, ScanError::Config { path, .. } if path.ends_with(DCGIGNORE_FILE)),
+            "{err:?}"
+        );
+        assert!(err.to_string().starts_with("Invalid "), "{err}");
+        assert!(std::error::Error::source(&err).is_none());
+    }
+
+    #[test]
+    fn scan_excludes_prune_directories_and_count_excluded_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("node_modules").join("pkg")).unwrap();
+        std::fs::create_dir_all(root.join("scripts")).unwrap();
+        std::fs::write(root.join("node_modules/pkg/install.sh"), "rm -rf /\n").unwrap();
+        std::fs::write(root.join("scripts/deploy.sh"), "rm -rf /\n").unwrap();
+        std::fs::write(root.join("scripts/gen.sh"), "rm -rf /\n").unwrap();
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: DEFAULT_SCAN_EXCLUDES
+                .iter()
+                .map(ToString::to_string)
+                .chain(["scripts/gen.sh".to_string()])
+                .collect(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let report =
+            scan_paths(&[root.to_path_buf()], &options, &config, &ctx, Some(root)).unwrap();
+
+        let files: Vec<_> = report.findings.iter().map(|f| f.file.as_str()).collect();
+        assert_eq!(files.len(), 1, "{files:?}");
+        assert!(files[0].ends_with("deploy.sh"), "{files:?}");
+        // The pruned directory is never read; only the excluded file counts.
+        assert_eq!(report.summary.skipped.excluded, 1);
+        assert_eq!(report.summary.files_skipped, 1);
+    }
+
+    #[test]
+    fn scan_respects_nested_gitignore_and_negation() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("vendor")).unwrap();
+        std::fs::create_dir_all(root.join("scripts").join("generated")).unwrap();
+        std::fs::write(root.join(".gitignore"), "vendor/\n*.gen.sh\n").unwrap();
+        std::fs::write(
+            root.join("scripts").join(".gitignore"),
+            "generated/\n!keep.gen.sh\n",
+        )
+        .unwrap();
+        for file in [
+            "vendor/install.sh",
+            "scripts/build.gen.sh",
+            "scripts/keep.gen.sh",
+            "scripts/generated/out.sh",
+            "scripts/deploy.sh",
+        ] {
+            std::fs::write(root.join(file), "rm -rf /\n").unwrap();
+        }
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let mut options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let scanned_files = |options: &ScanOptions, path: &Path| {
+            let report =
+                scan_paths(&[path.to_path_buf()], options, &config, &ctx, Some(root)).unwrap();
+            let mut files: Vec<String> = report
+                .findings
+                .iter()
+                .map(|f| {
+                    Path::new(&f.file)
+                        .strip_prefix(root)
+                        .unwrap()
+                        .to_string_lossy()
+                        .replace('\\', "/")
+                })
+                .collect();
+            files.sort();
+            files
+        };
+
+        assert_eq!(
+            scanned_files(&options, root),
+            vec!["scripts/deploy.sh", "scripts/keep.gen.sh"]
+        );
+        // Starting below the root still applies the root `.gitignore`.
+        assert_eq!(
+            scanned_files(&options, &root.join("scripts")),
+            vec!["scripts/deploy.sh", "scripts/keep.gen.sh"]
         );
+
+        options.respect_gitignore = false;
+        assert_eq!(scanned_files(&options, root).len(), 5);
     }
 
     #[test]
-    fn filter_paths_matches_repo_relative_include_for_absolute_paths() {
-        use tempfile::TempDir;
+    fn scan_reports_are_identical_for_any_job_count() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        for i in 0..24 {
+            let dir = root.join(format!("dir{}", i % 5));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join(format!("script{i}.sh")),
+                format!("echo {i}\ngit reset --hard\nrm -rf /tmp/build{i}\ngit clean -fd\n"),
+            )
+            .unwrap();
+        }
+        std::fs::write(root.join("empty.sh"), "").unwrap();
 
-        let temp = TempDir::new().unwrap();
-        let repo_root = temp.path().join("repo");
-        std::fs::create_dir_all(repo_root.join("src")).unwrap();
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let scan_json = |jobs: usize, max_findings: usize| {
+            let options = ScanOptions {
+                format: ScanFormat::Json,
+                fail_on: ScanFailOn::Error,
+                max_file_size_bytes: 1024 * 1024,
+                max_findings,
+                redact: ScanRedactMode::None,
+                truncate: 0,
+                scan_allow: Vec::new(),
+                extractors: None,
+                include: Vec::new(),
+                exclude: Vec::new(),
+                respect_gitignore: true,
+                jobs,
+            };
+            let mut report =
+                scan_paths(&[root.to_path_buf()], &options, &config, &ctx, Some(root)).unwrap();
+            report.summary.elapsed_ms = None;
+            serde_json::to_string(&report).unwrap()
+        };
 
-        let file_path = repo_root.join("src").join("main.rs");
-        let paths = vec![file_path.clone()];
-        let include = vec!["src/**".to_string()];
-        let exclude = Vec::new();
+        for max_findings in [1000, 7] {
+            let sequential = scan_json(1, max_findings);
+            assert_eq!(sequential, scan_json(4, max_findings));
+            assert_eq!(sequential, scan_json(0, max_findings));
+        }
+    }
 
-        let filtered = filter_paths(&paths, &include, &exclude, Some(&repo_root));
-        assert_eq!(filtered, vec![file_path]);
+    #[test]
+    fn dcgignore_missing_file_loads_as_none() {
+        let temp = tempfile::TempDir::new().unwrap();
+        assert!(DcgIgnore::load(temp.path()).unwrap().is_none());
     }
 
     #[test]
@@ -3626,6 +6002,23 @@ fail_on = "nope"
         assert!(glob_match("**/*.rs", r"src\main.rs"));
     }
 
+    #[test]
+    fn test_glob_match_double_star_directory_segment() {
+        assert!(glob_match("**/node_modules/**", "node_modules"));
+        assert!(glob_match("**/node_modules/**", "web/node_modules"));
+        assert!(glob_match(
+            "**/node_modules/**",
+            "web/node_modules/pkg/install.sh"
+        ));
+        assert!(!glob_match(
+            "**/node_modules/**",
+            "web/node_modules2/install.sh"
+        ));
+        assert!(!glob_match("**/target/**", "retarget/build.sh"));
+        assert!(glob_match("apps/**/dist/**", "apps/web/dist/run.sh"));
+        assert!(!glob_match("apps/**/dist/**", "libs/web/dist/run.sh"));
+    }
+
     #[test]
     fn test_glob_match_overlapping_prefix_suffix() {
         // Edge case: pattern where prefix+suffix > path length would cause panic
@@ -3654,11 +6047,17 @@ fail_on = "nope"
                     col: None,
                     extractor_id: "x".to_string(),
                     extracted_command: "rm -rf /".to_string(),
+                    normalized_command: None,
+                    metadata: None,
                     decision: ScanDecision::Deny,
                     severity: ScanSeverity::Error,
                     rule_id: Some("core.filesystem:rm-rf-general".to_string()),
                     reason: Some("blocked".to_string()),
+                    reason_code: ReasonCode::Unspecified,
                     suggestion: None,
+                    confidence: None,
+                    suppression: None,
+                    owner: None,
                 },
                 ScanFinding {
                     file: "b".to_string(),
@@ -3666,11 +6065,17 @@ fail_on = "nope"
                     col: None,
                     extractor_id: "x".to_string(),
                     extracted_command: "echo hi".to_string(),
+                    normalized_command: None,
+                    metadata: None,
                     decision: ScanDecision::Warn,
                     severity: ScanSeverity::Warning,
                     rule_id: None,
                     reason: Some("warn".to_string()),
+                    reason_code: ReasonCode::Unspecified,
                     suggestion: None,
+                    confidence: None,
+                    suppression: None,
+                    owner: None,
                 },
             ],
             2,
@@ -3694,11 +6099,17 @@ fail_on = "nope"
                 col: None,
                 extractor_id: "x".to_string(),
                 extracted_command: "cmd".to_string(),
+                normalized_command: None,
+                metadata: None,
                 decision: ScanDecision::Warn,
                 severity: ScanSeverity::Warning,
                 rule_id: Some("pack:rule".to_string()),
                 reason: None,
+                reason_code: ReasonCode::Unspecified,
                 suggestion: None,
+                confidence: None,
+                suppression: None,
+                owner: None,
             },
             ScanFinding {
                 file: "a".to_string(),
@@ -3706,11 +6117,17 @@ fail_on = "nope"
                 col: None,
                 extractor_id: "x".to_string(),
                 extracted_command: "cmd".to_string(),
+                normalized_command: None,
+                metadata: None,
                 decision: ScanDecision::Deny,
                 severity: ScanSeverity::Error,
                 rule_id: Some("pack:rule".to_string()),
                 reason: None,
+                reason_code: ReasonCode::Unspecified,
                 suggestion: None,
+                confidence: None,
+                suppression: None,
+                owner: None,
             },
         ];
 
@@ -3719,6 +6136,142 @@ fail_on = "nope"
         assert_eq!(findings[0].line, 1);
     }
 
+    #[test]
+    fn scan_allow_entry_matches_rule_and_path_globs() {
+        let entry = ScanAllowEntry::parse("core.filesystem:* in tests/fixtures/**").unwrap();
+        assert_eq!(entry.rule_glob, "core.filesystem:*");
+        assert_eq!(entry.path_glob, "tests/fixtures/**");
+
+        let mut finding = ScanFinding {
+            file: "./tests/fixtures/wipe.sh".to_string(),
+            line: 1,
+            col: None,
+            extractor_id: "shell.script".to_string(),
+            extracted_command: "rm -rf /".to_string(),
+            normalized_command: None,
+            metadata: None,
+            decision: ScanDecision::Deny,
+            severity: ScanSeverity::Error,
+            rule_id: Some("core.filesystem:rm-rf-root-home".to_string()),
+            reason: None,
+            reason_code: ReasonCode::Unspecified,
+            suggestion: None,
+            confidence: None,
+            suppression: None,
+            owner: None,
+        };
+        assert!(entry.matches(&finding, None));
+
+        finding.file = "scripts/wipe.sh".to_string();
+        assert!(!entry.matches(&finding, None), "path outside the glob");
+
+        finding.file = "tests/fixtures/wipe.sh".to_string();
+        finding.rule_id = Some("core.git:reset-hard".to_string());
+        assert!(!entry.matches(&finding, None), "rule outside the glob");
+
+        finding.rule_id = None;
+        assert!(!entry.matches(&finding, None), "findings without rule_id");
+
+        assert!(ScanAllowEntry::parse("core.git:*").is_err());
+        assert!(ScanAllowEntry::parse(" in tests/**").is_err());
+    }
+
+    #[test]
+    fn wildcard_match_handles_prefix_infix_and_suffix() {
+        assert!(wildcard_match("*", "core.git:reset-hard"));
+        assert!(wildcard_match("core.*:reset-*", "core.git:reset-hard"));
+        assert!(wildcard_match("*:reset-hard", "core.git:reset-hard"));
+        assert!(!wildcard_match("core.git:*", "core.gitx"));
+        assert!(!wildcard_match("core.git", "core.git:reset-hard"));
+    }
+
+    #[test]
+    fn parse_path_list_skips_blank_lines_and_comments() {
+        let list = "# changed files\nsrc/a.sh\n\n  deploy/run.sh  \n#old.sh\n";
+        assert_eq!(
+            parse_path_list(list),
+            vec![PathBuf::from("src/a.sh"), PathBuf::from("deploy/run.sh")]
+        );
+    }
+
+    #[test]
+    fn scan_counts_missing_paths_as_unreadable() {
+        let dir = tempfile::tempdir().unwrap();
+        let present = dir.path().join("build.sh");
+        std::fs::write(&present, "echo ok\n").unwrap();
+        let missing = dir.path().join("gone.sh");
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Json,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+
+        let report = scan_paths(
+            &[missing, present.clone(), present],
+            &options,
+            &config,
+            &ctx,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(report.summary.files_scanned, 1, "duplicates are merged");
+        assert_eq!(report.summary.files_skipped, 1);
+        assert_eq!(report.summary.skipped.unreadable, 1);
+    }
+
+    #[test]
+    fn scan_skips_binary_files_and_reports_reason() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("payload.sh"), b"rm -rf /\0\x89PNG\x01\x02").unwrap();
+        std::fs::write(dir.path().join("build.sh"), "echo ok\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "rm -rf /\n").unwrap();
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Json,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+
+        let report =
+            scan_paths(&[dir.path().to_path_buf()], &options, &config, &ctx, None).unwrap();
+
+        assert_eq!(report.summary.files_scanned, 1);
+        assert_eq!(report.summary.files_skipped, 2);
+        assert_eq!(
+            report.summary.skipped,
+            ScanSkipCounts {
+                binary: 1,
+                excluded: 1,
+                ..ScanSkipCounts::default()
+            }
+        );
+        assert!(report.findings.is_empty(), "binary file must not be parsed");
+    }
+
     #[test]
     fn evaluator_integration_maps_pack_rule_to_rule_id() {
         let config = default_config();
@@ -3730,6 +6283,12 @@ fail_on = "nope"
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         };
         let extracted = ExtractedCommand {
             file: "test".to_string(),
@@ -3738,6 +6297,7 @@ fail_on = "nope"
             extractor_id: "shell.script".to_string(),
             command: "git reset --hard".to_string(),
             metadata: None,
+            suppression: None,
         };
 
         let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
@@ -3746,6 +6306,236 @@ fail_on = "nope"
         assert_eq!(finding.severity, ScanSeverity::Error);
         assert_eq!(finding.rule_id.as_deref(), Some("core.git:reset-hard"));
         assert!(finding.reason.is_some());
+        assert_eq!(finding.reason_code, ReasonCode::DestroysUncommittedChanges);
+    }
+
+    #[test]
+    fn shell_heredoc_scans_embedded_python_as_python() {
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let content = "\
+#!/bin/bash
+echo start
+python3 <<'EOF'
+import os
+print('cleaning up')
+os.system('rm -rf /')
+EOF
+cat <<EOF > notes.txt
+os.system('rm -rf /')
+EOF
+";
+
+        let extracted = extract_shell_script_from_str("test.sh", content, &["rm"]);
+        let heredoc = extracted
+            .iter()
+            .find(|cmd| cmd.extractor_id == EXTRACTOR_ID_SHELL_HEREDOC)
+            .expect("python heredoc is extracted whole");
+        assert_eq!(heredoc.line, 3);
+        assert!(heredoc.command.ends_with("\nEOF"), "{}", heredoc.command);
+        assert_eq!(
+            heredoc.metadata,
+            Some(serde_json::json!({ "language": "python" }))
+        );
+        // Body lines are not re-scanned as shell commands.
+        assert!(
+            extracted
+                .iter()
+                .all(|cmd| cmd.extractor_id == EXTRACTOR_ID_SHELL_HEREDOC || cmd.line > 7),
+            "{extracted:?}"
+        );
+
+        let finding = evaluate_extracted_command(heredoc, &options, &config, &ctx)
+            .expect("os.system in an embedded python script is reported");
+        assert_eq!(finding.line, 6, "finding points inside the heredoc");
+        assert_eq!(finding.rule_id.as_deref(), Some("heredoc.python:os_system"));
+        assert_eq!(
+            finding.metadata,
+            Some(serde_json::json!({ "language": "python" }))
+        );
+    }
+
+    #[test]
+    fn shell_heredoc_head_line_is_still_scanned_as_shell() {
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let content = "\
+git clean -fdx; python3 <<'EOF'
+import os
+os.system('rm -rf /')
+EOF
+";
+
+        let extracted = extract_shell_script_from_str("test.sh", content, &["git", "rm"]);
+        let head = extracted
+            .iter()
+            .find(|cmd| cmd.extractor_id != EXTRACTOR_ID_SHELL_HEREDOC)
+            .expect("the heredoc head line is extracted as a shell command");
+        assert_eq!(head.line, 1);
+        assert!(
+            extracted
+                .iter()
+                .any(|cmd| cmd.extractor_id == EXTRACTOR_ID_SHELL_HEREDOC),
+            "{extracted:?}"
+        );
+
+        let finding = evaluate_extracted_command(head, &options, &config, &ctx)
+            .expect("git clean -fdx before the heredoc is reported");
+        assert_eq!(
+            finding.rule_id.as_deref(),
+            Some("core.git:clean-force-ignored")
+        );
+    }
+
+    #[test]
+    fn evaluator_integration_reports_normalized_command() {
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let mut options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let mut extracted = ExtractedCommand {
+            file: "test".to_string(),
+            line: 1,
+            col: None,
+            extractor_id: "shell.script".to_string(),
+            command: "\"/usr/bin/git\" reset --hard".to_string(),
+            metadata: None,
+            suppression: None,
+        };
+
+        let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
+            .expect("path-prefixed git reset --hard should be blocked");
+        assert_eq!(finding.extracted_command, "\"/usr/bin/git\" reset --hard");
+        assert_eq!(
+            finding.normalized_command.as_deref(),
+            Some("git reset --hard")
+        );
+
+        // Redaction applies to the normalized form as well.
+        extracted.command = "/usr/bin/git commit -m 'secret' && git reset --hard".to_string();
+        options.redact = ScanRedactMode::Quoted;
+        let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
+            .expect("git reset --hard should be blocked");
+        let normalized = finding.normalized_command.expect("normalized form");
+        assert!(normalized.starts_with("git commit"), "{normalized}");
+        assert!(!normalized.contains("secret"), "{normalized}");
+
+        // Unchanged commands carry no normalized form.
+        extracted.command = "git reset --hard".to_string();
+        let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
+            .expect("git reset --hard should be blocked");
+        assert_eq!(finding.normalized_command, None);
+    }
+
+    #[test]
+    fn context_filtered_patterns_apply_only_in_their_scan_contexts() {
+        let pack = crate::packs::external::parse_pack_string(
+            r"
+id: ci.deploy
+name: CI deploy
+version: 1.0.0
+keywords: [deploy]
+destructive_patterns:
+  - name: deploy-auto-confirm
+    pattern: deploy\s+.*--yes
+    contexts: [github_actions, gitlab_ci]
+  - name: deploy-prod
+    pattern: deploy\s+.*--env[= ]prod
+    severity: critical
+",
+        )
+        .expect("pack parses")
+        .into_pack();
+
+        let config = default_config();
+        let mut ctx = ScanEvalContext::from_config(&config);
+        ctx.external_store = Box::leak(Box::new(crate::packs::ExternalPackStore::from_packs([
+            pack,
+        ])));
+        ctx.external_pack_ids = HashSet::from(["ci.deploy".to_string()]);
+        let options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let rule_for = |extractor_id: &str, command: &str| {
+            let extracted = ExtractedCommand {
+                file: "test".to_string(),
+                line: 1,
+                col: None,
+                extractor_id: extractor_id.to_string(),
+                command: command.to_string(),
+                metadata: None,
+                suppression: None,
+            };
+            evaluate_extracted_command(&extracted, &options, &config, &ctx)
+                .and_then(|finding| finding.rule_id)
+        };
+
+        assert_eq!(
+            rule_for("github_actions.steps.run", "deploy --yes").as_deref(),
+            Some("ci.deploy:deploy-auto-confirm")
+        );
+        assert_eq!(rule_for("shell.script", "deploy --yes"), None);
+
+        // A match filtered out of this context does not hide a later pattern.
+        assert_eq!(
+            rule_for("shell.script", "deploy --yes --env=prod").as_deref(),
+            Some("ci.deploy:deploy-prod")
+        );
+        assert_eq!(
+            rule_for("gitlab_ci.script", "deploy --yes --env=prod").as_deref(),
+            Some("ci.deploy:deploy-auto-confirm")
+        );
     }
 
     #[test]
@@ -3760,6 +6550,12 @@ fail_on = "nope"
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         };
 
         // This is what docker-compose extractor produces for: command: sh -c "git reset --hard && ./start.sh"
@@ -3770,6 +6566,7 @@ fail_on = "nope"
             extractor_id: "docker_compose.command".to_string(),
             command: "sh -c \"git reset --hard && ./start.sh\"".to_string(),
             metadata: None,
+            suppression: None,
         };
 
         let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx);
@@ -3830,6 +6627,12 @@ fail_on = "nope"
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         };
 
         // Step 1: Extract
@@ -3867,38 +6670,184 @@ DOC="rm -rf /" # this is data, not an executed command
 export NOTE="git reset --hard" # also data
 "#;
 
-        let extracted = extract_shell_script_from_str("test.sh", content, &["rm", "git"]);
-        assert!(
-            extracted.is_empty(),
-            "Expected no extracted commands, got: {extracted:?}"
+        let extracted = extract_shell_script_from_str("test.sh", content, &["rm", "git"]);
+        assert!(
+            extracted.is_empty(),
+            "Expected no extracted commands, got: {extracted:?}"
+        );
+    }
+
+    #[test]
+    fn shell_extractor_includes_assignment_with_command_substitution() {
+        let content = r"export NOTE=$(rm -rf /)";
+        let extracted = extract_shell_script_from_str("test.sh", content, &["rm"]);
+
+        assert_eq!(extracted.len(), 1);
+        assert!(extracted[0].command.contains("rm -rf /"));
+    }
+
+    #[test]
+    fn shell_extractor_includes_assignment_with_backticks() {
+        let content = r"NOTE=`git reset --hard`";
+        let extracted = extract_shell_script_from_str("test.sh", content, &["git"]);
+
+        assert_eq!(extracted.len(), 1);
+        assert!(extracted[0].command.contains("git reset --hard"));
+    }
+
+    #[test]
+    fn shell_extractor_extracts_commands_after_export_assignment() {
+        let content = r"export FOO=bar && rm -rf ./tmp";
+        let extracted = extract_shell_script_from_str("test.sh", content, &["rm"]);
+
+        assert_eq!(extracted.len(), 1);
+        assert!(extracted[0].command.contains("rm -rf"));
+    }
+
+    #[test]
+    fn shell_extractor_attaches_dcg_ignore_suppression() {
+        let content = r"
+rm -rf ./cache # dcg:ignore core.filesystem:rm-rf-general cache is rebuilt on boot
+rm -rf ./build
+rm -rf ./dist # dcg:ignore core.filesystem:rm-rf-general
+# dcg:ignore core.filesystem:rm-rf-general standalone comments do not apply
+rm -rf ./tmp
+";
+        let extracted = extract_shell_script_from_str("test.sh", content, &["rm"]);
+        assert_eq!(extracted.len(), 4);
+
+        assert_eq!(
+            extracted[0].suppression,
+            Some(InlineSuppression {
+                rule_id: "core.filesystem:rm-rf-general".to_string(),
+                reason: "cache is rebuilt on boot".to_string(),
+            })
+        );
+        assert!(extracted[1].suppression.is_none(), "no comment");
+        assert!(extracted[2].suppression.is_none(), "reason is required");
+        assert!(
+            extracted[3].suppression.is_none(),
+            "must be a trailing comment"
+        );
+    }
+
+    #[test]
+    fn extractor_selection_runs_only_the_chosen_subset() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("deploy.sh"), "rm -rf /tmp/x\n").unwrap();
+        std::fs::write(dir.path().join("Dockerfile"), "RUN rm -rf /var/x\n").unwrap();
+        std::fs::write(dir.path().join("Makefile"), "clean:\n\trm -rf build\n").unwrap();
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let mut options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let paths = [dir.path().to_path_buf()];
+        let extractor_ids = |options: &ScanOptions| -> Vec<String> {
+            let report = extract_paths(&paths, options, &ctx, None).unwrap();
+            report
+                .commands
+                .into_iter()
+                .map(|c| c.extractor_id)
+                .collect()
+        };
+
+        assert_eq!(
+            extractor_ids(&options),
+            vec!["dockerfile.run", "makefile.recipe", "shell.script"]
+        );
+
+        options.extractors =
+            Some(parse_extractor_selection(&["shell.script", "docker.dockerfile"]).unwrap());
+        assert_eq!(
+            extractor_ids(&options),
+            vec!["dockerfile.run", "shell.script"]
         );
+        let report = extract_paths(&paths, &options, &ctx, None).unwrap();
+        assert_eq!(report.files_scanned, 2, "Makefile is skipped, not read");
     }
 
     #[test]
-    fn shell_extractor_includes_assignment_with_command_substitution() {
-        let content = r"export NOTE=$(rm -rf /)";
-        let extracted = extract_shell_script_from_str("test.sh", content, &["rm"]);
+    fn extractor_selection_rejects_unknown_ids_with_suggestion() {
+        assert_eq!(
+            parse_extractor_selection(&["shell.script", " shell.script", "makefile"]).unwrap(),
+            vec!["shell.script", "makefile"]
+        );
 
-        assert_eq!(extracted.len(), 1);
-        assert!(extracted[0].command.contains("rm -rf /"));
+        let err = parse_extractor_selection(&["dockerfile"]).unwrap_err();
+        assert!(err.contains("did you mean `docker.dockerfile`"), "{err}");
+        let err = parse_extractor_selection(&["shell.scrpt"]).unwrap_err();
+        assert!(err.contains("did you mean `shell.script`"), "{err}");
+        let err = parse_extractor_selection(&["kubernetes"]).unwrap_err();
+        assert!(!err.contains("did you mean"), "{err}");
+        assert!(parse_extractor_selection::<&str>(&[]).is_err());
     }
 
     #[test]
-    fn shell_extractor_includes_assignment_with_backticks() {
-        let content = r"NOTE=`git reset --hard`";
-        let extracted = extract_shell_script_from_str("test.sh", content, &["git"]);
-
-        assert_eq!(extracted.len(), 1);
-        assert!(extracted[0].command.contains("git reset --hard"));
+    fn extractor_ids_are_unique() {
+        let mut ids: Vec<&str> = SCAN_EXTRACTORS.iter().map(|e| e.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), SCAN_EXTRACTORS.len());
     }
 
     #[test]
-    fn shell_extractor_extracts_commands_after_export_assignment() {
-        let content = r"export FOO=bar && rm -rf ./tmp";
-        let extracted = extract_shell_script_from_str("test.sh", content, &["rm"]);
+    fn dcg_ignore_suppresses_only_the_named_rule() {
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let mut extracted = ExtractedCommand {
+            file: "test.sh".to_string(),
+            line: 1,
+            col: None,
+            extractor_id: "shell.script".to_string(),
+            command: "git reset --hard".to_string(),
+            metadata: None,
+            suppression: Some(InlineSuppression {
+                rule_id: "core.git:reset-hard".to_string(),
+                reason: "ci workspace is disposable".to_string(),
+            }),
+        };
 
-        assert_eq!(extracted.len(), 1);
-        assert!(extracted[0].command.contains("rm -rf"));
+        let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
+            .expect("still reported");
+        assert_eq!(
+            finding.suppression.as_ref().map(|s| s.reason.as_str()),
+            Some("ci workspace is disposable")
+        );
+
+        extracted.suppression = Some(InlineSuppression {
+            rule_id: "core.git:clean-force".to_string(),
+            reason: "wrong rule".to_string(),
+        });
+        let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
+            .expect("still reported");
+        assert!(finding.suppression.is_none());
     }
 
     #[test]
@@ -4057,11 +7006,17 @@ resource "null_resource" "test" {
                 col: Some(5),
                 extractor_id: "shell.script".to_string(),
                 extracted_command: "rm -rf /".to_string(),
+                normalized_command: None,
+                metadata: None,
                 decision: ScanDecision::Deny,
                 severity: ScanSeverity::Error,
                 rule_id: Some("core.filesystem:rm-rf-root-home".to_string()),
                 reason: Some("dangerous".to_string()),
+                reason_code: ReasonCode::Unspecified,
                 suggestion: Some("use safer rm".to_string()),
+                confidence: None,
+                suppression: None,
+                owner: None,
             }],
             1,
             0,
@@ -4127,12 +7082,320 @@ resource "null_resource" "test" {
             col: None,
             extractor_id: "test".to_string(),
             extracted_command: "cmd".to_string(),
+            normalized_command: None,
+            metadata: None,
             decision,
             severity,
             rule_id: None,
             reason: None,
+            reason_code: ReasonCode::Unspecified,
             suggestion: None,
+            confidence: None,
+            suppression: None,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn should_fail_with_tags_only_gates_tagged_packs() {
+        let mut tagged = make_finding("a", ScanDecision::Deny, ScanSeverity::Error);
+        tagged.rule_id = Some("core.git:reset-hard".to_string());
+        let mut untagged = make_finding("b", ScanDecision::Deny, ScanSeverity::Error);
+        untagged.rule_id = Some("cloud.aws:ec2-terminate".to_string());
+
+        let data_loss = vec!["data-loss".to_string()];
+
+        let report = build_report(vec![untagged.clone()], 1, 0, 1, false, None);
+        assert!(should_fail(&report, ScanFailOn::Error));
+        assert!(!should_fail_with_tags(
+            &report,
+            ScanFailOn::Error,
+            &data_loss
+        ));
+
+        let report = build_report(vec![untagged, tagged], 2, 0, 2, false, None);
+        assert!(should_fail_with_tags(
+            &report,
+            ScanFailOn::Error,
+            &data_loss
+        ));
+        assert!(should_fail_with_tags(
+            &report,
+            ScanFailOn::Error,
+            &["DATA-LOSS".to_string()]
+        ));
+        assert!(!should_fail_with_tags(
+            &report,
+            ScanFailOn::None,
+            &data_loss
+        ));
+    }
+
+    #[test]
+    fn risk_score_weights_severity_by_confidence() {
+        let mut low_confidence = make_finding("a", ScanDecision::Warn, ScanSeverity::Error);
+        low_confidence.confidence = Some(0.3);
+        let findings = vec![
+            make_finding("b", ScanDecision::Deny, ScanSeverity::Error),
+            make_finding("c", ScanDecision::Warn, ScanSeverity::Warning),
+            make_finding("d", ScanDecision::Allow, ScanSeverity::Info),
+            low_confidence,
+        ];
+
+        // 10 + 3 + 1 + 10 × 0.3
+        assert!((risk_score(&findings) - 17.0).abs() < f64::EPSILON);
+        assert!(risk_score(&[]).abs() < f64::EPSILON);
+
+        let mut reversed = findings.clone();
+        reversed.reverse();
+        assert!((risk_score(&reversed) - risk_score(&findings)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn summary_line_reports_counts_timing_and_exit_code() {
+        let report = build_report(
+            vec![
+                make_finding("a.sh", ScanDecision::Deny, ScanSeverity::Error),
+                make_finding("b.sh", ScanDecision::Deny, ScanSeverity::Error),
+                make_finding("c.sh", ScanDecision::Warn, ScanSeverity::Warning),
+            ],
+            57,
+            0,
+            3,
+            false,
+            Some(412),
+        );
+        assert_eq!(
+            summary_line(&report.summary, 1),
+            "dcg-scan: 3 findings (2 error, 1 warning) across 57 files in 412ms; exit=1"
+        );
+
+        let clean = build_report(vec![], 1, 0, 0, false, None);
+        assert_eq!(
+            summary_line(&clean.summary, 0),
+            "dcg-scan: 0 findings across 1 file; exit=0"
+        );
+    }
+
+    /// Minimal RFC 4180 parser, used to check that CSV output round-trips.
+    fn parse_csv(input: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = input.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, in_quotes) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                ('"', true) => in_quotes = false,
+                ('"', false) => in_quotes = true,
+                (',', false) => row.push(std::mem::take(&mut field)),
+                ('\n', false) => {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                _ => field.push(c),
+            }
         }
+        rows
+    }
+
+    #[test]
+    fn format_scan_csv_writes_header_and_escapes_fields() {
+        let mut tricky = make_finding(
+            "dir, with comma/run.sh",
+            ScanDecision::Deny,
+            ScanSeverity::Error,
+        );
+        tricky.line = 12;
+        tricky.col = Some(4);
+        tricky.rule_id = Some("core.git:reset-hard".to_string());
+        tricky.reason = Some("destroys \"uncommitted\" work".to_string());
+        tricky.extracted_command = "git reset --hard\necho done".to_string();
+        let plain = make_finding("ci.yml", ScanDecision::Warn, ScanSeverity::Warning);
+        let report = build_report(vec![tricky, plain], 2, 0, 2, false, None);
+
+        let csv = format_scan_csv(&report);
+        assert!(csv.starts_with("file,line,col,rule_id,decision,severity,reason,command\n"));
+        assert!(csv.contains("\"dir, with comma/run.sh\""));
+        assert!(csv.contains("\"destroys \"\"uncommitted\"\" work\""));
+
+        let rows = parse_csv(&csv);
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().all(|row| row.len() == 8));
+        // Findings are sorted by file.
+        assert_eq!(
+            rows[2],
+            vec![
+                "dir, with comma/run.sh",
+                "12",
+                "4",
+                "core.git:reset-hard",
+                "deny",
+                "error",
+                "destroys \"uncommitted\" work",
+                "git reset --hard\necho done",
+            ]
+        );
+        assert_eq!(
+            rows[1],
+            vec!["ci.yml", "1", "", "", "warn", "warning", "", "cmd"]
+        );
+
+        let empty = build_report(vec![], 1, 0, 0, false, None);
+        assert_eq!(format_scan_csv(&empty), format!("{SCAN_CSV_HEADER}\n"));
+    }
+
+    #[test]
+    fn format_scan_github_annotations_emits_escaped_workflow_commands() {
+        let mut deny = make_finding("scripts/deploy.sh", ScanDecision::Deny, ScanSeverity::Error);
+        deny.line = 7;
+        deny.col = Some(3);
+        deny.rule_id = Some("core.git:reset-hard".to_string());
+        deny.reason = Some("destroys 100% of\nuncommitted work\r".to_string());
+        let mut warn = make_finding("ci, main.yml", ScanDecision::Warn, ScanSeverity::Warning);
+        warn.line = 2;
+        warn.reason = Some("risky".to_string());
+        let mut info = make_finding("notes.sh", ScanDecision::Allow, ScanSeverity::Info);
+        info.line = 9;
+        info.rule_id = Some("core.git:stash-drop".to_string());
+        let report = build_report(vec![deny, warn, info], 3, 0, 3, false, None);
+
+        let out = format_scan_github_annotations(&report);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.contains(
+            &"::error file=scripts/deploy.sh,line=7,col=3::core.git:reset-hard destroys 100%25 of%0Auncommitted work%0D"
+        ));
+        assert!(lines.contains(&"::warning file=ci%2C main.yml,line=2::risky"));
+        assert!(lines.contains(&"::notice file=notes.sh,line=9::core.git:stash-drop"));
+
+        let clean = build_report(vec![], 1, 0, 0, false, None);
+        assert_eq!(format_scan_github_annotations(&clean), "");
+    }
+
+    #[test]
+    fn format_scan_github_summary_has_table_and_details_per_file() {
+        let mut deny = make_finding("scripts/deploy.sh", ScanDecision::Deny, ScanSeverity::Error);
+        deny.line = 7;
+        deny.col = Some(3);
+        deny.rule_id = Some("core.git:reset-hard".to_string());
+        deny.reason = Some("destroys uncommitted work".to_string());
+        deny.extracted_command = "git reset --hard ```x```".to_string();
+        deny.suggestion = Some("git stash first".to_string());
+        let warn = make_finding(".github/ci.yml", ScanDecision::Warn, ScanSeverity::Warning);
+        let report = build_report(vec![deny, warn], 3, 0, 5, false, None);
+
+        let md = format_scan_github_summary(&report, 0);
+        assert!(md.starts_with("## "));
+        let table = md
+            .find("| File | Errors | Warnings | Info |")
+            .expect("table");
+        let first_details = md.find("<details>").expect("details");
+        assert!(table < first_details, "summary table comes first\n{md}");
+        assert!(md.contains("| `scripts/deploy.sh` | 1 | 0 | 0 |"));
+        assert!(md.contains("| `.github/ci.yml` | 0 | 1 | 0 |"));
+        assert_eq!(md.matches("<details>").count(), 2);
+        assert_eq!(md.matches("</details>").count(), 2);
+        assert!(
+            md.contains(
+                "<summary>\u{274c} <code>scripts/deploy.sh</code> (1 finding(s))</summary>"
+            )
+        );
+        assert!(md.contains(
+            "- [ ] \u{274c} **DENY** line 7:3 `core.git:reset-hard` \u{2014} destroys uncommitted work"
+        ));
+        // Commands containing backticks get a longer fence.
+        assert!(md.contains("  ````sh\n  git reset --hard ```x```\n  ````\n"));
+        assert!(md.contains("git stash first"));
+
+        let limited = format_scan_github_summary(&report, 1);
+        assert_eq!(limited.matches("- [ ]").count(), 1);
+        assert!(limited.contains("Showing 1 of 2 findings"));
+        assert!(
+            limited.contains("| `scripts/deploy.sh` | 1 | 0 | 0 |"),
+            "table lists every file"
+        );
+
+        let clean = build_report(vec![], 4, 0, 9, false, None);
+        let md = format_scan_github_summary(&clean, 0);
+        assert!(md.contains("**No findings**"));
+        assert!(!md.contains("<details>"));
+    }
+
+    #[test]
+    fn apply_baseline_keeps_only_new_findings() {
+        let mut known = make_finding("deploy.sh", ScanDecision::Deny, ScanSeverity::Error);
+        known.rule_id = Some("core.git:reset-hard".to_string());
+        known.extracted_command = "git reset --hard".to_string();
+        let mut resolved = make_finding("deploy.sh", ScanDecision::Warn, ScanSeverity::Warning);
+        resolved.rule_id = Some("core.git:clean-force".to_string());
+        let baseline = build_report(vec![known.clone(), resolved], 1, 0, 2, false, None);
+
+        // Same finding on another line with extra spaces is still known; a
+        // second copy of it is new.
+        let mut moved = known.clone();
+        moved.line = 40;
+        moved.extracted_command = "git  reset   --hard".to_string();
+        let mut copy = known.clone();
+        copy.line = 41;
+        let mut report = build_report(vec![moved, copy], 1, 0, 2, false, Some(5));
+        report.summary.findings_scan_allowed = 3;
+
+        let matched = apply_baseline(&mut report, &baseline);
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].line, 40);
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].line, 41);
+        assert_eq!(report.summary.findings_total, 1);
+        assert_eq!(report.summary.severities.error, 1);
+        assert_eq!(report.summary.findings_baselined, 1);
+        assert_eq!(report.summary.findings_scan_allowed, 3, "other counts kept");
+
+        // Only the still-present entry survives pruning, with its new line.
+        let pruned = pruned_baseline(matched, &report);
+        assert_eq!(pruned.findings.len(), 1);
+        assert_eq!(pruned.findings[0].line, 40);
+        assert_eq!(
+            pruned.findings[0].rule_id.as_deref(),
+            Some("core.git:reset-hard")
+        );
+    }
+
+    #[test]
+    fn should_fail_on_score_requires_exceeding_threshold() {
+        let findings = vec![
+            make_finding("a", ScanDecision::Deny, ScanSeverity::Error),
+            make_finding("b", ScanDecision::Warn, ScanSeverity::Warning),
+        ];
+        let report = build_report(findings, 2, 0, 2, false, None);
+
+        assert!((report.summary.risk_score - 13.0).abs() < f64::EPSILON);
+        assert!(should_fail_on_score(&report, 12.5));
+        assert!(!should_fail_on_score(&report, 13.0));
+        assert!(!should_fail_on_score(&report, 50.0));
+    }
+
+    #[test]
+    fn should_fail_with_tags_ignores_findings_without_rule_id() {
+        let report = build_report(
+            vec![make_finding("a", ScanDecision::Deny, ScanSeverity::Error)],
+            1,
+            0,
+            1,
+            false,
+            None,
+        );
+
+        assert!(should_fail_with_tags(&report, ScanFailOn::Error, &[]));
+        assert!(!should_fail_with_tags(
+            &report,
+            ScanFailOn::Error,
+            &["data-loss".to_string()]
+        ));
     }
 
     // ========================================================================
@@ -4306,6 +7569,12 @@ resource "null_resource" "test" {
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         };
 
         let safe_commands = [
@@ -4326,6 +7595,7 @@ resource "null_resource" "test" {
                 extractor_id: "shell.script".to_string(),
                 command: cmd.to_string(),
                 metadata: None,
+                suppression: None,
             };
 
             let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx);
@@ -4351,6 +7621,12 @@ resource "null_resource" "test" {
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         };
 
         let dangerous_commands = [
@@ -4370,6 +7646,7 @@ resource "null_resource" "test" {
                 extractor_id: "shell.script".to_string(),
                 command: cmd.to_string(),
                 metadata: None,
+                suppression: None,
             };
 
             let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
@@ -4402,6 +7679,12 @@ resource "null_resource" "test" {
             max_findings: 100,
             redact: ScanRedactMode::None,
             truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
         };
 
         let extracted = ExtractedCommand {
@@ -4411,6 +7694,7 @@ resource "null_resource" "test" {
             extractor_id: "shell.script".to_string(),
             command: "git reset --hard HEAD".to_string(),
             metadata: None,
+            suppression: None,
         };
 
         let finding = evaluate_extracted_command(&extracted, &options, &config, &ctx)
@@ -4428,6 +7712,177 @@ resource "null_resource" "test" {
         );
     }
 
+    // ========================================================================
+    // Auto-fix tests
+    // ========================================================================
+
+    fn fix_test_options() -> ScanOptions {
+        ScanOptions {
+            format: ScanFormat::Pretty,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        }
+    }
+
+    #[test]
+    fn fix_rewrites_registered_rule_and_preserves_line_endings() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("deploy.sh");
+        std::fs::write(
+            &script,
+            "#!/bin/sh\r\ngit reset --hard HEAD~1\r\ngit clean -fd\r\necho done\r\n",
+        )
+        .unwrap();
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = fix_test_options();
+        let report =
+            scan_paths(std::slice::from_ref(&script), &options, &config, &ctx, None).unwrap();
+
+        let fixes = plan_fixes(&report.findings, &options, &config, &ctx);
+        assert_eq!(fixes.len(), 2, "{fixes:?}");
+        assert_eq!(fixes[0].line, 2);
+        assert_eq!(fixes[0].rule_ids, vec!["core.git:reset-hard".to_string()]);
+        assert_eq!(fixes[0].after, "git reset --soft HEAD~1");
+        assert_eq!(fixes[1].after, "git clean --dry-run -fd");
+
+        let written = apply_fixes(&fixes).unwrap();
+        assert_eq!(written.len(), 1);
+        let backup = written[0]
+            .backup
+            .as_ref()
+            .expect("untracked file is backed up");
+        assert!(std::fs::read_to_string(backup).unwrap().contains("--hard"));
+        assert_eq!(
+            std::fs::read_to_string(&script).unwrap(),
+            "#!/bin/sh\r\ngit reset --soft HEAD~1\r\ngit clean --dry-run -fd\r\necho done\r\n"
+        );
+
+        let diff = format_fix_diff(&fixes);
+        assert!(diff.contains("-git reset --hard HEAD~1\n+git reset --soft HEAD~1"));
+    }
+
+    #[test]
+    fn fix_leaves_findings_without_auto_fix_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("cleanup.sh");
+        let original = "rm -rf ./build\n";
+        std::fs::write(&script, original).unwrap();
+
+        let config = default_config();
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = fix_test_options();
+        let report =
+            scan_paths(std::slice::from_ref(&script), &options, &config, &ctx, None).unwrap();
+        assert_eq!(report.findings.len(), 1);
+
+        let fixes = plan_fixes(&report.findings, &options, &config, &ctx);
+        assert!(fixes.is_empty(), "{fixes:?}");
+        assert!(apply_fixes(&fixes).unwrap().is_empty());
+        assert_eq!(std::fs::read_to_string(&script).unwrap(), original);
+    }
+
+    // ========================================================================
+    // CODEOWNERS tests
+    // ========================================================================
+
+    #[test]
+    fn codeowners_resolves_nested_paths_last_match_wins() {
+        let owners = CodeOwners::parse(
+            "# default owners\n\
+             *                       @org/everyone\n\
+             *.sh                    @org/shell\n\
+             /infra/                 @org/infra\n\
+             /infra/prod/            @org/sre @alice # inline comment\n\
+             /infra/prod/scratch/\n\
+             docs/*                  @org/docs\n\
+             **/migrations           @org/dba\n",
+            PathBuf::from("/repo"),
+        );
+        let owner = |path: &str| owners.owners_of(path).map(|o| o.join(" "));
+
+        assert_eq!(owner("README.md").as_deref(), Some("@org/everyone"));
+        assert_eq!(owner("scripts/deploy.sh").as_deref(), Some("@org/shell"));
+        assert_eq!(owner("infra/deploy.sh").as_deref(), Some("@org/infra"));
+        assert_eq!(
+            owner("infra/prod/db/reset.sh").as_deref(),
+            Some("@org/sre @alice")
+        );
+        assert_eq!(owner("infra/prod/scratch/tmp.sh"), None);
+        assert_eq!(owner("docs/guide.md").as_deref(), Some("@org/docs"));
+        assert_eq!(
+            owner("docs/nested/guide.md").as_deref(),
+            Some("@org/everyone")
+        );
+        assert_eq!(
+            owner("app/db/migrations/001.sql").as_deref(),
+            Some("@org/dba")
+        );
+        // `/infra/` is anchored: a nested `infra` directory is not covered.
+        assert_eq!(owner("lib/infra/x.sh").as_deref(), Some("@org/shell"));
+    }
+
+    #[test]
+    fn codeowners_annotate_sets_null_owner_for_unmatched_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let github = dir.path().join(".github");
+        std::fs::create_dir_all(&github).unwrap();
+        std::fs::write(github.join("CODEOWNERS"), "/ops/ @org/ops\n").unwrap();
+        std::fs::create_dir_all(dir.path().join("ops/nested")).unwrap();
+        let owned = dir.path().join("ops/nested/run.sh");
+        let unowned = dir.path().join("run.sh");
+        std::fs::write(&owned, "").unwrap();
+        std::fs::write(&unowned, "").unwrap();
+
+        let owners = CodeOwners::load(&github.join("CODEOWNERS")).unwrap();
+        let mut report = build_report(
+            vec![
+                make_finding(
+                    owned.to_str().unwrap(),
+                    ScanDecision::Deny,
+                    ScanSeverity::Error,
+                ),
+                make_finding(
+                    unowned.to_str().unwrap(),
+                    ScanDecision::Deny,
+                    ScanSeverity::Error,
+                ),
+            ],
+            2,
+            0,
+            2,
+            false,
+            None,
+        );
+        owners.annotate(&mut report);
+
+        let finding_for = |path: &Path| {
+            report
+                .findings
+                .iter()
+                .find(|f| f.file == path.to_str().unwrap())
+                .unwrap()
+        };
+        assert_eq!(
+            finding_for(&owned).owner,
+            Some(Some("@org/ops".to_string()))
+        );
+        assert_eq!(finding_for(&unowned).owner, Some(None));
+        let json = serde_json::to_value(finding_for(&unowned)).unwrap();
+        assert!(json["owner"].is_null());
+        assert!(json.as_object().unwrap().contains_key("owner"));
+    }
+
     // ========================================================================
     // Shell extractor tests (git_safety_guard-scan.3.1)
     // ========================================================================
@@ -4519,6 +7974,69 @@ resource "null_resource" "test" {
         assert_eq!(extracted[0].command, "sh -c rm -rf /tmp");
     }
 
+    #[test]
+    fn dockerfile_extractor_strips_run_flags() {
+        let content = "FROM alpine\n\
+            RUN --mount=type=cache,target=/var/cache/apt --network=none \\\n  rm -rf /\n\
+            RUN --mount=type=secret,id=token [\"bash\", \"-c\", \"rm -rf /\"]";
+        let extracted = extract_dockerfile_from_str("Dockerfile", content, &["rm"]);
+        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted[0].line, 2);
+        assert_eq!(extracted[0].extractor_id, "dockerfile.run");
+        assert_eq!(extracted[0].command, "rm -rf /");
+        assert_eq!(extracted[1].line, 4);
+        assert_eq!(extracted[1].extractor_id, "dockerfile.run.exec");
+        assert_eq!(extracted[1].command, "bash -c rm -rf /");
+    }
+
+    #[test]
+    fn dockerfile_run_findings_flow_through_evaluator() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Dockerfile"),
+            "FROM alpine\n\
+             RUN --mount=type=cache,target=/root/.cache rm -rf /\n\
+             RUN [\"bash\", \"-c\", \"rm -rf /\"]\n\
+             RUN docker system prune -af\n\
+             RUN echo ok\n",
+        )
+        .unwrap();
+
+        let mut config = default_config();
+        config.packs.enabled.push("containers.docker".to_string());
+        let ctx = ScanEvalContext::from_config(&config);
+        let options = ScanOptions {
+            format: ScanFormat::Json,
+            fail_on: ScanFailOn::Error,
+            max_file_size_bytes: 1024 * 1024,
+            max_findings: 100,
+            redact: ScanRedactMode::None,
+            truncate: 0,
+            scan_allow: Vec::new(),
+            extractors: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            respect_gitignore: true,
+            jobs: 1,
+        };
+        let report =
+            scan_paths(&[dir.path().to_path_buf()], &options, &config, &ctx, None).unwrap();
+        let flagged: Vec<(usize, &str)> = report
+            .findings
+            .iter()
+            .map(|f| (f.line, f.rule_id.as_deref().unwrap_or_default()))
+            .collect();
+        assert_eq!(flagged.len(), 3, "{flagged:?}");
+        assert_eq!(
+            flagged.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert!(
+            flagged[2].1.starts_with("containers.docker:"),
+            "{flagged:?}"
+        );
+    }
+
     #[test]
     fn dockerfile_extractor_handles_continuations() {
         let content = "FROM alpine\nRUN apt-get update \\\n    && apt-get install curl";
@@ -6295,4 +9813,76 @@ RUN echo "path\\with\\backslashes" && rm -rf /tmp"#;
         assert!(extracted.iter().any(|e| e.command.contains("./build")));
         assert!(extracted.iter().any(|e| e.command.contains("./dist")));
     }
+
+    #[test]
+    fn is_lifecycle_config_path_detects_correctly() {
+        assert!(is_lifecycle_config_path(Path::new("Procfile")));
+        assert!(is_lifecycle_config_path(Path::new("app/netlify.toml")));
+        assert!(is_lifecycle_config_path(Path::new("vercel.json")));
+
+        assert!(!is_lifecycle_config_path(Path::new("Procfile.bak")));
+        assert!(!is_lifecycle_config_path(Path::new("netlify.yml")));
+        assert!(!is_lifecycle_config_path(Path::new("now.json")));
+    }
+
+    #[test]
+    fn lifecycle_extracts_procfile_processes() {
+        let content =
+            "# processes\nweb: bundle exec puma\nrelease: rm -rf ./tmp && rake db:migrate\n";
+
+        let extracted = extract_lifecycle_config_from_str("Procfile", content, &["rm"]);
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].extractor_id, "config.lifecycle");
+        assert_eq!(extracted[0].line, 3);
+        assert_eq!(extracted[0].command, "rm -rf ./tmp && rake db:migrate");
+        assert_eq!(
+            extracted[0].metadata,
+            Some(serde_json::json!({ "source_key": "release" }))
+        );
+    }
+
+    #[test]
+    fn lifecycle_extracts_netlify_known_keys_only() {
+        let content = r#"[build]
+  publish = "rm -rf dist"
+  command = "rm -rf dist && npm run build"
+
+[context.production]
+  command = "git reset --hard && npm run build"
+
+[[redirects]]
+  from = "/rm"
+"#;
+
+        let extracted = extract_lifecycle_config_from_str("netlify.toml", content, &["rm", "git"]);
+        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted[0].line, 3);
+        assert_eq!(
+            extracted[0].metadata,
+            Some(serde_json::json!({ "source_key": "build.command" }))
+        );
+        assert_eq!(extracted[1].line, 6);
+        assert_eq!(extracted[1].command, "git reset --hard && npm run build");
+        assert_eq!(
+            extracted[1].metadata,
+            Some(serde_json::json!({ "source_key": "context.production.command" }))
+        );
+    }
+
+    #[test]
+    fn lifecycle_extracts_vercel_command_fields() {
+        let content = r#"{
+  "framework": "nextjs",
+  "buildCommand": "rm -rf .next && next build",
+  "outputDirectory": "rm -rf out",
+  "rewrites": [{ "source": "/a", "destination": "/b" }]
+}"#;
+
+        let extracted = extract_lifecycle_config_from_str("vercel.json", content, &["rm"]);
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(extracted[0].line, 3);
+        assert_eq!(extracted[0].command, "rm -rf .next && next build");
+
+        assert!(extract_lifecycle_config_from_str("vercel.json", "{ nope", &[]).is_empty());
+    }
 }
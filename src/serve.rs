@@ -0,0 +1,375 @@
+//! HTTP server mode (`dcg serve --http`) for non-shell agents.
+//!
+//! Web IDEs and remote orchestrators that cannot run a shell hook can consult
+//! dcg over HTTP instead:
+//!
+//! - `POST /evaluate` with `{"command": "..."}` returns
+//!   `{"decision": "allow"|"deny", "rule_id": ..., "reason": ...}`.
+//! - `POST /explain` with the same body returns the full `dcg explain
+//!   --format json` trace.
+//!
+//! The evaluator context (packs, keyword index, overrides, allowlists) is
+//! built once at startup and shared by every request. The server speaks just
+//! enough HTTP/1.1 for JSON request/response pairs (one request per
+//! connection) on top of `std::net`, so it adds no dependencies; it is only
+//! compiled with the `http-server` cargo feature.
+//!
+//! When a shared secret is configured, every request must carry it in the
+//! [`TOKEN_HEADER`] header. Binding to a non-loopback address requires one.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::evaluator::{EvaluationDecision, evaluate_command_with_pack_order_at_path};
+use crate::scan::ScanEvalContext;
+
+/// Default listen address for `dcg serve --http`.
+pub const DEFAULT_HTTP_ADDR: &str = "127.0.0.1:8787";
+
+/// Request header carrying the shared secret.
+pub const TOKEN_HEADER: &str = "x-dcg-token";
+
+/// Largest accepted request body. Commands are small; this only bounds abuse.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Largest accepted request line or header line.
+const MAX_LINE_BYTES: usize = 16 * 1024;
+
+/// Clients that stall mid-request are dropped after this long.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Body of `POST /evaluate` and `POST /explain`.
+#[derive(Debug, Deserialize)]
+struct CommandRequest {
+    command: String,
+}
+
+/// Response of `POST /evaluate`.
+#[derive(Debug, Serialize)]
+pub struct EvaluateResponse {
+    /// "allow" or "deny"
+    pub decision: &'static str,
+    pub rule_id: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// A parsed HTTP request: method, path, the auth header, and the body.
+#[derive(Debug, Default)]
+struct HttpRequest {
+    method: String,
+    path: String,
+    token: Option<String>,
+    body: Vec<u8>,
+}
+
+/// An HTTP response: status code and JSON body.
+#[derive(Debug)]
+struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+impl HttpResponse {
+    fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self { status: 200, body },
+            Err(e) => Self::error(500, &format!("JSON serialization failed: {e}")),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }).to_string(),
+        }
+    }
+
+    const fn reason_phrase(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write_to(&self, stream: &mut impl Write) -> std::io::Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            self.reason_phrase(),
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()
+    }
+}
+
+/// Shared, warm evaluation state for all requests.
+struct ServerState {
+    config: Config,
+    eval_ctx: ScanEvalContext,
+    token: Option<String>,
+}
+
+impl ServerState {
+    fn evaluate(&self, command: &str) -> EvaluateResponse {
+        let result = evaluate_command_with_pack_order_at_path(
+            command,
+            &self.eval_ctx.enabled_keywords,
+            &self.eval_ctx.ordered_packs,
+            self.eval_ctx.keyword_index.as_ref(),
+            &self.eval_ctx.compiled_overrides,
+            &self.eval_ctx.allowlists,
+            &self.eval_ctx.heredoc_settings,
+            None,
+        );
+        let blocked = result
+            .effective_mode
+            .map_or(result.decision == EvaluationDecision::Deny, |mode| {
+                mode.blocks()
+            });
+        let info = result.pattern_info.as_ref();
+        EvaluateResponse {
+            decision: if blocked { "deny" } else { "allow" },
+            rule_id: info.and_then(|info| {
+                Some(format!(
+                    "{}:{}",
+                    info.pack_id.as_deref()?,
+                    info.pattern_name.as_deref()?
+                ))
+            }),
+            reason: info.map(|info| info.reason.clone()),
+        }
+    }
+
+    fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        if let Some(expected) = &self.token {
+            let authorized = request
+                .token
+                .as_deref()
+                .is_some_and(|token| constant_time_eq(token.as_bytes(), expected.as_bytes()));
+            if !authorized {
+                return HttpResponse::error(401, &format!("missing or invalid {TOKEN_HEADER}"));
+            }
+        }
+
+        match (request.method.as_str(), request.path.as_str()) {
+            ("POST", "/evaluate" | "/explain") => {}
+            (_, "/evaluate" | "/explain") => {
+                return HttpResponse::error(405, "use POST");
+            }
+            _ => return HttpResponse::error(404, "unknown endpoint"),
+        }
+
+        let command = match serde_json::from_slice::<CommandRequest>(&request.body) {
+            Ok(parsed) => parsed.command,
+            Err(e) => {
+                return HttpResponse::error(400, &format!("expected {{\"command\": ...}}: {e}"));
+            }
+        };
+
+        if request.path == "/evaluate" {
+            HttpResponse::json(&self.evaluate(&command))
+        } else {
+            let trace = crate::cli::explain_trace(
+                &self.config,
+                &command,
+                None,
+                &crate::cli::ExplainContext::default(),
+            );
+            HttpResponse::json(&trace.to_json_output())
+        }
+    }
+}
+
+/// Compare secrets without short-circuiting on the first differing byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Read one CRLF-terminated line, bounded by [`MAX_LINE_BYTES`].
+fn read_line(reader: &mut impl BufRead) -> Result<String, HttpResponse> {
+    let mut line = String::new();
+    reader
+        .by_ref()
+        .take(MAX_LINE_BYTES as u64)
+        .read_line(&mut line)
+        .map_err(|_| HttpResponse::error(400, "malformed request"))?;
+    if !line.ends_with('\n') {
+        return Err(HttpResponse::error(400, "request line or header too long"));
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn read_request(reader: &mut impl BufRead) -> Result<HttpRequest, HttpResponse> {
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(HttpResponse::error(400, "malformed request line"));
+    };
+    let mut request = HttpRequest {
+        method: method.to_string(),
+        path: target.split('?').next().unwrap_or(target).to_string(),
+        ..HttpRequest::default()
+    };
+
+    let mut content_length = 0usize;
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(HttpResponse::error(400, "malformed header"));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value
+                .parse()
+                .map_err(|_| HttpResponse::error(400, "invalid Content-Length"))?;
+        } else if name.eq_ignore_ascii_case(TOKEN_HEADER) {
+            request.token = Some(value.to_string());
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(HttpResponse::error(413, "request body too large"));
+    }
+    request.body = vec![0; content_length];
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|_| HttpResponse::error(400, "request body shorter than Content-Length"))?;
+    Ok(request)
+}
+
+fn handle_connection(state: &ServerState, stream: TcpStream) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let Ok(write_half) = stream.try_clone() else {
+        return;
+    };
+    let mut reader = BufReader::new(stream);
+    let response = match read_request(&mut reader) {
+        Ok(request) => state.handle(&request),
+        Err(response) => response,
+    };
+    let mut writer = write_half;
+    let _ = response.write_to(&mut writer);
+}
+
+/// A bound `dcg serve --http` listener with its warm evaluator.
+pub struct HttpServer {
+    listener: TcpListener,
+    state: Arc<ServerState>,
+}
+
+impl HttpServer {
+    /// Bind `addr` and build the evaluator context from `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address cannot be bound, or if it is not a
+    /// loopback address and no `token` is set.
+    pub fn bind(
+        addr: &str,
+        config: Config,
+        token: Option<String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let token = token.filter(|token| !token.is_empty());
+        let addrs: Vec<SocketAddr> = addr.to_socket_addrs()?.collect();
+        if token.is_none() && addrs.iter().any(|addr| !addr.ip().is_loopback()) {
+            return Err(format!(
+                "refusing to serve on non-loopback address {addr} without a shared secret \
+                 (set --token or DCG_SERVE_TOKEN)"
+            )
+            .into());
+        }
+        let listener = TcpListener::bind(addrs.as_slice())?;
+        let eval_ctx = ScanEvalContext::from_config(&config);
+        Ok(Self {
+            listener,
+            state: Arc::new(ServerState {
+                config,
+                eval_ctx,
+                token,
+            }),
+        })
+    }
+
+    /// The address actually bound (useful when binding port 0).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket address cannot be queried.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accept connections forever, one thread per connection.
+    pub fn run(self) {
+        for stream in self.listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let state = Arc::clone(&self.state);
+            std::thread::spawn(move || handle_connection(&state, stream));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(raw: &str) -> Result<HttpRequest, HttpResponse> {
+        read_request(&mut BufReader::new(raw.as_bytes()))
+    }
+
+    #[test]
+    fn parses_post_with_body_and_token() {
+        let parsed = request(
+            "POST /evaluate?x=1 HTTP/1.1\r\nX-DCG-Token: s3cret\r\nContent-Length: 2\r\n\r\n{}",
+        )
+        .expect("valid request");
+        assert_eq!(parsed.method, "POST");
+        assert_eq!(parsed.path, "/evaluate");
+        assert_eq!(parsed.token.as_deref(), Some("s3cret"));
+        assert_eq!(parsed.body, b"{}");
+    }
+
+    #[test]
+    fn rejects_oversized_and_truncated_bodies() {
+        let oversized = format!(
+            "POST /evaluate HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        assert_eq!(request(&oversized).unwrap_err().status, 413);
+        let truncated = "POST /evaluate HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}";
+        assert_eq!(request(truncated).unwrap_err().status, 400);
+    }
+
+    #[test]
+    fn constant_time_eq_compares_whole_secret() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+    }
+
+    #[test]
+    fn non_loopback_bind_requires_token() {
+        let err = HttpServer::bind("0.0.0.0:0", Config::default(), None)
+            .err()
+            .expect("should refuse");
+        assert!(err.to_string().contains("shared secret"));
+    }
+}
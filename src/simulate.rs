@@ -814,7 +814,8 @@ where
     I: IntoIterator<Item = ParsedCommand>,
 {
     let enabled_packs: HashSet<String> = config.enabled_pack_ids();
-    let ordered_packs = REGISTRY.expand_enabled_ordered(&enabled_packs);
+    let ordered_packs =
+        REGISTRY.expand_enabled_ordered_with(&enabled_packs, &config.packs.pack_order);
     let keywords = REGISTRY.collect_enabled_keywords(&enabled_packs);
     let keyword_index = REGISTRY.build_enabled_keyword_index(&ordered_packs);
     let compiled_overrides = config.overrides.compile();
@@ -1085,7 +1086,7 @@ pub fn format_json_output(
         },
     };
 
-    serde_json::to_string_pretty(&output)
+    crate::output::to_json_string(&output)
 }
 
 // =============================================================================
@@ -412,7 +412,68 @@ pub fn format_stats_pretty(stats: &AggregatedStats, period_days: u64) -> String
 /// Format statistics as JSON.
 #[must_use]
 pub fn format_stats_json(stats: &AggregatedStats) -> String {
-    serde_json::to_string_pretty(stats).unwrap_or_else(|_| "{}".to_string())
+    crate::output::to_json_string(stats).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Format statistics in the Prometheus text exposition format (v0.0.4).
+///
+/// Values are totals over the stats period, so they are exported as gauges.
+#[must_use]
+pub fn format_stats_prometheus(stats: &AggregatedStats) -> String {
+    use std::fmt::Write;
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "# HELP dcg_log_entries Log entries in the stats period.\n\
+         # TYPE dcg_log_entries gauge\n\
+         dcg_log_entries {}",
+        stats.total_entries
+    );
+
+    let _ = writeln!(
+        output,
+        "# HELP dcg_decisions Decisions in the stats period, by decision.\n\
+         # TYPE dcg_decisions gauge"
+    );
+    for (decision, count) in [
+        ("deny", stats.total_blocks),
+        ("allow", stats.total_allows),
+        ("bypass", stats.total_bypasses),
+        ("warn", stats.total_warns),
+    ] {
+        let _ = writeln!(output, "dcg_decisions{{decision=\"{decision}\"}} {count}");
+    }
+
+    let _ = writeln!(
+        output,
+        "# HELP dcg_pack_decisions Decisions in the stats period, by pack and decision.\n\
+         # TYPE dcg_pack_decisions gauge"
+    );
+    for pack in &stats.by_pack {
+        let pack_id = escape_label_value(&pack.pack_id);
+        for (decision, count) in [
+            ("deny", pack.blocks),
+            ("allow", pack.allows),
+            ("bypass", pack.bypasses),
+            ("warn", pack.warns),
+        ] {
+            let _ = writeln!(
+                output,
+                "dcg_pack_decisions{{pack=\"{pack_id}\",decision=\"{decision}\"}} {count}"
+            );
+        }
+    }
+
+    output
+}
+
+/// Escape a Prometheus label value (backslash, double quote, newline).
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
 }
 
 #[cfg(test)]
@@ -500,6 +561,31 @@ mod tests {
         assert_eq!(stats.total_blocks, 3);
     }
 
+    #[test]
+    fn test_format_stats_prometheus() {
+        let stats = AggregatedStats {
+            total_entries: 3,
+            total_blocks: 2,
+            total_allows: 1,
+            by_pack: vec![PackStats {
+                pack_id: "core.\"git\"".to_string(),
+                blocks: 2,
+                allows: 1,
+                bypasses: 0,
+                warns: 0,
+            }],
+            ..AggregatedStats::default()
+        };
+
+        let output = format_stats_prometheus(&stats);
+        assert!(output.contains("# TYPE dcg_decisions gauge\n"));
+        assert!(output.contains("dcg_log_entries 3\n"));
+        assert!(output.contains("dcg_decisions{decision=\"deny\"} 2\n"));
+        assert!(
+            output.contains("dcg_pack_decisions{pack=\"core.\\\"git\\\"\",decision=\"allow\"} 1\n")
+        );
+    }
+
     #[test]
     fn test_format_stats_pretty() {
         let stats = AggregatedStats {
@@ -10,6 +10,7 @@
 //! - [`Suggestion`] struct with actionable guidance
 //! - [`SUGGESTION_REGISTRY`] static registry keyed by `rule_id`
 //! - [`get_suggestions`] lookup function
+//! - [`similar_safe_command`] "did you mean" hints derived from a pack's safe patterns
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -41,6 +42,15 @@ pub enum SuggestionKind {
     /// "How to allowlist this specific rule"
     /// e.g., "To allow: `dcg allow core.git:reset-hard --reason '...'`"
     AllowSafely,
+
+    /// "Did you mean this safe command from the same pack?"
+    /// e.g., "Did you mean a safe operation like `aws sqs get-queue-attributes`?"
+    SimilarSafeCommand,
+
+    /// "This rewrite can be applied mechanically"; carries an [`AutoFixRewrite`]
+    /// that `dcg scan --fix` applies in place.
+    /// e.g., "Replace `--hard` with `--soft`"
+    AutoFix,
 }
 
 impl SuggestionKind {
@@ -53,6 +63,8 @@ impl SuggestionKind {
             Self::WorkflowFix => "Workflow fix",
             Self::Documentation => "Documentation",
             Self::AllowSafely => "Allow safely",
+            Self::SimilarSafeCommand => "Similar safe command",
+            Self::AutoFix => "Auto-fix",
         }
     }
 }
@@ -73,6 +85,30 @@ pub struct Suggestion {
     /// Optional URL for documentation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+
+    /// Machine-applicable rewrite (only for [`SuggestionKind::AutoFix`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rewrite: Option<AutoFixRewrite>,
+}
+
+/// A literal text substitution that turns a flagged command into its safer
+/// form, e.g. `--hard` -> `--soft`.
+///
+/// Only the first occurrence of `find` is replaced; a command that does not
+/// contain `find` is left alone rather than guessed at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoFixRewrite {
+    pub find: String,
+    pub replace: String,
+}
+
+impl AutoFixRewrite {
+    /// Apply the rewrite to `text`, or `None` if `find` does not occur.
+    #[must_use]
+    pub fn apply(&self, text: &str) -> Option<String> {
+        text.contains(&self.find)
+            .then(|| text.replacen(&self.find, &self.replace, 1))
+    }
 }
 
 impl Suggestion {
@@ -84,9 +120,26 @@ impl Suggestion {
             text: text.into(),
             command: None,
             url: None,
+            rewrite: None,
         }
     }
 
+    /// Create an [`SuggestionKind::AutoFix`] suggestion replacing `find`
+    /// with `replace`.
+    #[must_use]
+    pub fn auto_fix(find: impl Into<String>, replace: impl Into<String>) -> Self {
+        let rewrite = AutoFixRewrite {
+            find: find.into(),
+            replace: replace.into(),
+        };
+        let mut suggestion = Self::new(
+            SuggestionKind::AutoFix,
+            format!("Replace `{}` with `{}`", rewrite.find, rewrite.replace),
+        );
+        suggestion.rewrite = Some(rewrite);
+        suggestion
+    }
+
     /// Add a command to copy/paste.
     #[must_use]
     pub fn with_command(mut self, command: impl Into<String>) -> Self {
@@ -140,6 +193,280 @@ pub fn get_suggestion_by_kind(rule_id: &str, kind: SuggestionKind) -> Option<&'s
     get_suggestions(rule_id).and_then(|suggestions| suggestions.iter().find(|s| s.kind == kind))
 }
 
+/// The machine-applicable rewrite registered for `rule_id`, if any.
+#[must_use]
+pub fn get_auto_fix(rule_id: &str) -> Option<&'static AutoFixRewrite> {
+    get_suggestion_by_kind(rule_id, SuggestionKind::AutoFix)?
+        .rewrite
+        .as_ref()
+}
+
+/// Registry suggestions for `rule_id`, plus a [`similar_safe_command`] hint
+/// for the denied `command` when one is available.
+///
+/// Only call this on the deny path: ranking the pack's safe patterns is cheap
+/// but not free.
+#[must_use]
+pub fn suggestions_for_command(rule_id: &str, command: &str) -> Vec<Suggestion> {
+    let mut suggestions = get_suggestions(rule_id)
+        .map(<[_]>::to_vec)
+        .unwrap_or_default();
+    let pack_id = rule_id
+        .split_once(':')
+        .map_or(rule_id, |(pack_id, _)| pack_id);
+    if let Some(similar) = similar_safe_command(pack_id, command) {
+        suggestions.push(similar);
+    }
+    suggestions
+}
+
+// ============================================================================
+// Similar Safe Command Suggestions
+// ============================================================================
+
+/// Suggest the safe command from `pack_id` that most resembles `command`.
+///
+/// Each safe pattern of the pack is turned into a representative example
+/// command (e.g. `aws sqs get-queue-attributes`), and the example with the
+/// smallest edit distance to the start of `command` wins. Only examples with
+/// the same program and subcommand as `command` (`aws sqs`, `git reset`) are
+/// considered, so the hint never points at an unrelated operation; ties keep
+/// the pack's pattern order.
+#[must_use]
+pub fn similar_safe_command(pack_id: &str, command: &str) -> Option<Suggestion> {
+    let pack = crate::packs::REGISTRY
+        .get(pack_id)
+        .or_else(|| crate::packs::get_external_packs().and_then(|store| store.get(pack_id)))?;
+    let words: Vec<&str> = command.split_whitespace().collect();
+
+    let mut best: Option<(usize, String)> = None;
+    for pattern in &pack.safe_patterns {
+        let Some(example) = safe_pattern_example(pattern.regex.as_str()) else {
+            continue;
+        };
+        let example_words: Vec<&str> = example.split_whitespace().collect();
+        let [program, subcommand, ..] = example_words[..] else {
+            continue;
+        };
+        let Some(start) = words.iter().position(|w| *w == program) else {
+            continue;
+        };
+        if words.get(start + 1) != Some(&subcommand) {
+            continue;
+        }
+        let end = words.len().min(start + example_words.len());
+        let candidate = words[start..end].join(" ");
+        if candidate == example {
+            // The command already is the safe form; nothing useful to add.
+            continue;
+        }
+        let distance = edit_distance(&candidate, &example);
+        if best
+            .as_ref()
+            .is_none_or(|(best_distance, _)| distance < *best_distance)
+        {
+            best = Some((distance, example));
+        }
+    }
+
+    best.map(|(_, example)| {
+        Suggestion::new(
+            SuggestionKind::SimilarSafeCommand,
+            format!("Did you mean a safe operation like `{example}`?"),
+        )
+        .with_command(example)
+    })
+}
+
+/// Derive a representative literal command from a safe-pattern regex.
+///
+/// Literal text is kept, `\s` runs become a single space, optional pieces
+/// (`(...)*`, `(...)?`, `[...]*`) are skipped and the first alternative of a
+/// group is followed. Derivation stops at the first construct that has no
+/// obvious literal form. Returns `None` when nothing usable remains.
+fn safe_pattern_example(pattern: &str) -> Option<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let (mut example, _) = literal_prefix(&chars);
+    while example.ends_with(|c: char| c.is_whitespace() || c == '-' || c == '=') {
+        example.pop();
+    }
+    let example = example.split_whitespace().collect::<Vec<_>>().join(" ");
+    (example.chars().filter(char::is_ascii_alphanumeric).count() >= 2).then_some(example)
+}
+
+/// Walk `chars` and collect literal text. The flag is true when the whole
+/// input was consumed (so an enclosing group can keep going).
+fn literal_prefix(chars: &[char]) -> (String, bool) {
+    let mut out = String::new();
+    let mut i = 0;
+
+    let is_optional = |idx: usize| matches!(chars.get(idx), Some('*' | '?'));
+    let skip_quantifier = |mut idx: usize| {
+        if matches!(chars.get(idx), Some('*' | '+' | '?')) {
+            idx += 1;
+            if chars.get(idx) == Some(&'?') {
+                idx += 1;
+            }
+        }
+        idx
+    };
+
+    while i < chars.len() {
+        match chars[i] {
+            '^' => i += 1,
+            '\\' => {
+                let Some(&next) = chars.get(i + 1) else {
+                    return (out, false);
+                };
+                match next {
+                    's' => {
+                        if !out.ends_with(' ') {
+                            out.push(' ');
+                        }
+                        i = skip_quantifier(i + 2);
+                    }
+                    'b' | 'B' | 'A' => i += 2,
+                    'S' | 'd' | 'D' | 'w' | 'W' if is_optional(i + 2) => i = skip_quantifier(i + 2),
+                    'S' | 'd' | 'D' | 'w' | 'W' | 'z' | 'Z' => return (out, false),
+                    literal => {
+                        if !is_optional(i + 2) {
+                            out.push(literal);
+                        }
+                        i = skip_quantifier(i + 2);
+                    }
+                }
+            }
+            '(' => {
+                let Some(close) = matching_paren(chars, i) else {
+                    return (out, false);
+                };
+                let inner = &chars[i + 1..close];
+                let after = close + 1;
+                if is_optional(after) {
+                    i = skip_quantifier(after);
+                    continue;
+                }
+                match inner {
+                    // Lookarounds are zero-width.
+                    ['?', '=' | '!', ..] | ['?', '<', '=' | '!', ..] => {
+                        i = after;
+                        continue;
+                    }
+                    // Inline flags such as `(?i)`.
+                    ['?', rest @ ..] if rest.iter().all(char::is_ascii_alphabetic) => {
+                        i = after;
+                        continue;
+                    }
+                    _ => {}
+                }
+                let body = match inner {
+                    ['?', ':', rest @ ..] => rest,
+                    ['?', ..] => return (out, false),
+                    rest => rest,
+                };
+                let first_alternative = &body[..top_level_alternation(body).unwrap_or(body.len())];
+                let (text, complete) = literal_prefix(first_alternative);
+                out.push_str(&text);
+                if !complete {
+                    return (out, false);
+                }
+                i = skip_quantifier(after);
+            }
+            '[' => {
+                let Some(close) = matching_bracket(chars, i) else {
+                    return (out, false);
+                };
+                if is_optional(close + 1) {
+                    i = skip_quantifier(close + 1);
+                } else {
+                    return (out, false);
+                }
+            }
+            '|' | '.' | '$' | ')' | '*' | '+' | '?' | '{' => return (out, false),
+            literal => {
+                if !is_optional(i + 1) {
+                    out.push(literal);
+                }
+                i = skip_quantifier(i + 1);
+            }
+        }
+    }
+
+    (out, true)
+}
+
+fn matching_paren(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '[' => i = matching_bracket(chars, i)?,
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn matching_bracket(chars: &[char], open: usize) -> Option<usize> {
+    let mut i = open + 1;
+    if chars.get(i) == Some(&'^') {
+        i += 1;
+    }
+    // A leading `]` is a literal member of the class.
+    if chars.get(i) == Some(&']') {
+        i += 1;
+    }
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            ']' => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn top_level_alternation(chars: &[char]) -> Option<usize> {
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '(' => i = matching_paren(chars, i)?,
+            '[' => i = matching_bracket(chars, i)?,
+            '|' => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
 // ============================================================================
 // Explanation Fallback System
 // ============================================================================
@@ -269,6 +596,7 @@ fn register_core_git_suggestions(m: &mut HashMap<&'static str, Vec<Suggestion>>)
                 "See Git documentation for reset options",
             )
             .with_url("https://git-scm.com/docs/git-reset"),
+            Suggestion::auto_fix("--hard", "--soft"),
         ],
     );
 
@@ -289,6 +617,27 @@ fn register_core_git_suggestions(m: &mut HashMap<&'static str, Vec<Suggestion>>)
                 SuggestionKind::WorkflowFix,
                 "Add patterns to .gitignore instead of cleaning",
             ),
+            Suggestion::auto_fix("git clean", "git clean --dry-run"),
+        ],
+    );
+
+    m.insert(
+        "core.git:clean-force-ignored",
+        vec![
+            Suggestion::new(
+                SuggestionKind::PreviewFirst,
+                "Run `git clean -ndx` to preview everything, including ignored files",
+            )
+            .with_command("git clean -ndx"),
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Run `git clean -nd` first, then drop `-x` to keep ignored files like `.env`",
+            )
+            .with_command("git clean -nd"),
+            Suggestion::new(
+                SuggestionKind::WorkflowFix,
+                "Delete specific build directories instead of every ignored file",
+            ),
         ],
     );
 
@@ -333,6 +682,22 @@ fn register_core_git_suggestions(m: &mut HashMap<&'static str, Vec<Suggestion>>)
         checkout_discard_suggestions,
     );
 
+    m.insert(
+        "core.git:checkout-broad-pathspec",
+        vec![
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Stash changes with `git stash` instead of discarding them",
+            )
+            .with_command("git stash"),
+            Suggestion::new(
+                SuggestionKind::PreviewFirst,
+                "Run `git status` and `git diff` to see uncommitted changes that would be lost",
+            )
+            .with_command("git status && git diff"),
+        ],
+    );
+
     m.insert(
         "core.git:branch-force-delete",
         vec![
@@ -348,6 +713,28 @@ fn register_core_git_suggestions(m: &mut HashMap<&'static str, Vec<Suggestion>>)
         ],
     );
 
+    // remote branch deletion (push --delete / push :branch)
+    let push_delete_suggestions = vec![
+        Suggestion::new(
+            SuggestionKind::PreviewFirst,
+            "List remote branches with `git ls-remote --heads` to confirm the target",
+        )
+        .with_command("git ls-remote --heads origin"),
+        Suggestion::new(
+            SuggestionKind::SaferAlternative,
+            "Check the branch is merged with `git branch -r --merged` before deleting it",
+        )
+        .with_command("git branch -r --merged"),
+    ];
+    for rule in [
+        "core.git:push-delete-protected",
+        "core.git:push-refspec-delete-protected",
+        "core.git:push-delete",
+        "core.git:push-refspec-delete",
+    ] {
+        m.insert(rule, push_delete_suggestions.clone());
+    }
+
     // restore worktree patterns
     let restore_worktree_suggestions = vec![
         Suggestion::new(
@@ -424,6 +811,37 @@ fn register_core_git_suggestions(m: &mut HashMap<&'static str, Vec<Suggestion>>)
             .with_command("git stash drop stash@{0}"),
         ],
     );
+
+    // remote and config edits that can lose push targets
+    let remote_edit_suggestions = vec![
+        Suggestion::new(
+            SuggestionKind::PreviewFirst,
+            "Record the current remote URLs with `git remote -v` so they can be restored",
+        )
+        .with_command("git remote -v"),
+        Suggestion::new(
+            SuggestionKind::SaferAlternative,
+            "Use `git remote rename` to keep the old remote around under another name",
+        )
+        .with_command("git remote rename origin old-origin"),
+    ];
+    m.insert("core.git:remote-remove", remote_edit_suggestions.clone());
+    m.insert("core.git:remote-set-url", remote_edit_suggestions);
+
+    m.insert(
+        "core.git:config-unset-all",
+        vec![
+            Suggestion::new(
+                SuggestionKind::PreviewFirst,
+                "Record the current values with `git config --get-all` before removing them",
+            )
+            .with_command("git config --get-all <key>"),
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Remove a single value with `git config --unset <key> <value-regex>`",
+            ),
+        ],
+    );
 }
 
 /// Register suggestions for core.filesystem pack rules.
@@ -450,6 +868,28 @@ fn register_core_filesystem_suggestions(m: &mut HashMap<&'static str, Vec<Sugges
     m.insert("core.filesystem:rm-rf-general", rm_rf_suggestions.clone());
     m.insert("core.filesystem:rm-r-f-separate", rm_rf_suggestions.clone());
     m.insert("core.filesystem:rm-recursive-force-long", rm_rf_suggestions);
+
+    // Shared suggestions for `for f in $(find ...); do rm ...` loops
+    let find_loop_suggestions = vec![
+        Suggestion::new(
+            SuggestionKind::PreviewFirst,
+            "Print what the find matches before deleting anything",
+        )
+        .with_command("find <dir> -name '<glob>' -print | head -20"),
+        Suggestion::new(
+            SuggestionKind::SaferAlternative,
+            "Scope the find to a project directory instead of / or a system path",
+        ),
+        Suggestion::new(
+            SuggestionKind::WorkflowFix,
+            "Use `find <dir> ... -print0 | xargs -0 rm --` so paths with spaces are not split",
+        ),
+    ];
+    m.insert(
+        "core.filesystem:find-loop-delete-system",
+        find_loop_suggestions.clone(),
+    );
+    m.insert("core.filesystem:find-loop-delete", find_loop_suggestions);
 }
 
 /// Register suggestions for heredoc pattern rules.
@@ -595,6 +1035,20 @@ fn register_docker_suggestions(m: &mut HashMap<&'static str, Vec<Suggestion>>) {
         ],
     );
 
+    m.insert(
+        "containers.docker:network-rm",
+        vec![
+            Suggestion::new(
+                SuggestionKind::PreviewFirst,
+                "Check attached containers with `docker network inspect <name>`",
+            ),
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Disconnect containers with `docker network disconnect` before removing",
+            ),
+        ],
+    );
+
     m.insert(
         "containers.docker:stop-all",
         vec![
@@ -1278,6 +1732,54 @@ fn register_system_permissions_suggestions(m: &mut HashMap<&'static str, Vec<Sug
         ],
     );
 
+    // chmod/chown -R on / itself
+    m.insert(
+        "system.permissions:chmod-recursive-root-target",
+        vec![
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Target the specific directory that needs new permissions",
+            )
+            .with_command("chmod -R u+rwX ./<dir>"),
+            Suggestion::new(
+                SuggestionKind::WorkflowFix,
+                "Recursive chmod on / breaks sudo, SSH keys, and services; recovery usually needs a reinstall",
+            ),
+        ],
+    );
+
+    m.insert(
+        "system.permissions:chown-recursive-root-target",
+        vec![
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Change ownership of the specific directory you own",
+            )
+            .with_command("chown -R $USER:$USER ./<dir>"),
+            Suggestion::new(
+                SuggestionKind::WorkflowFix,
+                "Recursive chown on / breaks package-managed files and daemons that check ownership",
+            ),
+        ],
+    );
+
+    // chmod -R 000
+    m.insert(
+        "system.permissions:chmod-recursive-000",
+        vec![
+            Suggestion::new(
+                SuggestionKind::SaferAlternative,
+                "Remove access for group and others only, keeping owner access",
+            )
+            .with_command("chmod -R go-rwx <path>"),
+            Suggestion::new(
+                SuggestionKind::PreviewFirst,
+                "Check which files would be affected first",
+            )
+            .with_command("find <path> | head -20"),
+        ],
+    );
+
     // chmod -R on system directories
     m.insert(
         "system.permissions:chmod-recursive-root",
@@ -1419,6 +1921,107 @@ mod tests {
         assert_eq!(SuggestionKind::WorkflowFix.label(), "Workflow fix");
         assert_eq!(SuggestionKind::Documentation.label(), "Documentation");
         assert_eq!(SuggestionKind::AllowSafely.label(), "Allow safely");
+        assert_eq!(
+            SuggestionKind::SimilarSafeCommand.label(),
+            "Similar safe command"
+        );
+        assert_eq!(SuggestionKind::AutoFix.label(), "Auto-fix");
+    }
+
+    #[test]
+    fn auto_fix_rewrites_only_registered_rules() {
+        let fix = get_auto_fix("core.git:reset-hard").expect("reset-hard is fixable");
+        assert_eq!(
+            fix.apply("git reset --hard HEAD~1").as_deref(),
+            Some("git reset --soft HEAD~1")
+        );
+        assert_eq!(fix.apply("git reset HEAD~1"), None, "never guesses");
+        assert!(get_auto_fix("core.filesystem:rm-rf-general").is_none());
+
+        for suggestions in SUGGESTION_REGISTRY.values() {
+            for suggestion in suggestions {
+                assert_eq!(
+                    suggestion.kind == SuggestionKind::AutoFix,
+                    suggestion.rewrite.is_some(),
+                    "rewrite must be set exactly on AutoFix suggestions: {suggestion:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn safe_pattern_example_extracts_literal_command() {
+        assert_eq!(
+            safe_pattern_example(r"aws(?:\s+--?\S+(?:\s+\S+)?)*\s+sqs\s+get-queue-attributes\b")
+                .as_deref(),
+            Some("aws sqs get-queue-attributes")
+        );
+        assert_eq!(
+            safe_pattern_example(r"git\s+(?:\S+\s+)*checkout\s+-b\s+").as_deref(),
+            Some("git checkout -b")
+        );
+        assert_eq!(
+            safe_pattern_example(r"git\s+(?:\S+\s+)*clean\s+-[a-z]*n[a-z]*").as_deref(),
+            Some("git clean -n")
+        );
+        assert_eq!(
+            safe_pattern_example(r"kubectl\s+(?:get|describe)\s+pods").as_deref(),
+            Some("kubectl get pods")
+        );
+        assert_eq!(safe_pattern_example(r".*"), None);
+    }
+
+    #[test]
+    fn similar_safe_command_ranks_by_edit_distance() {
+        let suggestion = similar_safe_command(
+            "messaging.sqs_sns",
+            "aws sqs delete-queue --queue-url https://example.com/q",
+        )
+        .expect("sqs pack should offer a safe alternative");
+        assert_eq!(suggestion.kind, SuggestionKind::SimilarSafeCommand);
+        let command = suggestion.command.expect("suggestion carries a command");
+        assert!(command.starts_with("aws sqs "), "got {command}");
+        assert!(suggestion.text.contains(&command));
+    }
+
+    #[test]
+    fn similar_safe_command_requires_known_pack_and_program() {
+        assert!(similar_safe_command("nonexistent.pack", "aws sqs delete-queue").is_none());
+        assert!(similar_safe_command("messaging.sqs_sns", "rm -rf /").is_none());
+    }
+
+    #[test]
+    fn similar_safe_command_keeps_program_and_subcommand() {
+        let suggested = similar_safe_command("core.git", "git clean -fdx")
+            .and_then(|suggestion| suggestion.command);
+        assert_eq!(suggested.as_deref(), Some("git clean -n"));
+
+        // No safe example shares the program and subcommand.
+        assert!(similar_safe_command("core.git", "git reset --hard").is_none());
+        assert!(similar_safe_command("core.git", "git push origin --delete main").is_none());
+        assert!(similar_safe_command("core.filesystem", "rm -rf /").is_none());
+    }
+
+    #[test]
+    fn suggestions_for_command_appends_similar_safe_command() {
+        let base = get_suggestions("messaging.sqs_sns:aws-sqs-delete-queue").unwrap_or(&[]);
+        let all = suggestions_for_command(
+            "messaging.sqs_sns:aws-sqs-delete-queue",
+            "aws sqs delete-queue --queue-url https://example.com/q",
+        );
+        assert_eq!(&all[..base.len()], base);
+        let similar = &all[base.len()..];
+        assert_eq!(similar.len(), 1, "{similar:?}");
+        assert_eq!(similar[0].kind, SuggestionKind::SimilarSafeCommand);
+        let command = similar[0].command.as_deref().unwrap_or_default();
+        assert!(command.starts_with("aws sqs "), "got {command}");
+    }
+
+    #[test]
+    fn edit_distance_basics() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
     }
 
     #[test]
@@ -1495,11 +2098,19 @@ mod tests {
             "core.git:push-force-short",
             "core.git:checkout-discard",
             "core.git:checkout-ref-discard",
+            "core.git:checkout-broad-pathspec",
             "core.git:branch-force-delete",
+            "core.git:push-delete-protected",
+            "core.git:push-refspec-delete-protected",
+            "core.git:push-delete",
+            "core.git:push-refspec-delete",
             "core.git:restore-worktree",
             "core.git:restore-worktree-explicit",
             "core.git:stash-drop",
             "core.git:stash-clear",
+            "core.git:remote-remove",
+            "core.git:remote-set-url",
+            "core.git:config-unset-all",
         ];
 
         for rule in expected_rules {
@@ -1519,6 +2130,8 @@ mod tests {
             "core.filesystem:rm-rf-general",
             "core.filesystem:rm-r-f-separate",
             "core.filesystem:rm-recursive-force-long",
+            "core.filesystem:find-loop-delete-system",
+            "core.filesystem:find-loop-delete",
         ];
 
         for rule in expected_rules {
@@ -1751,6 +2364,9 @@ mod tests {
     fn registry_has_system_permissions_rules() {
         let expected = [
             "system.permissions:chmod-777",
+            "system.permissions:chmod-recursive-root-target",
+            "system.permissions:chown-recursive-root-target",
+            "system.permissions:chmod-recursive-000",
             "system.permissions:chmod-recursive-root",
             "system.permissions:chown-recursive-root",
             "system.permissions:chmod-setuid",
@@ -41,9 +41,11 @@
 //! ```
 
 use crate::allowlist::AllowlistLayer;
-use crate::evaluator::{EvaluationDecision, MatchSource};
-use crate::packs::Severity;
+use crate::evaluator::{EvaluationDecision, MatchSource, PatternHit, PatternHitKind};
+use crate::output::hyperlink::{hyperlinks_supported, rule_id_link};
+use crate::packs::{DecisionMode, ReasonCode, Severity};
 use serde::Serialize;
+use std::fmt::Write as _;
 use std::time::Instant;
 
 /// Current JSON schema version for explain output.
@@ -51,6 +53,28 @@ use std::time::Instant;
 /// v2 adds `matched_span`, `matched_text_preview`, and `explanation` in `match`.
 pub const EXPLAIN_JSON_SCHEMA_VERSION: u32 = 2;
 
+/// JSON Schema (Draft 2020-12) for [`ExplainJsonOutput`], as printed by
+/// `dcg explain --print-schema`.
+///
+/// The `$id` embeds [`EXPLAIN_JSON_SCHEMA_VERSION`] so consumers can pin to it.
+///
+/// # Panics
+///
+/// Only if the embedded schema file is not valid JSON, which tests rule out.
+#[must_use]
+pub fn explain_json_schema() -> serde_json::Value {
+    let mut schema: serde_json::Value =
+        serde_json::from_str(include_str!("../docs/json-schema/explain-output.json"))
+            .expect("docs/json-schema/explain-output.json is valid JSON");
+    schema["$id"] = serde_json::Value::String(format!(
+        "https://github.com/Dicklesworthstone/destructive_command_guard/docs/json-schema/explain-output-v{EXPLAIN_JSON_SCHEMA_VERSION}.json"
+    ));
+    schema
+}
+
+/// Suffix marking the hit that decided the outcome in `--all-matches` output.
+pub const ALL_MATCHES_DECIDED_MARKER: &str = "  <- decided";
+
 /// A complete trace of a command evaluation.
 ///
 /// Contains all information needed for `dcg explain` output formatting.
@@ -76,6 +100,11 @@ pub struct ExplainTrace {
     pub allowlist_info: Option<AllowlistInfo>,
     /// Summary of packs that were evaluated.
     pub pack_summary: Option<PackSummary>,
+    /// Every pattern hit across all packs (`--all-matches` only).
+    pub all_matches: Option<Vec<PatternHit>>,
+    /// Configured `packs.pack_order` that put these packs first
+    /// (`--all-matches` only, when set).
+    pub pack_order: Option<Vec<String>>,
 }
 
 /// A single step in the evaluation trace.
@@ -100,6 +129,44 @@ pub enum TraceDetails {
         command_len: usize,
     },
 
+    /// Directory and git branch the command was evaluated in.
+    EvaluationContext {
+        /// Working directory used for config and path-scoped policy lookup.
+        working_dir: String,
+        /// Branch name (None if detached HEAD or not in a git repo).
+        branch: Option<String>,
+        /// Whether the branch came from `--branch` rather than git.
+        branch_overridden: bool,
+        /// Effective branch strictness (None when git awareness is disabled).
+        strictness: Option<String>,
+        /// Whether branch strictness changed the decision.
+        affected_decision: bool,
+    },
+
+    /// `[branch_policy]` entry applied for the current branch.
+    BranchPolicy {
+        /// Branch name (None if detached HEAD or not in a git repo).
+        branch: Option<String>,
+        /// Branch pattern that matched (None falls back to the normal policy).
+        matched_pattern: Option<String>,
+        /// Mode configured for the matched pattern.
+        policy_mode: Option<DecisionMode>,
+        /// Mode applied to the matched rule (None if nothing matched).
+        effective_mode: Option<DecisionMode>,
+    },
+
+    /// `--min-severity` what-if preview (never changes the decision).
+    SeverityThreshold {
+        /// Threshold requested with `--min-severity`.
+        min_severity: Severity,
+        /// Severity of the match (None if nothing matched or unknown).
+        match_severity: Option<Severity>,
+        /// Whether the match falls below the threshold.
+        below_threshold: bool,
+        /// Decision if only matches at or above the threshold were enforced.
+        threshold_decision: EvaluationDecision,
+    },
+
     /// Keyword gating (quick reject).
     KeywordGating {
         /// Whether the command was quick-rejected (no keywords found).
@@ -118,6 +185,14 @@ pub enum TraceDetails {
         stripped_prefix: Option<String>,
     },
 
+    /// `eval` / same-line variable indirection resolved to a literal command.
+    IndirectionResolution {
+        /// What was resolved (`eval`, `var`, or `eval+var`).
+        kind: &'static str,
+        /// The command that would actually run.
+        resolved_command: String,
+    },
+
     /// Context sanitization (false positive immunity).
     Sanitization {
         /// Whether the command was modified.
@@ -146,6 +221,16 @@ pub enum TraceDetails {
         matched_layer: Option<AllowlistLayer>,
     },
 
+    /// Allowlist entry that would have matched but has expired.
+    AllowlistExpired {
+        /// Layer the entry came from.
+        layer: AllowlistLayer,
+        /// What the entry targets (e.g. `rule core.git:reset-hard`).
+        selector: String,
+        /// When the entry expired.
+        expired_at: String,
+    },
+
     /// Pack evaluation.
     PackEvaluation {
         /// Packs that were evaluated (not skipped).
@@ -248,6 +333,10 @@ pub struct TraceCollector {
     allowlist_info: Option<AllowlistInfo>,
     /// Pack summary (set during evaluation).
     pack_summary: Option<PackSummary>,
+    /// Exhaustive pattern hits (set by `--all-matches`).
+    all_matches: Option<Vec<PatternHit>>,
+    /// Configured pack priority (set by `--all-matches`).
+    pack_order: Option<Vec<String>>,
     /// Whether evaluation skipped deeper analysis due to a budget overrun.
     skipped_due_to_budget: bool,
 }
@@ -267,6 +356,8 @@ impl TraceCollector {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
             skipped_due_to_budget: false,
         }
     }
@@ -321,6 +412,16 @@ impl TraceCollector {
         self.pack_summary = Some(summary);
     }
 
+    /// Set the exhaustive list of pattern hits.
+    pub fn set_all_matches(&mut self, hits: Vec<PatternHit>) {
+        self.all_matches = Some(hits);
+    }
+
+    /// Record the configured `packs.pack_order` used for evaluation.
+    pub fn set_pack_order(&mut self, pack_order: Vec<String>) {
+        self.pack_order = Some(pack_order);
+    }
+
     /// Mark whether evaluation skipped deeper analysis due to budget.
     pub const fn set_budget_skip(&mut self, skipped: bool) {
         self.skipped_due_to_budget = skipped;
@@ -342,6 +443,8 @@ impl TraceCollector {
             match_info: self.match_info,
             allowlist_info: self.allowlist_info,
             pack_summary: self.pack_summary,
+            all_matches: self.all_matches,
+            pack_order: self.pack_order,
         }
     }
 }
@@ -402,6 +505,140 @@ impl ExplainTrace {
         )
     }
 
+    /// Format the trace as a Graphviz DOT digraph (`dcg explain --format dot`).
+    ///
+    /// The command heads the graph, each step is one node labeled with its name
+    /// and duration, edges follow chronological order, and the graph ends in a
+    /// decision node filled red for DENY and green for ALLOW. Match info hangs
+    /// off the step that evaluated packs as a dashed callout (or off the
+    /// decision node when the match came from elsewhere, e.g. a config override).
+    ///
+    /// Render with `dcg explain --format dot "git reset --hard" | dot -Tpng -o explain.png`.
+    #[must_use]
+    pub fn format_dot(&self) -> String {
+        let mut out = String::with_capacity(512 + self.steps.len() * 64);
+        out.push_str("digraph explain {\n");
+        out.push_str("  rankdir=TB;\n");
+        out.push_str("  node [shape=box, style=\"rounded\", fontname=\"Helvetica\"];\n");
+        out.push_str("  edge [fontname=\"Helvetica\"];\n\n");
+
+        let _ = writeln!(
+            out,
+            "  command [shape=note, label=\"{}\"];",
+            dot_escape(&truncate_utf8(&self.command, 80))
+        );
+
+        let mut previous = "command".to_string();
+        for (idx, step) in self.steps.iter().enumerate() {
+            let node = format!("step{idx}");
+            let _ = writeln!(
+                out,
+                "  {node} [label=\"{}\\n{}\"];",
+                dot_escape(step.name),
+                format_duration(step.duration_us)
+            );
+            let _ = writeln!(out, "  {previous} -> {node};");
+            previous = node;
+        }
+
+        let (decision_label, fill, border) = match self.decision {
+            EvaluationDecision::Allow => ("ALLOW", "#d4edda", "#2e7d32"),
+            EvaluationDecision::Deny => ("DENY", "#f8d7da", "#c62828"),
+        };
+        let budget_note = if self.skipped_due_to_budget {
+            "\\n(budget exceeded)"
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            out,
+            "  decision [shape=doubleoctagon, style=\"filled\", fillcolor=\"{fill}\", color=\"{border}\", \
+             label=\"{decision_label}\\n{}{budget_note}\"];",
+            format_duration(self.total_duration_us)
+        );
+        let _ = writeln!(out, "  {previous} -> decision;");
+
+        if let Some(info) = &self.match_info {
+            let mut label = String::new();
+            if let Some(rule_id) = &info.rule_id {
+                label.push_str(&dot_escape(rule_id));
+                label.push_str("\\n");
+            }
+            label.push_str(&dot_escape(&truncate_utf8(&info.reason, 80)));
+            if let Some(preview) = &info.matched_text_preview {
+                let _ = write!(label, "\\nmatched: {}", dot_escape(preview));
+            }
+
+            // `dcg explain` records pack evaluation as a keyword-gating step
+            // carrying the matched pack, so fall back to that before the decision.
+            let anchor = self
+                .steps
+                .iter()
+                .position(|step| matches!(step.details, TraceDetails::PackEvaluation { .. }))
+                .or_else(|| {
+                    self.steps.iter().position(|step| {
+                        matches!(
+                            step.details,
+                            TraceDetails::KeywordGating {
+                                first_match: Some(_),
+                                ..
+                            }
+                        )
+                    })
+                })
+                .map_or_else(|| "decision".to_string(), |idx| format!("step{idx}"));
+            let _ = writeln!(
+                out,
+                "  match [shape=note, style=\"filled\", fillcolor=\"#fff3cd\", label=\"{label}\"];"
+            );
+            let _ = writeln!(out, "  {anchor} -> match [style=dashed, arrowhead=none];");
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Compare this trace against `other` (`dcg explain --compare`).
+    ///
+    /// Steps are paired by name and reported only when their details differ or
+    /// one side lacks the step; durations are ignored since they vary run to run.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> TraceDiff {
+        let left = TraceDiffSide::from_trace(self);
+        let right = TraceDiffSide::from_trace(other);
+
+        let mut names: Vec<&'static str> = self.steps.iter().map(|s| s.name).collect();
+        for step in &other.steps {
+            if !names.contains(&step.name) {
+                names.push(step.name);
+            }
+        }
+        let steps = names
+            .into_iter()
+            .filter_map(|name| {
+                let summarize = |trace: &Self| {
+                    trace
+                        .find_step(name)
+                        .map(|s| format_step_details_summary(&s.details))
+                };
+                let (a, b) = (summarize(self), summarize(other));
+                (a != b).then(|| StepDiff {
+                    name: name.to_string(),
+                    left: a,
+                    right: b,
+                })
+            })
+            .collect();
+
+        TraceDiff {
+            decision_differs: self.decision != other.decision,
+            match_differs: left.rule_id != right.rule_id || left.reason != right.reason,
+            left,
+            right,
+            steps,
+        }
+    }
+
     /// Get the reason for the decision (from match info).
     #[must_use]
     pub fn reason(&self) -> Option<&str> {
@@ -416,10 +653,16 @@ impl ExplainTrace {
     /// - Which stages were run and how long did they take?
     /// - What can I do next (safe alternatives, allowlist command)?
     ///
-    /// Set `use_color` to enable ANSI color codes for terminal output.
+    /// Set `use_color` to enable ANSI color codes for terminal output. When
+    /// colors are on and the terminal supports OSC 8, the rule ID links to
+    /// its pack docs.
     #[must_use]
-    #[allow(clippy::too_many_lines, clippy::format_push_string)]
     pub fn format_pretty(&self, use_color: bool) -> String {
+        self.format_pretty_with_links(use_color, use_color && hyperlinks_supported())
+    }
+
+    #[allow(clippy::too_many_lines, clippy::format_push_string)]
+    fn format_pretty_with_links(&self, use_color: bool, hyperlinks: bool) -> String {
         let mut out = String::with_capacity(1024);
 
         // Color helpers
@@ -448,6 +691,12 @@ impl ExplainTrace {
             EvaluationDecision::Deny => format!("{red}{bold}DENY{reset}"),
         };
         out.push_str(&format!("{bold}Decision:{reset} {decision_str}\n"));
+        if let Some(note) = self.severity_threshold_note() {
+            out.push_str(&format!("{yellow}{note}{reset}\n"));
+        }
+        for note in self.expired_allowlist_notes() {
+            out.push_str(&format!("{yellow}{note}{reset}\n"));
+        }
         out.push_str(&format!(
             "{bold}Latency:{reset}  {}\n",
             format_duration(self.total_duration_us)
@@ -484,6 +733,7 @@ impl ExplainTrace {
             ));
 
             if let Some(ref rule_id) = info.rule_id {
+                let rule_id = rule_id_link(rule_id, hyperlinks);
                 out.push_str(&format!(
                     "{cyan}Rule ID:{reset}    {yellow}{rule_id}{reset}\n"
                 ));
@@ -575,6 +825,23 @@ impl ExplainTrace {
             out.push('\n');
         }
 
+        // ═══════════════════════════════════════════════════════════════════
+        // ALL MATCHES (--all-matches)
+        // ═══════════════════════════════════════════════════════════════════
+        if self.all_matches.is_some() {
+            out.push_str(&format!(
+                "{bold}─── All Matches ───────────────────────────────────────────────────{reset}\n"
+            ));
+            for line in self.all_matches_lines() {
+                if line.ends_with(ALL_MATCHES_DECIDED_MARKER) {
+                    out.push_str(&format!("{red}{line}{reset}\n"));
+                } else {
+                    out.push_str(&format!("{line}\n"));
+                }
+            }
+            out.push('\n');
+        }
+
         // ═══════════════════════════════════════════════════════════════════
         // PIPELINE TRACE (steps)
         // ═══════════════════════════════════════════════════════════════════
@@ -600,25 +867,25 @@ impl ExplainTrace {
         // ═══════════════════════════════════════════════════════════════════
         if let Some(ref info) = self.match_info {
             if let Some(rule_id) = info.rule_id.as_deref() {
-                if let Some(suggestions) = crate::suggestions::get_suggestions(rule_id) {
-                    if !suggestions.is_empty() {
-                        out.push_str(&format!("{bold}─── Suggestions ───────────────────────────────────────────────────{reset}\n"));
-
-                        for s in suggestions {
-                            out.push_str(&format!(
-                                "{yellow}• {}{reset}: {}\n",
-                                s.kind.label(),
-                                s.text
-                            ));
-                            if let Some(ref cmd) = s.command {
-                                out.push_str(&format!("  {dim}${reset} {green}{cmd}{reset}\n"));
-                            }
-                            if let Some(ref url) = s.url {
-                                out.push_str(&format!("  {dim}→ {url}{reset}\n"));
-                            }
+                let suggestions =
+                    crate::suggestions::suggestions_for_command(rule_id, &self.command);
+                if !suggestions.is_empty() {
+                    out.push_str(&format!("{bold}─── Suggestions ───────────────────────────────────────────────────{reset}\n"));
+
+                    for s in &suggestions {
+                        out.push_str(&format!(
+                            "{yellow}• {}{reset}: {}\n",
+                            s.kind.label(),
+                            s.text
+                        ));
+                        if let Some(ref cmd) = s.command {
+                            out.push_str(&format!("  {dim}${reset} {green}{cmd}{reset}\n"));
+                        }
+                        if let Some(ref url) = s.url {
+                            out.push_str(&format!("  {dim}→ {url}{reset}\n"));
                         }
-                        out.push('\n');
                     }
+                    out.push('\n');
                 }
             }
         }
@@ -633,6 +900,114 @@ impl ExplainTrace {
         out
     }
 
+    /// Whether `hit` is the match that decided a deny.
+    #[must_use]
+    pub fn is_deciding_hit(&self, hit: &PatternHit) -> bool {
+        self.decision == EvaluationDecision::Deny
+            && hit.kind == PatternHitKind::Destructive
+            && self.match_info.as_ref().is_some_and(|info| {
+                info.pack_id.as_deref() == Some(hit.pack_id.as_str())
+                    && info.pattern_name == hit.pattern_name
+            })
+    }
+
+    /// Ranked, plain-text lines for the `--all-matches` section.
+    ///
+    /// Empty when `--all-matches` was not requested.
+    #[must_use]
+    pub fn all_matches_lines(&self) -> Vec<String> {
+        let Some(hits) = self.all_matches.as_ref() else {
+            return Vec::new();
+        };
+        if hits.is_empty() {
+            return vec!["No pack patterns matched.".to_string()];
+        }
+
+        let mut lines: Vec<String> = hits
+            .iter()
+            .enumerate()
+            .map(|(i, hit)| {
+                let rule = hit
+                    .rule_id()
+                    .unwrap_or_else(|| format!("{}:<unnamed>", hit.pack_id));
+                let mut line = format!("{:>2}. {rule:<40} {:<11}", i + 1, hit.kind.label());
+                if let Some(severity) = hit.severity {
+                    let _ = write!(line, " {:<8}", severity.label());
+                }
+                if let Some(span) = hit.matched_span {
+                    let _ = write!(line, " bytes {}..{}", span.start, span.end);
+                }
+                if hit.shadowed_by_safe {
+                    line.push_str(" (skipped: safe pattern in same pack)");
+                }
+                if self.is_deciding_hit(hit) {
+                    line.push_str(ALL_MATCHES_DECIDED_MARKER);
+                }
+                line.trim_end().to_string()
+            })
+            .collect();
+
+        let packs: std::collections::HashSet<&str> =
+            hits.iter().map(|hit| hit.pack_id.as_str()).collect();
+        lines.push(format!(
+            "{} match{} across {} pack{}",
+            hits.len(),
+            if hits.len() == 1 { "" } else { "es" },
+            packs.len(),
+            if packs.len() == 1 { "" } else { "s" }
+        ));
+        if let Some(pack_order) = &self.pack_order {
+            lines.push(format!(
+                "Evaluated first (packs.pack_order): {}",
+                pack_order.join(", ")
+            ));
+        }
+        lines
+    }
+
+    /// One-line note when `--min-severity` would change the decision.
+    ///
+    /// The note always states that the real decision is unchanged, so a
+    /// preview never reads as if blocking had been weakened.
+    #[must_use]
+    pub fn severity_threshold_note(&self) -> Option<String> {
+        self.steps.iter().find_map(|step| match &step.details {
+            TraceDetails::SeverityThreshold {
+                min_severity,
+                match_severity: Some(severity),
+                below_threshold: true,
+                threshold_decision: EvaluationDecision::Allow,
+            } => Some(format!(
+                "At --min-severity {}: ALLOW (match is {}; actual decision above is unchanged)",
+                min_severity.label(),
+                severity.label()
+            )),
+            _ => None,
+        })
+    }
+
+    /// Notes for allowlist entries skipped because they expired.
+    ///
+    /// Explains why a command is blocked even though an allowlist entry for it
+    /// exists.
+    #[must_use]
+    pub fn expired_allowlist_notes(&self) -> Vec<String> {
+        self.steps
+            .iter()
+            .filter_map(|step| match &step.details {
+                TraceDetails::AllowlistExpired {
+                    layer,
+                    selector,
+                    expired_at,
+                } => Some(format!(
+                    "Skipped {} allowlist entry for {selector}: expired {expired_at}",
+                    layer.label()
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Format the trace as JSON output.
     ///
     /// Returns a stable, versioned JSON representation suitable for:
@@ -645,8 +1020,7 @@ impl ExplainTrace {
     #[must_use]
     pub fn format_json(&self) -> String {
         let json_output = self.to_json_output();
-        // Use pretty printing for human readability
-        serde_json::to_string_pretty(&json_output)
+        crate::output::to_json_string(&json_output)
             .unwrap_or_else(|e| format!("{{\"error\": \"JSON serialization failed: {e}\"}}"))
     }
 
@@ -658,7 +1032,7 @@ impl ExplainTrace {
             .match_info
             .as_ref()
             .and_then(|m| m.rule_id.as_deref())
-            .and_then(crate::suggestions::get_suggestions)
+            .map(|rule_id| crate::suggestions::suggestions_for_command(rule_id, &self.command))
             .map(|slist| {
                 slist
                     .iter()
@@ -692,10 +1066,197 @@ impl ExplainTrace {
             } else {
                 Some(suggestions)
             },
+            all_matches: self.all_matches.as_ref().map(|hits| {
+                hits.iter()
+                    .enumerate()
+                    .map(|(i, hit)| JsonPatternHit {
+                        rank: i + 1,
+                        rule_id: hit.rule_id(),
+                        pack_id: hit.pack_id.clone(),
+                        pattern_name: hit.pattern_name.clone(),
+                        kind: hit.kind.label().to_string(),
+                        severity: hit.severity.map(|s| s.label().to_string()),
+                        matched_span: hit.matched_span.map(|span| JsonSpan {
+                            start: span.start,
+                            end: span.end,
+                        }),
+                        shadowed_by_safe: hit.shadowed_by_safe,
+                        decided: self.is_deciding_hit(hit),
+                    })
+                    .collect()
+            }),
+            pack_order: self.pack_order.clone(),
         }
     }
 }
 
+// ============================================================================
+// Trace comparison (`dcg explain --compare`)
+// ============================================================================
+
+/// Differences between two explain traces, from [`ExplainTrace::diff`].
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceDiff {
+    /// Summary of the first command.
+    pub left: TraceDiffSide,
+    /// Summary of the second command.
+    pub right: TraceDiffSide,
+    /// Whether the final decisions differ.
+    pub decision_differs: bool,
+    /// Whether the matched rule or reason differs.
+    pub match_differs: bool,
+    /// Steps whose details differ, in pipeline order.
+    pub steps: Vec<StepDiff>,
+}
+
+/// One side of a [`TraceDiff`].
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceDiffSide {
+    /// Command that was evaluated.
+    pub command: String,
+    /// Decision: "allow" or "deny".
+    pub decision: String,
+    /// Stable rule ID of the match (if any).
+    pub rule_id: Option<String>,
+    /// Reason attached to the match (if any).
+    pub reason: Option<String>,
+    /// Enabled pack keywords that appear in the command.
+    pub keywords_matched: Vec<String>,
+}
+
+/// A step whose details differ between the two traces.
+#[derive(Debug, Clone, Serialize)]
+pub struct StepDiff {
+    /// Step name.
+    pub name: String,
+    /// Details summary for the first command (None if the step did not run).
+    pub left: Option<String>,
+    /// Details summary for the second command (None if the step did not run).
+    pub right: Option<String>,
+}
+
+impl TraceDiffSide {
+    fn from_trace(trace: &ExplainTrace) -> Self {
+        let keywords_matched = trace
+            .steps
+            .iter()
+            .filter_map(|step| match &step.details {
+                TraceDetails::KeywordGating {
+                    keywords_checked, ..
+                } => Some(keywords_checked),
+                _ => None,
+            })
+            .flatten()
+            .filter(|kw| !crate::packs::pack_aware_quick_reject(&trace.command, &[kw.as_str()]))
+            .cloned()
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        Self {
+            command: trace.command.clone(),
+            decision: match trace.decision {
+                EvaluationDecision::Allow => "allow".to_string(),
+                EvaluationDecision::Deny => "deny".to_string(),
+            },
+            rule_id: trace.rule_id().map(str::to_string),
+            reason: trace.reason().map(str::to_string),
+            keywords_matched,
+        }
+    }
+}
+
+impl TraceDiff {
+    /// Format the comparison as a two-column, side-by-side table.
+    ///
+    /// Rows that differ are marked with `*`; steps that match on both sides
+    /// are omitted.
+    #[must_use]
+    pub fn format_pretty(&self) -> String {
+        const MAX_CELL: usize = 48;
+        let none = || "-".to_string();
+        let keywords = |side: &TraceDiffSide| {
+            if side.keywords_matched.is_empty() {
+                none()
+            } else {
+                side.keywords_matched.join(", ")
+            }
+        };
+
+        let mut rows: Vec<(String, String, String)> = vec![
+            (
+                "command".to_string(),
+                self.left.command.clone(),
+                self.right.command.clone(),
+            ),
+            (
+                "decision".to_string(),
+                self.left.decision.to_uppercase(),
+                self.right.decision.to_uppercase(),
+            ),
+            (
+                "rule".to_string(),
+                self.left.rule_id.clone().unwrap_or_else(none),
+                self.right.rule_id.clone().unwrap_or_else(none),
+            ),
+            (
+                "reason".to_string(),
+                self.left.reason.clone().unwrap_or_else(none),
+                self.right.reason.clone().unwrap_or_else(none),
+            ),
+            (
+                "keywords".to_string(),
+                keywords(&self.left),
+                keywords(&self.right),
+            ),
+        ];
+        rows.extend(self.steps.iter().map(|step| {
+            (
+                format!("step {}", step.name),
+                step.left.clone().unwrap_or_else(|| "(not run)".to_string()),
+                step.right
+                    .clone()
+                    .unwrap_or_else(|| "(not run)".to_string()),
+            )
+        }));
+
+        let rows: Vec<(String, String, String)> = rows
+            .into_iter()
+            .map(|(label, a, b)| {
+                (
+                    label,
+                    truncate_utf8(&a, MAX_CELL),
+                    truncate_utf8(&b, MAX_CELL),
+                )
+            })
+            .collect();
+        let label_width = rows
+            .iter()
+            .map(|(l, _, _)| l.chars().count())
+            .max()
+            .unwrap_or(0);
+        let left_width = rows
+            .iter()
+            .map(|(_, a, _)| a.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let mut out = String::new();
+        let _ = writeln!(out, "  {:label_width$}  {:left_width$}  B", "", "A");
+        for (label, a, b) in &rows {
+            let marker = if a == b { ' ' } else { '*' };
+            let _ = writeln!(out, "{marker} {label:label_width$}  {a:left_width$}  {b}");
+        }
+        if self.steps.is_empty() {
+            out.push_str("\nNo pipeline steps differed.");
+        } else {
+            let _ = write!(out, "\n{} pipeline step(s) differed.", self.steps.len());
+        }
+        out
+    }
+}
+
 // ============================================================================
 // JSON Output Structures (versioned, stable schema)
 // ============================================================================
@@ -734,6 +1295,39 @@ pub struct ExplainJsonOutput {
     /// Actionable suggestions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suggestions: Option<Vec<JsonSuggestion>>,
+    /// Every pattern hit in evaluation order (`--all-matches` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_matches: Option<Vec<JsonPatternHit>>,
+    /// Configured `packs.pack_order` (`--all-matches` only, when set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pack_order: Option<Vec<String>>,
+}
+
+/// JSON representation of one `--all-matches` hit.
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonPatternHit {
+    /// 1-based position in evaluation order.
+    pub rank: usize,
+    /// Stable rule ID (unnamed patterns have none).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    /// Pack ID.
+    pub pack_id: String,
+    /// Pattern name within the pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern_name: Option<String>,
+    /// "safe" or "destructive".
+    pub kind: String,
+    /// Severity level (destructive hits only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    /// Matched span (byte offsets).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_span: Option<JsonSpan>,
+    /// A safe pattern in the same pack matched first.
+    pub shadowed_by_safe: bool,
+    /// Whether this hit decided the outcome.
+    pub decided: bool,
 }
 
 /// JSON representation of a trace step.
@@ -755,6 +1349,25 @@ pub enum JsonTraceDetails {
         is_hook_input: bool,
         command_len: usize,
     },
+    EvaluationContext {
+        working_dir: String,
+        branch: Option<String>,
+        branch_overridden: bool,
+        strictness: Option<String>,
+        affected_decision: bool,
+    },
+    BranchPolicy {
+        branch: Option<String>,
+        matched_pattern: Option<String>,
+        policy_mode: Option<String>,
+        effective_mode: Option<String>,
+    },
+    SeverityThreshold {
+        min_severity: String,
+        match_severity: Option<String>,
+        below_threshold: bool,
+        threshold_decision: String,
+    },
     KeywordGating {
         quick_rejected: bool,
         keywords_checked: Vec<String>,
@@ -764,6 +1377,10 @@ pub enum JsonTraceDetails {
         was_modified: bool,
         stripped_prefix: Option<String>,
     },
+    IndirectionResolution {
+        kind: String,
+        resolved_command: String,
+    },
     Sanitization {
         was_modified: bool,
         spans_masked: usize,
@@ -778,6 +1395,11 @@ pub enum JsonTraceDetails {
         matched: bool,
         matched_layer: Option<String>,
     },
+    AllowlistExpired {
+        layer: String,
+        selector: String,
+        expired_at: String,
+    },
     PackEvaluation {
         packs_evaluated: Vec<String>,
         packs_skipped: Vec<String>,
@@ -813,6 +1435,8 @@ pub struct JsonMatchInfo {
     pub severity: Option<String>,
     /// Human-readable reason for the match.
     pub reason: String,
+    /// Stable category for `reason`, for programmatic handling.
+    pub reason_code: ReasonCode,
     /// Source of the match.
     pub source: String,
     /// Matched span (byte offsets).
@@ -893,6 +1517,44 @@ impl TraceDetails {
                 is_hook_input: *is_hook_input,
                 command_len: *command_len,
             },
+            Self::EvaluationContext {
+                working_dir,
+                branch,
+                branch_overridden,
+                strictness,
+                affected_decision,
+            } => JsonTraceDetails::EvaluationContext {
+                working_dir: working_dir.clone(),
+                branch: branch.clone(),
+                branch_overridden: *branch_overridden,
+                strictness: strictness.clone(),
+                affected_decision: *affected_decision,
+            },
+            Self::BranchPolicy {
+                branch,
+                matched_pattern,
+                policy_mode,
+                effective_mode,
+            } => JsonTraceDetails::BranchPolicy {
+                branch: branch.clone(),
+                matched_pattern: matched_pattern.clone(),
+                policy_mode: policy_mode.map(|mode| mode.label().to_string()),
+                effective_mode: effective_mode.map(|mode| mode.label().to_string()),
+            },
+            Self::SeverityThreshold {
+                min_severity,
+                match_severity,
+                below_threshold,
+                threshold_decision,
+            } => JsonTraceDetails::SeverityThreshold {
+                min_severity: min_severity.label().to_string(),
+                match_severity: match_severity.map(|s| s.label().to_string()),
+                below_threshold: *below_threshold,
+                threshold_decision: match threshold_decision {
+                    EvaluationDecision::Allow => "allow".to_string(),
+                    EvaluationDecision::Deny => "deny".to_string(),
+                },
+            },
             Self::KeywordGating {
                 quick_rejected,
                 keywords_checked,
@@ -909,6 +1571,13 @@ impl TraceDetails {
                 was_modified: *was_modified,
                 stripped_prefix: stripped_prefix.clone(),
             },
+            Self::IndirectionResolution {
+                kind,
+                resolved_command,
+            } => JsonTraceDetails::IndirectionResolution {
+                kind: (*kind).to_string(),
+                resolved_command: resolved_command.clone(),
+            },
             Self::Sanitization {
                 was_modified,
                 spans_masked,
@@ -934,6 +1603,15 @@ impl TraceDetails {
                 matched: *matched,
                 matched_layer: matched_layer.as_ref().map(|l| l.label().to_string()),
             },
+            Self::AllowlistExpired {
+                layer,
+                selector,
+                expired_at,
+            } => JsonTraceDetails::AllowlistExpired {
+                layer: layer.label().to_string(),
+                selector: selector.clone(),
+                expired_at: expired_at.clone(),
+            },
             Self::PackEvaluation {
                 packs_evaluated,
                 packs_skipped,
@@ -969,6 +1647,18 @@ impl TraceDetails {
 }
 
 impl MatchInfo {
+    /// Stable reason code for the matched rule (`Unspecified` if unknown).
+    #[must_use]
+    pub fn reason_code(&self) -> ReasonCode {
+        match (self.pack_id.as_deref(), self.pattern_name.as_deref()) {
+            (Some(pack_id), Some(pattern_name)) => ReasonCode::for_rule(pack_id, pattern_name),
+            _ => self
+                .rule_id
+                .as_deref()
+                .map_or(ReasonCode::Unspecified, ReasonCode::for_rule_id),
+        }
+    }
+
     fn rule_label(&self) -> Option<String> {
         if let Some(rule_id) = self.rule_id.as_ref() {
             return Some(rule_id.clone());
@@ -1014,11 +1704,13 @@ impl MatchInfo {
             pattern_name: self.pattern_name.clone(),
             severity: self.severity.map(|s| s.label().to_string()),
             reason: self.reason.clone(),
+            reason_code: self.reason_code(),
             source: match self.source {
                 MatchSource::Pack => "pack".to_string(),
                 MatchSource::ConfigOverride => "config_override".to_string(),
                 MatchSource::LegacyPattern => "legacy_pattern".to_string(),
                 MatchSource::HeredocAst => "heredoc_ast".to_string(),
+                MatchSource::Heuristic => "heuristic".to_string(),
             },
             matched_span: match (self.match_start, self.match_end) {
                 (Some(start), Some(end)) => Some(JsonSpan { start, end }),
@@ -1062,6 +1754,68 @@ fn format_step_details_summary(details: &TraceDetails) -> String {
             let source = if *is_hook_input { "hook" } else { "CLI" };
             format!("source={source}, len={command_len}")
         }
+        TraceDetails::EvaluationContext {
+            working_dir,
+            branch,
+            branch_overridden,
+            strictness,
+            affected_decision,
+        } => {
+            let mut out = format!("dir={working_dir}");
+            match branch {
+                Some(name) if *branch_overridden => {
+                    let _ = write!(out, ", branch={name} (override)");
+                }
+                Some(name) => {
+                    let _ = write!(out, ", branch={name}");
+                }
+                None => out.push_str(", no branch"),
+            }
+            if let Some(level) = strictness {
+                let _ = write!(out, ", strictness={level}");
+            }
+            if *affected_decision {
+                out.push_str(" (decision relaxed)");
+            }
+            out
+        }
+        TraceDetails::BranchPolicy {
+            branch,
+            matched_pattern,
+            policy_mode,
+            effective_mode,
+        } => {
+            let mut out = match (branch, matched_pattern, policy_mode) {
+                (Some(name), Some(pattern), Some(mode)) => {
+                    format!("branch={name} matches \"{pattern}\" => {}", mode.label())
+                }
+                (Some(name), _, _) => format!("branch={name}, no policy (default)"),
+                (None, _, _) => "no branch, default policy".to_string(),
+            };
+            if let Some(mode) = effective_mode {
+                let _ = write!(out, ", effective={}", mode.label());
+            }
+            out
+        }
+        TraceDetails::SeverityThreshold {
+            min_severity,
+            match_severity,
+            below_threshold,
+            threshold_decision,
+        } => {
+            let mut out = format!("min={}", min_severity.label());
+            if let Some(severity) = match_severity {
+                let _ = write!(out, ", match={}", severity.label());
+            }
+            if *below_threshold {
+                let dec = match threshold_decision {
+                    EvaluationDecision::Allow => "ALLOW",
+                    EvaluationDecision::Deny => "DENY",
+                };
+                let _ = write!(out, " -> {dec} at threshold (preview only)");
+            }
+            out
+        }
         TraceDetails::KeywordGating {
             quick_rejected,
             first_match,
@@ -1089,6 +1843,10 @@ fn format_step_details_summary(details: &TraceDetails) -> String {
                 "no change".to_string()
             }
         }
+        TraceDetails::IndirectionResolution {
+            kind,
+            resolved_command,
+        } => format!("resolved {kind}: {resolved_command}"),
         TraceDetails::Sanitization {
             was_modified,
             spans_masked,
@@ -1129,6 +1887,14 @@ fn format_step_details_summary(details: &TraceDetails) -> String {
                 format!("checked {layers_checked} layer(s), no match")
             }
         }
+        TraceDetails::AllowlistExpired {
+            layer,
+            selector,
+            expired_at,
+        } => format!(
+            "skipped {selector} ({}): expired {expired_at}",
+            layer.label()
+        ),
         TraceDetails::PackEvaluation {
             packs_evaluated,
             matched_pack,
@@ -1224,6 +1990,24 @@ pub fn truncate_utf8(s: &str, max_len: usize) -> String {
     result
 }
 
+/// Escape text for a double-quoted DOT string.
+///
+/// Backslashes and quotes are escaped, line breaks become DOT `\n` breaks, and
+/// other control characters are dropped so the output always parses.
+fn dot_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1517,6 +2301,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let compact = trace.format_compact(None);
@@ -1547,6 +2333,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let compact = trace.format_compact(None);
@@ -1571,6 +2359,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let compact = trace.format_compact(Some(40));
@@ -1602,6 +2392,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let compact = trace.format_compact(None);
@@ -1611,6 +2403,173 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_dot_deny_chains_steps_and_attaches_match_to_pack_step() {
+        let trace = ExplainTrace {
+            command: "git reset --hard \"HEAD~1\"".to_string(),
+            normalized_command: None,
+            sanitized_command: None,
+            decision: EvaluationDecision::Deny,
+            skipped_due_to_budget: false,
+            total_duration_us: 847,
+            steps: vec![
+                TraceStep {
+                    name: "keyword_gating",
+                    duration_us: 12,
+                    details: TraceDetails::KeywordGating {
+                        quick_rejected: false,
+                        keywords_checked: vec!["git".to_string()],
+                        first_match: None,
+                    },
+                },
+                TraceStep {
+                    name: "pack_evaluation",
+                    duration_us: 800,
+                    details: TraceDetails::PackEvaluation {
+                        packs_evaluated: vec!["core.git".to_string()],
+                        packs_skipped: vec![],
+                        matched_pack: Some("core.git".to_string()),
+                        matched_pattern: Some("reset-hard".to_string()),
+                    },
+                },
+            ],
+            match_info: Some(MatchInfo {
+                rule_id: Some("core.git:reset-hard".to_string()),
+                pack_id: Some("core.git".to_string()),
+                pattern_name: Some("reset-hard".to_string()),
+                severity: Some(Severity::Critical),
+                reason: "destroys uncommitted changes".to_string(),
+                source: MatchSource::Pack,
+                match_start: Some(0),
+                match_end: Some(16),
+                matched_text_preview: Some("git reset --hard".to_string()),
+                explanation: None,
+            }),
+            allowlist_info: None,
+            pack_summary: None,
+            all_matches: None,
+            pack_order: None,
+        };
+
+        let dot = trace.format_dot();
+        assert!(dot.starts_with("digraph explain {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(
+            dot.contains("  command [shape=note, label=\"git reset --hard \\\"HEAD~1\\\"\"];\n")
+        );
+        assert!(dot.contains("  step0 [label=\"keyword_gating\\n12us\"];\n"));
+        assert!(dot.contains("  step1 [label=\"pack_evaluation\\n800us\"];\n"));
+        let command_edge = dot.find("  command -> step0;").unwrap();
+        let step_edge = dot.find("  step0 -> step1;").unwrap();
+        let decision_edge = dot.find("  step1 -> decision;").unwrap();
+        assert!(command_edge < step_edge && step_edge < decision_edge);
+        assert!(dot.contains("fillcolor=\"#f8d7da\", color=\"#c62828\", label=\"DENY\\n847us\""));
+        assert!(dot.contains(
+            "label=\"core.git:reset-hard\\ndestroys uncommitted changes\\nmatched: git reset --hard\""
+        ));
+        assert!(dot.contains("  step1 -> match [style=dashed, arrowhead=none];\n"));
+    }
+
+    #[test]
+    fn format_dot_allow_is_green_without_match_callout() {
+        let trace = ExplainTrace {
+            command: "git status".to_string(),
+            normalized_command: None,
+            sanitized_command: None,
+            decision: EvaluationDecision::Allow,
+            skipped_due_to_budget: false,
+            total_duration_us: 94,
+            steps: vec![],
+            match_info: None,
+            allowlist_info: None,
+            pack_summary: None,
+            all_matches: None,
+            pack_order: None,
+        };
+
+        let dot = trace.format_dot();
+        assert!(dot.contains("  command -> decision;\n"));
+        assert!(dot.contains("fillcolor=\"#d4edda\", color=\"#2e7d32\", label=\"ALLOW\\n94us\""));
+        assert!(!dot.contains("match ["));
+    }
+
+    #[test]
+    fn diff_reports_differing_steps_and_match() {
+        let gating = |first_match: Option<&str>| TraceStep {
+            name: "keyword_gating",
+            duration_us: 10,
+            details: TraceDetails::KeywordGating {
+                quick_rejected: first_match.is_none(),
+                keywords_checked: vec!["git".to_string(), "docker".to_string()],
+                first_match: first_match.map(str::to_string),
+            },
+        };
+        let sanitization = TraceStep {
+            name: "sanitization",
+            duration_us: 3,
+            details: TraceDetails::Sanitization {
+                was_modified: false,
+                spans_masked: 0,
+            },
+        };
+        let mut collector = TraceCollector::new("git reset --hard");
+        collector.record_step("keyword_gating", 10, gating(Some("git")).details);
+        collector.record_step(sanitization.name, 3, sanitization.details.clone());
+        collector.set_match(MatchInfo {
+            rule_id: Some("core.git:reset-hard".to_string()),
+            pack_id: Some("core.git".to_string()),
+            pattern_name: Some("reset-hard".to_string()),
+            severity: Some(Severity::Critical),
+            reason: "destroys uncommitted changes".to_string(),
+            source: MatchSource::Pack,
+            match_start: None,
+            match_end: None,
+            matched_text_preview: None,
+            explanation: None,
+        });
+        let hard = collector.finish(EvaluationDecision::Deny);
+
+        let mut collector = TraceCollector::new("git reset --soft");
+        collector.record_step("keyword_gating", 40, gating(None).details);
+        collector.record_step(sanitization.name, 5, sanitization.details);
+        let soft = collector.finish(EvaluationDecision::Allow);
+
+        let diff = hard.diff(&soft);
+        assert!(diff.decision_differs);
+        assert!(diff.match_differs);
+        assert_eq!(diff.left.rule_id.as_deref(), Some("core.git:reset-hard"));
+        assert_eq!(diff.right.rule_id, None);
+        assert_eq!(diff.left.keywords_matched, vec!["git".to_string()]);
+        assert_eq!(diff.right.keywords_matched, vec!["git".to_string()]);
+        // Only the gating step differs; sanitization differs only in duration.
+        assert_eq!(diff.steps.len(), 1);
+        assert_eq!(diff.steps[0].name, "keyword_gating");
+
+        let pretty = diff.format_pretty();
+        assert!(pretty.contains("* decision"));
+        assert!(pretty.contains("DENY"));
+        assert!(pretty.contains("ALLOW"));
+        assert!(pretty.contains("  keywords"));
+        assert!(pretty.contains("* step keyword_gating"));
+        assert!(!pretty.contains("step sanitization"));
+
+        let json = serde_json::to_value(&diff).expect("serialize");
+        assert_eq!(json["left"]["decision"], "deny");
+        assert_eq!(json["right"]["decision"], "allow");
+        assert_eq!(json["steps"][0]["name"], "keyword_gating");
+
+        let same = soft.diff(&soft);
+        assert!(!same.decision_differs && !same.match_differs);
+        assert!(same.steps.is_empty());
+    }
+
+    #[test]
+    fn dot_escape_handles_quotes_backslashes_and_newlines() {
+        assert_eq!(dot_escape("a\"b"), "a\\\"b");
+        assert_eq!(dot_escape("C:\\tmp"), "C:\\\\tmp");
+        assert_eq!(dot_escape("line1\nline2\r\t"), "line1\\nline2");
+    }
+
     // ========================================================================
     // Pretty formatter tests
     // ========================================================================
@@ -1628,6 +2587,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let pretty = trace.format_pretty(false);
@@ -1669,6 +2630,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let pretty = trace.format_pretty(false);
@@ -1697,6 +2660,100 @@ mod tests {
         assert!(pretty.contains("─── Suggestions"));
     }
 
+    #[test]
+    fn all_matches_ranks_hits_and_marks_decider() {
+        use crate::evaluator::MatchSpan;
+
+        let hit = |pack: &str, name: &str, kind, severity, shadowed| PatternHit {
+            pack_id: pack.to_string(),
+            pattern_name: Some(name.to_string()),
+            kind,
+            severity,
+            matched_span: Some(MatchSpan { start: 0, end: 19 }),
+            shadowed_by_safe: shadowed,
+        };
+        let trace = ExplainTrace {
+            command: "docker compose down -v".to_string(),
+            normalized_command: None,
+            sanitized_command: None,
+            decision: EvaluationDecision::Deny,
+            skipped_due_to_budget: false,
+            total_duration_us: 100,
+            steps: vec![],
+            match_info: Some(MatchInfo {
+                rule_id: Some("containers.compose:down-volumes".to_string()),
+                pack_id: Some("containers.compose".to_string()),
+                pattern_name: Some("down-volumes".to_string()),
+                severity: Some(Severity::Critical),
+                reason: "removes volumes".to_string(),
+                source: MatchSource::Pack,
+                match_start: Some(0),
+                match_end: Some(22),
+                matched_text_preview: None,
+                explanation: None,
+            }),
+            allowlist_info: None,
+            pack_summary: None,
+            all_matches: Some(vec![
+                hit(
+                    "containers.docker",
+                    "docker-compose-safe",
+                    PatternHitKind::Safe,
+                    None,
+                    false,
+                ),
+                hit(
+                    "containers.docker",
+                    "docker-down",
+                    PatternHitKind::Destructive,
+                    Some(Severity::High),
+                    true,
+                ),
+                hit(
+                    "containers.compose",
+                    "down-volumes",
+                    PatternHitKind::Destructive,
+                    Some(Severity::Critical),
+                    false,
+                ),
+            ]),
+            pack_order: None,
+        };
+
+        let lines = trace.all_matches_lines();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with(" 1. containers.docker:docker-compose-safe"));
+        assert!(lines[1].contains("skipped: safe pattern in same pack"));
+        assert!(!lines[1].ends_with(ALL_MATCHES_DECIDED_MARKER));
+        assert!(lines[2].starts_with(" 3. containers.compose:down-volumes"));
+        assert!(lines[2].ends_with(ALL_MATCHES_DECIDED_MARKER));
+        assert_eq!(lines[3], "3 matches across 2 packs");
+
+        let pretty = trace.format_pretty(false);
+        assert!(pretty.contains("─── All Matches"));
+
+        let json = trace.to_json_output();
+        let hits = json.all_matches.expect("all_matches in JSON");
+        assert_eq!(hits.len(), 3);
+        assert_eq!(
+            hits.iter()
+                .filter(|h| h.decided)
+                .map(|h| h.rank)
+                .collect::<Vec<_>>(),
+            vec![3]
+        );
+        assert_eq!(hits[0].kind, "safe");
+        assert!(hits[1].shadowed_by_safe);
+    }
+
+    #[test]
+    fn all_matches_omitted_unless_requested() {
+        let trace = TraceCollector::new("ls").finish(EvaluationDecision::Allow);
+        assert!(trace.all_matches_lines().is_empty());
+        assert!(!trace.format_pretty(false).contains("All Matches"));
+        assert!(!trace.format_json().contains("all_matches"));
+    }
+
     #[test]
     fn format_pretty_with_normalized_command() {
         let trace = ExplainTrace {
@@ -1721,6 +2778,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let pretty = trace.format_pretty(false);
@@ -1762,6 +2821,8 @@ mod tests {
                 original_match,
             }),
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let pretty = trace.format_pretty(false);
@@ -1795,6 +2856,8 @@ mod tests {
                     "database.postgresql".to_string(),
                 ],
             }),
+            all_matches: None,
+            pack_order: None,
         };
 
         let pretty = trace.format_pretty(false);
@@ -1849,6 +2912,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let pretty = trace.format_pretty(false);
@@ -1887,6 +2952,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let with_color = trace.format_pretty(true);
@@ -1902,6 +2969,47 @@ mod tests {
         assert!(without_color.contains("DENY"));
     }
 
+    #[test]
+    fn format_pretty_links_rule_id_only_when_enabled() {
+        let trace = ExplainTrace {
+            command: "git reset --hard".to_string(),
+            normalized_command: None,
+            sanitized_command: None,
+            decision: EvaluationDecision::Deny,
+            skipped_due_to_budget: false,
+            total_duration_us: 847,
+            steps: vec![],
+            match_info: Some(MatchInfo {
+                rule_id: Some("core.git:reset-hard".to_string()),
+                pack_id: Some("core.git".to_string()),
+                pattern_name: Some("reset-hard".to_string()),
+                severity: Some(Severity::Critical),
+                reason: "destroys uncommitted changes".to_string(),
+                source: MatchSource::Pack,
+                match_start: None,
+                match_end: None,
+                matched_text_preview: None,
+                explanation: None,
+            }),
+            allowlist_info: None,
+            pack_summary: None,
+            all_matches: None,
+            pack_order: None,
+        };
+
+        let linked = trace.format_pretty_with_links(true, true);
+        assert!(linked.contains("\x1b]8;;"));
+        assert!(linked.contains("docs/packs/core.md"));
+        assert!(linked.contains("core.git:reset-hard\x1b]8;;\x1b\\"));
+
+        assert!(
+            !trace
+                .format_pretty_with_links(true, false)
+                .contains("\x1b]8;;")
+        );
+        assert!(!trace.format_pretty(false).contains("\x1b]8;;"));
+    }
+
     #[test]
     fn format_step_details_summary_all_variants() {
         // Test each TraceDetails variant produces reasonable summary
@@ -1962,6 +3070,16 @@ mod tests {
             "hit at Project"
         );
 
+        let allowlist_expired = TraceDetails::AllowlistExpired {
+            layer: AllowlistLayer::Project,
+            selector: "rule core.git:reset-hard".to_string(),
+            expired_at: "2020-01-01T00:00:00Z".to_string(),
+        };
+        assert_eq!(
+            format_step_details_summary(&allowlist_expired),
+            "skipped rule core.git:reset-hard (project): expired 2020-01-01T00:00:00Z"
+        );
+
         let pack_match = TraceDetails::PackEvaluation {
             packs_evaluated: vec!["core.git".to_string()],
             packs_skipped: vec![],
@@ -1991,6 +3109,28 @@ mod tests {
             format_step_details_summary(&policy_allow),
             "ALLOW (allowlisted)"
         );
+
+        let branch_policy = TraceDetails::BranchPolicy {
+            branch: Some("release/2.0".to_string()),
+            matched_pattern: Some("release/*".to_string()),
+            policy_mode: Some(DecisionMode::Deny),
+            effective_mode: Some(DecisionMode::Deny),
+        };
+        assert_eq!(
+            format_step_details_summary(&branch_policy),
+            "branch=release/2.0 matches \"release/*\" => deny, effective=deny"
+        );
+
+        let detached = TraceDetails::BranchPolicy {
+            branch: None,
+            matched_pattern: None,
+            policy_mode: None,
+            effective_mode: Some(DecisionMode::Warn),
+        };
+        assert_eq!(
+            format_step_details_summary(&detached),
+            "no branch, default policy, effective=warn"
+        );
     }
 
     // ========================================================================
@@ -2010,6 +3150,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2032,6 +3174,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2062,6 +3206,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2119,6 +3265,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2163,6 +3311,8 @@ mod tests {
                 original_match,
             }),
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2191,6 +3341,8 @@ mod tests {
                 evaluated: vec!["core.git".to_string()],
                 skipped: vec!["containers.docker".to_string()],
             }),
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2239,6 +3391,8 @@ mod tests {
                 evaluated: vec!["core.git".to_string()],
                 skipped: vec!["containers.docker".to_string()],
             }),
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2274,6 +3428,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let output = trace.to_json_output();
@@ -2308,6 +3464,12 @@ mod tests {
             explanation: None,
         };
 
+        assert_eq!(info.reason_code(), ReasonCode::DestroysUncommittedChanges);
+        assert_eq!(
+            info.to_json().reason_code,
+            ReasonCode::DestroysUncommittedChanges
+        );
+
         let fallback = info.fallback_explanation();
         assert!(
             fallback.contains("Matched destructive pattern core.git:reset-hard"),
@@ -2551,6 +3713,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2588,6 +3752,8 @@ mod tests {
             }),
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -2623,6 +3789,8 @@ mod tests {
             match_info: None,
             allowlist_info: None,
             pack_summary: None,
+            all_matches: None,
+            pack_order: None,
         };
 
         let json = trace.format_json();
@@ -697,7 +697,7 @@ pub fn format_check_result(result: &VersionCheckResult, use_color: bool) -> Stri
 ///
 /// Returns `VersionCheckError::ParseError` if JSON serialization fails.
 pub fn format_check_result_json(result: &VersionCheckResult) -> Result<String, VersionCheckError> {
-    serde_json::to_string_pretty(result)
+    crate::output::to_json_string(result)
         .map_err(|e| VersionCheckError::ParseError(format!("Failed to serialize result: {e}")))
 }
 
@@ -102,6 +102,101 @@ fn run_dcg_hook(command: &str) -> HookRunOutput {
     run_dcg_hook_with_env(command, &[])
 }
 
+/// Strip insignificant whitespace from JSON text, keeping key order intact.
+fn minify_json(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in json.chars() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+            out.push(c);
+        } else if !c.is_whitespace() {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Replace the numeric value of every `"key":<n>` in minified JSON with 0.
+///
+/// Used to neutralize timing fields when comparing two runs.
+fn mask_number_field(json: &str, key: &str) -> String {
+    let needle = format!("\"{key}\":");
+    let mut out = String::with_capacity(json.len());
+    let mut rest = json;
+    while let Some(pos) = rest.find(&needle) {
+        let value_start = pos + needle.len();
+        out.push_str(&rest[..value_start]);
+        rest = rest[value_start..].trim_start_matches(|c: char| c.is_ascii_digit());
+        out.push('0');
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Run a JSON-emitting command with and without `--compact-json` and assert
+/// both outputs carry the same fields in the same order.
+fn assert_compact_json_matches_pretty(args: &[&str], timing_fields: &[&str]) {
+    let pretty = run_dcg(args);
+    let mut compact_args = args.to_vec();
+    compact_args.push("--compact-json");
+    let compact = run_dcg(&compact_args);
+
+    let pretty = String::from_utf8_lossy(&pretty.stdout).to_string();
+    let compact = String::from_utf8_lossy(&compact.stdout).to_string();
+
+    assert!(
+        pretty.trim().lines().count() > 1,
+        "default JSON should be indented"
+    );
+    assert_eq!(
+        compact.trim().lines().count(),
+        1,
+        "--compact-json should print a single line: {compact}"
+    );
+
+    let pretty_value: serde_json::Value = serde_json::from_str(&pretty).expect("pretty JSON");
+    let compact_value: serde_json::Value = serde_json::from_str(&compact).expect("compact JSON");
+    assert_eq!(
+        pretty_value.as_object().map(serde_json::Map::len),
+        compact_value.as_object().map(serde_json::Map::len)
+    );
+
+    let mut pretty_min = minify_json(&pretty);
+    let mut compact_min = compact.trim().to_string();
+    for field in timing_fields {
+        pretty_min = mask_number_field(&pretty_min, field);
+        compact_min = mask_number_field(&compact_min, field);
+    }
+    assert_eq!(pretty_min, compact_min, "outputs differ beyond whitespace");
+}
+
+/// Assert that `instance` validates against the schema printed by
+/// `dcg <subcommand> --print-schema`.
+fn assert_matches_printed_schema(subcommand: &str, instance: &serde_json::Value) {
+    let output = run_dcg(&[subcommand, "--print-schema"]);
+    assert!(output.status.success(), "--print-schema should succeed");
+    let schema: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("schema should be valid JSON");
+    let compiled = jsonschema::JSONSchema::compile(&schema).expect("schema should compile");
+    if let Err(errors) = compiled.validate(instance) {
+        let errors: Vec<String> = errors
+            .map(|e| format!("{}: {e}", e.instance_path))
+            .collect();
+        panic!("{subcommand} output does not match its schema:\n{errors:#?}\n{instance:#}");
+    }
+}
+
 // ============================================================================
 // DCG EXPLAIN Tests
 // ============================================================================
@@ -109,6 +204,76 @@ fn run_dcg_hook(command: &str) -> HookRunOutput {
 mod explain_tests {
     use super::*;
 
+    #[test]
+    fn pack_order_config_changes_reported_pack() {
+        let temp = tempfile::tempdir().unwrap();
+        let explain = |pack_order: &str| {
+            let config_path = temp.path().join("config.toml");
+            std::fs::write(
+                &config_path,
+                format!("[packs]\nenabled = [\"cloud\"]\npack_order = [{pack_order}]\n"),
+            )
+            .unwrap();
+            let output = Command::new(dcg_binary())
+                .args([
+                    "explain",
+                    "--format",
+                    "json",
+                    "--all-matches",
+                    "aws iam delete-user --user-name bob",
+                ])
+                .env("HOME", temp.path())
+                .env("XDG_CONFIG_HOME", temp.path())
+                .env("DCG_CONFIG", &config_path)
+                .env_remove("DCG_PACKS")
+                .output()
+                .expect("failed to execute dcg");
+            let json: serde_json::Value = serde_json::from_slice(&output.stdout)
+                .expect("explain --format json should produce valid JSON");
+            assert_matches_printed_schema("explain", &json);
+            json
+        };
+
+        let default = explain("");
+        assert_eq!(default["match"]["pack_id"], "cloud.aws");
+        assert!(default.get("pack_order").is_none());
+
+        let reordered = explain("\"cloud.aws_security\"");
+        assert_eq!(reordered["match"]["pack_id"], "cloud.aws_security");
+        assert_ne!(reordered["match"]["reason"], default["match"]["reason"]);
+        assert_eq!(reordered["pack_order"][0], "cloud.aws_security");
+        assert_eq!(reordered["all_matches"][0]["pack_id"], "cloud.aws_security");
+        assert_eq!(reordered["all_matches"][0]["decided"], true);
+    }
+
+    #[test]
+    fn color_flag_overrides_tty_and_no_color_detection() {
+        let run = |color: &str| {
+            let output = Command::new(dcg_binary())
+                .args(["--color", color, "explain", "git reset --hard"])
+                .env("NO_COLOR", "1")
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .output()
+                .expect("failed to execute dcg");
+            assert!(output.status.success());
+            [output.stdout, output.stderr].concat()
+        };
+
+        assert!(
+            !run("never").contains(&0x1b),
+            "--color never must not emit escape sequences"
+        );
+        assert!(
+            !run("auto").contains(&0x1b),
+            "auto respects NO_COLOR and the pipe"
+        );
+        assert!(
+            run("always").contains(&0x1b),
+            "--color always colors even when piped"
+        );
+    }
+
     #[test]
     fn explain_safe_command_returns_allow_pretty() {
         let output = run_dcg(&["explain", "echo hello"]);
@@ -139,6 +304,36 @@ mod explain_tests {
         assert!(stdout.contains("core.git"), "should mention pack");
     }
 
+    #[test]
+    fn explain_output_writes_uncolored_trace_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let trace_path = dir.path().join("explain.txt");
+
+        let output = run_dcg(&[
+            "explain",
+            "--format",
+            "compact",
+            "--output",
+            trace_path.to_str().unwrap(),
+            "git reset --hard",
+        ]);
+        assert!(output.status.success());
+        assert!(output.stdout.is_empty(), "trace should not go to stdout");
+
+        // Compact output embeds the evaluation time, e.g. "(3ms)".
+        let without_timing = |text: &str| {
+            text.split_whitespace()
+                .filter(|word| !(word.starts_with('(') && word.ends_with("ms)")))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let stdout_run = run_dcg(&["explain", "--format", "compact", "git reset --hard"]);
+        assert_eq!(
+            without_timing(&std::fs::read_to_string(&trace_path).unwrap()),
+            without_timing(&String::from_utf8_lossy(&stdout_run.stdout))
+        );
+    }
+
     #[test]
     fn explain_json_format_is_valid() {
         // Use git command since core.git is always enabled
@@ -159,6 +354,52 @@ mod explain_tests {
         assert!(json["steps"].is_array(), "should have steps array");
     }
 
+    #[test]
+    fn explain_print_schema_validates_real_output() {
+        let output = run_dcg(&["explain", "--print-schema"]);
+        let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            schema["$id"],
+            "https://github.com/Dicklesworthstone/destructive_command_guard/docs/json-schema/explain-output-v2.json"
+        );
+        assert_eq!(schema["properties"]["schema_version"]["const"], 2);
+
+        for args in [
+            &["explain", "--format", "json", "git reset --hard"][..],
+            &[
+                "explain",
+                "--format",
+                "json",
+                "--all-matches",
+                "git reset --hard",
+            ],
+            &["explain", "--format", "json", "ls -la"],
+            &["explain", "--format", "json", "eval 'rm -rf /'"],
+        ] {
+            let output = run_dcg(args);
+            let json: serde_json::Value = serde_json::from_slice(&output.stdout)
+                .expect("explain --format json should produce valid JSON");
+            assert_matches_printed_schema("explain", &json);
+        }
+    }
+
+    #[test]
+    fn explain_json_shows_resolved_eval_step() {
+        let output = run_dcg(&["explain", "--format", "json", "eval 'git reset --hard'"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout).expect("explain --format json should produce valid JSON");
+
+        assert_eq!(json["decision"], "deny");
+        let step = json["steps"]
+            .as_array()
+            .and_then(|steps| steps.iter().find(|s| s["name"] == "resolved eval/var"))
+            .expect("should include a resolved eval/var step");
+        assert_eq!(step["details"]["type"], "indirection_resolution");
+        assert_eq!(step["details"]["kind"], "eval");
+        assert_eq!(step["details"]["resolved_command"], "git reset --hard");
+    }
+
     #[test]
     fn explain_json_includes_suggestions_for_blocked_commands() {
         // Use git command since core.git is always enabled
@@ -175,6 +416,130 @@ mod explain_tests {
         );
     }
 
+    #[test]
+    fn explain_context_and_branch_drive_branch_strictness() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join(".git")).unwrap();
+        std::fs::write(
+            repo.path().join(".dcg.toml"),
+            "[git_awareness]\nenabled = true\n",
+        )
+        .unwrap();
+        let home = tempfile::tempdir().unwrap();
+        let dir = repo.path().to_str().unwrap();
+
+        let explain_on = |branch: &str, command: &str| -> serde_json::Value {
+            let output = Command::new(dcg_binary())
+                .args(["explain", "--format", "json", "--context", dir])
+                .args(["--branch", branch, command])
+                .env("HOME", home.path())
+                .env("XDG_CONFIG_HOME", home.path())
+                .env_remove("DCG_CONFIG")
+                .current_dir(home.path())
+                .output()
+                .expect("failed to execute dcg");
+            serde_json::from_slice(&output.stdout).expect("explain should emit JSON")
+        };
+        let context_step = |json: &serde_json::Value| {
+            json["steps"]
+                .as_array()
+                .and_then(|steps| steps.iter().find(|s| s["name"] == "evaluation_context"))
+                .map(|s| s["details"].clone())
+                .expect("trace should record the evaluation context")
+        };
+
+        // checkout -- is High severity: blocked on a protected branch...
+        let json = explain_on("main", "git checkout -- src/lib.rs");
+        assert_eq!(json["decision"], "deny");
+        let ctx = context_step(&json);
+        assert_eq!(ctx["working_dir"], dir);
+        assert_eq!(ctx["branch"], "main");
+        assert_eq!(ctx["branch_overridden"], true);
+        assert_eq!(ctx["strictness"], "all");
+        assert_eq!(ctx["affected_decision"], false);
+
+        // ...but only Critical rules block on a relaxed branch.
+        let json = explain_on("feature/experiment", "git checkout -- src/lib.rs");
+        assert_eq!(json["decision"], "allow");
+        let ctx = context_step(&json);
+        assert_eq!(ctx["branch"], "feature/experiment");
+        assert_eq!(ctx["strictness"], "critical");
+        assert_eq!(ctx["affected_decision"], true);
+    }
+
+    #[test]
+    fn explain_notes_expired_allowlist_entry() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join(".git")).unwrap();
+        std::fs::create_dir_all(repo.path().join(".dcg")).unwrap();
+        std::fs::write(
+            repo.path().join(".dcg/allowlist.toml"),
+            r#"
+[[allow]]
+rule = "core.git:reset-hard"
+reason = "temporary release cleanup"
+expires_at = "2020-01-01T00:00:00Z"
+"#,
+        )
+        .unwrap();
+        let home = tempfile::tempdir().unwrap();
+
+        let output = Command::new(dcg_binary())
+            .args(["explain", "--format", "json", "git reset --hard HEAD"])
+            .env("HOME", home.path())
+            .env("XDG_CONFIG_HOME", home.path())
+            .env("DCG_ALLOWLIST_SYSTEM_PATH", "")
+            .env_remove("DCG_CONFIG")
+            .current_dir(repo.path())
+            .output()
+            .expect("failed to execute dcg");
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("explain should emit JSON");
+        assert_matches_printed_schema("explain", &json);
+        assert_eq!(json["decision"], "deny");
+        let expired = json["steps"]
+            .as_array()
+            .and_then(|steps| steps.iter().find(|s| s["name"] == "allowlist_expired"))
+            .map(|s| s["details"].clone())
+            .expect("trace should note the expired allowlist entry");
+        assert_eq!(expired["layer"], "project");
+        assert_eq!(expired["selector"], "rule core.git:reset-hard");
+        assert_eq!(expired["expired_at"], "2020-01-01T00:00:00Z");
+
+        let output = Command::new(dcg_binary())
+            .args(["explain", "git reset --hard HEAD"])
+            .env("HOME", home.path())
+            .env("XDG_CONFIG_HOME", home.path())
+            .env("DCG_ALLOWLIST_SYSTEM_PATH", "")
+            .env("NO_COLOR", "1")
+            .env_remove("DCG_CONFIG")
+            .current_dir(repo.path())
+            .output()
+            .expect("failed to execute dcg");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains(
+                "Skipped project allowlist entry for rule core.git:reset-hard: expired 2020-01-01T00:00:00Z"
+            ),
+            "pretty output should mention the expired entry\n{stdout}"
+        );
+    }
+
+    #[test]
+    fn explain_context_rejects_missing_directory() {
+        let output = run_dcg(&["explain", "--context", "/nonexistent/dcg-context", "ls"]);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("--context"));
+    }
+
+    #[test]
+    fn explain_compact_json_matches_pretty_json() {
+        assert_compact_json_matches_pretty(
+            &["explain", "--format", "json", "git reset --hard"],
+            &["total_duration_us", "duration_us"],
+        );
+    }
+
     #[test]
     fn explain_compact_format_is_single_line() {
         let output = run_dcg(&["explain", "--format", "compact", "echo hello"]);
@@ -456,6 +821,11 @@ mod allow_once_flow_tests {
 
         /// Run dcg in hook mode with JSON input.
         fn run_hook(&self, command: &str) -> HookRunOutput {
+            self.run_hook_with_env(command, &[])
+        }
+
+        /// Run dcg in hook mode with extra environment variables.
+        fn run_hook_with_env(&self, command: &str, extra_env: &[(&str, &str)]) -> HookRunOutput {
             let input = serde_json::json!({
                 "tool_name": "Bash",
                 "tool_input": {
@@ -471,6 +841,7 @@ mod allow_once_flow_tests {
                 .env("DCG_PACKS", "core.git,core.filesystem")
                 .env("DCG_PENDING_EXCEPTIONS_PATH", &self.pending_path)
                 .env("DCG_ALLOW_ONCE_PATH", &self.allow_once_path)
+                .envs(extra_env.iter().copied())
                 .current_dir(self.temp.path())
                 .stdin(Stdio::piped())
                 .stdout(Stdio::piped())
@@ -630,6 +1001,70 @@ mod allow_once_flow_tests {
         assert_is_allowed(&result3);
     }
 
+    #[test]
+    fn approve_token_allows_exactly_one_run() {
+        let env = FlowTestEnv::new();
+        let command = "git reset --hard";
+
+        let approve = env.run_cli(&["approve", command, "--expires", "10m", "--json"]);
+        assert!(
+            approve.status.success(),
+            "approve should succeed\nstdout: {}\nstderr: {}",
+            String::from_utf8_lossy(&approve.stdout),
+            String::from_utf8_lossy(&approve.stderr)
+        );
+        let json: serde_json::Value =
+            serde_json::from_slice(&approve.stdout).expect("approve JSON output");
+        assert_eq!(json["single_use"], true);
+        assert!(json["token"].as_str().is_some_and(|t| !t.is_empty()));
+
+        // First run consumes the token; the second is denied again.
+        assert_is_allowed(&env.run_hook(command));
+        assert_is_denial(&env.run_hook(command));
+    }
+
+    #[test]
+    fn approve_token_use_is_recorded_as_bypass() {
+        use destructive_command_guard::history::{ExportOptions, HistoryDb, Outcome};
+
+        let env = FlowTestEnv::new();
+        let command = "git reset --hard";
+        let db_path = env.temp.path().join("history.db");
+        let history_env = [
+            ("DCG_HISTORY_ENABLED", "true"),
+            ("DCG_HISTORY_DB", db_path.to_str().expect("utf-8 path")),
+        ];
+
+        let approve = env.run_cli(&["approve", command, "--json"]);
+        assert!(approve.status.success());
+        let json: serde_json::Value =
+            serde_json::from_slice(&approve.stdout).expect("approve JSON output");
+        let token = json["token"].as_str().expect("token").to_string();
+
+        assert_is_allowed(&env.run_hook_with_env(command, &history_env));
+
+        let db = HistoryDb::open(Some(db_path)).expect("open history db");
+        let entries = db
+            .query_commands_for_export(&ExportOptions::default())
+            .expect("query history");
+        let entry = entries
+            .iter()
+            .find(|entry| entry.command == command)
+            .expect("hook run should be recorded");
+        assert_eq!(entry.outcome, Outcome::Bypass);
+        assert_eq!(entry.bypass_code.as_deref(), Some(token.as_str()));
+    }
+
+    #[test]
+    fn approve_only_covers_the_exact_command() {
+        let env = FlowTestEnv::new();
+        let approve = env.run_cli(&["approve", "git reset --hard HEAD~1"]);
+        assert!(approve.status.success());
+
+        assert_is_denial(&env.run_hook("git reset --hard"));
+        assert_is_allowed(&env.run_hook("git reset --hard HEAD~1"));
+    }
+
     #[test]
     fn block_emits_full_hash_in_hook_output() {
         let env = FlowTestEnv::new();
@@ -987,6 +1422,42 @@ mod scan_tests {
         );
     }
 
+    #[test]
+    fn scan_pretty_summary_badges_match_json_summary() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "git reset --hard").unwrap();
+        writeln!(file, "git clean -fd").unwrap();
+        writeln!(file, "echo hello").unwrap();
+        file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let json_output = run_dcg(&["scan", "--format", "json", "--paths", path]);
+        let json: serde_json::Value =
+            serde_json::from_slice(&json_output.stdout).expect("scan JSON");
+        let severities = &json["summary"]["severities"];
+
+        let output = run_dcg(&["--color", "never", "scan", "--paths", path]);
+        assert!(!output.status.success(), "findings should fail the scan");
+        // Plain builds print to stdout; rich builds route the console to stderr.
+        let text = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(!text.contains('\x1b'), "--color never leaked ANSI: {text}");
+
+        let expected = format!(
+            "Severities: HIGH {} error  MEDIUM {} warning  LOW {} info",
+            severities["error"], severities["warning"], severities["info"]
+        );
+        assert!(text.contains(&expected), "missing `{expected}` in: {text}");
+        let result = format!(
+            "Result: FAIL (exit 1), {} finding(s)",
+            json["summary"]["findings_total"]
+        );
+        assert!(text.contains(&result), "missing `{result}` in: {text}");
+    }
+
     #[test]
     fn scan_json_format_is_valid() {
         let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
@@ -1071,6 +1542,37 @@ mod scan_tests {
         );
     }
 
+    #[test]
+    fn scan_csv_format_writes_only_csv_to_stdout() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "git reset --hard HEAD~1").unwrap();
+        file.flush().unwrap();
+
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            file.path().to_str().unwrap(),
+            "--format",
+            "csv",
+            "--fail-on",
+            "none",
+        ]);
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut lines = stdout.lines();
+        assert_eq!(
+            lines.next(),
+            Some("file,line,col,rule_id,decision,severity,reason,command")
+        );
+        let row = lines.next().expect("one finding row");
+        assert!(row.contains("core.git:reset-hard"), "row: {row}");
+        assert!(row.contains(",deny,error,"), "row: {row}");
+        assert!(lines.next().is_none(), "stdout should be pure CSV");
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("dcg-scan:"), "summary goes to stderr");
+    }
+
     #[test]
     fn scan_fail_on_none_always_succeeds() {
         let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
@@ -1093,75 +1595,716 @@ mod scan_tests {
     }
 
     #[test]
-    fn scan_empty_directory_succeeds() {
-        let dir = tempfile::tempdir().unwrap();
-
-        let output = run_dcg(&["scan", "--paths", dir.path().to_str().unwrap()]);
-
-        assert!(output.status.success(), "scan on empty dir should succeed");
-    }
-
-    #[test]
-    fn scan_findings_include_file_and_line() {
+    fn scan_fail_on_score_gates_on_cumulative_risk() {
         let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
-        writeln!(file, "echo safe").unwrap();
-        // Use git command since core.git is always enabled
         writeln!(file, "git reset --hard").unwrap();
         file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
 
         let output = run_dcg(&[
             "scan",
             "--paths",
-            file.path().to_str().unwrap(),
+            path,
+            "--fail-on",
+            "none",
             "--format",
             "json",
         ]);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let score = json["summary"]["risk_score"]
+            .as_f64()
+            .expect("summary should include risk_score");
+        assert!(score > 0.0, "a denied finding should contribute risk");
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
-        let findings = json["findings"].as_array().unwrap();
+        let under_budget = format!("{}", score + 1.0);
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            path,
+            "--fail-on",
+            "none",
+            "--fail-on-score",
+            &under_budget,
+        ]);
+        assert!(
+            output.status.success(),
+            "score within budget should succeed"
+        );
 
-        assert!(!findings.is_empty(), "should have findings");
-        let finding = &findings[0];
-        assert!(finding["file"].is_string(), "finding should have file");
-        assert!(finding["line"].is_number(), "finding should have line");
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            path,
+            "--fail-on",
+            "none",
+            "--fail-on-score",
+            "0",
+        ]);
         assert!(
-            finding["rule_id"].is_string(),
-            "finding should have rule_id"
+            !output.status.success(),
+            "score above budget should fail the scan"
         );
     }
-}
 
-// ============================================================================
-// DCG TEST (single command evaluation) Tests
-// ============================================================================
+    #[test]
+    fn scan_prints_summary_line_to_stderr_for_machine_formats() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "git reset --hard").unwrap();
+        file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
 
-mod test_command_tests {
-    use super::*;
+        let output = run_dcg(&["scan", "--paths", path, "--format", "json"]);
+        serde_json::from_slice::<serde_json::Value>(&output.stdout)
+            .expect("stdout should remain valid JSON");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let line = stderr
+            .lines()
+            .find(|l| l.starts_with("dcg-scan: "))
+            .expect("stderr should contain the summary line");
+        assert!(line.contains("1 finding (1 error)"), "got: {line}");
+        assert!(line.ends_with("exit=1"), "got: {line}");
+
+        let output = run_dcg(&["scan", "--paths", path, "--format", "json", "--quiet"]);
+        assert!(
+            !String::from_utf8_lossy(&output.stderr).contains("dcg-scan:"),
+            "--quiet should suppress the summary line"
+        );
+    }
 
     #[test]
-    fn test_safe_command_returns_allowed() {
-        let output = run_dcg(&["test", "echo hello"]);
-        let stdout = String::from_utf8_lossy(&output.stdout);
+    fn scan_output_writes_report_to_file() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "git reset --hard").unwrap();
+        file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let report_path = dir.path().join("reports/nested/scan.json");
 
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            path,
+            "--format",
+            "json",
+            "--output",
+            report_path.to_str().unwrap(),
+        ]);
+        assert_eq!(
+            output.status.code(),
+            Some(1),
+            "findings still fail the scan"
+        );
+        assert!(output.stdout.is_empty(), "report should not go to stdout");
         assert!(
-            output.status.success(),
-            "test should succeed for safe command"
+            String::from_utf8_lossy(&output.stderr).contains("dcg-scan: 1 finding"),
+            "summary line should still be printed"
         );
+
+        let stdout_run = run_dcg(&["scan", "--paths", path, "--format", "json"]);
+        let mut from_file: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap();
+        let mut from_stdout: serde_json::Value =
+            serde_json::from_slice(&stdout_run.stdout).unwrap();
+        for json in [&mut from_file, &mut from_stdout] {
+            json["summary"]["elapsed_ms"] = serde_json::Value::Null;
+        }
+        assert_eq!(from_file, from_stdout);
+
+        let output = run_dcg(&["scan", "--paths", path, "--output", "unused.txt"]);
         assert!(
-            stdout.contains("ALLOWED") || stdout.contains("allow"),
-            "should show allowed result"
+            !output.status.success(),
+            "pretty output cannot go to a file"
         );
     }
 
     #[test]
-    fn test_dangerous_command_returns_blocked() {
-        // Use git command since core.git is always enabled
-        let output = run_dcg(&["test", "git reset --hard"]);
-        let stdout = String::from_utf8_lossy(&output.stdout);
+    fn scan_baseline_update_on_pass_prunes_resolved_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("deploy.sh");
+        std::fs::write(&script, "git reset --hard\ngit clean -fd\n").unwrap();
+        let script = script.to_str().unwrap();
+        let baseline = dir.path().join("baseline.json");
+        let baseline_arg = baseline.to_str().unwrap();
+
+        let output = run_dcg(&["scan", "--paths", script, "--format", "json"]);
+        std::fs::write(&baseline, &output.stdout).unwrap();
+        let findings = |path: &std::path::Path| -> Vec<String> {
+            let json: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+            json["findings"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|f| f["rule_id"].as_str().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(findings(&baseline).len(), 2);
+
+        // A new finding fails the run, so the baseline is left alone even
+        // though `git clean -fd` was resolved.
+        std::fs::write(
+            dir.path().join("deploy.sh"),
+            "\ngit reset --hard\nrm -rf /\n",
+        )
+        .unwrap();
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            script,
+            "--baseline",
+            baseline_arg,
+            "--update-baseline-on-pass",
+        ]);
+        assert_eq!(output.status.code(), Some(1), "new finding fails the scan");
+        assert_eq!(findings(&baseline).len(), 2, "failing run must not write");
+
+        // Once the new finding is gone the run passes (the moved `git reset
+        // --hard` is still known) and the resolved entry is pruned.
+        std::fs::write(dir.path().join("deploy.sh"), "\ngit reset --hard\n").unwrap();
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            script,
+            "--baseline",
+            baseline_arg,
+            "--update-baseline-on-pass",
+            "--format",
+            "json",
+        ]);
+        assert!(output.status.success(), "only known findings remain");
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(report["summary"]["findings_total"], 0);
+        assert_eq!(report["summary"]["findings_baselined"], 1);
+        assert!(
+            String::from_utf8_lossy(&output.stderr).contains("removed 1 resolved finding(s)"),
+            "should report the pruning"
+        );
+        assert_eq!(findings(&baseline), ["core.git:reset-hard"]);
+
+        let output = run_dcg(&["scan", "--paths", script, "--update-baseline-on-pass"]);
+        assert!(!output.status.success(), "flag requires --baseline");
+    }
+
+    #[test]
+    fn scan_write_baseline_then_fail_only_on_new_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("deploy.sh");
+        std::fs::write(&script, "git reset --hard\ngit clean -fd\n").unwrap();
+        let script = script.to_str().unwrap();
+        let baseline = dir.path().join("baseline.json");
+        let baseline_arg = baseline.to_str().unwrap();
+
+        let output = run_dcg(&["scan", "--paths", script, "--write-baseline", baseline_arg]);
+        assert_eq!(output.status.code(), Some(1), "writing does not suppress");
+        assert!(
+            String::from_utf8_lossy(&output.stderr).contains("wrote 2 finding(s)"),
+            "should report the write"
+        );
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&baseline).unwrap()).unwrap();
+        assert_eq!(written["findings"].as_array().unwrap().len(), 2);
+        assert!(written["summary"].get("elapsed_ms").is_none());
+
+        // Shifted lines still match; only the new finding is reported.
+        std::fs::write(
+            dir.path().join("deploy.sh"),
+            "set -e\n\ngit reset --hard\ngit clean -fd\n",
+        )
+        .unwrap();
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            script,
+            "--baseline",
+            baseline_arg,
+            "--format",
+            "json",
+        ]);
+        assert!(output.status.success(), "moved findings are still known");
+
+        std::fs::write(
+            dir.path().join("deploy.sh"),
+            "git reset --hard\ngit clean -fd\nrm -rf /\n",
+        )
+        .unwrap();
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            script,
+            "--baseline",
+            baseline_arg,
+            "--format",
+            "json",
+        ]);
+        assert_eq!(output.status.code(), Some(1), "new finding fails the scan");
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(report["summary"]["findings_total"], 1);
+        assert_eq!(report["summary"]["findings_baselined"], 2);
+    }
+
+    #[test]
+    fn scan_print_schema_validates_real_report() {
+        let output = run_dcg(&["scan", "--print-schema"]);
+        let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            schema["$id"],
+            "https://github.com/Dicklesworthstone/destructive_command_guard/docs/json-schema/scan-results-v1.json"
+        );
+        assert_eq!(schema["properties"]["schema_version"]["const"], 1);
+
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "git reset --hard").unwrap();
+        writeln!(file, "# dcg:ignore core.git:clean-force fixture cleanup").unwrap();
+        writeln!(file, "git clean -fd").unwrap();
+        writeln!(file, "rm -rf ./build").unwrap();
+        writeln!(
+            file,
+            "python3 <<'EOF'\nimport shutil\nshutil.rmtree('/srv')\nEOF"
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            file.path().to_str().unwrap(),
+            "--format",
+            "json",
+        ]);
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("scan should produce valid JSON");
+        assert!(
+            json["findings"].as_array().is_some_and(|f| !f.is_empty()),
+            "fixture should produce findings: {json:#}"
+        );
+        assert_matches_printed_schema("scan", &json);
+    }
+
+    #[test]
+    fn scan_compact_json_matches_pretty_json() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "git reset --hard").unwrap();
+        file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        assert_compact_json_matches_pretty(
+            &["scan", "--paths", path, "--format", "json"],
+            &["elapsed_ms"],
+        );
+    }
+
+    #[test]
+    fn scan_dcg_ignore_comment_suppresses_finding() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(
+            file,
+            "git reset --hard # dcg:ignore core.git:reset-hard disposable CI checkout"
+        )
+        .unwrap();
+        file.flush().unwrap();
+        let path = file.path().to_str().unwrap();
+
+        let output = run_dcg(&["scan", "--paths", path, "--format", "json"]);
+        assert!(
+            output.status.success(),
+            "suppressed findings should not fail the scan"
+        );
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["summary"]["findings_total"], 0);
+        assert_eq!(json["summary"]["findings_suppressed"], 1);
+        assert_eq!(
+            json["suppressed"][0]["suppression"]["reason"],
+            "disposable CI checkout"
+        );
+
+        let mut unsuppressed = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(
+            unsuppressed,
+            "git reset --hard # dcg:ignore core.git:reset-hard"
+        )
+        .unwrap();
+        unsuppressed.flush().unwrap();
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            unsuppressed.path().to_str().unwrap(),
+            "--format",
+            "json",
+        ]);
+        assert!(
+            !output.status.success(),
+            "a dcg:ignore comment without a reason must not suppress"
+        );
+    }
+
+    #[test]
+    fn scan_respects_dcgignore_and_reports_excluded_count() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".git")).unwrap();
+        std::fs::create_dir_all(dir.path().join("fixtures")).unwrap();
+        std::fs::write(dir.path().join(".dcgignore"), "fixtures/\n").unwrap();
+        std::fs::write(dir.path().join("fixtures/wipe.sh"), "rm -rf /\n").unwrap();
+        std::fs::write(dir.path().join("build.sh"), "echo ok\n").unwrap();
+
+        let output = Command::new(dcg_binary())
+            .current_dir(dir.path())
+            .args(["scan", "--paths", ".", "--format", "json"])
+            .output()
+            .expect("failed to execute dcg");
+        assert!(
+            output.status.success(),
+            "ignored fixtures should not fail the scan: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["summary"]["files_dcgignored"], 1);
+        assert_eq!(json["summary"]["findings_total"], 0);
+    }
+
+    #[test]
+    fn scan_paths_from_merges_with_paths_and_skips_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".git")).unwrap();
+        std::fs::write(dir.path().join("wipe.sh"), "rm -rf /\n").unwrap();
+        std::fs::write(dir.path().join("reset.sh"), "git reset --hard\n").unwrap();
+        std::fs::write(
+            dir.path().join("changed.txt"),
+            "# computed by another tool\nwipe.sh\n\nmissing.sh\nwipe.sh\n",
+        )
+        .unwrap();
+
+        let output = Command::new(dcg_binary())
+            .current_dir(dir.path())
+            .args([
+                "scan",
+                "--paths",
+                "reset.sh",
+                "--paths-from",
+                "changed.txt",
+                "--format",
+                "json",
+            ])
+            .output()
+            .expect("failed to execute dcg");
+
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["summary"]["files_scanned"], 2);
+        assert_eq!(json["summary"]["files_skipped"], 1);
+        assert_eq!(json["summary"]["skipped"]["unreadable"], 1);
+        let files: Vec<&str> = json["findings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["file"].as_str().unwrap())
+            .collect();
+        assert_eq!(files, ["reset.sh", "wipe.sh"], "deterministic order");
+    }
+
+    #[test]
+    fn scan_allow_suppresses_matching_rule_and_path_only() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".git")).unwrap();
+        std::fs::create_dir_all(dir.path().join("tests/fixtures")).unwrap();
+        std::fs::write(dir.path().join("tests/fixtures/wipe.sh"), "rm -rf /\n").unwrap();
+        std::fs::write(dir.path().join("deploy.sh"), "git reset --hard\n").unwrap();
+
+        let output = Command::new(dcg_binary())
+            .current_dir(dir.path())
+            .args([
+                "scan",
+                "--paths",
+                ".",
+                "--format",
+                "json",
+                "--scan-allow",
+                "core.filesystem:* in tests/fixtures/**",
+            ])
+            .output()
+            .expect("failed to execute dcg");
+
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["summary"]["findings_scan_allowed"], 1);
+        let findings = json["findings"].as_array().unwrap();
+        assert_eq!(findings.len(), 1, "deploy.sh finding must remain");
+        assert_eq!(findings[0]["rule_id"], "core.git:reset-hard");
+    }
+
+    #[test]
+    fn scan_allow_rejects_malformed_entry() {
+        let output = run_dcg(&["scan", "--paths", ".", "--scan-allow", "core.filesystem:*"]);
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("<rule_id glob> in <path glob>"), "{stderr}");
+    }
+
+    #[test]
+    fn scan_progress_stays_off_stdout_and_off_without_tty() {
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..30 {
+            std::fs::write(dir.path().join(format!("s{i}.sh")), "echo ok\n").unwrap();
+        }
+
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            dir.path().to_str().unwrap(),
+            "--format",
+            "json",
+            "--progress",
+        ]);
+
+        assert!(output.status.success());
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["summary"]["files_scanned"], 30);
+        // stderr is a pipe here, so the counter must auto-disable.
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!stderr.contains("Scanning:"), "{stderr}");
+    }
+
+    #[test]
+    fn scan_extractors_limits_scan_to_selected_extractors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("deploy.sh"), "git status\n").unwrap();
+        std::fs::write(dir.path().join("Dockerfile"), "RUN rm -rf /\n").unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let args = |extractors: &str| {
+            run_dcg(&[
+                "scan",
+                "--paths",
+                path,
+                "--extractors",
+                extractors,
+                "--format",
+                "json",
+                "--fail-on",
+                "error",
+            ])
+        };
+
+        let output = args("shell.script");
+        assert!(output.status.success(), "Dockerfile finding not scanned");
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(json["summary"]["files_scanned"], 1);
+
+        let output = args("shell.script,docker.dockerfile");
+        assert!(
+            !output.status.success(),
+            "Dockerfile finding fails the scan"
+        );
+
+        let output = args("dockerfile");
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("unknown extractor `dockerfile`"),
+            "{stderr}"
+        );
+        assert!(stderr.contains("docker.dockerfile"), "{stderr}");
+    }
+
+    #[test]
+    fn scan_list_extractors_shows_ids_and_file_patterns() {
+        let output = run_dcg(&["scan", "--list-extractors"]);
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("shell.script"), "{stdout}");
+        assert!(stdout.contains("Dockerfile, Dockerfile.*"), "{stdout}");
+
+        let output = run_dcg(&["scan", "--list-extractors", "--format", "json"]);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let ids: Vec<&str> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["id"].as_str().unwrap())
+            .collect();
+        assert!(ids.contains(&"docker.dockerfile"), "{ids:?}");
+        assert!(json[0]["file_patterns"].is_string());
+    }
+
+    #[test]
+    fn scan_fix_rewrites_fixable_lines_and_leaves_others() {
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("deploy.sh");
+        std::fs::write(&script, "git reset --hard HEAD~1\nrm -rf ./build\n").unwrap();
+
+        let output = run_dcg(&["scan", "--paths", script.to_str().unwrap(), "--fix"]);
+        assert!(!output.status.success(), "pre-fix findings still fail");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("-git reset --hard HEAD~1"), "{stdout}");
+        assert!(stdout.contains("+git reset --soft HEAD~1"), "{stdout}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("applied 1 fix(es)"), "{stderr}");
+
+        assert_eq!(
+            std::fs::read_to_string(&script).unwrap(),
+            "git reset --soft HEAD~1\nrm -rf ./build\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("deploy.sh.dcg.bak")).unwrap(),
+            "git reset --hard HEAD~1\nrm -rf ./build\n"
+        );
+    }
+
+    #[test]
+    fn scan_codeowners_attaches_owner_to_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("ops")).unwrap();
+        std::fs::write(dir.path().join("CODEOWNERS"), "/ops/ @org/ops\n").unwrap();
+        std::fs::write(dir.path().join("ops/deploy.sh"), "rm -rf /\n").unwrap();
+        std::fs::write(dir.path().join("other.sh"), "rm -rf /\n").unwrap();
+
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            dir.path().join("ops").to_str().unwrap(),
+            dir.path().join("other.sh").to_str().unwrap(),
+            "--codeowners",
+            dir.path().join("CODEOWNERS").to_str().unwrap(),
+            "--format",
+            "json",
+        ]);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let findings = json["findings"].as_array().unwrap();
+        assert_eq!(findings.len(), 2, "{json}");
+        for finding in findings {
+            let file = finding["file"].as_str().unwrap();
+            if file.ends_with("deploy.sh") {
+                assert_eq!(finding["owner"], "@org/ops");
+            } else {
+                assert!(finding["owner"].is_null(), "{finding}");
+            }
+        }
+    }
+
+    #[test]
+    fn scan_print_extracted_dumps_commands_without_evaluating() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("deploy.sh"), "git status\nrm -rf /\n").unwrap();
+        std::fs::write(dir.path().join("README.md"), "rm -rf /\n").unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            path,
+            "--print-extracted",
+            "--format",
+            "json",
+            "--fail-on",
+            "error",
+        ]);
+        assert!(
+            output.status.success(),
+            "extraction dump never fails on findings"
+        );
+
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert!(
+            json.get("findings").is_none(),
+            "no evaluation phase: {json}"
+        );
+        assert_eq!(json["files_scanned"], 1);
+        let commands = json["commands"].as_array().unwrap();
+        let dumped: Vec<(&str, u64, &str)> = commands
+            .iter()
+            .map(|c| {
+                (
+                    c["extractor_id"].as_str().unwrap(),
+                    c["line"].as_u64().unwrap(),
+                    c["command"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            dumped,
+            vec![
+                ("shell.script", 1, "git status"),
+                ("shell.script", 2, "rm -rf /")
+            ]
+        );
+
+        let output = run_dcg(&["scan", "--paths", path, "--print-extracted"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains("deploy.sh:2\t[shell.script]\trm -rf /"),
+            "{stdout}"
+        );
+    }
+
+    #[test]
+    fn scan_empty_directory_succeeds() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let output = run_dcg(&["scan", "--paths", dir.path().to_str().unwrap()]);
+
+        assert!(output.status.success(), "scan on empty dir should succeed");
+    }
+
+    #[test]
+    fn scan_findings_include_file_and_line() {
+        let mut file = tempfile::Builder::new().suffix(".sh").tempfile().unwrap();
+        writeln!(file, "echo safe").unwrap();
+        // Use git command since core.git is always enabled
+        writeln!(file, "git reset --hard").unwrap();
+        file.flush().unwrap();
+
+        let output = run_dcg(&[
+            "scan",
+            "--paths",
+            file.path().to_str().unwrap(),
+            "--format",
+            "json",
+        ]);
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        let findings = json["findings"].as_array().unwrap();
+
+        assert!(!findings.is_empty(), "should have findings");
+        let finding = &findings[0];
+        assert!(finding["file"].is_string(), "finding should have file");
+        assert!(finding["line"].is_number(), "finding should have line");
+        assert!(
+            finding["rule_id"].is_string(),
+            "finding should have rule_id"
+        );
+        assert_eq!(finding["reason_code"], "DestroysUncommittedChanges");
+    }
+}
+
+// ============================================================================
+// DCG TEST (single command evaluation) Tests
+// ============================================================================
+
+mod test_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_command_returns_allowed() {
+        let output = run_dcg(&["test", "echo hello"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
 
-        // Note: test command currently returns exit code 0 even for blocked commands
-        // This tests the output content instead
+        assert!(
+            output.status.success(),
+            "test should succeed for safe command"
+        );
+        assert!(
+            stdout.contains("ALLOWED") || stdout.contains("allow"),
+            "should show allowed result"
+        );
+    }
+
+    #[test]
+    fn test_dangerous_command_returns_blocked() {
+        // Use git command since core.git is always enabled
+        let output = run_dcg(&["test", "git reset --hard"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // Note: test command currently returns exit code 0 even for blocked commands
+        // This tests the output content instead
         assert!(
             stdout.contains("BLOCKED") || stdout.contains("blocked"),
             "should show blocked result"
@@ -1173,16 +2316,189 @@ mod test_command_tests {
     }
 
     #[test]
-    fn test_output_includes_rule_info() {
-        // Use git command since core.git is always enabled
-        let output = run_dcg(&["test", "git reset --hard"]);
+    fn test_output_includes_rule_info() {
+        // Use git command since core.git is always enabled
+        let output = run_dcg(&["test", "git reset --hard"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // The output should include pattern information
+        assert!(
+            stdout.contains("hard-reset") || stdout.contains("Pattern"),
+            "should include pattern info"
+        );
+    }
+
+    #[test]
+    fn test_only_pack_ignores_configured_packs_and_overrides() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            "[packs]\nenabled = [\"containers.docker\"]\n\n\
+             [overrides]\nallow = [\"git reset --hard\"]\n",
+        )
+        .unwrap();
+        let config = config_path.to_str().unwrap();
+        let decision = |args: &[&str]| {
+            let mut full = vec!["test", "--config", config, "--format", "json"];
+            full.extend_from_slice(args);
+            let output = run_dcg(&full);
+            let json: serde_json::Value =
+                serde_json::from_slice(&output.stdout).expect("valid JSON");
+            json["decision"].as_str().unwrap().to_string()
+        };
+
+        // Ambient config: the override allows, the configured docker pack blocks.
+        assert_eq!(decision(&["git reset --hard"]), "allow");
+        assert_eq!(decision(&["docker system prune -af"]), "deny");
+
+        // Only the named pack applies; config overrides and packs are ignored.
+        assert_eq!(
+            decision(&["--only-pack", "core.git", "git reset --hard"]),
+            "deny"
+        );
+        assert_eq!(
+            decision(&["--only-pack", "core.git", "docker system prune -af"]),
+            "allow"
+        );
+
+        let output = run_dcg(&["test", "--only-pack", "no.such_pack", "ls"]);
+        assert!(!output.status.success(), "unknown packs are rejected");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("no.such_pack"));
+    }
+
+    #[test]
+    fn test_no_allowlist_skips_allowlist_overrides() {
+        let dir = tempfile::tempdir().unwrap();
+        let allowlist_path = dir.path().join("allowlist.toml");
+        std::fs::write(
+            &allowlist_path,
+            "[[allow]]\nrule = \"core.git:reset-hard\"\nreason = \"test fixture\"\n",
+        )
+        .unwrap();
+        let decision = |extra: &[&str]| {
+            let mut args = vec!["test", "--format", "json"];
+            args.extend_from_slice(extra);
+            args.push("git reset --hard");
+            let output = Command::new(dcg_binary())
+                .args(&args)
+                .env("DCG_ALLOWLIST_SYSTEM_PATH", &allowlist_path)
+                .output()
+                .expect("failed to execute dcg");
+            let json: serde_json::Value =
+                serde_json::from_slice(&output.stdout).expect("valid JSON");
+            json["decision"].as_str().unwrap().to_string()
+        };
+
+        assert_eq!(decision(&[]), "allow", "allowlisted by default");
+        assert_eq!(decision(&["--no-allowlist"]), "deny");
+        assert_eq!(decision(&["--only-pack", "core.git"]), "deny");
+    }
+
+    #[test]
+    fn test_min_severity_previews_without_changing_decision() {
+        // checkout-broad-pathspec is Medium: below a High threshold.
+        let output = run_dcg(&[
+            "test",
+            "--format",
+            "json",
+            "--min-severity",
+            "high",
+            "git checkout .",
+        ]);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+        assert_eq!(json["decision"], "deny", "real decision is unchanged");
+        assert_eq!(json["severity"], "medium");
+        assert_eq!(json["min_severity"], "high");
+        assert_eq!(json["threshold_decision"], "allow");
+        assert!(
+            !output.status.success(),
+            "exit code follows the real decision"
+        );
+
+        // reset-hard is Critical: still denied at the threshold.
+        let output = run_dcg(&[
+            "test",
+            "--format",
+            "json",
+            "--min-severity",
+            "high",
+            "git reset --hard",
+        ]);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+        assert_eq!(json["threshold_decision"], "deny");
+
+        let output = run_dcg(&["explain", "--min-severity", "high", "git checkout ."]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains("At --min-severity high: ALLOW")
+                && stdout.contains("actual decision above is unchanged"),
+            "explain should annotate the preview\n{stdout}"
+        );
+    }
+
+    fn run_test_stdin(input: &str, extra_args: &[&str]) -> std::process::Output {
+        let mut child = Command::new(dcg_binary())
+            .args(["test", "--stdin"])
+            .args(extra_args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn dcg test --stdin");
+        child
+            .stdin
+            .as_mut()
+            .expect("failed to open stdin")
+            .write_all(input.as_bytes())
+            .expect("failed to write");
+        child.wait_with_output().expect("failed to wait for dcg")
+    }
+
+    #[test]
+    fn test_stdin_reports_each_line() {
+        let input = "git status\n\n# a comment\ngit reset --hard\nls -la\n";
+        let output = run_test_stdin(input, &[]);
         let stdout = String::from_utf8_lossy(&output.stdout);
 
-        // The output should include pattern information
-        assert!(
-            stdout.contains("hard-reset") || stdout.contains("Pattern"),
-            "should include pattern info"
+        assert!(output.status.success(), "no --exit-code: exit 0");
+        assert_eq!(
+            stdout.lines().collect::<Vec<_>>(),
+            ["ALLOW", "DENY core.git:reset-hard", "ALLOW"],
+            "blank lines and comments are skipped\n{stdout}"
         );
+
+        let output = run_test_stdin(input, &["--exit-code"]);
+        assert_eq!(output.status.code(), Some(1), "a denied line fails");
+        let output = run_test_stdin("git status\nls\n", &["--exit-code"]);
+        assert!(output.status.success(), "all allowed: exit 0");
+    }
+
+    #[test]
+    fn test_stdin_json_lines() {
+        let output = run_test_stdin("git status\ngit reset --hard\n", &["--format", "json"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let lines: Vec<serde_json::Value> = stdout
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("valid JSON line"))
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["line"], 1);
+        assert_eq!(lines[0]["decision"], "allow");
+        assert!(lines[0].get("rule_id").is_none());
+        assert_eq!(lines[1]["line"], 2);
+        assert_eq!(lines[1]["command"], "git reset --hard");
+        assert_eq!(lines[1]["decision"], "deny");
+        assert_eq!(lines[1]["rule_id"], "core.git:reset-hard");
+    }
+
+    #[test]
+    fn test_stdin_conflicts_with_positional_command() {
+        let output = run_dcg(&["test", "--stdin", "git status"]);
+        assert!(!output.status.success());
+        let output = run_dcg(&["test", "--exit-code", "git status"]);
+        assert!(!output.status.success(), "--exit-code requires --stdin");
     }
 }
 
@@ -1258,6 +2574,123 @@ mod config_tests {
         );
     }
 
+    #[test]
+    fn config_lists_included_files_and_applies_them() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let home_dir = temp.path().join("home");
+        let xdg_config_dir = temp.path().join("xdg_config");
+        std::fs::create_dir_all(&home_dir).expect("HOME dir");
+        std::fs::create_dir_all(&xdg_config_dir).expect("XDG_CONFIG_HOME dir");
+        std::fs::create_dir_all(temp.path().join("policies")).expect("policies dir");
+        std::fs::write(
+            temp.path().join("policies/base.toml"),
+            "[packs]\nenabled = [\"containers.docker\"]\n",
+        )
+        .expect("write base");
+
+        let cfg_path = temp.path().join("config.toml");
+        std::fs::write(
+            &cfg_path,
+            "include = [\"policies/base.toml\"]\n\n[packs]\nenabled = [\"database.postgresql\"]\n",
+        )
+        .expect("write config");
+
+        let output = Command::new(dcg_binary())
+            .env_clear()
+            .env("HOME", &home_dir)
+            .env("XDG_CONFIG_HOME", &xdg_config_dir)
+            .env("DCG_CONFIG", &cfg_path)
+            .current_dir(temp.path())
+            .arg("config")
+            .output()
+            .expect("run dcg config");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "stdout:\n{stdout}");
+
+        let include_line = stdout
+            .lines()
+            .position(|line| line.contains("include:") && line.contains("base.toml"))
+            .expect("included file listed in sources");
+        let config_line = stdout
+            .lines()
+            .position(|line| line.contains("DCG_CONFIG:"))
+            .expect("DCG_CONFIG listed in sources");
+        assert!(include_line < config_line, "includes merge first\n{stdout}");
+        assert!(stdout.contains("containers.docker"), "{stdout}");
+        assert!(stdout.contains("database.postgresql"), "{stdout}");
+    }
+
+    #[test]
+    fn policy_export_includes_overrides_and_allowlist() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let home_dir = temp.path().join("home");
+        let xdg_config_dir = temp.path().join("xdg_config");
+        std::fs::create_dir_all(&home_dir).expect("HOME dir");
+        std::fs::create_dir_all(&xdg_config_dir).expect("XDG_CONFIG_HOME dir");
+        std::fs::create_dir_all(temp.path().join(".git")).expect(".git dir");
+        std::fs::create_dir_all(temp.path().join(".dcg")).expect(".dcg dir");
+        std::fs::write(
+            temp.path().join(".dcg/allowlist.toml"),
+            "[[allow]]\nrule = \"core.git:clean-force\"\nreason = \"build dir cleanup\"\n",
+        )
+        .expect("write allowlist");
+
+        let cfg_path = temp.path().join("policy.toml");
+        std::fs::write(
+            &cfg_path,
+            "[packs]\nenabled = [\"containers.docker\"]\n\n[policy.rules]\n\"core.git:reset-hard\" = \"warn\"\n",
+        )
+        .expect("write config");
+
+        let export = |format: &str| {
+            let output = Command::new(dcg_binary())
+                .env_clear()
+                .env("HOME", &home_dir)
+                .env("XDG_CONFIG_HOME", &xdg_config_dir)
+                .env("DCG_CONFIG", &cfg_path)
+                .env("DCG_ALLOWLIST_SYSTEM_PATH", "")
+                .current_dir(temp.path())
+                .args([
+                    "policy",
+                    "export",
+                    "--agent",
+                    "claude-code",
+                    "--format",
+                    format,
+                ])
+                .output()
+                .expect("run dcg policy export");
+            assert!(output.status.success(), "dcg policy export should succeed");
+            String::from_utf8(output.stdout).expect("utf-8 output")
+        };
+
+        let toml_text = export("toml");
+        assert_eq!(toml_text, export("toml"), "export should be deterministic");
+        let from_toml: serde_json::Value =
+            serde_json::to_value(toml::from_str::<toml::Value>(&toml_text).expect("valid TOML"))
+                .unwrap();
+        let from_json: serde_json::Value =
+            serde_json::from_str(&export("json")).expect("valid JSON");
+
+        for json in [&from_toml, &from_json] {
+            assert_eq!(json["profile"]["agent"], "claude-code");
+            assert!(
+                json["packs"]["enabled"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&serde_json::json!("containers.docker"))
+            );
+            let rules = json["rules"].as_array().unwrap();
+            let reset_hard = rules
+                .iter()
+                .find(|rule| rule["rule_id"] == "core.git:reset-hard")
+                .expect("reset-hard rule listed");
+            assert_eq!(reset_hard["mode"], "warn");
+            assert_eq!(json["allowlist"][0]["layer"], "project");
+            assert_eq!(json["allowlist"][0]["value"], "core.git:clean-force");
+        }
+    }
+
     #[test]
     fn doctor_reports_missing_dcg_config_override() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1331,6 +2764,61 @@ mod config_tests {
         );
     }
 
+    #[test]
+    fn doctor_warns_about_allowlist_entries_expiring_soon() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let (home_dir, xdg_config_dir, bin_dir) = setup_doctor_env(&temp);
+
+        let soon = (chrono::Utc::now() + chrono::TimeDelta::days(3)).to_rfc3339();
+        let later = (chrono::Utc::now() + chrono::TimeDelta::days(60)).to_rfc3339();
+        std::fs::create_dir_all(temp.path().join(".dcg")).expect(".dcg dir");
+        std::fs::write(
+            temp.path().join(".dcg/allowlist.toml"),
+            format!(
+                r#"
+[[allow]]
+rule = "core.git:reset-hard"
+reason = "release cleanup"
+expires_at = "{soon}"
+
+[[allow]]
+rule = "core.git:clean-force"
+reason = "build sandbox"
+expires_at = "{later}"
+"#
+            ),
+        )
+        .expect("write allowlist");
+
+        let output = Command::new(dcg_binary())
+            .env_clear()
+            .env("HOME", &home_dir)
+            .env("XDG_CONFIG_HOME", &xdg_config_dir)
+            .env("PATH", &bin_dir)
+            .env("NO_COLOR", "1")
+            .env("DCG_ALLOWLIST_SYSTEM_PATH", "")
+            .current_dir(temp.path())
+            .arg("doctor")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .expect("run dcg doctor");
+
+        let combined = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(
+            combined.contains("entry 1 expires soon"),
+            "expected expiring-soon warning\noutput:\n{combined}"
+        );
+        assert!(
+            !combined.contains("entry 2 expires soon"),
+            "entry expiring in 60 days should not be flagged\noutput:\n{combined}"
+        );
+    }
+
     #[test]
     fn doctor_fix_installs_hook_and_config() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1421,6 +2909,84 @@ mod config_tests {
             "expected binary_path check in JSON output"
         );
     }
+
+    #[test]
+    fn doctor_json_covers_health_checks() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let (home_dir, xdg_config_dir, bin_dir) = setup_doctor_env(&temp);
+
+        let output = Command::new(dcg_binary())
+            .env_clear()
+            .env("HOME", &home_dir)
+            .env("XDG_CONFIG_HOME", &xdg_config_dir)
+            .env("PATH", &bin_dir)
+            .env("DCG_ALLOWLIST_SYSTEM_PATH", "")
+            .current_dir(temp.path())
+            .args(["doctor", "--format", "json"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .expect("run dcg doctor --format json");
+
+        assert!(output.status.success(), "healthy doctor run should exit 0");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&stdout).expect("doctor JSON output should parse");
+        let checks = parsed["checks"].as_array().expect("checks array");
+        for id in [
+            "config_sources",
+            "dcg_config",
+            "allowlists",
+            "history_db",
+            "git",
+            "pack_catalog",
+        ] {
+            let check = checks
+                .iter()
+                .find(|c| c["id"] == id)
+                .unwrap_or_else(|| panic!("expected {id} check in JSON output\n{stdout}"));
+            assert!(check["status"].is_string(), "{id} should have a status");
+            assert!(check["message"].is_string(), "{id} should have a message");
+        }
+        let pack_catalog = checks.iter().find(|c| c["id"] == "pack_catalog").unwrap();
+        assert_eq!(pack_catalog["status"], "ok");
+    }
+
+    #[test]
+    fn doctor_json_exits_nonzero_when_a_check_fails() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let (home_dir, xdg_config_dir, bin_dir) = setup_doctor_env(&temp);
+        let missing = temp.path().join("missing_config.toml");
+
+        let output = Command::new(dcg_binary())
+            .env_clear()
+            .env("HOME", &home_dir)
+            .env("XDG_CONFIG_HOME", &xdg_config_dir)
+            .env("PATH", &bin_dir)
+            .env("DCG_ALLOWLIST_SYSTEM_PATH", "")
+            .env("DCG_CONFIG", &missing)
+            .current_dir(temp.path())
+            .args(["doctor", "--format", "json"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .expect("run dcg doctor --format json");
+
+        assert!(
+            !output.status.success(),
+            "doctor should exit non-zero when a check fails"
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&stdout).expect("doctor JSON output should parse");
+        assert_eq!(parsed["ok"], false);
+        let checks = parsed["checks"].as_array().expect("checks array");
+        let dcg_config = checks
+            .iter()
+            .find(|c| c["id"] == "dcg_config")
+            .expect("dcg_config check");
+        assert_eq!(dcg_config["status"], "error");
+    }
 }
 
 // ============================================================================
@@ -1443,6 +3009,29 @@ mod packs_tests {
         );
     }
 
+    #[test]
+    fn packs_tag_filter_lists_only_tagged_packs() {
+        let output = run_dcg(&["packs", "--tag", "aws", "--format", "json"]);
+        assert!(output.status.success(), "packs --tag should succeed");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+        let packs = json["packs"].as_array().expect("packs array");
+        assert!(
+            packs.iter().any(|p| p["id"] == "cloud.aws"),
+            "cloud.aws should be tagged aws"
+        );
+        assert!(
+            !packs.iter().any(|p| p["id"] == "core.git"),
+            "core.git should be filtered out"
+        );
+        for pack in packs {
+            let tags = pack["tags"].as_array().expect("tags array");
+            assert!(tags.iter().any(|t| t == "aws"), "unexpected pack: {pack}");
+            assert!(pack["docs_url"].is_string(), "docs_url should be present");
+        }
+    }
+
     #[test]
     fn pack_show_displays_pack_info() {
         let output = run_dcg(&["pack", "info", "core.git"]);
@@ -1454,6 +3043,29 @@ mod packs_tests {
             "should show git pack info"
         );
     }
+
+    #[test]
+    fn version_json_reports_build_and_catalog_info() {
+        let output = run_dcg(&["version", "--json"]);
+        assert!(output.status.success(), "version --json should succeed");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        assert!(
+            json.get("git_sha").is_some(),
+            "git_sha key should be present"
+        );
+        assert!(
+            json.get("build_date").is_some(),
+            "build_date key should be present"
+        );
+        assert_eq!(json["schema_versions"]["scan"], 1);
+        assert!(json["schema_versions"]["telemetry"].is_u64());
+        assert!(json["schema_versions"]["explain"].is_u64());
+        assert!(json["pack_count"].as_u64().unwrap_or(0) > 0);
+        assert!(json["rule_count"].as_u64().unwrap_or(0) > 0);
+    }
 }
 
 // ============================================================================
@@ -1810,6 +3422,96 @@ block = [
         }
     }
 
+    #[test]
+    fn hook_mode_warn_rule_allows_with_advisory() {
+        let temp = tempfile::tempdir().expect("failed to create temp dir");
+        let cfg_path = temp.path().join("policy.toml");
+        std::fs::write(
+            &cfg_path,
+            "[policy.rules]\n\"core.git:reset-hard\" = \"warn\"\n",
+        )
+        .expect("write config");
+
+        let result = run_dcg_hook_with_env(
+            "git reset --hard HEAD",
+            &[("DCG_CONFIG", cfg_path.as_os_str())],
+        );
+        let stdout = result.stdout_str();
+        let stderr = result.stderr_str();
+        assert!(result.output.status.success(), "stderr:\n{stderr}");
+
+        let json: serde_json::Value =
+            serde_json::from_str(stdout.trim()).expect("warn should emit hook JSON");
+        let output = &json["hookSpecificOutput"];
+        assert!(
+            output.get("permissionDecision").is_none(),
+            "warn mode must not emit a permission decision\nstdout:\n{stdout}"
+        );
+        assert_eq!(output["decisionMode"], "warn");
+        assert_eq!(output["ruleId"], "core.git:reset-hard");
+        assert_eq!(output["severity"], "critical");
+        assert!(output.get("allowOnceCode").is_none());
+        assert!(
+            output["permissionDecisionReason"]
+                .as_str()
+                .is_some_and(|reason| reason.contains("allowed by policy")),
+            "stdout:\n{stdout}"
+        );
+        assert!(
+            stderr.contains("dcg WARNING (allowed by policy)"),
+            "stderr:\n{stderr}"
+        );
+
+        // Without the policy override the same command is denied, with no decisionMode.
+        let denied = run_dcg_hook("git reset --hard HEAD");
+        let json: serde_json::Value =
+            serde_json::from_str(denied.stdout_str().trim()).expect("deny JSON");
+        assert_eq!(json["hookSpecificOutput"]["permissionDecision"], "deny");
+        assert!(json["hookSpecificOutput"].get("decisionMode").is_none());
+    }
+
+    #[test]
+    fn hook_mode_review_queue_records_denial() {
+        let temp = tempfile::tempdir().expect("failed to create temp dir");
+        let queue_path = temp.path().join("review_queue.jsonl");
+        let cfg_path = temp.path().join("review.toml");
+        std::fs::write(
+            &cfg_path,
+            format!(
+                "[review_queue]\nenabled = true\npath = {:?}\n",
+                queue_path.to_string_lossy()
+            ),
+        )
+        .expect("write config");
+
+        let result = run_dcg_hook_with_env(
+            "git reset --hard HEAD",
+            &[("DCG_CONFIG", cfg_path.as_os_str())],
+        );
+        let stdout = result.stdout_str();
+        let json: serde_json::Value =
+            serde_json::from_str(stdout.trim()).expect("deny should emit hook JSON");
+        let output = &json["hookSpecificOutput"];
+        assert_eq!(output["permissionDecision"], "deny", "stdout:\n{stdout}");
+        let id = output["reviewQueueId"]
+            .as_str()
+            .expect("denial should carry reviewQueueId");
+        assert!(
+            output["permissionDecisionReason"]
+                .as_str()
+                .is_some_and(|reason| reason.contains("dcg review")),
+            "stdout:\n{stdout}"
+        );
+
+        let queue = std::fs::read_to_string(&queue_path).expect("queue file written");
+        let entry: serde_json::Value =
+            serde_json::from_str(queue.lines().next().expect("one entry")).expect("entry JSON");
+        assert_eq!(entry["id"], id);
+        assert_eq!(entry["command"], "git reset --hard HEAD");
+        assert_eq!(entry["rule_id"], "core.git:reset-hard");
+        assert_eq!(entry["status"], "pending");
+    }
+
     #[test]
     fn hook_mode_command_substitution_and_backticks_are_blocked() {
         let deny_cases = [
@@ -3983,4 +5685,87 @@ mod stats_rules_tests {
             );
         }
     }
+
+    // -------------------------------------------------------------------------
+    // History activity summary tests (stats --history)
+    // -------------------------------------------------------------------------
+
+    #[test]
+    fn stats_history_json_summarizes_window() {
+        let env = StatsRulesEnv::new();
+        env.seed_rule_metrics_data();
+
+        let output = env.run(&["stats", "--history", "--since", "1d", "--format", "json"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            output.status.success(),
+            "stats --history should succeed\nstdout:\n{stdout}\nstderr:\n{stderr}"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+        assert_eq!(json["total_commands"], 12);
+        assert_eq!(json["outcomes"]["denied"], 9);
+        assert_eq!(json["outcomes"]["bypassed"], 3);
+
+        let top = json["top_blocked_rules"].as_array().unwrap();
+        assert_eq!(top[0]["rule_id"], "core.filesystem:rm-rf");
+        assert_eq!(top[0]["denied"], 4);
+        assert_eq!(top[1]["rule_id"], "core.git:reset-hard");
+        assert_eq!(top[1]["hits"], 5);
+
+        assert_eq!(json["agents"][0]["name"], "claude_code");
+        assert_eq!(json["agents"][0]["count"], 12);
+    }
+
+    #[test]
+    fn stats_history_pretty_lists_top_blocked_rules() {
+        let env = StatsRulesEnv::new();
+        env.seed_rule_metrics_data();
+
+        let output = env.run(&["stats", "--history"]);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "stdout:\n{stdout}");
+        assert!(
+            stdout.contains("Top blocked rules"),
+            "should show top blocked rules\nstdout:\n{stdout}"
+        );
+        assert!(
+            stdout.contains("core.filesystem:rm-rf"),
+            "stdout:\n{stdout}"
+        );
+        assert!(stdout.contains("claude_code"), "stdout:\n{stdout}");
+    }
+
+    #[test]
+    fn stats_history_reports_disabled_history() {
+        let env = StatsRulesEnv::new();
+
+        let output = Command::new(dcg_binary())
+            .env("DCG_HISTORY_DB", &env.db_path)
+            .env("DCG_HISTORY_DISABLED", "1")
+            .current_dir(env.temp.path())
+            .args(["stats", "--history"])
+            .output()
+            .expect("run dcg");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "stdout:\n{stdout}");
+        assert!(
+            stdout.contains("History is disabled"),
+            "should explain that history is disabled\nstdout:\n{stdout}"
+        );
+    }
+
+    #[test]
+    fn stats_since_requires_history() {
+        let env = StatsRulesEnv::new();
+
+        let output = env.run(&["stats", "--since", "7d"]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success(), "--since alone should fail");
+        assert!(
+            stderr.contains("--since requires --history"),
+            "stderr:\n{stderr}"
+        );
+    }
 }
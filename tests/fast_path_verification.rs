@@ -15,6 +15,8 @@ mod tests {
             name: "Test Lazy Pack",
             description: "Verifies lazy instantiation",
             keywords: &["lazy_trigger"],
+            tags: &[],
+            docs_url: None,
             safe_patterns: vec![],
             destructive_patterns: vec![],
             keyword_matcher: None,
@@ -223,7 +223,7 @@ fn golden_rule_id_verification() {
     assert_denies_with_rule("git reset --hard", "core.git:reset-hard");
     assert_denies_with_rule("git reset --hard HEAD~1", "core.git:reset-hard");
     assert_denies_with_rule("git clean -fd", "core.git:clean-force");
-    assert_denies_with_rule("git clean -fdx", "core.git:clean-force");
+    assert_denies_with_rule("git clean -fdx", "core.git:clean-force-ignored");
     assert_denies_with_rule("git push --force", "core.git:push-force-long");
     assert_denies_with_rule("git push -f origin main", "core.git:push-force-short");
     assert_denies_with_rule("git checkout -- .", "core.git:checkout-discard");
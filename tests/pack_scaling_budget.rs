@@ -0,0 +1,130 @@
+//! Latency regression guard for the full pack catalog.
+//!
+//! Enables every pack in `REGISTRY` and checks that evaluating a safe and a
+//! dangerous command stays within a generous per-command budget. Because it
+//! iterates the registry, newly added packs are covered automatically; a pack
+//! with a pathological keyword list or regex fails this test instead of
+//! silently eating into the hook-mode latency budget.
+//!
+//! The budget is an order of magnitude above typical timings so it only trips
+//! on real regressions, not on slow CI hardware. Debug builds get extra
+//! headroom since `cargo test` runs unoptimized.
+//!
+//! # Running
+//!
+//! ```bash
+//! cargo test --test pack_scaling_budget
+//! ```
+
+use std::time::{Duration, Instant};
+
+use destructive_command_guard as dcg;
+use destructive_command_guard::packs::REGISTRY;
+
+/// Per-command budget for optimized builds.
+const RELEASE_BUDGET: Duration = Duration::from_millis(5);
+
+/// Unoptimized builds run regex matching much slower.
+const DEBUG_BUDGET_MULTIPLIER: u32 = 10;
+
+/// Timed evaluations per command; the median is compared to the budget.
+const ITERATIONS: usize = 25;
+
+/// Coverage instrumentation skews timings too much to be meaningful.
+fn is_coverage_build() -> bool {
+    std::env::var("CARGO_LLVM_COV").is_ok() || std::env::var("LLVM_PROFILE_FILE").is_ok()
+}
+
+fn budget() -> Duration {
+    if cfg!(debug_assertions) {
+        RELEASE_BUDGET * DEBUG_BUDGET_MULTIPLIER
+    } else {
+        RELEASE_BUDGET
+    }
+}
+
+fn all_packs_config() -> dcg::Config {
+    let mut config = dcg::Config::default();
+    config.packs.enabled = REGISTRY
+        .all_pack_ids()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    config
+}
+
+/// Median wall-clock time of `ITERATIONS` evaluations, after one warm-up
+/// evaluation (which pays for lazy pack construction and regex compilation).
+fn median_eval_time(command: &str, config: &dcg::Config) -> (Duration, dcg::EvaluationResult) {
+    let enabled_keywords = REGISTRY.collect_enabled_keywords(&config.enabled_pack_ids());
+    let compiled_overrides = config.overrides.compile();
+    let allowlists = dcg::LayeredAllowlist::default();
+
+    let result = dcg::evaluate_command(
+        command,
+        config,
+        &enabled_keywords,
+        &compiled_overrides,
+        &allowlists,
+    );
+
+    let mut timings: Vec<Duration> = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            let _ = std::hint::black_box(dcg::evaluate_command(
+                command,
+                config,
+                &enabled_keywords,
+                &compiled_overrides,
+                &allowlists,
+            ));
+            start.elapsed()
+        })
+        .collect();
+    timings.sort();
+    (timings[ITERATIONS / 2], result)
+}
+
+#[test]
+fn all_packs_are_enabled_for_the_guard() {
+    let config = all_packs_config();
+    let expanded = REGISTRY.expand_enabled(&config.enabled_pack_ids());
+    for id in REGISTRY.all_pack_ids() {
+        assert!(expanded.contains(id), "pack {id} not enabled");
+    }
+}
+
+#[test]
+fn safe_command_with_all_packs_stays_within_budget() {
+    if is_coverage_build() {
+        return;
+    }
+    let config = all_packs_config();
+    let (median, result) = median_eval_time("git status", &config);
+
+    assert!(result.is_allowed(), "git status should be allowed");
+    assert!(
+        median <= budget(),
+        "evaluating `git status` with all {} packs took {median:?} (budget {:?})",
+        REGISTRY.all_pack_ids().len(),
+        budget()
+    );
+}
+
+#[test]
+fn dangerous_command_with_all_packs_stays_within_budget() {
+    if is_coverage_build() {
+        return;
+    }
+    let config = all_packs_config();
+    let command = "git reset --hard HEAD~1 && rm -rf ./build";
+    let (median, result) = median_eval_time(command, &config);
+
+    assert!(result.is_denied(), "{command} should be denied");
+    assert!(
+        median <= budget(),
+        "evaluating `{command}` with all {} packs took {median:?} (budget {:?})",
+        REGISTRY.all_pack_ids().len(),
+        budget()
+    );
+}
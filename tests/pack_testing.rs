@@ -0,0 +1,73 @@
+//! Exercises the public `pack_testing` module the way an external pack author would.
+
+use std::io::Write;
+
+use destructive_command_guard::pack_testing::*;
+use destructive_command_guard::packs::external::parse_pack_string;
+
+const DEPLOY_PACK: &str = r"
+schema_version: 1
+id: mycompany.deploy
+name: MyCompany Deployment Policies
+version: 1.0.0
+description: Prevents accidental production deployments
+keywords:
+  - deploy
+destructive_patterns:
+  - name: prod-direct
+    pattern: deploy\s+--env\s*=?\s*prod
+    severity: critical
+    description: Direct production deployment
+safe_patterns:
+  - name: staging-deploy
+    pattern: deploy\s+--env\s*=?\s*(staging|dev)
+";
+
+#[test]
+fn external_pack_passes_structural_checks() {
+    let pack = load_pack_str(DEPLOY_PACK);
+    validate_pack(&pack);
+    assert_patterns_compile(&pack);
+    assert_unique_pattern_names(&pack);
+}
+
+#[test]
+fn external_pack_commands_map_to_rule_ids() {
+    let pack = load_pack_str(DEPLOY_PACK);
+    assert_blocks_with_rule_id(&pack, "deploy --env prod", "mycompany.deploy:prod-direct");
+    assert_blocks_with_pattern(&pack, "deploy --env=prod", "prod-direct");
+    assert_safe_pattern_matches(&pack, "deploy --env staging");
+    assert_allows(&pack, "deploy --env dev");
+    assert_no_match(&pack, "git status");
+}
+
+#[test]
+fn load_pack_file_reads_yaml_from_disk() {
+    let mut file = tempfile::NamedTempFile::new().expect("tempfile");
+    file.write_all(DEPLOY_PACK.as_bytes()).expect("write pack");
+
+    let pack = load_pack_file(file.path());
+    assert_eq!(pack.id, "mycompany.deploy");
+}
+
+#[test]
+#[should_panic(expected = "unexpected rule ID")]
+fn rule_id_mismatch_panics() {
+    let pack = load_pack_str(DEPLOY_PACK);
+    assert_blocks_with_rule_id(&pack, "deploy --env prod", "mycompany.deploy:other");
+}
+
+#[test]
+#[should_panic(expected = "does not compile")]
+fn broken_regex_fails_compile_check() {
+    // Pack files reject invalid regexes at parse time, so break one afterwards.
+    let mut external = parse_pack_string(DEPLOY_PACK).expect("valid pack");
+    external.destructive_patterns[0].pattern = "deploy (prod".to_string();
+    assert_patterns_compile(&external.into_pack());
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse pack YAML")]
+fn invalid_pack_yaml_panics_with_error() {
+    let _ = load_pack_str("id: not-a-valid-id\nname: Broken\n");
+}
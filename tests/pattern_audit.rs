@@ -49,6 +49,7 @@ fn test_audit_backtracking_requirements() {
                 "checkout-ref-discard",
                 "restore-worktree",
                 "push-force-long",
+                "clean-force-ignored",
             ]),
         ),
         (
@@ -69,12 +70,66 @@ fn test_audit_backtracking_requirements() {
                 "gh-actions-api-delete-variables",
             ]),
         ),
+        (
+            "cloud.azure",
+            HashSet::from([
+                "az-show",
+                "az-list",
+                "az-account",
+                "az-configure",
+                "az-login",
+                "az-version",
+                "az-help",
+                "az-what-if",
+            ]),
+        ),
+        (
+            "cloud.gcp",
+            HashSet::from([
+                // Safe patterns
+                "gcloud-describe",
+                "gcloud-list",
+                "gsutil-ls",
+                "gsutil-cp",
+                "gcloud-config",
+                "gcloud-auth",
+                "gcloud-info",
+                // Destructive patterns
+                "compute-delete",
+                "disk-delete",
+                "sql-delete",
+                "gsutil-rm-recursive",
+                "gsutil-rb",
+                "storage-rm-recursive",
+                "storage-buckets-delete",
+                "gke-delete",
+                "project-delete",
+                "functions-delete",
+                "pubsub-delete",
+                "firestore-delete",
+                "container-images-delete",
+                "artifacts-docker-images-delete",
+                "artifacts-repositories-delete",
+            ]),
+        ),
         (
             "containers.compose",
             HashSet::from(["compose-down-no-volumes"]),
         ),
         ("dns.generic", HashSet::from(["dns-dig-safe"])),
-        ("database.mongodb", HashSet::from(["mongodump-no-drop"])),
+        (
+            "database.mongodb",
+            HashSet::from([
+                "mongo-find",
+                "mongo-count",
+                "mongo-aggregate",
+                "mongo-list-collections",
+                "mongo-stats",
+                "mongodump-no-drop",
+                "mongo-explain",
+            ]),
+        ),
+        ("database.mysql", HashSet::from(["mysqldump-no-drop"])),
         ("database.postgresql", HashSet::from(["pg-dump-no-clean"])),
         ("database.redis", HashSet::from(["shutdown"])),
         (
@@ -83,7 +138,7 @@ fn test_audit_backtracking_requirements() {
         ),
         (
             "infrastructure.terraform",
-            HashSet::from(["terraform-plan"]),
+            HashSet::from(["terraform-plan", "destroy"]),
         ),
         ("kubernetes.helm", HashSet::from(["uninstall", "rollback"])),
         (
@@ -119,6 +174,9 @@ fn test_audit_backtracking_requirements() {
                 "gh-auth-status",
                 "gh-status",
                 "gh-api-explicit-get",
+                "gh-run-list-view",
+                "gh-cache-list",
+                "gh-workflow-list-view",
                 // Destructive patterns
                 "gh-repo-delete",
                 "gh-repo-archive",
@@ -130,6 +188,10 @@ fn test_audit_backtracking_requirements() {
                 "gh-variable-delete",
                 "gh-repo-deploy-key-delete",
                 "gh-run-cancel",
+                "gh-run-delete",
+                "gh-cache-delete-all",
+                "gh-cache-delete",
+                "gh-workflow-disable",
                 "gh-api-delete-repo",
                 "gh-api-delete-actions-secret",
                 "gh-api-delete-actions-variable",
@@ -138,6 +200,33 @@ fn test_audit_backtracking_requirements() {
                 "gh-api-delete-release",
             ]),
         ),
+        (
+            "platform.gitlab",
+            HashSet::from([
+                "glab-repo-list",
+                "glab-repo-view",
+                "glab-repo-clone",
+                "glab-mr-list",
+                "glab-mr-view",
+                "glab-issue-list",
+                "glab-issue-view",
+                "glab-variable-list",
+                "glab-release-list",
+                "glab-release-view",
+                "glab-api-explicit-get",
+                "glab-repo-delete",
+                "glab-repo-archive",
+                "glab-release-delete",
+                "glab-issue-delete",
+                "glab-mr-delete",
+                "glab-variable-delete",
+                "glab-api-delete-project",
+                "glab-api-delete-release",
+                "glab-api-delete-variable",
+                "glab-api-delete-protected-branch",
+                "glab-api-delete-hook",
+            ]),
+        ),
         (
             "system.disk",
             HashSet::from(["fdisk-edit", "parted-modify"]),
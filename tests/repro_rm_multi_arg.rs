@@ -28,4 +28,22 @@ mod tests {
             "Command '{cmd}' should be denied but was allowed!"
         );
     }
+
+    #[test]
+    fn test_rm_segment_after_directory_change_is_denied() {
+        let config = Config::default();
+        let compiled_overrides = config.overrides.compile();
+        let allowlists = load_default_allowlists();
+
+        for cmd in [
+            "cd /data && rm -rf .",
+            "pushd x; rm -rf .",
+            "rm -rf /tmp/x && rm -rf /",
+        ] {
+            let result = evaluate_command(cmd, &config, &["rm"], &compiled_overrides, &allowlists);
+            assert!(result.is_denied(), "Command '{cmd}' should be denied");
+            let info = result.pattern_info.as_ref().expect("pattern info");
+            assert_eq!(info.pack_id.as_deref(), Some("core.filesystem"), "{cmd}");
+        }
+    }
 }
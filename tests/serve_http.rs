@@ -0,0 +1,95 @@
+//! Integration tests for `dcg serve --http`.
+//!
+//! Binds the server on an ephemeral loopback port and talks raw HTTP/1.1 to
+//! it, checking the `/evaluate` and `/explain` endpoints and token auth.
+//!
+//! # Running
+//!
+//! ```bash
+//! cargo test --features http-server --test serve_http
+//! ```
+#![cfg(feature = "http-server")]
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+
+use destructive_command_guard::config::Config;
+use destructive_command_guard::serve::HttpServer;
+
+fn start_server(token: Option<&str>) -> SocketAddr {
+    let server = HttpServer::bind("127.0.0.1:0", Config::default(), token.map(String::from))
+        .expect("bind loopback");
+    let addr = server.local_addr().expect("local addr");
+    std::thread::spawn(move || server.run());
+    addr
+}
+
+/// Send one request and return `(status, parsed JSON body)`.
+fn post(addr: SocketAddr, path: &str, token: Option<&str>, body: &str) -> (u16, serde_json::Value) {
+    let mut stream = TcpStream::connect(addr).expect("connect");
+    let token_header = token.map_or_else(String::new, |t| format!("X-DCG-Token: {t}\r\n"));
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+         {token_header}Content-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .expect("write request");
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("read response");
+    let (head, json) = response.split_once("\r\n\r\n").expect("header terminator");
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .expect("status code");
+    (status, serde_json::from_str(json).expect("JSON body"))
+}
+
+#[test]
+fn evaluate_reports_allow_and_deny() {
+    let addr = start_server(None);
+
+    let (status, body) = post(addr, "/evaluate", None, r#"{"command":"git status"}"#);
+    assert_eq!(status, 200);
+    assert_eq!(body["decision"], "allow");
+    assert!(body["rule_id"].is_null());
+
+    let (status, body) = post(addr, "/evaluate", None, r#"{"command":"git reset --hard"}"#);
+    assert_eq!(status, 200);
+    assert_eq!(body["decision"], "deny");
+    assert_eq!(body["rule_id"], "core.git:reset-hard");
+    assert!(body["reason"].as_str().is_some_and(|r| !r.is_empty()));
+}
+
+#[test]
+fn explain_returns_trace_json() {
+    let addr = start_server(None);
+    let (status, body) = post(addr, "/explain", None, r#"{"command":"git reset --hard"}"#);
+    assert_eq!(status, 200);
+    assert_eq!(body["command"], "git reset --hard");
+    assert_eq!(body["decision"], "deny");
+    assert!(body["schema_version"].is_number());
+}
+
+#[test]
+fn token_is_required_when_configured() {
+    let addr = start_server(Some("s3cret"));
+    let body = r#"{"command":"git status"}"#;
+
+    let (status, _) = post(addr, "/evaluate", None, body);
+    assert_eq!(status, 401);
+    let (status, _) = post(addr, "/evaluate", Some("wrong"), body);
+    assert_eq!(status, 401);
+    let (status, json) = post(addr, "/evaluate", Some("s3cret"), body);
+    assert_eq!(status, 200);
+    assert_eq!(json["decision"], "allow");
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let addr = start_server(None);
+    assert_eq!(post(addr, "/evaluate", None, "not json").0, 400);
+    assert_eq!(post(addr, "/nope", None, "{}").0, 404);
+}
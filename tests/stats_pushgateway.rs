@@ -0,0 +1,120 @@
+//! Integration tests for `dcg stats --push-gateway`.
+//!
+//! Runs the `dcg` binary against a one-shot mock Pushgateway on an ephemeral
+//! loopback port and checks the pushed request.
+//!
+//! # Running
+//!
+//! ```bash
+//! cargo test --features http-server --test stats_pushgateway
+//! ```
+#![cfg(feature = "http-server")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Accept one request, answer with `status`, and hand back the raw request.
+fn mock_gateway(status: &'static str) -> (SocketAddr, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind loopback");
+    let addr = listener.local_addr().expect("local addr");
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().expect("accept");
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("read header");
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().expect("content length");
+                }
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).expect("read body");
+        request.push_str(&String::from_utf8(body).expect("utf-8 body"));
+
+        let mut stream = reader.into_inner();
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Length: 10\r\nConnection: close\r\n\r\nbad metric"
+        )
+        .expect("write response");
+        tx.send(request).expect("send request");
+    });
+    (addr, rx)
+}
+
+fn run_stats_push(addr: SocketAddr, log: &std::path::Path) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_dcg"))
+        .args(["stats", "--file"])
+        .arg(log)
+        .args(["--push-gateway", &format!("http://{addr}")])
+        .args(["--job", "dcg-ci", "--instance", "runner/7"])
+        .output()
+        .expect("run dcg")
+}
+
+fn write_log(dir: &std::path::Path) -> std::path::PathBuf {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock")
+        .as_secs();
+    let log = dir.join("blocked.log");
+    std::fs::write(
+        &log,
+        format!("[{now}] [core.git] blocked git reset --hard\n  Command: git reset --hard\n"),
+    )
+    .expect("write log");
+    log
+}
+
+#[test]
+fn pushes_prometheus_text_with_job_and_instance_labels() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let log = write_log(dir.path());
+    let (addr, requests) = mock_gateway("200 OK");
+
+    let output = run_stats_push(addr, &log);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let request = requests.recv().expect("gateway saw a request");
+    assert!(
+        request.starts_with("POST /metrics/job/dcg-ci/instance@base64/cnVubmVyLzc= HTTP/1.1\r\n"),
+        "{request}"
+    );
+    assert!(
+        request.contains("Content-Type: text/plain; version=0.0.4\r\n"),
+        "{request}"
+    );
+    assert!(request.contains("dcg_log_entries 1\n"), "{request}");
+    assert!(
+        request.contains("dcg_pack_decisions{pack=\"core.git\",decision=\"deny\"} 1\n"),
+        "{request}"
+    );
+}
+
+#[test]
+fn reports_gateway_rejection() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let log = write_log(dir.path());
+    let (addr, _requests) = mock_gateway("400 Bad Request");
+
+    let output = run_stats_push(addr, &log);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("HTTP 400 bad metric"), "{stderr}");
+}